@@ -121,8 +121,14 @@ elif shape == "capabilities":
         "observations": True,
         "sessions": True,
         "workstreams": True,
+        "workstream_plans": True,
         "events": True,
+        "event_stream": True,
+        "openapi": True,
+        "injections": True,
+        "web_ui": True,
         "tasks": True,
+        "timeline_report": True,
         "memory_archive": True,
         "memory_restore": True,
         "memory_delete": False,
@@ -141,10 +147,18 @@ elif shape == "capabilities":
         "sessions_detail": "/api/v1/sessions/{id}",
         "workstreams_list": "/api/v1/workstreams",
         "workstreams_detail": "/api/v1/workstreams/{id}",
+        "workstream_create": "/api/v1/workstreams",
+        "workstream_plan": "/api/v1/workstreams/{id}/plan",
         "events_list": "/api/v1/events",
         "events_detail": "/api/v1/events/{id}",
+        "event_stream": "/api/v1/stream",
+        "openapi": "/api/v1/openapi.json",
+        "injections": "/api/v1/injections",
+        "injection_detail": "/api/v1/injections/{id}",
+        "web_ui": "/ui/",
         "tasks_list": "/api/v1/tasks",
         "tasks_detail": "/api/v1/tasks/{id}",
+        "timeline_report": "/api/v1/timeline/report",
         "memory_archive": "/api/v1/memories/{id}/archive",
         "memory_restore": "/api/v1/memories/{id}/restore",
     }
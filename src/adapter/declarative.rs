@@ -0,0 +1,385 @@
+//! Hook adapters described by a TOML or JSON spec instead of Rust code.
+//!
+//! Specs live in `<config dir>/adapters/*.toml|*.json` (next to
+//! `config.toml`). A spec names JSON-pointer paths into the host payload and
+//! maps host tool names onto the tools remem already classifies (`Bash`,
+//! `Edit`, `Write`, ...), so skip rules and event summaries are shared with
+//! the built-in adapters. Select one with `REMEM_HOOK_ADAPTER=<name>` or a
+//! host's `capture_adapter`; specs with `detect` pointers also take part in
+//! auto-detection ahead of the built-in adapters.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, ensure, Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::adapter::common;
+use crate::adapter::{EventSummary, ParsedHookEvent, ToolAdapter};
+
+#[cfg(test)]
+mod tests;
+
+const ADAPTERS_DIR: &str = "adapters";
+const BUILTIN_NAMES: &[&str] = &["claude-code", "codex-cli", "gemini-cli"];
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AdapterSpec {
+    pub name: String,
+    /// Pointers that must all resolve for auto-detection; empty means the
+    /// adapter is only used when selected by name.
+    #[serde(default)]
+    pub detect: Vec<String>,
+    pub paths: PayloadPaths,
+    /// Tool names (after `events` mapping) that are never captured.
+    #[serde(default)]
+    pub skip_tools: Vec<String>,
+    #[serde(default)]
+    pub bash: BashPolicy,
+    /// Host tool name -> remem tool it should be classified as.
+    #[serde(default)]
+    pub events: BTreeMap<String, EventMapping>,
+}
+
+/// Each field is one JSON pointer or a list tried in order; the first
+/// pointer that resolves to a non-null value wins.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PayloadPaths {
+    pub session_id: Pointers,
+    #[serde(default)]
+    pub cwd: Pointers,
+    #[serde(default)]
+    pub tool_name: Pointers,
+    #[serde(default)]
+    pub tool_input: Pointers,
+    #[serde(default)]
+    pub tool_response: Pointers,
+    #[serde(default)]
+    pub timestamp: Pointers,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(from = "OneOrMany")]
+pub struct Pointers(pub Vec<String>);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl From<OneOrMany> for Pointers {
+    fn from(value: OneOrMany) -> Self {
+        match value {
+            OneOrMany::One(pointer) => Self(vec![pointer]),
+            OneOrMany::Many(pointers) => Self(pointers),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BashPolicy {
+    /// Apply remem's built-in read-only command list.
+    #[serde(default = "default_true")]
+    pub builtin_read_only: bool,
+    /// Extra `regex_lite` patterns for read-only commands to skip.
+    #[serde(default)]
+    pub read_only_patterns: Vec<String>,
+}
+
+impl Default for BashPolicy {
+    fn default() -> Self {
+        Self {
+            builtin_read_only: true,
+            read_only_patterns: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventMapping {
+    pub tool: String,
+    /// remem input key -> pointer into the host's tool input, for hosts
+    /// that name fields differently (e.g. `file_path = "/absolute_path"`).
+    #[serde(default)]
+    pub input: BTreeMap<String, String>,
+    /// Same for the tool response (`exitCode`, `stderr`).
+    #[serde(default)]
+    pub response: BTreeMap<String, String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+pub struct DeclarativeAdapter {
+    spec: AdapterSpec,
+    read_only: Vec<regex_lite::Regex>,
+}
+
+impl DeclarativeAdapter {
+    pub fn from_spec(spec: AdapterSpec) -> Result<Self> {
+        let name = spec.name.as_str();
+        ensure!(
+            !name.is_empty()
+                && name
+                    .chars()
+                    .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '-'),
+            "adapter name {:?} must be lowercase ASCII letters, digits and '-'",
+            spec.name
+        );
+        ensure!(
+            !BUILTIN_NAMES.contains(&name),
+            "adapter name '{name}' is reserved for a built-in adapter"
+        );
+        ensure!(
+            !spec.paths.session_id.0.is_empty(),
+            "adapter '{name}' needs paths.session_id"
+        );
+        let pointers = spec
+            .detect
+            .iter()
+            .chain(&spec.paths.session_id.0)
+            .chain(&spec.paths.cwd.0)
+            .chain(&spec.paths.tool_name.0)
+            .chain(&spec.paths.tool_input.0)
+            .chain(&spec.paths.tool_response.0)
+            .chain(&spec.paths.timestamp.0)
+            .chain(
+                spec.events
+                    .values()
+                    .flat_map(|mapping| mapping.input.values().chain(mapping.response.values())),
+            );
+        for pointer in pointers {
+            validate_pointer(pointer).with_context(|| format!("adapter '{name}'"))?;
+        }
+        for (host_tool, mapping) in &spec.events {
+            ensure!(
+                !common::should_skip_tool(&mapping.tool),
+                "adapter '{name}' maps '{host_tool}' to unsupported tool '{}'",
+                mapping.tool
+            );
+        }
+        let read_only = spec
+            .bash
+            .read_only_patterns
+            .iter()
+            .map(|pattern| {
+                regex_lite::Regex::new(pattern).with_context(|| {
+                    format!("adapter '{name}' has invalid bash read-only pattern {pattern:?}")
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { spec, read_only })
+    }
+
+    /// Parse only when every `detect` pointer resolves; used for
+    /// auto-detection, where the spec must positively recognize the payload.
+    pub(crate) fn detect(&self, raw_json: &str) -> Option<ParsedHookEvent> {
+        if self.spec.detect.is_empty() {
+            return None;
+        }
+        let payload = serde_json::from_str::<Value>(raw_json).ok()?;
+        self.spec
+            .detect
+            .iter()
+            .all(|pointer| {
+                payload
+                    .pointer(pointer)
+                    .is_some_and(|value| !value.is_null())
+            })
+            .then(|| self.parse_value(&payload))
+            .flatten()
+    }
+
+    fn parse_value(&self, payload: &Value) -> Option<ParsedHookEvent> {
+        let paths = &self.spec.paths;
+        let session_id = resolve(payload, &paths.session_id)?.as_str()?.to_string();
+        let cwd = resolve(payload, &paths.cwd)
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let host_tool = resolve(payload, &paths.tool_name)
+            .and_then(Value::as_str)
+            .unwrap_or("unknown");
+        let mut tool_input = resolve(payload, &paths.tool_input).cloned();
+        let mut tool_response = resolve(payload, &paths.tool_response).cloned();
+        let tool_name = match self.spec.events.get(host_tool) {
+            Some(mapping) => {
+                tool_input = remap_fields(tool_input, &mapping.input);
+                tool_response = remap_fields(tool_response, &mapping.response);
+                mapping.tool.clone()
+            }
+            None => host_tool.to_string(),
+        };
+        let reference_time_epoch = resolve(payload, &paths.timestamp)
+            .and_then(|value| crate::memory::reference_time::parse_json_epoch_value(Some(value)));
+        let project = crate::db::project_from_cwd(cwd.as_deref().unwrap_or("."));
+        Some(ParsedHookEvent {
+            session_id,
+            cwd,
+            project,
+            reference_time_epoch,
+            tool_name,
+            tool_input,
+            tool_response,
+        })
+    }
+}
+
+impl ToolAdapter for DeclarativeAdapter {
+    fn name(&self) -> &str {
+        &self.spec.name
+    }
+
+    fn parse_hook(&self, raw_json: &str) -> Option<ParsedHookEvent> {
+        match serde_json::from_str::<Value>(raw_json) {
+            Ok(payload) => self.parse_value(&payload),
+            Err(e) => {
+                crate::log::error(
+                    "adapter",
+                    &format!(
+                        "{}: failed to parse hook payload: {e}; raw (truncated): {}",
+                        self.spec.name,
+                        common::redact_hook_payload_preview(raw_json, 512)
+                    ),
+                );
+                None
+            }
+        }
+    }
+
+    fn should_skip(&self, event: &ParsedHookEvent) -> bool {
+        self.spec.skip_tools.contains(&event.tool_name)
+            || common::should_skip_tool(&event.tool_name)
+    }
+
+    fn should_skip_bash(&self, command: &str) -> bool {
+        (self.spec.bash.builtin_read_only && common::should_skip_bash_command(command))
+            || self
+                .read_only
+                .iter()
+                .any(|pattern| pattern.is_match(command.trim()))
+    }
+
+    fn classify_event(&self, event: &ParsedHookEvent) -> Option<EventSummary> {
+        common::event_summary(&event.tool_name, &event.tool_input, &event.tool_response)
+    }
+}
+
+pub fn adapters_dir() -> Result<PathBuf> {
+    let config = crate::runtime_config::config_path()?;
+    Ok(config
+        .parent()
+        .map(|dir| dir.join(ADAPTERS_DIR))
+        .unwrap_or_else(|| PathBuf::from(ADAPTERS_DIR)))
+}
+
+pub fn load_spec(path: &Path) -> Result<AdapterSpec> {
+    let text = std::fs::read_to_string(path).with_context(|| format!("read {}", path.display()))?;
+    let value = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => serde_json::from_str::<Value>(&text)
+            .with_context(|| format!("parse {}", path.display()))?,
+        Some("toml") => {
+            let doc = text
+                .parse::<toml_edit::DocumentMut>()
+                .with_context(|| format!("parse {}", path.display()))?;
+            Value::Object(
+                crate::toml_json::table_to_json(doc.as_table())
+                    .with_context(|| format!("parse {}", path.display()))?,
+            )
+        }
+        _ => bail!("{} is not a .toml or .json adapter spec", path.display()),
+    };
+    serde_json::from_value(value)
+        .with_context(|| format!("invalid adapter spec {}", path.display()))
+}
+
+/// Load every spec in `dir` in file-name order. Invalid specs and later
+/// duplicates of a name are reported and skipped, never fatal to capture.
+pub fn load_adapters(dir: &Path) -> (Vec<DeclarativeAdapter>, Vec<String>) {
+    let mut paths = match std::fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                matches!(
+                    path.extension().and_then(|ext| ext.to_str()),
+                    Some("toml" | "json")
+                )
+            })
+            .collect::<Vec<_>>(),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return (Vec::new(), Vec::new()),
+        Err(err) => return (Vec::new(), vec![format!("read {}: {err}", dir.display())]),
+    };
+    paths.sort();
+
+    let mut adapters: Vec<DeclarativeAdapter> = Vec::new();
+    let mut errors = Vec::new();
+    for path in paths {
+        match load_spec(&path).and_then(DeclarativeAdapter::from_spec) {
+            Ok(adapter) if adapters.iter().any(|known| known.name() == adapter.name()) => {
+                errors.push(format!(
+                    "{}: duplicate adapter name '{}'",
+                    path.display(),
+                    adapter.name()
+                ));
+            }
+            Ok(adapter) => adapters.push(adapter),
+            Err(err) => errors.push(format!("{err:#}")),
+        }
+    }
+    (adapters, errors)
+}
+
+pub(crate) fn load_configured_adapters() -> Vec<DeclarativeAdapter> {
+    let dir = match adapters_dir() {
+        Ok(dir) => dir,
+        Err(err) => {
+            crate::log::warn(
+                "adapter",
+                &format!("resolve adapter spec directory: {err:#}"),
+            );
+            return Vec::new();
+        }
+    };
+    let (adapters, errors) = load_adapters(&dir);
+    for error in errors {
+        crate::log::warn("adapter", &format!("skipping adapter spec: {error}"));
+    }
+    adapters
+}
+
+fn validate_pointer(pointer: &str) -> Result<()> {
+    ensure!(
+        pointer.is_empty() || pointer.starts_with('/'),
+        "JSON pointer {pointer:?} must be empty or start with '/'"
+    );
+    Ok(())
+}
+
+fn resolve<'a>(payload: &'a Value, pointers: &Pointers) -> Option<&'a Value> {
+    pointers
+        .0
+        .iter()
+        .filter_map(|pointer| payload.pointer(pointer))
+        .find(|value| !value.is_null())
+}
+
+fn remap_fields(value: Option<Value>, aliases: &BTreeMap<String, String>) -> Option<Value> {
+    if aliases.is_empty() {
+        return value;
+    }
+    let original = value.unwrap_or(Value::Object(Default::default()));
+    let mut remapped = original.as_object().cloned().unwrap_or_default();
+    for (key, pointer) in aliases {
+        if let Some(value) = original.pointer(pointer).filter(|value| !value.is_null()) {
+            remapped.insert(key.clone(), value.clone());
+        }
+    }
+    Some(Value::Object(remapped))
+}
@@ -0,0 +1,225 @@
+use std::path::PathBuf;
+
+use anyhow::{ensure, Context, Result};
+use serde::Deserialize;
+use serde_json::json;
+
+use super::*;
+
+const PAYLOADS: &str = include_str!("../../../tests/fixtures/hook-adapter-payloads.json");
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PayloadSuite {
+    schema_version: u32,
+    cases: Vec<PayloadCase>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PayloadCase {
+    id: String,
+    adapter: String,
+    payload: Value,
+    expect: Expectation,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expectation {
+    #[serde(default = "default_true")]
+    parsed: bool,
+    session_id: Option<String>,
+    cwd: Option<String>,
+    tool_name: Option<String>,
+    reference_time_epoch: Option<i64>,
+    skip: Option<bool>,
+    skip_bash: Option<bool>,
+    event_type: Option<String>,
+    summary_contains: Option<String>,
+    exit_code: Option<i32>,
+    detail: Option<String>,
+}
+
+fn fixture_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/hook-adapters")
+}
+
+fn spec(value: Value) -> Result<AdapterSpec> {
+    Ok(serde_json::from_value(value)?)
+}
+
+#[test]
+fn fixture_specs_replay_sample_hook_payloads() -> Result<()> {
+    let (adapters, errors) = load_adapters(&fixture_dir());
+    ensure!(errors.is_empty(), "fixture specs must load: {errors:?}");
+    ensure!(
+        adapters.len() == 2,
+        "expected the TOML and JSON fixture specs"
+    );
+    let suite: PayloadSuite = serde_json::from_str(PAYLOADS)?;
+    ensure!(suite.schema_version == 1, "unsupported fixture schema");
+
+    for case in suite.cases {
+        let adapter = adapters
+            .iter()
+            .find(|adapter| adapter.name() == case.adapter)
+            .with_context(|| format!("{}: unknown adapter {}", case.id, case.adapter))?;
+        let parsed = adapter.parse_hook(&case.payload.to_string());
+        let expect = case.expect;
+        let Some(event) = parsed else {
+            ensure!(!expect.parsed, "{}: payload did not parse", case.id);
+            continue;
+        };
+        ensure!(expect.parsed, "{}: payload unexpectedly parsed", case.id);
+
+        let id = &case.id;
+        if let Some(session_id) = &expect.session_id {
+            ensure!(&event.session_id == session_id, "{id}: session {event:?}");
+        }
+        if let Some(cwd) = &expect.cwd {
+            ensure!(event.cwd.as_ref() == Some(cwd), "{id}: cwd {event:?}");
+        }
+        if let Some(tool_name) = &expect.tool_name {
+            ensure!(&event.tool_name == tool_name, "{id}: tool {event:?}");
+        }
+        if let Some(epoch) = expect.reference_time_epoch {
+            ensure!(event.reference_time_epoch == Some(epoch), "{id}: time");
+        }
+        if let Some(skip) = expect.skip {
+            ensure!(adapter.should_skip(&event) == skip, "{id}: should_skip");
+        }
+        if let Some(skip_bash) = expect.skip_bash {
+            let command = event
+                .tool_input
+                .as_ref()
+                .and_then(|input| input.get("command"))
+                .and_then(Value::as_str)
+                .with_context(|| format!("{id}: no bash command"))?;
+            ensure!(
+                adapter.should_skip_bash(command) == skip_bash,
+                "{id}: should_skip_bash({command})"
+            );
+        }
+        if let Some(event_type) = &expect.event_type {
+            let summary = adapter
+                .classify_event(&event)
+                .with_context(|| format!("{id}: no event summary"))?;
+            ensure!(&summary.event_type == event_type, "{id}: {summary:?}");
+            if let Some(needle) = &expect.summary_contains {
+                ensure!(summary.summary.contains(needle), "{id}: {summary:?}");
+            }
+            if let Some(exit_code) = expect.exit_code {
+                ensure!(summary.exit_code == Some(exit_code), "{id}: {summary:?}");
+            }
+            if let Some(detail) = &expect.detail {
+                ensure!(summary.detail.as_ref() == Some(detail), "{id}: {summary:?}");
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn detection_requires_every_detect_pointer() -> Result<()> {
+    let with_detect = DeclarativeAdapter::from_spec(spec(json!({
+        "name": "sample",
+        "detect": ["/sessionID", "/tool"],
+        "paths": {"session_id": "/sessionID", "tool_name": "/tool"}
+    }))?)?;
+    let by_name_only = DeclarativeAdapter::from_spec(spec(json!({
+        "name": "sample-by-name",
+        "paths": {"session_id": "/sessionID"}
+    }))?)?;
+    let payload = r#"{"sessionID":"s1","tool":"bash"}"#;
+
+    assert_eq!(
+        with_detect.detect(payload).map(|event| event.session_id),
+        Some("s1".to_string())
+    );
+    assert!(with_detect
+        .detect(r#"{"sessionID":"s1","tool":null}"#)
+        .is_none());
+    assert!(with_detect
+        .detect(r#"{"session_id":"s1","tool_name":"Edit"}"#)
+        .is_none());
+    assert!(by_name_only.detect(payload).is_none());
+    assert!(by_name_only.parse_hook(payload).is_some());
+    Ok(())
+}
+
+#[test]
+fn invalid_specs_are_rejected_with_reason() -> Result<()> {
+    for (value, expected) in [
+        (
+            json!({"name": "claude-code", "paths": {"session_id": "/s"}}),
+            "reserved",
+        ),
+        (
+            json!({"name": "Bad Name", "paths": {"session_id": "/s"}}),
+            "lowercase",
+        ),
+        (
+            json!({"name": "x", "paths": {"session_id": []}}),
+            "paths.session_id",
+        ),
+        (
+            json!({"name": "x", "paths": {"session_id": "session"}}),
+            "must be empty or start with '/'",
+        ),
+        (
+            json!({"name": "x", "paths": {"session_id": "/s"}, "events": {"read": {"tool": "Read"}}}),
+            "unsupported tool 'Read'",
+        ),
+        (
+            json!({"name": "x", "paths": {"session_id": "/s"}, "bash": {"read_only_patterns": ["("]}}),
+            "invalid bash read-only pattern",
+        ),
+    ] {
+        let error = DeclarativeAdapter::from_spec(spec(value.clone())?)
+            .err()
+            .with_context(|| format!("{value} should be rejected"))?;
+        ensure!(
+            format!("{error:#}").contains(expected),
+            "{value}: {error:#}"
+        );
+    }
+    assert!(spec(json!({"name": "x", "paths": {"session_id": "/s"}, "extra": 1})).is_err());
+    Ok(())
+}
+
+#[test]
+fn directory_loading_skips_bad_and_duplicate_specs() -> Result<()> {
+    let dir = std::env::temp_dir().join(format!(
+        "remem-adapter-specs-{}-{}",
+        std::process::id(),
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+    ));
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(
+        dir.join("a.toml"),
+        "name = \"dup\"\n[paths]\nsession_id = \"/s\"\n",
+    )?;
+    std::fs::write(
+        dir.join("b.json"),
+        r#"{"name": "dup", "paths": {"session_id": "/s"}}"#,
+    )?;
+    std::fs::write(dir.join("c.toml"), "name = ")?;
+    std::fs::write(dir.join("notes.txt"), "ignored")?;
+
+    let (adapters, errors) = load_adapters(&dir);
+
+    assert_eq!(
+        adapters
+            .iter()
+            .map(|adapter| adapter.name())
+            .collect::<Vec<_>>(),
+        ["dup"]
+    );
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    assert!(errors[0].contains("duplicate adapter name 'dup'"));
+    assert!(errors[1].contains("c.toml"));
+    assert!(load_adapters(&dir.join("missing")).0.is_empty());
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+}
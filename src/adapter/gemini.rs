@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests;
+
+use serde_json::Value;
+
+use crate::adapter::{EventSummary, ParsedHookEvent, ToolAdapter};
+
+/// Gemini CLI `AfterTool` hooks send Claude-shaped payloads (`session_id`,
+/// `cwd`, `tool_name`, `tool_input`, `tool_response`) but with Gemini's own
+/// snake_case tool names and a `{llmContent, returnDisplay}` response.
+pub struct GeminiCliAdapter;
+
+impl ToolAdapter for GeminiCliAdapter {
+    fn name(&self) -> &str {
+        crate::runtime_config::GEMINI_HOST
+    }
+
+    fn parse_hook(&self, raw_json: &str) -> Option<ParsedHookEvent> {
+        let mut event = crate::adapter::common::parse_tool_hook(raw_json)?;
+        if let Some(tool) = canonical_tool_name(&event.tool_name) {
+            event.tool_name = tool.to_string();
+        }
+        if event.tool_name == "Bash" {
+            event.tool_response = shell_response(event.tool_response);
+        }
+        Some(event)
+    }
+
+    fn should_skip(&self, event: &ParsedHookEvent) -> bool {
+        crate::adapter::common::should_skip_tool(&event.tool_name)
+    }
+
+    fn should_skip_bash(&self, command: &str) -> bool {
+        crate::adapter::common::should_skip_bash_command(command)
+    }
+
+    fn classify_event(&self, event: &ParsedHookEvent) -> Option<EventSummary> {
+        crate::adapter::common::event_summary(
+            &event.tool_name,
+            &event.tool_input,
+            &event.tool_response,
+        )
+    }
+}
+
+/// Gemini built-in tools whose inputs already use the field names the shared
+/// summarizer reads (`file_path`, `command`, `pattern`, `path`).
+fn canonical_tool_name(tool_name: &str) -> Option<&'static str> {
+    match tool_name {
+        "write_file" => Some("Write"),
+        "replace" => Some("Edit"),
+        "run_shell_command" => Some("Bash"),
+        "search_file_content" => Some("Grep"),
+        "glob" => Some("Glob"),
+        _ => None,
+    }
+}
+
+/// `run_shell_command` reports its result as text (`Stderr: ...`,
+/// `Exit Code: N`) inside `llmContent`; lift those into the `exitCode` /
+/// `stderr` fields the Bash summary expects.
+fn shell_response(response: Option<Value>) -> Option<Value> {
+    let mut object = match response {
+        Some(Value::Object(object)) => object,
+        other => return other,
+    };
+    if object.contains_key("exitCode") {
+        return Some(Value::Object(object));
+    }
+    let text = object
+        .get("llmContent")
+        .map(content_text)
+        .unwrap_or_default();
+    let mut stderr = None;
+    let mut error = None;
+    for line in text.lines() {
+        if let Some(code) = line.strip_prefix("Exit Code:") {
+            if let Ok(code) = code.trim().parse::<i64>() {
+                object.insert("exitCode".to_string(), Value::from(code));
+            }
+        } else if let Some(value) = line.strip_prefix("Stderr:") {
+            stderr = meaningful(value).or(stderr);
+        } else if let Some(value) = line.strip_prefix("Error:") {
+            error = meaningful(value).or(error);
+        }
+    }
+    if let Some(stderr) = stderr.or(error) {
+        object
+            .entry("stderr")
+            .or_insert_with(|| Value::String(stderr));
+    }
+    Some(Value::Object(object))
+}
+
+fn content_text(content: &Value) -> String {
+    match content {
+        Value::String(text) => text.clone(),
+        Value::Array(parts) => parts
+            .iter()
+            .filter_map(|part| part.get("text").and_then(Value::as_str))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => String::new(),
+    }
+}
+
+fn meaningful(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty() && value != "(none)" && value != "(empty)").then(|| value.to_string())
+}
@@ -0,0 +1,58 @@
+use crate::adapter::ToolAdapter;
+
+use super::GeminiCliAdapter;
+
+#[test]
+fn maps_gemini_file_tools_onto_shared_summaries() {
+    let adapter = GeminiCliAdapter;
+    let json = r#"{
+        "session_id": "g1",
+        "cwd": "/tmp/remem",
+        "hook_event_name": "AfterTool",
+        "timestamp": "2026-01-01T00:00:00Z",
+        "tool_name": "replace",
+        "tool_input": {"file_path": "/tmp/remem/src/lib.rs", "old_string": "a", "new_string": "b"},
+        "tool_response": {"llmContent": "Successfully modified file", "returnDisplay": "diff"}
+    }"#;
+
+    let event = adapter.parse_hook(json).expect("gemini hook should parse");
+    let summary = adapter.classify_event(&event).expect("edit summary");
+
+    assert_eq!(event.session_id, "g1");
+    assert_eq!(event.tool_name, "Edit");
+    assert_eq!(event.reference_time_epoch, Some(1_767_225_600));
+    assert!(!adapter.should_skip(&event));
+    assert_eq!(summary.event_type, "file_edit");
+    assert!(summary.summary.contains("lib.rs"));
+}
+
+#[test]
+fn lifts_shell_exit_code_and_stderr_from_llm_content() {
+    let adapter = GeminiCliAdapter;
+    let json = r#"{
+        "session_id": "g1",
+        "cwd": "/tmp/remem",
+        "tool_name": "run_shell_command",
+        "tool_input": {"command": "cargo test -p api", "description": "run tests"},
+        "tool_response": {"llmContent": "Command: cargo test -p api\nDirectory: (root)\nStdout: running 3 tests\nStderr: test failed\nError: (none)\nExit Code: 101\nSignal: (none)"}
+    }"#;
+
+    let event = adapter.parse_hook(json).expect("gemini hook should parse");
+    let summary = adapter.classify_event(&event).expect("bash summary");
+
+    assert_eq!(event.tool_name, "Bash");
+    assert_eq!(summary.exit_code, Some(101));
+    assert_eq!(summary.detail.as_deref(), Some("test failed"));
+    assert!(adapter.should_skip_bash("git status"));
+}
+
+#[test]
+fn read_only_gemini_tools_are_skipped() {
+    let adapter = GeminiCliAdapter;
+    let json = r#"{"session_id":"g1","cwd":"/tmp","tool_name":"read_file","tool_input":{"absolute_path":"/tmp/a"}}"#;
+
+    let event = adapter.parse_hook(json).expect("gemini hook should parse");
+
+    assert_eq!(event.tool_name, "read_file");
+    assert!(adapter.should_skip(&event));
+}
@@ -3,6 +3,8 @@ use std::sync::LazyLock;
 pub mod claude;
 pub mod codex;
 pub(crate) mod common;
+pub mod declarative;
+pub mod gemini;
 pub(crate) mod redaction;
 
 /// Normalized event parsed from a hook's raw JSON input.
@@ -28,7 +30,8 @@ pub struct EventSummary {
 }
 
 /// Adapter trait for AI coding tool integrations.
-/// Each supported tool (Claude Code, Codex, Cursor, ...) implements this.
+/// Each supported tool (Claude Code, Codex, Cursor, ...) implements this;
+/// other hosts can be described declaratively (see [`declarative`]).
 pub trait ToolAdapter: Send + Sync {
     /// Adapter identifier (e.g. "claude-code", "codex-cli")
     fn name(&self) -> &str;
@@ -51,17 +54,29 @@ static ADAPTERS: LazyLock<Vec<Box<dyn ToolAdapter>>> = LazyLock::new(|| {
     vec![
         Box::new(crate::adapter::claude::ClaudeCodeAdapter),
         Box::new(crate::adapter::codex::CodexAdapter),
+        Box::new(crate::adapter::gemini::GeminiCliAdapter),
     ]
 });
 
+/// Spec-defined adapters from `<config dir>/adapters`, loaded once per process.
+static DECLARATIVE_ADAPTERS: LazyLock<Vec<declarative::DeclarativeAdapter>> =
+    LazyLock::new(declarative::load_configured_adapters);
+
 /// Auto-detect adapter from raw hook JSON and parse the event.
 pub fn detect_adapter(raw_json: &str) -> Option<(&'static dyn ToolAdapter, ParsedHookEvent)> {
     if let Ok(name) = std::env::var("REMEM_HOOK_ADAPTER") {
         if !name.trim().is_empty() {
-            return detect_adapter_by_name(raw_json, &name);
+            return detect_adapter_by_name(raw_json, name.trim());
         }
     }
 
+    // Built-in parsers accept any payload with a session id, so spec adapters
+    // that positively recognize the payload go first.
+    for adapter in DECLARATIVE_ADAPTERS.iter() {
+        if let Some(event) = adapter.detect(raw_json) {
+            return Some((adapter as &dyn ToolAdapter, event));
+        }
+    }
     for adapter in ADAPTERS.iter() {
         if let Some(event) = adapter.parse_hook(raw_json) {
             return Some((adapter.as_ref(), event));
@@ -74,11 +89,13 @@ pub(crate) fn detect_adapter_by_name(
     raw_json: &str,
     name: &str,
 ) -> Option<(&'static dyn ToolAdapter, ParsedHookEvent)> {
-    for adapter in ADAPTERS.iter() {
+    let builtin = ADAPTERS.iter().map(|adapter| adapter.as_ref());
+    let declarative = DECLARATIVE_ADAPTERS
+        .iter()
+        .map(|adapter| adapter as &dyn ToolAdapter);
+    for adapter in builtin.chain(declarative) {
         if adapter.name() == name {
-            return adapter
-                .parse_hook(raw_json)
-                .map(|event| (adapter.as_ref(), event));
+            return adapter.parse_hook(raw_json).map(|event| (adapter, event));
         }
     }
     None
@@ -1,5 +1,9 @@
 use crate::db;
 
+mod detectors;
+
+pub(crate) use detectors::{capture_detectors, scrub_secrets, SecretDetectors};
+
 pub(crate) const HOOK_PAYLOAD_PREVIEW_REDACTION_LOOKAHEAD_BYTES: usize = 4 * 1024;
 
 pub(crate) fn redact_and_truncate(text: &str, max_bytes: usize) -> String {
@@ -95,7 +99,8 @@ pub(crate) fn redact_sensitive_value(value: &serde_json::Value) -> serde_json::V
 }
 
 pub(crate) fn redact_sensitive_text(text: &str) -> String {
-    text.lines()
+    scrub_secrets(text)
+        .lines()
         .map(redact_sensitive_line)
         .collect::<Vec<_>>()
         .join("\n")
@@ -109,7 +114,7 @@ fn redact_sensitive_line(line: &str) -> String {
 }
 
 fn redact_hook_payload_text(text: &str) -> String {
-    let redacted = redact_inline_sensitive_assignments(text);
+    let redacted = redact_inline_sensitive_assignments(&scrub_secrets(text));
     redacted
         .lines()
         .map(redact_hook_payload_line)
@@ -120,6 +125,7 @@ fn redact_hook_payload_text(text: &str) -> String {
 fn hook_payload_text_contains_sensitive_match(text: &str) -> bool {
     contains_inline_sensitive_assignment(text)
         || text.lines().any(hook_payload_line_contains_sensitive_match)
+        || capture_detectors().contains_secret(text)
 }
 
 fn redact_hook_payload_line(line: &str) -> String {
@@ -0,0 +1,240 @@
+use std::borrow::Cow;
+use std::sync::{LazyLock, OnceLock};
+
+use anyhow::{Context, Result};
+use regex::Regex;
+
+use crate::runtime_config::RedactionConfig;
+
+pub(crate) const REDACTED: &str = "[REDACTED]";
+
+const ENTROPY_MIN_LEN: usize = 24;
+const ENTROPY_MIN_BITS_PER_CHAR: f64 = 4.0;
+const ENTROPY_MIN_DIGITS: usize = 2;
+const ENTROPY_MAX_SLASHES: usize = 2;
+const ENTROPY_DETECTOR: &str = "high_entropy";
+
+/// Provider token formats and private-key blocks, checked in order; the first
+/// detector to claim a span names it.
+const BUILTIN_PATTERNS: &[(&str, &str)] = &[
+    (
+        "private_key",
+        r"-----BEGIN [A-Z0-9 ]*PRIVATE KEY(?: BLOCK)?-----[\s\S]*?(?:-----END [A-Z0-9 ]*PRIVATE KEY(?: BLOCK)?-----|\z)",
+    ),
+    (
+        "aws_access_key_id",
+        r"\b(?:AKIA|ASIA|ABIA|ACCA|AGPA|AIDA|AIPA|ANPA|ANVA|AROA|APKA)[A-Z0-9]{16}\b",
+    ),
+    (
+        "github_token",
+        r"\b(?:gh[pousr]_[A-Za-z0-9]{36,255}|github_pat_[A-Za-z0-9_]{22,255})",
+    ),
+    ("gitlab_token", r"\bglpat-[A-Za-z0-9_-]{20,}"),
+    ("slack_token", r"\bxox[abposr]-[A-Za-z0-9-]{10,}"),
+    (
+        "slack_webhook",
+        r"https://hooks\.slack\.com/services/[A-Za-z0-9_/-]+",
+    ),
+    ("stripe_key", r"\b(?:sk|rk)_(?:live|test)_[A-Za-z0-9]{16,}"),
+    ("anthropic_api_key", r"\bsk-ant-[A-Za-z0-9_-]{20,}"),
+    (
+        "openai_api_key",
+        r"\bsk-(?:proj-|svcacct-|admin-)?[A-Za-z0-9_-]{20,}",
+    ),
+    ("google_api_key", r"\bAIza[0-9A-Za-z_-]{35}"),
+    ("npm_token", r"\bnpm_[A-Za-z0-9]{36}\b"),
+    (
+        "jwt",
+        r"\beyJ[A-Za-z0-9_-]{5,}\.eyJ[A-Za-z0-9_-]{5,}\.[A-Za-z0-9_-]{8,}",
+    ),
+];
+
+static BUILTIN_DETECTORS: LazyLock<Vec<Detector>> = LazyLock::new(|| {
+    BUILTIN_PATTERNS
+        .iter()
+        .map(|(name, pattern)| Detector {
+            name: (*name).to_string(),
+            regex: Regex::new(pattern).expect("built-in secret pattern should compile"),
+        })
+        .collect()
+});
+
+static ENTROPY_CANDIDATE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!("[A-Za-z0-9+/=_-]{{{ENTROPY_MIN_LEN},}}"))
+        .expect("entropy candidate pattern should compile")
+});
+
+static CAPTURE_DETECTORS: OnceLock<SecretDetectors> = OnceLock::new();
+
+#[derive(Debug, Clone)]
+struct Detector {
+    name: String,
+    regex: Regex,
+}
+
+/// One secret span, by byte offsets into the scanned text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SecretMatch {
+    pub detector: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Value-based secret detection: built-in token formats, optional entropy
+/// scoring and user-configured regexes from `[redaction.patterns]`.
+#[derive(Debug, Clone)]
+pub(crate) struct SecretDetectors {
+    custom: Vec<Detector>,
+    entropy: bool,
+}
+
+impl SecretDetectors {
+    pub(crate) fn new(config: &RedactionConfig) -> Result<Self> {
+        let custom = config
+            .patterns
+            .iter()
+            .map(|(name, pattern)| {
+                Ok(Detector {
+                    name: format!("custom:{name}"),
+                    regex: Regex::new(pattern)
+                        .with_context(|| format!("compile redaction.patterns.{name}"))?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            custom,
+            entropy: config.entropy,
+        })
+    }
+
+    pub(crate) fn builtin() -> Self {
+        Self {
+            custom: Vec::new(),
+            entropy: true,
+        }
+    }
+
+    /// Non-overlapping matches in text order. Overlapping spans merge and keep
+    /// the name of the detector that claimed the earliest start.
+    pub(crate) fn find(&self, text: &str) -> Vec<SecretMatch> {
+        let mut spans = Vec::new();
+        for detector in BUILTIN_DETECTORS.iter().chain(&self.custom) {
+            spans.extend(
+                detector
+                    .regex
+                    .find_iter(text)
+                    .filter(|found| !found.is_empty())
+                    .map(|found| SecretMatch {
+                        detector: detector.name.clone(),
+                        start: found.start(),
+                        end: found.end(),
+                    }),
+            );
+        }
+        if self.entropy {
+            spans.extend(
+                ENTROPY_CANDIDATE
+                    .find_iter(text)
+                    .filter(|found| is_high_entropy_secret(found.as_str()))
+                    .map(|found| SecretMatch {
+                        detector: ENTROPY_DETECTOR.to_string(),
+                        start: found.start(),
+                        end: found.end(),
+                    }),
+            );
+        }
+        spans.sort_by(|left, right| left.start.cmp(&right.start).then(right.end.cmp(&left.end)));
+        let mut merged: Vec<SecretMatch> = Vec::with_capacity(spans.len());
+        for span in spans {
+            match merged.last_mut() {
+                Some(last) if span.start < last.end => last.end = last.end.max(span.end),
+                _ => merged.push(span),
+            }
+        }
+        merged
+    }
+
+    pub(crate) fn contains_secret(&self, text: &str) -> bool {
+        !self.find(text).is_empty()
+    }
+
+    /// `text` with every match replaced by `[REDACTED]`, borrowed when clean.
+    pub(crate) fn scrub<'a>(&self, text: &'a str) -> (Cow<'a, str>, Vec<SecretMatch>) {
+        let matches = self.find(text);
+        if matches.is_empty() {
+            return (Cow::Borrowed(text), matches);
+        }
+        let mut output = String::with_capacity(text.len());
+        let mut cursor = 0;
+        for found in &matches {
+            output.push_str(&text[cursor..found.start]);
+            output.push_str(REDACTED);
+            cursor = found.end;
+        }
+        output.push_str(&text[cursor..]);
+        (Cow::Owned(output), matches)
+    }
+}
+
+/// Detectors for the capture path, built once per process from
+/// `config.toml`. Invalid `[redaction]` entries are logged and skipped while
+/// the valid ones still apply; `remem doctor` fails the runtime config check
+/// until they are fixed. Only an unreadable config falls back to the built-in
+/// set, so capture never fails on it.
+pub(crate) fn capture_detectors() -> &'static SecretDetectors {
+    CAPTURE_DETECTORS.get_or_init(|| {
+        crate::runtime_config::redaction_config_with_problems()
+            .and_then(|(config, problems)| {
+                for problem in &problems {
+                    crate::log::error("redaction", &format!("skipped: {problem}"));
+                }
+                SecretDetectors::new(&config)
+            })
+            .unwrap_or_else(|error| {
+                crate::log::error(
+                    "redaction",
+                    &format!("using built-in secret detectors: {error:#}"),
+                );
+                SecretDetectors::builtin()
+            })
+    })
+}
+
+pub(crate) fn scrub_secrets(text: &str) -> Cow<'_, str> {
+    capture_detectors().scrub(text).0
+}
+
+/// Random-looking tokens: mixed case, several digits, few path separators and
+/// at least four bits of Shannon entropy per character. Lowercase hex (commit
+/// SHAs, UUIDs) never qualifies.
+fn is_high_entropy_secret(token: &str) -> bool {
+    let token = token.trim_matches(|ch| matches!(ch, '-' | '_' | '=' | '/' | '+'));
+    if token.len() < ENTROPY_MIN_LEN
+        || !token.bytes().any(|byte| byte.is_ascii_uppercase())
+        || !token.bytes().any(|byte| byte.is_ascii_lowercase())
+        || token.bytes().filter(u8::is_ascii_digit).count() < ENTROPY_MIN_DIGITS
+        || token.bytes().filter(|byte| *byte == b'/').count() > ENTROPY_MAX_SLASHES
+    {
+        return false;
+    }
+    shannon_entropy(token) >= ENTROPY_MIN_BITS_PER_CHAR
+}
+
+fn shannon_entropy(token: &str) -> f64 {
+    let mut counts = [0usize; 256];
+    for byte in token.bytes() {
+        counts[byte as usize] += 1;
+    }
+    let len = token.len() as f64;
+    counts
+        .iter()
+        .filter(|count| **count > 0)
+        .map(|count| {
+            let probability = *count as f64 / len;
+            -probability * probability.log2()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests;
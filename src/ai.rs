@@ -1,8 +1,10 @@
+pub(crate) mod budget;
 mod cli;
 mod codex_cli;
 mod codex_usage;
 mod config;
 mod http;
+mod openai_compat;
 mod pricing;
 #[cfg(test)]
 mod tests;
@@ -12,6 +14,7 @@ mod usage;
 use cli::call_cli;
 use codex_cli::call_codex_cli;
 use http::call_http;
+use openai_compat::call_openai_compatible;
 use pricing::estimate_tokens;
 use usage::record_usage;
 
@@ -47,11 +50,20 @@ pub async fn call_ai(
         crate::runtime_config::MemoryAiExecutor::CodexCli => {
             call_codex_cli(system, user_message, &profile).await
         }
+        crate::runtime_config::MemoryAiExecutor::OpenAiCompatible => {
+            call_openai_compatible(system, user_message, &profile).await
+        }
     }?;
 
     let input_tokens = estimate_tokens(system) + estimate_tokens(user_message);
     let output_tokens = estimate_tokens(&result.text);
-    record_usage(ctx, &result, input_tokens, output_tokens);
+    record_usage(
+        ctx,
+        &profile.profile_name,
+        &result,
+        input_tokens,
+        output_tokens,
+    );
     Ok(result.text)
 }
 
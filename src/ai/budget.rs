@@ -0,0 +1,397 @@
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
+use rusqlite::Connection;
+
+use crate::runtime_config::{AiBudgetConfig, SpendLimits};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BudgetScope {
+    Project,
+    Profile,
+}
+
+impl BudgetScope {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Project => "project",
+            Self::Profile => "profile",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BudgetWindow {
+    Daily,
+    Monthly,
+}
+
+impl BudgetWindow {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Daily => "daily",
+            Self::Monthly => "monthly",
+        }
+    }
+
+    /// UTC `[start, reset)` epoch bounds of the window containing `now`.
+    fn bounds(self, now: DateTime<Utc>) -> (i64, i64) {
+        let today = now.date_naive();
+        let (start, reset) = match self {
+            Self::Daily => (today, today + Duration::days(1)),
+            Self::Monthly => {
+                let start = today.with_day(1).unwrap_or(today);
+                let reset = if start.month() == 12 {
+                    NaiveDate::from_ymd_opt(start.year() + 1, 1, 1)
+                } else {
+                    NaiveDate::from_ymd_opt(start.year(), start.month() + 1, 1)
+                }
+                .unwrap_or(start + Duration::days(31));
+                (start, reset)
+            }
+        };
+        (midnight_epoch(start), midnight_epoch(reset))
+    }
+}
+
+fn midnight_epoch(date: NaiveDate) -> i64 {
+    Utc.from_utc_datetime(&date.and_time(chrono::NaiveTime::MIN))
+        .timestamp()
+}
+
+/// One configured budget and how much of it the current window has used.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct BudgetStatus {
+    pub scope: BudgetScope,
+    pub name: String,
+    pub window: BudgetWindow,
+    pub limit_usd: f64,
+    pub spent_usd: f64,
+    pub resets_at_epoch: i64,
+}
+
+impl BudgetStatus {
+    pub(crate) fn remaining_usd(&self) -> f64 {
+        (self.limit_usd - self.spent_usd).max(0.0)
+    }
+
+    /// Share of the budget used; a zero budget counts as fully used.
+    pub(crate) fn used_ratio(&self) -> f64 {
+        if self.limit_usd <= 0.0 {
+            return 1.0;
+        }
+        self.spent_usd / self.limit_usd
+    }
+
+    pub(crate) fn is_exhausted(&self) -> bool {
+        self.spent_usd >= self.limit_usd
+    }
+
+    pub(crate) fn label(&self) -> String {
+        format!(
+            "{} {} budget for {}",
+            self.window.as_str(),
+            self.scope.as_str(),
+            self.name
+        )
+    }
+
+    /// Reason recorded on work deferred by this budget.
+    pub(crate) fn deferral_reason(&self) -> String {
+        let resets_at = DateTime::from_timestamp(self.resets_at_epoch, 0)
+            .map(|at| at.to_rfc3339())
+            .unwrap_or_else(|| self.resets_at_epoch.to_string());
+        format!(
+            "{} exhausted (${:.4} of ${:.2}); deferred until {resets_at}",
+            self.label(),
+            self.spent_usd,
+            self.limit_usd
+        )
+    }
+}
+
+/// Every configured budget with its spend in the current UTC window, projects
+/// first, then profiles.
+pub(crate) fn budget_statuses(
+    conn: &Connection,
+    config: &AiBudgetConfig,
+    now: DateTime<Utc>,
+) -> Result<Vec<BudgetStatus>> {
+    let mut out = Vec::new();
+    for (name, limits) in &config.projects {
+        push_statuses(conn, &mut out, BudgetScope::Project, name, limits, now)?;
+    }
+    for (name, limits) in &config.profiles {
+        push_statuses(conn, &mut out, BudgetScope::Profile, name, limits, now)?;
+    }
+    Ok(out)
+}
+
+/// The exhausted budget that blocks work for `project` on `profile`, if any.
+/// When several are exhausted, the one that resets last wins so a deferred
+/// job is not re-admitted into another spent window.
+pub(crate) fn blocking_budget(
+    conn: &Connection,
+    config: &AiBudgetConfig,
+    project: &str,
+    profile: Option<&str>,
+    now: DateTime<Utc>,
+) -> Result<Option<BudgetStatus>> {
+    let mut statuses = Vec::new();
+    if let Some(limits) = config.projects.get(project) {
+        push_statuses(
+            conn,
+            &mut statuses,
+            BudgetScope::Project,
+            project,
+            limits,
+            now,
+        )?;
+    }
+    if let Some((name, limits)) = profile.and_then(|name| config.profiles.get_key_value(name)) {
+        push_statuses(conn, &mut statuses, BudgetScope::Profile, name, limits, now)?;
+    }
+    Ok(statuses
+        .into_iter()
+        .filter(BudgetStatus::is_exhausted)
+        .max_by_key(|status| status.resets_at_epoch))
+}
+
+/// Worker admission check for a background AI job. Resolves the profile the
+/// job would run on and reports the budget that should defer it. Config or
+/// profile errors never block work here; the job surfaces them itself.
+pub(crate) fn admission_block(
+    conn: &Connection,
+    project: &str,
+    host: &str,
+    profile: Option<&str>,
+) -> Result<Option<BudgetStatus>> {
+    let config = match crate::runtime_config::ai_budget_config() {
+        Ok(config) => config,
+        Err(error) => {
+            crate::log::warn("budget", &format!("budget config ignored: {error}"));
+            return Ok(None);
+        }
+    };
+    if config.is_empty() {
+        return Ok(None);
+    }
+    let resolved = crate::runtime_config::resolve_memory_ai_profile(
+        crate::runtime_config::MemoryAiSelection {
+            host: profile.is_none().then_some(host),
+            profile,
+        },
+    )
+    .ok()
+    .map(|resolved| resolved.profile_name);
+    blocking_budget(conn, &config, project, resolved.as_deref(), Utc::now())
+}
+
+fn push_statuses(
+    conn: &Connection,
+    out: &mut Vec<BudgetStatus>,
+    scope: BudgetScope,
+    name: &str,
+    limits: &SpendLimits,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    for (window, limit) in [
+        (BudgetWindow::Daily, limits.daily_usd),
+        (BudgetWindow::Monthly, limits.monthly_usd),
+    ] {
+        let Some(limit_usd) = limit else {
+            continue;
+        };
+        let (since, resets_at_epoch) = window.bounds(now);
+        let (project, profile) = match scope {
+            BudgetScope::Project => (Some(name), None),
+            BudgetScope::Profile => (None, Some(name)),
+        };
+        let spent_usd = crate::db::query_ai_spend_since(conn, project, profile, since)?;
+        out.push(BudgetStatus {
+            scope,
+            name: name.to_string(),
+            window,
+            limit_usd,
+            spent_usd,
+            resets_at_epoch,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::ai::TokenUsage;
+
+    fn record(conn: &Connection, project: &str, profile: &str, cost: f64, at: DateTime<Utc>) {
+        crate::db::record_ai_usage(
+            conn,
+            Some(project),
+            None,
+            Some(profile),
+            "compress",
+            "codex-cli",
+            Some("gpt-5.2"),
+            &TokenUsage::estimated(10, 10),
+            "text_estimate",
+            "remem_static",
+            cost,
+        )
+        .expect("usage recorded");
+        conn.execute(
+            "UPDATE ai_usage_events SET created_at_epoch = ?1 WHERE id = last_insert_rowid()",
+            [at.timestamp()],
+        )
+        .expect("backdate usage");
+    }
+
+    fn config(
+        projects: &[(&str, SpendLimits)],
+        profiles: &[(&str, SpendLimits)],
+    ) -> AiBudgetConfig {
+        AiBudgetConfig {
+            warn_ratio: 0.8,
+            projects: projects
+                .iter()
+                .map(|(name, limits)| (name.to_string(), *limits))
+                .collect::<BTreeMap<_, _>>(),
+            profiles: profiles
+                .iter()
+                .map(|(name, limits)| (name.to_string(), *limits))
+                .collect::<BTreeMap<_, _>>(),
+        }
+    }
+
+    fn at(text: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(text)
+            .expect("test timestamp")
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn windows_follow_utc_calendar_boundaries() {
+        let now = at("2026-12-31T18:30:00Z");
+        assert_eq!(
+            BudgetWindow::Daily.bounds(now),
+            (
+                at("2026-12-31T00:00:00Z").timestamp(),
+                at("2027-01-01T00:00:00Z").timestamp()
+            )
+        );
+        assert_eq!(
+            BudgetWindow::Monthly.bounds(now),
+            (
+                at("2026-12-01T00:00:00Z").timestamp(),
+                at("2027-01-01T00:00:00Z").timestamp()
+            )
+        );
+    }
+
+    #[test]
+    fn budget_statuses_count_only_the_current_window() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        crate::migrate::run_migrations(&conn)?;
+        let now = at("2026-10-17T12:00:00Z");
+        record(
+            &conn,
+            "/repo",
+            "codex-default",
+            0.40,
+            at("2026-10-17T01:00:00Z"),
+        );
+        record(
+            &conn,
+            "/repo",
+            "codex-default",
+            0.25,
+            at("2026-10-03T09:00:00Z"),
+        );
+        record(
+            &conn,
+            "/repo",
+            "codex-default",
+            9.00,
+            at("2026-09-30T23:59:00Z"),
+        );
+        record(
+            &conn,
+            "/other",
+            "codex-default",
+            0.10,
+            at("2026-10-17T02:00:00Z"),
+        );
+        let limits = SpendLimits {
+            daily_usd: Some(1.0),
+            monthly_usd: Some(2.0),
+        };
+        let statuses = budget_statuses(
+            &conn,
+            &config(&[("/repo", limits)], &[("codex-default", limits)]),
+            now,
+        )?;
+        let spent: Vec<_> = statuses
+            .iter()
+            .map(|status| {
+                (
+                    status.scope,
+                    status.window,
+                    (status.spent_usd * 100.0).round() / 100.0,
+                )
+            })
+            .collect();
+        assert_eq!(
+            spent,
+            vec![
+                (BudgetScope::Project, BudgetWindow::Daily, 0.40),
+                (BudgetScope::Project, BudgetWindow::Monthly, 0.65),
+                (BudgetScope::Profile, BudgetWindow::Daily, 0.50),
+                (BudgetScope::Profile, BudgetWindow::Monthly, 0.75),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn blocking_budget_prefers_the_latest_reset() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        crate::migrate::run_migrations(&conn)?;
+        let now = at("2026-10-17T12:00:00Z");
+        record(
+            &conn,
+            "/repo",
+            "codex-default",
+            3.0,
+            at("2026-10-17T08:00:00Z"),
+        );
+        let project_daily = SpendLimits {
+            daily_usd: Some(1.0),
+            monthly_usd: None,
+        };
+        let profile_monthly = SpendLimits {
+            daily_usd: None,
+            monthly_usd: Some(2.5),
+        };
+        let config = config(
+            &[("/repo", project_daily)],
+            &[("codex-default", profile_monthly)],
+        );
+
+        let blocked = blocking_budget(&conn, &config, "/repo", Some("codex-default"), now)?
+            .expect("both budgets are exhausted");
+        assert_eq!(blocked.scope, BudgetScope::Profile);
+        assert_eq!(blocked.window, BudgetWindow::Monthly);
+        assert_eq!(
+            blocked.resets_at_epoch,
+            at("2026-11-01T00:00:00Z").timestamp()
+        );
+
+        assert!(blocking_budget(&conn, &config, "/elsewhere", Some("other"), now)?.is_none());
+        let only_project = blocking_budget(&conn, &config, "/repo", None, now)?
+            .expect("project budget still applies without a profile");
+        assert_eq!(only_project.scope, BudgetScope::Project);
+        Ok(())
+    }
+}
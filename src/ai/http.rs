@@ -13,7 +13,7 @@ use crate::runtime_config::ResolvedMemoryAiProfile;
 /// configuration every time. The timeout is a compile-time constant, so one
 /// client serves every call. The client is cheap to reuse across base URLs
 /// because the pool is keyed by host.
-fn shared_client() -> Result<&'static reqwest::Client> {
+pub(super) fn shared_client() -> Result<&'static reqwest::Client> {
     static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
     if let Some(client) = CLIENT.get() {
         return Ok(client);
@@ -0,0 +1,209 @@
+use anyhow::{Context, Result};
+
+use crate::ai::http::shared_client;
+use crate::ai::types::{AiCallResult, TokenUsage};
+use crate::runtime_config::ResolvedMemoryAiProfile;
+
+const DEFAULT_BASE_URL: &str = "https://api.openai.com/v1";
+const DEFAULT_API_KEY_ENV: &str = "OPENAI_API_KEY";
+
+/// Chat-completions executor for OpenAI-compatible servers (OpenAI, vLLM,
+/// llama.cpp, Ollama, ...). `base_url` points at the API root that serves
+/// `/chat/completions`, e.g. `http://127.0.0.1:11434/v1`.
+pub(super) async fn call_openai_compatible(
+    system: &str,
+    user_message: &str,
+    profile: &ResolvedMemoryAiProfile,
+) -> Result<AiCallResult> {
+    let model = profile.model.as_deref().with_context(|| {
+        format!(
+            "memory_ai profile '{}' uses openai-compatible executor but sets no model",
+            profile.profile_name
+        )
+    })?;
+    let base_url = profile.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+    let api_key = resolve_api_key(profile.api_key_env.as_deref())?;
+
+    let body = serde_json::json!({
+        "model": model,
+        "max_tokens": 4096,
+        "messages": [
+            {"role": "system", "content": system},
+            {"role": "user", "content": user_message}
+        ]
+    });
+
+    let client = shared_client()?;
+    let mut request = client
+        .post(chat_completions_url(base_url))
+        .header("content-type", "application/json")
+        .json(&body);
+    if let Some(api_key) = api_key {
+        request = request.bearer_auth(api_key);
+    }
+    let resp = request.send().await?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let text = resp
+            .text()
+            .await
+            .unwrap_or_else(|error| format!("<body read error: {}>", error));
+        anyhow::bail!("OpenAI-compatible API error {}: {}", status, text);
+    }
+
+    let data: serde_json::Value = resp.json().await?;
+    let text = extract_text(&data)?;
+    let usage = extract_usage(&data);
+    let response_model = data
+        .get("model")
+        .and_then(serde_json::Value::as_str)
+        .filter(|value| !value.trim().is_empty())
+        .unwrap_or(model);
+
+    Ok(AiCallResult {
+        text,
+        executor: "openai-compatible",
+        model: response_model.to_string(),
+        usage,
+        usage_source: Some("openai_usage"),
+    })
+}
+
+/// An explicitly configured `api_key_env` must be set; the default
+/// `OPENAI_API_KEY` is optional because local servers usually run without
+/// authentication.
+fn resolve_api_key(api_key_env: Option<&str>) -> Result<Option<String>> {
+    match api_key_env {
+        Some(name) => std::env::var(name)
+            .map(Some)
+            .with_context(|| format!("{name} not set (memory_ai profile api_key_env)")),
+        None => Ok(std::env::var(DEFAULT_API_KEY_ENV)
+            .ok()
+            .filter(|value| !value.trim().is_empty())),
+    }
+}
+
+fn chat_completions_url(base_url: &str) -> String {
+    let base = base_url.trim_end_matches('/');
+    if base.ends_with("/chat/completions") {
+        base.to_string()
+    } else {
+        format!("{base}/chat/completions")
+    }
+}
+
+fn extract_text(data: &serde_json::Value) -> Result<String> {
+    let text = data["choices"]
+        .as_array()
+        .and_then(|arr| arr.first())
+        .and_then(|choice| choice["message"]["content"].as_str())
+        .ok_or_else(|| {
+            let snippet: String = serde_json::to_string(data)
+                .unwrap_or_default()
+                .chars()
+                .take(512)
+                .collect();
+            anyhow::anyhow!(
+                "OpenAI-compatible response missing choices[0].message.content: {}",
+                snippet
+            )
+        })?
+        .to_string();
+
+    if text.trim().is_empty() {
+        anyhow::bail!("OpenAI-compatible server returned empty text body");
+    }
+    Ok(text)
+}
+
+fn json_i64(data: &serde_json::Value, pointer: &str) -> i64 {
+    data.pointer(pointer)
+        .and_then(serde_json::Value::as_i64)
+        .unwrap_or(0)
+}
+
+/// `prompt_tokens` includes cached prompt tokens and `completion_tokens`
+/// includes reasoning tokens, so both are split out to keep the breakdown
+/// additive like the Codex usage parser.
+fn extract_usage(data: &serde_json::Value) -> Option<TokenUsage> {
+    let usage = data.get("usage")?;
+    let prompt_tokens = json_i64(usage, "/prompt_tokens");
+    let completion_tokens = json_i64(usage, "/completion_tokens");
+    let cached_tokens = json_i64(usage, "/prompt_tokens_details/cached_tokens");
+    let reasoning_tokens = json_i64(usage, "/completion_tokens_details/reasoning_tokens");
+    let token_usage = TokenUsage {
+        input_tokens: (prompt_tokens - cached_tokens).max(0),
+        output_tokens: (completion_tokens - reasoning_tokens).max(0),
+        reasoning_tokens,
+        cache_read_tokens: cached_tokens,
+        raw_input_tokens: prompt_tokens,
+        raw_output_tokens: completion_tokens,
+        ..TokenUsage::default()
+    };
+    (!token_usage.is_empty()).then_some(token_usage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{chat_completions_url, extract_text, extract_usage};
+    use serde_json::json;
+
+    #[test]
+    fn chat_completions_url_appends_endpoint_once() {
+        assert_eq!(
+            chat_completions_url("http://127.0.0.1:8000/v1/"),
+            "http://127.0.0.1:8000/v1/chat/completions"
+        );
+        assert_eq!(
+            chat_completions_url("http://localhost:11434/v1/chat/completions"),
+            "http://localhost:11434/v1/chat/completions"
+        );
+    }
+
+    #[test]
+    fn extracts_text_from_first_choice() {
+        let data = json!({
+            "choices": [{"index": 0, "message": {"role": "assistant", "content": "hello"}}]
+        });
+        assert_eq!(extract_text(&data).unwrap(), "hello");
+    }
+
+    #[test]
+    fn errors_on_missing_or_empty_choice_content() {
+        let missing = json!({"choices": []});
+        let err = extract_text(&missing).unwrap_err().to_string();
+        assert!(err.contains("missing choices[0].message.content"), "{err}");
+
+        let empty = json!({"choices": [{"message": {"content": "  "}}]});
+        let err = extract_text(&empty).unwrap_err().to_string();
+        assert!(err.contains("empty text body"), "{err}");
+    }
+
+    #[test]
+    fn extracts_usage_with_cached_and_reasoning_breakdown() {
+        let data = json!({
+            "usage": {
+                "prompt_tokens": 500,
+                "completion_tokens": 120,
+                "total_tokens": 620,
+                "prompt_tokens_details": {"cached_tokens": 200},
+                "completion_tokens_details": {"reasoning_tokens": 20}
+            }
+        });
+        let usage = extract_usage(&data).expect("usage should parse");
+        assert_eq!(usage.input_tokens, 300);
+        assert_eq!(usage.cache_read_tokens, 200);
+        assert_eq!(usage.output_tokens, 100);
+        assert_eq!(usage.reasoning_tokens, 20);
+        assert_eq!(usage.raw_input_tokens, 500);
+        assert_eq!(usage.raw_output_tokens, 120);
+        assert_eq!(usage.total_tokens(), 620);
+    }
+
+    #[test]
+    fn missing_or_zero_usage_falls_back_to_estimate() {
+        assert!(extract_usage(&json!({})).is_none());
+        assert!(extract_usage(&json!({"usage": {"prompt_tokens": 0}})).is_none());
+    }
+}
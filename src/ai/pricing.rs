@@ -177,11 +177,36 @@ fn apply_family_env(default: ModelPricing, prefix: &str) -> ModelPricing {
     }
 }
 
+/// Self-hosted OpenAI-compatible servers run arbitrary model names. When the
+/// model is not a known family the call is priced at zero unless the operator
+/// configures `[pricing.openai_compatible]` or `REMEM_PRICE_OPENAI_COMPATIBLE_*`.
+fn openai_compatible_pricing() -> Result<ModelPricing> {
+    let prefix = "OPENAI_COMPATIBLE";
+    Ok(apply_family_env(
+        apply_family_config(ModelPricing::openai(0.0, 0.0, 0.0), prefix)?,
+        prefix,
+    ))
+}
+
+#[cfg(test)]
 pub(super) fn estimate_cost_usd(
     model: &str,
     usage: &crate::ai::TokenUsage,
 ) -> Result<(f64, &'static str)> {
-    let Some(pricing) = pricing_breakdown_for_model(model)? else {
+    estimate_cost_usd_for_executor("", model, usage)
+}
+
+pub(super) fn estimate_cost_usd_for_executor(
+    executor: &str,
+    model: &str,
+    usage: &crate::ai::TokenUsage,
+) -> Result<(f64, &'static str)> {
+    let pricing = match pricing_breakdown_for_model(model)? {
+        Some(pricing) => Some(pricing),
+        None if executor == "openai-compatible" => Some(openai_compatible_pricing()?),
+        None => None,
+    };
+    let Some(pricing) = pricing else {
         return Ok((0.0, "unknown_pricing"));
     };
 
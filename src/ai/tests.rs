@@ -4,7 +4,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use anyhow::{Context, Result};
 
 use super::config::resolve_model_for_api;
-use super::pricing::{estimate_cost_usd, pricing_for_model};
+use super::pricing::{estimate_cost_usd, estimate_cost_usd_for_executor, pricing_for_model};
 use super::stable_working_dir;
 use super::TokenUsage;
 
@@ -134,6 +134,61 @@ fn explicit_usd_override_still_applies_to_gpt_56_credit_models() {
     );
 }
 
+#[test]
+fn openai_compatible_unknown_model_defaults_to_zero_cost() {
+    with_pricing_config(
+        "version = 1\n",
+        &[
+            ("REMEM_PRICE_INPUT_PER_MTOK", None),
+            ("REMEM_PRICE_OUTPUT_PER_MTOK", None),
+            ("REMEM_PRICE_OPENAI_COMPATIBLE_INPUT_PER_MTOK", None),
+            ("REMEM_PRICE_OPENAI_COMPATIBLE_OUTPUT_PER_MTOK", None),
+        ],
+        || {
+            let usage = TokenUsage::estimated(1_000_000, 1_000_000);
+            assert_eq!(
+                estimate_cost_usd_for_executor("openai-compatible", "qwen2.5-coder:7b", &usage)
+                    .expect("pricing"),
+                (0.0, "remem_static")
+            );
+            assert_eq!(
+                estimate_cost_usd_for_executor("http", "qwen2.5-coder:7b", &usage)
+                    .expect("pricing"),
+                (0.0, "unknown_pricing")
+            );
+        },
+    );
+}
+
+#[test]
+fn openai_compatible_pricing_uses_config_family_and_known_models() {
+    with_pricing_config(
+        "[pricing.openai_compatible]\ninput_per_mtok = 0.5\noutput_per_mtok = 1.5\n",
+        &[
+            ("REMEM_PRICE_INPUT_PER_MTOK", None),
+            ("REMEM_PRICE_OUTPUT_PER_MTOK", None),
+            ("REMEM_PRICE_OPENAI_COMPATIBLE_INPUT_PER_MTOK", None),
+            ("REMEM_PRICE_OPENAI_COMPATIBLE_OUTPUT_PER_MTOK", None),
+            ("REMEM_PRICE_GPT54_MINI_INPUT_PER_MTOK", None),
+            ("REMEM_PRICE_GPT54_MINI_OUTPUT_PER_MTOK", None),
+        ],
+        || {
+            let usage = TokenUsage::estimated(1_000_000, 1_000_000);
+            let (cost, source) =
+                estimate_cost_usd_for_executor("openai-compatible", "llama-3.1-8b", &usage)
+                    .expect("pricing");
+            assert!((cost - 2.0).abs() < 1e-9, "{cost}");
+            assert_eq!(source, "config_override");
+
+            let (cost, source) =
+                estimate_cost_usd_for_executor("openai-compatible", "gpt-5.4-mini", &usage)
+                    .expect("pricing");
+            assert!((cost - 5.25).abs() < 1e-9, "{cost}");
+            assert_eq!(source, "remem_static");
+        },
+    );
+}
+
 #[test]
 fn pricing_for_model_prefers_env_override() {
     with_pricing_config(
@@ -410,6 +465,7 @@ printf 'ok\n'
                 model: Some("haiku".to_string()),
                 cli_path: Some(script.clone()),
                 base_url: None,
+                api_key_env: None,
                 reasoning_effort: None,
             };
             let _data_dir = crate::db::test_support::ScopedTestDataDir::new("ai-claude-child");
@@ -466,6 +522,7 @@ async fn resolved_profile_scope_does_not_reread_removed_config_profile() -> Resu
         model: Some("haiku".to_string()),
         cli_path: Some(script_path.to_string_lossy().into_owned()),
         base_url: None,
+        api_key_env: None,
         reasoning_effort: None,
     };
     let response = super::with_resolved_profile(
@@ -1,8 +1,9 @@
-use crate::ai::pricing::estimate_cost_usd;
+use crate::ai::pricing::estimate_cost_usd_for_executor;
 use crate::ai::types::{AiCallResult, TokenUsage, UsageContext};
 
 pub(super) fn record_usage(
     ctx: UsageContext<'_>,
+    profile_name: &str,
     result: &AiCallResult,
     input_tokens: i64,
     output_tokens: i64,
@@ -22,13 +23,14 @@ pub(super) fn record_usage(
             "text_estimate",
         ),
     };
-    let (cost, pricing_source) = match estimate_cost_usd(&result.model, &usage) {
-        Ok(estimated) => estimated,
-        Err(error) => {
-            crate::log::error("ai", &format!("usage cost pricing failed: {error}"));
-            return;
-        }
-    };
+    let (cost, pricing_source) =
+        match estimate_cost_usd_for_executor(result.executor, &result.model, &usage) {
+            Ok(estimated) => estimated,
+            Err(error) => {
+                crate::log::error("ai", &format!("usage cost pricing failed: {error}"));
+                return;
+            }
+        };
     if pricing_source == "unknown_pricing" && !usage.is_empty() {
         crate::log::warn(
             "ai",
@@ -40,6 +42,7 @@ pub(super) fn record_usage(
             &conn,
             ctx.project,
             ctx.session_id,
+            Some(profile_name),
             operation,
             result.executor,
             Some(&result.model),
@@ -1,3 +1,4 @@
+mod access;
 mod auth;
 // SP880-T1 lands shared primitives before the T2-T4 handlers consume them.
 #[allow(dead_code)]
@@ -6,12 +7,17 @@ mod handlers;
 mod helpers;
 #[allow(dead_code)]
 pub(crate) mod mutation;
+mod openapi;
 mod read_resources;
 mod server;
 #[cfg(test)]
 mod tests;
+pub(crate) mod tokens;
 mod types;
+mod ui;
 
+pub(crate) use auth::require_api_token;
 pub use auth::{ensure_api_token, load_api_token};
+pub use openapi::openapi_document;
 pub use server::{build_router, run_api_server};
 pub use types::DbState;
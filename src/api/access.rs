@@ -0,0 +1,352 @@
+//! Route-level authorization for scoped API tokens.
+//!
+//! Every `/api/v1` route maps to the minimum [`ApiScope`] it needs and to
+//! where its project lives, so `require_api_token` can enforce project
+//! allow-lists without each handler knowing about tokens. Unknown routes,
+//! including MCP over HTTP, need [`ApiScope::Admin`].
+
+use std::collections::HashMap;
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::{Query, Request},
+    http::{Method, StatusCode},
+    response::{IntoResponse, Response},
+};
+use rusqlite::{params, Connection};
+
+use super::helpers::error_response;
+use super::tokens::{ApiPrincipal, ApiScope};
+
+/// Largest JSON body buffered to read its `project` for restricted tokens.
+const MAX_SCOPED_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ResourceKind {
+    Memory,
+    Candidate,
+    Observation,
+    Session,
+    Workstream,
+    Event,
+    Task,
+    SessionTurn,
+    Injection,
+}
+
+/// Where a route's project comes from when the token has an allow-list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ProjectTarget {
+    /// Not project data (health, capabilities, the OpenAPI document).
+    None,
+    /// Aggregates over every project; restricted tokens are denied.
+    Global,
+    /// The `project` query parameter.
+    Query,
+    /// The `project` field of the JSON body.
+    Body,
+    /// The project of the resource named by the path or `?id=`.
+    Resource(ResourceKind, ResourceId),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ResourceId {
+    Path,
+    QueryId,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct RouteAccess {
+    pub scope: ApiScope,
+    pub project: ProjectTarget,
+}
+
+impl RouteAccess {
+    const fn new(scope: ApiScope, project: ProjectTarget) -> Self {
+        Self { scope, project }
+    }
+}
+
+pub(super) fn route_access(method: &Method, path: &str) -> RouteAccess {
+    use ApiScope::{Admin, Read, Review, Write};
+    use ProjectTarget::{Body, Global, Query};
+
+    let admin = RouteAccess::new(Admin, Global);
+    let Some(route) = path.strip_prefix("/api/v1/") else {
+        return admin;
+    };
+    let segments: Vec<&str> = route.split('/').collect();
+    let resource = |kind| ProjectTarget::Resource(kind, ResourceId::Path);
+
+    if *method == Method::GET {
+        let project = match segments.as_slice() {
+            ["health" | "capabilities" | "openapi.json"] => ProjectTarget::None,
+            ["status" | "stats"] => Global,
+            ["memory"] => ProjectTarget::Resource(ResourceKind::Memory, ResourceId::QueryId),
+            ["search" | "memories" | "graph" | "stream" | "session-stats"]
+            | ["memories", "list"]
+            | ["timeline", "report"]
+            | ["candidates"]
+            | ["candidates", "blocked"]
+            | ["observations" | "sessions" | "workstreams" | "events" | "tasks" | "injections"]
+            | ["session-activity"]
+            | ["session-activity", "sessions"] => Query,
+            ["memories", _] => resource(ResourceKind::Memory),
+            ["candidates", _] => resource(ResourceKind::Candidate),
+            ["observations", _] => resource(ResourceKind::Observation),
+            ["sessions", _] => resource(ResourceKind::Session),
+            ["workstreams", _] => resource(ResourceKind::Workstream),
+            ["events", _] => resource(ResourceKind::Event),
+            ["tasks", _] => resource(ResourceKind::Task),
+            ["session-activity", _] => resource(ResourceKind::SessionTurn),
+            ["injections", _] => resource(ResourceKind::Injection),
+            _ => return admin,
+        };
+        return RouteAccess::new(Read, project);
+    }
+
+    if *method == Method::POST {
+        return match segments.as_slice() {
+            ["user", "recall"] => RouteAccess::new(Read, Body),
+            ["memories"] | ["workstreams"] | ["session-activity", "project"] => {
+                RouteAccess::new(Write, Body)
+            }
+            ["workstreams", _, "plan"] => {
+                RouteAccess::new(Write, resource(ResourceKind::Workstream))
+            }
+            ["memories", _, "archive" | "restore"] => {
+                RouteAccess::new(Write, resource(ResourceKind::Memory))
+            }
+            ["candidates", _, "approve" | "reject" | "edit"]
+            | ["candidates", _, "review", "approve" | "reject" | "edit"] => {
+                RouteAccess::new(Review, resource(ResourceKind::Candidate))
+            }
+            _ => admin,
+        };
+    }
+
+    admin
+}
+
+/// Checks `principal` against the route and returns the request to forward,
+/// rebuilt when its body had to be read.
+pub(super) async fn authorize_request(
+    principal: &ApiPrincipal,
+    req: Request,
+) -> Result<Request, Response> {
+    let access = route_access(req.method(), req.uri().path());
+    if principal.scope < access.scope {
+        return Err(forbidden(
+            "scope_insufficient",
+            &format!(
+                "API token scope {} cannot call this route; {} is required",
+                principal.scope.as_str(),
+                access.scope.as_str()
+            ),
+        ));
+    }
+    if principal.projects.is_none() {
+        return Ok(req);
+    }
+
+    match access.project {
+        ProjectTarget::None => Ok(req),
+        ProjectTarget::Global => Err(forbidden(
+            "project_forbidden",
+            "project-restricted API tokens cannot read cross-project aggregates",
+        )),
+        ProjectTarget::Query => {
+            let project = query_param(&req, "project");
+            match project_denial(principal, project.as_deref()) {
+                Some(denied) => Err(denied),
+                None => Ok(req),
+            }
+        }
+        ProjectTarget::Body => {
+            let (parts, body) = req.into_parts();
+            let bytes = to_bytes(body, MAX_SCOPED_BODY_BYTES).await.map_err(|_| {
+                error_response(
+                    StatusCode::PAYLOAD_TOO_LARGE,
+                    "body_too_large",
+                    "request body is too large",
+                )
+                .into_response()
+            })?;
+            let project = serde_json::from_slice::<serde_json::Value>(&bytes)
+                .ok()
+                .and_then(|body| body.get("project")?.as_str().map(str::to_string));
+            match project_denial(principal, project.as_deref()) {
+                Some(denied) => Err(denied),
+                None => Ok(Request::from_parts(parts, Body::from(bytes))),
+            }
+        }
+        ProjectTarget::Resource(kind, id_source) => {
+            let id = match id_source {
+                ResourceId::Path => req.uri().path().split('/').nth(4).map(str::to_string),
+                ResourceId::QueryId => query_param(&req, "id"),
+            };
+            // Malformed or unknown ids fall through so the handler answers
+            // with its usual 400 or 404.
+            let Some(id) = id.and_then(|id| id.trim().parse::<i64>().ok()) else {
+                return Ok(req);
+            };
+            let conn = crate::db::open_db().map_err(|err| {
+                crate::log::error("api", &format!("token project check db open: {err}"));
+                error_response(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "db_open_failed",
+                    "failed to open database",
+                )
+                .into_response()
+            })?;
+            let identifiers = resource_projects(&conn, kind, id).map_err(|err| {
+                crate::log::error("api", &format!("token project check query: {err}"));
+                error_response(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "project_check_failed",
+                    "failed to resolve the resource project",
+                )
+                .into_response()
+            })?;
+            match identifiers {
+                Some(identifiers) if !principal.allows_any_project(&identifiers) => Err(forbidden(
+                    "project_forbidden",
+                    "API token cannot access this project",
+                )),
+                _ => Ok(req),
+            }
+        }
+    }
+}
+
+/// Returns the 403 to send when `project` is missing or not allowed.
+fn project_denial(principal: &ApiPrincipal, project: Option<&str>) -> Option<Response> {
+    let Some(project) = project.map(str::trim).filter(|project| !project.is_empty()) else {
+        return Some(forbidden(
+            "project_required",
+            "project-restricted API tokens must name a project",
+        ));
+    };
+    (!principal.allows_any_project(&[project.to_string()]))
+        .then(|| forbidden("project_forbidden", "API token cannot access this project"))
+}
+
+fn query_param(req: &Request, name: &str) -> Option<String> {
+    Query::<HashMap<String, String>>::try_from_uri(req.uri())
+        .ok()?
+        .0
+        .remove(name)
+}
+
+/// Project keys and paths the resource belongs to, or `None` when it does
+/// not exist.
+fn resource_projects(
+    conn: &Connection,
+    kind: ResourceKind,
+    id: i64,
+) -> anyhow::Result<Option<Vec<String>>> {
+    let sql = match kind {
+        ResourceKind::Memory => "SELECT project, target_project FROM memories WHERE id = ?1",
+        ResourceKind::Candidate => {
+            "SELECT COALESCE(c.target_project, p.project_path, c.source_project,
+                             CASE WHEN c.owner_scope = 'repo' THEN c.owner_key END),
+                    p.project_key
+             FROM memory_candidates c LEFT JOIN projects p ON p.id = c.project_id
+             WHERE c.id = ?1"
+        }
+        ResourceKind::Observation => {
+            "SELECT COALESCE(o.project, p.project_key), p.project_path
+             FROM observations o LEFT JOIN projects p ON p.id = o.project_id
+             WHERE o.id = ?1"
+        }
+        ResourceKind::Session => {
+            "SELECT p.project_key, p.project_path
+             FROM sessions s JOIN projects p ON p.id = s.project_id WHERE s.id = ?1"
+        }
+        ResourceKind::Event => {
+            "SELECT p.project_key, p.project_path
+             FROM captured_events ce JOIN projects p ON p.id = ce.project_id WHERE ce.id = ?1"
+        }
+        ResourceKind::Task => {
+            "SELECT p.project_key, p.project_path
+             FROM extraction_tasks t JOIN projects p ON p.id = t.project_id WHERE t.id = ?1"
+        }
+        ResourceKind::Workstream => "SELECT project, NULL FROM workstreams WHERE id = ?1",
+        ResourceKind::SessionTurn => "SELECT project, NULL FROM session_turns WHERE id = ?1",
+        ResourceKind::Injection => {
+            "SELECT project, NULL FROM context_injection_items WHERE id = ?1"
+        }
+    };
+    let mut stmt = conn.prepare(sql)?;
+    let mut rows = stmt.query(params![id])?;
+    let Some(row) = rows.next()? else {
+        return Ok(None);
+    };
+    let identifiers = [row.get::<_, Option<String>>(0)?, row.get(1)?];
+    Ok(Some(identifiers.into_iter().flatten().collect()))
+}
+
+fn forbidden(code: &str, message: &str) -> Response {
+    error_response(StatusCode::FORBIDDEN, code, message).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_map_to_least_privilege_scopes() {
+        let cases = [
+            (Method::GET, "/api/v1/health", ApiScope::Read),
+            (Method::GET, "/api/v1/memories/12", ApiScope::Read),
+            (Method::POST, "/api/v1/user/recall", ApiScope::Read),
+            (
+                Method::POST,
+                "/api/v1/candidates/3/review/approve",
+                ApiScope::Review,
+            ),
+            (Method::POST, "/api/v1/candidates/3/edit", ApiScope::Review),
+            (Method::POST, "/api/v1/memories", ApiScope::Write),
+            (Method::POST, "/api/v1/memories/4/archive", ApiScope::Write),
+            (
+                Method::POST,
+                "/api/v1/session-activity/project",
+                ApiScope::Write,
+            ),
+            (Method::POST, "/mcp", ApiScope::Admin),
+            (Method::DELETE, "/api/v1/memories/4", ApiScope::Admin),
+            (Method::GET, "/api/v1/unknown/route/here", ApiScope::Admin),
+        ];
+        for (method, path, scope) in cases {
+            assert_eq!(route_access(&method, path).scope, scope, "{method} {path}");
+        }
+    }
+
+    #[test]
+    fn routes_name_where_their_project_lives() {
+        assert_eq!(
+            route_access(&Method::GET, "/api/v1/stats").project,
+            ProjectTarget::Global
+        );
+        assert_eq!(
+            route_access(&Method::GET, "/api/v1/memories/list").project,
+            ProjectTarget::Query
+        );
+        assert_eq!(
+            route_access(&Method::GET, "/api/v1/memory").project,
+            ProjectTarget::Resource(ResourceKind::Memory, ResourceId::QueryId)
+        );
+        assert_eq!(
+            route_access(&Method::GET, "/api/v1/session-activity/7").project,
+            ProjectTarget::Resource(ResourceKind::SessionTurn, ResourceId::Path)
+        );
+        assert_eq!(
+            route_access(&Method::GET, "/api/v1/injections/3").project,
+            ProjectTarget::Resource(ResourceKind::Injection, ResourceId::Path)
+        );
+        assert_eq!(
+            route_access(&Method::POST, "/api/v1/memories").project,
+            ProjectTarget::Body
+        );
+    }
+}
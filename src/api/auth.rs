@@ -7,7 +7,9 @@ use axum::{
 };
 use std::{io::Write, path::PathBuf};
 
+use super::access::authorize_request;
 use super::helpers::error_response;
+use super::tokens::{resolve_api_token, ApiPrincipal, NAMED_TOKEN_PREFIX};
 
 const API_TOKEN_FILE: &str = ".api-token";
 const API_TOKEN_BYTES: usize = 32;
@@ -57,7 +59,7 @@ pub fn load_api_token() -> Result<String> {
     Ok(token)
 }
 
-pub(in crate::api) async fn require_api_token(req: Request, next: Next) -> Response {
+pub(crate) async fn require_api_token(req: Request, next: Next) -> Response {
     let expected = match load_api_token() {
         Ok(token) => token,
         Err(err) => {
@@ -71,16 +73,50 @@ pub(in crate::api) async fn require_api_token(req: Request, next: Next) -> Respo
         }
     };
 
-    if request_has_token(req.headers(), &expected) {
-        next.run(req).await
+    let Some(presented) = bearer_token(req.headers()).map(str::to_string) else {
+        return unauthorized();
+    };
+    let principal = if constant_time_eq(presented.as_bytes(), expected.as_bytes()) {
+        ApiPrincipal::file_token()
     } else {
-        error_response(
-            StatusCode::UNAUTHORIZED,
-            "unauthorized",
-            "Missing or invalid API token",
-        )
-        .into_response()
+        match resolve_named_token(&presented) {
+            Ok(Some(principal)) => principal,
+            Ok(None) => return unauthorized(),
+            Err(err) => {
+                crate::log::error("api", &format!("API token lookup failed: {err}"));
+                return error_response(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "api_token_unavailable",
+                    "API token could not be verified",
+                )
+                .into_response();
+            }
+        }
+    };
+
+    let mut req = match authorize_request(&principal, req).await {
+        Ok(req) => req,
+        Err(response) => return response,
+    };
+    req.extensions_mut().insert(principal);
+    next.run(req).await
+}
+
+fn resolve_named_token(presented: &str) -> Result<Option<ApiPrincipal>> {
+    if !presented.starts_with(NAMED_TOKEN_PREFIX) {
+        return Ok(None);
     }
+    let conn = crate::db::open_db()?;
+    resolve_api_token(&conn, presented, chrono::Utc::now().timestamp())
+}
+
+fn unauthorized() -> Response {
+    error_response(
+        StatusCode::UNAUTHORIZED,
+        "unauthorized",
+        "Missing or invalid API token",
+    )
+    .into_response()
 }
 
 fn validate_existing_token(path: &std::path::Path) -> Result<()> {
@@ -162,13 +198,6 @@ fn enforce_token_permissions(path: &std::path::Path) -> Result<()> {
     Ok(())
 }
 
-fn request_has_token(headers: &HeaderMap, expected: &str) -> bool {
-    let Some(actual) = bearer_token(headers) else {
-        return false;
-    };
-    constant_time_eq(actual.as_bytes(), expected.as_bytes())
-}
-
 fn bearer_token(headers: &HeaderMap) -> Option<&str> {
     let value = headers.get(header::AUTHORIZATION)?.to_str().ok()?;
     let token = value.strip_prefix("Bearer ")?;
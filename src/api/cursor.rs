@@ -16,6 +16,8 @@ pub(crate) enum CursorKind {
     Workstreams,
     Events,
     Tasks,
+    Injections,
+    Stream,
 }
 
 impl CursorKind {
@@ -26,6 +28,8 @@ impl CursorKind {
             Self::Workstreams => "workstreams",
             Self::Events => "events",
             Self::Tasks => "tasks",
+            Self::Injections => "injections",
+            Self::Stream => "stream",
         }
     }
 }
@@ -217,11 +221,12 @@ mod tests {
             CursorKind::Workstreams,
             CursorKind::Events,
             CursorKind::Tasks,
+            CursorKind::Injections,
         ]
         .map(CursorKind::as_str);
         let unique = values
             .into_iter()
             .collect::<std::collections::BTreeSet<_>>();
-        assert_eq!(unique.len(), 5);
+        assert_eq!(unique.len(), 6);
     }
 }
@@ -7,9 +7,11 @@ mod detail;
 mod events;
 mod graph;
 mod health;
+mod injections;
 mod list;
 mod memory_governance;
 mod observations;
+mod openapi;
 mod save;
 mod search;
 mod session_activity;
@@ -17,7 +19,9 @@ mod sessions;
 mod show;
 mod stats;
 mod status;
+mod stream;
 mod tasks;
+mod timeline;
 mod user_recall;
 mod workstreams;
 
@@ -36,23 +40,31 @@ pub(super) use detail::handle_memory_detail;
 pub(super) use events::{handle_event_detail, handle_list_events};
 pub(super) use graph::handle_graph;
 pub(super) use health::handle_health;
+pub(super) use injections::{handle_injection_detail, handle_list_injections};
 pub(super) use list::handle_list_memories;
 #[cfg(test)]
 pub(super) use memory_governance::execute_memory_governance_for_test;
 pub(super) use memory_governance::{handle_archive_memory, handle_restore_memory};
 pub(super) use observations::{handle_list_observations, handle_observation_detail};
+pub(super) use openapi::handle_openapi;
 pub(super) use save::handle_save_memory;
 pub(super) use search::handle_search;
 #[cfg(test)]
 pub(super) use search::search_request_from_params;
 pub(super) use session_activity::{
     handle_activity_sessions, handle_list_session_activity, handle_project_session_activity,
-    handle_session_activity_detail, handle_session_activity_stats,
+    handle_session_activity_detail, handle_session_activity_stats, ActivityParams,
+    ProjectActivityRequest,
 };
 pub(super) use sessions::{handle_list_sessions, handle_session_detail};
 pub(super) use show::handle_get_memory;
 pub(super) use stats::handle_stats;
 pub(super) use status::handle_status;
+pub(super) use stream::{handle_event_stream, StreamParams, STREAM_EVENT_TYPES};
 pub(super) use tasks::{handle_list_tasks, handle_task_detail};
+pub(super) use timeline::{handle_timeline_report, TimelineReportParams};
 pub(super) use user_recall::handle_user_recall;
-pub(super) use workstreams::{handle_list_workstreams, handle_workstream_detail};
+pub(super) use workstreams::{
+    handle_create_workstream, handle_list_workstreams, handle_update_workstream_plan,
+    handle_workstream_detail,
+};
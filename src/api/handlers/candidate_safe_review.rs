@@ -3,7 +3,7 @@ use axum::{
     extract::{Path, State},
     http::StatusCode,
     response::{IntoResponse, Response},
-    Json,
+    Extension, Json,
 };
 use rusqlite::{params, TransactionBehavior};
 use serde::Serialize;
@@ -19,6 +19,7 @@ use crate::memory_candidate::review::{
     normalize_candidate_edit, CandidateEdit, ReviewApprovalOutcome, ReviewMeta,
 };
 
+use super::super::tokens::ApiPrincipal;
 use super::super::types::{
     CandidateSafeApproveRequest, CandidateSafeEditRequest, CandidateSafeRejectRequest,
     CandidateSafeReviewResponse, DbState, SafeMutationErrorDetail, SafeMutationErrorResponse,
@@ -26,7 +27,6 @@ use super::super::types::{
 use super::candidate_detail::load_candidate_detail;
 
 const RESOURCE_KIND: &str = "candidate";
-const REVIEW_ACTOR: &str = "api";
 
 struct SafeReviewFailure(Box<Response>);
 
@@ -47,30 +47,36 @@ type SafeReviewResult<T> = Result<T, SafeReviewFailure>;
 pub(in crate::api) async fn handle_safe_approve_candidate(
     State(_state): State<DbState>,
     Path(id): Path<i64>,
+    Extension(principal): Extension<ApiPrincipal>,
     body: Bytes,
 ) -> Response {
+    let actor = principal.audit_actor();
     parse_safe_request(&body, SafeActionKind::Approve)
-        .and_then(|(identity, request)| execute_safe_review(id, identity, request))
+        .and_then(|(identity, request)| execute_safe_review(id, identity, request, &actor))
         .unwrap_or_else(SafeReviewFailure::into_response)
 }
 
 pub(in crate::api) async fn handle_safe_reject_candidate(
     State(_state): State<DbState>,
     Path(id): Path<i64>,
+    Extension(principal): Extension<ApiPrincipal>,
     body: Bytes,
 ) -> Response {
+    let actor = principal.audit_actor();
     parse_safe_request(&body, SafeActionKind::Reject)
-        .and_then(|(identity, request)| execute_safe_review(id, identity, request))
+        .and_then(|(identity, request)| execute_safe_review(id, identity, request, &actor))
         .unwrap_or_else(SafeReviewFailure::into_response)
 }
 
 pub(in crate::api) async fn handle_safe_edit_candidate(
     State(_state): State<DbState>,
     Path(id): Path<i64>,
+    Extension(principal): Extension<ApiPrincipal>,
     body: Bytes,
 ) -> Response {
+    let actor = principal.audit_actor();
     parse_safe_request(&body, SafeActionKind::Edit)
-        .and_then(|(identity, request)| execute_safe_review(id, identity, request))
+        .and_then(|(identity, request)| execute_safe_review(id, identity, request, &actor))
         .unwrap_or_else(SafeReviewFailure::into_response)
 }
 
@@ -301,13 +307,14 @@ fn execute_safe_review(
     id: i64,
     identity: MutationIdentity,
     request: SafeRequest,
+    actor: &str,
 ) -> SafeReviewResult<Response> {
     if id <= 0 {
         return Err(invalid_typed_request(Some(&identity.operation_id)).into());
     }
     let mut conn = crate::db::open_db()
         .map_err(|_| safe_internal_error("db_open_failed", Some(&identity.operation_id)))?;
-    execute_safe_review_on_connection(&mut conn, id, identity, request)
+    execute_safe_review_on_connection(&mut conn, id, identity, request, actor)
 }
 
 fn execute_safe_review_on_connection(
@@ -315,6 +322,7 @@ fn execute_safe_review_on_connection(
     id: i64,
     identity: MutationIdentity,
     request: SafeRequest,
+    actor: &str,
 ) -> SafeReviewResult<Response> {
     let request_hash = request.request_hash(id).map_err(|_| {
         safe_internal_error("candidate_review_hash_failed", Some(&identity.operation_id))
@@ -437,7 +445,7 @@ fn execute_safe_review_on_connection(
             Some(&identity.operation_id),
         )
     })?;
-    let mut meta = ReviewMeta::single(REVIEW_ACTOR);
+    let mut meta = ReviewMeta::single(actor);
     meta.reason = Some(request.reason().to_string());
     let apply_outcome = apply_safe_action(&tx, id, &request, &meta).map_err(|_| {
         safe_error(
@@ -572,7 +580,13 @@ pub(in crate::api) fn execute_safe_review_for_test(
     };
     parse_safe_request(body, kind)
         .and_then(|(identity, request)| {
-            execute_safe_review_on_connection(conn, id, identity, request)
+            execute_safe_review_on_connection(
+                conn,
+                id,
+                identity,
+                request,
+                &ApiPrincipal::file_token().audit_actor(),
+            )
         })
         .unwrap_or_else(SafeReviewFailure::into_response)
 }
@@ -29,6 +29,14 @@ pub(in crate::api) async fn handle_capabilities() -> impl IntoResponse {
             "/api/v1/session-activity/project",
         ),
         ("session_activity_stats", "/api/v1/session-stats"),
+        ("event_stream", "/api/v1/stream"),
+        ("openapi", "/api/v1/openapi.json"),
+        ("injections", "/api/v1/injections"),
+        ("injection_detail", "/api/v1/injections/{id}"),
+        ("web_ui", "/ui/"),
+        ("workstream_create", "/api/v1/workstreams"),
+        ("workstream_plan", "/api/v1/workstreams/{id}/plan"),
+        ("timeline_report", "/api/v1/timeline/report"),
     ]);
     endpoints.extend(candidate_console_endpoint_bundle(true));
     endpoints.extend(memory_governance_endpoint_bundle(
@@ -70,9 +78,15 @@ pub(in crate::api) async fn handle_capabilities() -> impl IntoResponse {
             sessions: true,
             session_activity: true,
             workstreams: true,
+            workstream_plans: true,
             events: true,
+            event_stream: true,
+            openapi: true,
+            injections: true,
+            web_ui: true,
             tasks: true,
             graph: true,
+            timeline_report: true,
             user_recall: true,
             user_recall_usage_policy: true,
         },
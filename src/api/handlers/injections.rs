@@ -0,0 +1,191 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Response,
+};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+
+use super::super::cursor::CursorKind;
+use super::super::read_resources::{
+    detail_resource, list_resource, redact_bounded, redact_optional, PolicyRelation,
+    ReadResourceParams, ReadResourceSpec, ResourceProjectionPolicy, SafeResourceRef,
+};
+use super::super::types::DbState;
+
+pub(in crate::api) async fn handle_list_injections(
+    State(_state): State<DbState>,
+    Query(params): Query<ReadResourceParams>,
+) -> Response {
+    list_resource::<Injections>(params)
+}
+
+pub(in crate::api) async fn handle_injection_detail(
+    State(_state): State<DbState>,
+    Path(id): Path<String>,
+) -> Response {
+    detail_resource::<Injections>(id)
+}
+
+/// Per-item context injection audit rows (`context_injection_items`): what a
+/// hook injected, dropped or abstained from, and why.
+struct Injections;
+
+struct InjectionRow {
+    id: i64,
+    injection_run_id: String,
+    host: String,
+    project: String,
+    hook_source: Option<String>,
+    output_mode: String,
+    decision: String,
+    item_kind: String,
+    memory_id: Option<i64>,
+    channel: String,
+    score: Option<f64>,
+    render_order: Option<i64>,
+    status: String,
+    drop_reason: Option<String>,
+    title: Option<String>,
+    staleness: Option<String>,
+    injected_at_epoch: i64,
+}
+
+#[derive(Serialize)]
+struct InjectionItem {
+    id: i64,
+    injection_run_id: String,
+    host: String,
+    project: String,
+    hook_source: Option<String>,
+    output_mode: String,
+    decision: String,
+    item_kind: String,
+    memory_id: Option<i64>,
+    channel: String,
+    score: Option<f64>,
+    render_order: Option<i64>,
+    status: String,
+    drop_reason: Option<String>,
+    title: Option<String>,
+    staleness: Option<String>,
+    injected_at_epoch: i64,
+    references: Vec<SafeResourceRef>,
+}
+
+const SELECT_INJECTION: &str =
+    "SELECT i.id, i.injection_run_id, i.host, i.project, i.hook_source, i.output_mode,
+            i.decision, i.item_kind, i.memory_id, i.channel, i.score, i.render_order,
+            i.status, i.drop_reason, i.title, i.staleness, i.injected_at_epoch
+     FROM context_injection_items i";
+
+impl ReadResourceSpec for Injections {
+    type Row = InjectionRow;
+    type Item = InjectionItem;
+
+    const KIND: CursorKind = CursorKind::Injections;
+    fn row_id(row: &Self::Row) -> i64 {
+        row.id
+    }
+
+    fn load_batch(
+        conn: &Connection,
+        resume_before_id: Option<i64>,
+        project: Option<&str>,
+        status: Option<&str>,
+        limit: usize,
+    ) -> anyhow::Result<Vec<Self::Row>> {
+        let sql = format!(
+            "{SELECT_INJECTION}
+             WHERE (?1 IS NULL OR i.id < ?1)
+               AND (?2 IS NULL OR i.project = ?2)
+               AND (?3 IS NULL OR i.status = ?3)
+             ORDER BY i.id DESC LIMIT ?4"
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(
+            params![resume_before_id, project, status, limit as i64],
+            map_row,
+        )?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    }
+
+    fn load_one(conn: &Connection, id: i64) -> anyhow::Result<Option<Self::Row>> {
+        conn.query_row(
+            &format!("{SELECT_INJECTION} WHERE i.id = ?1"),
+            params![id],
+            map_row,
+        )
+        .optional()
+        .map_err(Into::into)
+    }
+
+    fn project(
+        row: Self::Row,
+        policy: &ResourceProjectionPolicy,
+    ) -> anyhow::Result<Option<Self::Item>> {
+        let mut visible = vec![row.project.as_str(), row.item_kind.as_str()];
+        visible.extend(row.title.as_deref());
+        visible.extend(row.drop_reason.as_deref());
+        let relations = row
+            .memory_id
+            .map(PolicyRelation::Memory)
+            .into_iter()
+            .collect::<Vec<_>>();
+        if policy.suppresses(&visible, &relations) {
+            return Ok(None);
+        }
+        let references = row
+            .memory_id
+            .filter(|id| *id > 0)
+            .map(|id| SafeResourceRef {
+                kind: "memory",
+                id,
+                title: None,
+                status: None,
+            })
+            .into_iter()
+            .collect();
+        Ok(Some(InjectionItem {
+            id: row.id,
+            injection_run_id: redact_bounded(&row.injection_run_id),
+            host: redact_bounded(&row.host),
+            project: redact_bounded(&row.project),
+            hook_source: redact_optional(row.hook_source),
+            output_mode: redact_bounded(&row.output_mode),
+            decision: redact_bounded(&row.decision),
+            item_kind: redact_bounded(&row.item_kind),
+            memory_id: row.memory_id,
+            channel: redact_bounded(&row.channel),
+            score: row.score,
+            render_order: row.render_order,
+            status: row.status,
+            drop_reason: redact_optional(row.drop_reason),
+            title: redact_optional(row.title),
+            staleness: redact_optional(row.staleness),
+            injected_at_epoch: row.injected_at_epoch,
+            references,
+        }))
+    }
+}
+
+fn map_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<InjectionRow> {
+    Ok(InjectionRow {
+        id: row.get(0)?,
+        injection_run_id: row.get(1)?,
+        host: row.get(2)?,
+        project: row.get(3)?,
+        hook_source: row.get(4)?,
+        output_mode: row.get(5)?,
+        decision: row.get(6)?,
+        item_kind: row.get(7)?,
+        memory_id: row.get(8)?,
+        channel: row.get(9)?,
+        score: row.get(10)?,
+        render_order: row.get(11)?,
+        status: row.get(12)?,
+        drop_reason: row.get(13)?,
+        title: row.get(14)?,
+        staleness: row.get(15)?,
+        injected_at_epoch: row.get(16)?,
+    })
+}
@@ -3,7 +3,7 @@ use axum::{
     extract::{Path, State},
     http::StatusCode,
     response::{IntoResponse, Response},
-    Json,
+    Extension, Json,
 };
 use rusqlite::TransactionBehavior;
 use serde::Serialize;
@@ -19,13 +19,13 @@ use crate::memory::governance::{
     WebMemoryGovernanceRequest,
 };
 
+use super::super::tokens::ApiPrincipal;
 use super::super::types::{
     DbState, MemorySafeGovernanceRequest, MemorySafeGovernanceResponse, SafeMutationErrorDetail,
     SafeMutationErrorResponse,
 };
 
 const RESOURCE_KIND: &str = "memory";
-const GOVERNANCE_ACTOR: &str = "api";
 
 struct GovernanceFailure(Box<Response>);
 
@@ -46,11 +46,19 @@ type GovernanceResult<T> = Result<T, GovernanceFailure>;
 pub(in crate::api) async fn handle_archive_memory(
     State(_state): State<DbState>,
     Path(id): Path<String>,
+    Extension(principal): Extension<ApiPrincipal>,
     body: Bytes,
 ) -> Response {
+    let actor = principal.audit_actor();
     parse_request(&body)
         .and_then(|(identity, request)| {
-            execute_governance(id, WebMemoryGovernanceAction::Archive, identity, request)
+            execute_governance(
+                id,
+                WebMemoryGovernanceAction::Archive,
+                identity,
+                request,
+                &actor,
+            )
         })
         .unwrap_or_else(GovernanceFailure::into_response)
 }
@@ -58,11 +66,19 @@ pub(in crate::api) async fn handle_archive_memory(
 pub(in crate::api) async fn handle_restore_memory(
     State(_state): State<DbState>,
     Path(id): Path<String>,
+    Extension(principal): Extension<ApiPrincipal>,
     body: Bytes,
 ) -> Response {
+    let actor = principal.audit_actor();
     parse_request(&body)
         .and_then(|(identity, request)| {
-            execute_governance(id, WebMemoryGovernanceAction::Restore, identity, request)
+            execute_governance(
+                id,
+                WebMemoryGovernanceAction::Restore,
+                identity,
+                request,
+                &actor,
+            )
         })
         .unwrap_or_else(GovernanceFailure::into_response)
 }
@@ -142,11 +158,12 @@ fn execute_governance(
     action: WebMemoryGovernanceAction,
     identity: MutationIdentity,
     request: MemorySafeGovernanceRequest,
+    actor: &str,
 ) -> GovernanceResult<Response> {
     let memory_id = parse_memory_id(&raw_id, &identity)?;
     let mut conn = crate::db::open_db()
         .map_err(|_| safe_internal_error("db_open_failed", Some(&identity.operation_id)))?;
-    execute_governance_on_connection(&mut conn, memory_id, action, identity, request)
+    execute_governance_on_connection(&mut conn, memory_id, action, identity, request, actor)
 }
 
 fn parse_memory_id(raw_id: &str, identity: &MutationIdentity) -> GovernanceResult<i64> {
@@ -168,6 +185,7 @@ fn execute_governance_on_connection(
     action: WebMemoryGovernanceAction,
     identity: MutationIdentity,
     request: MemorySafeGovernanceRequest,
+    actor: &str,
 ) -> GovernanceResult<Response> {
     let request_hash = mutation_request_hash(
         RESOURCE_KIND,
@@ -245,7 +263,7 @@ fn execute_governance_on_connection(
             expected_version: request.expected_version,
             operation_id: &identity.operation_id,
             reason: &request.reason,
-            actor: GOVERNANCE_ACTOR,
+            actor,
         },
     )
     .map_err(|_| {
@@ -360,7 +378,14 @@ pub(in crate::api) fn execute_memory_governance_for_test(
     };
     parse_request(body)
         .and_then(|(identity, request)| {
-            execute_governance_on_connection(conn, memory_id, action, identity, request)
+            execute_governance_on_connection(
+                conn,
+                memory_id,
+                action,
+                identity,
+                request,
+                &ApiPrincipal::file_token().audit_actor(),
+            )
         })
         .unwrap_or_else(GovernanceFailure::into_response)
 }
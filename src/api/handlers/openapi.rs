@@ -0,0 +1,22 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use super::super::helpers::error_response;
+
+pub(in crate::api) async fn handle_openapi() -> Response {
+    match super::super::openapi::openapi_document() {
+        Ok(document) => Json(document).into_response(),
+        Err(err) => {
+            crate::log::error("api", &format!("openapi document failed: {err:#}"));
+            error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "openapi_failed",
+                "failed to build the OpenAPI document",
+            )
+            .into_response()
+        }
+    }
+}
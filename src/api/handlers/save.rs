@@ -34,6 +34,17 @@ pub(in crate::api) async fn handle_save_memory(
         claim_enabled: req.claim_enabled,
         claim_source: req.claim_source.or_else(|| Some("api_save".to_string())),
         acknowledge_pattern: req.acknowledge_pattern,
+        anchors: req.anchors.map(|anchors| {
+            anchors
+                .into_iter()
+                .map(|anchor| crate::memory::anchors::MemoryAnchorInput {
+                    file: anchor.file,
+                    symbol: anchor.symbol,
+                    start_line: anchor.start_line,
+                    end_line: anchor.end_line,
+                })
+                .collect()
+        }),
     };
 
     match service::save_memory_with_reference_time(&conn, &save_req, reference_time_epoch) {
@@ -118,6 +129,7 @@ mod tests {
             claim_enabled: None,
             claim_source: None,
             acknowledge_pattern: None,
+            anchors: None,
         }
     }
 
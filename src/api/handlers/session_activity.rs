@@ -4,6 +4,7 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
+use rmcp::schemars::{self, JsonSchema};
 use serde::Deserialize;
 use serde_json::json;
 
@@ -17,7 +18,7 @@ const MAX_LIMIT: i64 = 200;
 const DEFAULT_STATS_WINDOW_SECS: i64 = 30 * 24 * 60 * 60;
 const MAX_STATS_WINDOW_SECS: i64 = 366 * 24 * 60 * 60;
 
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Deserialize, JsonSchema, Default)]
 pub(in crate::api) struct ActivityParams {
     project: Option<String>,
     source_root: Option<String>,
@@ -29,7 +30,7 @@ pub(in crate::api) struct ActivityParams {
     limit: Option<i64>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub(in crate::api) struct ProjectActivityRequest {
     source_root: String,
     project: String,
@@ -0,0 +1,348 @@
+//! `GET /api/v1/stream`: server-sent events over the `api_stream_events`
+//! feed that the v088 triggers fill from every writer process.
+//!
+//! Each event's SSE `id` is an `api::cursor` token for the last feed row the
+//! client has seen, so a reconnecting `EventSource` resumes through
+//! `Last-Event-ID` (or `?cursor=`) without gaps. Without a cursor the stream
+//! starts at the current head. A cursor older than the retained feed gets a
+//! `stream_reset` event first so the client knows to refetch list state.
+
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::{
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
+};
+use futures_util::stream;
+use rmcp::schemars::{self, JsonSchema};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use super::super::cursor::{decode_cursor, encode_cursor, filter_fingerprint, CursorKind};
+use super::super::read_resources::{
+    redact_bounded, stable_error, PolicyRelation, ResourceProjectionPolicy,
+};
+use super::super::types::DbState;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+const BATCH_SIZE: i64 = 200;
+
+/// Event types written by the v088 triggers, in the order clients see them
+/// documented.
+pub(in crate::api) const STREAM_EVENT_TYPES: &[&str] = &[
+    "memory_saved",
+    "candidate_created",
+    "candidate_reviewed",
+    "workstream_transition",
+    "job_completed",
+    "injection_audit",
+];
+
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
+pub(in crate::api) struct StreamParams {
+    pub project: Option<String>,
+    /// Comma-separated event types to deliver; empty means all.
+    pub types: Option<String>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct StreamFilters<'a> {
+    project: Option<&'a str>,
+    types: &'a [&'static str],
+}
+
+#[derive(Debug, Serialize)]
+struct StreamResource {
+    kind: String,
+    id: i64,
+}
+
+#[derive(Debug, Serialize)]
+struct StreamEventItem {
+    id: i64,
+    event_type: String,
+    project: Option<String>,
+    resource: StreamResource,
+    created_at_epoch: i64,
+    data: serde_json::Value,
+}
+
+struct StreamState {
+    conn: Connection,
+    policy: ResourceProjectionPolicy,
+    project: Option<String>,
+    types: Vec<&'static str>,
+    fingerprint: String,
+    last_id: i64,
+    pending: VecDeque<Event>,
+}
+
+pub(in crate::api) async fn handle_event_stream(
+    State(_state): State<DbState>,
+    headers: HeaderMap,
+    Query(params): Query<StreamParams>,
+) -> Response {
+    let project = params
+        .project
+        .map(|project| project.trim().to_string())
+        .filter(|project| !project.is_empty());
+    let types = match parse_event_types(params.types.as_deref()) {
+        Ok(types) => types,
+        Err(()) => return stable_error(StatusCode::BAD_REQUEST, "event_type_invalid"),
+    };
+    let fingerprint = match filter_fingerprint(&StreamFilters {
+        project: project.as_deref(),
+        types: &types,
+    }) {
+        Ok(fingerprint) => fingerprint,
+        Err(_) => return stable_error(StatusCode::INTERNAL_SERVER_ERROR, "cursor_encode_failed"),
+    };
+    let cursor = headers
+        .get("last-event-id")
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .or(params.cursor);
+    // For stream cursors the decoded id is the last delivered feed row.
+    let resume_after_id = match cursor.as_deref() {
+        Some(cursor) => match decode_cursor(cursor, CursorKind::Stream, &fingerprint) {
+            Ok(decoded) => Some(decoded.resume_before_id),
+            Err(_) => return stable_error(StatusCode::BAD_REQUEST, "cursor_invalid"),
+        },
+        None => None,
+    };
+    let conn = match crate::db::open_db() {
+        Ok(conn) => conn,
+        Err(_) => return stable_error(StatusCode::INTERNAL_SERVER_ERROR, "db_open_failed"),
+    };
+    let mut state = StreamState::new(conn, project, types, fingerprint);
+    if let Err(err) = state.start(resume_after_id).and_then(|()| state.poll()) {
+        crate::log::warn("api", &format!("event stream start failed: {err}"));
+        return stable_error(StatusCode::INTERNAL_SERVER_ERROR, "stream_query_failed");
+    }
+
+    let events = stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(event) = state.pending.pop_front() {
+                return Some((Ok::<_, Infallible>(event), state));
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+            if let Err(err) = state.poll() {
+                crate::log::warn("api", &format!("event stream poll failed: {err}"));
+                return None;
+            }
+        }
+    });
+    Sse::new(events)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+fn parse_event_types(raw: Option<&str>) -> Result<Vec<&'static str>, ()> {
+    let mut types = Vec::new();
+    for requested in raw.unwrap_or_default().split(',').map(str::trim) {
+        if requested.is_empty() {
+            continue;
+        }
+        let known = STREAM_EVENT_TYPES
+            .iter()
+            .find(|known| **known == requested)
+            .ok_or(())?;
+        types.push(*known);
+    }
+    types.sort_unstable();
+    types.dedup();
+    Ok(types)
+}
+
+impl StreamState {
+    fn new(
+        conn: Connection,
+        project: Option<String>,
+        types: Vec<&'static str>,
+        fingerprint: String,
+    ) -> Self {
+        Self {
+            conn,
+            policy: ResourceProjectionPolicy::default(),
+            project,
+            types,
+            fingerprint,
+            last_id: 0,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Positions the stream and queues `stream_reset` (when the cursor can no
+    /// longer be replayed) and `stream_ready`.
+    fn start(&mut self, resume_after_id: Option<i64>) -> anyhow::Result<()> {
+        let head: i64 = self
+            .conn
+            .query_row(
+                "SELECT seq FROM sqlite_sequence WHERE name = 'api_stream_events'",
+                [],
+                |row| row.get(0),
+            )
+            .optional()?
+            .unwrap_or(0);
+        let oldest: Option<i64> =
+            self.conn
+                .query_row("SELECT MIN(id) FROM api_stream_events", [], |row| {
+                    row.get(0)
+                })?;
+        self.last_id = match resume_after_id {
+            None => head,
+            Some(after) if after > head => {
+                self.queue_reset("cursor_ahead_of_feed")?;
+                head
+            }
+            Some(after) if after < head && oldest.is_none_or(|oldest| after < oldest - 1) => {
+                self.queue_reset("cursor_expired")?;
+                after
+            }
+            Some(after) => after,
+        };
+        let ready = serde_json::json!({
+            "head": head,
+            "project": self.project,
+            "types": if self.types.is_empty() { STREAM_EVENT_TYPES } else { &self.types[..] },
+        });
+        let mut event = Event::default().event("stream_ready").json_data(ready)?;
+        if let Some(cursor) = self.cursor_for(self.last_id) {
+            event = event.id(cursor);
+        }
+        self.pending.push_back(event);
+        Ok(())
+    }
+
+    fn queue_reset(&mut self, reason: &str) -> anyhow::Result<()> {
+        let event = Event::default()
+            .event("stream_reset")
+            .json_data(serde_json::json!({ "reason": reason }))?;
+        self.pending.push_back(event);
+        Ok(())
+    }
+
+    fn cursor_for(&self, id: i64) -> Option<String> {
+        (id > 0)
+            .then(|| encode_cursor(CursorKind::Stream, &self.fingerprint, id).ok())
+            .flatten()
+    }
+
+    /// Loads feed rows past `last_id` and queues the visible ones. Rows
+    /// filtered by type or suppressed by policy still advance `last_id`.
+    /// The suppression policy is re-read for every batch so a suppression
+    /// added while the stream is open applies to the next event.
+    fn poll(&mut self) -> anyhow::Result<()> {
+        loop {
+            let rows = load_feed_batch(&self.conn, self.last_id, self.project.as_deref())?;
+            if rows.is_empty() {
+                return Ok(());
+            }
+            self.policy = ResourceProjectionPolicy::load(&self.conn)?;
+            let exhausted = (rows.len() as i64) < BATCH_SIZE;
+            for item in rows {
+                self.last_id = item.id;
+                if !self.types.is_empty() && !self.types.contains(&item.event_type.as_str()) {
+                    continue;
+                }
+                let Some(item) = project_item(item, &self.policy) else {
+                    continue;
+                };
+                let mut event = Event::default()
+                    .event(item.event_type.clone())
+                    .json_data(&item)?;
+                if let Some(cursor) = self.cursor_for(item.id) {
+                    event = event.id(cursor);
+                }
+                self.pending.push_back(event);
+            }
+            if exhausted {
+                return Ok(());
+            }
+        }
+    }
+}
+
+fn load_feed_batch(
+    conn: &Connection,
+    after_id: i64,
+    project: Option<&str>,
+) -> anyhow::Result<Vec<StreamEventItem>> {
+    let mut stmt = conn.prepare_cached(
+        "SELECT id, event_type, project, resource_kind, resource_id, payload_json,
+                created_at_epoch
+         FROM api_stream_events
+         WHERE id > ?1 AND (?2 IS NULL OR project = ?2)
+         ORDER BY id ASC LIMIT ?3",
+    )?;
+    let rows = stmt.query_map(params![after_id, project, BATCH_SIZE], |row| {
+        let payload: String = row.get(5)?;
+        Ok(StreamEventItem {
+            id: row.get(0)?,
+            event_type: row.get(1)?,
+            project: row.get(2)?,
+            resource: StreamResource {
+                kind: row.get(3)?,
+                id: row.get(4)?,
+            },
+            created_at_epoch: row.get(6)?,
+            data: serde_json::from_str(&payload).unwrap_or(serde_json::Value::Null),
+        })
+    })?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+}
+
+/// Applies the same suppression policy and title redaction as the read
+/// resources. Returns `None` when the event must not be published.
+fn project_item(
+    mut item: StreamEventItem,
+    policy: &ResourceProjectionPolicy,
+) -> Option<StreamEventItem> {
+    let title = item
+        .data
+        .get("title")
+        .and_then(serde_json::Value::as_str)
+        .map(str::to_string);
+    let mut visible = vec![item.event_type.as_str()];
+    visible.extend(item.project.as_deref());
+    visible.extend(title.as_deref());
+    let relations = if item.resource.kind == "memory" {
+        vec![PolicyRelation::Memory(item.resource.id)]
+    } else {
+        Vec::new()
+    };
+    if policy.suppresses(&visible, &relations) {
+        return None;
+    }
+    if let (Some(title), Some(data)) = (title, item.data.as_object_mut()) {
+        data.insert(
+            "title".to_string(),
+            serde_json::Value::String(redact_bounded(&title)),
+        );
+    }
+    Some(item)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_types_parse_sorted_and_reject_unknown_names() {
+        assert_eq!(parse_event_types(None), Ok(Vec::new()));
+        assert_eq!(
+            parse_event_types(Some("job_completed, memory_saved,job_completed")),
+            Ok(vec!["job_completed", "memory_saved"])
+        );
+        assert_eq!(parse_event_types(Some("memory_saved,bogus")), Err(()));
+    }
+}
@@ -0,0 +1,87 @@
+use axum::{
+    extract::{Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use rmcp::schemars::{self, JsonSchema};
+use serde::Deserialize;
+
+use crate::timeline::TimelineReportFormat;
+
+use super::super::helpers::{error_response, open_request_db};
+use super::super::read_resources::redact_bounded;
+use super::super::types::DbState;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub(in crate::api) struct TimelineReportParams {
+    /// Project path to report on.
+    project: String,
+    /// Include the recent timeline and monthly breakdown.
+    full: Option<bool>,
+    /// `json` (default), `csv`, `html`, or `markdown`.
+    format: Option<String>,
+}
+
+/// Serves the timeline and Token ROI report. JSON is the default; the other
+/// formats are returned as text bodies with their own content type.
+pub(in crate::api) async fn handle_timeline_report(
+    State(_state): State<DbState>,
+    Query(params): Query<TimelineReportParams>,
+) -> Response {
+    let format = match params.format.as_deref() {
+        None => TimelineReportFormat::Json,
+        Some(raw) => match TimelineReportFormat::parse(raw) {
+            Some(format) => format,
+            None => {
+                return error_response(
+                    StatusCode::BAD_REQUEST,
+                    "format_invalid",
+                    &format!("unknown report format '{raw}'; expected json, csv, html or markdown"),
+                )
+                .into_response()
+            }
+        },
+    };
+    let project = params.project.trim();
+    if project.is_empty() {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "project_required",
+            "project is required",
+        )
+        .into_response();
+    }
+    let conn = match open_request_db() {
+        Ok(conn) => conn,
+        Err(response) => return response,
+    };
+    let mut report = match crate::timeline::generate_timeline_report_data(
+        &conn,
+        project,
+        params.full.unwrap_or(false),
+    ) {
+        Ok(report) => report,
+        Err(err) => {
+            return error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "timeline_report_failed",
+                &err.to_string(),
+            )
+            .into_response()
+        }
+    };
+    report.map_titles(redact_bounded);
+    if format == TimelineReportFormat::Json {
+        return Json(report).into_response();
+    }
+    match crate::timeline::render_timeline_report(&report, format) {
+        Ok(body) => ([(header::CONTENT_TYPE, format.content_type())], body).into_response(),
+        Err(err) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "timeline_report_failed",
+            &err.to_string(),
+        )
+        .into_response(),
+    }
+}
@@ -1,16 +1,26 @@
 use axum::{
     extract::{Path, Query, State},
-    response::Response,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
 };
 use rusqlite::{params, Connection, OptionalExtension};
 use serde::Serialize;
 
+use crate::workstream::{
+    NewWorkStream, WorkStreamDependency, WorkStreamDependencyKind, WorkStreamMilestone,
+    WorkStreamPlanError, WorkStreamPlanUpdate,
+};
+
 use super::super::cursor::CursorKind;
+use super::super::helpers::{error_response, open_request_db};
 use super::super::read_resources::{
     detail_resource, list_resource, redact_bounded, redact_optional, PolicyRelation,
     ReadResourceParams, ReadResourceSpec, ResourceProjectionPolicy, SafeResourceRef,
 };
-use super::super::types::DbState;
+use super::super::types::{
+    CreateWorkstreamRequest, DbState, WorkstreamDependencyRequest, WorkstreamPlanRequest,
+};
 
 pub(in crate::api) async fn handle_list_workstreams(
     State(_state): State<DbState>,
@@ -26,6 +36,134 @@ pub(in crate::api) async fn handle_workstream_detail(
     detail_resource::<Workstreams>(id)
 }
 
+/// Creates a workstream and answers with the same redacted projection as
+/// `GET /api/v1/workstreams/{id}`.
+pub(in crate::api) async fn handle_create_workstream(
+    State(_state): State<DbState>,
+    Json(req): Json<CreateWorkstreamRequest>,
+) -> Response {
+    let conn = match open_request_db() {
+        Ok(conn) => conn,
+        Err(response) => return response.into_response(),
+    };
+    let dependencies = match parse_dependencies(&req.dependencies) {
+        Ok(dependencies) => dependencies,
+        Err(response) => return response,
+    };
+    let created = crate::workstream::create_workstream(
+        &conn,
+        &NewWorkStream {
+            project: &req.project,
+            title: &req.title,
+            description: req.description.as_deref(),
+            next_action: req.next_action.as_deref(),
+            parent_id: req.parent_id,
+            dependencies: &dependencies,
+            milestones: &req.milestones,
+        },
+    );
+    match created {
+        Ok(id) => {
+            let mut response = detail_resource::<Workstreams>(id.to_string());
+            if response.status() == StatusCode::OK {
+                *response.status_mut() = StatusCode::CREATED;
+            }
+            response
+        }
+        Err(err) => plan_error_response(err),
+    }
+}
+
+/// Applies parent, dependency and milestone edits to one workstream.
+pub(in crate::api) async fn handle_update_workstream_plan(
+    State(_state): State<DbState>,
+    Path(id): Path<i64>,
+    Json(req): Json<WorkstreamPlanRequest>,
+) -> Response {
+    if req.parent_id.is_some() && req.clear_parent {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "workstream_plan_invalid",
+            "parent_id and clear_parent cannot be combined",
+        )
+        .into_response();
+    }
+    let add_dependencies = match parse_dependencies(&req.add_dependencies) {
+        Ok(dependencies) => dependencies,
+        Err(response) => return response,
+    };
+    let update = WorkStreamPlanUpdate {
+        parent_id: match (req.parent_id, req.clear_parent) {
+            (Some(parent_id), _) => Some(Some(parent_id)),
+            (None, true) => Some(None),
+            (None, false) => None,
+        },
+        add_dependencies: &add_dependencies,
+        remove_dependencies: &req.remove_dependencies,
+        add_milestones: &req.add_milestones,
+        complete_milestones: &req.complete_milestones,
+    };
+    if update.is_empty() {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "workstream_plan_invalid",
+            "at least one plan change is required",
+        )
+        .into_response();
+    }
+    let conn = match open_request_db() {
+        Ok(conn) => conn,
+        Err(response) => return response.into_response(),
+    };
+    match crate::workstream::update_workstream_plan(&conn, id, &update) {
+        Ok(true) => detail_resource::<Workstreams>(id.to_string()),
+        Ok(false) => error_response(
+            StatusCode::NOT_FOUND,
+            "workstream_not_found",
+            &format!("workstream {id} not found"),
+        )
+        .into_response(),
+        Err(err) => plan_error_response(err),
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn parse_dependencies(
+    dependencies: &[WorkstreamDependencyRequest],
+) -> Result<Vec<(i64, WorkStreamDependencyKind)>, Response> {
+    dependencies
+        .iter()
+        .map(|dependency| {
+            let kind = match dependency.kind.as_deref() {
+                None => WorkStreamDependencyKind::Hard,
+                Some(kind) => WorkStreamDependencyKind::parse(kind).ok_or_else(|| {
+                    error_response(
+                        StatusCode::BAD_REQUEST,
+                        "workstream_plan_invalid",
+                        &format!("unknown dependency kind '{kind}'; expected hard or soft"),
+                    )
+                    .into_response()
+                })?,
+            };
+            Ok((dependency.workstream_id, kind))
+        })
+        .collect()
+}
+
+fn plan_error_response(err: anyhow::Error) -> Response {
+    let message = err.to_string();
+    if err.is::<WorkStreamPlanError>() {
+        return error_response(StatusCode::BAD_REQUEST, "workstream_plan_invalid", &message)
+            .into_response();
+    }
+    error_response(
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "workstream_write_failed",
+        &message,
+    )
+    .into_response()
+}
+
 struct Workstreams;
 
 struct WorkstreamRow {
@@ -41,6 +179,9 @@ struct WorkstreamRow {
     created_at_epoch: i64,
     updated_at_epoch: i64,
     completed_at_epoch: Option<i64>,
+    parent_id: Option<i64>,
+    dependencies: Vec<WorkStreamDependency>,
+    milestones: Vec<WorkStreamMilestone>,
 }
 
 #[derive(Serialize)]
@@ -56,9 +197,27 @@ struct WorkstreamItem {
     created_at_epoch: i64,
     updated_at_epoch: i64,
     completed_at_epoch: Option<i64>,
+    parent_id: Option<i64>,
+    dependencies: Vec<WorkstreamDependencyItem>,
+    milestones: Vec<WorkstreamMilestoneItem>,
     references: Vec<SafeResourceRef>,
 }
 
+#[derive(Serialize)]
+struct WorkstreamDependencyItem {
+    depends_on_id: i64,
+    kind: &'static str,
+    satisfied: bool,
+}
+
+#[derive(Serialize)]
+struct WorkstreamMilestoneItem {
+    id: i64,
+    title: String,
+    position: i64,
+    completed_at_epoch: Option<i64>,
+}
+
 const SELECT_WORKSTREAM: &str =
     "SELECT w.id, w.project, w.title, w.description, w.status, w.progress,
             w.next_action, w.blockers, w.topic_domain, w.created_at_epoch,
@@ -94,7 +253,11 @@ impl ReadResourceSpec for Workstreams {
             params![resume_before_id, project, status, limit as i64],
             map_row,
         )?;
-        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+        let mut rows = rows.collect::<Result<Vec<_>, _>>()?;
+        for row in &mut rows {
+            attach_plan(conn, row)?;
+        }
+        Ok(rows)
     }
 
     fn load_one(conn: &Connection, id: i64) -> anyhow::Result<Option<Self::Row>> {
@@ -106,8 +269,9 @@ impl ReadResourceSpec for Workstreams {
             params![id],
             map_row,
         )
-        .optional()
-        .map_err(Into::into)
+        .optional()?
+        .map(|mut row| attach_plan(conn, &mut row).map(|()| row))
+        .transpose()
     }
 
     fn project(
@@ -124,6 +288,11 @@ impl ReadResourceSpec for Workstreams {
         visible.extend(row.next_action.as_deref());
         visible.extend(row.blockers.as_deref());
         visible.extend(row.topic_domain.as_deref());
+        visible.extend(
+            row.milestones
+                .iter()
+                .map(|milestone| milestone.title.as_str()),
+        );
         let relations = row
             .topic_domain
             .as_deref()
@@ -133,6 +302,21 @@ impl ReadResourceSpec for Workstreams {
         if policy.suppresses(&visible, &relations) {
             return Ok(None);
         }
+        let references = row
+            .parent_id
+            .into_iter()
+            .chain(
+                row.dependencies
+                    .iter()
+                    .map(|dependency| dependency.depends_on_id),
+            )
+            .map(|id| SafeResourceRef {
+                kind: "workstream",
+                id,
+                title: None,
+                status: None,
+            })
+            .collect();
         Ok(Some(WorkstreamItem {
             id: row.id,
             project: redact_bounded(&row.project),
@@ -145,7 +329,27 @@ impl ReadResourceSpec for Workstreams {
             created_at_epoch: row.created_at_epoch,
             updated_at_epoch: row.updated_at_epoch,
             completed_at_epoch: row.completed_at_epoch,
-            references: Vec::new(),
+            parent_id: row.parent_id,
+            dependencies: row
+                .dependencies
+                .iter()
+                .map(|dependency| WorkstreamDependencyItem {
+                    depends_on_id: dependency.depends_on_id,
+                    kind: dependency.kind.as_str(),
+                    satisfied: dependency.satisfied,
+                })
+                .collect(),
+            milestones: row
+                .milestones
+                .into_iter()
+                .map(|milestone| WorkstreamMilestoneItem {
+                    id: milestone.id,
+                    title: redact_bounded(&milestone.title),
+                    position: milestone.position,
+                    completed_at_epoch: milestone.completed_at_epoch,
+                })
+                .collect(),
+            references,
         }))
     }
 }
@@ -164,5 +368,17 @@ fn map_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<WorkstreamRow> {
         created_at_epoch: row.get(9)?,
         updated_at_epoch: row.get(10)?,
         completed_at_epoch: row.get(11)?,
+        parent_id: None,
+        dependencies: Vec::new(),
+        milestones: Vec::new(),
     })
 }
+
+fn attach_plan(conn: &Connection, row: &mut WorkstreamRow) -> anyhow::Result<()> {
+    if let Some(workstream) = crate::workstream::load_workstream(conn, row.id)? {
+        row.parent_id = workstream.parent_id;
+        row.dependencies = workstream.dependencies;
+        row.milestones = workstream.milestones;
+    }
+    Ok(())
+}
@@ -0,0 +1,765 @@
+//! OpenAPI 3.1 description of the REST surface.
+//!
+//! `CONTRACTS` is the registry for `server::build_router`, the way
+//! `mcp::server::tool_contracts` is for MCP tools: every mounted route has
+//! exactly one entry naming its query, request body and response schemas.
+//! Request schemas derive from the handler extractor types; responses come
+//! from the strict DTOs in `schemas`. The contract tests fail when a route
+//! is mounted without an entry or a live response leaves its schema.
+
+mod schemas;
+#[cfg(test)]
+mod tests;
+
+use anyhow::{bail, Context};
+use rmcp::schemars::{generate::SchemaSettings, Schema, SchemaGenerator};
+use serde_json::{json, Map, Value};
+
+use super::handlers::{
+    ActivityParams, ProjectActivityRequest, StreamParams, TimelineReportParams, STREAM_EVENT_TYPES,
+};
+use super::read_resources::ReadResourceParams;
+use super::types::{
+    BlockedParams, CandidateApproveRequest, CandidateEditRequest, CandidateParams,
+    CandidateSafeApproveRequest, CandidateSafeEditRequest, CandidateSafeRejectRequest,
+    CreateWorkstreamRequest, GraphParams, ListParams, MemoryDetailParams,
+    MemorySafeGovernanceRequest, SaveMemoryRequest, SearchParams, ShowParams, StatusParams,
+    UserRecallRequest, WorkstreamPlanRequest,
+};
+use schemas::{body, params, Body};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum HttpMethod {
+    Get,
+    Post,
+}
+
+impl HttpMethod {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Get => "get",
+            Self::Post => "post",
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Success {
+    Json {
+        status: u16,
+        body: Body,
+    },
+    /// `text/event-stream`; `event` describes the `data` of feed events.
+    EventStream {
+        event: Body,
+    },
+}
+
+#[derive(Clone, Copy)]
+struct RequestBody {
+    body: Body,
+    required: bool,
+}
+
+#[derive(Clone, Copy)]
+struct RouteContract {
+    method: HttpMethod,
+    path: &'static str,
+    operation_id: &'static str,
+    tag: &'static str,
+    summary: &'static str,
+    query: Option<fn(&mut SchemaGenerator) -> Schema>,
+    request: Option<RequestBody>,
+    success: Success,
+    /// Text media types the success response may take instead of JSON.
+    text_formats: &'static [&'static str],
+    error: Body,
+}
+
+const fn get(
+    path: &'static str,
+    operation_id: &'static str,
+    tag: &'static str,
+    summary: &'static str,
+    response: Body,
+) -> RouteContract {
+    RouteContract {
+        method: HttpMethod::Get,
+        path,
+        operation_id,
+        tag,
+        summary,
+        query: None,
+        request: None,
+        success: Success::Json {
+            status: 200,
+            body: response,
+        },
+        text_formats: &[],
+        error: body::<schemas::ErrorResponse>(),
+    }
+}
+
+const fn post(
+    path: &'static str,
+    operation_id: &'static str,
+    tag: &'static str,
+    summary: &'static str,
+    response: Body,
+) -> RouteContract {
+    let mut contract = get(path, operation_id, tag, summary, response);
+    contract.method = HttpMethod::Post;
+    contract
+}
+
+impl RouteContract {
+    const fn query(mut self, schema: fn(&mut SchemaGenerator) -> Schema) -> Self {
+        self.query = Some(schema);
+        self
+    }
+
+    const fn json_body(mut self, body: Body) -> Self {
+        self.request = Some(RequestBody {
+            body,
+            required: true,
+        });
+        self
+    }
+
+    const fn optional_json_body(mut self, body: Body) -> Self {
+        self.request = Some(RequestBody {
+            body,
+            required: false,
+        });
+        self
+    }
+
+    const fn created(mut self) -> Self {
+        if let Success::Json { body, .. } = self.success {
+            self.success = Success::Json { status: 201, body };
+        }
+        self
+    }
+
+    const fn event_stream(mut self) -> Self {
+        if let Success::Json { body, .. } = self.success {
+            self.success = Success::EventStream { event: body };
+        }
+        self
+    }
+
+    const fn text_formats(mut self, media_types: &'static [&'static str]) -> Self {
+        self.text_formats = media_types;
+        self
+    }
+
+    /// Idempotent mutations report failures with an `operation_id`.
+    const fn safe_mutation_errors(mut self) -> Self {
+        self.error = body::<schemas::SafeMutationErrorResponse>();
+        self
+    }
+
+    fn path_parameters(&self) -> impl Iterator<Item = &'static str> {
+        self.path
+            .split('/')
+            .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+    }
+}
+
+const CONTRACTS: [RouteContract; 45] = [
+    get(
+        "/api/v1/health",
+        "getHealth",
+        "meta",
+        "Liveness plus binary and schema versions",
+        body::<schemas::HealthResponse>(),
+    ),
+    get(
+        "/api/v1/capabilities",
+        "getCapabilities",
+        "meta",
+        "Feature flags and endpoint map for this build",
+        body::<schemas::CapabilitiesResponse>(),
+    ),
+    get(
+        "/api/v1/openapi.json",
+        "getOpenApiDocument",
+        "meta",
+        "This OpenAPI document",
+        body::<schemas::OpenApiDocument>(),
+    ),
+    get(
+        "/api/v1/status",
+        "getStatus",
+        "meta",
+        "Queue counts, poisoning defense and promotion funnel (cached briefly)",
+        body::<schemas::StatusResponse>(),
+    )
+    .query(params::<StatusParams>()),
+    get(
+        "/api/v1/stats",
+        "getStats",
+        "meta",
+        "Memory, candidate and AI usage totals",
+        body::<schemas::StatsResponse>(),
+    ),
+    get(
+        "/api/v1/search",
+        "searchMemories",
+        "memories",
+        "Search curated memories",
+        body::<schemas::SearchResponse>(),
+    )
+    .query(params::<SearchParams>()),
+    get(
+        "/api/v1/memory",
+        "getMemoryById",
+        "memories",
+        "Fetch one memory by `id` and mark it accessed",
+        body::<schemas::MemoryItem>(),
+    )
+    .query(params::<ShowParams>()),
+    get(
+        "/api/v1/memories",
+        "listMemories",
+        "memories",
+        "List memories with filters",
+        body::<schemas::MemoryListResponse>(),
+    )
+    .query(params::<ListParams>()),
+    post(
+        "/api/v1/memories",
+        "saveMemory",
+        "memories",
+        "Save or upsert a memory",
+        body::<schemas::SaveMemoryResponse>(),
+    )
+    .json_body(body::<SaveMemoryRequest>())
+    .created(),
+    get(
+        "/api/v1/memories/list",
+        "listMemoriesAlias",
+        "memories",
+        "Alias of `GET /api/v1/memories`",
+        body::<schemas::MemoryListResponse>(),
+    )
+    .query(params::<ListParams>()),
+    get(
+        "/api/v1/memories/{id}",
+        "getMemory",
+        "memories",
+        "Memory detail with entities and graph edges",
+        body::<schemas::MemoryDetailResponse>(),
+    )
+    .query(params::<MemoryDetailParams>()),
+    post(
+        "/api/v1/memories/{id}/archive",
+        "archiveMemory",
+        "memories",
+        "Archive a memory (idempotent, version-checked)",
+        body::<schemas::MemorySafeGovernanceResponse>(),
+    )
+    .json_body(body::<MemorySafeGovernanceRequest>())
+    .safe_mutation_errors(),
+    post(
+        "/api/v1/memories/{id}/restore",
+        "restoreMemory",
+        "memories",
+        "Restore an archived memory (idempotent, version-checked)",
+        body::<schemas::MemorySafeGovernanceResponse>(),
+    )
+    .json_body(body::<MemorySafeGovernanceRequest>())
+    .safe_mutation_errors(),
+    post(
+        "/api/v1/user/recall",
+        "recallUserContext",
+        "memories",
+        "Recall bounded user context for a task",
+        body::<schemas::UserRecallResponse>(),
+    )
+    .json_body(body::<UserRecallRequest>()),
+    get(
+        "/api/v1/candidates",
+        "listCandidates",
+        "candidates",
+        "List memory candidates",
+        body::<schemas::CandidateListResponse>(),
+    )
+    .query(params::<CandidateParams>()),
+    get(
+        "/api/v1/candidates/blocked",
+        "listBlockedCandidateReasons",
+        "candidates",
+        "Pending candidates grouped by auto-promotion block reason",
+        body::<schemas::BlockedReasonListResponse>(),
+    )
+    .query(params::<BlockedParams>()),
+    get(
+        "/api/v1/candidates/{id}",
+        "getCandidate",
+        "candidates",
+        "Candidate detail with evidence and review decision",
+        body::<schemas::CandidateDetailResponse>(),
+    ),
+    post(
+        "/api/v1/candidates/{id}/approve",
+        "approveCandidate",
+        "candidates",
+        "Approve a candidate into a memory",
+        body::<schemas::CandidateReviewResponse>(),
+    )
+    .optional_json_body(body::<CandidateApproveRequest>()),
+    post(
+        "/api/v1/candidates/{id}/reject",
+        "rejectCandidate",
+        "candidates",
+        "Discard a candidate",
+        body::<schemas::CandidateReviewResponse>(),
+    ),
+    post(
+        "/api/v1/candidates/{id}/edit",
+        "editCandidate",
+        "candidates",
+        "Edit then approve a candidate",
+        body::<schemas::CandidateReviewResponse>(),
+    )
+    .json_body(body::<CandidateEditRequest>()),
+    post(
+        "/api/v1/candidates/{id}/review/approve",
+        "safeApproveCandidate",
+        "candidates",
+        "Approve a candidate (idempotent, version-checked)",
+        body::<schemas::CandidateSafeReviewResponse>(),
+    )
+    .json_body(body::<CandidateSafeApproveRequest>())
+    .safe_mutation_errors(),
+    post(
+        "/api/v1/candidates/{id}/review/reject",
+        "safeRejectCandidate",
+        "candidates",
+        "Reject a candidate (idempotent, version-checked)",
+        body::<schemas::CandidateSafeReviewResponse>(),
+    )
+    .json_body(body::<CandidateSafeRejectRequest>())
+    .safe_mutation_errors(),
+    post(
+        "/api/v1/candidates/{id}/review/edit",
+        "safeEditCandidate",
+        "candidates",
+        "Edit and approve a candidate (idempotent, version-checked)",
+        body::<schemas::CandidateSafeReviewResponse>(),
+    )
+    .json_body(body::<CandidateSafeEditRequest>())
+    .safe_mutation_errors(),
+    get(
+        "/api/v1/observations",
+        "listObservations",
+        "resources",
+        "Page through redacted observations",
+        body::<schemas::ObservationListResponse>(),
+    )
+    .query(params::<ReadResourceParams>()),
+    get(
+        "/api/v1/observations/{id}",
+        "getObservation",
+        "resources",
+        "One redacted observation",
+        body::<schemas::ObservationDetailResponse>(),
+    ),
+    get(
+        "/api/v1/sessions",
+        "listSessions",
+        "resources",
+        "Page through redacted sessions",
+        body::<schemas::SessionListResponse>(),
+    )
+    .query(params::<ReadResourceParams>()),
+    get(
+        "/api/v1/sessions/{id}",
+        "getSession",
+        "resources",
+        "One redacted session",
+        body::<schemas::SessionDetailResponse>(),
+    ),
+    get(
+        "/api/v1/workstreams",
+        "listWorkstreams",
+        "resources",
+        "Page through redacted workstreams",
+        body::<schemas::WorkstreamListResponse>(),
+    )
+    .query(params::<ReadResourceParams>()),
+    post(
+        "/api/v1/workstreams",
+        "createWorkstream",
+        "resources",
+        "Create a workstream with its parent, dependencies and milestones",
+        body::<schemas::WorkstreamDetailResponse>(),
+    )
+    .json_body(body::<CreateWorkstreamRequest>())
+    .created(),
+    get(
+        "/api/v1/workstreams/{id}",
+        "getWorkstream",
+        "resources",
+        "One redacted workstream",
+        body::<schemas::WorkstreamDetailResponse>(),
+    ),
+    post(
+        "/api/v1/workstreams/{id}/plan",
+        "updateWorkstreamPlan",
+        "resources",
+        "Change a workstream's parent, dependencies or milestones",
+        body::<schemas::WorkstreamDetailResponse>(),
+    )
+    .json_body(body::<WorkstreamPlanRequest>()),
+    get(
+        "/api/v1/events",
+        "listEvents",
+        "resources",
+        "Page through redacted captured events",
+        body::<schemas::EventListResponse>(),
+    )
+    .query(params::<ReadResourceParams>()),
+    get(
+        "/api/v1/events/{id}",
+        "getEvent",
+        "resources",
+        "One redacted captured event",
+        body::<schemas::EventDetailResponse>(),
+    ),
+    get(
+        "/api/v1/tasks",
+        "listTasks",
+        "resources",
+        "Page through extraction tasks",
+        body::<schemas::TaskListResponse>(),
+    )
+    .query(params::<ReadResourceParams>()),
+    get(
+        "/api/v1/tasks/{id}",
+        "getTask",
+        "resources",
+        "One extraction task",
+        body::<schemas::TaskDetailResponse>(),
+    ),
+    get(
+        "/api/v1/injections",
+        "listInjections",
+        "resources",
+        "Page through per-item context injection audits",
+        body::<schemas::InjectionListResponse>(),
+    )
+    .query(params::<ReadResourceParams>()),
+    get(
+        "/api/v1/injections/{id}",
+        "getInjection",
+        "resources",
+        "One context injection audit item",
+        body::<schemas::InjectionDetailResponse>(),
+    ),
+    get(
+        "/api/v1/stream",
+        "streamEvents",
+        "resources",
+        "Server-sent change feed, resumable through `Last-Event-ID`",
+        body::<schemas::StreamEvent>(),
+    )
+    .query(params::<StreamParams>())
+    .event_stream(),
+    get(
+        "/api/v1/session-activity/sessions",
+        "listActivitySessions",
+        "session-activity",
+        "Raw sessions available for turn projection",
+        body::<schemas::ActivitySessionsResponse>(),
+    )
+    .query(params::<ActivityParams>()),
+    post(
+        "/api/v1/session-activity/project",
+        "projectSessionActivity",
+        "session-activity",
+        "Project one raw session into turns",
+        body::<schemas::ActivityProjectionResponse>(),
+    )
+    .json_body(body::<ProjectActivityRequest>()),
+    get(
+        "/api/v1/session-activity",
+        "listSessionActivity",
+        "session-activity",
+        "Projected turns, newest first",
+        body::<schemas::ActivityTurnsResponse>(),
+    )
+    .query(params::<ActivityParams>()),
+    get(
+        "/api/v1/session-activity/{id}",
+        "getSessionActivity",
+        "session-activity",
+        "One projected turn",
+        body::<schemas::ActivityTurnResponse>(),
+    ),
+    get(
+        "/api/v1/session-stats",
+        "getSessionActivityStats",
+        "session-activity",
+        "Turn, action and tool counts over a time window",
+        body::<schemas::ActivityStatsResponse>(),
+    )
+    .query(params::<ActivityParams>()),
+    get(
+        "/api/v1/graph",
+        "getGraph",
+        "graph",
+        "Entity co-mention graph",
+        body::<schemas::GraphResponse>(),
+    )
+    .query(params::<GraphParams>()),
+    get(
+        "/api/v1/timeline/report",
+        "getTimelineReport",
+        "reports",
+        "Activity history, type distribution, Token ROI and monthly breakdown; `format` selects JSON, CSV, HTML or Markdown",
+        body::<schemas::TimelineReportResponse>(),
+    )
+    .query(params::<TimelineReportParams>())
+    .text_formats(&["text/csv", "text/html", "text/markdown"]),
+];
+
+/// Builds the document served at `/api/v1/openapi.json` and printed by
+/// `remem api --openapi`.
+pub fn openapi_document() -> anyhow::Result<Value> {
+    let mut generator = SchemaSettings::draft2020_12()
+        .with(|settings| {
+            settings.definitions_path = "/components/schemas".into();
+            settings.meta_schema = None;
+        })
+        .into_generator();
+    let mut paths = Map::new();
+    for contract in &CONTRACTS {
+        let operation = operation(contract, &mut generator).with_context(|| {
+            format!(
+                "describe {} {}",
+                contract.method.as_str().to_uppercase(),
+                contract.path
+            )
+        })?;
+        let item = paths
+            .entry(contract.path)
+            .or_insert_with(|| Value::Object(Map::new()));
+        if item
+            .as_object_mut()
+            .and_then(|item| item.insert(contract.method.as_str().to_string(), operation))
+            .is_some()
+        {
+            bail!(
+                "duplicate OpenAPI operation for {} {}",
+                contract.method.as_str(),
+                contract.path
+            );
+        }
+    }
+    let schemas = generator.take_definitions(true);
+
+    Ok(json!({
+        "openapi": "3.1.0",
+        "jsonSchemaDialect": "https://json-schema.org/draft/2020-12/schema",
+        "info": {
+            "title": "remem local API",
+            "version": crate::build_info::package_version(),
+            "description": "Loopback REST API served by `remem api`. Every route requires the bearer token from the API token file.",
+        },
+        "servers": [{ "url": "http://127.0.0.1:5567" }],
+        "security": [{ "bearerAuth": [] }],
+        "paths": paths,
+        "components": {
+            "securitySchemes": {
+                "bearerAuth": { "type": "http", "scheme": "bearer" },
+            },
+            "schemas": schemas,
+        },
+    }))
+}
+
+fn operation(contract: &RouteContract, generator: &mut SchemaGenerator) -> anyhow::Result<Value> {
+    let mut parameters = contract
+        .path_parameters()
+        .map(|name| {
+            json!({
+                "name": name,
+                "in": "path",
+                "required": true,
+                "schema": { "type": "integer", "format": "int64", "minimum": 1 },
+            })
+        })
+        .collect::<Vec<_>>();
+    if let Some(query) = contract.query {
+        parameters.extend(query_parameters(query(generator))?);
+    }
+
+    let success = match contract.success {
+        Success::Json { status, body } => {
+            let mut content = Map::new();
+            content.insert(
+                "application/json".to_string(),
+                json!({ "schema": (body.schema)(generator) }),
+            );
+            for media_type in contract.text_formats {
+                content.insert(
+                    media_type.to_string(),
+                    json!({ "schema": { "type": "string" } }),
+                );
+            }
+            (
+                status.to_string(),
+                json!({ "description": "Success", "content": content }),
+            )
+        }
+        Success::EventStream { event } => {
+            let mut events = Map::new();
+            events.insert(
+                "stream_ready".to_string(),
+                generator.subschema_for::<schemas::StreamReady>().into(),
+            );
+            events.insert(
+                "stream_reset".to_string(),
+                generator.subschema_for::<schemas::StreamReset>().into(),
+            );
+            let feed_event: Value = (event.schema)(generator).into();
+            for event_type in STREAM_EVENT_TYPES {
+                events.insert(event_type.to_string(), feed_event.clone());
+            }
+            (
+                "200".to_string(),
+                json!({
+                    "description": "Server-sent events; each `data` line is JSON described by `x-remem-events` under the SSE event name.",
+                    "content": {
+                        "text/event-stream": {
+                            "schema": { "type": "string" },
+                            "x-remem-events": events,
+                        },
+                    },
+                }),
+            )
+        }
+    };
+    let mut responses = Map::new();
+    responses.insert(success.0, success.1);
+    responses.insert(
+        "default".to_string(),
+        json!({
+            "description": "Error with a stable `error.code`",
+            "content": { "application/json": { "schema": (contract.error.schema)(generator) } },
+        }),
+    );
+
+    let mut operation = json!({
+        "operationId": contract.operation_id,
+        "summary": contract.summary,
+        "tags": [contract.tag],
+        "responses": responses,
+    });
+    if !parameters.is_empty() {
+        operation["parameters"] = Value::Array(parameters);
+    }
+    if let Some(request) = contract.request {
+        operation["requestBody"] = json!({
+            "required": request.required,
+            "content": { "application/json": { "schema": (request.body.schema)(generator) } },
+        });
+    }
+    Ok(operation)
+}
+
+/// Spreads an inlined query struct schema into one `in: query` parameter per
+/// property. Absent and null mean the same thing in a query string, so the
+/// `null` arm of optional fields is dropped.
+fn query_parameters(schema: Schema) -> anyhow::Result<Vec<Value>> {
+    let Value::Object(mut schema) = Value::from(schema) else {
+        bail!("query parameter schema must be an object");
+    };
+    let required = schema
+        .remove("required")
+        .and_then(|required| serde_json::from_value::<Vec<String>>(required).ok())
+        .unwrap_or_default();
+    let Some(Value::Object(properties)) = schema.remove("properties") else {
+        bail!("query parameter schema declares no properties");
+    };
+    Ok(properties
+        .into_iter()
+        .map(|(name, mut property)| {
+            let description = property
+                .as_object_mut()
+                .and_then(|property| property.remove("description"));
+            if let Some(Value::Array(types)) = property.get_mut("type") {
+                types.retain(|value| value != "null");
+                if types.len() == 1 {
+                    property["type"] = types.remove(0);
+                }
+            }
+            let mut parameter = json!({
+                "name": name,
+                "in": "query",
+                "required": required.contains(&name),
+                "schema": property,
+            });
+            if let Some(description) = description {
+                parameter["description"] = description;
+            }
+            parameter
+        })
+        .collect())
+}
+
+#[cfg(test)]
+fn find_contract(method: &str, path: &str) -> anyhow::Result<&'static RouteContract> {
+    CONTRACTS
+        .iter()
+        .find(|contract| {
+            contract.method.as_str().eq_ignore_ascii_case(method) && contract.path == path
+        })
+        .with_context(|| format!("no OpenAPI contract for {method} {path}"))
+}
+
+/// `(method, path template)` of every documented operation.
+#[cfg(test)]
+pub(super) fn documented_operations() -> Vec<(&'static str, &'static str)> {
+    CONTRACTS
+        .iter()
+        .map(|contract| (contract.method.as_str(), contract.path))
+        .collect()
+}
+
+/// Checks a live JSON response against the success or error schema that the
+/// document declares for `method path` (a route template).
+#[cfg(test)]
+pub(super) fn validate_response(
+    method: &str,
+    path: &str,
+    status: u16,
+    body: &Value,
+) -> anyhow::Result<()> {
+    let contract = find_contract(method, path)?;
+    match contract.success {
+        Success::Json {
+            status: documented,
+            body: schema,
+        } if status == documented => (schema.validate)(body),
+        _ if status >= 400 => (contract.error.validate)(body),
+        _ => bail!("{method} {path} answered with undocumented status {status}"),
+    }
+}
+
+/// Checks the `data` of one SSE frame from `GET /api/v1/stream`.
+#[cfg(test)]
+pub(super) fn validate_stream_event(event: &str, data: &Value) -> anyhow::Result<()> {
+    let contract = find_contract("get", "/api/v1/stream")?;
+    let Success::EventStream { event: feed_event } = contract.success else {
+        bail!("/api/v1/stream is not documented as an event stream");
+    };
+    match event {
+        "stream_ready" => (schemas::body::<schemas::StreamReady>().validate)(data),
+        "stream_reset" => (schemas::body::<schemas::StreamReset>().validate)(data),
+        _ if STREAM_EVENT_TYPES.contains(&event) => (feed_event.validate)(data),
+        _ => bail!("undocumented stream event {event}"),
+    }
+}
@@ -0,0 +1,132 @@
+#![allow(dead_code)]
+
+//! Response DTOs behind the OpenAPI document.
+//!
+//! As with the MCP output contracts, these mirror what the handlers put on
+//! the wire instead of deriving schemas from the `Serialize` types: several
+//! responses are assembled with `json!`, flattened, or serialized by hand.
+//! Every DTO rejects unknown fields, so the contract test fails as soon as a
+//! handler emits a shape the document does not describe.
+
+mod activity;
+mod candidates;
+mod memories;
+mod reports;
+mod resources;
+
+use anyhow::Context;
+use rmcp::schemars::{self, JsonSchema, Schema, SchemaGenerator};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+pub(super) use activity::{
+    ActivityProjectionResponse, ActivitySessionsResponse, ActivityStatsResponse,
+    ActivityTurnResponse, ActivityTurnsResponse,
+};
+pub(super) use candidates::{
+    BlockedReasonListResponse, CandidateDetailResponse, CandidateListResponse,
+    CandidateReviewResponse, CandidateSafeReviewResponse,
+};
+pub(super) use memories::{
+    CapabilitiesResponse, GraphResponse, HealthResponse, MemoryDetailResponse, MemoryItem,
+    MemoryListResponse, MemorySafeGovernanceResponse, SaveMemoryResponse, SearchResponse,
+    StatsResponse, StatusResponse,
+};
+pub(super) use reports::TimelineReportResponse;
+pub(super) use resources::{
+    EventDetailResponse, EventListResponse, InjectionDetailResponse, InjectionListResponse,
+    ObservationDetailResponse, ObservationListResponse, SessionDetailResponse, SessionListResponse,
+    StreamEvent, StreamReady, StreamReset, TaskDetailResponse, TaskListResponse,
+    WorkstreamDetailResponse, WorkstreamListResponse,
+};
+
+type SchemaFn = fn(&mut SchemaGenerator) -> Schema;
+type ValidateFn = fn(&Value) -> anyhow::Result<()>;
+
+/// A JSON body: its schema (a `$ref` into `components.schemas`) and a strict
+/// validator for live payloads.
+#[derive(Clone, Copy)]
+pub(super) struct Body {
+    pub(super) schema: SchemaFn,
+    pub(super) validate: ValidateFn,
+}
+
+pub(super) const fn body<T: JsonSchema + DeserializeOwned>() -> Body {
+    Body {
+        schema: referenced::<T>,
+        validate: validate::<T>,
+    }
+}
+
+/// Query-string parameters, inlined so each property becomes one parameter.
+pub(super) const fn params<T: JsonSchema>() -> SchemaFn {
+    inline::<T>
+}
+
+fn referenced<T: JsonSchema>(generator: &mut SchemaGenerator) -> Schema {
+    generator.subschema_for::<T>()
+}
+
+fn inline<T: JsonSchema>(generator: &mut SchemaGenerator) -> Schema {
+    T::json_schema(generator)
+}
+
+fn validate<T: DeserializeOwned>(value: &Value) -> anyhow::Result<()> {
+    T::deserialize(value)
+        .map(drop)
+        .with_context(|| format!("validate {}", std::any::type_name::<T>()))
+}
+
+/// Pairs with `#[schemars(required)]` so a cursor that is always present,
+/// but null on the last page, is rejected when it goes missing.
+fn deserialize_required_nullable<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer)
+}
+
+fn unconstrained_json_value_schema(_: &mut SchemaGenerator) -> Schema {
+    schemars::json_schema!({})
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(super) struct ErrorResponse {
+    error: ErrorDetail,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct ErrorDetail {
+    /// Stable machine-readable error code.
+    code: String,
+    message: String,
+}
+
+/// Error body of the idempotent review and governance mutations.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(super) struct SafeMutationErrorResponse {
+    error: SafeMutationErrorDetail,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct SafeMutationErrorDetail {
+    code: String,
+    message: String,
+    #[serde(default)]
+    operation_id: Option<String>,
+}
+
+/// Payload owned by `user_context::recall`; the MCP `recall_user_context`
+/// output contract describes the same object field by field.
+#[derive(Deserialize, JsonSchema)]
+pub(super) struct UserRecallResponse(Map<String, Value>);
+
+/// The document served by `GET /api/v1/openapi.json`.
+#[derive(Deserialize, JsonSchema)]
+pub(super) struct OpenApiDocument(Map<String, Value>);
@@ -0,0 +1,138 @@
+use rmcp::schemars::{self, JsonSchema};
+use serde::Deserialize;
+
+use super::deserialize_required_nullable;
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct ActivitySessionsResponse {
+    meta: ActivitySessionsMeta,
+    data: Vec<RawSessionActivity>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct ActivitySessionsMeta {
+    count: usize,
+    limit: i64,
+    has_more: bool,
+    #[serde(deserialize_with = "deserialize_required_nullable")]
+    #[schemars(required)]
+    next_cursor: Option<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct RawSessionActivity {
+    source_root: String,
+    project: String,
+    session_id: String,
+    message_count: i64,
+    user_message_count: i64,
+    assistant_message_count: i64,
+    first_epoch: Option<i64>,
+    last_epoch: i64,
+    message_counts_truncated: bool,
+    projected_turn_count: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct ActivityTurnsResponse {
+    meta: ActivityTurnsMeta,
+    data: Vec<SessionActivityItem>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct ActivityTurnsMeta {
+    count: usize,
+    limit: i64,
+    has_more: bool,
+    #[serde(deserialize_with = "deserialize_required_nullable")]
+    #[schemars(required)]
+    next_before_id: Option<i64>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct ActivityTurnResponse {
+    data: SessionActivityItem,
+}
+
+/// One projected turn together with the session it belongs to.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct SessionActivityItem {
+    source_root: String,
+    project: String,
+    session_id: String,
+    id: Option<i64>,
+    turn_index: i64,
+    user_message_id: i64,
+    user_said: String,
+    understanding_message_id: Option<i64>,
+    understanding: Option<String>,
+    understanding_source: Option<String>,
+    result_message_id: Option<i64>,
+    actions_summary: Option<String>,
+    result_status: String,
+    result_summary: Option<String>,
+    started_at_epoch: i64,
+    ended_at_epoch: Option<i64>,
+    capture_health: String,
+    actions: Vec<TurnAction>,
+    actions_truncated: bool,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct TurnAction {
+    index: i64,
+    kind: String,
+    tool_name: Option<String>,
+    summary: String,
+    event_row_id: Option<i64>,
+    files: Vec<String>,
+    outcome: Option<String>,
+    created_at_epoch: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct ActivityStatsResponse {
+    data: SessionActivityStats,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct SessionActivityStats {
+    sessions: i64,
+    turns: i64,
+    actions: i64,
+    result_status: Vec<ActivityCount>,
+    capture_health: Vec<ActivityCount>,
+    projects: Vec<ActivityCount>,
+    tools: Vec<ActivityCount>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct ActivityCount {
+    key: String,
+    count: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct ActivityProjectionResponse {
+    data: ProjectionResult,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct ProjectionResult {
+    changed: bool,
+    source_digest: String,
+    turn_count: usize,
+}
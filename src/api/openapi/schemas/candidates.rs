@@ -0,0 +1,185 @@
+use rmcp::schemars::{self, JsonSchema};
+use serde::Deserialize;
+
+use super::memories::ListMeta;
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct CandidateItem {
+    id: i64,
+    #[serde(default)]
+    project: Option<String>,
+    memory_type: String,
+    text: String,
+    scope: String,
+    confidence: f64,
+    risk_class: String,
+    review_status: String,
+    evidence_count: i64,
+    created_at_epoch: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct CandidateListResponse {
+    data: Vec<CandidateItem>,
+    meta: ListMeta,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct BlockedReasonItem {
+    /// Auto-promotion block reason; omitted for unblocked pending rows.
+    #[serde(default)]
+    reason: Option<String>,
+    pending: i64,
+    example_ids: Vec<i64>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct BlockedReasonListResponse {
+    data: Vec<BlockedReasonItem>,
+    meta: ListMeta,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct CandidateReviewResponse {
+    candidate_id: i64,
+    status: String,
+    #[serde(default)]
+    memory_id: Option<i64>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct CandidateSafeReviewResponse {
+    response_schema_version: i64,
+    operation_id: String,
+    audit_id: i64,
+    candidate_id: i64,
+    #[serde(default)]
+    memory_id: Option<i64>,
+    action: String,
+    before_status: String,
+    after_status: String,
+    version: i64,
+    occurred_at_epoch: i64,
+    /// True when the idempotency key matched an earlier identical request.
+    replayed: bool,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct CandidateDetailResponse {
+    data: CandidateDetailItem,
+    evidence: Vec<CandidateEvidenceItem>,
+    /// Dream-generated candidates only.
+    #[serde(default)]
+    provenance: Option<CandidateDreamProvenance>,
+    decision: CandidateReviewDecision,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct CandidateDetailItem {
+    id: i64,
+    project: Option<String>,
+    scope: String,
+    memory_type: String,
+    topic_key: String,
+    text: String,
+    source_kind: Option<String>,
+    source_project: Option<String>,
+    target_project: Option<String>,
+    owner_scope: Option<String>,
+    owner_key: Option<String>,
+    topic_domain: Option<String>,
+    routing_confidence: Option<f64>,
+    routing_reason: Option<String>,
+    context_class: Option<String>,
+    confidence: f64,
+    risk_class: String,
+    review_status: String,
+    auto_promote_block_reason: Option<String>,
+    source_trust_class: String,
+    quarantine_pattern_id: Option<String>,
+    quarantine_pattern_version: Option<i64>,
+    version: i64,
+    created_at_epoch: i64,
+    updated_at_epoch: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct CandidateEvidenceItem {
+    source_kind: String,
+    source_id: i64,
+    event_type: Option<String>,
+    role: Option<String>,
+    tool_name: Option<String>,
+    created_at_epoch: Option<i64>,
+    summary: String,
+    preview: String,
+    provenance_status: String,
+    redacted: bool,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct CandidateDreamProvenance {
+    kind: String,
+    review_token: Option<String>,
+    authorized_supersede_ids: Vec<i64>,
+    artifacts: Vec<CandidateDreamArtifact>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct CandidateDreamArtifact {
+    artifact_id: i64,
+    version: i64,
+    project: String,
+    cluster_signature: String,
+    member_ids: Vec<i64>,
+    decision_kind: String,
+    decision_ids: Vec<i64>,
+    decision_payload_sha256: String,
+    intended_superseded_ids: Vec<i64>,
+    generated_topic_key: Option<String>,
+    generated_memory_type: Option<String>,
+    generated_title: Option<String>,
+    generated_content: Option<String>,
+    generated_field: String,
+    pattern_id: String,
+    pattern_version: i64,
+    source_operation: String,
+    source_trust_class: String,
+    occurrence_count: i64,
+    created_at_epoch: i64,
+    updated_at_epoch: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct CandidateReviewDecision {
+    can_review: bool,
+    blocked_reasons: Vec<String>,
+    actions: CandidateReviewActionDecisions,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct CandidateReviewActionDecisions {
+    approve: CandidateReviewActionDecision,
+    reject: CandidateReviewActionDecision,
+    edit: CandidateReviewActionDecision,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct CandidateReviewActionDecision {
+    allowed: bool,
+    blocked_reasons: Vec<String>,
+}
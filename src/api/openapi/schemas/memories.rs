@@ -0,0 +1,384 @@
+use std::collections::BTreeMap;
+
+use rmcp::schemars::{self, JsonSchema};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{deserialize_required_nullable, unconstrained_json_value_schema};
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct HealthResponse {
+    ok: bool,
+    version: String,
+    api_version: u16,
+    schema_version: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct CapabilitiesResponse {
+    version: String,
+    schema_version: i64,
+    api_version: u16,
+    features: CapabilitiesFeatures,
+    /// Feature name to the route (or route template) that serves it.
+    endpoints: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct CapabilitiesFeatures {
+    health: bool,
+    status: bool,
+    stats: bool,
+    search: bool,
+    search_explain: bool,
+    memory_list: bool,
+    memory_detail: bool,
+    save_memory: bool,
+    memory_archive: bool,
+    memory_restore: bool,
+    memory_delete: bool,
+    candidate_rows: bool,
+    candidate_filters: bool,
+    candidate_review: bool,
+    candidate_detail: bool,
+    candidate_evidence: bool,
+    candidate_review_safe: bool,
+    observations: bool,
+    sessions: bool,
+    session_activity: bool,
+    workstreams: bool,
+    workstream_plans: bool,
+    events: bool,
+    event_stream: bool,
+    openapi: bool,
+    injections: bool,
+    web_ui: bool,
+    tasks: bool,
+    graph: bool,
+    timeline_report: bool,
+    user_recall: bool,
+    user_recall_usage_policy: bool,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct StatusResponse {
+    version: String,
+    memories: i64,
+    observations: i64,
+    total_observations: i64,
+    captured_events: i64,
+    capture_drop_events: i64,
+    unrecovered_capture_spills: i64,
+    pending_extraction_tasks: i64,
+    pending_memory_candidates: i64,
+    pending_graph_candidates: i64,
+    poisoning_defense: PoisoningDefenseStatus,
+    promotion_funnel: PromotionFunnel,
+    cache: StatusCacheInfo,
+    /// Present only when a failed refresh fell back to the cached payload.
+    #[serde(default)]
+    warnings: Option<Vec<StatusWarning>>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct PoisoningDefenseStatus {
+    pattern_set_version: i64,
+    quarantined_candidates: i64,
+    quarantined_summaries: i64,
+    legacy_unscanned_summaries: i64,
+    summary_block_count: i64,
+    quarantined_observations: i64,
+    memory_injection_drops: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct PromotionFunnel {
+    captured_events: i64,
+    observations: i64,
+    observation_rate_percent: f64,
+    candidates: i64,
+    candidate_rate_percent: f64,
+    promoted: i64,
+    promoted_rate_percent: f64,
+    pending_review: i64,
+    pending_review_rate_percent: f64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct StatusCacheInfo {
+    hit: bool,
+    stale: bool,
+    generated_at_epoch: i64,
+    ttl_secs: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct StatusWarning {
+    code: String,
+    message: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct StatsResponse {
+    active_memories: i64,
+    total_memories: i64,
+    pending_candidates: i64,
+    captured_events: i64,
+    pending_extraction_tasks: i64,
+    ai_calls: i64,
+    ai_cost_usd: f64,
+    ai_total_tokens: i64,
+    type_distribution: Vec<TypeCount>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct TypeCount {
+    memory_type: String,
+    count: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum MemoryVisibilityClass {
+    Current,
+    LegacyUnverified,
+    Quarantined,
+    Expired,
+    Superseded,
+    NotYetValid,
+    Inactive,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct MemoryStalenessLabel {
+    status: String,
+    age: String,
+    source_anchor: String,
+    label: String,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct MemoryItem {
+    id: i64,
+    #[serde(default)]
+    version: Option<i64>,
+    title: String,
+    content: String,
+    memory_type: String,
+    project: String,
+    scope: String,
+    status: String,
+    classification: MemoryVisibilityClass,
+    current_context_eligible: bool,
+    classification_reason: String,
+    staleness: MemoryStalenessLabel,
+    #[serde(default)]
+    topic_key: Option<String>,
+    #[serde(default)]
+    branch: Option<String>,
+    created_at_epoch: i64,
+    updated_at_epoch: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct SearchMeta {
+    count: usize,
+    has_more: bool,
+    limit: i64,
+    offset: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct SearchResponse {
+    data: Vec<MemoryItem>,
+    meta: SearchMeta,
+    #[serde(default)]
+    multi_hop: Option<MultiHopInfo>,
+    /// Raw archive fallback hits; omitted when empty.
+    #[serde(default)]
+    raw_hits: Option<Vec<RawHitItem>>,
+    #[serde(default)]
+    raw_hits_error: Option<String>,
+    /// Ranking breakdown requested with `explain=true`.
+    #[serde(default)]
+    #[schemars(schema_with = "unconstrained_json_value_schema")]
+    explain: Option<Value>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct MultiHopInfo {
+    hops: u8,
+    entities_discovered: Vec<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct RawHitItem {
+    id: i64,
+    session_id: String,
+    project: String,
+    role: String,
+    preview: String,
+    source: String,
+    #[serde(default)]
+    branch: Option<String>,
+    created_at_epoch: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct ListMeta {
+    count: usize,
+    total: i64,
+    limit: i64,
+    offset: i64,
+    has_more: bool,
+    #[serde(deserialize_with = "deserialize_required_nullable")]
+    #[schemars(required)]
+    next_offset: Option<i64>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct MemoryListResponse {
+    data: Vec<MemoryItem>,
+    meta: ListMeta,
+}
+
+/// A memory item plus its entities and graph edges.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct MemoryDetailResponse {
+    id: i64,
+    #[serde(default)]
+    version: Option<i64>,
+    title: String,
+    content: String,
+    memory_type: String,
+    project: String,
+    scope: String,
+    status: String,
+    classification: MemoryVisibilityClass,
+    current_context_eligible: bool,
+    classification_reason: String,
+    staleness: MemoryStalenessLabel,
+    #[serde(default)]
+    topic_key: Option<String>,
+    #[serde(default)]
+    branch: Option<String>,
+    created_at_epoch: i64,
+    updated_at_epoch: i64,
+    entities: Vec<String>,
+    edges: Vec<MemoryEdgeItem>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct MemoryEdgeItem {
+    id: i64,
+    edge_type: String,
+    from_memory_id: Option<i64>,
+    to_memory_id: Option<i64>,
+    confidence: Option<f64>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct SaveMemoryResponse {
+    id: i64,
+    status: String,
+    memory_type: String,
+    project: String,
+    scope: String,
+    topic_key: Option<String>,
+    branch: Option<String>,
+    operation: String,
+    created_at_epoch: i64,
+    reference_time_epoch: i64,
+    updated_at_epoch: i64,
+    upserted: bool,
+    local_copy: LocalCopy,
+    local_status: String,
+    #[serde(default)]
+    local_path: Option<String>,
+    claim_status: String,
+    claim_id: Option<i64>,
+    claim_error: Option<String>,
+    next_step: SaveMemoryNextStep,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct LocalCopy {
+    status: String,
+    path: Option<String>,
+    #[serde(default)]
+    reason: Option<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct SaveMemoryNextStep {
+    tool: String,
+    ids: Vec<i64>,
+    source: String,
+    reason: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct MemorySafeGovernanceResponse {
+    response_schema_version: i64,
+    operation_id: String,
+    audit_id: i64,
+    memory_id: i64,
+    action: String,
+    before_status: String,
+    after_status: String,
+    version: i64,
+    occurred_at_epoch: i64,
+    /// True when the idempotency key matched an earlier identical request.
+    replayed: bool,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct GraphResponse {
+    nodes: Vec<GraphNodeItem>,
+    edges: Vec<GraphEdgeItem>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct GraphNodeItem {
+    id: i64,
+    name: String,
+    entity_type: Option<String>,
+    mention_count: i64,
+    mems: Vec<i64>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct GraphEdgeItem {
+    a: i64,
+    b: i64,
+    w: i64,
+}
@@ -0,0 +1,67 @@
+use rmcp::schemars::{self, JsonSchema};
+use serde::Deserialize;
+
+use super::deserialize_required_nullable;
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct TimelineReportResponse {
+    project: String,
+    full: bool,
+    overview: TimelineOverview,
+    activity_by_type: Vec<TimelineTypeCount>,
+    token_economics: TimelineTokenEconomics,
+    /// Present only for `full=true`.
+    recent_timeline: Option<Vec<TimelineObservation>>,
+    /// Present only for `full=true`.
+    monthly_breakdown: Option<Vec<TimelineMonth>>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct TimelineOverview {
+    first_date: String,
+    last_date: String,
+    days_span: i64,
+    total_observations: i64,
+    total_sessions: i64,
+    total_memories: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct TimelineTypeCount {
+    obs_type: String,
+    count: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct TimelineTokenEconomics {
+    total_ai_cost: f64,
+    total_discovery_tokens: i64,
+    sessions_with_context: i64,
+    estimated_recall_savings_tokens: i64,
+    /// Null until there is any AI spend.
+    #[serde(deserialize_with = "deserialize_required_nullable")]
+    #[schemars(required)]
+    recall_tokens_per_usd: Option<f64>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct TimelineObservation {
+    id: i64,
+    obs_type: String,
+    title: Option<String>,
+    created_at_epoch: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct TimelineMonth {
+    month: String,
+    observations: i64,
+    sessions: i64,
+    ai_cost: f64,
+}
@@ -0,0 +1,227 @@
+use rmcp::schemars::{self, JsonSchema};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{deserialize_required_nullable, unconstrained_json_value_schema};
+
+/// Redacted pointer from one read resource to a related one.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct SafeResourceRef {
+    kind: String,
+    id: i64,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    status: Option<String>,
+}
+
+/// Declares the `{data, next_cursor, page_size}` page and `{data}` detail
+/// envelopes shared by every read resource.
+macro_rules! read_resource_envelopes {
+    ($item:ident, $list:ident, $detail:ident) => {
+        #[derive(Deserialize, JsonSchema)]
+        #[serde(deny_unknown_fields)]
+        pub(in crate::api::openapi) struct $list {
+            data: Vec<$item>,
+            /// Opaque cursor bound to the request filters; null on the last page.
+            #[serde(deserialize_with = "deserialize_required_nullable")]
+            #[schemars(required)]
+            next_cursor: Option<String>,
+            page_size: i64,
+        }
+
+        #[derive(Deserialize, JsonSchema)]
+        #[serde(deny_unknown_fields)]
+        pub(in crate::api::openapi) struct $detail {
+            data: $item,
+        }
+    };
+}
+
+read_resource_envelopes!(
+    ObservationItem,
+    ObservationListResponse,
+    ObservationDetailResponse
+);
+read_resource_envelopes!(SessionItem, SessionListResponse, SessionDetailResponse);
+read_resource_envelopes!(
+    WorkstreamItem,
+    WorkstreamListResponse,
+    WorkstreamDetailResponse
+);
+read_resource_envelopes!(EventItem, EventListResponse, EventDetailResponse);
+read_resource_envelopes!(TaskItem, TaskListResponse, TaskDetailResponse);
+read_resource_envelopes!(
+    InjectionItem,
+    InjectionListResponse,
+    InjectionDetailResponse
+);
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct ObservationItem {
+    id: i64,
+    project: Option<String>,
+    observation_type: String,
+    status: String,
+    title: Option<String>,
+    summary: String,
+    preview: String,
+    created_at_epoch: Option<i64>,
+    reference_time_epoch: Option<i64>,
+    branch: Option<String>,
+    references: Vec<SafeResourceRef>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct SessionItem {
+    id: i64,
+    host: String,
+    project: String,
+    status: String,
+    summary: String,
+    started_at_epoch: Option<i64>,
+    last_seen_at_epoch: i64,
+    references: Vec<SafeResourceRef>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct WorkstreamItem {
+    id: i64,
+    project: String,
+    title: String,
+    description: Option<String>,
+    status: String,
+    progress: Option<String>,
+    next_action: Option<String>,
+    blockers: Option<String>,
+    created_at_epoch: i64,
+    updated_at_epoch: i64,
+    completed_at_epoch: Option<i64>,
+    parent_id: Option<i64>,
+    dependencies: Vec<WorkstreamDependencyItem>,
+    milestones: Vec<WorkstreamMilestoneItem>,
+    references: Vec<SafeResourceRef>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct WorkstreamDependencyItem {
+    depends_on_id: i64,
+    /// `hard` blocks `active`/`completed` until the target completes; `soft`
+    /// is ordering only.
+    kind: String,
+    /// True once the target workstream is completed.
+    satisfied: bool,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct WorkstreamMilestoneItem {
+    id: i64,
+    title: String,
+    position: i64,
+    completed_at_epoch: Option<i64>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct EventItem {
+    id: i64,
+    project: String,
+    event_type: String,
+    role: Option<String>,
+    tool_name: Option<String>,
+    retention_class: String,
+    summary: String,
+    preview: String,
+    created_at_epoch: i64,
+    inserted_at_epoch: i64,
+    reference_time_epoch: Option<i64>,
+    references: Vec<SafeResourceRef>,
+}
+
+/// One item a context hook injected, dropped or abstained from.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct InjectionItem {
+    id: i64,
+    injection_run_id: String,
+    host: String,
+    project: String,
+    hook_source: Option<String>,
+    output_mode: String,
+    decision: String,
+    item_kind: String,
+    memory_id: Option<i64>,
+    channel: String,
+    score: Option<f64>,
+    render_order: Option<i64>,
+    /// `injected`, `dropped` or `abstained`.
+    status: String,
+    drop_reason: Option<String>,
+    title: Option<String>,
+    staleness: Option<String>,
+    injected_at_epoch: i64,
+    references: Vec<SafeResourceRef>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct TaskItem {
+    id: i64,
+    project: String,
+    task_kind: String,
+    status: String,
+    priority: i64,
+    attempts: i64,
+    has_error: bool,
+    error_class: Option<String>,
+    next_retry_epoch: Option<i64>,
+    failed_at_epoch: Option<i64>,
+    archived_at_epoch: Option<i64>,
+    created_at_epoch: i64,
+    updated_at_epoch: i64,
+    references: Vec<SafeResourceRef>,
+}
+
+/// `data` of the first `stream_ready` event on every connection.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct StreamReady {
+    head: i64,
+    project: Option<String>,
+    types: Vec<String>,
+}
+
+/// `data` of a `stream_reset` event sent before `stream_ready` when the
+/// resume cursor can no longer be replayed.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct StreamReset {
+    reason: String,
+}
+
+/// `data` of every feed event; the SSE event name equals `event_type`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(in crate::api::openapi) struct StreamEvent {
+    id: i64,
+    event_type: String,
+    project: Option<String>,
+    resource: StreamResource,
+    created_at_epoch: i64,
+    /// Structural payload written by the feed trigger for this event type.
+    #[schemars(schema_with = "unconstrained_json_value_schema")]
+    data: Value,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct StreamResource {
+    kind: String,
+    id: i64,
+}
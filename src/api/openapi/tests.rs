@@ -0,0 +1,155 @@
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+
+use super::{openapi_document, CONTRACTS};
+
+const SERVER_SOURCE: &str = include_str!("../server.rs");
+
+/// `(method, path)` pairs mounted by `build_router`, read from its source so
+/// a new `.route(..)` without a contract fails here.
+fn mounted_operations() -> BTreeSet<(String, String)> {
+    let mut mounted = BTreeSet::new();
+    for route in SERVER_SOURCE.split(".route(").skip(1) {
+        let route = route.split(".route_layer(").next().unwrap_or(route);
+        let path = route
+            .split('"')
+            .nth(1)
+            .expect("route call starts with a path literal");
+        for method in ["get", "post", "put", "patch", "delete"] {
+            let call = format!("{method}(");
+            let mounted_here = route.match_indices(&call).any(|(index, _)| {
+                index == 0
+                    || !route.as_bytes()[index - 1].is_ascii_alphanumeric()
+                        && route.as_bytes()[index - 1] != b'_'
+            });
+            if mounted_here {
+                mounted.insert((method.to_string(), path.to_string()));
+            }
+        }
+    }
+    mounted
+}
+
+#[test]
+fn every_mounted_route_has_exactly_one_contract() {
+    let contracted = CONTRACTS
+        .iter()
+        .map(|contract| {
+            (
+                contract.method.as_str().to_string(),
+                contract.path.to_string(),
+            )
+        })
+        .collect::<BTreeSet<_>>();
+    assert_eq!(contracted.len(), CONTRACTS.len(), "duplicate contract");
+
+    let mounted = mounted_operations();
+    assert!(mounted.contains(&("get".to_string(), "/api/v1/openapi.json".to_string())));
+    assert_eq!(
+        mounted.difference(&contracted).collect::<Vec<_>>(),
+        Vec::<&(String, String)>::new(),
+        "mounted without an OpenAPI contract"
+    );
+    assert_eq!(
+        contracted.difference(&mounted).collect::<Vec<_>>(),
+        Vec::<&(String, String)>::new(),
+        "OpenAPI contract without a mounted route"
+    );
+}
+
+#[test]
+fn operation_ids_are_unique() {
+    let ids = CONTRACTS
+        .iter()
+        .map(|contract| contract.operation_id)
+        .collect::<BTreeSet<_>>();
+    assert_eq!(ids.len(), CONTRACTS.len());
+}
+
+#[test]
+fn document_is_openapi_3_1_and_every_reference_resolves() -> anyhow::Result<()> {
+    let document = openapi_document()?;
+    assert_eq!(document["openapi"], "3.1.0");
+    assert_eq!(
+        document["info"]["version"],
+        crate::build_info::package_version()
+    );
+    let components = document["components"]["schemas"]
+        .as_object()
+        .expect("components.schemas is an object");
+
+    let mut references = Vec::new();
+    collect_references(&document, &mut references);
+    assert!(!references.is_empty());
+    for reference in references {
+        let name = reference
+            .strip_prefix("#/components/schemas/")
+            .unwrap_or_else(|| panic!("unexpected $ref {reference}"));
+        assert!(components.contains_key(name), "dangling $ref {reference}");
+    }
+
+    let operations = document["paths"]
+        .as_object()
+        .expect("paths is an object")
+        .values()
+        .flat_map(|item| item.as_object().expect("path item").values())
+        .collect::<Vec<_>>();
+    assert_eq!(operations.len(), CONTRACTS.len());
+    for operation in operations {
+        assert!(operation["responses"]["default"].is_object(), "{operation}");
+    }
+    Ok(())
+}
+
+#[test]
+fn path_and_query_parameters_come_from_route_templates_and_extractors() -> anyhow::Result<()> {
+    let document = openapi_document()?;
+    let candidate = &document["paths"]["/api/v1/candidates/{id}/review/approve"]["post"];
+    assert_eq!(candidate["parameters"][0]["name"], "id");
+    assert_eq!(candidate["parameters"][0]["in"], "path");
+    assert_eq!(candidate["requestBody"]["required"], true);
+    let body_schema = &candidate["requestBody"]["content"]["application/json"]["schema"];
+    assert_eq!(
+        body_schema["$ref"],
+        "#/components/schemas/CandidateSafeApproveRequest"
+    );
+    let approve = &document["components"]["schemas"]["CandidateSafeApproveRequest"];
+    assert_eq!(approve["additionalProperties"], false);
+    assert!(approve["required"]
+        .as_array()
+        .is_some_and(|required| required.iter().any(|field| field == "idempotency_key")));
+
+    let search = document["paths"]["/api/v1/search"]["get"]["parameters"]
+        .as_array()
+        .expect("search has query parameters");
+    let memory_type = search
+        .iter()
+        .find(|parameter| parameter["name"] == "type")
+        .expect("serde rename is honoured");
+    assert_eq!(memory_type["in"], "query");
+    assert_eq!(memory_type["required"], false);
+    assert_eq!(memory_type["schema"]["type"], "string");
+
+    let save = &document["paths"]["/api/v1/memories"]["post"]["responses"];
+    assert!(save["201"].is_object());
+    assert!(save.get("200").is_none());
+    Ok(())
+}
+
+fn collect_references<'a>(value: &'a Value, references: &mut Vec<&'a str>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                match (key.as_str(), value) {
+                    ("$ref", Value::String(reference)) => references.push(reference),
+                    _ => collect_references(value, references),
+                }
+            }
+        }
+        Value::Array(items) => items
+            .iter()
+            .for_each(|item| collect_references(item, references)),
+        _ => {}
+    }
+}
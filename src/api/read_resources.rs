@@ -3,6 +3,7 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
+use rmcp::schemars::{self, JsonSchema};
 use rusqlite::Connection;
 use serde::{Deserialize, Serialize};
 
@@ -17,7 +18,7 @@ const MAX_RAW_SCAN_BUDGET: usize = 1000;
 const RAW_BATCH_SIZE: usize = 100;
 const MAX_VISIBLE_CHARS: usize = 240;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub(super) struct ReadResourceParams {
     pub project: Option<String>,
     pub status: Option<String>,
@@ -64,7 +65,7 @@ pub(super) struct ResourceProjectionPolicy {
 }
 
 impl ResourceProjectionPolicy {
-    fn load(conn: &Connection) -> anyhow::Result<Self> {
+    pub(super) fn load(conn: &Connection) -> anyhow::Result<Self> {
         let mut stmt = conn.prepare(
             "SELECT target_kind, target_id, target_value
              FROM memory_suppressions WHERE status = 'active' ORDER BY id",
@@ -334,7 +335,7 @@ fn parse_page_size(raw: Option<&str>) -> Result<i64, ()> {
         .map_err(|_| ())
 }
 
-fn stable_error(status: StatusCode, code: &str) -> Response {
+pub(super) fn stable_error(status: StatusCode, code: &str) -> Response {
     error_response(status, code, code).into_response()
 }
 
@@ -7,22 +7,27 @@ use axum::{
 use super::auth::{ensure_api_token, require_api_token};
 use super::handlers::{
     handle_activity_sessions, handle_approve_candidate, handle_archive_memory,
-    handle_blocked_candidates, handle_candidate_detail, handle_capabilities, handle_edit_candidate,
-    handle_event_detail, handle_get_memory, handle_graph, handle_health, handle_list_candidates,
-    handle_list_events, handle_list_memories, handle_list_observations,
-    handle_list_session_activity, handle_list_sessions, handle_list_tasks, handle_list_workstreams,
-    handle_memory_detail, handle_observation_detail, handle_project_session_activity,
-    handle_reject_candidate, handle_restore_memory, handle_safe_approve_candidate,
-    handle_safe_edit_candidate, handle_safe_reject_candidate, handle_save_memory, handle_search,
+    handle_blocked_candidates, handle_candidate_detail, handle_capabilities,
+    handle_create_workstream, handle_edit_candidate, handle_event_detail, handle_event_stream,
+    handle_get_memory, handle_graph, handle_health, handle_injection_detail,
+    handle_list_candidates, handle_list_events, handle_list_injections, handle_list_memories,
+    handle_list_observations, handle_list_session_activity, handle_list_sessions,
+    handle_list_tasks, handle_list_workstreams, handle_memory_detail, handle_observation_detail,
+    handle_openapi, handle_project_session_activity, handle_reject_candidate,
+    handle_restore_memory, handle_safe_approve_candidate, handle_safe_edit_candidate,
+    handle_safe_reject_candidate, handle_save_memory, handle_search,
     handle_session_activity_detail, handle_session_activity_stats, handle_session_detail,
-    handle_stats, handle_status, handle_task_detail, handle_user_recall, handle_workstream_detail,
+    handle_stats, handle_status, handle_task_detail, handle_timeline_report,
+    handle_update_workstream_plan, handle_user_recall, handle_workstream_detail,
 };
 use super::types::{DbState, StatusCache};
+use super::ui::ui_router;
 
 pub fn build_router(_port: u16) -> Router<DbState> {
     Router::new()
         .route("/api/v1/health", get(handle_health))
         .route("/api/v1/capabilities", get(handle_capabilities))
+        .route("/api/v1/openapi.json", get(handle_openapi))
         .route("/api/v1/search", get(handle_search))
         .route("/api/v1/memory", get(handle_get_memory))
         .route(
@@ -80,20 +85,33 @@ pub fn build_router(_port: u16) -> Router<DbState> {
             get(handle_session_activity_detail),
         )
         .route("/api/v1/session-stats", get(handle_session_activity_stats))
-        .route("/api/v1/workstreams", get(handle_list_workstreams))
+        .route(
+            "/api/v1/workstreams",
+            get(handle_list_workstreams).post(handle_create_workstream),
+        )
         .route("/api/v1/workstreams/{id}", get(handle_workstream_detail))
+        .route(
+            "/api/v1/workstreams/{id}/plan",
+            post(handle_update_workstream_plan),
+        )
         .route("/api/v1/events", get(handle_list_events))
         .route("/api/v1/events/{id}", get(handle_event_detail))
+        .route("/api/v1/stream", get(handle_event_stream))
         .route("/api/v1/tasks", get(handle_list_tasks))
         .route("/api/v1/tasks/{id}", get(handle_task_detail))
+        .route("/api/v1/injections", get(handle_list_injections))
+        .route("/api/v1/injections/{id}", get(handle_injection_detail))
         .route("/api/v1/graph", get(handle_graph))
+        .route("/api/v1/timeline/report", get(handle_timeline_report))
         .route("/api/v1/stats", get(handle_stats))
         .route_layer(middleware::from_fn(require_api_token))
+        .merge(ui_router())
         .layer(Extension(StatusCache::default()))
 }
 
 pub async fn run_api_server(port: u16) -> anyhow::Result<()> {
     let token_path = ensure_api_token()?;
+    let _api_lock = crate::db::key_rotation::hold_api_lock()?;
     let app = build_router(port).with_state(DbState);
     let addr = format!("127.0.0.1:{}", port);
 
@@ -107,6 +125,7 @@ pub async fn run_api_server(port: u16) -> anyhow::Result<()> {
         "API token: Authorization: Bearer $(cat {})",
         token_path.display()
     );
+    println!("Web UI: http://{addr}/ui/ (paste the API token when asked)");
 
     let listener = tokio::net::TcpListener::bind(&addr).await?;
     axum::serve(listener, app).await?;
@@ -25,10 +25,14 @@ use super::DbState;
 mod candidate_review_poisoning;
 mod candidate_safe_review;
 mod candidates;
+mod event_stream;
 mod memory_governance;
+mod openapi_contract;
 mod read_resources;
+mod scoped_tokens;
 mod session_activity;
 mod web_regressions;
+mod web_ui;
 
 fn authorized_request(method: Method, uri: &str, token: &str, body: Body) -> Request<Body> {
     Request::builder()
@@ -443,6 +447,11 @@ async fn router_serves_capabilities_with_auth() -> anyhow::Result<()> {
     assert_eq!(payload["features"]["candidate_filters"], true);
     assert_eq!(payload["features"]["candidate_review"], true);
     assert_eq!(payload["features"]["graph"], true);
+    assert_eq!(payload["features"]["openapi"], true);
+    assert_eq!(payload["features"]["injections"], true);
+    assert_eq!(payload["features"]["web_ui"], true);
+    assert_eq!(payload["features"]["workstream_plans"], true);
+    assert_eq!(payload["features"]["timeline_report"], true);
     assert_eq!(payload["features"]["user_recall"], true);
     assert_eq!(payload["features"]["user_recall_usage_policy"], true);
     assert_eq!(payload["endpoints"]["health"], "/api/v1/health");
@@ -469,6 +478,17 @@ async fn router_serves_capabilities_with_auth() -> anyhow::Result<()> {
         "/api/v1/candidates/{id}/approve"
     );
     assert_eq!(payload["endpoints"]["graph"], "/api/v1/graph");
+    assert_eq!(payload["endpoints"]["openapi"], "/api/v1/openapi.json");
+    assert_eq!(payload["endpoints"]["injections"], "/api/v1/injections");
+    assert_eq!(payload["endpoints"]["web_ui"], "/ui/");
+    assert_eq!(
+        payload["endpoints"]["timeline_report"],
+        "/api/v1/timeline/report"
+    );
+    assert_eq!(
+        payload["endpoints"]["workstream_plan"],
+        "/api/v1/workstreams/{id}/plan"
+    );
     assert_eq!(payload["endpoints"]["user_recall"], "/api/v1/user/recall");
     assert!(payload.get("token").is_none());
 
@@ -0,0 +1,331 @@
+use std::time::Duration;
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, Method, Request, StatusCode},
+    Router,
+};
+use futures_util::StreamExt;
+use rusqlite::params;
+use serde_json::Value;
+use tower::ServiceExt;
+
+use crate::db::test_support::ScopedTestDataDir;
+use crate::{db, memory};
+
+use super::{authorized_request, insert_review_candidate, DbState};
+
+const SECRET_SENTINEL: &str = "token=stream-super-secret";
+
+struct SseFrame {
+    event: String,
+    id: Option<String>,
+    data: Value,
+}
+
+struct SseReader {
+    body: axum::body::BodyDataStream,
+    buffer: String,
+}
+
+impl SseReader {
+    async fn open(app: &Router, request: Request<Body>) -> anyhow::Result<Self> {
+        let response = app.clone().oneshot(request).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "text/event-stream"
+        );
+        Ok(Self {
+            body: response.into_body().into_data_stream(),
+            buffer: String::new(),
+        })
+    }
+
+    /// Returns the next named event, skipping keep-alive comments.
+    async fn next_event(&mut self) -> anyhow::Result<SseFrame> {
+        loop {
+            if let Some(end) = self.buffer.find("\n\n") {
+                let block = self.buffer[..end].to_string();
+                self.buffer.drain(..end + 2);
+                let mut event = None;
+                let mut id = None;
+                let mut data = String::new();
+                for line in block.lines() {
+                    if let Some(value) = line.strip_prefix("event: ") {
+                        event = Some(value.to_string());
+                    } else if let Some(value) = line.strip_prefix("id: ") {
+                        id = Some(value.to_string());
+                    } else if let Some(value) = line.strip_prefix("data: ") {
+                        data.push_str(value);
+                    }
+                }
+                if let Some(event) = event {
+                    let data = serde_json::from_str(&data)?;
+                    crate::api::openapi::validate_stream_event(&event, &data)?;
+                    return Ok(SseFrame { event, id, data });
+                }
+                continue;
+            }
+            let chunk = tokio::time::timeout(Duration::from_secs(10), self.body.next())
+                .await
+                .map_err(|_| anyhow::anyhow!("timed out waiting for stream event"))?
+                .ok_or_else(|| anyhow::anyhow!("stream ended"))??;
+            self.buffer.push_str(std::str::from_utf8(&chunk)?);
+        }
+    }
+}
+
+fn stream_request(uri: &str, token: &str, last_event_id: Option<&str>) -> Request<Body> {
+    let mut request = authorized_request(Method::GET, uri, token, Body::empty());
+    if let Some(id) = last_event_id {
+        request
+            .headers_mut()
+            .insert("last-event-id", id.parse().expect("header value"));
+    }
+    request
+}
+
+#[tokio::test]
+async fn event_stream_delivers_typed_events_and_resumes_from_last_event_id() -> anyhow::Result<()> {
+    let _test_dir = ScopedTestDataDir::new("api-event-stream-resume");
+    crate::api::ensure_api_token()?;
+    let token = crate::api::load_api_token()?;
+    let app = super::super::build_router(0).with_state(DbState);
+    let conn = db::open_db()?;
+
+    let mut live = SseReader::open(&app, stream_request("/api/v1/stream", &token, None)).await?;
+    let ready = live.next_event().await?;
+    assert_eq!(ready.event, "stream_ready");
+
+    let memory_id = memory::insert_memory(
+        &conn,
+        Some("session-stream"),
+        "proj-stream",
+        None,
+        SECRET_SENTINEL,
+        "stream memory body",
+        "decision",
+        None,
+    )?;
+    let saved = live.next_event().await?;
+    assert_eq!(saved.event, "memory_saved");
+    assert_eq!(saved.data["resource"]["kind"], "memory");
+    assert_eq!(saved.data["resource"]["id"], memory_id);
+    assert_eq!(saved.data["project"], "proj-stream");
+    assert_eq!(saved.data["data"]["change"], "created");
+    assert!(
+        !saved.data.to_string().contains("stream-super-secret"),
+        "{}",
+        saved.data
+    );
+    let saved_cursor = saved.id.expect("memory event carries a cursor");
+
+    let candidate_id = insert_review_candidate("stream-topic", "stream candidate")?;
+    conn.execute(
+        "UPDATE memory_candidates SET review_status = 'rejected' WHERE id = ?1",
+        params![candidate_id],
+    )?;
+    let created = live.next_event().await?;
+    assert_eq!(created.event, "candidate_created");
+    assert_eq!(created.data["resource"]["id"], candidate_id);
+    let reviewed = live.next_event().await?;
+    assert_eq!(reviewed.event, "candidate_reviewed");
+    assert_eq!(reviewed.data["data"]["previous_status"], "pending_review");
+    assert_eq!(reviewed.data["data"]["review_status"], "rejected");
+    drop(live);
+
+    let mut resumed = SseReader::open(
+        &app,
+        stream_request("/api/v1/stream", &token, Some(&saved_cursor)),
+    )
+    .await?;
+    assert_eq!(resumed.next_event().await?.event, "stream_ready");
+    assert_eq!(resumed.next_event().await?.event, "candidate_created");
+    assert_eq!(resumed.next_event().await?.event, "candidate_reviewed");
+
+    let filtered = format!("/api/v1/stream?types=candidate_reviewed&cursor={saved_cursor}");
+    let response = app
+        .clone()
+        .oneshot(stream_request(&filtered, &token, None))
+        .await?;
+    assert_eq!(
+        response.status(),
+        StatusCode::BAD_REQUEST,
+        "a cursor is bound to the filters it was issued for"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn event_stream_applies_suppressions_added_while_open() -> anyhow::Result<()> {
+    let _test_dir = ScopedTestDataDir::new("api-event-stream-suppression");
+    crate::api::ensure_api_token()?;
+    let token = crate::api::load_api_token()?;
+    let app = super::super::build_router(0).with_state(DbState);
+    let conn = db::open_db()?;
+
+    let mut live = SseReader::open(&app, stream_request("/api/v1/stream", &token, None)).await?;
+    assert_eq!(live.next_event().await?.event, "stream_ready");
+
+    let now = chrono::Utc::now().timestamp();
+    conn.execute(
+        "INSERT INTO memory_suppressions
+         (target_kind, target_value, reason, actor, status, created_at_epoch, updated_at_epoch)
+         VALUES ('pattern', 'hidden-stream-pattern', 'test', 'test', 'active', ?1, ?1)",
+        params![now],
+    )?;
+    memory::insert_memory(
+        &conn,
+        Some("session-stream"),
+        "proj-stream",
+        None,
+        "hidden-stream-pattern title",
+        "suppressed body",
+        "decision",
+        None,
+    )?;
+    let visible_id = memory::insert_memory(
+        &conn,
+        Some("session-stream"),
+        "proj-stream",
+        None,
+        "Visible stream memory",
+        "visible body",
+        "decision",
+        None,
+    )?;
+    let saved = live.next_event().await?;
+    assert_eq!(saved.event, "memory_saved");
+    assert_eq!(
+        saved.data["resource"]["id"], visible_id,
+        "the suppressed memory must not be published"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn event_stream_requires_auth_and_rejects_bad_filters() -> anyhow::Result<()> {
+    let _test_dir = ScopedTestDataDir::new("api-event-stream-errors");
+    crate::api::ensure_api_token()?;
+    let token = crate::api::load_api_token()?;
+    let app = super::super::build_router(0).with_state(DbState);
+
+    let missing = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(Method::GET)
+                .uri("/api/v1/stream")
+                .body(Body::empty())?,
+        )
+        .await?;
+    assert_eq!(missing.status(), StatusCode::UNAUTHORIZED);
+
+    for (uri, code) in [
+        (
+            "/api/v1/stream?types=memory_saved,bogus",
+            "event_type_invalid",
+        ),
+        ("/api/v1/stream?cursor=not-a-cursor", "cursor_invalid"),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(stream_request(uri, &token, None))
+            .await?;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{uri}");
+        let payload: Value = serde_json::from_slice(&to_bytes(response.into_body(), 4096).await?)?;
+        assert_eq!(payload["error"]["code"], code, "{uri}");
+    }
+    Ok(())
+}
+
+#[test]
+fn feed_triggers_record_workstream_job_and_injection_transitions() -> anyhow::Result<()> {
+    let conn = rusqlite::Connection::open_in_memory()?;
+    crate::migrate::run_migrations(&conn)?;
+    conn.execute(
+        "INSERT INTO workstreams (project, title, status, created_at_epoch, updated_at_epoch)
+         VALUES ('proj-feed', 'Ship stream', 'active', 1, 1)",
+        [],
+    )?;
+    let workstream_id = conn.last_insert_rowid();
+    conn.execute(
+        "UPDATE workstreams SET status = 'completed', progress = 'done' WHERE id = ?1",
+        params![workstream_id],
+    )?;
+    conn.execute(
+        "UPDATE workstreams SET progress = 'still done' WHERE id = ?1",
+        params![workstream_id],
+    )?;
+    conn.execute(
+        "INSERT INTO jobs (job_type, project, payload_json, state, created_at_epoch, updated_at_epoch)
+         VALUES ('observation', 'proj-feed', '{}', 'pending', 1, 1)",
+        [],
+    )?;
+    let job_id = conn.last_insert_rowid();
+    conn.execute(
+        "UPDATE jobs SET state = 'processing' WHERE id = ?1",
+        params![job_id],
+    )?;
+    conn.execute(
+        "UPDATE jobs SET state = 'done' WHERE id = ?1",
+        params![job_id],
+    )?;
+
+    conn.execute(
+        "INSERT INTO context_injections
+          (host, project, injection_key, context_hash, output_mode, output_chars,
+           created_at_epoch, updated_at_epoch, last_emitted_epoch)
+         VALUES ('claude-code', 'proj-feed', 'session-1', 'hash', 'full', 120, 1, 1, 1)",
+        [],
+    )?;
+    let injection_id = conn.last_insert_rowid();
+    conn.execute(
+        "UPDATE context_injections SET suppress_count = suppress_count + 1 WHERE id = ?1",
+        params![injection_id],
+    )?;
+
+    let mut stmt = conn.prepare(
+        "SELECT event_type, resource_id, payload_json FROM api_stream_events ORDER BY id",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, i64>(1)?,
+            row.get::<_, String>(2)?,
+        ))
+    })?;
+    let rows = crate::db::query::collect_rows(rows)?;
+    let summary = rows
+        .iter()
+        .map(|(event_type, id, payload)| {
+            let payload: Value = serde_json::from_str(payload).expect("payload json");
+            (
+                event_type.as_str(),
+                *id,
+                payload["status"]
+                    .as_str()
+                    .or(payload["state"].as_str())
+                    .or(payload["decision"].as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        vec![
+            ("workstream_transition", workstream_id, "active".to_string()),
+            (
+                "workstream_transition",
+                workstream_id,
+                "completed".to_string()
+            ),
+            ("job_completed", job_id, "done".to_string()),
+            ("injection_audit", injection_id, "emitted".to_string()),
+            ("injection_audit", injection_id, "suppressed".to_string()),
+        ]
+    );
+    Ok(())
+}
@@ -0,0 +1,446 @@
+use std::collections::BTreeSet;
+
+use anyhow::Context;
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, Method, StatusCode},
+    Router,
+};
+use rusqlite::params;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+use crate::api::openapi::{documented_operations, validate_response};
+use crate::db::test_support::ScopedTestDataDir;
+use crate::{db, memory};
+
+use super::candidate_safe_review::insert_safe_review_candidate;
+use super::read_resources::insert_fixture;
+use super::{authorized_json_request, authorized_request, DbState};
+
+/// Calls routes by their contract template and validates every JSON reply
+/// against the schema the OpenAPI document declares for it.
+struct ContractClient {
+    app: Router,
+    token: String,
+    exercised: BTreeSet<(&'static str, &'static str)>,
+}
+
+impl ContractClient {
+    async fn call(
+        &mut self,
+        method: Method,
+        template: &'static str,
+        uri: &str,
+        body: Option<Value>,
+    ) -> anyhow::Result<(StatusCode, Value)> {
+        let request = match &body {
+            Some(body) => {
+                authorized_json_request(method.clone(), uri, &self.token, &body.to_string())
+            }
+            None => authorized_request(method.clone(), uri, &self.token, Body::empty()),
+        };
+        let response = self.app.clone().oneshot(request).await?;
+        let status = response.status();
+        let payload: Value =
+            serde_json::from_slice(&to_bytes(response.into_body(), usize::MAX).await?)
+                .with_context(|| format!("{method} {uri} returned non-JSON"))?;
+        validate_response(method.as_str(), template, status.as_u16(), &payload)
+            .with_context(|| format!("{method} {uri} -> {status}: {payload}"))?;
+        self.exercised
+            .insert((if method == Method::GET { "get" } else { "post" }, template));
+        Ok((status, payload))
+    }
+
+    async fn get(
+        &mut self,
+        template: &'static str,
+        uri: &str,
+    ) -> anyhow::Result<(StatusCode, Value)> {
+        self.call(Method::GET, template, uri, None).await
+    }
+
+    async fn post(
+        &mut self,
+        template: &'static str,
+        uri: &str,
+        body: Value,
+    ) -> anyhow::Result<(StatusCode, Value)> {
+        self.call(Method::POST, template, uri, Some(body)).await
+    }
+}
+
+fn version_of(table: &str, id: i64) -> anyhow::Result<i64> {
+    let conn = db::open_db()?;
+    conn.query_row(
+        &format!("SELECT version FROM {table} WHERE id = ?1"),
+        params![id],
+        |row| row.get(0),
+    )
+    .map_err(Into::into)
+}
+
+fn seed_session_activity() -> anyhow::Result<()> {
+    db::open_db()?.execute(
+        "INSERT INTO raw_messages
+         (id, session_id, project, role, content, content_hash, source, cwd,
+          created_at_epoch, source_root, event_time_source)
+         VALUES
+         (951, 'contract-session', 'proj-contract', 'user', 'Document the API',
+          'contract-user', 'transcript', '/contract', 100, 'local', 'transcript_event'),
+         (952, 'contract-session', 'proj-contract', 'assistant', 'The API is documented.',
+          'contract-assistant', 'transcript', '/contract', 120, 'local', 'transcript_event')",
+        [],
+    )?;
+    Ok(())
+}
+
+fn first_id(payload: &Value) -> i64 {
+    payload["data"][0]["id"]
+        .as_i64()
+        .unwrap_or_else(|| panic!("page should have a row: {payload}"))
+}
+
+#[tokio::test]
+async fn live_responses_match_the_openapi_document_for_every_route() -> anyhow::Result<()> {
+    let _test_dir = ScopedTestDataDir::new("api-openapi-contract");
+    let conn = db::open_db()?;
+    let memory_id = memory::insert_memory(
+        &conn,
+        Some("session-contract"),
+        "proj-contract",
+        Some("contract-topic"),
+        "Contract memory",
+        "contract memory body",
+        "decision",
+        None,
+    )?;
+    drop(conn);
+    let mut candidates = [0; 6];
+    for (index, candidate) in candidates.iter_mut().enumerate() {
+        *candidate = insert_safe_review_candidate(
+            &format!("contract-candidate-{index}"),
+            "file_edit",
+            "contract candidate evidence",
+            &format!("contract candidate {index}"),
+        )?
+        .0;
+    }
+    insert_fixture("contract")?;
+    seed_session_activity()?;
+    crate::api::ensure_api_token()?;
+    let mut client = ContractClient {
+        app: super::super::build_router(0).with_state(DbState),
+        token: crate::api::load_api_token()?,
+        exercised: BTreeSet::new(),
+    };
+
+    for (template, uri) in [
+        ("/api/v1/health", "/api/v1/health"),
+        ("/api/v1/capabilities", "/api/v1/capabilities"),
+        ("/api/v1/openapi.json", "/api/v1/openapi.json"),
+        ("/api/v1/status", "/api/v1/status"),
+        ("/api/v1/stats", "/api/v1/stats"),
+        (
+            "/api/v1/search",
+            "/api/v1/search?query=contract&project=proj-contract&explain=true",
+        ),
+        ("/api/v1/memories", "/api/v1/memories?project=proj-contract"),
+        ("/api/v1/memories/list", "/api/v1/memories/list?limit=1"),
+        ("/api/v1/candidates", "/api/v1/candidates"),
+        ("/api/v1/candidates/blocked", "/api/v1/candidates/blocked"),
+        ("/api/v1/graph", "/api/v1/graph?project=proj-contract"),
+        ("/api/v1/session-stats", "/api/v1/session-stats"),
+    ] {
+        let (status, _) = client.get(template, uri).await?;
+        assert_eq!(status, StatusCode::OK, "{uri}");
+    }
+
+    let (status, _) = client
+        .get("/api/v1/memory", &format!("/api/v1/memory?id={memory_id}"))
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = client
+        .get(
+            "/api/v1/memories/{id}",
+            &format!("/api/v1/memories/{memory_id}"),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = client
+        .get("/api/v1/memories/{id}", "/api/v1/memories/999999")
+        .await?;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = client
+        .post(
+            "/api/v1/memories",
+            "/api/v1/memories",
+            json!({"text": "contract saved body", "project": "proj-contract", "local_copy_enabled": false}),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _) = client
+        .post(
+            "/api/v1/user/recall",
+            "/api/v1/user/recall",
+            json!({"query": "contract", "project": "proj-contract"}),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+
+    let archive_uri = format!("/api/v1/memories/{memory_id}/archive");
+    let archive = json!({
+        "reason": "contract archive",
+        "expected_version": version_of("memories", memory_id)?,
+        "idempotency_key": "contract-archive",
+    });
+    let (status, _) = client
+        .post("/api/v1/memories/{id}/archive", &archive_uri, archive)
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    let stale = json!({
+        "reason": "contract archive conflict",
+        "expected_version": version_of("memories", memory_id)? + 1,
+        "idempotency_key": "contract-archive-conflict",
+    });
+    let (status, _) = client
+        .post("/api/v1/memories/{id}/archive", &archive_uri, stale)
+        .await?;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let restore = json!({
+        "reason": "contract restore",
+        "expected_version": version_of("memories", memory_id)?,
+        "idempotency_key": "contract-restore",
+    });
+    let (status, _) = client
+        .post(
+            "/api/v1/memories/{id}/restore",
+            &format!("/api/v1/memories/{memory_id}/restore"),
+            restore,
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+
+    let [approve, reject, edit, safe_approve, safe_reject, safe_edit] = candidates;
+    let (status, _) = client
+        .get(
+            "/api/v1/candidates/{id}",
+            &format!("/api/v1/candidates/{approve}"),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = client
+        .get("/api/v1/candidates/{id}", "/api/v1/candidates/999999")
+        .await?;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    for (template, id, body) in [
+        ("/api/v1/candidates/{id}/approve", approve, json!({})),
+        ("/api/v1/candidates/{id}/reject", reject, json!({})),
+        (
+            "/api/v1/candidates/{id}/edit",
+            edit,
+            json!({"text": "contract edited"}),
+        ),
+    ] {
+        let uri = template.replace("{id}", &id.to_string());
+        let (status, _) = client.post(template, &uri, body).await?;
+        assert_eq!(status, StatusCode::OK, "{uri}");
+    }
+    for (template, id, extra) in [
+        (
+            "/api/v1/candidates/{id}/review/approve",
+            safe_approve,
+            json!({}),
+        ),
+        (
+            "/api/v1/candidates/{id}/review/reject",
+            safe_reject,
+            json!({}),
+        ),
+        (
+            "/api/v1/candidates/{id}/review/edit",
+            safe_edit,
+            json!({"text": "contract safe edited"}),
+        ),
+    ] {
+        let uri = template.replace("{id}", &id.to_string());
+        let mut body = json!({
+            "reason": "contract review",
+            "expected_version": version_of("memory_candidates", id)?,
+            "idempotency_key": format!("contract-review-{id}"),
+        });
+        body.as_object_mut()
+            .expect("review body is an object")
+            .extend(extra.as_object().cloned().unwrap_or_default());
+        let (status, payload) = client.post(template, &uri, body).await?;
+        assert_eq!(status, StatusCode::OK, "{uri}: {payload}");
+    }
+
+    for resource in [
+        "observations",
+        "sessions",
+        "workstreams",
+        "events",
+        "tasks",
+        "injections",
+    ] {
+        let list_template = documented_template("get", &format!("/api/v1/{resource}"));
+        let detail_template = documented_template("get", &format!("/api/v1/{resource}/{{id}}"));
+        let (status, page) = client
+            .get(list_template, &format!("/api/v1/{resource}?page_size=1"))
+            .await?;
+        assert_eq!(status, StatusCode::OK, "{resource}");
+        let (status, _) = client
+            .get(
+                detail_template,
+                &format!("/api/v1/{resource}/{}", first_id(&page)),
+            )
+            .await?;
+        assert_eq!(status, StatusCode::OK, "{resource}");
+        let (status, _) = client
+            .get(detail_template, &format!("/api/v1/{resource}/not-an-id"))
+            .await?;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{resource}");
+    }
+
+    let (status, parent) = client
+        .post(
+            "/api/v1/workstreams",
+            "/api/v1/workstreams",
+            json!({"project": "proj-contract", "title": "Contract rollout", "milestones": ["Draft"]}),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::CREATED, "{parent}");
+    let parent_id = parent["data"]["id"]
+        .as_i64()
+        .expect("created workstream id");
+    let (status, child) = client
+        .post(
+            "/api/v1/workstreams",
+            "/api/v1/workstreams",
+            json!({"project": "proj-contract", "title": "Contract docs", "parent_id": parent_id}),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::CREATED, "{child}");
+    let child_id = child["data"]["id"].as_i64().expect("created workstream id");
+    let (status, updated) = client
+        .post(
+            "/api/v1/workstreams/{id}/plan",
+            &format!("/api/v1/workstreams/{child_id}/plan"),
+            json!({"add_dependencies": [{"workstream_id": parent_id, "kind": "soft"}], "add_milestones": ["Review"]}),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK, "{updated}");
+    let (status, _) = client
+        .post(
+            "/api/v1/workstreams/{id}/plan",
+            &format!("/api/v1/workstreams/{parent_id}/plan"),
+            json!({"parent_id": child_id}),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _) = client
+        .post(
+            "/api/v1/workstreams/{id}/plan",
+            "/api/v1/workstreams/999999/plan",
+            json!({"add_milestones": ["Orphan"]}),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = client
+        .post(
+            "/api/v1/session-activity/project",
+            "/api/v1/session-activity/project",
+            json!({"source_root": "local", "project": "proj-contract", "session_id": "contract-session"}),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = client
+        .get(
+            "/api/v1/session-activity/sessions",
+            "/api/v1/session-activity/sessions?project=proj-contract",
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    let (status, turns) = client
+        .get(
+            "/api/v1/session-activity",
+            "/api/v1/session-activity?session_id=contract-session",
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = client
+        .get(
+            "/api/v1/session-activity/{id}",
+            &format!("/api/v1/session-activity/{}", first_id(&turns)),
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, report) = client
+        .get(
+            "/api/v1/timeline/report",
+            "/api/v1/timeline/report?project=proj-contract&full=true",
+        )
+        .await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(report["monthly_breakdown"].is_array());
+    let (status, _) = client
+        .get(
+            "/api/v1/timeline/report",
+            "/api/v1/timeline/report?project=proj-contract&format=pdf",
+        )
+        .await?;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    for (format, content_type) in [
+        ("csv", "text/csv; charset=utf-8"),
+        ("html", "text/html; charset=utf-8"),
+    ] {
+        let response = client
+            .app
+            .clone()
+            .oneshot(authorized_request(
+                Method::GET,
+                &format!("/api/v1/timeline/report?project=proj-contract&format={format}"),
+                &client.token,
+                Body::empty(),
+            ))
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], content_type);
+    }
+
+    let stream = client
+        .app
+        .clone()
+        .oneshot(authorized_request(
+            Method::GET,
+            "/api/v1/stream",
+            &client.token,
+            Body::empty(),
+        ))
+        .await?;
+    assert_eq!(stream.status(), StatusCode::OK);
+    assert_eq!(stream.headers()[header::CONTENT_TYPE], "text/event-stream");
+    client.exercised.insert(("get", "/api/v1/stream"));
+
+    let missing = documented_operations()
+        .into_iter()
+        .filter(|operation| !client.exercised.contains(operation))
+        .collect::<Vec<_>>();
+    assert!(
+        missing.is_empty(),
+        "contract routes not exercised: {missing:?}"
+    );
+    Ok(())
+}
+
+fn documented_template(method: &str, path: &str) -> &'static str {
+    documented_operations()
+        .into_iter()
+        .find(|(documented_method, template)| *documented_method == method && *template == path)
+        .map(|(_, template)| template)
+        .unwrap_or_else(|| panic!("{method} {path} is not documented"))
+}
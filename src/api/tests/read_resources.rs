@@ -15,12 +15,13 @@ use super::{authorized_request, DbState};
 const RAW_SENTINEL: &str = "RAW_TRANSCRIPT_SENTINEL_DO_NOT_EXPOSE";
 const SECRET_SENTINEL: &str = "token=web-resource-super-secret";
 
-struct Fixture {
+pub(super) struct Fixture {
     observation_id: i64,
     session_id: i64,
     workstream_id: i64,
     event_id: i64,
     task_id: i64,
+    injection_id: i64,
 }
 
 #[tokio::test]
@@ -73,6 +74,7 @@ async fn every_resource_returns_real_safe_list_and_detail_projection() -> anyhow
         ("/api/v1/workstreams", fixture.workstream_id),
         ("/api/v1/events", fixture.event_id),
         ("/api/v1/tasks", fixture.task_id),
+        ("/api/v1/injections", fixture.injection_id),
     ] {
         let (status, list) = get_json(&app, route, &token).await?;
         assert_eq!(status, StatusCode::OK, "{route}");
@@ -394,7 +396,7 @@ async fn all_five_cursors_ignore_reinserted_ids_above_the_old_boundary() -> anyh
     Ok(())
 }
 
-fn insert_fixture(name: &str) -> anyhow::Result<Fixture> {
+pub(super) fn insert_fixture(name: &str) -> anyhow::Result<Fixture> {
     let conn = db::open_db()?;
     let outcome = db::record_captured_event(
         &conn,
@@ -450,12 +452,27 @@ fn insert_fixture(name: &str) -> anyhow::Result<Fixture> {
          WHERE id = ?1",
         params![task_id, format!("{RAW_SENTINEL} {SECRET_SENTINEL}")],
     )?;
+    conn.execute(
+        "INSERT INTO context_injection_items
+         (injection_run_id, host, project, injection_key, output_mode, decision,
+          item_kind, channel, status, drop_reason, title, injected_at_epoch)
+         VALUES (?1, 'codex-cli', ?2, 'session-start', 'additional_context', 'emit',
+                 'memory', 'project', 'dropped', ?3, 'Safe injected title', ?4)",
+        params![
+            format!("{name}-run"),
+            format!("{name}-project"),
+            SECRET_SENTINEL,
+            now
+        ],
+    )?;
+    let injection_id = conn.last_insert_rowid();
     Ok(Fixture {
         observation_id,
         session_id,
         workstream_id,
         event_id: outcome.event_row_id,
         task_id,
+        injection_id,
     })
 }
 
@@ -608,13 +625,14 @@ fn assert_safe_json(payload: &Value) {
     assert!(!serialized.contains("idempotency_key"));
 }
 
-fn list_routes() -> [&'static str; 5] {
+fn list_routes() -> [&'static str; 6] {
     [
         "/api/v1/observations",
         "/api/v1/sessions",
         "/api/v1/workstreams",
         "/api/v1/events",
         "/api/v1/tasks",
+        "/api/v1/injections",
     ]
 }
 
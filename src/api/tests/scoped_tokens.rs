@@ -0,0 +1,278 @@
+use axum::{
+    body::{to_bytes, Body},
+    http::{Method, StatusCode},
+    Router,
+};
+use rusqlite::params;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+use crate::api::tokens::{create_api_token, revoke_api_token, ApiScope, NewApiToken};
+use crate::api::DbState;
+use crate::db::test_support::ScopedTestDataDir;
+use crate::{db, memory};
+
+use super::candidate_safe_review::insert_safe_review_candidate;
+use super::{authorized_json_request, authorized_request};
+
+fn router() -> anyhow::Result<Router> {
+    crate::api::ensure_api_token()?;
+    Ok(super::super::build_router(0).with_state(DbState))
+}
+
+fn named_token(
+    name: &str,
+    scope: ApiScope,
+    projects: &[&str],
+    expires_at_epoch: Option<i64>,
+) -> anyhow::Result<String> {
+    let projects: Vec<String> = projects.iter().map(|project| project.to_string()).collect();
+    create_api_token(
+        &db::open_db()?,
+        &NewApiToken {
+            name,
+            scope,
+            projects: &projects,
+            expires_at_epoch,
+        },
+        chrono::Utc::now().timestamp(),
+    )
+}
+
+async fn send(
+    app: &Router,
+    method: Method,
+    uri: &str,
+    token: &str,
+    body: Option<Value>,
+) -> anyhow::Result<(StatusCode, Value)> {
+    let request = match body {
+        Some(body) => authorized_json_request(method, uri, token, &body.to_string()),
+        None => authorized_request(method, uri, token, Body::empty()),
+    };
+    let response = app.clone().oneshot(request).await?;
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await?;
+    Ok((
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    ))
+}
+
+fn candidate_version(id: i64) -> anyhow::Result<i64> {
+    Ok(db::open_db()?.query_row(
+        "SELECT version FROM memory_candidates WHERE id = ?1",
+        params![id],
+        |row| row.get(0),
+    )?)
+}
+
+#[tokio::test]
+async fn token_scopes_gate_mutations_and_record_audit_actor() -> anyhow::Result<()> {
+    let _test_dir = ScopedTestDataDir::new("api-scoped-token-scopes");
+    let app = router()?;
+    let reader = named_token("reader", ApiScope::Read, &[], None)?;
+    let reviewer = named_token("reviewer", ApiScope::Review, &[], None)?;
+    let (candidate, _) = insert_safe_review_candidate(
+        "tokens-scope",
+        "file_edit",
+        "scoped token evidence",
+        "scoped token candidate",
+    )?;
+
+    let (status, _) = send(&app, Method::GET, "/api/v1/stats", &reader, None).await?;
+    assert_eq!(status, StatusCode::OK);
+    let (status, payload) = send(
+        &app,
+        Method::POST,
+        "/api/v1/memories",
+        &reader,
+        Some(json!({"text": "reader save", "project": "tokens-scope"})),
+    )
+    .await?;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(payload["error"]["code"], "scope_insufficient");
+
+    let review = json!({
+        "reason": "scoped review",
+        "expected_version": candidate_version(candidate)?,
+        "idempotency_key": "scoped-review-reject",
+    });
+    let uri = format!("/api/v1/candidates/{candidate}/review/reject");
+    let (status, _) = send(&app, Method::POST, &uri, &reader, Some(review.clone())).await?;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, payload) = send(&app, Method::POST, &uri, &reviewer, Some(review)).await?;
+    assert_eq!(status, StatusCode::OK, "{payload}");
+    let actor: String = db::open_db()?.query_row(
+        "SELECT review_actor FROM memory_candidates WHERE id = ?1",
+        params![candidate],
+        |row| row.get(0),
+    )?;
+    assert_eq!(actor, "api:reviewer");
+
+    let (status, payload) = send(
+        &app,
+        Method::POST,
+        "/api/v1/memories",
+        &reviewer,
+        Some(json!({"text": "reviewer save", "project": "tokens-scope"})),
+    )
+    .await?;
+    assert_eq!(status, StatusCode::FORBIDDEN, "{payload}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn project_allow_lists_restrict_queries_bodies_and_resources() -> anyhow::Result<()> {
+    let _test_dir = ScopedTestDataDir::new("api-scoped-token-projects");
+    let app = router()?;
+    let conn = db::open_db()?;
+    let allowed = memory::insert_memory(
+        &conn,
+        Some("session-allowed"),
+        "tokens-allowed",
+        None,
+        "Allowed memory",
+        "allowed memory body",
+        "decision",
+        None,
+    )?;
+    let other = memory::insert_memory(
+        &conn,
+        Some("session-other"),
+        "tokens-other",
+        None,
+        "Other memory",
+        "other memory body",
+        "decision",
+        None,
+    )?;
+    drop(conn);
+    let writer = named_token("allowed-writer", ApiScope::Write, &["tokens-allowed"], None)?;
+
+    let (status, _) = send(
+        &app,
+        Method::GET,
+        "/api/v1/memories?project=tokens-allowed",
+        &writer,
+        None,
+    )
+    .await?;
+    assert_eq!(status, StatusCode::OK);
+    let (status, payload) = send(&app, Method::GET, "/api/v1/memories", &writer, None).await?;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(payload["error"]["code"], "project_required");
+    for uri in ["/api/v1/memories?project=tokens-other", "/api/v1/stats"] {
+        let (status, payload) = send(&app, Method::GET, uri, &writer, None).await?;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{uri}");
+        assert_eq!(payload["error"]["code"], "project_forbidden", "{uri}");
+    }
+
+    let (status, _) = send(
+        &app,
+        Method::GET,
+        &format!("/api/v1/memories/{allowed}"),
+        &writer,
+        None,
+    )
+    .await?;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(
+        &app,
+        Method::GET,
+        &format!("/api/v1/memory?id={other}"),
+        &writer,
+        None,
+    )
+    .await?;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send(&app, Method::GET, "/api/v1/memories/999999", &writer, None).await?;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(
+        &app,
+        Method::POST,
+        "/api/v1/memories",
+        &writer,
+        Some(
+            json!({"text": "scoped save", "project": "tokens-other", "local_copy_enabled": false}),
+        ),
+    )
+    .await?;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, payload) = send(
+        &app,
+        Method::POST,
+        "/api/v1/memories",
+        &writer,
+        Some(json!({"text": "scoped save", "project": "tokens-allowed", "local_copy_enabled": false})),
+    )
+    .await?;
+    assert_eq!(status, StatusCode::CREATED, "{payload}");
+
+    let archive = |memory_id: i64| -> anyhow::Result<Value> {
+        let version: i64 = db::open_db()?.query_row(
+            "SELECT version FROM memories WHERE id = ?1",
+            params![memory_id],
+            |row| row.get(0),
+        )?;
+        Ok(json!({
+            "reason": "scoped archive",
+            "expected_version": version,
+            "idempotency_key": format!("scoped-archive-{memory_id}"),
+        }))
+    };
+    let (status, _) = send(
+        &app,
+        Method::POST,
+        &format!("/api/v1/memories/{other}/archive"),
+        &writer,
+        Some(archive(other)?),
+    )
+    .await?;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, payload) = send(
+        &app,
+        Method::POST,
+        &format!("/api/v1/memories/{allowed}/archive"),
+        &writer,
+        Some(archive(allowed)?),
+    )
+    .await?;
+    assert_eq!(status, StatusCode::OK, "{payload}");
+    let detail: String = db::open_db()?.query_row(
+        "SELECT detail FROM events WHERE event_type = 'memory_governance' ORDER BY id DESC",
+        [],
+        |row| row.get(0),
+    )?;
+    assert_eq!(
+        serde_json::from_str::<Value>(&detail)?["actor"],
+        "api:allowed-writer"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn revoked_expired_and_unknown_tokens_are_unauthorized() -> anyhow::Result<()> {
+    let _test_dir = ScopedTestDataDir::new("api-scoped-token-lifecycle");
+    let app = router()?;
+    let now = chrono::Utc::now().timestamp();
+    let revoked = named_token("revoked", ApiScope::Admin, &[], None)?;
+    revoke_api_token(&db::open_db()?, "revoked", now)?;
+    let expiring = named_token("expiring", ApiScope::Admin, &[], Some(now + 3600))?;
+    db::open_db()?.execute(
+        "UPDATE api_tokens SET expires_at_epoch = ?1 WHERE name = 'expiring'",
+        params![now - 1],
+    )?;
+
+    for token in [revoked.as_str(), expiring.as_str(), "remem_unknown"] {
+        let (status, payload) = send(&app, Method::GET, "/api/v1/status", token, None).await?;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(payload["error"]["code"], "unauthorized");
+    }
+
+    let file_token = crate::api::load_api_token()?;
+    let (status, _) = send(&app, Method::GET, "/api/v1/status", &file_token, None).await?;
+    assert_eq!(status, StatusCode::OK);
+    Ok(())
+}
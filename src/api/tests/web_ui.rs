@@ -0,0 +1,84 @@
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, Method, Request, StatusCode},
+    Router,
+};
+use tower::ServiceExt;
+
+use crate::api::DbState;
+use crate::db::test_support::ScopedTestDataDir;
+
+async fn get(app: &Router, uri: &str) -> anyhow::Result<(StatusCode, header::HeaderMap, String)> {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(Method::GET)
+                .uri(uri)
+                .body(Body::empty())?,
+        )
+        .await?;
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = to_bytes(response.into_body(), usize::MAX).await?;
+    Ok((status, headers, String::from_utf8(body.to_vec())?))
+}
+
+#[tokio::test]
+async fn dashboard_shell_is_embedded_and_locked_down_without_a_token() -> anyhow::Result<()> {
+    let _test_dir = ScopedTestDataDir::new("api-web-ui");
+    crate::api::ensure_api_token()?;
+    let token = crate::api::load_api_token()?;
+    let app = super::super::build_router(0).with_state(DbState);
+
+    let (status, headers, _) = get(&app, "/ui").await?;
+    assert_eq!(status, StatusCode::PERMANENT_REDIRECT);
+    assert_eq!(headers[header::LOCATION], "/ui/");
+
+    for (uri, content_type, marker) in [
+        ("/ui/", "text/html; charset=utf-8", "/ui/assets/app.js"),
+        (
+            "/ui/assets/app.js",
+            "text/javascript; charset=utf-8",
+            "/candidates/${id}/review/${action}",
+        ),
+        ("/ui/assets/app.css", "text/css; charset=utf-8", ".diff"),
+    ] {
+        let (status, headers, body) = get(&app, uri).await?;
+        assert_eq!(status, StatusCode::OK, "{uri}");
+        assert_eq!(headers[header::CONTENT_TYPE], content_type, "{uri}");
+        let policy = headers[header::CONTENT_SECURITY_POLICY].to_str()?;
+        assert!(policy.contains("script-src 'self'"), "{uri}");
+        assert!(policy.contains("frame-ancestors 'none'"), "{uri}");
+        assert_eq!(headers[header::X_CONTENT_TYPE_OPTIONS], "nosniff");
+        assert!(body.contains(marker), "{uri}");
+        assert!(!body.contains(&token), "{uri} must not embed the API token");
+    }
+
+    let (status, _, body) = get(&app, "/ui/assets/../../.api-token").await?;
+    assert_ne!(status, StatusCode::OK);
+    assert!(!body.contains(&token));
+    let (status, _, body) = get(&app, "/ui/assets/missing.js").await?;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(body.contains("ui_asset_not_found"));
+
+    let (status, _, _) = get(&app, "/api/v1/candidates").await?;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    Ok(())
+}
+
+#[test]
+fn dashboard_only_calls_documented_api_routes() {
+    let script = include_str!("../ui/app.js");
+    let documented = crate::api::openapi::documented_operations();
+    for call in script.split("api(`").skip(1) {
+        let path = call.split(['`', '$']).next().unwrap_or_default();
+        let path = format!("/api/v1{}", path.trim_end_matches('/'));
+        assert!(
+            documented
+                .iter()
+                .any(|(_, template)| *template == path || template.starts_with(&format!("{path}/"))),
+            "dashboard calls undocumented route {path}"
+        );
+    }
+}
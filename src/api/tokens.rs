@@ -0,0 +1,378 @@
+//! Named, scoped API tokens (`remem api tokens`).
+//!
+//! The `.api-token` file remains the unrestricted admin credential that
+//! hooks, installs and the MCP HTTP transport already use. Named tokens add
+//! least-privilege access: a scope, an optional project allow-list and an
+//! optional expiry. Only the SHA-256 of each token is stored; the plaintext
+//! is returned once by [`create_api_token`].
+
+use anyhow::{anyhow, bail, Context, Result};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+/// Prefix that tells named tokens apart from the 64-hex `.api-token` value.
+pub(super) const NAMED_TOKEN_PREFIX: &str = "remem_";
+const NAMED_TOKEN_BYTES: usize = 32;
+const MAX_TOKEN_NAME_LEN: usize = 64;
+/// Name reserved for the `.api-token` file credential in audit actors.
+const FILE_TOKEN_NAME: &str = "default";
+/// `last_used_at_epoch` is refreshed at most this often per token.
+const LAST_USED_RESOLUTION_SECS: i64 = 60;
+
+/// Permission level of a token. Each scope includes the ones before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ApiScope {
+    /// Every GET route plus read-only `POST /api/v1/user/recall`.
+    Read,
+    /// Candidate approve, reject and edit.
+    Review,
+    /// Memory saves, archive and restore, session-activity projection.
+    Write,
+    /// Everything, including MCP over HTTP.
+    Admin,
+}
+
+impl ApiScope {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Review => "review",
+            Self::Write => "write",
+            Self::Admin => "admin",
+        }
+    }
+
+    fn parse(value: &str) -> Result<Self> {
+        match value {
+            "read" => Ok(Self::Read),
+            "review" => Ok(Self::Review),
+            "write" => Ok(Self::Write),
+            "admin" => Ok(Self::Admin),
+            other => bail!("unknown API token scope {other:?}"),
+        }
+    }
+}
+
+/// The authenticated caller, attached to each request by `require_api_token`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ApiPrincipal {
+    pub name: String,
+    pub scope: ApiScope,
+    /// `None` allows every project.
+    pub projects: Option<Vec<String>>,
+}
+
+impl ApiPrincipal {
+    pub(crate) fn file_token() -> Self {
+        Self {
+            name: FILE_TOKEN_NAME.to_string(),
+            scope: ApiScope::Admin,
+            projects: None,
+        }
+    }
+
+    /// Actor recorded in governance and review audits. The file token keeps
+    /// the historical `api` actor so existing audit rows stay comparable.
+    pub(crate) fn audit_actor(&self) -> String {
+        if self.name == FILE_TOKEN_NAME {
+            "api".to_string()
+        } else {
+            format!("api:{}", self.name)
+        }
+    }
+
+    /// True when any of `identifiers` (a resource's project key or path)
+    /// is on the allow-list.
+    pub(crate) fn allows_any_project(&self, identifiers: &[String]) -> bool {
+        match &self.projects {
+            None => true,
+            Some(allowed) => identifiers
+                .iter()
+                .any(|identifier| allowed.iter().any(|project| project == identifier)),
+        }
+    }
+}
+
+pub(crate) struct NewApiToken<'a> {
+    pub name: &'a str,
+    pub scope: ApiScope,
+    pub projects: &'a [String],
+    pub expires_at_epoch: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct ApiTokenRow {
+    pub name: String,
+    pub scope: ApiScope,
+    pub projects: Option<Vec<String>>,
+    pub created_at_epoch: i64,
+    pub expires_at_epoch: Option<i64>,
+    pub revoked_at_epoch: Option<i64>,
+    pub last_used_at_epoch: Option<i64>,
+}
+
+/// Stores a new token and returns its plaintext, which is not recoverable.
+pub(crate) fn create_api_token(
+    conn: &Connection,
+    token: &NewApiToken<'_>,
+    now_epoch: i64,
+) -> Result<String> {
+    validate_token_name(token.name)?;
+    if token
+        .expires_at_epoch
+        .is_some_and(|expires| expires <= now_epoch)
+    {
+        bail!("API token expiry must be in the future");
+    }
+    let projects = normalize_projects(token.projects)?;
+    let exists: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM api_tokens WHERE name = ?1)",
+        params![token.name],
+        |row| row.get(0),
+    )?;
+    if exists {
+        bail!("API token {:?} already exists", token.name);
+    }
+    let plaintext = generate_named_token()?;
+    conn.execute(
+        "INSERT INTO api_tokens
+         (name, token_sha256, scope, projects_json, created_at_epoch, expires_at_epoch)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            token.name,
+            token_sha256(&plaintext),
+            token.scope.as_str(),
+            projects
+                .map(|projects| serde_json::to_string(&projects))
+                .transpose()?,
+            now_epoch,
+            token.expires_at_epoch,
+        ],
+    )
+    .with_context(|| format!("store API token {:?}", token.name))?;
+    Ok(plaintext)
+}
+
+pub(crate) fn list_api_tokens(conn: &Connection) -> Result<Vec<ApiTokenRow>> {
+    let mut stmt = conn.prepare(
+        "SELECT name, scope, projects_json, created_at_epoch, expires_at_epoch,
+                revoked_at_epoch, last_used_at_epoch
+         FROM api_tokens ORDER BY name",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, Option<String>>(2)?,
+            row.get::<_, i64>(3)?,
+            row.get::<_, Option<i64>>(4)?,
+            row.get::<_, Option<i64>>(5)?,
+            row.get::<_, Option<i64>>(6)?,
+        ))
+    })?;
+    rows.map(|row| {
+        let (name, scope, projects, created, expires, revoked, last_used) = row?;
+        Ok(ApiTokenRow {
+            scope: ApiScope::parse(&scope)?,
+            projects: parse_projects(projects.as_deref())
+                .with_context(|| format!("API token {name:?} projects"))?,
+            name,
+            created_at_epoch: created,
+            expires_at_epoch: expires,
+            revoked_at_epoch: revoked,
+            last_used_at_epoch: last_used,
+        })
+    })
+    .collect()
+}
+
+/// Revokes `name`; returns false when no active token has that name.
+pub(crate) fn revoke_api_token(conn: &Connection, name: &str, now_epoch: i64) -> Result<bool> {
+    let changed = conn.execute(
+        "UPDATE api_tokens SET revoked_at_epoch = ?2
+         WHERE name = ?1 AND revoked_at_epoch IS NULL",
+        params![name, now_epoch],
+    )?;
+    Ok(changed == 1)
+}
+
+/// Resolves a presented bearer value to an active named token.
+pub(crate) fn resolve_api_token(
+    conn: &Connection,
+    presented: &str,
+    now_epoch: i64,
+) -> Result<Option<ApiPrincipal>> {
+    let row = conn
+        .query_row(
+            "SELECT id, name, scope, projects_json, last_used_at_epoch FROM api_tokens
+             WHERE token_sha256 = ?1
+               AND revoked_at_epoch IS NULL
+               AND (expires_at_epoch IS NULL OR expires_at_epoch > ?2)",
+            params![token_sha256(presented), now_epoch],
+            |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                    row.get::<_, Option<i64>>(4)?,
+                ))
+            },
+        )
+        .optional()?;
+    let Some((id, name, scope, projects, last_used)) = row else {
+        return Ok(None);
+    };
+    if last_used.is_none_or(|last_used| now_epoch - last_used >= LAST_USED_RESOLUTION_SECS) {
+        conn.execute(
+            "UPDATE api_tokens SET last_used_at_epoch = ?2 WHERE id = ?1",
+            params![id, now_epoch],
+        )?;
+    }
+    Ok(Some(ApiPrincipal {
+        scope: ApiScope::parse(&scope)?,
+        projects: parse_projects(projects.as_deref())?,
+        name,
+    }))
+}
+
+fn validate_token_name(name: &str) -> Result<()> {
+    let valid_chars = name
+        .bytes()
+        .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || b"-_.".contains(&byte));
+    if name.is_empty()
+        || name.len() > MAX_TOKEN_NAME_LEN
+        || !valid_chars
+        || !name.as_bytes()[0].is_ascii_alphanumeric()
+    {
+        bail!(
+            "API token name must be 1-{MAX_TOKEN_NAME_LEN} characters of a-z, 0-9, '-', '_' or '.', \
+             starting with a letter or digit"
+        );
+    }
+    if name == FILE_TOKEN_NAME {
+        bail!("API token name {FILE_TOKEN_NAME:?} is reserved for the .api-token file");
+    }
+    Ok(())
+}
+
+fn normalize_projects(projects: &[String]) -> Result<Option<Vec<String>>> {
+    if projects.is_empty() {
+        return Ok(None);
+    }
+    let mut normalized = Vec::with_capacity(projects.len());
+    for project in projects {
+        let project = project.trim();
+        if project.is_empty() {
+            bail!("API token project allow-list entries must not be empty");
+        }
+        if !normalized.iter().any(|existing| existing == project) {
+            normalized.push(project.to_string());
+        }
+    }
+    Ok(Some(normalized))
+}
+
+fn parse_projects(projects_json: Option<&str>) -> Result<Option<Vec<String>>> {
+    projects_json
+        .map(|json| {
+            serde_json::from_str(json).map_err(|err| anyhow!("invalid projects_json: {err}"))
+        })
+        .transpose()
+}
+
+fn generate_named_token() -> Result<String> {
+    let mut bytes = [0u8; NAMED_TOKEN_BYTES];
+    getrandom::fill(&mut bytes)
+        .map_err(|err| anyhow!("OS randomness unavailable while generating API token: {err}"))?;
+    let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+    Ok(format!("{NAMED_TOKEN_PREFIX}{hex}"))
+}
+
+fn token_sha256(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::ScopedTestDataDir;
+
+    fn new_token<'a>(name: &'a str, scope: ApiScope, projects: &'a [String]) -> NewApiToken<'a> {
+        NewApiToken {
+            name,
+            scope,
+            projects,
+            expires_at_epoch: None,
+        }
+    }
+
+    #[test]
+    fn created_tokens_are_stored_hashed_and_resolve_until_revoked() -> Result<()> {
+        let _test_dir = ScopedTestDataDir::new("api-tokens-lifecycle");
+        let conn = crate::db::open_db()?;
+        let projects = vec!["/repo/a".to_string(), " /repo/a ".to_string()];
+        let plaintext = create_api_token(
+            &conn,
+            &new_token("ci-reader", ApiScope::Read, &projects),
+            100,
+        )?;
+        assert!(plaintext.starts_with(NAMED_TOKEN_PREFIX));
+
+        let stored: String =
+            conn.query_row("SELECT token_sha256 FROM api_tokens", [], |row| row.get(0))?;
+        assert_ne!(stored, plaintext);
+        assert!(!stored.contains(&plaintext[NAMED_TOKEN_PREFIX.len()..]));
+
+        let principal = resolve_api_token(&conn, &plaintext, 200)?.expect("token resolves");
+        assert_eq!(principal.name, "ci-reader");
+        assert_eq!(principal.scope, ApiScope::Read);
+        assert_eq!(principal.projects, Some(vec!["/repo/a".to_string()]));
+        assert_eq!(principal.audit_actor(), "api:ci-reader");
+        assert_eq!(list_api_tokens(&conn)?[0].last_used_at_epoch, Some(200));
+
+        assert!(resolve_api_token(&conn, "remem_not-a-token", 200)?.is_none());
+        assert!(revoke_api_token(&conn, "ci-reader", 300)?);
+        assert!(!revoke_api_token(&conn, "ci-reader", 301)?);
+        assert!(resolve_api_token(&conn, &plaintext, 400)?.is_none());
+        assert_eq!(list_api_tokens(&conn)?[0].revoked_at_epoch, Some(300));
+        Ok(())
+    }
+
+    #[test]
+    fn expired_duplicate_reserved_and_malformed_tokens_are_rejected() -> Result<()> {
+        let _test_dir = ScopedTestDataDir::new("api-tokens-validation");
+        let conn = crate::db::open_db()?;
+        let expiring = NewApiToken {
+            expires_at_epoch: Some(150),
+            ..new_token("short-lived", ApiScope::Write, &[])
+        };
+        let plaintext = create_api_token(&conn, &expiring, 100)?;
+        assert!(resolve_api_token(&conn, &plaintext, 149)?.is_some());
+        assert!(resolve_api_token(&conn, &plaintext, 150)?.is_none());
+
+        assert!(
+            create_api_token(&conn, &new_token("short-lived", ApiScope::Read, &[]), 100).is_err()
+        );
+        assert!(create_api_token(&conn, &new_token("default", ApiScope::Read, &[]), 100).is_err());
+        assert!(create_api_token(&conn, &new_token("Bad Name", ApiScope::Read, &[]), 100).is_err());
+        let past = NewApiToken {
+            expires_at_epoch: Some(99),
+            ..new_token("stale", ApiScope::Read, &[])
+        };
+        assert!(create_api_token(&conn, &past, 100).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn scopes_are_ordered_and_file_token_keeps_legacy_actor() {
+        assert!(ApiScope::Read < ApiScope::Review);
+        assert!(ApiScope::Review < ApiScope::Write);
+        assert!(ApiScope::Write < ApiScope::Admin);
+        let file = ApiPrincipal::file_token();
+        assert_eq!(file.audit_actor(), "api");
+        assert!(file.allows_any_project(&["/anything".to_string()]));
+    }
+}
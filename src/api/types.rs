@@ -1,6 +1,7 @@
 use std::collections::BTreeMap;
 use std::sync::{Arc, Mutex};
 
+use rmcp::schemars::{self, JsonSchema};
 use serde::{ser::SerializeStruct, Deserialize, Serialize};
 
 #[derive(Clone, Copy, Default)]
@@ -17,12 +18,12 @@ pub(super) struct StatusCacheEntry {
     pub(super) payload: serde_json::Value,
 }
 
-#[derive(Deserialize, Default)]
+#[derive(Deserialize, JsonSchema, Default)]
 pub(super) struct StatusParams {
     pub refresh: Option<bool>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub(super) struct SearchParams {
     pub query: Option<String>,
     pub project: Option<String>,
@@ -146,9 +147,15 @@ pub(super) struct CapabilitiesFeatures {
     pub sessions: bool,
     pub session_activity: bool,
     pub workstreams: bool,
+    pub workstream_plans: bool,
     pub events: bool,
+    pub event_stream: bool,
+    pub openapi: bool,
+    pub injections: bool,
+    pub web_ui: bool,
     pub tasks: bool,
     pub graph: bool,
+    pub timeline_report: bool,
     pub user_recall: bool,
     pub user_recall_usage_policy: bool,
 }
@@ -161,7 +168,7 @@ pub(super) struct HealthResponse {
     pub schema_version: i64,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub(super) struct SaveMemoryRequest {
     pub text: String,
     #[serde(default)]
@@ -196,9 +203,64 @@ pub(super) struct SaveMemoryRequest {
     pub claim_source: Option<String>,
     #[serde(default)]
     pub acknowledge_pattern: Option<String>,
+    #[serde(default)]
+    pub anchors: Option<Vec<MemoryAnchorRequest>>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub(super) struct MemoryAnchorRequest {
+    pub file: String,
+    #[serde(default)]
+    pub symbol: Option<String>,
+    #[serde(default)]
+    pub start_line: Option<i64>,
+    #[serde(default)]
+    pub end_line: Option<i64>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub(super) struct CreateWorkstreamRequest {
+    pub project: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub next_action: Option<String>,
+    #[serde(default)]
+    pub parent_id: Option<i64>,
+    #[serde(default)]
+    pub dependencies: Vec<WorkstreamDependencyRequest>,
+    /// Ordered milestone checklist titles.
+    #[serde(default)]
+    pub milestones: Vec<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub(super) struct WorkstreamDependencyRequest {
+    pub workstream_id: i64,
+    /// `hard` (default) blocks setting the workstream `active` or `completed`
+    /// until the target completes; `soft` only records a preferred order.
+    #[serde(default)]
+    pub kind: Option<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub(super) struct WorkstreamPlanRequest {
+    #[serde(default)]
+    pub parent_id: Option<i64>,
+    #[serde(default)]
+    pub clear_parent: bool,
+    #[serde(default)]
+    pub add_dependencies: Vec<WorkstreamDependencyRequest>,
+    #[serde(default)]
+    pub remove_dependencies: Vec<i64>,
+    #[serde(default)]
+    pub add_milestones: Vec<String>,
+    #[serde(default)]
+    pub complete_milestones: Vec<i64>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub(super) struct UserRecallRequest {
     pub query: String,
     #[serde(default)]
@@ -267,19 +329,19 @@ pub(super) struct SaveMemoryNextStepResponse {
     pub reason: String,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub(super) struct ShowParams {
     pub id: i64,
     pub include_suppressed: Option<bool>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub(super) struct MemoryDetailParams {
     pub include_suppressed: Option<bool>,
 }
 // ===== remem-web 只读端点类型 =====
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub(super) struct ListParams {
     pub project: Option<String>,
     #[serde(rename = "type")]
@@ -327,7 +389,7 @@ pub(super) struct ListResponse<T: Serialize> {
     pub meta: ListMeta,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub(super) struct CandidateParams {
     pub project: Option<String>,
     pub status: Option<String>,
@@ -342,7 +404,7 @@ pub(super) struct CandidateParams {
     pub offset: Option<i64>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub(super) struct BlockedParams {
     pub project: Option<String>,
 }
@@ -370,7 +432,7 @@ pub(super) struct CandidateItem {
     pub created_at_epoch: i64,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub(super) struct CandidateEditRequest {
     #[serde(default)]
     pub scope: Option<String>,
@@ -382,7 +444,7 @@ pub(super) struct CandidateEditRequest {
     pub text: Option<String>,
 }
 
-#[derive(Deserialize, Default)]
+#[derive(Deserialize, JsonSchema, Default)]
 pub(super) struct CandidateApproveRequest {
     #[serde(default)]
     pub acknowledge_pattern: Option<String>,
@@ -501,7 +563,7 @@ pub(super) struct CandidateReviewActionDecision {
     pub blocked_reasons: Vec<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub(super) struct CandidateSafeApproveRequest {
     pub reason: String,
@@ -513,7 +575,7 @@ pub(super) struct CandidateSafeApproveRequest {
     pub acknowledge_dream_review_token: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub(super) struct CandidateSafeRejectRequest {
     pub reason: String,
@@ -521,7 +583,7 @@ pub(super) struct CandidateSafeRejectRequest {
     pub idempotency_key: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub(super) struct CandidateSafeEditRequest {
     pub reason: String,
@@ -553,7 +615,7 @@ pub(super) struct CandidateSafeReviewResponse {
     pub replayed: bool,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub(super) struct MemorySafeGovernanceRequest {
     pub reason: String,
@@ -588,7 +650,7 @@ pub(super) struct SafeMutationErrorDetail {
     pub operation_id: Option<String>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 pub(super) struct GraphParams {
     pub project: Option<String>,
     pub include_suppressed: Option<bool>,
@@ -0,0 +1,71 @@
+//! Embedded local dashboard served at `/ui/`.
+//!
+//! The HTML, script and stylesheet are compiled into the binary and served
+//! without authentication because they hold no data. Every view reads the
+//! bearer-authenticated `/api/v1` routes, so scoped tokens apply unchanged.
+
+use axum::{
+    extract::Path,
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Redirect, Response},
+    routing::get,
+    Router,
+};
+
+use super::helpers::error_response;
+use super::types::DbState;
+
+const INDEX_HTML: &str = include_str!("ui/index.html");
+const APP_JS: &str = include_str!("ui/app.js");
+const APP_CSS: &str = include_str!("ui/app.css");
+
+/// Scripts, styles and fetches stay on this origin; nothing may frame the UI.
+const CONTENT_SECURITY_POLICY: &str = "default-src 'none'; script-src 'self'; \
+     style-src 'self'; connect-src 'self'; img-src 'self'; base-uri 'none'; \
+     form-action 'none'; frame-ancestors 'none'";
+
+pub(super) fn ui_router() -> Router<DbState> {
+    Router::new()
+        .route("/ui", get(|| async { Redirect::permanent("/ui/") }))
+        .route("/ui/", get(handle_index))
+        .route("/ui/assets/{asset}", get(handle_asset))
+}
+
+async fn handle_index() -> Response {
+    embedded(INDEX_HTML, "text/html; charset=utf-8")
+}
+
+async fn handle_asset(Path(asset): Path<String>) -> Response {
+    match asset.as_str() {
+        "app.js" => embedded(APP_JS, "text/javascript; charset=utf-8"),
+        "app.css" => embedded(APP_CSS, "text/css; charset=utf-8"),
+        _ => error_response(
+            StatusCode::NOT_FOUND,
+            "ui_asset_not_found",
+            "unknown dashboard asset",
+        )
+        .into_response(),
+    }
+}
+
+fn embedded(body: &'static str, content_type: &'static str) -> Response {
+    let mut response = body.into_response();
+    let headers = response.headers_mut();
+    headers.insert(header::CONTENT_TYPE, HeaderValue::from_static(content_type));
+    headers.insert(
+        header::CONTENT_SECURITY_POLICY,
+        HeaderValue::from_static(CONTENT_SECURITY_POLICY),
+    );
+    headers.insert(
+        header::X_CONTENT_TYPE_OPTIONS,
+        HeaderValue::from_static("nosniff"),
+    );
+    headers.insert(header::X_FRAME_OPTIONS, HeaderValue::from_static("DENY"));
+    headers.insert(
+        header::REFERRER_POLICY,
+        HeaderValue::from_static("no-referrer"),
+    );
+    // Assets change with the binary, not the URL; always revalidate.
+    headers.insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+    response
+}
@@ -0,0 +1,414 @@
+// remem local dashboard. Plain DOM, no build step: every view reads the
+// authenticated REST API and renders with textContent, never innerHTML.
+"use strict";
+
+const TOKEN_KEY = "remem.api_token";
+const PROJECT_KEY = "remem.project";
+const views = {};
+let currentView = "candidates";
+
+function token() {
+  return sessionStorage.getItem(TOKEN_KEY);
+}
+
+function project() {
+  return sessionStorage.getItem(PROJECT_KEY) || "";
+}
+
+function el(tag, props, ...children) {
+  const node = document.createElement(tag);
+  for (const [key, value] of Object.entries(props || {})) {
+    if (key === "class") node.className = value;
+    else if (key.startsWith("on")) node.addEventListener(key.slice(2), value);
+    else if (value !== undefined && value !== null) node.setAttribute(key, value);
+  }
+  for (const child of children.flat()) {
+    if (child === null || child === undefined) continue;
+    node.append(child instanceof Node ? child : document.createTextNode(String(child)));
+  }
+  return node;
+}
+
+function svg(tag, attrs, ...children) {
+  const node = document.createElementNS("http://www.w3.org/2000/svg", tag);
+  for (const [key, value] of Object.entries(attrs || {})) node.setAttribute(key, value);
+  for (const child of children) {
+    node.append(child instanceof Node ? child : document.createTextNode(String(child)));
+  }
+  return node;
+}
+
+function status(message) {
+  document.getElementById("status").textContent = message || "";
+}
+
+function when(epoch) {
+  return epoch ? new Date(epoch * 1000).toLocaleString() : "";
+}
+
+function query(params) {
+  const search = new URLSearchParams();
+  for (const [key, value] of Object.entries(params)) {
+    if (value !== undefined && value !== null && value !== "") search.set(key, value);
+  }
+  const text = search.toString();
+  return text ? `?${text}` : "";
+}
+
+async function api(path, options = {}) {
+  const headers = { Authorization: `Bearer ${token()}` };
+  if (options.body !== undefined) headers["Content-Type"] = "application/json";
+  const response = await fetch(`/api/v1${path}`, {
+    method: options.method || "GET",
+    headers,
+    body: options.body === undefined ? undefined : JSON.stringify(options.body),
+  });
+  const payload = await response.json().catch(() => null);
+  if (response.status === 401) {
+    sessionStorage.removeItem(TOKEN_KEY);
+    showLogin();
+  }
+  if (!response.ok) {
+    const code = payload && payload.error ? payload.error.code : response.status;
+    const message = payload && payload.error ? payload.error.message : response.statusText;
+    throw new Error(`${code}: ${message}`);
+  }
+  return payload;
+}
+
+function idempotencyKey(action, id, version) {
+  const nonce = crypto.getRandomValues(new Uint32Array(1))[0].toString(16);
+  return `ui-${action}-${id}-v${version}-${nonce}`;
+}
+
+// Line diff via longest common subsequence; candidate texts are short.
+function lineDiff(before, after) {
+  const a = before.split("\n");
+  const b = after.split("\n");
+  const lcs = Array.from({ length: a.length + 1 }, () => new Array(b.length + 1).fill(0));
+  for (let i = a.length - 1; i >= 0; i--) {
+    for (let j = b.length - 1; j >= 0; j--) {
+      lcs[i][j] = a[i] === b[j] ? lcs[i + 1][j + 1] + 1 : Math.max(lcs[i + 1][j], lcs[i][j + 1]);
+    }
+  }
+  const out = [];
+  let i = 0;
+  let j = 0;
+  while (i < a.length || j < b.length) {
+    if (i < a.length && j < b.length && a[i] === b[j]) {
+      out.push(["same", a[i]]);
+      i++;
+      j++;
+    } else if (j < b.length && (i === a.length || lcs[i][j + 1] >= lcs[i + 1][j])) {
+      out.push(["add", b[j++]]);
+    } else {
+      out.push(["del", a[i++]]);
+    }
+  }
+  return out;
+}
+
+function renderDiff(before, after) {
+  return el(
+    "div",
+    { class: "diff" },
+    lineDiff(before, after).map(([kind, line]) =>
+      el("div", { class: kind === "same" ? "" : kind }, `${kind === "add" ? "+ " : kind === "del" ? "- " : "  "}${line}`),
+    ),
+  );
+}
+
+function selectable(list, item, onSelect) {
+  item.addEventListener("click", () => {
+    for (const sibling of list.children) sibling.removeAttribute("aria-selected");
+    item.setAttribute("aria-selected", "true");
+    onSelect();
+  });
+  return item;
+}
+
+views.candidates = async (root) => {
+  const page = await api(`/candidates${query({ project: project(), status: "pending_review", limit: 100 })}`);
+  const detail = el("div", { class: "panel" }, "Select a candidate to review.");
+  const list = el("ul", { class: "list" });
+  for (const candidate of page.data) {
+    list.append(
+      selectable(
+        list,
+        el(
+          "li",
+          {},
+          el("div", {}, candidate.text.split("\n")[0].slice(0, 120)),
+          el("div", { class: "meta" }, `#${candidate.id} · ${candidate.memory_type} · ${candidate.risk_class} · ${candidate.project || ""}`),
+        ),
+        () => showCandidate(candidate.id, detail).catch((error) => status(error.message)),
+      ),
+    );
+  }
+  if (!page.data.length) list.append(el("li", {}, "No pending candidates."));
+  root.append(el("div", { class: "split" }, list, detail));
+};
+
+async function showCandidate(id, panel) {
+  const detail = await api(`/candidates/${id}`);
+  const candidate = detail.data;
+  const edited = el("textarea", { "aria-label": "Edited text" });
+  edited.value = candidate.text;
+  const diff = el("div", {}, renderDiff(candidate.text, candidate.text));
+  edited.addEventListener("input", () => diff.replaceChildren(renderDiff(candidate.text, edited.value)));
+  const reason = el("input", { placeholder: "reason (required)", size: 40 });
+  const reviewToken = detail.provenance && detail.provenance.review_token;
+  const acknowledge = reviewToken ? el("input", { type: "checkbox" }) : null;
+
+  const act = async (action) => {
+    if (!reason.value.trim()) {
+      status("A review reason is required.");
+      return;
+    }
+    const body = {
+      reason: reason.value.trim(),
+      expected_version: candidate.version,
+      idempotency_key: idempotencyKey(action, id, candidate.version),
+    };
+    if (action === "edit") body.text = edited.value;
+    if (action === "approve" && acknowledge && acknowledge.checked) {
+      body.acknowledge_dream_review_token = reviewToken;
+    }
+    const result = await api(`/candidates/${id}/review/${action}`, { method: "POST", body });
+    status(`Candidate #${id}: ${result.before_status} → ${result.after_status}`);
+    render();
+  };
+  const button = (label, action, primary) =>
+    el("button", { class: primary ? "primary" : "", type: "button", onclick: () => act(action).catch((error) => status(error.message)) }, label);
+  const decision = detail.decision;
+
+  panel.replaceChildren(
+    el("h2", {}, `Candidate #${candidate.id}`),
+    el("p", { class: "meta" }, `${candidate.scope} · ${candidate.memory_type} · topic ${candidate.topic_key} · confidence ${candidate.confidence} · v${candidate.version}`),
+    decision.can_review ? null : el("p", {}, `Blocked: ${decision.blocked_reasons.join(", ")}`),
+    el("h3", {}, "Text"),
+    edited,
+    el("h3", {}, "Diff"),
+    diff,
+    el(
+      "div",
+      { class: "actions" },
+      reason,
+      acknowledge ? el("label", {}, acknowledge, " acknowledge dream review token") : null,
+      decision.actions.approve.allowed ? button("Approve", "approve", true) : null,
+      decision.actions.edit.allowed ? button("Save edit", "edit") : null,
+      decision.actions.reject.allowed ? button("Reject", "reject") : null,
+    ),
+    el("h3", {}, "Evidence"),
+    el(
+      "ul",
+      {},
+      detail.evidence.map((evidence) =>
+        el("li", {}, el("div", {}, evidence.summary), el("div", { class: "meta" }, `${evidence.source_kind} #${evidence.source_id} · ${evidence.provenance_status} · ${when(evidence.created_at_epoch)}`), el("div", {}, evidence.preview)),
+      ),
+    ),
+  );
+}
+
+views.graph = async (root) => {
+  const graph = await api(`/graph${query({ project: project(), limit: 150 })}`);
+  if (!graph.nodes.length) {
+    root.append(el("p", {}, "No entities yet."));
+    return;
+  }
+  const width = 1000;
+  const height = 700;
+  const nodes = graph.nodes.map((node, index) => ({
+    ...node,
+    x: width / 2 + Math.cos(index) * 200 * Math.random(),
+    y: height / 2 + Math.sin(index) * 200 * Math.random(),
+  }));
+  const byId = new Map(nodes.map((node) => [node.id, node]));
+  const edges = graph.edges.filter((edge) => byId.has(edge.a) && byId.has(edge.b));
+  // A few hundred steps of a plain spring layout settle small graphs.
+  for (let step = 0; step < 300; step++) {
+    for (const a of nodes) {
+      for (const b of nodes) {
+        if (a === b) continue;
+        const dx = a.x - b.x;
+        const dy = a.y - b.y;
+        const distance = Math.max(Math.hypot(dx, dy), 1);
+        const push = 800 / (distance * distance);
+        a.x += (dx / distance) * push;
+        a.y += (dy / distance) * push;
+      }
+    }
+    for (const edge of edges) {
+      const a = byId.get(edge.a);
+      const b = byId.get(edge.b);
+      const dx = b.x - a.x;
+      const dy = b.y - a.y;
+      const pull = 0.002 * Math.min(edge.w, 5);
+      a.x += dx * pull;
+      a.y += dy * pull;
+      b.x -= dx * pull;
+      b.y -= dy * pull;
+    }
+    for (const node of nodes) {
+      node.x = Math.min(width - 20, Math.max(20, node.x + (width / 2 - node.x) * 0.01));
+      node.y = Math.min(height - 20, Math.max(20, node.y + (height / 2 - node.y) * 0.01));
+    }
+  }
+  const info = el("p", { class: "meta" }, `${nodes.length} entities · ${edges.length} links. Click an entity to list its memories.`);
+  const canvas = svg("svg", { class: "graph", viewBox: `0 0 ${width} ${height}` });
+  for (const edge of edges) {
+    const a = byId.get(edge.a);
+    const b = byId.get(edge.b);
+    canvas.append(svg("line", { x1: a.x, y1: a.y, x2: b.x, y2: b.y, "stroke-width": Math.min(edge.w, 4) }));
+  }
+  for (const node of nodes) {
+    const group = svg("g", { tabindex: 0 });
+    group.append(svg("circle", { cx: node.x, cy: node.y, r: 4 + Math.min(Math.sqrt(node.mention_count), 8) }));
+    group.append(svg("text", { x: node.x + 8, y: node.y + 4 }, node.name));
+    group.append(svg("title", {}, `${node.name} (${node.entity_type || "entity"}) · ${node.mention_count} mentions`));
+    group.addEventListener("click", () => {
+      info.textContent = `${node.name}: memories ${node.mems.map((id) => `#${id}`).join(", ") || "none"}`;
+    });
+    canvas.append(group);
+  }
+  root.append(info, canvas);
+};
+
+views.workstreams = async (root) => {
+  const page = await api(`/workstreams${query({ project: project(), page_size: 100 })}`);
+  const detail = el("div", { class: "panel" }, "Select a workstream.");
+  const list = el("ul", { class: "list" });
+  for (const workstream of page.data) {
+    list.append(
+      selectable(list, el("li", {}, el("div", {}, workstream.title), el("div", { class: "meta" }, `${workstream.status} · updated ${when(workstream.updated_at_epoch)}`)), () => {
+        const field = (label, value) => (value ? [el("h3", {}, label), el("p", {}, value)] : []);
+        detail.replaceChildren(
+          el("h2", {}, workstream.title),
+          el("p", { class: "meta" }, `#${workstream.id} · ${workstream.project} · ${workstream.status} · created ${when(workstream.created_at_epoch)}`),
+          ...field("Description", workstream.description),
+          ...field("Progress", workstream.progress),
+          ...field("Next action", workstream.next_action),
+          ...field("Blockers", workstream.blockers),
+        );
+      }),
+    );
+  }
+  if (!page.data.length) list.append(el("li", {}, "No workstreams."));
+  root.append(el("div", { class: "split" }, list, detail));
+};
+
+views.timeline = async (root) => {
+  const sessions = await api(`/session-activity/sessions${query({ project: project(), limit: 50 })}`);
+  const turns = el("div", { class: "panel" }, "Select a session.");
+  const list = el("ul", { class: "list" });
+  for (const session of sessions.data) {
+    list.append(
+      selectable(list, el("li", {}, el("div", {}, session.session_id), el("div", { class: "meta" }, `${session.project} · ${session.message_count} messages · ${when(session.last_epoch)}`)), async () => {
+        try {
+          const page = await api(`/session-activity${query({ project: session.project, session_id: session.session_id, limit: 200 })}`);
+          turns.replaceChildren(
+            el(
+              "ol",
+              { class: "timeline" },
+              page.data.map((turn) =>
+                el(
+                  "li",
+                  {},
+                  el("div", { class: "meta" }, `${when(turn.started_at_epoch)} · ${turn.result_status}`),
+                  el("div", {}, turn.user_said),
+                  turn.result_summary ? el("div", { class: "meta" }, turn.result_summary) : null,
+                ),
+              ),
+            ),
+          );
+        } catch (error) {
+          status(error.message);
+        }
+      }),
+    );
+  }
+  if (!sessions.data.length) list.append(el("li", {}, "No captured sessions."));
+  root.append(el("div", { class: "split" }, list, turns));
+};
+
+views.injections = async (root) => {
+  const page = await api(`/injections${query({ project: project(), page_size: 100 })}`);
+  const rows = page.data.map((item) =>
+    el(
+      "tr",
+      {},
+      el("td", {}, when(item.injected_at_epoch)),
+      el("td", {}, item.host),
+      el("td", {}, item.status),
+      el("td", {}, item.title || item.item_kind),
+      el("td", {}, item.channel),
+      el("td", {}, item.drop_reason || ""),
+      el("td", {}, item.memory_id ? `#${item.memory_id}` : ""),
+    ),
+  );
+  root.append(
+    el(
+      "table",
+      {},
+      el("thead", {}, el("tr", {}, ["When", "Host", "Status", "Item", "Channel", "Drop reason", "Memory"].map((label) => el("th", {}, label)))),
+      el("tbody", {}, rows.length ? rows : el("tr", {}, el("td", { colspan: 7 }, "No injection audits."))),
+    ),
+  );
+};
+
+async function render() {
+  for (const button of document.querySelectorAll("#tabs button")) {
+    if (button.dataset.view === currentView) button.setAttribute("aria-current", "page");
+    else button.removeAttribute("aria-current");
+  }
+  const root = document.getElementById("view");
+  root.replaceChildren();
+  status("Loading…");
+  try {
+    await views[currentView](root);
+    status("");
+  } catch (error) {
+    status(error.message);
+  }
+}
+
+function showLogin() {
+  document.getElementById("login").hidden = false;
+  document.getElementById("view").replaceChildren();
+}
+
+function start() {
+  // `remem api` can print /ui/#token=...; the fragment never reaches the
+  // server, and is dropped from the address bar once stored.
+  const fragment = new URLSearchParams(location.hash.slice(1));
+  if (fragment.get("token")) {
+    sessionStorage.setItem(TOKEN_KEY, fragment.get("token"));
+    history.replaceState(null, "", location.pathname);
+  }
+  document.getElementById("project").value = project();
+  document.getElementById("login-form").addEventListener("submit", (event) => {
+    event.preventDefault();
+    sessionStorage.setItem(TOKEN_KEY, document.getElementById("token").value.trim());
+    document.getElementById("login").hidden = true;
+    render();
+  });
+  document.getElementById("project-form").addEventListener("submit", (event) => {
+    event.preventDefault();
+    sessionStorage.setItem(PROJECT_KEY, document.getElementById("project").value.trim());
+    render();
+  });
+  document.getElementById("sign-out").addEventListener("click", () => {
+    sessionStorage.removeItem(TOKEN_KEY);
+    showLogin();
+  });
+  for (const button of document.querySelectorAll("#tabs button")) {
+    button.addEventListener("click", () => {
+      currentView = button.dataset.view;
+      render();
+    });
+  }
+  if (token()) render();
+  else showLogin();
+}
+
+document.addEventListener("DOMContentLoaded", start);
@@ -1,4 +1,5 @@
 mod admin;
+mod api_tokens;
 mod codex_memory_import;
 mod config_command;
 mod context_plan;
@@ -8,6 +9,7 @@ mod encrypt_state;
 #[cfg(feature = "eval")]
 mod eval;
 mod export;
+mod glossary;
 mod import;
 mod ingest_sessions;
 mod maintenance;
@@ -16,6 +18,7 @@ mod memory_policy;
 mod model;
 mod pack_export;
 mod pack_import;
+mod pack_team;
 mod pending;
 mod preferences;
 mod procedures;
@@ -25,12 +28,14 @@ mod review;
 mod rules;
 mod scope_cleanup;
 mod shared;
+mod sync;
 mod usage;
 mod user_profile;
 mod user_review;
 mod user_summary;
 
 pub(super) use admin::run_admin;
+pub(super) use api_tokens::run_api_tokens;
 pub(super) use config_command::run_config;
 pub(super) use context_plan::run_context_plan;
 pub(super) use dream_backfill::run_dream_backfill;
@@ -39,16 +44,20 @@ pub(super) use embedding::run_embedding;
 pub(super) use eval::{
     run_bench, run_eval, run_eval_associative_baseline, run_eval_capacity, run_eval_coding_bench,
     run_eval_e2e, run_eval_extraction, run_eval_gates, run_eval_governance,
-    run_eval_graph_decision, run_eval_local, run_eval_provider_comparison, run_eval_weight_grid,
+    run_eval_graph_decision, run_eval_local, run_eval_provider_comparison, run_eval_weight_fit,
+    run_eval_weight_grid,
 };
 pub(super) use export::run_export;
+pub(super) use glossary::run_glossary;
 pub(super) use import::run_import;
 pub(super) use ingest_sessions::run_ingest_sessions_cli;
 pub(super) use maintenance::{
-    run_cleanup, run_dream, run_encrypt, run_governance, GovernanceCliRequest,
+    run_cleanup, run_dream, run_encrypt, run_governance, run_redact, run_rotate_key,
+    GovernanceCliRequest,
 };
 pub(super) use memory_policy::run_memory_action;
 pub(super) use model::run_model;
+pub(super) use pack_team::run_pack;
 pub(super) use pending::run_pending;
 pub(super) use preferences::{run_preferences, run_user};
 pub(super) use procedures::run_procedures;
@@ -62,6 +71,7 @@ pub(super) use rules::run_rules;
 pub(super) use scope_cleanup::{
     run_archive, run_audit_scope, run_merge_preferences, run_reroute, RerouteCliRequest,
 };
+pub(super) use sync::run_sync;
 pub(super) use usage::run_usage;
 pub(super) use user_profile::run_user_profile;
 pub(super) use user_review::run_user_review;
@@ -0,0 +1,106 @@
+use anyhow::{bail, Result};
+use serde_json::json;
+
+use crate::api::tokens::{
+    create_api_token, list_api_tokens, revoke_api_token, ApiScope, NewApiToken,
+};
+use crate::cli::types::{ApiScopeArg, ApiTokensAction};
+use crate::db;
+
+const SECS_PER_DAY: i64 = 24 * 60 * 60;
+
+pub(in crate::cli) fn run_api_tokens(action: ApiTokensAction) -> Result<()> {
+    let conn = db::open_db()?;
+    let now_epoch = chrono::Utc::now().timestamp();
+    match action {
+        ApiTokensAction::Create {
+            name,
+            scope,
+            projects,
+            expires_in_days,
+            json,
+        } => {
+            let expires_at_epoch = match expires_in_days {
+                Some(0) => bail!("--expires-in-days must be at least 1"),
+                Some(days) => Some(now_epoch + i64::from(days) * SECS_PER_DAY),
+                None => None,
+            };
+            let scope = ApiScope::from(scope);
+            let token = create_api_token(
+                &conn,
+                &NewApiToken {
+                    name: &name,
+                    scope,
+                    projects: &projects,
+                    expires_at_epoch,
+                },
+                now_epoch,
+            )?;
+            if json {
+                println!(
+                    "{}",
+                    json!({
+                        "name": name,
+                        "scope": scope.as_str(),
+                        "projects": (!projects.is_empty()).then_some(&projects),
+                        "expires_at_epoch": expires_at_epoch,
+                        "token": token,
+                    })
+                );
+            } else {
+                println!("Created API token '{name}' (scope={}).", scope.as_str());
+                println!("{token}");
+                println!("Store it now; remem keeps only its hash.");
+            }
+        }
+        ApiTokensAction::List { json } => {
+            let tokens = list_api_tokens(&conn)?;
+            if json {
+                println!("{}", json!({ "tokens": tokens }));
+                return Ok(());
+            }
+            if tokens.is_empty() {
+                println!("No named API tokens. The .api-token file grants admin access.");
+                return Ok(());
+            }
+            for token in tokens {
+                let state = match (token.revoked_at_epoch, token.expires_at_epoch) {
+                    (Some(_), _) => "revoked",
+                    (None, Some(expires)) if expires <= now_epoch => "expired",
+                    _ => "active",
+                };
+                let projects = token
+                    .projects
+                    .map(|projects| projects.join(","))
+                    .unwrap_or_else(|| "*".to_string());
+                let last_used = token
+                    .last_used_at_epoch
+                    .map(|epoch| epoch.to_string())
+                    .unwrap_or_else(|| "never".to_string());
+                println!(
+                    "{} scope={} state={state} projects={projects} last_used={last_used}",
+                    token.name,
+                    token.scope.as_str()
+                );
+            }
+        }
+        ApiTokensAction::Revoke { name } => {
+            if !revoke_api_token(&conn, &name, now_epoch)? {
+                bail!("no active API token named '{name}'");
+            }
+            println!("Revoked API token '{name}'.");
+        }
+    }
+    Ok(())
+}
+
+impl From<ApiScopeArg> for ApiScope {
+    fn from(value: ApiScopeArg) -> Self {
+        match value {
+            ApiScopeArg::Read => Self::Read,
+            ApiScopeArg::Review => Self::Review,
+            ApiScopeArg::Write => Self::Write,
+            ApiScopeArg::Admin => Self::Admin,
+        }
+    }
+}
@@ -405,6 +405,31 @@ pub(in crate::cli) fn run_eval_weight_grid(
     Ok(())
 }
 
+pub(in crate::cli) fn run_eval_weight_fit(
+    project: &str,
+    k: usize,
+    profile: Option<String>,
+    min_queries: usize,
+    json: bool,
+) -> Result<()> {
+    let conn = db::open_db()?;
+    let report = crate::eval::weight_grid::fit_project_weights(
+        &conn,
+        crate::eval::weight_grid::WeightFitOptions {
+            project: project.to_string(),
+            k,
+            profile,
+            min_queries,
+        },
+    )?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print!("{report}");
+    }
+    Ok(())
+}
+
 pub(in crate::cli) async fn run_eval_coding_bench(args: EvalCodingBenchArgs) -> Result<()> {
     run_coding_bench_options(crate::eval::coding_bench::CodingBenchOptions {
         fixture_path: args.fixture,
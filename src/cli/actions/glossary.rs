@@ -0,0 +1,175 @@
+use anyhow::{bail, Context, Result};
+
+use crate::cli::cwd::resolve_cwd_arg;
+use crate::cli::types::GlossaryAction;
+use crate::db;
+use crate::retrieval::glossary::{
+    self, GlossaryEntry, GlossarySource, GlossaryStatus, GLOSSARY_FILE,
+};
+
+pub(in crate::cli) fn run_glossary(action: GlossaryAction) -> Result<()> {
+    match action {
+        GlossaryAction::Add {
+            term,
+            expansions,
+            project,
+        } => {
+            let project = db::project_from_cwd(&resolve_cwd_arg(project));
+            let conn = db::open_db()?;
+            for expansion in &expansions {
+                let id = glossary::add_glossary_entry(
+                    &conn,
+                    &project,
+                    &term,
+                    expansion,
+                    GlossarySource::Manual,
+                )?;
+                println!("Added #{id}: {} -> {}", term.trim(), expansion.trim());
+            }
+        }
+        GlossaryAction::List {
+            suggested,
+            project,
+            json,
+        } => {
+            let project = db::project_from_cwd(&resolve_cwd_arg(project));
+            let conn = db::open_db()?;
+            let status = if suggested {
+                GlossaryStatus::Suggested
+            } else {
+                GlossaryStatus::Active
+            };
+            let entries = glossary::list_glossary_entries(&conn, &project, Some(status))?;
+            // Suggestions never come from the file, so only the active view shows it.
+            let file_terms = if suggested {
+                Vec::new()
+            } else {
+                glossary::load_glossary_file(glossary::glossary_file_path(&project))?
+                    .unwrap_or_default()
+            };
+            if json {
+                let file_terms = file_terms
+                    .iter()
+                    .map(|(term, expansion)| {
+                        serde_json::json!({ "term": term, "expansion": expansion })
+                    })
+                    .collect::<Vec<_>>();
+                let output = serde_json::json!({
+                    "project": project,
+                    "status": status.as_str(),
+                    "entries": entries,
+                    "file": { "path": GLOSSARY_FILE, "terms": file_terms },
+                });
+                println!("{}", serde_json::to_string_pretty(&output)?);
+                return Ok(());
+            }
+            print_entries(&project, status, &entries);
+            if !file_terms.is_empty() {
+                println!("From {GLOSSARY_FILE}:");
+                for (term, expansion) in file_terms {
+                    println!("  {term} -> {expansion}");
+                }
+            }
+        }
+        GlossaryAction::Import { path, project } => {
+            let project = db::project_from_cwd(&resolve_cwd_arg(project));
+            let path = path
+                .map(std::path::PathBuf::from)
+                .unwrap_or_else(|| glossary::glossary_file_path(&project));
+            let Some(pairs) = glossary::load_glossary_file(&path)? else {
+                bail!("glossary file not found: {}", path.display());
+            };
+            let mut conn = db::open_db()?;
+            let tx = conn.transaction()?;
+            for (term, expansion) in &pairs {
+                glossary::add_glossary_entry(
+                    &tx,
+                    &project,
+                    term,
+                    expansion,
+                    GlossarySource::Import,
+                )
+                .with_context(|| format!("import {term} -> {expansion}"))?;
+            }
+            tx.commit()?;
+            println!(
+                "Imported {} glossary entries from {} into '{project}'.",
+                pairs.len(),
+                path.display()
+            );
+        }
+        GlossaryAction::Suggest { project, json } => {
+            let project = db::project_from_cwd(&resolve_cwd_arg(project));
+            let conn = db::open_db()?;
+            let report = glossary::suggest_glossary_entries(&conn, &project)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+                return Ok(());
+            }
+            println!(
+                "Scanned {} memories in '{}'.",
+                report.scanned_memories, report.project
+            );
+            if report.suggestions.is_empty() {
+                println!("No new glossary suggestions.");
+                return Ok(());
+            }
+            for suggestion in &report.suggestions {
+                println!(
+                    "  {} -> {} (support={}{})",
+                    suggestion.term,
+                    suggestion.expansion,
+                    suggestion.support_count,
+                    if suggestion.new { ", new" } else { "" }
+                );
+            }
+            println!(
+                "Review with `remem glossary list --suggested`, then `remem glossary accept <id>` or `reject <id>`."
+            );
+        }
+        GlossaryAction::Accept { id } => set_status(id, GlossaryStatus::Active)?,
+        GlossaryAction::Reject { id } => set_status(id, GlossaryStatus::Rejected)?,
+        GlossaryAction::Remove { id } => {
+            let conn = db::open_db()?;
+            if !glossary::remove_glossary_entry(&conn, id)? {
+                bail!("glossary entry #{id} not found");
+            }
+            println!("Removed glossary entry #{id}.");
+        }
+    }
+    Ok(())
+}
+
+fn set_status(id: i64, status: GlossaryStatus) -> Result<()> {
+    let conn = db::open_db()?;
+    let Some(entry) = glossary::set_glossary_entry_status(&conn, id, status)? else {
+        bail!("glossary entry #{id} not found");
+    };
+    println!(
+        "Glossary entry #{} is now {}: {} -> {}",
+        entry.id,
+        status.as_str(),
+        entry.term,
+        entry.expansion
+    );
+    Ok(())
+}
+
+fn print_entries(project: &str, status: GlossaryStatus, entries: &[GlossaryEntry]) {
+    if entries.is_empty() {
+        println!("No {} glossary entries for '{project}'.", status.as_str());
+        return;
+    }
+    println!("Glossary ({}) for '{project}':", status.as_str());
+    for entry in entries {
+        let support = if entry.source == GlossarySource::Mined.as_str() {
+            format!(" support={}", entry.support_count)
+        } else {
+            String::new()
+        };
+        println!(
+            "  #{} {} -> {} (source={}{support})",
+            entry.id, entry.term, entry.expansion, entry.source
+        );
+    }
+}
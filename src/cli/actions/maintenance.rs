@@ -47,6 +47,14 @@ pub(in crate::cli) async fn run_dream(
 }
 
 pub(in crate::cli) fn run_encrypt(rekey_raw: bool) -> Result<()> {
+    if let Some(command) = db::configured_key_command()? {
+        if rekey_raw {
+            bail!(
+                "--rekey-raw migrates ~/.remem/.key, but the key comes from encryption.key_command"
+            );
+        }
+        return run_encrypt_with_key_command(&command);
+    }
     if rekey_raw {
         return run_rekey_raw();
     }
@@ -120,6 +128,74 @@ pub(in crate::cli) fn run_encrypt(rekey_raw: bool) -> Result<()> {
     Ok(())
 }
 
+fn run_encrypt_with_key_command(command: &str) -> Result<()> {
+    let key = db::run_key_command(command)?;
+    let db_path = db::try_db_path()?;
+    if !db_path.exists() {
+        initialize_missing_database_with_key(&db_path, &key)?;
+        println!(
+            "Initialized encrypted database at {} with the key from encryption.key_command",
+            db_path.display()
+        );
+        return Ok(());
+    }
+    if db::sqlite_file_is_plaintext(&db_path)? {
+        println!("Encrypting database with the key from encryption.key_command...");
+        db::encrypt_database(&key)?;
+        println!("Done. Database is now encrypted with SQLCipher.");
+        return Ok(());
+    }
+    let conn =
+        rusqlite::Connection::open_with_flags(&db_path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .with_context(|| format!("open existing remem database {}", db_path.display()))?;
+    db::configure_cipher(&conn, Some(&key)).with_context(|| {
+        format!(
+            "encryption.key_command `{command}` does not unlock {}",
+            db_path.display()
+        )
+    })?;
+    println!("Database is already encrypted (verified with encryption.key_command)");
+    Ok(())
+}
+
+/// `remem encrypt --rotate`.
+pub(in crate::cli) fn run_rotate_key(new_key_command: Option<&str>) -> Result<()> {
+    println!("Rotating the SQLCipher key (stop `remem api` and the worker first)...");
+    let report = db::rotate_cipher_key(new_key_command)?;
+    if report.resumed {
+        println!("Resumed an interrupted rotation.");
+    }
+    println!(
+        "Database: {}",
+        if report.database_rekeyed {
+            "re-keyed"
+        } else {
+            "already on the new key"
+        }
+    );
+    println!(
+        "Spill queues: {} line(s) re-keyed in {} file(s)",
+        report.spill_lines, report.spill_files
+    );
+    if report.spill_unreadable_lines > 0 {
+        println!(
+            "Warning: {} spill line(s) open with neither key and were left unchanged",
+            report.spill_unreadable_lines
+        );
+    }
+    println!("Backups: {} re-keyed", report.backups_rekeyed);
+    match report.new_key {
+        db::KeySource::File => println!(
+            "New key saved to {}",
+            db::try_data_dir()?.join(".key").display()
+        ),
+        db::KeySource::Command { command } => {
+            println!("encryption.key_command is now `{command}`; the old key is no longer needed")
+        }
+    }
+    Ok(())
+}
+
 fn run_rekey_raw() -> Result<()> {
     let db_path = db::try_db_path()?;
     if !db_path.exists() {
@@ -189,6 +265,81 @@ fn run_rekey_raw() -> Result<()> {
     Ok(())
 }
 
+pub(in crate::cli) fn run_redact(apply: bool, project: Option<&str>, json: bool) -> Result<()> {
+    let config = crate::runtime_config::redaction_config()?;
+    let detectors = crate::adapter::redaction::SecretDetectors::new(&config)?;
+    let mut conn = db::open_db()?;
+    let report = if apply {
+        crate::maintenance::apply_stored_secret_redaction(
+            &mut conn,
+            &detectors,
+            project,
+            &db::absolute_data_dir()?,
+            chrono::Utc::now().timestamp(),
+        )?
+    } else {
+        crate::maintenance::scan_stored_secrets(&conn, &detectors, project)?
+    };
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+    print!("{}", render_redaction_report(&report));
+    Ok(())
+}
+
+fn render_redaction_report(report: &crate::maintenance::RedactionReport) -> String {
+    let mut out = String::new();
+    let verb = if report.applied { "Redacted" } else { "Found" };
+    let scope = report.project.as_deref().unwrap_or("all projects");
+    if report.matches == 0 {
+        out.push_str(&format!("No stored secrets found ({scope}).\n"));
+        return out;
+    }
+    out.push_str(&format!(
+        "{verb} {} secret(s) in {} row(s) ({scope}):\n",
+        report.matches, report.rows
+    ));
+    for column in &report.columns {
+        let detectors = column
+            .detectors
+            .iter()
+            .map(|(name, count)| format!("{name}={count}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        out.push_str(&format!(
+            "  {}.{}: {} row(s), {} match(es) [{detectors}]\n",
+            column.table, column.column, column.rows, column.matches
+        ));
+    }
+    out.push_str("Findings:\n");
+    for finding in &report.findings {
+        out.push_str(&format!(
+            "  {} #{} {} [{}]\n",
+            finding.table,
+            finding.row_id,
+            finding.column,
+            finding.detectors.join(", ")
+        ));
+    }
+    if report.findings_truncated {
+        out.push_str("  ...\n");
+    }
+    match &report.run_id {
+        Some(run_id) => {
+            out.push_str(&format!("Audit run: {run_id}\n"));
+            if report.memory_embeddings_invalidated > 0 {
+                out.push_str(&format!(
+                    "Dropped {} memory embedding(s); the worker re-embeds them.\n",
+                    report.memory_embeddings_invalidated
+                ));
+            }
+        }
+        None => out.push_str("Run `remem redact --apply` to scrub them.\n"),
+    }
+    out
+}
+
 pub(in crate::cli) fn run_cleanup(
     dry_run: bool,
     json: bool,
@@ -247,3 +247,35 @@ fn cleanup_report_json_exposes_dry_run_plan_counts() -> std::result::Result<(),
     );
     Ok(())
 }
+
+#[cfg(unix)]
+#[test]
+fn run_encrypt_uses_key_command_instead_of_generating_a_key_file() -> Result<()> {
+    let test_dir = ScopedTestDataDir::new("encrypt-key-command");
+    std::env::remove_var("REMEM_ALLOW_PLAINTEXT_DB");
+    std::env::remove_var("REMEM_CIPHER_KEY");
+    std::fs::create_dir_all(&test_dir.path)?;
+    {
+        let conn = rusqlite::Connection::open(test_dir.db_path())?;
+        conn.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, v TEXT)", [])?;
+        conn.execute("INSERT INTO t (v) VALUES ('from plaintext')", [])?;
+    }
+    let key_hex = "9".repeat(64);
+    std::fs::write(
+        test_dir.path.join("config.toml"),
+        format!("[encryption]\nkey_command = \"printf v2:{key_hex}\"\n"),
+    )?;
+
+    run_encrypt(false)?;
+    run_encrypt(false)?;
+
+    assert!(!test_dir.path.join(".key").exists());
+    assert_no_plaintext_sqlite_files(&test_dir.path)?;
+    let conn = rusqlite::Connection::open(test_dir.db_path())?;
+    db::configure_cipher(&conn, Some(&db::CipherKey::Raw(key_hex)))?;
+    let value: String = conn.query_row("SELECT v FROM t WHERE id = 1", [], |row| row.get(0))?;
+    assert_eq!(value, "from plaintext");
+    let error = run_encrypt(true).expect_err("--rekey-raw has no key file to migrate");
+    assert!(error.to_string().contains("encryption.key_command"));
+    Ok(())
+}
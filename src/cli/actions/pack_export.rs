@@ -46,12 +46,27 @@ pub(in crate::cli::actions) fn export_pack(
     conn: &Connection,
     request: PackExportRequest<'_>,
 ) -> Result<PackExportStats> {
-    let rows = load_pack_memories(conn, request.project, normalize_limit(request.limit))?;
+    let pack_rows = collect_pack_memories(conn, request.project, request.limit)?;
+    write_pack_files(request.output, request.project, &pack_rows)
+}
+
+/// Current repo-owned startup memories of `project` in pack order.
+pub(super) fn collect_pack_memories(
+    conn: &Connection,
+    project: &str,
+    limit: i64,
+) -> Result<Vec<PackMemory>> {
+    let rows = load_pack_memories(conn, project, normalize_limit(limit))?;
     let mut pack_rows = rows
         .into_iter()
         .map(PackMemory::try_from)
         .collect::<Result<Vec<_>>>()?;
-    pack_rows.sort_by(|left, right| {
+    sort_pack_memories(&mut pack_rows);
+    Ok(pack_rows)
+}
+
+pub(super) fn sort_pack_memories(rows: &mut [PackMemory]) {
+    rows.sort_by(|left, right| {
         (
             left.memory_type.as_str(),
             left.state_key.as_deref().unwrap_or(""),
@@ -63,39 +78,43 @@ pub(in crate::cli::actions) fn export_pack(
                 right.content_hash.as_str(),
             ))
     });
+}
 
-    let memories_jsonl = render_memories_jsonl(&pack_rows)?;
+/// Writes `memories.jsonl`, `pack.json` and `INDEX.md` for already sorted rows.
+pub(super) fn write_pack_files(
+    output: &Path,
+    project: &str,
+    pack_rows: &[PackMemory],
+) -> Result<PackExportStats> {
+    let memories_jsonl = render_memories_jsonl(pack_rows)?;
     let content_digest = hex_sha256(memories_jsonl.as_bytes());
     let manifest = PackManifest {
         format_version: PACK_FORMAT_VERSION,
-        project: request.project.to_string(),
+        project: project.to_string(),
         exporter: "remem".to_string(),
         exporter_version: env!("CARGO_PKG_VERSION").to_string(),
         memory_count: pack_rows.len(),
         content_digest: content_digest.clone(),
     };
 
-    fs::create_dir_all(request.output)
-        .with_context(|| format!("create pack directory {}", request.output.display()))?;
+    fs::create_dir_all(output)
+        .with_context(|| format!("create pack directory {}", output.display()))?;
+    fs::write(output.join("memories.jsonl"), memories_jsonl.as_bytes())
+        .with_context(|| format!("write {}", output.join("memories.jsonl").display()))?;
     fs::write(
-        request.output.join("memories.jsonl"),
-        memories_jsonl.as_bytes(),
-    )
-    .with_context(|| format!("write {}", request.output.join("memories.jsonl").display()))?;
-    fs::write(
-        request.output.join("pack.json"),
+        output.join("pack.json"),
         format!("{}\n", serde_json::to_string_pretty(&manifest)?),
     )
-    .with_context(|| format!("write {}", request.output.join("pack.json").display()))?;
+    .with_context(|| format!("write {}", output.join("pack.json").display()))?;
     fs::write(
-        request.output.join("INDEX.md"),
-        render_index(request.project, &pack_rows).as_bytes(),
+        output.join("INDEX.md"),
+        render_index(project, pack_rows).as_bytes(),
     )
-    .with_context(|| format!("write {}", request.output.join("INDEX.md").display()))?;
+    .with_context(|| format!("write {}", output.join("INDEX.md").display()))?;
 
     Ok(PackExportStats {
         exported: pack_rows.len(),
-        output: request.output.to_path_buf(),
+        output: output.to_path_buf(),
         digest: content_digest,
     })
 }
@@ -197,19 +216,25 @@ impl TryFrom<PackMemoryRow> for PackMemory {
     fn try_from(row: PackMemoryRow) -> Result<Self> {
         ensure_no_redaction_hit(row.id, "title", &row.title)?;
         ensure_no_redaction_hit(row.id, "content", &row.content)?;
-        let content_hash = pack_memory_content_hash(
+        let state_key = pack_state_key(
             &row.memory_type,
             row.state_key.as_deref(),
             &row.title,
             &row.content,
         );
+        let content_hash = pack_memory_content_hash(
+            &row.memory_type,
+            state_key.as_deref(),
+            &row.title,
+            &row.content,
+        );
         let origin = pack_memory_origin(&row);
         Ok(Self {
             title: row.title,
             content: row.content,
             memory_type: row.memory_type,
             scope: row.scope,
-            state_key: row.state_key,
+            state_key,
             state_key_confidence: None,
             state_key_reason: None,
             confidence: row.confidence,
@@ -311,6 +336,31 @@ pub(super) fn hex_sha256(bytes: &[u8]) -> String {
         .collect::<String>()
 }
 
+/// Content-only pack rows are reviewed with their content hash as topic key,
+/// which approval turns into a state key. Fold that key back so the row keeps
+/// its content identity when it is exported again.
+pub(super) fn pack_state_key(
+    memory_type: &str,
+    state_key: Option<&str>,
+    title: &str,
+    content: &str,
+) -> Option<String> {
+    state_key
+        .filter(|key| *key != pack_memory_content_hash(memory_type, None, title, content))
+        .map(str::to_string)
+}
+
+/// Pack content hash of a local memory row, with [`pack_state_key`] applied.
+pub(super) fn local_pack_content_hash(
+    memory_type: &str,
+    state_key: Option<&str>,
+    title: &str,
+    content: &str,
+) -> String {
+    let state_key = pack_state_key(memory_type, state_key, title, content);
+    pack_memory_content_hash(memory_type, state_key.as_deref(), title, content)
+}
+
 pub(super) fn pack_memory_content_hash(
     memory_type: &str,
     state_key: Option<&str>,
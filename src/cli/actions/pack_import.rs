@@ -9,6 +9,8 @@ use super::pack_export::{
 
 mod active_import;
 
+pub(super) use active_import::{ensure_project_row, insert_pack_candidate};
+
 pub(in crate::cli) fn run_import_pack(pack: &Path, project: &str, dry_run: bool) -> Result<()> {
     let loaded = load_pack(pack)?;
     if dry_run {
@@ -69,17 +71,17 @@ impl PackImportStats {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-struct PackImportEntry {
-    category: PackImportCategory,
-    reason: String,
+pub(super) struct PackImportEntry {
+    pub(super) category: PackImportCategory,
+    pub(super) reason: String,
     title: String,
     state_key: Option<String>,
     content_hash: String,
-    memory: PackMemory,
+    pub(super) memory: PackMemory,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum PackImportCategory {
+pub(super) enum PackImportCategory {
     Add,
     Dedup,
     Skip,
@@ -169,17 +171,24 @@ fn plan_loaded_pack(
     })
 }
 
-struct LoadedPack {
-    manifest: PackManifest,
-    memories: Vec<PackMemory>,
+pub(super) struct LoadedPack {
+    pub(super) manifest: PackManifest,
+    pub(super) memories: Vec<PackMemory>,
 }
 
-fn load_pack(pack: &Path) -> Result<LoadedPack> {
+pub(super) fn load_pack(pack: &Path) -> Result<LoadedPack> {
+    let manifest_path = pack.join("pack.json");
+    let manifest_bytes =
+        fs::read(&manifest_path).with_context(|| format!("read {}", manifest_path.display()))?;
+    load_pack_with_manifest(pack, &manifest_bytes)
+}
+
+/// Loads a pack whose `pack.json` bytes were already read, so a caller that
+/// verified a signature over those bytes parses exactly what it verified.
+pub(super) fn load_pack_with_manifest(pack: &Path, manifest_bytes: &[u8]) -> Result<LoadedPack> {
     let manifest_path = pack.join("pack.json");
     let memories_path = pack.join("memories.jsonl");
-    let manifest_text = fs::read_to_string(&manifest_path)
-        .with_context(|| format!("read {}", manifest_path.display()))?;
-    let manifest: PackManifest = serde_json::from_str(&manifest_text)
+    let manifest: PackManifest = serde_json::from_slice(manifest_bytes)
         .with_context(|| format!("parse {}", manifest_path.display()))?;
     if manifest.format_version != PACK_FORMAT_VERSION {
         bail!(
@@ -261,13 +270,18 @@ fn validate_pack_memory(line_number: usize, memory: &PackMemory) -> Result<()> {
     Ok(())
 }
 
+/// A pack row's identity: its state key when it has one, else its content.
+pub(super) fn pack_identity(memory: &PackMemory) -> String {
+    match memory.state_key.as_deref() {
+        Some(state_key) => format!("state:{}:{state_key}", memory.memory_type),
+        None => format!("content:{}:{}", memory.memory_type, memory.content_hash),
+    }
+}
+
 fn validate_unique_pack_identities(memories: &[PackMemory]) -> Result<()> {
     let mut seen = HashSet::new();
     for (idx, memory) in memories.iter().enumerate() {
-        let identity = match memory.state_key.as_deref() {
-            Some(state_key) => format!("state:{}:{state_key}", memory.memory_type),
-            None => format!("content:{}:{}", memory.memory_type, memory.content_hash),
-        };
+        let identity = pack_identity(memory);
         if !seen.insert(identity.clone()) {
             bail!(
                 "memories.jsonl line {} duplicates pack import identity {}",
@@ -279,7 +293,7 @@ fn validate_unique_pack_identities(memories: &[PackMemory]) -> Result<()> {
     Ok(())
 }
 
-fn classify_pack_memory(
+pub(super) fn classify_pack_memory(
     conn: Option<&Connection>,
     target_project: &str,
     memory: PackMemory,
@@ -341,6 +355,17 @@ fn classify_pack_memory(
     })
 }
 
+/// Whether a local suppression covers `memory`, independent of how the
+/// planner would otherwise classify it.
+pub(super) fn is_pack_memory_suppressed(
+    conn: &Connection,
+    target_project: &str,
+    memory: &PackMemory,
+) -> Result<bool> {
+    let local_matches = load_local_matches(conn, target_project, memory)?;
+    Ok(matching_suppression(conn, memory, &local_matches)?.is_some())
+}
+
 #[derive(Debug, Clone)]
 struct SuppressionMatch {
     target_kind: String,
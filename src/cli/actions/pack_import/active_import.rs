@@ -110,7 +110,10 @@ pub(super) fn render_import_apply_report(pack: &Path, report: &PackImportApplyRe
     output
 }
 
-fn ensure_project_row(conn: &Connection, target_project: &str) -> Result<i64> {
+pub(in crate::cli::actions) fn ensure_project_row(
+    conn: &Connection,
+    target_project: &str,
+) -> Result<i64> {
     let now = chrono::Utc::now().timestamp();
     conn.execute(
         "INSERT INTO workspaces(root_path, git_remote, git_branch, created_at_epoch, updated_at_epoch)
@@ -212,7 +215,7 @@ fn insert_pack_memory(
     Ok(memory_id)
 }
 
-fn insert_pack_candidate(
+pub(in crate::cli::actions) fn insert_pack_candidate(
     conn: &Connection,
     project_id: i64,
     target_project: &str,
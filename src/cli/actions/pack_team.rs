@@ -0,0 +1,113 @@
+//! `remem pack`: a project memory pack committed to the repository and shared
+//! by a team. `pull` verifies the pack's Ed25519 signature against
+//! `[pack.trusted_authors]` and queues new or changed rows for review; `push`
+//! merges local accepted memory back and signs the result. `remem import
+//! --pack` stays the one-shot path for a pack the user already trusts.
+
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+
+use crate::cli::cwd::resolve_cwd_arg;
+use crate::cli::types::PackAction;
+use crate::db;
+
+mod pull;
+mod push;
+mod signature;
+mod state;
+
+use pull::pull_with_connection;
+use push::push_with_connection;
+use signature::PackSigner;
+
+pub(in crate::cli) fn run_pack(action: PackAction) -> Result<()> {
+    match action {
+        PackAction::Pull {
+            dir,
+            project,
+            dry_run,
+        } => {
+            let project = db::project_from_cwd(&resolve_cwd_arg(project));
+            let trust = crate::runtime_config::pack_trust_config()?;
+            let mut conn = db::open_db().context("open runtime database for pack pull")?;
+            let report = pull_with_connection(
+                &mut conn,
+                &trust,
+                &dir,
+                &project,
+                dry_run,
+                chrono::Utc::now().timestamp(),
+            )?;
+            print!("{}", pull::render_pull_report(&dir, &report, dry_run));
+        }
+        PackAction::Push {
+            dir,
+            project,
+            author,
+            limit,
+        } => {
+            let project = db::project_from_cwd(&resolve_cwd_arg(project));
+            let trust = crate::runtime_config::pack_trust_config()?;
+            let signer = load_signer(author, &trust)?;
+            let mut conn = db::open_db().context("open runtime database for pack push")?;
+            let report = push_with_connection(
+                &mut conn,
+                &trust,
+                &signer,
+                &dir,
+                &project,
+                limit,
+                chrono::Utc::now().timestamp(),
+            )?;
+            print!("{}", push::render_push_report(&report, &signer.author));
+            if !trust.trusted_authors.contains_key(&signer.author) {
+                println!(
+                    "Note: {} is not in your own [pack.trusted_authors]; pulls of this pack will be refused until it is.",
+                    signer.author
+                );
+            }
+        }
+        PackAction::Author { name } => {
+            let trust = crate::runtime_config::pack_trust_config()?;
+            let (key, created) = signature::load_or_create_author_key()?;
+            let path = signature::author_key_path()?;
+            let name = name
+                .or(trust.author)
+                .unwrap_or_else(|| "<your-name>".to_string());
+            print!(
+                "{}",
+                render_author(&path, created, &name, &signature::encode_public_key(&key))
+            );
+        }
+    }
+    Ok(())
+}
+
+fn load_signer(
+    author: Option<String>,
+    trust: &crate::runtime_config::PackTrustConfig,
+) -> Result<PackSigner> {
+    let Some(author) = author
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .or_else(|| trust.author.clone())
+    else {
+        bail!(
+            "no pack author name: pass --author or set pack.author in the config (code=pack_author_missing)"
+        );
+    };
+    let (key, _) = signature::load_or_create_author_key()?;
+    Ok(PackSigner { author, key })
+}
+
+fn render_author(path: &Path, created: bool, name: &str, public_key: &str) -> String {
+    format!(
+        "{} pack author key at {}.\nPublic key: {public_key}\nTeammates trust your pushes by adding this to their remem config:\n\n[pack.trusted_authors]\n{name} = \"{public_key}\"\n",
+        if created { "Generated" } else { "Using" },
+        path.display()
+    )
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,300 @@
+//! `remem pack pull`: verifies the team pack and routes every new or changed
+//! row into the candidate review queue. Nothing from the pack becomes active
+//! memory without a local approval.
+//!
+//! Against the entries recorded by the previous pull or push, a changed hash
+//! under the same state key is an upstream supersession and a missing
+//! identity is an upstream deletion: the withdrawn version's pending
+//! candidates are discarded and its unedited pack-sourced memory is marked
+//! stale with a `memory_governance` audit event.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use rusqlite::{params, Connection};
+
+use super::signature;
+use super::state::{self, TrackedEntry};
+use crate::cli::actions::pack_export::{local_pack_content_hash, pack_memory_content_hash};
+use crate::cli::actions::pack_import::{
+    classify_pack_memory, ensure_project_row, insert_pack_candidate, is_pack_memory_suppressed,
+    load_pack_with_manifest, pack_identity, PackImportCategory,
+};
+use crate::memory_candidate::review::{decode_pack_review_text, discard_candidate_with_meta};
+use crate::runtime_config::PackTrustConfig;
+
+const PACK_PULL_ACTOR: &str = "remem pack pull";
+/// `session_id` of the audit events written when a pull retires a memory.
+const PACK_PULL_SESSION: &str = "pack-pull";
+const PACK_REVIEW_BLOCK_REASON: &str = "pack_import_review";
+const PACK_CONFLICT_BLOCK_REASON: &str = "pack_import_conflict";
+const PACK_SUPERSESSION_BLOCK_REASON: &str = "pack_upstream_supersession";
+const PACK_QUARANTINE_BLOCK_REASON: &str = "quarantined_instruction_pattern";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(in crate::cli::actions) struct PackPullReport {
+    pub(in crate::cli::actions) author: Option<String>,
+    pub(in crate::cli::actions) digest: String,
+    pub(in crate::cli::actions) unchanged: usize,
+    pub(in crate::cli::actions) queued: usize,
+    pub(in crate::cli::actions) superseded: usize,
+    pub(in crate::cli::actions) quarantined: usize,
+    pub(in crate::cli::actions) dedup: usize,
+    pub(in crate::cli::actions) skipped: usize,
+    pub(in crate::cli::actions) removed: usize,
+    pub(in crate::cli::actions) retired_memories: usize,
+    pub(in crate::cli::actions) discarded_candidates: usize,
+}
+
+pub(in crate::cli::actions) fn pull_with_connection(
+    conn: &mut Connection,
+    trust: &PackTrustConfig,
+    pack_dir: &Path,
+    project: &str,
+    dry_run: bool,
+    now: i64,
+) -> Result<PackPullReport> {
+    let manifest = signature::read_manifest_bytes(pack_dir)?;
+    let author = signature::verify_pack(pack_dir, &manifest, trust)?;
+    let loaded = load_pack_with_manifest(pack_dir, &manifest)?;
+    let dir_key = state::dir_key(pack_dir);
+    let tx = conn.transaction()?;
+    let tracked = state::load_entries(&tx, project, &dir_key)?;
+    let project_id = ensure_project_row(&tx, project)?;
+    let digest = loaded.manifest.content_digest.clone();
+    let mut report = PackPullReport {
+        author: author.clone(),
+        digest: digest.clone(),
+        ..PackPullReport::default()
+    };
+    let mut withdrawn_hashes = BTreeSet::new();
+    let mut present = BTreeSet::new();
+
+    for memory in &loaded.memories {
+        let identity = pack_identity(memory);
+        let previous = tracked.get(&identity);
+        present.insert(identity);
+        if previous.is_some_and(|entry| entry.content_hash == memory.content_hash) {
+            report.unchanged += 1;
+            continue;
+        }
+        let superseded = previous.is_some();
+        if let Some(previous) = previous {
+            withdrawn_hashes.insert(previous.content_hash.clone());
+        }
+
+        let entry = classify_pack_memory(Some(&tx), project, memory.clone())?;
+        let block_reason = match entry.category {
+            PackImportCategory::Quarantine => {
+                let matched = crate::memory::poisoning::scan_instruction_pattern(&format!(
+                    "{}\n{}",
+                    memory.title, memory.content
+                ))
+                .context("quarantine plan row lost instruction-pattern match before pull")?;
+                let candidate_id = insert_pack_candidate(
+                    &tx,
+                    project_id,
+                    project,
+                    memory,
+                    &digest,
+                    "quarantined",
+                    PACK_QUARANTINE_BLOCK_REASON,
+                    Some(matched),
+                )?;
+                if candidate_id != 0 {
+                    report.quarantined += 1;
+                }
+                continue;
+            }
+            PackImportCategory::Dedup => {
+                report.dedup += 1;
+                continue;
+            }
+            // A retired local identity blocks a fresh pack row, but a newer
+            // upstream version of a row this device already saw still goes
+            // to review unless a suppression covers it.
+            PackImportCategory::Skip
+                if !superseded || is_pack_memory_suppressed(&tx, project, memory)? =>
+            {
+                report.skipped += 1;
+                continue;
+            }
+            _ if superseded => PACK_SUPERSESSION_BLOCK_REASON,
+            PackImportCategory::Conflict => PACK_CONFLICT_BLOCK_REASON,
+            PackImportCategory::Add | PackImportCategory::Skip => PACK_REVIEW_BLOCK_REASON,
+        };
+        let candidate_id = insert_pack_candidate(
+            &tx,
+            project_id,
+            project,
+            memory,
+            &digest,
+            "pending_review",
+            block_reason,
+            None,
+        )?;
+        if candidate_id != 0 {
+            report.queued += 1;
+            if superseded {
+                report.superseded += 1;
+            }
+        }
+    }
+
+    for (identity, entry) in &tracked {
+        if present.contains(identity) {
+            continue;
+        }
+        report.removed += 1;
+        withdrawn_hashes.insert(entry.content_hash.clone());
+        report.retired_memories += retire_removed_memories(&tx, project, entry, now)?;
+    }
+    report.discarded_candidates = discard_withdrawn_candidates(&tx, project, &withdrawn_hashes)?;
+
+    state::record_pack_state(
+        &tx,
+        project,
+        &dir_key,
+        &digest,
+        author.as_deref(),
+        &loaded.memories,
+        now,
+    )?;
+    if !dry_run {
+        tx.commit()?;
+    }
+    Ok(report)
+}
+
+/// Marks active pack-sourced memories that still carry the removed version
+/// stale. Rows edited locally hash differently and are left alone.
+fn retire_removed_memories(
+    conn: &Connection,
+    project: &str,
+    entry: &TrackedEntry,
+    now: i64,
+) -> Result<usize> {
+    let mut stmt = conn.prepare(
+        "SELECT m.id, m.title, m.content, sk.state_key
+         FROM memories m
+         LEFT JOIN memory_state_keys sk ON sk.id = m.state_key_id
+         WHERE m.owner_scope = 'repo'
+           AND m.owner_key = ?1
+           AND m.memory_type = ?2
+           AND m.status = 'active'
+           AND m.source_trust_class = 'pack'",
+    )?;
+    let rows = stmt.query_map(params![project, entry.memory_type], |row| {
+        Ok((
+            row.get::<_, i64>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, Option<String>>(3)?,
+        ))
+    })?;
+    let ids = crate::db::query::collect_rows(rows)?
+        .into_iter()
+        .filter(|(_, title, content, state_key)| {
+            local_pack_content_hash(&entry.memory_type, state_key.as_deref(), title, content)
+                == entry.content_hash
+        })
+        .map(|(id, ..)| id)
+        .collect::<Vec<_>>();
+    for id in &ids {
+        conn.execute(
+            "UPDATE memories
+             SET status = 'stale',
+                 updated_at_epoch = ?1,
+                 valid_to_epoch = COALESCE(valid_to_epoch, ?1)
+             WHERE id = ?2",
+            params![now, id],
+        )?;
+        let detail = serde_json::json!({
+            "action": "pack_pull",
+            "memory_id": id,
+            "previous_status": "active",
+            "new_status": "stale",
+            "reason": "removed from team pack",
+        })
+        .to_string();
+        crate::memory::insert_event(
+            conn,
+            PACK_PULL_SESSION,
+            project,
+            "memory_governance",
+            &format!("pack pull memory {id}: active -> stale"),
+            Some(&detail),
+            None,
+            None,
+        )?;
+    }
+    Ok(ids.len())
+}
+
+fn discard_withdrawn_candidates(
+    conn: &Connection,
+    project: &str,
+    withdrawn_hashes: &BTreeSet<String>,
+) -> Result<usize> {
+    if withdrawn_hashes.is_empty() {
+        return Ok(0);
+    }
+    let mut stmt = conn.prepare(
+        "SELECT id, memory_type, state_key, text
+         FROM memory_candidates
+         WHERE source_kind = 'pack'
+           AND target_project = ?1
+           AND review_status IN ('pending_review', 'quarantined')",
+    )?;
+    let rows = stmt.query_map([project], |row| {
+        Ok((
+            row.get::<_, i64>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, Option<String>>(2)?,
+            row.get::<_, String>(3)?,
+        ))
+    })?;
+    let meta = crate::memory_candidate::review::ReviewMeta {
+        reason: Some("withdrawn from team pack".to_string()),
+        ..crate::memory_candidate::review::ReviewMeta::single(PACK_PULL_ACTOR)
+    };
+    let mut discarded = 0;
+    for (id, memory_type, state_key, text) in crate::db::query::collect_rows(rows)? {
+        let Some((title, content)) = decode_pack_review_text(&text) else {
+            continue;
+        };
+        let hash = pack_memory_content_hash(&memory_type, state_key.as_deref(), &title, &content);
+        if withdrawn_hashes.contains(&hash) && discard_candidate_with_meta(conn, id, &meta)? {
+            discarded += 1;
+        }
+    }
+    Ok(discarded)
+}
+
+pub(in crate::cli::actions) fn render_pull_report(
+    pack_dir: &Path,
+    report: &PackPullReport,
+    dry_run: bool,
+) -> String {
+    let mut output = format!(
+        "Team pack pull{} from {} (digest {}, signed by {}): queued_for_review={} upstream_superseded={} quarantined={} unchanged={} dedup={} skip={} upstream_removed={} retired_memories={} discarded_candidates={}.\n",
+        if dry_run { " dry-run" } else { "" },
+        pack_dir.display(),
+        report.digest,
+        report.author.as_deref().unwrap_or("nobody trusted"),
+        report.queued,
+        report.superseded,
+        report.quarantined,
+        report.unchanged,
+        report.dedup,
+        report.skipped,
+        report.removed,
+        report.retired_memories,
+        report.discarded_candidates
+    );
+    if report.queued + report.quarantined > 0 && !dry_run {
+        output.push_str("Review queued rows with `remem review list`.\n");
+    }
+    output
+}
@@ -0,0 +1,151 @@
+//! `remem pack push`: merges this device's accepted project memories into the
+//! team pack and re-signs it.
+//!
+//! Local memory wins for an identity it changed; rows the pack already holds
+//! with the same content keep their upstream bytes so a push does not churn
+//! timestamps. A pack row whose local copy was retired (stale or archived) is
+//! dropped, which is how a deletion travels upstream. Push refuses to run
+//! over a pack this device has not pulled, like a non-fast-forward git push.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use rusqlite::{params, Connection};
+
+use super::signature::{self, PackSigner};
+use super::state;
+use crate::cli::actions::pack_export::{
+    collect_pack_memories, local_pack_content_hash, sort_pack_memories, write_pack_files,
+    PackMemory,
+};
+use crate::cli::actions::pack_import::{load_pack_with_manifest, pack_identity};
+use crate::runtime_config::PackTrustConfig;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(in crate::cli::actions) struct PackPushReport {
+    pub(in crate::cli::actions) output: PathBuf,
+    pub(in crate::cli::actions) digest: String,
+    pub(in crate::cli::actions) total: usize,
+    pub(in crate::cli::actions) added: usize,
+    pub(in crate::cli::actions) updated: usize,
+    pub(in crate::cli::actions) kept: usize,
+    pub(in crate::cli::actions) dropped: usize,
+}
+
+pub(in crate::cli::actions) fn push_with_connection(
+    conn: &mut Connection,
+    trust: &PackTrustConfig,
+    signer: &PackSigner,
+    pack_dir: &Path,
+    project: &str,
+    limit: i64,
+    now: i64,
+) -> Result<PackPushReport> {
+    std::fs::create_dir_all(pack_dir)
+        .with_context(|| format!("create pack directory {}", pack_dir.display()))?;
+    let dir_key = state::dir_key(pack_dir);
+    let tx = conn.transaction()?;
+
+    let (label, upstream) = if pack_dir.join("pack.json").exists() {
+        let manifest = signature::read_manifest_bytes(pack_dir)?;
+        signature::verify_pack(pack_dir, &manifest, trust)?;
+        let loaded = load_pack_with_manifest(pack_dir, &manifest)?;
+        let head = state::load_head(&tx, project, &dir_key)?;
+        if head.as_deref() != Some(loaded.manifest.content_digest.as_str()) {
+            bail!(
+                "team pack {} changed since this device last pulled it; run `remem pack pull --dir {}` first (code=pack_pull_required)",
+                pack_dir.display(),
+                pack_dir.display()
+            );
+        }
+        (loaded.manifest.project, loaded.memories)
+    } else {
+        (project.to_string(), Vec::new())
+    };
+
+    let mut local = collect_pack_memories(&tx, project, limit)?
+        .into_iter()
+        .map(|memory| (pack_identity(&memory), memory))
+        .collect::<BTreeMap<_, _>>();
+    let mut report = PackPushReport::default();
+    let mut rows = Vec::with_capacity(upstream.len() + local.len());
+    for memory in upstream {
+        match local.remove(&pack_identity(&memory)) {
+            Some(mine) if mine.content_hash == memory.content_hash => {
+                report.kept += 1;
+                rows.push(memory);
+            }
+            Some(mine) => {
+                report.updated += 1;
+                rows.push(mine);
+            }
+            None if retired_locally(&tx, project, &memory)? => report.dropped += 1,
+            None => {
+                report.kept += 1;
+                rows.push(memory);
+            }
+        }
+    }
+    report.added = local.len();
+    rows.extend(local.into_values());
+    sort_pack_memories(&mut rows);
+
+    let stats = write_pack_files(pack_dir, &label, &rows)?;
+    signature::sign_pack(pack_dir, signer)?;
+    state::record_pack_state(
+        &tx,
+        project,
+        &dir_key,
+        &stats.digest,
+        Some(&signer.author),
+        &rows,
+        now,
+    )?;
+    tx.commit()?;
+    report.output = stats.output;
+    report.digest = stats.digest;
+    report.total = stats.exported;
+    Ok(report)
+}
+
+/// True when a local copy of exactly this row exists but is no longer
+/// current, i.e. someone on this device retired it.
+fn retired_locally(conn: &Connection, project: &str, memory: &PackMemory) -> Result<bool> {
+    let mut stmt = conn.prepare(
+        "SELECT m.title, m.content, sk.state_key
+         FROM memories m
+         LEFT JOIN memory_state_keys sk ON sk.id = m.state_key_id
+         WHERE m.owner_scope = 'repo'
+           AND m.owner_key = ?1
+           AND m.memory_type = ?2
+           AND m.status <> 'active'",
+    )?;
+    let rows = stmt.query_map(params![project, memory.memory_type], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, Option<String>>(2)?,
+        ))
+    })?;
+    Ok(crate::db::query::collect_rows(rows)?
+        .into_iter()
+        .any(|(title, content, state_key)| {
+            local_pack_content_hash(&memory.memory_type, state_key.as_deref(), &title, &content)
+                == memory.content_hash
+        }))
+}
+
+pub(in crate::cli::actions) fn render_push_report(report: &PackPushReport, author: &str) -> String {
+    format!(
+        "Pushed {} memories to team pack {} as {} (digest {}): added={} updated={} kept={} dropped={}.\n",
+        report.total,
+        report.output.display(),
+        author,
+        report.digest,
+        report.added,
+        report.updated,
+        report.kept,
+        report.dropped
+    )
+}
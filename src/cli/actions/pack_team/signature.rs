@@ -0,0 +1,173 @@
+//! Ed25519 signatures over a team pack's `pack.json`.
+//!
+//! The manifest carries the SHA-256 digest of `memories.jsonl`, so signing its
+//! exact bytes covers every row; `INDEX.md` is derived and left unsigned. The
+//! author key lives in `<data_dir>/pack/author.key` and never enters the pack.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use base64::Engine as _;
+use ring::signature::{Ed25519KeyPair, KeyPair, UnparsedPublicKey, ED25519};
+use serde::{Deserialize, Serialize};
+
+use crate::runtime_config::PackTrustConfig;
+
+const PACK_SIGNATURE_FILE: &str = "pack.sig";
+const PACK_SIGNATURE_FORMAT: &str = "remem-pack-sig-v1";
+const AUTHOR_KEY_FILE: &str = "author.key";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PackSignature {
+    format: String,
+    author: String,
+    public_key: String,
+    signature: String,
+}
+
+pub(in crate::cli::actions) struct PackSigner {
+    pub(super) author: String,
+    pub(super) key: Ed25519KeyPair,
+}
+
+impl PackSigner {
+    pub(super) fn public_key_base64(&self) -> String {
+        encode_public_key(&self.key)
+    }
+
+    #[cfg(test)]
+    pub(super) fn generate_for_test(author: &str) -> Self {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&ring::rand::SystemRandom::new())
+            .expect("generate test author key");
+        Self {
+            author: author.to_string(),
+            key: Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).expect("parse test author key"),
+        }
+    }
+}
+
+pub(super) fn encode_public_key(key: &Ed25519KeyPair) -> String {
+    base64::engine::general_purpose::STANDARD.encode(key.public_key().as_ref())
+}
+
+pub(super) fn author_key_path() -> Result<PathBuf> {
+    Ok(crate::db::try_data_dir()?
+        .join("pack")
+        .join(AUTHOR_KEY_FILE))
+}
+
+/// Loads this machine's author key, generating it on first use. Returns the
+/// key and whether it was just created.
+pub(super) fn load_or_create_author_key() -> Result<(Ed25519KeyPair, bool)> {
+    let path = author_key_path()?;
+    let (pkcs8, created) = match std::fs::read_to_string(&path) {
+        Ok(encoded) => (
+            base64::engine::general_purpose::STANDARD
+                .decode(encoded.trim())
+                .with_context(|| format!("decode {}", path.display()))?,
+            false,
+        ),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            let document = Ed25519KeyPair::generate_pkcs8(&ring::rand::SystemRandom::new())
+                .map_err(|_| anyhow::anyhow!("generate pack author key"))?;
+            let encoded = base64::engine::general_purpose::STANDARD.encode(document.as_ref());
+            let dir = path.parent().unwrap_or(Path::new("."));
+            crate::cli::actions::sync::write_private_file(
+                dir,
+                &path,
+                format!("{encoded}\n").as_bytes(),
+            )?;
+            (document.as_ref().to_vec(), true)
+        }
+        Err(error) => return Err(error).with_context(|| format!("read {}", path.display())),
+    };
+    let key = Ed25519KeyPair::from_pkcs8(&pkcs8)
+        .map_err(|_| anyhow::anyhow!("parse pack author key {}", path.display()))?;
+    Ok((key, created))
+}
+
+/// Signs the current `pack.json` and writes `pack.sig` next to it.
+pub(super) fn sign_pack(pack_dir: &Path, signer: &PackSigner) -> Result<()> {
+    let manifest = read_manifest_bytes(pack_dir)?;
+    let signature = PackSignature {
+        format: PACK_SIGNATURE_FORMAT.to_string(),
+        author: signer.author.clone(),
+        public_key: signer.public_key_base64(),
+        signature: base64::engine::general_purpose::STANDARD
+            .encode(signer.key.sign(&manifest).as_ref()),
+    };
+    crate::atomic_file::write_atomic(
+        pack_dir.join(PACK_SIGNATURE_FILE),
+        format!("{}\n", serde_json::to_string_pretty(&signature)?),
+    )
+}
+
+/// Checks `pack.sig` over the given `pack.json` bytes against the trusted
+/// author list. Returns the trusted author, or `None` for an unsigned pack
+/// when signatures are not required. A signature that does not verify is
+/// rejected even when not required.
+pub(super) fn verify_pack(
+    pack_dir: &Path,
+    manifest: &[u8],
+    trust: &PackTrustConfig,
+) -> Result<Option<String>> {
+    let path = pack_dir.join(PACK_SIGNATURE_FILE);
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            if trust.require_signature {
+                bail!(
+                    "team pack {} is not signed; ask a trusted author to run `remem pack push` or set pack.require_signature = false (code=pack_signature_missing)",
+                    pack_dir.display()
+                );
+            }
+            return Ok(None);
+        }
+        Err(error) => return Err(error).with_context(|| format!("read {}", path.display())),
+    };
+    let signature: PackSignature = serde_json::from_str(&text)
+        .with_context(|| format!("parse {} (code=pack_signature_format)", path.display()))?;
+    if signature.format != PACK_SIGNATURE_FORMAT {
+        bail!(
+            "unsupported pack signature format {:?} in {} (code=pack_signature_format)",
+            signature.format,
+            path.display()
+        );
+    }
+    let engine = base64::engine::general_purpose::STANDARD;
+    let public_key = engine
+        .decode(signature.public_key.trim())
+        .with_context(|| format!("decode public_key in {}", path.display()))?;
+    let signature_bytes = engine
+        .decode(signature.signature.trim())
+        .with_context(|| format!("decode signature in {}", path.display()))?;
+    UnparsedPublicKey::new(&ED25519, &public_key)
+        .verify(manifest, &signature_bytes)
+        .map_err(|_| {
+            anyhow::anyhow!(
+                "team pack {} signature does not match pack.json (code=pack_signature_invalid)",
+                pack_dir.display()
+            )
+        })?;
+
+    let trusted = trust
+        .trusted_authors
+        .get(&signature.author)
+        .is_some_and(|key| key.trim() == signature.public_key.trim());
+    if trusted {
+        return Ok(Some(signature.author));
+    }
+    if trust.require_signature {
+        bail!(
+            "team pack {} is signed by {:?} with a key not listed in [pack.trusted_authors] (code=pack_untrusted_author)",
+            pack_dir.display(),
+            signature.author
+        );
+    }
+    Ok(None)
+}
+
+pub(super) fn read_manifest_bytes(pack_dir: &Path) -> Result<Vec<u8>> {
+    let path = pack_dir.join("pack.json");
+    std::fs::read(&path).with_context(|| format!("read {}", path.display()))
+}
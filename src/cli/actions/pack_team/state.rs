@@ -0,0 +1,98 @@
+//! What this device last saw of a team pack directory: the pack digest and
+//! every identity with its content hash, replaced wholesale after each pull
+//! or push.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::Result;
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::cli::actions::pack_export::PackMemory;
+use crate::cli::actions::pack_import::pack_identity;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct TrackedEntry {
+    pub(super) memory_type: String,
+    pub(super) content_hash: String,
+}
+
+/// Canonical pack directory path used to key the tracking rows.
+pub(super) fn dir_key(pack_dir: &Path) -> String {
+    std::fs::canonicalize(pack_dir)
+        .unwrap_or_else(|_| pack_dir.to_path_buf())
+        .to_string_lossy()
+        .to_string()
+}
+
+pub(super) fn load_head(conn: &Connection, project: &str, dir_key: &str) -> Result<Option<String>> {
+    conn.query_row(
+        "SELECT content_digest FROM pack_sync_heads WHERE project = ?1 AND pack_dir = ?2",
+        params![project, dir_key],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(Into::into)
+}
+
+pub(super) fn load_entries(
+    conn: &Connection,
+    project: &str,
+    dir_key: &str,
+) -> Result<BTreeMap<String, TrackedEntry>> {
+    let mut stmt = conn.prepare(
+        "SELECT identity, memory_type, content_hash
+         FROM pack_sync_entries
+         WHERE project = ?1 AND pack_dir = ?2",
+    )?;
+    let rows = stmt.query_map(params![project, dir_key], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            TrackedEntry {
+                memory_type: row.get(1)?,
+                content_hash: row.get(2)?,
+            },
+        ))
+    })?;
+    Ok(crate::db::query::collect_rows(rows)?.into_iter().collect())
+}
+
+pub(super) fn record_pack_state(
+    conn: &Connection,
+    project: &str,
+    dir_key: &str,
+    content_digest: &str,
+    author: Option<&str>,
+    memories: &[PackMemory],
+    now: i64,
+) -> Result<()> {
+    conn.execute(
+        "INSERT INTO pack_sync_heads (project, pack_dir, content_digest, author, updated_at_epoch)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(project, pack_dir) DO UPDATE SET
+             content_digest = excluded.content_digest,
+             author = excluded.author,
+             updated_at_epoch = excluded.updated_at_epoch",
+        params![project, dir_key, content_digest, author, now],
+    )?;
+    conn.execute(
+        "DELETE FROM pack_sync_entries WHERE project = ?1 AND pack_dir = ?2",
+        params![project, dir_key],
+    )?;
+    let mut insert = conn.prepare(
+        "INSERT INTO pack_sync_entries
+         (project, pack_dir, identity, memory_type, content_hash, seen_at_epoch)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+    )?;
+    for memory in memories {
+        insert.execute(params![
+            project,
+            dir_key,
+            pack_identity(memory),
+            memory.memory_type,
+            memory.content_hash,
+            now
+        ])?;
+    }
+    Ok(())
+}
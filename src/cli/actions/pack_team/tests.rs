@@ -0,0 +1,293 @@
+//! Two teammates sharing one pack directory: push/pull round trip through the
+//! review queue, upstream supersession and deletion, and signature trust.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use rusqlite::{params, Connection};
+
+use super::{pull_with_connection, push_with_connection, PackSigner};
+use crate::memory_candidate::review::approve_candidate;
+use crate::runtime_config::PackTrustConfig;
+
+const PROJECT: &str = "/repo";
+
+fn device_db() -> Result<Connection> {
+    let conn = Connection::open_in_memory()?;
+    conn.execute_batch("PRAGMA journal_mode=WAL; PRAGMA foreign_keys=ON;")?;
+    crate::migrate::run_migrations(&conn)?;
+    Ok(conn)
+}
+
+fn unique_pack_dir(label: &str) -> PathBuf {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    std::env::temp_dir().join(format!("remem-{label}-{}-{nanos}", std::process::id()))
+}
+
+fn trusting(signers: &[&PackSigner]) -> PackTrustConfig {
+    PackTrustConfig {
+        require_signature: true,
+        author: None,
+        trusted_authors: signers
+            .iter()
+            .map(|signer| (signer.author.clone(), signer.public_key_base64()))
+            .collect::<BTreeMap<_, _>>(),
+    }
+}
+
+fn insert_memory(
+    conn: &Connection,
+    state_key: Option<&str>,
+    title: &str,
+    content: &str,
+    epoch: i64,
+) -> Result<i64> {
+    conn.execute(
+        "INSERT INTO memories
+         (project, title, content, memory_type, created_at_epoch, updated_at_epoch,
+          status, scope, source_project, target_project, owner_scope, owner_key, context_class)
+         VALUES (?1, ?2, ?3, 'decision', ?4, ?4, 'active', 'project',
+                 ?1, ?1, 'repo', ?1, 'startup_core')",
+        params![PROJECT, title, content, epoch],
+    )?;
+    let id = conn.last_insert_rowid();
+    if let Some(state_key) = state_key {
+        crate::memory::state_key::attach_current_memory(
+            conn,
+            id,
+            "repo",
+            PROJECT,
+            "decision",
+            &crate::memory::state_key::StateKeyDecision {
+                state_key: state_key.to_string(),
+                confidence: 1.0,
+                reason: "test".to_string(),
+            },
+            epoch,
+        )?;
+    }
+    Ok(id)
+}
+
+fn pending_pack_candidates(conn: &Connection) -> Result<Vec<(i64, String)>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, auto_promote_block_reason FROM memory_candidates
+         WHERE source_kind = 'pack' AND review_status = 'pending_review'
+         ORDER BY id",
+    )?;
+    let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+    crate::db::query::collect_rows(rows)
+}
+
+fn candidate_titled(conn: &Connection, title: &str) -> Result<i64> {
+    Ok(conn.query_row(
+        "SELECT id FROM memory_candidates WHERE source_kind = 'pack' AND text LIKE ?1",
+        [format!("pack_title: {title}\n%")],
+        |row| row.get(0),
+    )?)
+}
+
+fn expect_code<T: std::fmt::Debug>(result: Result<T>, code: &str) {
+    let error = result.expect_err("pack must be refused");
+    assert!(format!("{error:#}").contains(code), "{error:#}");
+}
+
+fn active_contents(conn: &Connection) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        "SELECT content FROM memories WHERE project = ?1 AND status = 'active' ORDER BY content",
+    )?;
+    let rows = stmt.query_map([PROJECT], |row| row.get(0))?;
+    crate::db::query::collect_rows(rows)
+}
+
+#[test]
+fn pulled_pack_rows_wait_for_review_and_pull_again_is_a_noop() -> Result<()> {
+    let dir = unique_pack_dir("pack-team-round-trip");
+    let now = chrono::Utc::now().timestamp();
+    let alice = PackSigner::generate_for_test("alice");
+    let trust = trusting(&[&alice]);
+    let mut alice_db = device_db()?;
+    let mut bob_db = device_db()?;
+    insert_memory(
+        &alice_db,
+        Some("deploy-strategy"),
+        "Deploy strategy",
+        "Use blue-green deploys",
+        now - 100,
+    )?;
+    insert_memory(&alice_db, None, "Changelog", "Keep the changelog", now - 90)?;
+
+    let pushed = push_with_connection(&mut alice_db, &trust, &alice, &dir, PROJECT, 100, now)?;
+    assert_eq!((pushed.total, pushed.added), (2, 2));
+    assert!(dir.join("pack.sig").exists());
+
+    let pulled = pull_with_connection(&mut bob_db, &trust, &dir, PROJECT, false, now)?;
+    assert_eq!(pulled.author.as_deref(), Some("alice"));
+    assert_eq!(pulled.queued, 2);
+    assert!(
+        active_contents(&bob_db)?.is_empty(),
+        "pack rows must not become active without review"
+    );
+    let pending = pending_pack_candidates(&bob_db)?;
+    assert!(pending
+        .iter()
+        .all(|(_, reason)| reason == "pack_import_review"));
+
+    let memory_id = approve_candidate(&mut bob_db, pending[0].0)?.expect("approve pack row");
+    let trust_class: String = bob_db.query_row(
+        "SELECT source_trust_class FROM memories WHERE id = ?1",
+        [memory_id],
+        |row| row.get(0),
+    )?;
+    assert_eq!(trust_class, "pack");
+
+    let again = pull_with_connection(&mut bob_db, &trust, &dir, PROJECT, false, now + 5)?;
+    assert_eq!((again.unchanged, again.queued), (2, 0));
+    let _ = std::fs::remove_dir_all(&dir);
+    Ok(())
+}
+
+#[test]
+fn upstream_supersession_and_deletion_reach_teammate() -> Result<()> {
+    let dir = unique_pack_dir("pack-team-upstream");
+    let now = chrono::Utc::now().timestamp();
+    let alice = PackSigner::generate_for_test("alice");
+    let bob = PackSigner::generate_for_test("bob");
+    let trust = trusting(&[&alice, &bob]);
+    let mut alice_db = device_db()?;
+    let mut bob_db = device_db()?;
+    let old_runner = insert_memory(
+        &alice_db,
+        Some("queue-runner"),
+        "Queue runner",
+        "Queue runner stays single-threaded",
+        now - 200,
+    )?;
+    let cache = insert_memory(&alice_db, None, "Cache", "Cache lives in /tmp", now - 190)?;
+    let lint = insert_memory(&alice_db, None, "Lint", "Run clippy before push", now - 180)?;
+    push_with_connection(&mut alice_db, &trust, &alice, &dir, PROJECT, 100, now)?;
+
+    pull_with_connection(&mut bob_db, &trust, &dir, PROJECT, false, now)?;
+    for title in ["Queue runner", "Cache"] {
+        let id = candidate_titled(&bob_db, title)?;
+        approve_candidate(&mut bob_db, id)?;
+    }
+    let lint_candidate = candidate_titled(&bob_db, "Lint")?;
+
+    alice_db.execute(
+        "UPDATE memories SET status = 'stale' WHERE id IN (?1, ?2, ?3)",
+        params![old_runner, cache, lint],
+    )?;
+    insert_memory(
+        &alice_db,
+        Some("queue-runner"),
+        "Queue runner",
+        "Queue runner uses four workers",
+        now - 50,
+    )?;
+    let pushed = push_with_connection(&mut alice_db, &trust, &alice, &dir, PROJECT, 100, now)?;
+    assert_eq!(
+        (pushed.updated, pushed.dropped, pushed.kept),
+        (1, 2, 0),
+        "{pushed:?}"
+    );
+
+    let pulled = pull_with_connection(&mut bob_db, &trust, &dir, PROJECT, false, now + 10)?;
+    assert_eq!(
+        (
+            pulled.superseded,
+            pulled.removed,
+            pulled.retired_memories,
+            pulled.discarded_candidates
+        ),
+        (1, 2, 1, 1),
+        "{pulled:?}"
+    );
+    let cache_status: String = bob_db.query_row(
+        "SELECT status FROM memories WHERE content = 'Cache lives in /tmp'",
+        [],
+        |row| row.get(0),
+    )?;
+    assert_eq!(cache_status, "stale");
+    let lint_status: String = bob_db.query_row(
+        "SELECT review_status FROM memory_candidates WHERE id = ?1",
+        [lint_candidate],
+        |row| row.get(0),
+    )?;
+    assert_eq!(lint_status, "discarded");
+    let supersession = pending_pack_candidates(&bob_db)?
+        .into_iter()
+        .find(|(_, reason)| reason == "pack_upstream_supersession")
+        .expect("newer upstream version is queued for review");
+
+    approve_candidate(&mut bob_db, supersession.0)?;
+    let current = crate::memory::state_key::current_memory_id(
+        &bob_db,
+        "repo",
+        PROJECT,
+        "decision",
+        "queue-runner",
+        now + 20,
+    )?
+    .expect("state key has a current memory");
+    let content: String = bob_db.query_row(
+        "SELECT content FROM memories WHERE id = ?1",
+        [current],
+        |row| row.get(0),
+    )?;
+    assert_eq!(content, "Queue runner uses four workers");
+    let _ = std::fs::remove_dir_all(&dir);
+    Ok(())
+}
+
+#[test]
+fn untrusted_tampered_unsigned_and_unpulled_packs_are_refused() -> Result<()> {
+    let dir = unique_pack_dir("pack-team-trust");
+    let now = chrono::Utc::now().timestamp();
+    let alice = PackSigner::generate_for_test("alice");
+    let mallory = PackSigner::generate_for_test("alice");
+    let trust = trusting(&[&alice]);
+    let mut alice_db = device_db()?;
+    insert_memory(&alice_db, None, "Note", "Keep the changelog", now - 100)?;
+    push_with_connection(&mut alice_db, &trust, &alice, &dir, PROJECT, 100, now)?;
+
+    let mut bob_db = device_db()?;
+    insert_memory(&bob_db, None, "Local", "Bob's own note", now - 100)?;
+    expect_code(
+        push_with_connection(&mut bob_db, &trust, &alice, &dir, PROJECT, 100, now),
+        "code=pack_pull_required",
+    );
+    expect_code(
+        pull_with_connection(
+            &mut bob_db,
+            &trusting(&[&mallory]),
+            &dir,
+            PROJECT,
+            false,
+            now,
+        ),
+        "code=pack_untrusted_author",
+    );
+
+    let manifest_path = dir.join("pack.json");
+    let manifest = std::fs::read_to_string(&manifest_path)?;
+    std::fs::write(&manifest_path, manifest.replace("remem", "remem "))?;
+    expect_code(
+        pull_with_connection(&mut bob_db, &trust, &dir, PROJECT, false, now),
+        "code=pack_signature_invalid",
+    );
+
+    std::fs::write(&manifest_path, manifest)?;
+    std::fs::remove_file(dir.join("pack.sig"))?;
+    expect_code(
+        pull_with_connection(&mut bob_db, &trust, &dir, PROJECT, false, now),
+        "code=pack_signature_missing",
+    );
+    assert!(pending_pack_candidates(&bob_db)?.is_empty());
+    let _ = std::fs::remove_dir_all(&dir);
+    Ok(())
+}
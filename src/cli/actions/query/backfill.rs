@@ -5,62 +5,108 @@ use std::time::Instant;
 
 use crate::db;
 
-pub(in crate::cli) fn run_backfill_entities() -> Result<()> {
+pub(in crate::cli) fn run_backfill_entities(project: Option<&str>, reextract: bool) -> Result<()> {
     let conn = db::open_db()?;
     let count: i64 = conn
         .query_row(
-            "SELECT COUNT(*) FROM memories WHERE status = 'active'",
-            [],
+            "SELECT COUNT(*) FROM memories
+             WHERE status = 'active' AND (?1 IS NULL OR project = ?1)",
+            [project],
             |row| row.get(0),
         )
         .unwrap_or(0);
-    println!("Backfilling entities from {} active memories...", count);
-
-    let mut stmt =
-        conn.prepare("SELECT id, title, content FROM memories WHERE status = 'active'")?;
-    let rows = stmt.query_map([], |row| {
-        Ok((
-            row.get::<_, i64>(0)?,
-            row.get::<_, String>(1)?,
-            row.get::<_, String>(2)?,
-        ))
+    if reextract {
+        println!("Re-extracting entities for {} active memories...", count);
+    } else {
+        println!("Backfilling entities from {} active memories...", count);
+    }
+    let report = backfill_entities_with_connection(&conn, project, reextract, |processed| {
+        if processed.is_multiple_of(100) {
+            println!("  processed {}/{}", processed, count);
+        }
     })?;
+    println!(
+        "Done. {} entities extracted, {} unique entities, {} memories processed{}.",
+        report.entities_extracted,
+        report.unique_entities,
+        report.memories_processed,
+        if reextract {
+            format!(", {} orphaned entities pruned", report.orphans_pruned)
+        } else {
+            String::new()
+        }
+    );
+    Ok(())
+}
 
-    print_backfill_progress(&conn, rows, count)
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(in crate::cli) struct EntityBackfillReport {
+    pub memories_processed: usize,
+    pub entities_extracted: usize,
+    pub unique_entities: i64,
+    pub orphans_pruned: usize,
 }
 
-fn print_backfill_progress<F>(
+/// Links entities for every active memory with the project-aware extractor.
+/// `reextract` replaces each memory's links instead of adding to them and
+/// then prunes entities no memory mentions anymore, so links made by an
+/// older extractor do not linger in the graph.
+pub(in crate::cli) fn backfill_entities_with_connection(
     conn: &Connection,
-    rows: rusqlite::MappedRows<'_, F>,
-    count: i64,
-) -> Result<()>
-where
-    F: FnMut(&rusqlite::Row<'_>) -> rusqlite::Result<(i64, String, String)>,
-{
-    let mut total_entities = 0usize;
-    let mut memories_processed = 0usize;
-
-    for row in rows {
-        let (id, title, content) = row?;
-        let entities = crate::retrieval::entity::extract_entities(&title, &content);
-        if !entities.is_empty() {
+    project: Option<&str>,
+    reextract: bool,
+    mut on_progress: impl FnMut(usize),
+) -> Result<EntityBackfillReport> {
+    let mut stmt = conn.prepare(
+        "SELECT id, project, title, content FROM memories
+         WHERE status = 'active' AND (?1 IS NULL OR project = ?1)
+         ORDER BY id",
+    )?;
+    let rows = stmt
+        .query_map([project], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, Option<String>>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut report = EntityBackfillReport::default();
+    for (id, memory_project, title, content) in rows {
+        let entities = match memory_project.as_deref() {
+            Some(memory_project) => crate::retrieval::entity::extract_project_entities(
+                conn,
+                memory_project,
+                &title,
+                &content,
+            ),
+            None => crate::retrieval::entity::extract_entities(&title, &content),
+        };
+        if reextract {
+            crate::retrieval::entity::refresh_memory_entities(conn, id, &entities)?;
+        } else if !entities.is_empty() {
             crate::retrieval::entity::link_entities(conn, id, &entities)?;
-            total_entities += entities.len();
-        }
-        memories_processed += 1;
-        if memories_processed.is_multiple_of(100) {
-            println!("  processed {}/{}", memories_processed, count);
         }
+        report.entities_extracted += entities.len();
+        report.memories_processed += 1;
+        on_progress(report.memories_processed);
     }
 
-    let unique: i64 = conn
+    if reextract {
+        report.orphans_pruned = conn.execute(
+            "DELETE FROM entities
+             WHERE NOT EXISTS (
+                 SELECT 1 FROM memory_entities WHERE entity_id = entities.id
+             )",
+            [],
+        )?;
+    }
+    report.unique_entities = conn
         .query_row("SELECT COUNT(*) FROM entities", [], |row| row.get(0))
         .unwrap_or(0);
-    println!(
-        "Done. {} entities extracted, {} unique entities, {} memories processed.",
-        total_entities, unique, memories_processed
-    );
-    Ok(())
+    Ok(report)
 }
 
 pub(in crate::cli) fn run_backfill_embeddings(limit: i64, batch_size: i64) -> Result<()> {
@@ -641,9 +641,11 @@ fn cli_why_render_distinguishes_visibility_from_query_scoring() {
         None,
         None,
         &[],
+        &[],
     );
 
     assert!(output.contains("Memory #1"));
+    assert!(!output.contains("code anchors"));
     assert!(output.contains("project match: exact proj"));
     assert!(output.contains("branch match: branchless; visible in branch-scoped search for main"));
     assert!(output.contains("type: core memory type"));
@@ -673,9 +675,118 @@ fn cli_why_render_exposes_pack_attribution() {
         None,
         Some(&pack),
         &[],
+        &[],
     );
 
     assert!(output.contains(
         "pack attribution: origin=pack:abc123def456 imported_from=repo:/source trust=pack"
     ));
 }
+
+#[test]
+fn cli_why_render_shows_drifted_anchor_diff() {
+    let anchor =
+        |symbol: &str, status: &str, diff: Option<&str>| crate::memory::anchors::MemoryAnchor {
+            id: 1,
+            memory_id: 1,
+            file_path: "src/cache.rs".to_string(),
+            symbol: Some(symbol.to_string()),
+            start_line: Some(8),
+            end_line: Some(10),
+            repo_path: Some("/repo".to_string()),
+            base_commit_sha: Some("1111111111111111".to_string()),
+            resolved_commit_sha: Some("2222222222222222".to_string()),
+            resolved_commit_epoch: Some(1_700_000_000),
+            resolved_start_line: Some(9),
+            resolved_end_line: Some(11),
+            drift_status: status.to_string(),
+            drift_diff: diff.map(str::to_string),
+            checked_at_epoch: Some(1_700_000_000),
+        };
+    let anchors = [
+        anchor("Cache::len", "moved", None),
+        anchor(
+            "Cache::evict",
+            "changed",
+            Some("@@ -9 +10 @@\n-        self.size = 0;\n+        self.size = 7;"),
+        ),
+    ];
+
+    let output = render_why_memory(
+        &sample_memory(),
+        Some("proj"),
+        Some("main"),
+        None,
+        None,
+        None,
+        &[],
+        &anchors,
+    );
+
+    assert!(output.contains("  code anchors:\n"));
+    assert!(output.contains("    - src/cache.rs::Cache::len L8-10: moved at 2222222 (now L9-11)\n"));
+    assert!(
+        output.contains("    - src/cache.rs::Cache::evict L8-10: changed at 2222222 (now L9-11)\n")
+    );
+    assert!(output.contains("      +        self.size = 7;\n"));
+}
+
+#[test]
+fn backfill_entities_reextract_replaces_stale_links_and_prunes_orphans() -> anyhow::Result<()> {
+    let _data_dir = crate::db::test_support::ScopedTestDataDir::new("backfill-entities-reextract");
+    let conn = crate::db::test_support::runtime_connection()?;
+    let id = crate::memory::insert_memory(
+        &conn,
+        None,
+        "proj/reextract",
+        None,
+        "Fix GH-823 in search_multihop",
+        "discover_entities() now reads the project vocabulary",
+        "bugfix",
+        None,
+    )?;
+    crate::retrieval::entity::link_entities(&conn, id, &["LegacyOnlyTerm".to_string()])?;
+
+    let additive = super::backfill::backfill_entities_with_connection(
+        &conn,
+        Some("proj/reextract"),
+        false,
+        |_| {},
+    )?;
+    assert_eq!(additive.orphans_pruned, 0);
+    let legacy_links: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM entities WHERE canonical_name = 'LegacyOnlyTerm'",
+        [],
+        |row| row.get(0),
+    )?;
+    assert_eq!(legacy_links, 1, "additive backfill keeps existing links");
+
+    let report = super::backfill::backfill_entities_with_connection(
+        &conn,
+        Some("proj/reextract"),
+        true,
+        |_| {},
+    )?;
+
+    assert_eq!(report.memories_processed, 1);
+    assert_eq!(report.orphans_pruned, 1);
+    let mut stmt = conn.prepare(
+        "SELECT e.canonical_name FROM memory_entities me
+         JOIN entities e ON e.id = me.entity_id
+         WHERE me.memory_id = ?1 ORDER BY e.canonical_name",
+    )?;
+    let names = stmt
+        .query_map([id], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    for expected in ["GH-823", "search_multihop", "discover_entities"] {
+        assert!(
+            names.iter().any(|name| name == expected),
+            "missing {expected}: {names:?}"
+        );
+    }
+    assert!(
+        !names.iter().any(|name| name == "LegacyOnlyTerm"),
+        "{names:?}"
+    );
+    Ok(())
+}
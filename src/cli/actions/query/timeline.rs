@@ -2,6 +2,7 @@ use anyhow::{bail, Result};
 use serde::Serialize;
 
 use crate::cli::types::TimelineAction;
+use crate::timeline::TimelineReportFormat;
 use crate::{db, retrieval::search::search_observations};
 
 use super::show::format_memory_timestamp;
@@ -26,8 +27,9 @@ pub(in crate::cli) fn run_timeline(action: TimelineAction) -> Result<()> {
         TimelineAction::Report {
             project,
             full,
+            format,
             json,
-        } => run_timeline_report(&project, full, json),
+        } => run_timeline_report(&project, full, format.into(), json),
     }
 }
 
@@ -84,19 +86,29 @@ fn resolve_anchor(
     Ok(result.id)
 }
 
-fn run_timeline_report(project: &str, full: bool, json: bool) -> Result<()> {
+fn run_timeline_report(
+    project: &str,
+    full: bool,
+    format: TimelineReportFormat,
+    json: bool,
+) -> Result<()> {
     let conn = db::open_db()?;
+    let report = crate::timeline::generate_timeline_report_data(&conn, project, full)?;
     if json {
         let output = TimelineReportJson {
             project: project.to_string(),
             full,
-            report: crate::timeline::generate_timeline_report_data(&conn, project, full)?,
+            report,
         };
         println!("{}", serde_json::to_string_pretty(&output)?);
         return Ok(());
     }
-    let report = crate::timeline::generate_timeline_report(&conn, project, full)?;
-    print!("{report}");
+    let rendered = crate::timeline::render_timeline_report(&report, format)?;
+    if format == TimelineReportFormat::Json {
+        println!("{rendered}");
+    } else {
+        print!("{rendered}");
+    }
     Ok(())
 }
 
@@ -3,7 +3,7 @@ use rusqlite::OptionalExtension;
 
 use crate::{
     db,
-    memory::{self, suppression::SuppressionRecord, Memory},
+    memory::{self, anchors::MemoryAnchor, suppression::SuppressionRecord, Memory},
 };
 
 use super::show::format_memory_timestamp;
@@ -50,6 +50,13 @@ pub(in crate::cli) fn run_why(id: i64, project: Option<&str>, branch: Option<&st
     let currentness = load_memory_currentness(&conn, memory.id)?;
     let pack_attribution = load_pack_attribution(&conn, memory.id)?;
     let suppressions = memory::suppression::active_suppressions_for_memory(&conn, memory.id)?;
+    if let Err(error) = memory::anchors::refresh_memory_anchors(&conn, memory.id) {
+        crate::log::warn(
+            "why",
+            &format!("anchor re-resolution for memory #{id} failed: {error:#}"),
+        );
+    }
+    let anchors = memory::anchors::memory_anchors(&conn, memory.id)?;
 
     print!(
         "{}",
@@ -61,6 +68,7 @@ pub(in crate::cli) fn run_why(id: i64, project: Option<&str>, branch: Option<&st
             currentness.as_ref(),
             pack_attribution.as_ref(),
             &suppressions,
+            &anchors,
         )
     );
     Ok(())
@@ -184,6 +192,7 @@ pub(super) fn render_why_memory(
     currentness: Option<&MemoryCurrentness>,
     pack_attribution: Option<&PackAttribution>,
     suppressions: &[SuppressionRecord],
+    anchors: &[MemoryAnchor],
 ) -> String {
     let mut output = String::new();
     output.push_str(&format!("Memory #{}\n", memory.id));
@@ -219,6 +228,12 @@ pub(super) fn render_why_memory(
             pack_attribution_visibility(pack_attribution)
         ));
     }
+    if !anchors.is_empty() {
+        output.push_str("  code anchors:\n");
+        for anchor in anchors {
+            output.push_str(&anchor_visibility(anchor));
+        }
+    }
     output.push_str(&format!(
         "  recency: updated {}\n",
         format_memory_timestamp(memory.updated_at_epoch)
@@ -241,6 +256,33 @@ pub(super) fn render_why_memory(
     output
 }
 
+fn anchor_visibility(anchor: &MemoryAnchor) -> String {
+    let at = anchor
+        .resolved_commit_sha
+        .as_deref()
+        .map(|sha| format!(" at {}", crate::git_util::short_sha_for(sha)))
+        .unwrap_or_default();
+    let now = match (anchor.resolved_start_line, anchor.resolved_end_line) {
+        (Some(start), Some(end)) if anchor.drift_status != "unchanged" => {
+            format!(" (now L{start}-{end})")
+        }
+        _ => String::new(),
+    };
+    let mut line = format!(
+        "    - {}: {}{at}{now}\n",
+        anchor.label(),
+        anchor.drift_status
+    );
+    if anchor.has_drifted() {
+        if let Some(diff) = anchor.drift_diff.as_deref() {
+            for diff_line in diff.lines() {
+                line.push_str(&format!("      {diff_line}\n"));
+            }
+        }
+    }
+    line
+}
+
 fn pack_attribution_visibility(pack: &PackAttribution) -> String {
     let imported_from = pack
         .routing_reason
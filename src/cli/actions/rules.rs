@@ -5,7 +5,7 @@ use anyhow::Result;
 use crate::cli::cwd::resolve_cwd_arg;
 use crate::cli::types::{RuleActionArg, RuleHostArg, RulesAction};
 use crate::db;
-use crate::rules::{self, RuleAction, RulePredicate};
+use crate::rules::{self, RuleAction, RuleOrigin, RulePredicate};
 
 pub(in crate::cli) fn run_rules(action: RulesAction) -> Result<()> {
     let (project_arg, mutation) = match action {
@@ -53,12 +53,15 @@ pub(in crate::cli) fn run_rules(action: RulesAction) -> Result<()> {
     }
 
     let project_rules = rules::list_project_rules(&data_dir, &project)?;
-    if project_rules.rules.is_empty() {
+    if let Some(error) = &project_rules.skipped_source {
+        println!("Warning: skipped a broken rules source; the rules below still apply: {error}");
+    }
+    if project_rules.rules.is_empty() && project_rules.shadowed.is_empty() {
         println!("No compiled rules for project '{}'.", project_rules.project);
         return Ok(());
     }
     println!(
-        "Compiled rules for '{}' (compiled_at_epoch={}):",
+        "Rules for '{}' (compiled_at_epoch={}):",
         project_rules.project, project_rules.compiled_at_epoch
     );
     for rule in project_rules.rules {
@@ -68,11 +71,19 @@ pub(in crate::cli) fn run_rules(action: RulesAction) -> Result<()> {
             .action_override
             .map(action_label)
             .unwrap_or("default");
+        let provenance = match rule.origin {
+            RuleOrigin::Compiled => format!(
+                "origin=compiled source_memory={} reinforcement={}",
+                rule.source_memory_id, rule.reinforcement_count
+            ),
+            RuleOrigin::Authored => {
+                format!("origin=authored source={}", rules::AUTHORED_RULES_FILE)
+            }
+        };
         println!(
-            "  {} source_memory={} reinforcement={} predicate={} data={} base_action={} effective_action={} override={} disabled={}",
+            "  {} {} predicate={} data={} base_action={} effective_action={} override={} disabled={}",
             rule.rule_id,
-            rule.source_memory_id,
-            rule.reinforcement_count,
+            provenance,
             predicate_kind,
             predicate_data,
             action_label(rule.action),
@@ -81,6 +92,12 @@ pub(in crate::cli) fn run_rules(action: RulesAction) -> Result<()> {
             rule.override_state.disabled
         );
     }
+    for shadowed in project_rules.shadowed {
+        println!(
+            "  {} origin=compiled shadowed_by={} (not evaluated)",
+            shadowed.rule_id, shadowed.authored_rule_id
+        );
+    }
     Ok(())
 }
 
@@ -113,60 +130,115 @@ fn run_rules_eval(host: Option<RuleHostArg>) -> Result<()> {
     }
     let session_hint = rules::session_id_hint(&raw);
     let project_hint = rules::project_hint(&raw);
+    let output = rules::with_additional_context(rule_verdict_output(&raw, host, &data_dir), || {
+        file_touch_context(&raw, host)
+    });
+    if let Some(output) = output {
+        match serde_json::to_string(&output) {
+            Ok(output) => println!("{output}"),
+            Err(error) => rules::log_evaluation_error_once_with_diagnostic(
+                &data_dir,
+                session_hint.as_deref(),
+                project_hint.as_deref(),
+                &[rules::EvaluationDiagnosticCode::OutputSerialize],
+                &format!("serialize Claude PreToolUse hook output: {error}"),
+            ),
+        }
+    }
+    Ok(())
+}
+
+/// Compiled/authored rule verdict for the hook input. Every failure is logged
+/// once per session and fails open.
+fn rule_verdict_output(
+    raw: &str,
+    host: Option<RuleHostArg>,
+    data_dir: &std::path::Path,
+) -> Option<serde_json::Value> {
+    let session_hint = rules::session_id_hint(raw);
+    let project_hint = rules::project_hint(raw);
     let config = match crate::runtime_config::rule_compilation_config() {
         Ok(config) => config,
         Err(error) => {
             rules::log_evaluation_error_once_with_diagnostic(
-                &data_dir,
+                data_dir,
                 session_hint.as_deref(),
                 None,
                 &[rules::EvaluationDiagnosticCode::Config],
                 &format!("read rule compilation config: {error:#}"),
             );
-            return Ok(());
+            return None;
         }
     };
     let evaluated = match rules::evaluate_pre_tool_use_with_diagnostics(
-        &raw,
+        raw,
         host.map(rule_host_label),
-        &data_dir,
+        data_dir,
         config.enabled,
     ) {
         Ok(evaluated) => evaluated,
         Err(error) => {
             rules::log_evaluation_error_once_with_diagnostic(
-                &data_dir,
+                data_dir,
                 session_hint.as_deref(),
                 project_hint.as_deref(),
                 &[rules::EvaluationDiagnosticCode::HookInput],
                 &format!("{error:#}"),
             );
-            return Ok(());
+            return None;
         }
     };
+    if let Some((code, message)) = &evaluated.skipped_source {
+        // The other rules source still decided the verdict below; record the
+        // broken one so doctor surfaces it.
+        rules::log_evaluation_error_once_with_diagnostic(
+            data_dir,
+            evaluated.evaluation.session_id.as_deref(),
+            evaluated.project.as_deref(),
+            &[*code],
+            message,
+        );
+    }
     if !evaluated.evaluation.diagnostics.is_empty() {
         rules::log_evaluation_error_once_with_diagnostic(
-            &data_dir,
+            data_dir,
             evaluated.evaluation.session_id.as_deref(),
             evaluated.project.as_deref(),
             &evaluated.diagnostic_codes,
             &evaluated.evaluation.diagnostics.join("; "),
         );
-        return Ok(());
+        return None;
     }
-    if let Some(output) = evaluated.evaluation.output {
-        match serde_json::to_string(&output) {
-            Ok(output) => println!("{output}"),
-            Err(error) => rules::log_evaluation_error_once_with_diagnostic(
-                &data_dir,
-                evaluated.evaluation.session_id.as_deref(),
-                evaluated.project.as_deref(),
-                &[rules::EvaluationDiagnosticCode::OutputSerialize],
-                &format!("serialize Claude PreToolUse hook output: {error}"),
-            ),
+    evaluated.evaluation.output
+}
+
+/// Memories scoped to the file a Read/Edit/Write call targets. Failures are
+/// logged and never block the tool.
+fn file_touch_context(raw: &str, host: Option<RuleHostArg>) -> Option<String> {
+    let touch = rules::pre_tool_use_file_touch(raw, crate::context::FILE_TOUCH_TOOLS)?;
+    let result = db::open_db_for_hook().and_then(|conn| {
+        crate::context::file_touch_additional_context(
+            &conn,
+            &touch.cwd,
+            &touch.project,
+            &touch.session_id,
+            &touch.file_path,
+            Some(host.map(rule_host_label).unwrap_or("claude-code")),
+        )
+    });
+    match result {
+        Ok(context) => context,
+        Err(error) => {
+            crate::log::warn(
+                "rules-eval",
+                &format!(
+                    "file-scoped context skipped for {}: {error:#}",
+                    touch.file_path
+                ),
+            );
+            None
         }
     }
-    Ok(())
 }
 
 fn rule_host_label(host: RuleHostArg) -> &'static str {
@@ -202,5 +274,17 @@ fn predicate_display(predicate: &RulePredicate) -> (&'static str, String) {
             "git_push_force_forbidden",
             "git push force option".to_string(),
         ),
+        RulePredicate::FilePathGlob { glob, .. } => ("file_path_glob", format!("{glob:?}")),
+        RulePredicate::ToolNameDeny { tools, .. } => ("tool_name_deny", tools.join(",")),
+        RulePredicate::ToolNameAllow { tools, .. } => ("tool_name_allow", tools.join(",")),
+        RulePredicate::ContentRegex {
+            pattern, path_glob, ..
+        } => (
+            "content_regex",
+            match path_glob {
+                Some(glob) => format!("{pattern:?} in {glob:?}"),
+                None => format!("{pattern:?}"),
+            },
+        ),
     }
 }
@@ -0,0 +1,118 @@
+//! `remem sync`: multi-device exchange of curated project memory through an
+//! append-only, encrypted and signed change log in a user-chosen directory
+//! (Syncthing, Dropbox or a git checkout). One sync directory holds the log
+//! of one project; every device writes only under its own device id.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use rusqlite::Connection;
+
+use crate::retrieval::entity::ProjectVocabulary;
+
+mod export;
+mod keys;
+mod replay;
+mod segment;
+
+pub(in crate::cli::actions) use export::PublishedSegment;
+pub(in crate::cli::actions) use keys::{write_private_file, SyncIdentity};
+pub(in crate::cli::actions) use replay::ReplayStats;
+
+/// `routing_reason` prefix on memories inserted by a replay; the exporter
+/// skips them so a peer's memory is never published back.
+const SYNC_ROUTING_REASON_PREFIX: &str = "sync from device ";
+/// `session_id` prefix on governance audit events written by a replay.
+const SYNC_AUDIT_SESSION_PREFIX: &str = "sync:";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(in crate::cli::actions) struct SyncReport {
+    pub(in crate::cli::actions) device_id: String,
+    pub(in crate::cli::actions) replay: ReplayStats,
+    pub(in crate::cli::actions) published: Option<PublishedSegment>,
+}
+
+pub(in crate::cli) fn run_sync(dir: &Path, project: &str, init_key: bool) -> Result<()> {
+    if init_key {
+        let (path, created) = keys::init_log_key()?;
+        if created {
+            println!(
+                "Generated sync key at {}; copy it to the same path on every peer device.",
+                path.display()
+            );
+        } else {
+            println!("Sync key already exists at {}.", path.display());
+        }
+    }
+    let identity = keys::load_identity()?;
+    let mut conn = crate::db::open_db().context("open runtime database for sync")?;
+    let report = sync_with_connection(
+        &mut conn,
+        &identity,
+        dir,
+        project,
+        chrono::Utc::now().timestamp(),
+    )?;
+    print!("{}", render_sync_report(dir, &report));
+    Ok(())
+}
+
+/// Replays new peer segments, then publishes this device's changes, in one
+/// transaction with the cursor updates.
+pub(in crate::cli::actions) fn sync_with_connection(
+    conn: &mut Connection,
+    identity: &SyncIdentity,
+    sync_dir: &Path,
+    project: &str,
+    now: i64,
+) -> Result<SyncReport> {
+    std::fs::create_dir_all(sync_dir)
+        .with_context(|| format!("create sync directory {}", sync_dir.display()))?;
+    let dir_key = std::fs::canonicalize(sync_dir)
+        .unwrap_or_else(|_| sync_dir.to_path_buf())
+        .to_string_lossy()
+        .to_string();
+    let vocabulary = ProjectVocabulary::for_project(conn, project);
+    let tx = conn.transaction()?;
+    let replay =
+        replay::replay_peers(&tx, identity, sync_dir, &dir_key, project, &vocabulary, now)?;
+    let published = export::publish(&tx, identity, sync_dir, &dir_key, project, now)?;
+    tx.commit()?;
+    Ok(SyncReport {
+        device_id: identity.device_id.clone(),
+        replay,
+        published,
+    })
+}
+
+fn render_sync_report(dir: &Path, report: &SyncReport) -> String {
+    let replay = &report.replay;
+    let mut output = format!(
+        "Sync {} as device {}: replayed {} segment(s) from {} peer(s): added={} superseded={} conflicts={} unchanged={} status_applied={} status_skipped={} status_missing={}.\n",
+        dir.display(),
+        report.device_id,
+        replay.segments,
+        replay.peers,
+        replay.added,
+        replay.superseded,
+        replay.conflicts,
+        replay.unchanged,
+        replay.status_applied,
+        replay.status_skipped,
+        replay.status_missing
+    );
+    match &report.published {
+        Some(segment) => output.push_str(&format!(
+            "Published segment {} ({} memories, {} status changes) to {}.\n",
+            segment.seq,
+            segment.upserts,
+            segment.status_changes,
+            segment.path.display()
+        )),
+        None => output.push_str("No local changes to publish.\n"),
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,308 @@
+//! Builds this device's next segment from local changes since the export
+//! cursor: active curated memories (upserts) and `memory_governance` audit
+//! events (status changes). Rows and events that were themselves written by a
+//! sync replay are skipped so peers' changes are never echoed back.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use rusqlite::{params, Connection, OptionalExtension};
+
+use super::keys::SyncIdentity;
+use super::segment::{self, SegmentPayload, SyncOp};
+use super::{SYNC_AUDIT_SESSION_PREFIX, SYNC_ROUTING_REASON_PREFIX};
+use crate::cli::actions::pack_export::{pack_memory_content_hash, PackMemory};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct ExportCursor {
+    memory_updated_epoch: i64,
+    governance_event_id: i64,
+    last_seq: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(in crate::cli::actions) struct PublishedSegment {
+    pub(in crate::cli::actions) seq: i64,
+    pub(in crate::cli::actions) path: PathBuf,
+    pub(in crate::cli::actions) upserts: usize,
+    pub(in crate::cli::actions) status_changes: usize,
+}
+
+pub(super) fn publish(
+    conn: &Connection,
+    identity: &SyncIdentity,
+    sync_dir: &Path,
+    dir_key: &str,
+    project: &str,
+    now: i64,
+) -> Result<Option<PublishedSegment>> {
+    let existing = segment::list_segment_seqs(&segment::device_dir(sync_dir, &identity.device_id))?;
+    let mut cursor = load_cursor(conn, project, dir_key)?;
+    if existing.is_empty() && cursor.last_seq > 0 {
+        // The device's log was removed from the sync dir; republish from scratch.
+        crate::log::warn(
+            "sync",
+            &format!(
+                "log for device {} missing; re-exporting",
+                identity.device_id
+            ),
+        );
+        cursor = ExportCursor::default();
+    }
+
+    // Rows updated in the current second may still be joined by another write
+    // in the same second, so the memory cursor only advances over whole seconds.
+    let memory_horizon = now - 1;
+    let mut ops = memory_upserts(conn, project, cursor.memory_updated_epoch, memory_horizon)?;
+    let upserts = ops.len();
+    let (status_ops, last_event_id) = status_changes(conn, project, cursor.governance_event_id)?;
+    let status_count = status_ops.len();
+    ops.extend(status_ops);
+
+    let next_cursor = ExportCursor {
+        memory_updated_epoch: cursor.memory_updated_epoch.max(memory_horizon),
+        governance_event_id: last_event_id,
+        last_seq: existing.last().copied().unwrap_or(0),
+    };
+    if ops.is_empty() {
+        save_cursor(conn, project, dir_key, next_cursor, now)?;
+        return Ok(None);
+    }
+
+    let seq = next_cursor.last_seq + 1;
+    let payload = SegmentPayload {
+        project_label: project_label(project),
+        created_at_epoch: now,
+        ops,
+    };
+    let path = segment::write_segment(sync_dir, identity, seq, &payload)?;
+    save_cursor(
+        conn,
+        project,
+        dir_key,
+        ExportCursor {
+            last_seq: seq,
+            ..next_cursor
+        },
+        now,
+    )?;
+    Ok(Some(PublishedSegment {
+        seq,
+        path,
+        upserts,
+        status_changes: status_count,
+    }))
+}
+
+fn memory_upserts(
+    conn: &Connection,
+    project: &str,
+    after_epoch: i64,
+    through_epoch: i64,
+) -> Result<Vec<SyncOp>> {
+    let mut stmt = conn.prepare(
+        "SELECT m.title, m.content, m.memory_type, COALESCE(m.scope, 'project'),
+                sk.state_key, m.confidence, m.created_at_epoch, m.valid_from_epoch,
+                m.expires_at_epoch, m.updated_at_epoch
+         FROM memories m
+         LEFT JOIN memory_state_keys sk ON sk.id = m.state_key_id
+         WHERE m.owner_scope = 'repo'
+           AND m.owner_key = ?1
+           AND COALESCE(m.target_project, m.project) = ?1
+           AND COALESCE(m.scope, 'project') = 'project'
+           AND m.status = 'active'
+           AND m.updated_at_epoch > ?2
+           AND m.updated_at_epoch <= ?3
+           AND COALESCE(m.routing_reason, '') NOT LIKE ?4
+         ORDER BY m.updated_at_epoch ASC, m.id ASC",
+    )?;
+    let rows = stmt.query_map(
+        params![
+            project,
+            after_epoch,
+            through_epoch,
+            format!("{SYNC_ROUTING_REASON_PREFIX}%")
+        ],
+        |row| {
+            let memory_type: String = row.get(2)?;
+            let state_key: Option<String> = row.get(4)?;
+            let title: String = row.get(0)?;
+            let content: String = row.get(1)?;
+            let content_hash =
+                pack_memory_content_hash(&memory_type, state_key.as_deref(), &title, &content);
+            Ok(SyncOp::Upsert {
+                memory: PackMemory {
+                    title,
+                    content,
+                    memory_type,
+                    scope: row.get(3)?,
+                    state_key,
+                    state_key_confidence: None,
+                    state_key_reason: None,
+                    confidence: row.get(5)?,
+                    created_at_epoch: row.get(6)?,
+                    valid_from_epoch: row.get(7)?,
+                    expires_at_epoch: row.get(8)?,
+                    owner_intent: "repo".to_string(),
+                    origin: format!("repo:{project}"),
+                    content_hash,
+                },
+                updated_at_epoch: row.get(9)?,
+            })
+        },
+    )?;
+    crate::db::query::collect_rows(rows)
+}
+
+/// Status ops from the governance audit trail. Returns the ops and the last
+/// audit event id scanned.
+fn status_changes(
+    conn: &Connection,
+    project: &str,
+    after_event_id: i64,
+) -> Result<(Vec<SyncOp>, i64)> {
+    let mut stmt = conn.prepare(
+        "SELECT id, detail, created_at_epoch
+         FROM events
+         WHERE event_type = 'memory_governance'
+           AND project = ?1
+           AND id > ?2
+           AND session_id NOT LIKE ?3
+         ORDER BY id ASC",
+    )?;
+    let rows = stmt.query_map(
+        params![
+            project,
+            after_event_id,
+            format!("{SYNC_AUDIT_SESSION_PREFIX}%")
+        ],
+        |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, Option<String>>(1)?,
+                row.get::<_, i64>(2)?,
+            ))
+        },
+    )?;
+    let events = crate::db::query::collect_rows(rows)?;
+    let last_event_id = events
+        .last()
+        .map(|(id, _, _)| *id)
+        .unwrap_or(after_event_id);
+
+    let mut ops = Vec::new();
+    for (_, detail, created_at_epoch) in events {
+        let Some(detail) = detail
+            .as_deref()
+            .and_then(|detail| serde_json::from_str::<serde_json::Value>(detail).ok())
+        else {
+            continue;
+        };
+        let (Some(memory_id), Some(status)) =
+            (detail["memory_id"].as_i64(), detail["new_status"].as_str())
+        else {
+            continue;
+        };
+        if detail["previous_status"].as_str() == Some(status) {
+            continue;
+        }
+        let Some((memory_type, state_key, title, content)) =
+            load_repo_memory(conn, project, memory_id)?
+        else {
+            continue;
+        };
+        ops.push(SyncOp::Status {
+            content_hash: pack_memory_content_hash(
+                &memory_type,
+                state_key.as_deref(),
+                &title,
+                &content,
+            ),
+            memory_type,
+            state_key,
+            status: status.to_string(),
+            reason: detail["reason"].as_str().map(str::to_string),
+            changed_at_epoch: created_at_epoch,
+        });
+    }
+    Ok((ops, last_event_id))
+}
+
+type RepoMemoryIdentity = (String, Option<String>, String, String);
+
+fn load_repo_memory(
+    conn: &Connection,
+    project: &str,
+    memory_id: i64,
+) -> Result<Option<RepoMemoryIdentity>> {
+    conn.query_row(
+        "SELECT m.memory_type, sk.state_key, m.title, m.content
+         FROM memories m
+         LEFT JOIN memory_state_keys sk ON sk.id = m.state_key_id
+         WHERE m.id = ?1
+           AND m.owner_scope = 'repo'
+           AND m.owner_key = ?2
+           AND COALESCE(m.scope, 'project') = 'project'",
+        params![memory_id, project],
+        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+    )
+    .optional()
+    .map_err(Into::into)
+}
+
+fn load_cursor(conn: &Connection, project: &str, dir_key: &str) -> Result<ExportCursor> {
+    Ok(conn
+        .query_row(
+            "SELECT memory_updated_epoch, governance_event_id, last_seq
+             FROM sync_export_cursors
+             WHERE project = ?1 AND sync_dir = ?2",
+            params![project, dir_key],
+            |row| {
+                Ok(ExportCursor {
+                    memory_updated_epoch: row.get(0)?,
+                    governance_event_id: row.get(1)?,
+                    last_seq: row.get(2)?,
+                })
+            },
+        )
+        .optional()?
+        .unwrap_or_default())
+}
+
+fn save_cursor(
+    conn: &Connection,
+    project: &str,
+    dir_key: &str,
+    cursor: ExportCursor,
+    now: i64,
+) -> Result<()> {
+    conn.execute(
+        "INSERT INTO sync_export_cursors
+         (project, sync_dir, memory_updated_epoch, governance_event_id, last_seq, updated_at_epoch)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+         ON CONFLICT(project, sync_dir) DO UPDATE SET
+             memory_updated_epoch = excluded.memory_updated_epoch,
+             governance_event_id = excluded.governance_event_id,
+             last_seq = excluded.last_seq,
+             updated_at_epoch = excluded.updated_at_epoch",
+        params![
+            project,
+            dir_key,
+            cursor.memory_updated_epoch,
+            cursor.governance_event_id,
+            cursor.last_seq,
+            now
+        ],
+    )?;
+    Ok(())
+}
+
+/// Last path component only: peers see which project a segment belongs to
+/// without learning this device's directory layout.
+fn project_label(project: &str) -> String {
+    project
+        .rsplit(['/', '\\'])
+        .find(|part| !part.is_empty())
+        .unwrap_or(project)
+        .to_string()
+}
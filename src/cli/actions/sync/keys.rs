@@ -0,0 +1,173 @@
+//! Sync key material: the shared AES-256-GCM log key and this device's
+//! Ed25519 signing identity.
+//!
+//! The shared key (`REMEM_SYNC_KEY` or `<data_dir>/sync/sync.key`) is copied
+//! to every device once and never written to the sync directory. The device
+//! key is generated on first sync and never leaves the data dir; peers learn
+//! its public half from the segments themselves, and the device id is derived
+//! from that public key so a segment cannot claim another device's log.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use base64::Engine as _;
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use sha2::{Digest, Sha256};
+
+const SYNC_KEY_ENV: &str = "REMEM_SYNC_KEY";
+const SYNC_KEY_FILE: &str = "sync.key";
+const DEVICE_KEY_FILE: &str = "device.key";
+const DEVICE_ID_HEX_LEN: usize = 32;
+
+pub(in crate::cli::actions) struct SyncIdentity {
+    pub(super) device_id: String,
+    pub(super) signing_key: Ed25519KeyPair,
+    pub(super) log_key: [u8; 32],
+}
+
+impl SyncIdentity {
+    pub(super) fn new(signing_key: Ed25519KeyPair, log_key: [u8; 32]) -> Self {
+        Self {
+            device_id: device_id_for_public_key(signing_key.public_key().as_ref()),
+            signing_key,
+            log_key,
+        }
+    }
+
+    #[cfg(test)]
+    pub(super) fn generate_for_test(log_key: [u8; 32]) -> Self {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&ring::rand::SystemRandom::new())
+            .expect("generate test device key");
+        Self::new(
+            Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).expect("parse test device key"),
+            log_key,
+        )
+    }
+
+    pub(super) fn public_key(&self) -> &[u8] {
+        self.signing_key.public_key().as_ref()
+    }
+}
+
+pub(super) fn sync_key_dir() -> Result<PathBuf> {
+    Ok(crate::db::try_data_dir()?.join("sync"))
+}
+
+/// Loads the shared log key and this device's signing key, creating the
+/// device key on first use.
+pub(super) fn load_identity() -> Result<SyncIdentity> {
+    let dir = sync_key_dir()?;
+    let log_key = load_log_key(&dir)?;
+    let signing_key = load_or_create_device_key(&dir)?;
+    Ok(SyncIdentity::new(signing_key, log_key))
+}
+
+/// Writes a fresh shared log key unless one already exists. Returns the key
+/// file path and whether it was created.
+pub(super) fn init_log_key() -> Result<(PathBuf, bool)> {
+    let dir = sync_key_dir()?;
+    let path = dir.join(SYNC_KEY_FILE);
+    if path.exists() {
+        return Ok((path, false));
+    }
+    let mut key = [0_u8; 32];
+    getrandom::fill(&mut key).map_err(|error| anyhow::anyhow!("generate sync log key: {error}"))?;
+    write_private_file(&dir, &path, format!("{}\n", hex_encode(&key)).as_bytes())?;
+    Ok((path, true))
+}
+
+fn load_log_key(dir: &Path) -> Result<[u8; 32]> {
+    if let Ok(value) = std::env::var(SYNC_KEY_ENV) {
+        if !value.trim().is_empty() {
+            return parse_log_key(&value).with_context(|| format!("parse {SYNC_KEY_ENV}"));
+        }
+    }
+    let path = dir.join(SYNC_KEY_FILE);
+    match std::fs::read_to_string(&path) {
+        Ok(value) => parse_log_key(&value).with_context(|| format!("parse {}", path.display())),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => bail!(
+            "no sync key: set {SYNC_KEY_ENV}, copy {} from a peer device, or run `remem sync --init-key` on the first device (code=sync_key_missing)",
+            path.display()
+        ),
+        Err(error) => Err(error).with_context(|| format!("read {}", path.display())),
+    }
+}
+
+/// The log key is 32 raw bytes written as 64 hex characters, as
+/// `remem sync --init-key` generates it. Passphrases are rejected: a fast
+/// unsalted hash of one would make every segment open to offline guessing.
+pub(super) fn parse_log_key(value: &str) -> Result<[u8; 32]> {
+    let value = value.trim();
+    if value.is_empty() {
+        bail!("sync key is empty");
+    }
+    if value.len() != 64 || !value.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        bail!(
+            "sync key must be 64 hex characters; run `remem sync --init-key` and copy the key file to every device (code=sync_key_invalid)"
+        );
+    }
+    let mut key = [0_u8; 32];
+    for (index, byte) in key.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&value[index * 2..index * 2 + 2], 16)?;
+    }
+    Ok(key)
+}
+
+fn load_or_create_device_key(dir: &Path) -> Result<Ed25519KeyPair> {
+    let path = dir.join(DEVICE_KEY_FILE);
+    let pkcs8 = match std::fs::read_to_string(&path) {
+        Ok(encoded) => base64::engine::general_purpose::STANDARD
+            .decode(encoded.trim())
+            .with_context(|| format!("decode {}", path.display()))?,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            let document = Ed25519KeyPair::generate_pkcs8(&ring::rand::SystemRandom::new())
+                .map_err(|_| anyhow::anyhow!("generate sync device key"))?;
+            let encoded = base64::engine::general_purpose::STANDARD.encode(document.as_ref());
+            write_private_file(dir, &path, format!("{encoded}\n").as_bytes())?;
+            document.as_ref().to_vec()
+        }
+        Err(error) => return Err(error).with_context(|| format!("read {}", path.display())),
+    };
+    Ed25519KeyPair::from_pkcs8(&pkcs8)
+        .map_err(|_| anyhow::anyhow!("parse sync device key {}", path.display()))
+}
+
+pub(super) fn device_id_for_public_key(public_key: &[u8]) -> String {
+    let mut id = hex_encode(&Sha256::digest(public_key));
+    id.truncate(DEVICE_ID_HEX_LEN);
+    id
+}
+
+pub(super) fn is_device_id(value: &str) -> bool {
+    value.len() == DEVICE_ID_HEX_LEN
+        && value
+            .chars()
+            .all(|ch| ch.is_ascii_digit() || ('a'..='f').contains(&ch))
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Creates `path` with owner-only permissions; fails if it already exists.
+pub(in crate::cli::actions) fn write_private_file(
+    dir: &Path,
+    path: &Path,
+    contents: &[u8],
+) -> Result<()> {
+    use std::io::Write;
+
+    std::fs::create_dir_all(dir).with_context(|| format!("create {}", dir.display()))?;
+    let mut options = std::fs::OpenOptions::new();
+    options.create_new(true).write(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options
+        .open(path)
+        .with_context(|| format!("create {}", path.display()))?;
+    file.write_all(contents)
+        .with_context(|| format!("write {}", path.display()))
+}
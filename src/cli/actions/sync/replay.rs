@@ -0,0 +1,534 @@
+//! Replays peers' segments into the local store.
+//!
+//! All new segments from every peer are opened before anything is applied,
+//! then upserts run first (peer, seq, log order) and status changes second
+//! (change time, peer, content hash), so a status change always finds the
+//! memory it targets when any peer has published it.
+//!
+//! State-key collisions resolve deterministically: the write with the later
+//! `updated_at_epoch` wins, ties broken by the larger content hash. Both
+//! versions are kept, the loser marked stale, and linked loser -> winner with
+//! a `conflicts` edge (or `supersedes` when both versions came from the same
+//! device), so every device ends with the same current memory and the same
+//! history regardless of replay order.
+
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use super::keys::SyncIdentity;
+use super::segment::{self, OpenedSegment, SyncOp};
+use super::{SYNC_AUDIT_SESSION_PREFIX, SYNC_ROUTING_REASON_PREFIX};
+use crate::cli::actions::pack_export::{pack_memory_content_hash, PackMemory};
+use crate::memory::edge::MemoryEdgeWriteContext;
+use crate::memory::state_key::StateKeyDecision;
+use crate::retrieval::entity::ProjectVocabulary;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(in crate::cli::actions) struct ReplayStats {
+    pub(in crate::cli::actions) peers: usize,
+    pub(in crate::cli::actions) segments: usize,
+    pub(in crate::cli::actions) added: usize,
+    pub(in crate::cli::actions) superseded: usize,
+    pub(in crate::cli::actions) conflicts: usize,
+    pub(in crate::cli::actions) unchanged: usize,
+    pub(in crate::cli::actions) status_applied: usize,
+    pub(in crate::cli::actions) status_skipped: usize,
+    pub(in crate::cli::actions) status_missing: usize,
+}
+
+pub(super) fn replay_peers(
+    conn: &Connection,
+    identity: &SyncIdentity,
+    sync_dir: &Path,
+    dir_key: &str,
+    project: &str,
+    vocabulary: &ProjectVocabulary,
+    now: i64,
+) -> Result<ReplayStats> {
+    let mut stats = ReplayStats::default();
+    let mut opened = Vec::new();
+    for device_id in segment::list_peer_devices(sync_dir, &identity.device_id)? {
+        let last_seq = load_peer_cursor(conn, project, dir_key, &device_id)?;
+        let mut next_seq = last_seq + 1;
+        for seq in segment::list_segment_seqs(&segment::device_dir(sync_dir, &device_id))? {
+            if seq < next_seq {
+                continue;
+            }
+            if seq != next_seq {
+                // A gap means the sync tool has not delivered every segment
+                // yet; stop at the gap and pick the rest up next run.
+                crate::log::info(
+                    "sync",
+                    &format!("peer {device_id} missing segment {next_seq}; waiting"),
+                );
+                break;
+            }
+            opened.push(segment::read_segment(
+                sync_dir,
+                &device_id,
+                seq,
+                &identity.log_key,
+            )?);
+            next_seq += 1;
+        }
+        if next_seq > last_seq + 1 {
+            stats.peers += 1;
+            save_peer_cursor(conn, project, dir_key, &device_id, next_seq - 1, now)?;
+        }
+    }
+    stats.segments = opened.len();
+
+    for segment in &opened {
+        for op in &segment.payload.ops {
+            if let SyncOp::Upsert {
+                memory,
+                updated_at_epoch,
+            } = op
+            {
+                apply_upsert(
+                    conn,
+                    project,
+                    vocabulary,
+                    segment,
+                    memory,
+                    *updated_at_epoch,
+                    now,
+                    &mut stats,
+                )?;
+            }
+        }
+    }
+
+    let mut status_ops = opened
+        .iter()
+        .flat_map(|segment| {
+            segment
+                .payload
+                .ops
+                .iter()
+                .map(move |op| (segment.device_id.as_str(), op))
+        })
+        .filter_map(|(device_id, op)| match op {
+            SyncOp::Status {
+                memory_type,
+                state_key,
+                content_hash,
+                status,
+                reason,
+                changed_at_epoch,
+            } => Some(StatusChange {
+                device_id,
+                memory_type,
+                state_key: state_key.as_deref(),
+                content_hash,
+                status,
+                reason: reason.as_deref(),
+                changed_at_epoch: *changed_at_epoch,
+            }),
+            SyncOp::Upsert { .. } => None,
+        })
+        .collect::<Vec<_>>();
+    status_ops.sort_by(|left, right| {
+        (left.changed_at_epoch, left.device_id, left.content_hash).cmp(&(
+            right.changed_at_epoch,
+            right.device_id,
+            right.content_hash,
+        ))
+    });
+    for change in &status_ops {
+        apply_status(conn, project, change, &mut stats)?;
+    }
+    Ok(stats)
+}
+
+struct StatusChange<'a> {
+    device_id: &'a str,
+    memory_type: &'a str,
+    state_key: Option<&'a str>,
+    content_hash: &'a str,
+    status: &'a str,
+    reason: Option<&'a str>,
+    changed_at_epoch: i64,
+}
+
+#[derive(Debug)]
+struct LocalVersion {
+    id: i64,
+    status: String,
+    updated_at_epoch: i64,
+    content_hash: String,
+    routing_reason: Option<String>,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn apply_upsert(
+    conn: &Connection,
+    project: &str,
+    vocabulary: &ProjectVocabulary,
+    segment: &OpenedSegment,
+    memory: &PackMemory,
+    updated_at_epoch: i64,
+    now: i64,
+    stats: &mut ReplayStats,
+) -> Result<()> {
+    validate_memory(segment, memory)?;
+    let versions = local_versions(
+        conn,
+        project,
+        &memory.memory_type,
+        memory.state_key.as_deref(),
+    )?;
+    if versions
+        .iter()
+        .any(|version| version.content_hash == memory.content_hash)
+    {
+        stats.unchanged += 1;
+        return Ok(());
+    }
+
+    let Some(state_key) = memory.state_key.as_deref() else {
+        insert_synced_memory(
+            conn,
+            project,
+            vocabulary,
+            &segment.device_id,
+            memory,
+            updated_at_epoch,
+            None,
+        )?;
+        stats.added += 1;
+        return Ok(());
+    };
+    let decision = StateKeyDecision {
+        state_key: state_key.to_string(),
+        confidence: memory.state_key_confidence.unwrap_or(1.0),
+        reason: memory
+            .state_key_reason
+            .clone()
+            .unwrap_or_else(|| "sync_replay".to_string()),
+    };
+    let current_id = crate::memory::state_key::current_memory_id(
+        conn,
+        "repo",
+        project,
+        &memory.memory_type,
+        state_key,
+        now,
+    )?;
+    let Some(current) = current_id.and_then(|id| versions.iter().find(|version| version.id == id))
+    else {
+        let memory_id = insert_synced_memory(
+            conn,
+            project,
+            vocabulary,
+            &segment.device_id,
+            memory,
+            updated_at_epoch,
+            None,
+        )?;
+        crate::memory::state_key::attach_current_memory(
+            conn,
+            memory_id,
+            "repo",
+            project,
+            &memory.memory_type,
+            &decision,
+            now,
+        )?;
+        stats.added += 1;
+        return Ok(());
+    };
+
+    let incoming_wins = (updated_at_epoch, memory.content_hash.as_str())
+        > (current.updated_at_epoch, current.content_hash.as_str());
+    let same_origin =
+        current.routing_reason.as_deref() == Some(sync_routing_reason(&segment.device_id).as_str());
+    let (loser, winner) = if incoming_wins {
+        let memory_id = insert_synced_memory(
+            conn,
+            project,
+            vocabulary,
+            &segment.device_id,
+            memory,
+            updated_at_epoch,
+            None,
+        )?;
+        let state_key_id = crate::memory::state_key::attach_current_memory(
+            conn,
+            memory_id,
+            "repo",
+            project,
+            &memory.memory_type,
+            &decision,
+            now,
+        )?;
+        crate::memory::lifecycle::soft_supersede(conn, project, &[current.id], Some(memory_id))?;
+        (current.id, (memory_id, state_key_id))
+    } else {
+        let memory_id = insert_synced_memory(
+            conn,
+            project,
+            vocabulary,
+            &segment.device_id,
+            memory,
+            updated_at_epoch,
+            Some(current.updated_at_epoch),
+        )?;
+        let state_key_id = crate::memory::state_key::ensure_state_key(
+            conn,
+            "repo",
+            project,
+            &memory.memory_type,
+            &decision,
+            now,
+        )?;
+        conn.execute(
+            "UPDATE memories SET state_key_id = ?1 WHERE id = ?2",
+            params![state_key_id, memory_id],
+        )?;
+        (memory_id, (current.id, state_key_id))
+    };
+
+    let reason = format!(
+        "sync replay from device {} seq {}: later write wins",
+        segment.device_id, segment.seq
+    );
+    let context = MemoryEdgeWriteContext {
+        state_key_id: Some(winner.1),
+        reason: Some(&reason),
+        ..MemoryEdgeWriteContext::default()
+    };
+    if same_origin {
+        crate::memory::edge::insert_supersedes_edges(conn, &[loser], winner.0, context)?;
+        stats.superseded += 1;
+    } else {
+        crate::memory::edge::insert_conflicts_edges(conn, &[loser], winner.0, context)?;
+        stats.conflicts += 1;
+    }
+    Ok(())
+}
+
+fn validate_memory(segment: &OpenedSegment, memory: &PackMemory) -> Result<()> {
+    let expected_hash = pack_memory_content_hash(
+        &memory.memory_type,
+        memory.state_key.as_deref(),
+        &memory.title,
+        &memory.content,
+    );
+    if crate::memory::MemoryType::parse(&memory.memory_type).is_none()
+        || memory.scope != "project"
+        || memory.owner_intent != "repo"
+        || memory.content_hash != expected_hash
+    {
+        bail!(
+            "device {} segment {} carries an invalid memory op hash={} (code=sync_op_invalid)",
+            segment.device_id,
+            segment.seq,
+            memory.content_hash
+        );
+    }
+    Ok(())
+}
+
+/// Every local version of the identity, any status: all rows under the state
+/// key, or all rows of the type when the memory has no state key.
+fn local_versions(
+    conn: &Connection,
+    project: &str,
+    memory_type: &str,
+    state_key: Option<&str>,
+) -> Result<Vec<LocalVersion>> {
+    let mut stmt = conn.prepare(
+        "SELECT m.id, m.status, m.updated_at_epoch, m.title, m.content,
+                sk.state_key, m.routing_reason
+         FROM memories m
+         LEFT JOIN memory_state_keys sk ON sk.id = m.state_key_id
+         WHERE m.owner_scope = 'repo'
+           AND m.owner_key = ?1
+           AND m.memory_type = ?2
+           AND (?3 IS NULL OR sk.state_key = ?3)
+         ORDER BY m.updated_at_epoch DESC, m.id DESC",
+    )?;
+    let rows = stmt.query_map(params![project, memory_type, state_key], |row| {
+        let title: String = row.get(3)?;
+        let content: String = row.get(4)?;
+        let row_state_key: Option<String> = row.get(5)?;
+        Ok(LocalVersion {
+            id: row.get(0)?,
+            status: row.get(1)?,
+            updated_at_epoch: row.get(2)?,
+            content_hash: pack_memory_content_hash(
+                memory_type,
+                row_state_key.as_deref(),
+                &title,
+                &content,
+            ),
+            routing_reason: row.get(6)?,
+        })
+    })?;
+    crate::db::query::collect_rows(rows)
+}
+
+fn insert_synced_memory(
+    conn: &Connection,
+    project: &str,
+    vocabulary: &ProjectVocabulary,
+    device_id: &str,
+    memory: &PackMemory,
+    updated_at_epoch: i64,
+    superseded_at_epoch: Option<i64>,
+) -> Result<i64> {
+    let reference_time = memory.valid_from_epoch.unwrap_or(memory.created_at_epoch);
+    let search_context = crate::memory::search_context::build_search_context(
+        &memory.memory_type,
+        memory.state_key.as_deref(),
+        &memory.content,
+        None,
+    );
+    conn.execute(
+        "INSERT INTO memories
+         (session_id, project, topic_key, title, content, memory_type, files, search_context,
+          created_at_epoch, updated_at_epoch, reference_time_epoch, status, branch, scope,
+          evidence_event_ids, source_candidate_id, confidence,
+          source_project, target_project, owner_scope, owner_key, topic_domain,
+          routing_confidence, routing_reason, context_class, expires_at_epoch,
+          valid_from_epoch, valid_to_epoch)
+         VALUES (NULL, ?1, ?2, ?3, ?4, ?5, NULL, ?6,
+                 ?7, ?8, ?9, ?10, NULL, 'project',
+                 '[]', NULL, ?11,
+                 ?1, ?1, 'repo', ?1, ?12,
+                 1.0, ?13, 'startup_core', ?14,
+                 ?15, ?16)",
+        params![
+            project,
+            memory.state_key.as_deref(),
+            memory.title.as_str(),
+            memory.content.as_str(),
+            memory.memory_type.as_str(),
+            search_context,
+            memory.created_at_epoch,
+            updated_at_epoch,
+            reference_time,
+            if superseded_at_epoch.is_some() {
+                "stale"
+            } else {
+                "active"
+            },
+            memory.confidence,
+            format!("sync:{}", &device_id[..12]),
+            sync_routing_reason(device_id),
+            memory.expires_at_epoch,
+            memory.valid_from_epoch,
+            superseded_at_epoch,
+        ],
+    )?;
+    let memory_id = conn.last_insert_rowid();
+    let entities = crate::retrieval::entity::extract_project_entities_with(
+        conn,
+        vocabulary,
+        project,
+        &memory.title,
+        &memory.content,
+    );
+    crate::retrieval::entity::refresh_memory_entities(conn, memory_id, &entities)?;
+    crate::retrieval::vector::upsert_memory_embedding_for_row(conn, memory_id)
+        .with_context(|| format!("embed synced memory id={memory_id}"))?;
+    Ok(memory_id)
+}
+
+fn apply_status(
+    conn: &Connection,
+    project: &str,
+    change: &StatusChange<'_>,
+    stats: &mut ReplayStats,
+) -> Result<()> {
+    let targets = local_versions(conn, project, change.memory_type, change.state_key)?
+        .into_iter()
+        .filter(|version| version.content_hash == change.content_hash)
+        .collect::<Vec<_>>();
+    if targets.is_empty() {
+        stats.status_missing += 1;
+        return Ok(());
+    }
+    for target in targets {
+        if target.status == change.status || target.updated_at_epoch > change.changed_at_epoch {
+            stats.status_skipped += 1;
+            continue;
+        }
+        conn.execute(
+            "UPDATE memories
+             SET status = ?1,
+                 updated_at_epoch = ?2,
+                 valid_to_epoch = CASE WHEN ?1 = 'active' THEN NULL
+                                       ELSE COALESCE(valid_to_epoch, ?2) END
+             WHERE id = ?3",
+            params![change.status, change.changed_at_epoch, target.id],
+        )?;
+        let detail = serde_json::json!({
+            "action": "sync_replay",
+            "memory_id": target.id,
+            "previous_status": target.status,
+            "new_status": change.status,
+            "reason": change.reason,
+            "device_id": change.device_id,
+        })
+        .to_string();
+        crate::memory::insert_event(
+            conn,
+            &format!("{SYNC_AUDIT_SESSION_PREFIX}{}", change.device_id),
+            project,
+            "memory_governance",
+            &format!(
+                "sync memory {}: {} -> {}",
+                target.id, target.status, change.status
+            ),
+            Some(&detail),
+            None,
+            None,
+        )?;
+        stats.status_applied += 1;
+    }
+    Ok(())
+}
+
+fn sync_routing_reason(device_id: &str) -> String {
+    format!("{SYNC_ROUTING_REASON_PREFIX}{device_id}")
+}
+
+fn load_peer_cursor(
+    conn: &Connection,
+    project: &str,
+    dir_key: &str,
+    device_id: &str,
+) -> Result<i64> {
+    Ok(conn
+        .query_row(
+            "SELECT last_seq FROM sync_peer_cursors
+             WHERE project = ?1 AND sync_dir = ?2 AND device_id = ?3",
+            params![project, dir_key, device_id],
+            |row| row.get(0),
+        )
+        .optional()?
+        .unwrap_or(0))
+}
+
+fn save_peer_cursor(
+    conn: &Connection,
+    project: &str,
+    dir_key: &str,
+    device_id: &str,
+    last_seq: i64,
+    now: i64,
+) -> Result<()> {
+    conn.execute(
+        "INSERT INTO sync_peer_cursors (project, sync_dir, device_id, last_seq, updated_at_epoch)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(project, sync_dir, device_id) DO UPDATE SET
+             last_seq = excluded.last_seq,
+             updated_at_epoch = excluded.updated_at_epoch",
+        params![project, dir_key, device_id, last_seq, now],
+    )?;
+    Ok(())
+}
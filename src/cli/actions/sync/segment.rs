@@ -0,0 +1,276 @@
+//! Change-log segment format.
+//!
+//! Each `remem sync` run that has something to publish appends one segment
+//! file `<sync_dir>/<device_id>/<seq>.remem-sync`; existing segments are
+//! never rewritten. The envelope carries the device's public key, an
+//! AES-256-GCM sealed payload whose AAD binds it to `(device_id, seq)`, and an
+//! Ed25519 signature over the envelope fields, so a replayed, renamed or
+//! re-attributed segment fails verification before anything is decrypted.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM};
+use ring::signature;
+use serde::{Deserialize, Serialize};
+
+use super::keys::{device_id_for_public_key, is_device_id, SyncIdentity};
+use crate::cli::actions::pack_export::PackMemory;
+
+pub(super) const SEGMENT_FORMAT: &str = "remem-sync-v1";
+const SEGMENT_EXTENSION: &str = "remem-sync";
+const NONCE_LEN: usize = 12;
+
+/// One replayable change. Memories are addressed by content hash and state
+/// key, never by local row id, so the same op resolves on every device.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub(super) enum SyncOp {
+    /// A curated memory as it exists on the origin device.
+    Upsert {
+        memory: PackMemory,
+        updated_at_epoch: i64,
+    },
+    /// A governance status change recorded in the origin's audit trail.
+    Status {
+        memory_type: String,
+        state_key: Option<String>,
+        content_hash: String,
+        status: String,
+        reason: Option<String>,
+        changed_at_epoch: i64,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(super) struct SegmentPayload {
+    pub(super) project_label: String,
+    pub(super) created_at_epoch: i64,
+    pub(super) ops: Vec<SyncOp>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SegmentEnvelope {
+    format: String,
+    device_id: String,
+    seq: i64,
+    public_key: String,
+    nonce: String,
+    ciphertext: String,
+    signature: String,
+}
+
+#[derive(Debug)]
+pub(super) struct OpenedSegment {
+    pub(super) device_id: String,
+    pub(super) seq: i64,
+    pub(super) payload: SegmentPayload,
+}
+
+pub(super) fn device_dir(sync_dir: &Path, device_id: &str) -> PathBuf {
+    sync_dir.join(device_id)
+}
+
+pub(super) fn segment_path(sync_dir: &Path, device_id: &str, seq: i64) -> PathBuf {
+    device_dir(sync_dir, device_id).join(format!("{seq:08}.{SEGMENT_EXTENSION}"))
+}
+
+/// Sequence numbers of the segment files in one device directory, ascending.
+pub(super) fn list_segment_seqs(dir: &Path) -> Result<Vec<i64>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error).with_context(|| format!("read {}", dir.display())),
+    };
+    let mut seqs = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some(SEGMENT_EXTENSION) {
+            continue;
+        }
+        if let Some(seq) = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.parse::<i64>().ok())
+            .filter(|seq| *seq > 0)
+        {
+            seqs.push(seq);
+        }
+    }
+    seqs.sort_unstable();
+    Ok(seqs)
+}
+
+/// Peer device directories in the sync dir, excluding this device.
+pub(super) fn list_peer_devices(sync_dir: &Path, own_device_id: &str) -> Result<Vec<String>> {
+    let mut devices = Vec::new();
+    for entry in std::fs::read_dir(sync_dir)
+        .with_context(|| format!("read sync directory {}", sync_dir.display()))?
+    {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name != own_device_id && is_device_id(&name) {
+            devices.push(name);
+        }
+    }
+    devices.sort();
+    Ok(devices)
+}
+
+pub(super) fn write_segment(
+    sync_dir: &Path,
+    identity: &SyncIdentity,
+    seq: i64,
+    payload: &SegmentPayload,
+) -> Result<PathBuf> {
+    let path = segment_path(sync_dir, &identity.device_id, seq);
+    if path.exists() {
+        bail!(
+            "sync segment {} already exists; change logs are append-only (code=sync_segment_exists)",
+            path.display()
+        );
+    }
+    let envelope = seal_segment(identity, seq, payload)?;
+    std::fs::create_dir_all(device_dir(sync_dir, &identity.device_id))
+        .with_context(|| format!("create {}", path.display()))?;
+    crate::atomic_file::write_atomic(
+        &path,
+        format!("{}\n", serde_json::to_string(&envelope)?).as_bytes(),
+    )
+    .with_context(|| format!("write {}", path.display()))?;
+    Ok(path)
+}
+
+pub(super) fn read_segment(
+    sync_dir: &Path,
+    device_id: &str,
+    seq: i64,
+    log_key: &[u8; 32],
+) -> Result<OpenedSegment> {
+    let path = segment_path(sync_dir, device_id, seq);
+    let raw = std::fs::read_to_string(&path).with_context(|| format!("read {}", path.display()))?;
+    let envelope: SegmentEnvelope =
+        serde_json::from_str(&raw).with_context(|| format!("parse {}", path.display()))?;
+    open_segment(&envelope, device_id, seq, log_key)
+        .with_context(|| format!("open sync segment {}", path.display()))
+}
+
+fn seal_segment(
+    identity: &SyncIdentity,
+    seq: i64,
+    payload: &SegmentPayload,
+) -> Result<SegmentEnvelope> {
+    let mut nonce = [0_u8; NONCE_LEN];
+    getrandom::fill(&mut nonce)
+        .map_err(|error| anyhow::anyhow!("generate sync segment nonce: {error}"))?;
+    let mut ciphertext = serde_json::to_vec(payload)?;
+    log_cipher(&identity.log_key)?
+        .seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(segment_aad(&identity.device_id, seq)),
+            &mut ciphertext,
+        )
+        .map_err(|_| anyhow::anyhow!("encrypt sync segment"))?;
+    let mut envelope = SegmentEnvelope {
+        format: SEGMENT_FORMAT.to_string(),
+        device_id: identity.device_id.clone(),
+        seq,
+        public_key: STANDARD.encode(identity.public_key()),
+        nonce: STANDARD.encode(nonce),
+        ciphertext: STANDARD.encode(&ciphertext),
+        signature: String::new(),
+    };
+    let signature = identity.signing_key.sign(&signed_bytes(&envelope));
+    envelope.signature = STANDARD.encode(signature.as_ref());
+    Ok(envelope)
+}
+
+fn open_segment(
+    envelope: &SegmentEnvelope,
+    expected_device_id: &str,
+    expected_seq: i64,
+    log_key: &[u8; 32],
+) -> Result<OpenedSegment> {
+    if envelope.format != SEGMENT_FORMAT {
+        bail!(
+            "unsupported sync segment format '{}' (code=sync_segment_format)",
+            envelope.format
+        );
+    }
+    if envelope.device_id != expected_device_id || envelope.seq != expected_seq {
+        bail!(
+            "segment claims device {} seq {} but is stored as device {} seq {} (code=sync_segment_misplaced)",
+            envelope.device_id,
+            envelope.seq,
+            expected_device_id,
+            expected_seq
+        );
+    }
+    let public_key = STANDARD
+        .decode(&envelope.public_key)
+        .context("decode segment public key")?;
+    if device_id_for_public_key(&public_key) != envelope.device_id {
+        bail!("segment public key does not match its device id (code=sync_segment_signature)");
+    }
+    let signature_bytes = STANDARD
+        .decode(&envelope.signature)
+        .context("decode segment signature")?;
+    signature::UnparsedPublicKey::new(&signature::ED25519, &public_key)
+        .verify(&signed_bytes(envelope), &signature_bytes)
+        .map_err(|_| {
+            anyhow::anyhow!("segment signature verification failed (code=sync_segment_signature)")
+        })?;
+    let nonce: [u8; NONCE_LEN] = STANDARD
+        .decode(&envelope.nonce)
+        .context("decode segment nonce")?
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("segment nonce must be {NONCE_LEN} bytes"))?;
+    let mut ciphertext = STANDARD
+        .decode(&envelope.ciphertext)
+        .context("decode segment ciphertext")?;
+    let plaintext = log_cipher(log_key)?
+        .open_in_place(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(segment_aad(&envelope.device_id, envelope.seq)),
+            &mut ciphertext,
+        )
+        .map_err(|_| {
+            anyhow::anyhow!(
+                "decrypt sync segment; the peer uses a different sync key (code=sync_key_mismatch)"
+            )
+        })?;
+    let payload = serde_json::from_slice(plaintext).context("parse segment payload")?;
+    Ok(OpenedSegment {
+        device_id: envelope.device_id.clone(),
+        seq: envelope.seq,
+        payload,
+    })
+}
+
+fn log_cipher(log_key: &[u8; 32]) -> Result<LessSafeKey> {
+    Ok(LessSafeKey::new(
+        UnboundKey::new(&AES_256_GCM, log_key)
+            .map_err(|_| anyhow::anyhow!("initialize sync log key"))?,
+    ))
+}
+
+fn segment_aad(device_id: &str, seq: i64) -> Vec<u8> {
+    format!("{SEGMENT_FORMAT}:{device_id}:{seq}").into_bytes()
+}
+
+fn signed_bytes(envelope: &SegmentEnvelope) -> Vec<u8> {
+    format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        envelope.format,
+        envelope.device_id,
+        envelope.seq,
+        envelope.public_key,
+        envelope.nonce,
+        envelope.ciphertext
+    )
+    .into_bytes()
+}
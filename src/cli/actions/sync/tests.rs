@@ -0,0 +1,271 @@
+//! Two-device replay through a shared directory: round trip without echo,
+//! deterministic state-key conflicts, governance status changes, and
+//! rejection of tampered or foreign-key segments and of non-hex log keys.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use rusqlite::{params, Connection};
+
+use super::{keys, segment, sync_with_connection, SyncIdentity};
+
+const PROJECT: &str = "/repo";
+const SHARED_KEY: [u8; 32] = [7; 32];
+
+fn device_db() -> Result<Connection> {
+    let conn = Connection::open_in_memory()?;
+    conn.execute_batch("PRAGMA journal_mode=WAL; PRAGMA foreign_keys=ON;")?;
+    crate::migrate::run_migrations(&conn)?;
+    Ok(conn)
+}
+
+fn unique_sync_dir(label: &str) -> PathBuf {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    std::env::temp_dir().join(format!("remem-{label}-{}-{nanos}", std::process::id()))
+}
+
+fn insert_memory(
+    conn: &Connection,
+    state_key: Option<&str>,
+    title: &str,
+    content: &str,
+    updated_at_epoch: i64,
+) -> Result<i64> {
+    conn.execute(
+        "INSERT INTO memories
+         (project, title, content, memory_type, created_at_epoch, updated_at_epoch,
+          status, scope, source_project, target_project, owner_scope, owner_key, context_class)
+         VALUES (?1, ?2, ?3, 'decision', ?4, ?4, 'active', 'project',
+                 ?1, ?1, 'repo', ?1, 'startup_core')",
+        params![PROJECT, title, content, updated_at_epoch],
+    )?;
+    let id = conn.last_insert_rowid();
+    if let Some(state_key) = state_key {
+        crate::memory::state_key::attach_current_memory(
+            conn,
+            id,
+            "repo",
+            PROJECT,
+            "decision",
+            &crate::memory::state_key::StateKeyDecision {
+                state_key: state_key.to_string(),
+                confidence: 1.0,
+                reason: "test".to_string(),
+            },
+            updated_at_epoch,
+        )?;
+    }
+    Ok(id)
+}
+
+fn active_contents(conn: &Connection) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        "SELECT content FROM memories WHERE project = ?1 AND status = 'active' ORDER BY content",
+    )?;
+    let rows = stmt.query_map([PROJECT], |row| row.get(0))?;
+    crate::db::query::collect_rows(rows)
+}
+
+fn edge_count(conn: &Connection, edge_type: &str) -> Result<i64> {
+    Ok(conn.query_row(
+        "SELECT COUNT(*) FROM memory_edges WHERE edge_type = ?1",
+        [edge_type],
+        |row| row.get(0),
+    )?)
+}
+
+#[test]
+fn peer_memory_replays_once_and_is_never_echoed_back() -> Result<()> {
+    let dir = unique_sync_dir("sync-round-trip");
+    let now = chrono::Utc::now().timestamp();
+    let laptop = SyncIdentity::generate_for_test(SHARED_KEY);
+    let workstation = SyncIdentity::generate_for_test(SHARED_KEY);
+    let mut laptop_db = device_db()?;
+    let mut workstation_db = device_db()?;
+    insert_memory(
+        &laptop_db,
+        Some("deploy-strategy"),
+        "Deploy strategy",
+        "Use blue-green deploys",
+        now - 100,
+    )?;
+
+    let pushed = sync_with_connection(&mut laptop_db, &laptop, &dir, PROJECT, now)?;
+    let published = pushed.published.expect("laptop publishes its memory");
+    assert_eq!((published.seq, published.upserts), (1, 1));
+    assert!(std::fs::read_to_string(&published.path)?.contains("remem-sync-v1"));
+    assert!(
+        !std::fs::read_to_string(&published.path)?.contains("blue-green"),
+        "segment payload must be encrypted"
+    );
+
+    let pulled = sync_with_connection(&mut workstation_db, &workstation, &dir, PROJECT, now)?;
+    assert_eq!((pulled.replay.segments, pulled.replay.added), (1, 1));
+    assert!(pulled.published.is_none(), "replayed memory must not echo");
+    assert_eq!(
+        active_contents(&workstation_db)?,
+        vec!["Use blue-green deploys"]
+    );
+    let current = crate::memory::state_key::current_memory_id(
+        &workstation_db,
+        "repo",
+        PROJECT,
+        "decision",
+        "deploy-strategy",
+        now,
+    )?;
+    assert!(current.is_some(), "replayed memory owns its state key");
+
+    let again = sync_with_connection(&mut workstation_db, &workstation, &dir, PROJECT, now + 5)?;
+    assert_eq!(again.replay.segments, 0);
+    assert!(again.published.is_none());
+    let _ = std::fs::remove_dir_all(&dir);
+    Ok(())
+}
+
+#[test]
+fn divergent_state_key_converges_to_later_write_with_conflict_edge() -> Result<()> {
+    let dir = unique_sync_dir("sync-conflict");
+    let now = chrono::Utc::now().timestamp();
+    let laptop = SyncIdentity::generate_for_test(SHARED_KEY);
+    let workstation = SyncIdentity::generate_for_test(SHARED_KEY);
+    let mut laptop_db = device_db()?;
+    let mut workstation_db = device_db()?;
+    insert_memory(
+        &laptop_db,
+        Some("queue-runner"),
+        "Queue runner",
+        "Queue runner stays single-threaded",
+        now - 200,
+    )?;
+    insert_memory(
+        &workstation_db,
+        Some("queue-runner"),
+        "Queue runner",
+        "Queue runner uses four workers",
+        now - 100,
+    )?;
+
+    sync_with_connection(&mut laptop_db, &laptop, &dir, PROJECT, now)?;
+    let workstation_report =
+        sync_with_connection(&mut workstation_db, &workstation, &dir, PROJECT, now)?;
+    let laptop_report = sync_with_connection(&mut laptop_db, &laptop, &dir, PROJECT, now + 1)?;
+
+    assert_eq!(workstation_report.replay.conflicts, 1);
+    assert_eq!(laptop_report.replay.conflicts, 1);
+    for conn in [&laptop_db, &workstation_db] {
+        assert_eq!(
+            active_contents(conn)?,
+            vec!["Queue runner uses four workers"]
+        );
+        assert_eq!(edge_count(conn, "conflicts")?, 1);
+        let stale: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM memories
+             WHERE status = 'stale' AND content = 'Queue runner stays single-threaded'",
+            [],
+            |row| row.get(0),
+        )?;
+        assert_eq!(stale, 1, "losing version is kept as history");
+    }
+    let _ = std::fs::remove_dir_all(&dir);
+    Ok(())
+}
+
+#[test]
+fn governance_status_change_replays_to_peer() -> Result<()> {
+    let dir = unique_sync_dir("sync-status");
+    let now = chrono::Utc::now().timestamp();
+    let laptop = SyncIdentity::generate_for_test(SHARED_KEY);
+    let workstation = SyncIdentity::generate_for_test(SHARED_KEY);
+    let mut laptop_db = device_db()?;
+    let mut workstation_db = device_db()?;
+    let id = insert_memory(
+        &laptop_db,
+        None,
+        "Old cache",
+        "Cache lives in /tmp",
+        now - 100,
+    )?;
+    sync_with_connection(&mut laptop_db, &laptop, &dir, PROJECT, now)?;
+    sync_with_connection(&mut workstation_db, &workstation, &dir, PROJECT, now)?;
+
+    crate::memory::governance::govern_memories(
+        &laptop_db,
+        &crate::memory::governance::GovernMemoryRequest {
+            project: PROJECT,
+            ids: &[id],
+            action: crate::memory::governance::MemoryGovernanceAction::MarkStale,
+            reason: Some("cache moved"),
+            actor: Some("tester"),
+            dry_run: false,
+            confirm_destructive: true,
+            acknowledge_pattern: None,
+        },
+    )?;
+    let pushed = sync_with_connection(&mut laptop_db, &laptop, &dir, PROJECT, now + 10)?;
+    assert_eq!(
+        pushed.published.map(|segment| segment.status_changes),
+        Some(1)
+    );
+    let pulled = sync_with_connection(&mut workstation_db, &workstation, &dir, PROJECT, now + 10)?;
+
+    assert_eq!(pulled.replay.status_applied, 1);
+    assert!(active_contents(&workstation_db)?.is_empty());
+    let audit: String = workstation_db.query_row(
+        "SELECT session_id FROM events WHERE event_type = 'memory_governance'",
+        [],
+        |row| row.get(0),
+    )?;
+    assert_eq!(audit, format!("sync:{}", laptop.device_id));
+    let _ = std::fs::remove_dir_all(&dir);
+    Ok(())
+}
+
+#[test]
+fn tampered_or_foreign_key_segments_are_rejected() -> Result<()> {
+    let dir = unique_sync_dir("sync-tamper");
+    let now = chrono::Utc::now().timestamp();
+    let laptop = SyncIdentity::generate_for_test(SHARED_KEY);
+    let mut laptop_db = device_db()?;
+    insert_memory(&laptop_db, None, "Note", "Keep the changelog", now - 100)?;
+    sync_with_connection(&mut laptop_db, &laptop, &dir, PROJECT, now)?;
+
+    let stranger = SyncIdentity::generate_for_test([9; 32]);
+    let error = sync_with_connection(&mut device_db()?, &stranger, &dir, PROJECT, now)
+        .expect_err("different sync key must not decrypt");
+    assert!(
+        format!("{error:#}").contains("code=sync_key_mismatch"),
+        "{error:#}"
+    );
+
+    let path = segment::segment_path(&dir, &laptop.device_id, 1);
+    let original = std::fs::read_to_string(&path)?;
+    let mut envelope: serde_json::Value = serde_json::from_str(&original)?;
+    envelope["nonce"] = serde_json::json!("AAAAAAAAAAAAAAAA");
+    std::fs::write(&path, serde_json::to_string(&envelope)?)?;
+    let peer = SyncIdentity::generate_for_test(SHARED_KEY);
+    let error = sync_with_connection(&mut device_db()?, &peer, &dir, PROJECT, now)
+        .expect_err("tampered segment must fail verification");
+    assert!(
+        format!("{error:#}").contains("code=sync_segment_signature"),
+        "{error:#}"
+    );
+    let _ = std::fs::remove_dir_all(&dir);
+    Ok(())
+}
+
+#[test]
+fn log_key_accepts_only_64_hex_characters() -> Result<()> {
+    let hex = "07".repeat(32);
+    assert_eq!(keys::parse_log_key(&format!("{hex}\n"))?, SHARED_KEY);
+    for rejected in ["correct horse battery staple", &hex[..62], ""] {
+        assert!(
+            keys::parse_log_key(rejected).is_err(),
+            "{rejected:?} must not become a log key"
+        );
+    }
+    Ok(())
+}
@@ -1,10 +1,12 @@
 use anyhow::{bail, Result};
 
+use crate::ai::budget::{budget_statuses, BudgetScope, BudgetStatus};
 use crate::db::{self, AiUsageBreakdown, AiUsageSourceTotals, AiUsageTotals};
 
 const SECS_PER_DAY: i64 = 86_400;
 const SECS_PER_WEEK: i64 = 7 * SECS_PER_DAY;
 const TOP_BREAKDOWN_LIMIT: i64 = 10;
+const BURN_BAR_WIDTH: usize = 20;
 
 pub(in crate::cli) fn run_usage(project: Option<&str>, days: i64, weeks: i64) -> Result<()> {
     validate_window("days", days)?;
@@ -20,6 +22,16 @@ pub(in crate::cli) fn run_usage(project: Option<&str>, days: i64, weeks: i64) ->
         db::query_ai_usage_breakdown(&conn, Some(weekly_since), project, TOP_BREAKDOWN_LIMIT)?;
     let daily = db::query_daily_ai_usage(&conn, daily_since, project, days)?;
     let weekly = db::query_weekly_ai_usage(&conn, weekly_since, project, weeks)?;
+    let budgets = budget_statuses(
+        &conn,
+        &crate::runtime_config::ai_budget_config()?,
+        chrono::Utc::now(),
+    )?
+    .into_iter()
+    .filter(|status| {
+        status.scope != BudgetScope::Project || project.is_none_or(|project| status.name == project)
+    })
+    .collect::<Vec<_>>();
 
     println!("Token usage");
     match project {
@@ -27,6 +39,10 @@ pub(in crate::cli) fn run_usage(project: Option<&str>, days: i64, weeks: i64) ->
         None => println!("Project: all"),
     }
     println!();
+    if !budgets.is_empty() {
+        print_budgets(&budgets);
+        println!();
+    }
     print_totals(&format!("Last {weeks} weeks"), &totals);
     print_precision(&source_totals);
     println!();
@@ -41,6 +57,48 @@ pub(in crate::cli) fn run_usage(project: Option<&str>, days: i64, weeks: i64) ->
     Ok(())
 }
 
+fn print_budgets(rows: &[BudgetStatus]) {
+    println!("Budget burn-down (UTC windows):");
+    println!(
+        "  {:<8} {:<24} {:<8} {:>10} {:>10} {:>10} {:>6}  {:<width$}  Resets",
+        "Scope",
+        "Name",
+        "Window",
+        "Spent",
+        "Limit",
+        "Left",
+        "Used",
+        "",
+        width = BURN_BAR_WIDTH + 2
+    );
+    for row in rows {
+        let resets = chrono::DateTime::from_timestamp(row.resets_at_epoch, 0)
+            .map(|at| at.format("%Y-%m-%d").to_string())
+            .unwrap_or_default();
+        println!(
+            "  {:<8} {:<24} {:<8} ${:>9.4} ${:>9.2} ${:>9.4} {:>5.0}%  {}  {}",
+            row.scope.as_str(),
+            compact_cell(&row.name, 24),
+            row.window.as_str(),
+            row.spent_usd,
+            row.limit_usd,
+            row.remaining_usd(),
+            row.used_ratio() * 100.0,
+            burn_bar(row.used_ratio()),
+            resets
+        );
+    }
+}
+
+fn burn_bar(used_ratio: f64) -> String {
+    let filled = ((used_ratio.clamp(0.0, 1.0) * BURN_BAR_WIDTH as f64).round()) as usize;
+    format!(
+        "[{}{}]",
+        "#".repeat(filled),
+        ".".repeat(BURN_BAR_WIDTH - filled)
+    )
+}
+
 fn print_precision(rows: &[AiUsageSourceTotals]) {
     if rows.is_empty() {
         return;
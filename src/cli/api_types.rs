@@ -0,0 +1,47 @@
+use clap::{Subcommand, ValueEnum};
+
+#[derive(Subcommand)]
+pub(in crate::cli) enum ApiCommand {
+    /// Manage named, scoped API tokens.
+    Tokens {
+        #[command(subcommand)]
+        action: ApiTokensAction,
+    },
+}
+
+#[derive(Subcommand)]
+pub(in crate::cli) enum ApiTokensAction {
+    /// Create a named token and print it once.
+    Create {
+        /// Token name recorded in governance and review audit actors.
+        name: String,
+        /// Highest permission the token grants.
+        #[arg(long, value_enum, default_value = "read")]
+        scope: ApiScopeArg,
+        /// Restrict the token to a project key or path. Repeatable.
+        #[arg(long = "project")]
+        projects: Vec<String>,
+        /// Expire the token after this many days.
+        #[arg(long)]
+        expires_in_days: Option<u32>,
+        /// Emit a single JSON object with stable fields for scripts.
+        #[arg(long)]
+        json: bool,
+    },
+    /// List named tokens without their secrets.
+    List {
+        /// Emit a single JSON object with stable fields for scripts.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Revoke a named token immediately.
+    Revoke { name: String },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(in crate::cli) enum ApiScopeArg {
+    Read,
+    Review,
+    Write,
+    Admin,
+}
@@ -65,3 +65,40 @@ pub(in crate::cli) enum ImportAction {
         best_effort: bool,
     },
 }
+
+#[derive(Subcommand)]
+pub(in crate::cli) enum PackAction {
+    /// Verify the team pack signature and queue new or changed rows for review.
+    Pull {
+        /// Team pack directory committed to the repository.
+        #[arg(long)]
+        dir: PathBuf,
+        /// Project path to pull into. Defaults to the current working directory.
+        #[arg(long, short)]
+        project: Option<String>,
+        /// Report what the pull would queue and retire, then roll it back.
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Merge local accepted project memories into the team pack and sign it.
+    Push {
+        /// Team pack directory committed to the repository.
+        #[arg(long)]
+        dir: PathBuf,
+        /// Project path to push from. Defaults to the current working directory.
+        #[arg(long, short)]
+        project: Option<String>,
+        /// Author name recorded in pack.sig. Defaults to pack.author.
+        #[arg(long)]
+        author: Option<String>,
+        /// Maximum local memories to merge.
+        #[arg(long, default_value = "10000")]
+        limit: i64,
+    },
+    /// Print this machine's pack author public key, creating it on first use.
+    Author {
+        /// Author name to show in the suggested trusted_authors entry.
+        #[arg(long)]
+        name: Option<String>,
+    },
+}
@@ -3,22 +3,24 @@ use anyhow::Result;
 use crate::{api, context, db, doctor, install, mcp, worker};
 
 use super::actions::{
-    run_admin, run_archive, run_audit_scope, run_backfill_embeddings, run_backfill_entities,
-    run_cleanup, run_commit, run_config, run_current_state, run_dream, run_dream_backfill,
-    run_embedding, run_encrypt, run_export, run_governance, run_graph_review, run_import,
-    run_ingest_sessions_cli, run_memory_action, run_merge_preferences, run_model, run_pending,
-    run_preferences, run_procedures, run_raw, run_reroute, run_review, run_rules, run_search,
-    run_show, run_status, run_timeline, run_usage, run_user, run_why, run_workstreams,
+    run_admin, run_api_tokens, run_archive, run_audit_scope, run_backfill_embeddings,
+    run_backfill_entities, run_cleanup, run_commit, run_config, run_current_state, run_dream,
+    run_dream_backfill, run_embedding, run_encrypt, run_export, run_glossary, run_governance,
+    run_graph_review, run_import, run_ingest_sessions_cli, run_memory_action,
+    run_merge_preferences, run_model, run_pack, run_pending, run_preferences, run_procedures,
+    run_raw, run_redact, run_reroute, run_review, run_rotate_key, run_rules, run_search, run_show,
+    run_status, run_sync, run_timeline, run_usage, run_user, run_why, run_workstreams,
     GovernanceCliRequest, RerouteCliRequest,
 };
 #[cfg(feature = "eval")]
 use super::actions::{
     run_bench, run_eval, run_eval_associative_baseline, run_eval_capacity, run_eval_coding_bench,
     run_eval_e2e, run_eval_extraction, run_eval_gates, run_eval_governance,
-    run_eval_graph_decision, run_eval_local, run_eval_provider_comparison, run_eval_weight_grid,
+    run_eval_graph_decision, run_eval_local, run_eval_provider_comparison, run_eval_weight_fit,
+    run_eval_weight_grid,
 };
 use super::cwd::resolve_cwd_arg;
-use super::types::{Cli, Commands, ContextGateAction, DoctorAction, RulesAction};
+use super::types::{ApiCommand, Cli, Commands, ContextGateAction, DoctorAction, RulesAction};
 
 #[path = "actions/context_gate.rs"]
 mod context_gate;
@@ -85,7 +87,7 @@ pub(super) async fn run_cli(cli: Cli) -> Result<()> {
                 worker::run(args.once, 2000).await?;
             }
         }
-        Commands::Mcp => mcp::run_mcp_server().await?,
+        Commands::Mcp { listen } => mcp::run_mcp_server(listen).await?,
         Commands::Install {
             target,
             hooks_only,
@@ -98,14 +100,16 @@ pub(super) async fn run_cli(cli: Cli) -> Result<()> {
             json,
             archived_failures,
         } => run_cleanup(dry_run, json, archived_failures)?,
+        Commands::Redact {
+            scan: _,
+            apply,
+            project,
+            json,
+        } => run_redact(apply, project.as_deref(), json)?,
         Commands::DreamBackfill(args) => run_dream_backfill(args)?,
-        Commands::SyncMemory { cwd } => {
-            let cwd = resolve_cwd_arg(cwd);
-            let project = db::project_from_cwd(&cwd);
-            let conn = db::open_db()?;
-            context::claude_memory::sync_to_claude_memory(&conn, &cwd, &project)?;
-        }
+        Commands::SyncMemory { cwd, host } => run_sync_memory(resolve_cwd_arg(cwd), host)?,
         Commands::Preferences { action } => run_preferences(action)?,
+        Commands::Glossary { action } => run_glossary(action)?,
         Commands::Rules { action } => {
             if should_skip_rules_action(&action, crate::hook_cli::remem_hooks_disabled()) {
                 return Ok(());
@@ -318,6 +322,13 @@ pub(super) async fn run_cli(cli: Cli) -> Result<()> {
         #[cfg(feature = "eval")]
         Commands::EvalCapacity(args) => run_eval_capacity(args)?,
         #[cfg(feature = "eval")]
+        Commands::EvalWeightGrid(args) if args.fit => run_eval_weight_fit(
+            args.project.as_deref().unwrap_or_default(),
+            args.k,
+            args.profile,
+            args.min_queries,
+            args.json,
+        )?,
         Commands::EvalWeightGrid(args) => {
             run_eval_weight_grid(&args.dataset, args.k, &args.json_out, args.json)?
         }
@@ -335,12 +346,34 @@ pub(super) async fn run_cli(cli: Cli) -> Result<()> {
         Commands::EvalCodingBench(args) => run_eval_coding_bench(*args).await?,
         #[cfg(feature = "eval")]
         Commands::EvalLocal => run_eval_local()?,
-        Commands::BackfillEntities => run_backfill_entities()?,
+        Commands::BackfillEntities { project, reextract } => {
+            run_backfill_entities(project.as_deref(), reextract)?
+        }
         Commands::BackfillEmbeddings { limit, batch_size } => {
             run_backfill_embeddings(limit, batch_size)?
         }
-        Commands::Encrypt { rekey_raw } => run_encrypt(rekey_raw)?,
-        Commands::Api { port } => api::run_api_server(port).await?,
+        Commands::Encrypt {
+            rekey_raw,
+            rotate,
+            new_key_command,
+        } => {
+            if rotate {
+                run_rotate_key(new_key_command.as_deref())?
+            } else {
+                run_encrypt(rekey_raw)?
+            }
+        }
+        Commands::Api {
+            command: Some(ApiCommand::Tokens { action }),
+            ..
+        } => run_api_tokens(action)?,
+        Commands::Api { openapi: true, .. } => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&api::openapi_document()?)?
+            )
+        }
+        Commands::Api { port, .. } => api::run_api_server(port).await?,
         Commands::Dream {
             project,
             profile,
@@ -374,11 +407,45 @@ pub(super) async fn run_cli(cli: Cli) -> Result<()> {
                 .unwrap_or_else(|| db::project_from_cwd(&resolve_cwd_arg(None)));
             run_export(args, &project)?;
         }
+        Commands::Sync {
+            dir,
+            project,
+            init_key,
+        } => {
+            let project = project.unwrap_or_else(|| db::project_from_cwd(&resolve_cwd_arg(None)));
+            run_sync(&dir, &project, init_key)?;
+        }
+        Commands::Pack { action } => run_pack(action)?,
     }
 
     Ok(())
 }
 
+fn run_sync_memory(cwd: String, host: Option<String>) -> Result<()> {
+    match host.as_deref().map(str::trim) {
+        None | Some("claude-code") => {
+            let project = db::project_from_cwd(&cwd);
+            let conn = db::open_db()?;
+            context::claude_memory::sync_to_claude_memory(&conn, &cwd, &project)
+        }
+        Some("cursor") => {
+            match context::materialize_cursor_rules(&cwd)? {
+                context::CursorRulesOutcome::Written(path) => {
+                    println!("wrote {}", path.display())
+                }
+                context::CursorRulesOutcome::Unchanged(path) => {
+                    println!("unchanged {}", path.display())
+                }
+                context::CursorRulesOutcome::Skipped(reason) => println!("skipped: {reason}"),
+            }
+            Ok(())
+        }
+        Some(other) => anyhow::bail!(
+            "sync-memory --host must be claude-code or cursor, got {other:?} (code=unsupported_host)"
+        ),
+    }
+}
+
 fn should_skip_rules_action(action: &RulesAction, hooks_disabled: bool) -> bool {
     hooks_disabled && matches!(action, RulesAction::Eval { .. })
 }
@@ -228,6 +228,17 @@ pub(in crate::cli) struct EvalWeightGridArgs {
     pub(in crate::cli) json_out: String,
     #[arg(long)]
     pub(in crate::cli) json: bool,
+    /// Learn weights from recorded citation feedback for `--project` instead
+    /// of the golden dataset, and write a winning profile in shadow mode.
+    #[arg(long, requires = "project")]
+    pub(in crate::cli) fit: bool,
+    #[arg(long, short)]
+    pub(in crate::cli) project: Option<String>,
+    /// Profile name to write; defaults to `fit-<project dir name>`.
+    #[arg(long, requires = "fit")]
+    pub(in crate::cli) profile: Option<String>,
+    #[arg(long, requires = "fit", default_value_t = crate::eval::weight_grid::DEFAULT_MIN_FEEDBACK_QUERIES)]
+    pub(in crate::cli) min_queries: usize,
 }
 
 #[derive(Args)]
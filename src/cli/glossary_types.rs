@@ -0,0 +1,49 @@
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub(in crate::cli) enum GlossaryAction {
+    /// Add a term with one or more expansions (e.g. `ptx "payment transaction"`).
+    Add {
+        term: String,
+        #[arg(required = true)]
+        expansions: Vec<String>,
+        /// Project working directory. Defaults to the current directory.
+        #[arg(long)]
+        project: Option<String>,
+    },
+    /// List active glossary entries and the repository glossary file.
+    List {
+        /// Show mined suggestions awaiting review instead.
+        #[arg(long)]
+        suggested: bool,
+        /// Project working directory. Defaults to the current directory.
+        #[arg(long)]
+        project: Option<String>,
+        /// Emit a single JSON object with stable fields for scripts.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Import `[terms]` from a glossary TOML file (defaults to the project's
+    /// `.remem/glossary.toml`).
+    Import {
+        path: Option<String>,
+        /// Project working directory. Defaults to the current directory.
+        #[arg(long)]
+        project: Option<String>,
+    },
+    /// Mine abbreviation suggestions from the project's memories for review.
+    Suggest {
+        /// Project working directory. Defaults to the current directory.
+        #[arg(long)]
+        project: Option<String>,
+        /// Emit a single JSON object with stable fields for scripts.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Activate a suggested (or rejected) entry.
+    Accept { id: i64 },
+    /// Reject an entry; rejected suggestions are not proposed again.
+    Reject { id: i64 },
+    /// Delete an entry.
+    Remove { id: i64 },
+}
@@ -1,4 +1,5 @@
 mod actions;
+mod api_types;
 mod archive_types;
 mod config_types;
 mod context_types;
@@ -9,6 +10,7 @@ mod dream_backfill_types;
 mod embedding_types;
 #[cfg(feature = "eval")]
 mod eval_types;
+mod glossary_types;
 mod memory_types;
 mod model_types;
 mod procedure_types;
@@ -19,12 +21,16 @@ mod rule_types;
 #[cfg(test)]
 mod tests;
 #[cfg(test)]
+mod tests_api;
+#[cfg(test)]
 mod tests_config;
 #[cfg(test)]
 mod tests_doctor;
 #[cfg(all(test, feature = "eval"))]
 mod tests_eval;
 #[cfg(test)]
+mod tests_glossary;
+#[cfg(test)]
 mod tests_governance;
 #[cfg(test)]
 mod tests_maintenance;
@@ -31,12 +31,34 @@ pub(in crate::cli) enum TimelineAction {
         /// Include recent timeline and monthly breakdown.
         #[arg(long)]
         full: bool,
+        /// Report format: markdown, json, csv, or a self-contained html page.
+        #[arg(long, value_enum, default_value = "markdown", conflicts_with = "json")]
+        format: TimelineReportFormatArg,
         /// Emit a single JSON object with stable fields for scripts.
         #[arg(long)]
         json: bool,
     },
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(in crate::cli) enum TimelineReportFormatArg {
+    Markdown,
+    Json,
+    Csv,
+    Html,
+}
+
+impl From<TimelineReportFormatArg> for crate::timeline::TimelineReportFormat {
+    fn from(value: TimelineReportFormatArg) -> Self {
+        match value {
+            TimelineReportFormatArg::Markdown => Self::Markdown,
+            TimelineReportFormatArg::Json => Self::Json,
+            TimelineReportFormatArg::Csv => Self::Csv,
+            TimelineReportFormatArg::Html => Self::Html,
+        }
+    }
+}
+
 #[derive(Subcommand)]
 pub(in crate::cli) enum WorkstreamAction {
     /// List project workstreams.
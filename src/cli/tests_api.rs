@@ -0,0 +1,65 @@
+use super::types::{ApiCommand, ApiScopeArg, ApiTokensAction, Cli, Commands};
+use clap::Parser;
+
+#[test]
+fn cli_parses_api_token_management_commands() {
+    let create = Cli::parse_from([
+        "remem",
+        "api",
+        "tokens",
+        "create",
+        "ci",
+        "--scope",
+        "review",
+        "--project",
+        "/repo/a",
+        "--project",
+        "/repo/b",
+        "--expires-in-days",
+        "30",
+    ]);
+    match create.command {
+        Commands::Api {
+            command:
+                Some(ApiCommand::Tokens {
+                    action:
+                        ApiTokensAction::Create {
+                            name,
+                            scope,
+                            projects,
+                            expires_in_days,
+                            json,
+                        },
+                }),
+            ..
+        } => {
+            assert_eq!(name, "ci");
+            assert_eq!(scope, ApiScopeArg::Review);
+            assert_eq!(projects, ["/repo/a", "/repo/b"]);
+            assert_eq!(expires_in_days, Some(30));
+            assert!(!json);
+        }
+        _ => panic!("expected api tokens create command"),
+    }
+
+    let revoke = Cli::parse_from(["remem", "api", "tokens", "revoke", "ci"]);
+    assert!(matches!(
+        revoke.command,
+        Commands::Api {
+            command: Some(ApiCommand::Tokens {
+                action: ApiTokensAction::Revoke { name }
+            }),
+            ..
+        } if name == "ci"
+    ));
+
+    let serve = Cli::parse_from(["remem", "api", "--port", "6000"]);
+    assert!(matches!(
+        serve.command,
+        Commands::Api {
+            port: 6000,
+            openapi: false,
+            command: None
+        }
+    ));
+}
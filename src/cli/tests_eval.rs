@@ -318,11 +318,39 @@ fn cli_parses_eval_weight_grid_options() {
             assert_eq!(args.json_out, "/tmp/weight-grid.json");
             assert_eq!(args.k, 7);
             assert!(args.json);
+            assert!(!args.fit);
         }
         _ => panic!("expected eval-weight-grid command"),
     }
 }
 
+#[test]
+fn cli_parses_eval_weight_grid_fit_and_requires_project() {
+    let cli = Cli::parse_from([
+        "remem",
+        "eval-weight-grid",
+        "--fit",
+        "-p",
+        "/work/app",
+        "--profile",
+        "app-feedback",
+        "--min-queries",
+        "3",
+    ]);
+
+    match cli.command {
+        Commands::EvalWeightGrid(args) => {
+            assert!(args.fit);
+            assert_eq!(args.project.as_deref(), Some("/work/app"));
+            assert_eq!(args.profile.as_deref(), Some("app-feedback"));
+            assert_eq!(args.min_queries, 3);
+        }
+        _ => panic!("expected eval-weight-grid command"),
+    }
+    assert!(Cli::try_parse_from(["remem", "eval-weight-grid", "--fit"]).is_err());
+    assert!(Cli::try_parse_from(["remem", "eval-weight-grid", "--profile", "p"]).is_err());
+}
+
 #[test]
 fn cli_parses_eval_coding_bench_options() {
     let cli = Cli::parse_from([
@@ -0,0 +1,69 @@
+use super::types::{Cli, Commands, GlossaryAction};
+use clap::Parser;
+
+#[test]
+fn cli_parses_glossary_commands() {
+    let add = Cli::parse_from([
+        "remem",
+        "glossary",
+        "add",
+        "ptx",
+        "payment transaction",
+        "pay tx",
+        "--project",
+        "/tmp/remem",
+    ]);
+    match add.command {
+        Commands::Glossary {
+            action:
+                GlossaryAction::Add {
+                    term,
+                    expansions,
+                    project,
+                },
+        } => {
+            assert_eq!(term, "ptx");
+            assert_eq!(expansions, vec!["payment transaction", "pay tx"]);
+            assert_eq!(project.as_deref(), Some("/tmp/remem"));
+        }
+        _ => panic!("expected glossary add command"),
+    }
+    assert!(Cli::try_parse_from(["remem", "glossary", "add", "ptx"]).is_err());
+
+    let list = Cli::parse_from(["remem", "glossary", "list", "--suggested", "--json"]);
+    assert!(matches!(
+        list.command,
+        Commands::Glossary {
+            action: GlossaryAction::List {
+                suggested: true,
+                project: None,
+                json: true,
+            }
+        }
+    ));
+
+    let import = Cli::parse_from(["remem", "glossary", "import", "terms.toml"]);
+    assert!(matches!(
+        import.command,
+        Commands::Glossary {
+            action: GlossaryAction::Import { path: Some(path), project: None }
+        } if path == "terms.toml"
+    ));
+
+    let accept = Cli::parse_from(["remem", "glossary", "accept", "7"]);
+    assert!(matches!(
+        accept.command,
+        Commands::Glossary {
+            action: GlossaryAction::Accept { id: 7 }
+        }
+    ));
+    assert!(matches!(
+        Cli::parse_from(["remem", "glossary", "suggest"]).command,
+        Commands::Glossary {
+            action: GlossaryAction::Suggest {
+                project: None,
+                json: false
+            }
+        }
+    ));
+}
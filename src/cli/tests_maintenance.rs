@@ -2,7 +2,7 @@ use super::query_types::{
     ProfileSnapshotFormatArg, UserClaimScopeArg, UserClaimSensitivityArg, UserClaimTypeArg,
     UserClaimsAction, UserProfileAction, UserReviewAction, UserSummaryAction,
 };
-use super::types::{Cli, Commands, EmbeddingAction, PendingAction, UserAction};
+use super::types::{Cli, Commands, EmbeddingAction, PackAction, PendingAction, UserAction};
 use clap::Parser;
 
 #[test]
@@ -22,6 +22,25 @@ fn cli_parses_top_level_cleanup_preview() {
     }
 }
 
+#[test]
+fn cli_redact_requires_exactly_one_of_scan_or_apply() {
+    let redact = Cli::parse_from(["remem", "redact", "--apply", "-p", "/repo"]);
+    match redact.command {
+        Commands::Redact {
+            scan,
+            apply,
+            project,
+            json,
+        } => {
+            assert!(!scan && apply && !json);
+            assert_eq!(project.as_deref(), Some("/repo"));
+        }
+        _ => panic!("expected redact command"),
+    }
+    assert!(Cli::try_parse_from(["remem", "redact"]).is_err());
+    assert!(Cli::try_parse_from(["remem", "redact", "--scan", "--apply"]).is_err());
+}
+
 #[test]
 fn cli_parses_cleanup_archived_failures_default_horizon() {
     let cleanup = Cli::parse_from(["remem", "cleanup", "--archived-failures"]);
@@ -136,9 +155,33 @@ fn pending_quarantine_acknowledgement_requires_exact_id() {
 fn cli_parses_encrypt_rekey_raw() {
     let cli = Cli::parse_from(["remem", "encrypt", "--rekey-raw"]);
     match cli.command {
-        Commands::Encrypt { rekey_raw } => assert!(rekey_raw),
+        Commands::Encrypt { rekey_raw, .. } => assert!(rekey_raw),
+        _ => panic!("expected encrypt command"),
+    }
+}
+
+#[test]
+fn cli_parses_encrypt_rotate_with_new_key_command() {
+    let cli = Cli::parse_from([
+        "remem",
+        "encrypt",
+        "--rotate",
+        "--new-key-command",
+        "pass show remem/db-2026",
+    ]);
+    match cli.command {
+        Commands::Encrypt {
+            rekey_raw,
+            rotate,
+            new_key_command,
+        } => {
+            assert!(rotate && !rekey_raw);
+            assert_eq!(new_key_command.as_deref(), Some("pass show remem/db-2026"));
+        }
         _ => panic!("expected encrypt command"),
     }
+    assert!(Cli::try_parse_from(["remem", "encrypt", "--new-key-command", "pass"]).is_err());
+    assert!(Cli::try_parse_from(["remem", "encrypt", "--rotate", "--rekey-raw"]).is_err());
 }
 
 #[test]
@@ -791,3 +834,77 @@ fn cli_parses_user_review_commands() {
         _ => panic!("expected user review edit command"),
     }
 }
+
+#[test]
+fn cli_parses_backfill_entities_reextract() {
+    let cli = Cli::parse_from([
+        "remem",
+        "backfill-entities",
+        "--reextract",
+        "--project",
+        "/tmp/proj",
+    ]);
+    match cli.command {
+        Commands::BackfillEntities { project, reextract } => {
+            assert!(reextract);
+            assert_eq!(project.as_deref(), Some("/tmp/proj"));
+        }
+        _ => panic!("expected backfill-entities command"),
+    }
+}
+
+#[test]
+fn cli_parses_sync_dir_and_init_key() {
+    let cli = Cli::parse_from(["remem", "sync", "--dir", "/tmp/remem-sync", "--init-key"]);
+    match cli.command {
+        Commands::Sync {
+            dir,
+            project,
+            init_key,
+        } => {
+            assert_eq!(dir, std::path::PathBuf::from("/tmp/remem-sync"));
+            assert!(project.is_none());
+            assert!(init_key);
+        }
+        _ => panic!("expected sync command"),
+    }
+}
+
+#[test]
+fn cli_parses_pack_pull_and_push() {
+    let cli = Cli::parse_from(["remem", "pack", "pull", "--dir", ".remem-pack", "--dry-run"]);
+    match cli.command {
+        Commands::Pack {
+            action:
+                PackAction::Pull {
+                    dir,
+                    project,
+                    dry_run,
+                },
+        } => {
+            assert_eq!(dir, std::path::PathBuf::from(".remem-pack"));
+            assert!(project.is_none());
+            assert!(dry_run);
+        }
+        _ => panic!("expected pack pull command"),
+    }
+
+    let cli = Cli::parse_from([
+        "remem",
+        "pack",
+        "push",
+        "--dir",
+        ".remem-pack",
+        "--author",
+        "alice",
+    ]);
+    match cli.command {
+        Commands::Pack {
+            action: PackAction::Push { author, limit, .. },
+        } => {
+            assert_eq!(author.as_deref(), Some("alice"));
+            assert_eq!(limit, 10_000);
+        }
+        _ => panic!("expected pack push command"),
+    }
+}
@@ -1,3 +1,4 @@
+use super::query_types::TimelineReportFormatArg;
 use super::types::{Cli, Commands, TimelineAction, WorkstreamAction, WorkstreamStatusArg};
 use clap::Parser;
 
@@ -139,3 +140,33 @@ fn cli_rejects_invalid_workstream_status() {
 
     assert!(parsed.is_err());
 }
+
+#[test]
+fn cli_parses_timeline_report_format() {
+    let cli = Cli::parse_from([
+        "remem", "timeline", "report", "/repo", "--full", "--format", "html",
+    ]);
+
+    match cli.command {
+        Commands::Timeline {
+            action:
+                TimelineAction::Report {
+                    project,
+                    full,
+                    format,
+                    json,
+                },
+        } => {
+            assert_eq!(project, "/repo");
+            assert!(full);
+            assert_eq!(format, TimelineReportFormatArg::Html);
+            assert!(!json);
+        }
+        _ => panic!("expected timeline report command"),
+    }
+
+    assert!(Cli::try_parse_from([
+        "remem", "timeline", "report", "/repo", "--format", "csv", "--json",
+    ])
+    .is_err());
+}
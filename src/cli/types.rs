@@ -1,12 +1,14 @@
 use clap::{Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
-pub(in crate::cli) use super::archive_types::{ExportArgs, ImportAction};
+pub(in crate::cli) use super::api_types::{ApiCommand, ApiScopeArg, ApiTokensAction};
+pub(in crate::cli) use super::archive_types::{ExportArgs, ImportAction, PackAction};
 pub(in crate::cli) use super::config_types::ConfigAction;
 pub(in crate::cli) use super::context_types::{ContextGateAction, ContextPlanArgs};
 pub(in crate::cli) use super::doctor_types::DoctorAction;
 pub(in crate::cli) use super::dream_backfill_types::DreamBackfillArgs;
 pub(in crate::cli) use super::embedding_types::EmbeddingAction;
+pub(in crate::cli) use super::glossary_types::GlossaryAction;
 pub(in crate::cli) use super::memory_types::{
     MemoryAction, MemoryCleanupType, MemorySuppressionsAction,
 };
@@ -116,8 +118,13 @@ pub(super) enum Commands {
     },
     /// Run the background worker loop, one drain pass, or one exact replay.
     Worker(WorkerArgs),
-    /// Run the MCP server over stdio.
-    Mcp,
+    /// Run the MCP server over stdio, or over streamable HTTP with --listen.
+    Mcp {
+        /// Serve streamable HTTP on this loopback address (e.g. 127.0.0.1:5568)
+        /// instead of stdio; requests need the REST API bearer token.
+        #[arg(long)]
+        listen: Option<std::net::SocketAddr>,
+    },
     /// Install remem MCP and hooks into supported hosts.
     Install {
         /// Which host(s) to install into.
@@ -154,11 +161,31 @@ pub(super) enum Commands {
         #[arg(long, value_name = "DAYS", num_args = 0..=1, default_missing_value = "90")]
         archived_failures: Option<i64>,
     },
-    /// Sync the project memory index into CLAUDE.md.
+    /// Find secrets already stored in the raw archive, observations and
+    /// memories, and scrub them with `--apply`.
+    Redact {
+        /// Report matches per table and detector without changing anything.
+        #[arg(long, conflicts_with = "apply", required_unless_present = "apply")]
+        scan: bool,
+        /// Replace matches with `[REDACTED]` and record a `redaction_audit` trail.
+        #[arg(long)]
+        apply: bool,
+        /// Restrict to one project path.
+        #[arg(long, short)]
+        project: Option<String>,
+        /// Emit a single JSON object with stable fields for scripts.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Sync the project memory index into CLAUDE.md, or with `--host cursor`
+    /// into the generated `.cursor/rules/remem.mdc` project rule.
     SyncMemory {
         /// Project working directory to sync.
         #[arg(long)]
         cwd: Option<String>,
+        /// Target host: claude-code (default) or cursor.
+        #[arg(long)]
+        host: Option<String>,
     },
     /// Re-scan pre-v076 Dream-merged active memories against the poisoning
     /// scanner: quarantine hits into the review queue, backfill trust class
@@ -169,6 +196,12 @@ pub(super) enum Commands {
         #[command(subcommand)]
         action: PreferenceAction,
     },
+    /// Manage the project glossary of codenames and abbreviations used for
+    /// query expansion, entity extraction and FTS search.
+    Glossary {
+        #[command(subcommand)]
+        action: GlossaryAction,
+    },
     /// Inspect and manage compiled preference rules.
     Rules {
         #[command(subcommand)]
@@ -547,7 +580,15 @@ pub(super) enum Commands {
     #[cfg(feature = "eval")]
     EvalLocal,
     /// Backfill entity records for existing memories.
-    BackfillEntities,
+    BackfillEntities {
+        /// Only process memories of this project.
+        #[arg(long)]
+        project: Option<String>,
+        /// Replace every memory's entity links with the current extractor's
+        /// output and prune entities no memory mentions anymore.
+        #[arg(long)]
+        reextract: bool,
+    },
     #[command(visible_alias = "reindex-embeddings")]
     BackfillEmbeddings {
         #[arg(long, default_value_t = 1000)]
@@ -555,17 +596,30 @@ pub(super) enum Commands {
         #[arg(long, default_value_t = 1000, help = "Rows per measured write batch")]
         batch_size: i64,
     },
-    /// Encrypt the local database or migrate its key format.
+    /// Encrypt the local database, rotate its key, or migrate its key format.
     Encrypt {
         /// Migrate an existing legacy passphrase key file to raw-key format.
         #[arg(long)]
         rekey_raw: bool,
+        /// Re-key the database, spill queues and backups under a new key.
+        /// Rerun after a crash to finish an interrupted rotation.
+        #[arg(long, conflicts_with = "rekey_raw")]
+        rotate: bool,
+        /// With --rotate: read the new key from this command and save it as
+        /// encryption.key_command instead of generating a new key file.
+        #[arg(long, requires = "rotate", value_name = "COMMAND")]
+        new_key_command: Option<String>,
     },
     /// Run the local HTTP API server.
     Api {
         /// Loopback port for the HTTP API.
         #[arg(long, short, default_value = "5567")]
         port: u16,
+        /// Print the OpenAPI 3.1 document for the API and exit instead of serving.
+        #[arg(long)]
+        openapi: bool,
+        #[command(subcommand)]
+        command: Option<ApiCommand>,
     },
     /// Merge duplicate or overlapping memories.
     Dream {
@@ -595,10 +649,10 @@ pub(super) enum Commands {
         #[command(subcommand)]
         action: Option<ImportAction>,
     },
-    /// Batch-ingest Claude Code / Codex session transcripts into the raw archive.
+    /// Batch-ingest Claude Code / Codex / Gemini CLI session transcripts into the raw archive.
     IngestSessions {
         /// Extra scan root as label=path (repeatable). Defaults always include
-        /// ~/.claude/projects and ~/.codex/sessions.
+        /// ~/.claude/projects, ~/.codex/sessions and ~/.gemini/tmp.
         #[arg(long = "root")]
         roots: Vec<String>,
         /// Skip files last modified before this bound (Unix epoch or ISO8601 date/datetime).
@@ -610,6 +664,24 @@ pub(super) enum Commands {
     },
     /// Export curated memories to a human-editable mirror.
     Export(ExportArgs),
+    /// Exchange curated project memory with other devices through an
+    /// encrypted, signed change log in a shared directory.
+    Sync {
+        /// Shared sync directory (Syncthing, Dropbox or git folder), one per project.
+        #[arg(long)]
+        dir: PathBuf,
+        /// Project path to sync. Defaults to the current working directory.
+        #[arg(long, short)]
+        project: Option<String>,
+        /// Generate the shared sync key on this device if it has none.
+        #[arg(long)]
+        init_key: bool,
+    },
+    /// Share a signed project memory pack with a team through the repository.
+    Pack {
+        #[command(subcommand)]
+        action: PackAction,
+    },
 }
 
 #[derive(Subcommand)]
@@ -4,9 +4,11 @@ mod bundle_candidates;
 pub mod claude_memory;
 mod commit_signals;
 mod current_truth;
+mod cursor_rules;
 mod debug;
 mod diagnostics;
 mod fact_labels;
+mod file_touch;
 mod filters;
 mod format;
 mod g2_backfill;
@@ -46,6 +48,8 @@ pub(crate) use bundle_candidates::load_session_start_candidates;
 pub(crate) use bundle_candidates::{
     load_session_start_candidates_with_limits, LoadedBundleCandidates,
 };
+pub use cursor_rules::{materialize_cursor_rules, refresh_cursor_rules, CursorRulesOutcome};
+pub(crate) use file_touch::{file_touch_additional_context, FILE_TOUCH_TOOLS};
 pub(crate) use hybrid_context::{
     query_hybrid_context_memories_with_rank_signal_mode, InjectionRankSignalMode,
 };
@@ -0,0 +1,184 @@
+//! Cursor rules-file materialization (`.cursor/rules/remem.mdc`).
+//!
+//! Cursor 3.12.17 sessionStart injection stays blocked (PR #914), so Cursor
+//! sessions receive the SessionStart context through an always-applied
+//! project rule instead. The body comes from the shared render pipeline with
+//! a `HostKind::Cursor` invocation (hook source `cursorRules`), so
+//! `context_bundle::compile` and the emission audit apply exactly as they do
+//! for hook-delivered hosts.
+//!
+//! Ownership is marker-based: remem only ever overwrites a rules file that
+//! carries [`GENERATED_MARKER`]. `remem sync-memory --host cursor` creates the
+//! file; the summary worker only refreshes one that already exists.
+//!
+//! The file holds private project memory, so creating it also lists it in the
+//! checkout's `.git/info/exclude` to keep it out of commits without touching
+//! the shared `.gitignore`.
+
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
+
+use super::host::HostKind;
+use super::invocation::ContextInvocation;
+use crate::db;
+
+/// `hook_source` recorded on rules-file renders and their audit rows.
+pub(crate) const CURSOR_RULES_SOURCE: &str = "cursorRules";
+/// Rules file location relative to the workspace (project) root.
+pub(crate) const CURSOR_RULES_RELATIVE_PATH: &str = ".cursor/rules/remem.mdc";
+
+const GENERATED_MARKER: &str = "<!-- remem:generated cursor-rules; edits are overwritten -->";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CursorRulesOutcome {
+    Written(PathBuf),
+    Unchanged(PathBuf),
+    Skipped(&'static str),
+}
+
+pub(crate) fn cursor_rules_path(cwd: &str) -> PathBuf {
+    PathBuf::from(db::project_from_cwd(cwd)).join(CURSOR_RULES_RELATIVE_PATH)
+}
+
+/// Creates or refreshes the rules file for the project containing `cwd`.
+/// Refuses to overwrite a hand-written file at the same path.
+pub fn materialize_cursor_rules(cwd: &str) -> Result<CursorRulesOutcome> {
+    let path = cursor_rules_path(cwd);
+    let existing = read_existing(&path)?;
+    if existing.as_deref().is_some_and(|body| !is_generated(body)) {
+        bail!(
+            "{} exists and was not generated by remem; move it aside first (code=cursor_rules_not_owned)",
+            path.display()
+        );
+    }
+    write_rules(cwd, &path, existing.as_deref())
+}
+
+/// Worker entrypoint: refreshes an existing remem-generated rules file and
+/// never creates one.
+pub fn refresh_cursor_rules(cwd: &str) -> Result<CursorRulesOutcome> {
+    let path = cursor_rules_path(cwd);
+    match read_existing(&path)? {
+        None => Ok(CursorRulesOutcome::Skipped("no rules file")),
+        Some(body) if !is_generated(&body) => Ok(CursorRulesOutcome::Skipped(
+            "rules file not generated by remem",
+        )),
+        Some(body) => write_rules(cwd, &path, Some(&body)),
+    }
+}
+
+fn write_rules(cwd: &str, path: &Path, existing: Option<&str>) -> Result<CursorRulesOutcome> {
+    // The audit is recorded only once the file really changes; an unchanged
+    // re-render delivers nothing new to Cursor.
+    let emission =
+        super::render::generate_context_emission_with_deferred_audit(rules_invocation(cwd), false)?;
+    let body = super::style::strip_ansi(&emission.stdout);
+    if body.trim().is_empty() {
+        return Ok(CursorRulesOutcome::Skipped("empty context"));
+    }
+    let content = render_rules_file(&body);
+    if existing == Some(content.as_str()) {
+        return Ok(CursorRulesOutcome::Unchanged(path.to_path_buf()));
+    }
+    crate::atomic_file::write_atomic(path, &content)
+        .with_context(|| format!("write {}", path.display()))?;
+    if existing.is_none() {
+        let project = db::project_from_cwd(cwd);
+        match exclude_from_git(Path::new(&project)) {
+            Ok(Some(exclude)) => crate::log::info(
+                "cursor-rules",
+                &format!("listed {CURSOR_RULES_RELATIVE_PATH} in {}", exclude.display()),
+            ),
+            Ok(None) => {}
+            Err(error) => crate::log::warn(
+                "cursor-rules",
+                &format!(
+                    "{} holds private memory but could not be git-excluded; keep it out of commits: {error:#}",
+                    path.display()
+                ),
+            ),
+        }
+    }
+    if let Some(audit) = emission.audit {
+        audit.persist();
+    }
+    crate::log::info(
+        "cursor-rules",
+        &format!("wrote {} ({} bytes)", path.display(), content.len()),
+    );
+    Ok(CursorRulesOutcome::Written(path.to_path_buf()))
+}
+
+fn rules_invocation(cwd: &str) -> ContextInvocation {
+    let project = db::project_from_cwd(cwd);
+    ContextInvocation {
+        cwd: project.clone(),
+        project,
+        session_id: None,
+        transcript_path: None,
+        source: Some(CURSOR_RULES_SOURCE.to_string()),
+        host: HostKind::Cursor,
+        use_colors: false,
+        debug: false,
+        force: true,
+        gate_mode: None,
+    }
+}
+
+fn render_rules_file(body: &str) -> String {
+    format!(
+        "---\ndescription: remem project memory (generated; refreshed after each summarized session)\nalwaysApply: true\n---\n{GENERATED_MARKER}\n\n{}\n",
+        body.trim_end()
+    )
+}
+
+fn is_generated(body: &str) -> bool {
+    body.contains(GENERATED_MARKER)
+}
+
+/// Appends the rules file to the checkout's `info/exclude` unless it is
+/// already listed. Returns the exclude file when it was changed, or `None`
+/// outside a git checkout.
+fn exclude_from_git(project: &Path) -> Result<Option<PathBuf>> {
+    let Some(output) = crate::git_util::git_output_soft(
+        project,
+        &["rev-parse", "--show-prefix", "--git-path", "info/exclude"],
+    ) else {
+        return Ok(None);
+    };
+    if !output.status.success() {
+        return Ok(None);
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines();
+    let (Some(prefix), Some(exclude)) = (lines.next(), lines.next()) else {
+        bail!("unexpected `git rev-parse --git-path` output: {stdout:?}");
+    };
+    let exclude = project.join(exclude);
+    let pattern = format!("/{prefix}{CURSOR_RULES_RELATIVE_PATH}");
+    let current = read_existing(&exclude)?.unwrap_or_default();
+    if current.lines().any(|line| line.trim() == pattern) {
+        return Ok(None);
+    }
+    let separator = if current.is_empty() || current.ends_with('\n') {
+        ""
+    } else {
+        "\n"
+    };
+    crate::atomic_file::write_atomic(
+        &exclude,
+        format!(
+            "{current}{separator}# remem: generated Cursor rules hold private memory\n{pattern}\n"
+        ),
+    )
+    .with_context(|| format!("write {}", exclude.display()))?;
+    Ok(Some(exclude))
+}
+
+fn read_existing(path: &Path) -> Result<Option<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(body) => Ok(Some(body)),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error).with_context(|| format!("read {}", path.display())),
+    }
+}
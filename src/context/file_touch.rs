@@ -0,0 +1,405 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use rusqlite::{params_from_iter, types::Value};
+
+use crate::memory::Memory;
+
+use super::audit::{
+    memory_render_metadata_with_labels, record_context_injection_items, ContextAuditItem,
+};
+use super::format::{char_len, format_epoch_short, truncate_chars_with_ellipsis};
+use super::host::resolve_host_kind;
+use super::injection_gate::{ContextGateAction, ContextGateDecision};
+use super::invocation::ContextInvocation;
+use super::prompt_submit::query_previously_injected_memory_ids;
+
+/// Host tools whose target file scopes a PreToolUse injection.
+pub(crate) const FILE_TOUCH_TOOLS: &[&str] =
+    &["Read", "Edit", "MultiEdit", "Write", "NotebookEdit"];
+
+const FILE_TOUCH_MEMORY_LIMIT: usize = 3;
+const FILE_TOUCH_CANDIDATE_LIMIT: i64 = 40;
+const FILE_TOUCH_CHAR_LIMIT: usize = 1_200;
+const FILE_TOUCH_PREVIEW_CHARS: usize = 200;
+
+/// How a memory covers the touched file. Lower sorts first.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum FileMatch {
+    Anchor(String),
+    File,
+    /// Listed directory, with the number of path segments between it and the
+    /// touched file.
+    Directory(usize, String),
+}
+
+impl FileMatch {
+    fn describe(&self) -> String {
+        match self {
+            Self::Anchor(label) => format!("anchor {label}"),
+            Self::File => "file".to_string(),
+            Self::Directory(_, dir) => format!("dir {dir}/"),
+        }
+    }
+}
+
+/// Memories whose `files` list or code anchors cover `file_path` (relative to
+/// `project`), rendered as a small note for the host's PreToolUse hook. Each
+/// memory is injected at most once per session, including memories already
+/// emitted by SessionStart or prompt-submit context.
+pub(crate) fn file_touch_additional_context(
+    conn: &rusqlite::Connection,
+    cwd: &str,
+    project: &str,
+    session_id: &str,
+    file_path: &str,
+    host_arg: Option<&str>,
+) -> Result<Option<String>> {
+    let file_path = file_path.trim().trim_start_matches("./").trim_matches('/');
+    if file_path.is_empty() {
+        return Ok(None);
+    }
+    let invocation = ContextInvocation {
+        cwd: cwd.to_string(),
+        project: project.to_string(),
+        session_id: Some(session_id.to_string()),
+        transcript_path: None,
+        source: Some("PreToolUse".to_string()),
+        host: resolve_host_kind(host_arg),
+        use_colors: false,
+        debug: false,
+        force: false,
+        gate_mode: None,
+    };
+
+    let matches = matching_memory_ids(conn, project, file_path)?;
+    if matches.is_empty() {
+        // Nothing covers this path; skip the audit row so every untracked
+        // Read does not write to the database.
+        return Ok(None);
+    }
+    let ids = matches.keys().copied().collect::<Vec<_>>();
+    let mut memories = crate::memory::get_memories_by_ids(conn, &ids, Some(project))?;
+    memories.retain(|memory| memory.status == "active");
+    memories.sort_by(|left, right| {
+        matches[&left.id]
+            .cmp(&matches[&right.id])
+            .then(right.updated_at_epoch.cmp(&left.updated_at_epoch))
+    });
+
+    let as_of_epoch = chrono::Utc::now().timestamp();
+    let mut g2_drops = Vec::new();
+    let mut g2_errors = Vec::new();
+    super::query::exclude_non_current_context_memories(
+        conn,
+        &mut memories,
+        &mut g2_drops,
+        as_of_epoch,
+        &mut g2_errors,
+    );
+    if let Some(error) = g2_errors.first() {
+        anyhow::bail!("file-touch memory visibility classification failed: {error:?}");
+    }
+
+    let already_injected = query_previously_injected_memory_ids(conn, &invocation)?;
+    let mut rendered = Vec::new();
+    let mut audit_items = Vec::new();
+    for drop in g2_drops {
+        if let super::types::ContextPreselectionItem::Memory(memory) = drop.item {
+            audit_items.push(ContextAuditItem::dropped_memory(
+                &memory,
+                "file_touch",
+                drop.reason,
+            ));
+        }
+    }
+    for memory in memories {
+        if already_injected.contains(&memory.id) {
+            audit_items.push(ContextAuditItem::dropped_memory(
+                &memory,
+                "file_touch",
+                "already_injected",
+            ));
+        } else if rendered.len() < FILE_TOUCH_MEMORY_LIMIT {
+            rendered.push(memory);
+        } else {
+            audit_items.push(ContextAuditItem::dropped_memory(
+                &memory,
+                "file_touch",
+                "file_touch_memory_limit",
+            ));
+        }
+    }
+
+    if rendered.is_empty() {
+        record_context_injection_items(
+            conn,
+            &invocation,
+            &file_touch_decision(String::new()),
+            &audit_items,
+        )?;
+        return Ok(None);
+    }
+
+    let staleness_labels = file_touch_staleness_labels(conn, &rendered, as_of_epoch);
+    let (output, render_count) = render_file_touch_context(
+        file_path,
+        &rendered,
+        &matches,
+        &staleness_labels,
+        as_of_epoch,
+    );
+    for (index, memory) in rendered.iter().enumerate() {
+        if index < render_count {
+            audit_items.push(ContextAuditItem::injected_memory_with_labels(
+                memory,
+                "file_touch",
+                index as i64 + 1,
+                &staleness_labels,
+            ));
+        } else {
+            audit_items.push(ContextAuditItem::dropped_memory(
+                memory,
+                "file_touch",
+                "file_touch_char_limit",
+            ));
+        }
+    }
+    let decision = file_touch_decision(if render_count == 0 {
+        String::new()
+    } else {
+        output
+    });
+    record_context_injection_items(conn, &invocation, &decision, &audit_items)?;
+    Ok((render_count > 0).then_some(decision.output))
+}
+
+/// Best match per candidate memory: anchors on the file, the file itself, or
+/// the nearest listed ancestor directory.
+fn matching_memory_ids(
+    conn: &rusqlite::Connection,
+    project: &str,
+    file_path: &str,
+) -> Result<HashMap<i64, FileMatch>> {
+    let mut matches: HashMap<i64, FileMatch> = HashMap::new();
+    let mut record = |id: i64, found: FileMatch| {
+        matches
+            .entry(id)
+            .and_modify(|best| {
+                if found < *best {
+                    *best = found.clone();
+                }
+            })
+            .or_insert(found);
+    };
+
+    let mut stmt = conn.prepare_cached(
+        "SELECT a.memory_id, a.symbol, a.start_line, a.end_line
+         FROM memory_anchors a
+         JOIN memories m ON m.id = a.memory_id
+         WHERE a.file_path = ?1
+           AND (m.project = ?2 OR m.scope = 'global')
+           AND m.status = 'active'
+         ORDER BY m.updated_at_epoch DESC
+         LIMIT ?3",
+    )?;
+    let anchors = stmt.query_map(
+        rusqlite::params![file_path, project, FILE_TOUCH_CANDIDATE_LIMIT],
+        |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, Option<String>>(1)?,
+                row.get::<_, Option<i64>>(2)?,
+                row.get::<_, Option<i64>>(3)?,
+            ))
+        },
+    )?;
+    for anchor in anchors {
+        let (id, symbol, start, end) = anchor?;
+        let label = match (symbol, start, end) {
+            (Some(symbol), _, _) => symbol,
+            (None, Some(start), Some(end)) => format!("L{start}-{end}"),
+            _ => file_path.to_string(),
+        };
+        record(id, FileMatch::Anchor(label));
+    }
+
+    let ancestors = ancestor_dirs(file_path);
+    let mut paths = vec![file_path.to_string()];
+    paths.extend(ancestors.iter().cloned());
+    // Entries may also carry the project path in front; the Rust check below
+    // strips it the same way `memory_file_paths` does.
+    let project_prefix = project.trim().trim_start_matches("./").trim_matches('/');
+    let mut needles = paths.clone();
+    if !project_prefix.is_empty() {
+        needles.extend(paths.iter().map(|path| format!("{project_prefix}/{path}")));
+    }
+    let placeholders = (0..needles.len())
+        .map(|index| format!("?{}", index + 3))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let substrings = (0..paths.len())
+        .map(|index| format!("instr(files, ?{}) > 0", index + 3))
+        .collect::<Vec<_>>()
+        .join(" OR ");
+    // Exact entries are matched in SQL before the limit, so newer memories
+    // that only list a sibling under a shared ancestor (`src/...`) cannot
+    // crowd the touched file's memories out of the candidate window. Legacy
+    // comma-separated lists keep the substring prefilter.
+    let sql = format!(
+        "SELECT id, files FROM memories
+         WHERE (project = ?1 OR scope = 'global')
+           AND status = 'active'
+           AND files IS NOT NULL
+           AND CASE WHEN (CASE WHEN json_valid(files) THEN json_type(files) END) = 'array'
+               THEN EXISTS (
+                   SELECT 1 FROM json_each(memories.files) AS entry
+                   WHERE entry.type = 'text'
+                     AND trim(CASE WHEN trim(entry.value) LIKE './%'
+                                   THEN substr(trim(entry.value), 3)
+                                   ELSE trim(entry.value) END, '/')
+                         IN ({placeholders}))
+               ELSE ({substrings})
+           END
+         ORDER BY updated_at_epoch DESC
+         LIMIT ?2"
+    );
+    let mut bind = vec![
+        Value::Text(project.to_string()),
+        Value::Integer(FILE_TOUCH_CANDIDATE_LIMIT),
+    ];
+    bind.extend(needles.into_iter().map(Value::Text));
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map(params_from_iter(bind), |row| {
+        Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+    })?;
+    for row in rows {
+        let (id, files) = row?;
+        let Ok(files) = crate::memory::staleness::memory_file_paths(Some(&files), project) else {
+            continue;
+        };
+        if files.contains(file_path) {
+            record(id, FileMatch::File);
+            continue;
+        }
+        if let Some((distance, dir)) = ancestors
+            .iter()
+            .enumerate()
+            .find(|(_, dir)| files.contains(dir.as_str()))
+        {
+            record(id, FileMatch::Directory(distance + 1, dir.clone()));
+        }
+    }
+    Ok(matches)
+}
+
+/// `src/db/usage.rs` -> `["src/db", "src"]`, nearest first.
+fn ancestor_dirs(file_path: &str) -> Vec<String> {
+    let mut dirs = Vec::new();
+    let mut current = file_path;
+    while let Some((parent, _)) = current.rsplit_once('/') {
+        if parent.is_empty() {
+            break;
+        }
+        dirs.push(parent.to_string());
+        current = parent;
+    }
+    dirs
+}
+
+fn render_file_touch_context(
+    file_path: &str,
+    memories: &[Memory],
+    matches: &HashMap<i64, FileMatch>,
+    staleness_labels: &HashMap<i64, crate::memory::MemoryStalenessLabel>,
+    render_reference_epoch: i64,
+) -> (String, usize) {
+    let mut output = format!("# remem file context: {file_path}\n");
+    output.push_str(crate::memory::usage::citation_contract_line());
+    output.push('\n');
+    let mut rendered = 0;
+    for memory in memories {
+        let via = matches
+            .get(&memory.id)
+            .map(FileMatch::describe)
+            .unwrap_or_default();
+        let header = format!(
+            "**#{} {}** ({}, {}; via {via}; {})\n",
+            memory.id,
+            memory.title,
+            memory.memory_type,
+            format_epoch_short(memory.updated_at_epoch),
+            memory_render_metadata_with_labels(memory, render_reference_epoch, staleness_labels)
+        );
+        if char_len(&output) + char_len(&header) >= FILE_TOUCH_CHAR_LIMIT {
+            break;
+        }
+        output.push_str(&header);
+        rendered += 1;
+        let remaining = FILE_TOUCH_CHAR_LIMIT.saturating_sub(char_len(&output) + 1);
+        let preview =
+            truncate_chars_with_ellipsis(&memory.text, remaining.min(FILE_TOUCH_PREVIEW_CHARS));
+        if !preview.is_empty() {
+            output.push_str(&preview);
+            output.push('\n');
+        }
+    }
+    (output, rendered)
+}
+
+fn file_touch_staleness_labels(
+    conn: &rusqlite::Connection,
+    memories: &[Memory],
+    render_reference_epoch: i64,
+) -> HashMap<i64, crate::memory::MemoryStalenessLabel> {
+    crate::memory::staleness::memory_staleness_labels_for_memories_lossy(
+        conn,
+        memories,
+        render_reference_epoch,
+        |id, error| {
+            crate::log::error(
+                "context",
+                &format!("file-touch source-anchor label failed for memory {id}: {error}"),
+            );
+        },
+    )
+    .unwrap_or_else(|error| {
+        crate::log::error(
+            "context",
+            &format!("file-touch staleness batch failed: {error}"),
+        );
+        memories
+            .iter()
+            .map(|memory| {
+                (
+                    memory.id,
+                    crate::memory::memory_staleness_error_label(
+                        memory,
+                        render_reference_epoch,
+                        &error,
+                    ),
+                )
+            })
+            .collect()
+    })
+}
+
+fn file_touch_decision(output: String) -> ContextGateDecision {
+    ContextGateDecision {
+        reason: if output.is_empty() {
+            "file_touch_empty"
+        } else {
+            "file_touch"
+        },
+        output,
+        action: ContextGateAction::Bypassed,
+        key: None,
+        context_hash: None,
+        output_mode: Some("file_touch"),
+        retained_context_chars: None,
+        output_truncated: false,
+    }
+}
+
+#[cfg(test)]
+mod tests;
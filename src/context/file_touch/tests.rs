@@ -0,0 +1,202 @@
+use anyhow::Result;
+use rusqlite::Connection;
+
+use super::*;
+use crate::db::test_support::ScopedTestDataDir;
+use crate::memory::anchors::{capture_anchors, replace_memory_anchors, MemoryAnchorInput};
+
+const PROJECT: &str = "/tmp/remem-file-touch";
+const SESSION: &str = "file-touch-session";
+
+fn setup_conn() -> Result<Connection> {
+    let conn = Connection::open_in_memory()?;
+    crate::migrate::run_migrations(&conn)?;
+    Ok(conn)
+}
+
+fn insert(conn: &Connection, id: i64, title: &str, files: Option<&str>) -> Result<i64> {
+    crate::context::tests::insert_memory(
+        conn,
+        id,
+        PROJECT,
+        None,
+        "decision",
+        title,
+        &format!("{title} details"),
+        1_710_000_000 + id,
+    );
+    conn.execute(
+        "UPDATE memories SET files = ?1 WHERE id = ?2",
+        rusqlite::params![files, id],
+    )?;
+    Ok(id)
+}
+
+fn touch(conn: &Connection, file_path: &str) -> Result<Option<String>> {
+    file_touch_additional_context(
+        conn,
+        PROJECT,
+        PROJECT,
+        SESSION,
+        file_path,
+        Some("claude-code"),
+    )
+}
+
+fn audit_rows(conn: &Connection) -> Result<Vec<(Option<i64>, String, String)>> {
+    let mut stmt = conn.prepare(
+        "SELECT memory_id, status, COALESCE(drop_reason, '')
+         FROM context_injection_items
+         WHERE channel = 'file_touch'
+         ORDER BY id",
+    )?;
+    let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?;
+    crate::db::query::collect_rows(rows)
+}
+
+#[test]
+fn ancestor_dirs_lists_nearest_directory_first() {
+    assert_eq!(ancestor_dirs("src/db/usage.rs"), vec!["src/db", "src"]);
+    assert!(ancestor_dirs("README.md").is_empty());
+}
+
+#[test]
+fn exact_file_match_injects_once_per_session() -> Result<()> {
+    let _data_dir = ScopedTestDataDir::new("file-touch-exact");
+    let conn = setup_conn()?;
+    let id = insert(
+        &conn,
+        1,
+        "Usage rollups run nightly",
+        Some("[\"src/db/usage.rs\"]"),
+    )?;
+    insert(
+        &conn,
+        2,
+        "Unrelated parser note",
+        Some("[\"src/parser.rs\"]"),
+    )?;
+
+    let output = touch(&conn, "./src/db/usage.rs")?.expect("file-scoped context");
+    assert!(
+        output.starts_with("# remem file context: src/db/usage.rs"),
+        "{output}"
+    );
+    assert!(
+        output.contains(&format!("**#{id} Usage rollups run nightly**")),
+        "{output}"
+    );
+    assert!(output.contains("via file"), "{output}");
+    assert!(!output.contains("Unrelated parser note"), "{output}");
+
+    assert_eq!(touch(&conn, "src/db/usage.rs")?, None);
+    let rows = audit_rows(&conn)?;
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0], (Some(id), "injected".to_string(), String::new()));
+    assert_eq!(
+        rows[1],
+        (
+            Some(id),
+            "dropped".to_string(),
+            "already_injected".to_string()
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn directory_memories_are_inherited_and_rank_after_file_matches() -> Result<()> {
+    let _data_dir = ScopedTestDataDir::new("file-touch-dir");
+    let conn = setup_conn()?;
+    let dir_id = insert(
+        &conn,
+        3,
+        "Everything under src/db is sync",
+        Some("[\"src/db\"]"),
+    )?;
+    let file_id = insert(&conn, 4, "Usage file note", Some("[\"src/db/usage.rs\"]"))?;
+    insert(
+        &conn,
+        5,
+        "Sibling prefix is not a parent",
+        Some("[\"src/d\"]"),
+    )?;
+
+    let output = touch(&conn, "src/db/usage.rs")?.expect("file-scoped context");
+    let file_at = output.find(&format!("**#{file_id} ")).expect("file memory");
+    let dir_at = output.find(&format!("**#{dir_id} ")).expect("dir memory");
+    assert!(file_at < dir_at, "{output}");
+    assert!(output.contains("via dir src/db/"), "{output}");
+    assert!(!output.contains("Sibling prefix"), "{output}");
+    Ok(())
+}
+
+#[test]
+fn newer_sibling_memories_do_not_crowd_out_the_exact_file() -> Result<()> {
+    let _data_dir = ScopedTestDataDir::new("file-touch-crowded");
+    let conn = setup_conn()?;
+    let id = insert(
+        &conn,
+        10,
+        "Old usage file note",
+        Some("[\"src/db/usage.rs\"]"),
+    )?;
+    let legacy_id = insert(
+        &conn,
+        11,
+        "Legacy list note",
+        Some("./src/db/usage.rs, README.md"),
+    )?;
+    for sibling in 0..(FILE_TOUCH_CANDIDATE_LIMIT + 5) {
+        let files = format!("[\"src/sibling_{sibling}.rs\"]");
+        insert(
+            &conn,
+            100 + sibling,
+            &format!("Sibling note {sibling}"),
+            Some(&files),
+        )?;
+    }
+
+    let output = touch(&conn, "src/db/usage.rs")?.expect("file-scoped context");
+    assert!(
+        output.contains(&format!("**#{id} Old usage file note**")),
+        "{output}"
+    );
+    assert!(
+        output.contains(&format!("**#{legacy_id} Legacy list note**")),
+        "{output}"
+    );
+    assert!(!output.contains("Sibling note"), "{output}");
+    Ok(())
+}
+
+#[test]
+fn anchored_memories_match_their_anchor_file() -> Result<()> {
+    let data_dir = ScopedTestDataDir::new("file-touch-anchor");
+    let conn = setup_conn()?;
+    let id = insert(&conn, 6, "Eviction resets size", None)?;
+    let captured = capture_anchors(
+        &data_dir.path.to_string_lossy(),
+        &[MemoryAnchorInput {
+            file: "src/cache.rs".to_string(),
+            symbol: Some("Cache::evict".to_string()),
+            ..Default::default()
+        }],
+    );
+    replace_memory_anchors(&conn, id, &captured)?;
+
+    let output = touch(&conn, "src/cache.rs")?.expect("anchor context");
+    assert!(output.contains("via anchor Cache::evict"), "{output}");
+    Ok(())
+}
+
+#[test]
+fn untracked_paths_write_no_audit_rows() -> Result<()> {
+    let _data_dir = ScopedTestDataDir::new("file-touch-none");
+    let conn = setup_conn()?;
+    insert(&conn, 7, "Parser note", Some("[\"src/parser.rs\"]"))?;
+
+    assert_eq!(touch(&conn, "src/lexer.rs")?, None);
+    assert!(audit_rows(&conn)?.is_empty());
+    Ok(())
+}
@@ -6,6 +6,7 @@ use super::policy::ContextPolicy;
 pub enum HostKind {
     ClaudeCode,
     CodexCli,
+    GeminiCli,
     /// Constructed only by the strict Cursor hook parser
     /// (`crate::cursor_hook`); deliberately absent from `parse()` so legacy
     /// env/default detection can never produce a Cursor invocation.
@@ -18,6 +19,7 @@ impl HostKind {
         match self {
             Self::ClaudeCode => "claude-code",
             Self::CodexCli => "codex-cli",
+            Self::GeminiCli => "gemini-cli",
             Self::Cursor => "cursor",
             Self::Unknown => "unknown",
         }
@@ -27,6 +29,7 @@ impl HostKind {
         match value.trim().to_ascii_lowercase().as_str() {
             "claude" | "claude-code" | "claudecode" => Some(Self::ClaudeCode),
             "codex" | "codex-cli" | "codexcli" => Some(Self::CodexCli),
+            "gemini" | "gemini-cli" | "geminicli" => Some(Self::GeminiCli),
             "unknown" => Some(Self::Unknown),
             _ => None,
         }
@@ -55,6 +58,7 @@ pub(super) trait ContextHostProfile {
 
 pub(super) struct ClaudeCodeContextProfile;
 pub(super) struct CodexCliContextProfile;
+pub(super) struct GeminiCliContextProfile;
 pub(super) struct CursorContextProfile;
 pub(super) struct UnknownContextProfile;
 
@@ -94,11 +98,33 @@ impl ContextHostProfile for CodexCliContextProfile {
     }
 }
 
+impl ContextHostProfile for GeminiCliContextProfile {
+    /// Gemini CLI hooks mirror Claude's lifecycle (SessionStart, AfterTool,
+    /// AfterAgent, PreCompress) except for a prompt-submit context hook.
+    fn capabilities(&self) -> HostCapabilities {
+        HostCapabilities {
+            has_mcp_tools: true,
+            has_session_start_hook: true,
+            has_user_prompt_submit_hook: false,
+            observes_native_file_edits: true,
+            observes_bash: true,
+        }
+    }
+
+    fn retrieval_hints(&self) -> RetrievalHints {
+        RetrievalHints {
+            line: "Use `search`/`get_observations` for details. `save_memory` after decisions/bugfixes.",
+        }
+    }
+}
+
 impl ContextHostProfile for CursorContextProfile {
     /// GH-823 v1 capability matrix (PR #914, Cursor 3.12.17): session-start
     /// injection is disabled (marker not model-visible) and no post-tool
     /// context command/renderer/install entry exists. Both model-visible
     /// context paths therefore stay unwired; observe capture is generic-only.
+    /// Session context reaches Cursor through the generated
+    /// `.cursor/rules/remem.mdc` rule instead (`context::cursor_rules`).
     fn capabilities(&self) -> HostCapabilities {
         HostCapabilities {
             has_mcp_tools: false,
@@ -156,6 +182,7 @@ pub(super) fn resolve_profile(host: HostKind) -> Box<dyn ContextHostProfile> {
     match host {
         HostKind::ClaudeCode => Box::new(ClaudeCodeContextProfile),
         HostKind::CodexCli => Box::new(CodexCliContextProfile),
+        HostKind::GeminiCli => Box::new(GeminiCliContextProfile),
         HostKind::Cursor => Box::new(CursorContextProfile),
         HostKind::Unknown => Box::new(UnknownContextProfile),
     }
@@ -169,6 +196,8 @@ mod tests {
     fn parses_supported_host_names() {
         assert_eq!(HostKind::parse("claude-code"), Some(HostKind::ClaudeCode));
         assert_eq!(HostKind::parse("codex-cli"), Some(HostKind::CodexCli));
+        assert_eq!(HostKind::parse("gemini"), Some(HostKind::GeminiCli));
+        assert_eq!(HostKind::GeminiCli.as_env_value(), "gemini-cli");
         assert_eq!(HostKind::parse("unknown"), Some(HostKind::Unknown));
         assert_eq!(HostKind::parse("missing"), None);
     }
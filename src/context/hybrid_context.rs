@@ -3,7 +3,7 @@ use rusqlite::{Connection, OptionalExtension};
 
 use crate::memory;
 use crate::retrieval::search::common::{
-    calibrated_vector_hits, sanitize_fts_query, WeightedRankedHit,
+    calibrated_vector_hits, sanitize_fts_terms, WeightedRankedHit,
 };
 use crate::retrieval::search::SearchWeights;
 
@@ -43,7 +43,7 @@ fn query_local_fts_channel(
     excluded_types: &[&str],
     limit: i64,
 ) -> Result<Vec<WeightedRankedHit>> {
-    let expanded = crate::retrieval::query_expand::expand_query(query);
+    let expanded = crate::retrieval::glossary::expand_query_for_project(conn, Some(project), query);
     let long_tokens = expanded
         .iter()
         .filter(|token| token.chars().count() >= 3)
@@ -53,7 +53,7 @@ fn query_local_fts_channel(
         return Ok(vec![]);
     }
 
-    let safe_query = sanitize_fts_query(&long_tokens.join(" "));
+    let safe_query = sanitize_fts_terms(&long_tokens);
     let mut conditions = vec!["memories_fts MATCH ?1".to_string()];
     let mut params: Vec<Box<dyn rusqlite::types::ToSql>> = vec![Box::new(safe_query)];
     let mut idx = 2;
@@ -95,7 +95,7 @@ fn query_local_entity_channel(
     excluded_types: &[&str],
     limit: i64,
 ) -> Result<Vec<WeightedRankedHit>> {
-    let entities = crate::retrieval::entity::extract_entities(query, "");
+    let entities = crate::retrieval::entity::extract_project_entities(conn, project, query, "");
     if entities.is_empty() {
         query_local_entity_like_channel(conn, project, query, current_branch, excluded_types, limit)
     } else {
@@ -34,21 +34,34 @@ pub(in crate::context) fn query_hybrid_context_memories_page(
     limit: i64,
     allow_remote_embedding: bool,
 ) -> Result<Vec<Memory>> {
-    query_hybrid_context_memories_with_weights_page(
-        conn,
-        project,
-        query,
-        current_branch,
-        excluded_types,
-        offset,
-        limit,
-        SearchWeights::production(),
-        allow_remote_embedding,
-    )
+    let project_weights = SearchWeights::for_project(Some(project));
+    let run = |weights| {
+        query_hybrid_context_memories_with_weights_page(
+            conn,
+            project,
+            query,
+            current_branch,
+            excluded_types,
+            offset,
+            limit,
+            weights,
+            allow_remote_embedding,
+        )
+    };
+    let memories = run(project_weights.weights)?;
+    crate::retrieval::search::log_shadow_ranking(
+        "context",
+        Some(project),
+        project_weights.shadow.as_ref(),
+        &memories,
+        run,
+    );
+    Ok(memories)
 }
 
 /// Injection retrieval against explicit weights. The production caller uses
-/// calibrated defaults plus the GH-947 usage-weight override.
+/// the project's `[search.weights]` profile or the calibrated defaults, plus
+/// the GH-947 usage-weight override.
 pub(in crate::context) fn query_hybrid_context_memories_with_weights(
     conn: &Connection,
     project: &str,
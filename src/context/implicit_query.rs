@@ -109,6 +109,9 @@ mod tests {
             created_at_epoch: 1,
             updated_at_epoch: 1,
             completed_at_epoch: None,
+            parent_id: None,
+            dependencies: Vec::new(),
+            milestones: Vec::new(),
         }];
 
         let query = build_implicit_context_query(
@@ -126,6 +126,12 @@ fn workstream_haystack(workstream: &crate::workstream::WorkStream) -> String {
     ]
     .into_iter()
     .flatten()
+    .chain(
+        workstream
+            .milestones
+            .iter()
+            .map(|milestone| milestone.title.as_str()),
+    )
     .collect::<Vec<_>>()
     .join("\n")
 }
@@ -144,7 +144,7 @@ pub(crate) fn prompt_submit_additional_context(
     Ok(Some(decision.output))
 }
 
-fn query_previously_injected_memory_ids(
+pub(super) fn query_previously_injected_memory_ids(
     conn: &rusqlite::Connection,
     invocation: &ContextInvocation,
 ) -> Result<HashSet<i64>> {
@@ -69,10 +69,54 @@ pub(in crate::context) struct RenderedContext {
     pub(in crate::context) context_bundle: Option<crate::context_bundle::ContextBundle>,
 }
 
+/// Rendered stdout plus, when the caller deferred it, the emission audit to
+/// record once the output has actually been delivered.
+pub(in crate::context) struct ContextEmission {
+    pub(in crate::context) stdout: String,
+    pub(in crate::context) audit: Option<PendingEmissionAudit>,
+}
+
+pub(in crate::context) struct PendingEmissionAudit {
+    conn: rusqlite::Connection,
+    invocation: ContextInvocation,
+    decision: ContextGateDecision,
+    audit_items: Vec<ContextAuditItem>,
+    context_bundle: Option<crate::context_bundle::ContextBundle>,
+}
+
+impl PendingEmissionAudit {
+    pub(in crate::context) fn persist(self) {
+        audit_persistence::persist_emission_audit(
+            &self.conn,
+            &self.invocation,
+            &self.decision,
+            &self.audit_items,
+            self.context_bundle.as_ref(),
+        );
+    }
+}
+
 pub(super) fn generate_context_output_for_invocation(
     invocation: ContextInvocation,
     use_gate: bool,
 ) -> Result<String> {
+    render_context_emission(invocation, use_gate, false).map(|emission| emission.stdout)
+}
+
+/// Like [`generate_context_output_for_invocation`] but hands the audit back
+/// instead of writing it, for callers that may end up not emitting.
+pub(in crate::context) fn generate_context_emission_with_deferred_audit(
+    invocation: ContextInvocation,
+    use_gate: bool,
+) -> Result<ContextEmission> {
+    render_context_emission(invocation, use_gate, true)
+}
+
+fn render_context_emission(
+    invocation: ContextInvocation,
+    use_gate: bool,
+    defer_audit: bool,
+) -> Result<ContextEmission> {
     let timer = crate::log::Timer::start("context", &format!("cwd={}", invocation.cwd));
     let debug_enabled = invocation.debug || context_debug_enabled();
     let request = ContextRequest {
@@ -140,7 +184,10 @@ pub(super) fn generate_context_output_for_invocation(
                 &rendered.stats,
                 ContextGatePrecheck::Off,
             );
-            return Ok(stdout);
+            return Ok(ContextEmission {
+                stdout,
+                audit: None,
+            });
         }
     };
     let db_open_timing = crate::perf::PhaseTiming::elapsed("db_open", db_open_start);
@@ -245,7 +292,7 @@ pub(super) fn generate_context_output_for_invocation(
         append_context_gate_debug_trace(&mut decision.output, &request, &decision_for_debug);
     }
     append_hook_integrity_warning(&mut decision.output, hook_integrity_warning.as_deref());
-    if !audit_items.is_empty() {
+    if !audit_items.is_empty() && !defer_audit {
         let audit_write_start = Instant::now();
         audit_persistence::persist_emission_audit(
             &conn,
@@ -261,7 +308,14 @@ pub(super) fn generate_context_output_for_invocation(
     }
     let stdout = context_stdout_for_invocation(&decision.output, &invocation)?;
     log_context_timer(timer, &request, &decision, &stats, precheck);
-    Ok(stdout)
+    let audit = (defer_audit && !audit_items.is_empty()).then(|| PendingEmissionAudit {
+        conn,
+        invocation,
+        decision,
+        audit_items,
+        context_bundle,
+    });
+    Ok(ContextEmission { stdout, audit })
 }
 
 #[cfg(test)]
@@ -117,7 +117,7 @@ pub(in crate::context) fn context_stdout_for_invocation(
         return Ok(format!("{}\n", serde_json::to_string(&hook_output)?));
     }
 
-    if !is_codex_session_start_hook(invocation) {
+    if !is_json_session_start_hook(invocation) {
         return Ok(output.to_string());
     }
 
@@ -140,8 +140,13 @@ fn is_cursor_session_start_hook(invocation: &ContextInvocation) -> bool {
         && invocation.source.as_deref() == Some("sessionStart")
 }
 
-fn is_codex_session_start_hook(invocation: &ContextInvocation) -> bool {
-    if invocation.host != super::super::host::HostKind::CodexCli {
+/// Codex and Gemini CLI both read SessionStart context from a
+/// `hookSpecificOutput` JSON object rather than raw stdout.
+fn is_json_session_start_hook(invocation: &ContextInvocation) -> bool {
+    if !matches!(
+        invocation.host,
+        super::super::host::HostKind::CodexCli | super::super::host::HostKind::GeminiCli
+    ) {
         return false;
     }
 
@@ -1,7 +1,12 @@
-use crate::workstream::WorkStream;
+use std::collections::{HashMap, HashSet};
+
+use crate::workstream::{critical_path, WorkStream, WorkStreamDependencyKind};
 
 use super::super::format::{char_len, inline_context_text};
 
+/// Deeper children are still listed, just not indented further.
+const MAX_RENDER_DEPTH: usize = 3;
+
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub(in crate::context) struct WorkstreamRenderSummary {
     pub count: usize,
@@ -34,21 +39,29 @@ pub(in crate::context) fn render_workstreams_with_summary(
     }
 
     let header = "## WorkStreams\n";
-    let header_chars = char_len(header);
+    let mut header_chars = char_len(header);
     let trailer_chars = 1;
     if header_chars + trailer_chars >= char_limit {
         return WorkstreamRenderSummary::default();
     }
 
+    let path = critical_path(workstreams);
     let output_start_chars = char_len(output);
     let mut section = String::from(header);
+    if let Some(path_line) = format_critical_path_line(workstreams, &path) {
+        let path_chars = char_len(&path_line);
+        if header_chars + path_chars + trailer_chars < char_limit {
+            section.push_str(&path_line);
+            header_chars += path_chars;
+        }
+    }
     let mut total_chars = header_chars + trailer_chars;
     let mut rendered = 0usize;
     let mut ids = Vec::new();
     let mut item_end_chars = Vec::new();
 
-    for workstream in workstreams.iter().take(item_limit) {
-        let line = format_workstream_line(workstream);
+    for (workstream, depth) in plan_order(workstreams, &path).into_iter().take(item_limit) {
+        let line = format_workstream_line(workstream, depth);
         let line_chars = char_len(&line);
         if total_chars + line_chars > char_limit {
             break;
@@ -73,7 +86,69 @@ pub(in crate::context) fn render_workstreams_with_summary(
     }
 }
 
-fn format_workstream_line(workstream: &WorkStream) -> String {
+/// Critical-path members first, then the remaining roots; each row is
+/// followed by its rendered children one level deeper.
+fn plan_order<'a>(workstreams: &'a [WorkStream], path: &[i64]) -> Vec<(&'a WorkStream, usize)> {
+    let by_id: HashMap<i64, &WorkStream> = workstreams.iter().map(|ws| (ws.id, ws)).collect();
+    let mut children: HashMap<i64, Vec<&WorkStream>> = HashMap::new();
+    for workstream in workstreams {
+        if let Some(parent_id) = workstream.parent_id.filter(|id| by_id.contains_key(id)) {
+            children.entry(parent_id).or_default().push(workstream);
+        }
+    }
+
+    fn visit<'a>(
+        workstream: &'a WorkStream,
+        depth: usize,
+        children: &HashMap<i64, Vec<&'a WorkStream>>,
+        seen: &mut HashSet<i64>,
+        ordered: &mut Vec<(&'a WorkStream, usize)>,
+    ) {
+        if !seen.insert(workstream.id) {
+            return;
+        }
+        ordered.push((workstream, depth));
+        for child in children.get(&workstream.id).into_iter().flatten() {
+            visit(child, depth + 1, children, seen, ordered);
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut ordered = Vec::with_capacity(workstreams.len());
+    for id in path {
+        if let Some(workstream) = by_id.get(id) {
+            visit(workstream, 0, &children, &mut seen, &mut ordered);
+        }
+    }
+    for workstream in workstreams {
+        if workstream
+            .parent_id
+            .is_none_or(|id| !by_id.contains_key(&id))
+        {
+            visit(workstream, 0, &children, &mut seen, &mut ordered);
+        }
+    }
+    for workstream in workstreams {
+        visit(workstream, 0, &children, &mut seen, &mut ordered);
+    }
+    ordered
+}
+
+fn format_critical_path_line(workstreams: &[WorkStream], path: &[i64]) -> Option<String> {
+    if path.is_empty() {
+        return None;
+    }
+    let steps = path
+        .iter()
+        .filter_map(|id| workstreams.iter().find(|ws| ws.id == *id))
+        .map(|ws| format!("#{} {}", ws.id, inline_context_text(&ws.title)))
+        .collect::<Vec<_>>();
+    Some(format!("Critical path: {}\n", steps.join(" => ")))
+}
+
+fn format_workstream_line(workstream: &WorkStream, depth: usize) -> String {
+    let indent = "  ".repeat(depth.min(MAX_RENDER_DEPTH));
+    let milestones_part = format_milestones(workstream);
     let next = workstream
         .next_action
         .as_deref()
@@ -84,6 +159,7 @@ fn format_workstream_line(workstream: &WorkStream) -> String {
     } else {
         format!(" -> {}", next)
     };
+    let dependencies_part = format_dependencies(workstream);
     let blockers = workstream
         .blockers
         .as_deref()
@@ -96,11 +172,58 @@ fn format_workstream_line(workstream: &WorkStream) -> String {
     };
     let title = inline_context_text(&workstream.title);
     format!(
-        "- #{} [{}] {}{}{}\n",
+        "{}- #{} [{}] {}{}{}{}{}\n",
+        indent,
         workstream.id,
         workstream.status.as_str(),
         title,
+        milestones_part,
         next_part,
+        dependencies_part,
         blockers_part
     )
 }
+
+fn format_milestones(workstream: &WorkStream) -> String {
+    if workstream.milestones.is_empty() {
+        return String::new();
+    }
+    let done = workstream
+        .milestones
+        .iter()
+        .filter(|milestone| milestone.completed_at_epoch.is_some())
+        .count();
+    let total = workstream.milestones.len();
+    match workstream
+        .milestones
+        .iter()
+        .find(|milestone| milestone.completed_at_epoch.is_none())
+    {
+        Some(open) => format!(
+            " ({done}/{total} milestones, next: {})",
+            inline_context_text(&open.title)
+        ),
+        None => format!(" ({done}/{total} milestones)"),
+    }
+}
+
+fn format_dependencies(workstream: &WorkStream) -> String {
+    let open_ids = |kind: WorkStreamDependencyKind| {
+        workstream
+            .dependencies
+            .iter()
+            .filter(|dependency| dependency.kind == kind && !dependency.satisfied)
+            .map(|dependency| format!("#{}", dependency.depends_on_id))
+            .collect::<Vec<_>>()
+    };
+    let mut out = String::new();
+    let hard = open_ids(WorkStreamDependencyKind::Hard);
+    if !hard.is_empty() {
+        out.push_str(&format!(" | blocked by {}", hard.join(", ")));
+    }
+    let soft = open_ids(WorkStreamDependencyKind::Soft);
+    if !soft.is_empty() {
+        out.push_str(&format!(" | after {}", soft.join(", ")));
+    }
+    out
+}
@@ -133,3 +133,27 @@ fn codex_suppressed_context_stdout_stays_silent() {
 
     assert_eq!(stdout, "");
 }
+
+#[test]
+fn gemini_session_start_hook_stdout_uses_structured_additional_context() {
+    let invocation = ContextInvocation {
+        cwd: "/tmp/remem".to_string(),
+        project: "/tmp/remem".to_string(),
+        session_id: Some("sess-gemini".to_string()),
+        transcript_path: None,
+        source: Some("resume".to_string()),
+        host: HostKind::GeminiCli,
+        use_colors: false,
+        debug: false,
+        force: false,
+        gate_mode: None,
+    };
+
+    let stdout = context_stdout_for_invocation("remem context\n", &invocation).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(stdout.trim()).unwrap();
+
+    assert_eq!(
+        parsed["hookSpecificOutput"]["additionalContext"],
+        "remem context\n"
+    );
+}
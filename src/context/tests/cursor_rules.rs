@@ -0,0 +1,151 @@
+//! `.cursor/rules/remem.mdc` materialization: shared bundle render, audit
+//! persistence under the `cursorRules` hook source, git exclusion, and
+//! marker ownership.
+
+use super::super::cursor_rules::{
+    cursor_rules_path, materialize_cursor_rules, refresh_cursor_rules, CursorRulesOutcome,
+};
+use super::insert_memory;
+
+fn seeded_project(
+    label: &str,
+) -> anyhow::Result<(crate::db::test_support::ScopedTestDataDir, String)> {
+    let data_dir = crate::db::test_support::ScopedTestDataDir::new(label);
+    let cwd = data_dir.path.to_string_lossy().to_string();
+    seed_memory(&cwd)?;
+    Ok((data_dir, cwd))
+}
+
+fn seeded_git_project(
+    label: &str,
+) -> anyhow::Result<(crate::db::test_support::ScopedTestDataDir, String)> {
+    let data_dir = crate::db::test_support::ScopedTestDataDir::new(label);
+    std::fs::create_dir_all(&data_dir.path)?;
+    let status = std::process::Command::new("git")
+        .args(["init", "-q"])
+        .current_dir(&data_dir.path)
+        .status()?;
+    anyhow::ensure!(status.success(), "git init failed");
+    let cwd = data_dir.path.to_string_lossy().to_string();
+    seed_memory(&cwd)?;
+    Ok((data_dir, cwd))
+}
+
+fn seed_memory(cwd: &str) -> anyhow::Result<()> {
+    let conn = crate::db::test_support::runtime_connection()?;
+    insert_memory(
+        &conn,
+        1,
+        &crate::db::project_from_cwd(cwd),
+        Some("cursor-rules-memory"),
+        "decision",
+        "Keep the queue runner single-threaded",
+        "Rules file body",
+        chrono::Utc::now().timestamp(),
+    );
+    Ok(())
+}
+
+#[test]
+fn materialize_writes_always_apply_rule_and_persists_cursor_audit() -> anyhow::Result<()> {
+    let (data_dir, cwd) = seeded_git_project("cursor-rules-write")?;
+
+    let outcome = materialize_cursor_rules(&cwd)?;
+
+    let path = cursor_rules_path(&cwd);
+    assert_eq!(outcome, CursorRulesOutcome::Written(path.clone()));
+    let body = std::fs::read_to_string(&path)?;
+    assert!(body.starts_with("---\ndescription: "), "{body}");
+    assert!(body.contains("\nalwaysApply: true\n---\n"), "{body}");
+    assert!(body.contains("remem:generated cursor-rules"), "{body}");
+    assert!(
+        body.contains("Keep the queue runner single-threaded"),
+        "{body}"
+    );
+    assert!(!body.contains('\x1b'), "rules file must be ANSI-free");
+
+    let injected = cursor_rules_injected_items()?;
+    assert!(
+        injected >= 1,
+        "rules render must persist its emission audit"
+    );
+
+    let exclude_path = data_dir.path.join(".git/info/exclude");
+    let exclude = std::fs::read_to_string(&exclude_path)?;
+    assert!(
+        exclude
+            .lines()
+            .any(|line| line == "/.cursor/rules/remem.mdc"),
+        "{exclude}"
+    );
+    std::fs::remove_file(&path)?;
+    materialize_cursor_rules(&cwd)?;
+    assert_eq!(
+        std::fs::read_to_string(&exclude_path)?,
+        exclude,
+        "a recreated rules file must not repeat the exclude entry"
+    );
+    Ok(())
+}
+
+#[test]
+fn rerender_with_same_context_leaves_file_unchanged() -> anyhow::Result<()> {
+    let (_data_dir, cwd) = seeded_project("cursor-rules-unchanged")?;
+
+    materialize_cursor_rules(&cwd)?;
+    let audited = cursor_rules_injected_items()?;
+    let outcome = refresh_cursor_rules(&cwd)?;
+
+    assert_eq!(
+        outcome,
+        CursorRulesOutcome::Unchanged(cursor_rules_path(&cwd))
+    );
+    assert_eq!(
+        cursor_rules_injected_items()?,
+        audited,
+        "an unchanged rules file must not record another injection"
+    );
+    Ok(())
+}
+
+fn cursor_rules_injected_items() -> anyhow::Result<i64> {
+    let conn = crate::db::test_support::runtime_connection()?;
+    Ok(conn.query_row(
+        "SELECT COUNT(*) FROM context_injection_items
+         WHERE host = 'cursor' AND hook_source = 'cursorRules' AND status = 'injected'",
+        [],
+        |row| row.get(0),
+    )?)
+}
+
+#[test]
+fn hand_written_rules_file_is_never_overwritten() -> anyhow::Result<()> {
+    let (_data_dir, cwd) = seeded_project("cursor-rules-foreign")?;
+    let path = cursor_rules_path(&cwd);
+    std::fs::create_dir_all(path.parent().expect("rules dir"))?;
+    std::fs::write(&path, "my own rule\n")?;
+
+    let error = materialize_cursor_rules(&cwd).expect_err("foreign file must be refused");
+    assert!(
+        format!("{error:#}").contains("code=cursor_rules_not_owned"),
+        "{error:#}"
+    );
+    assert_eq!(
+        refresh_cursor_rules(&cwd)?,
+        CursorRulesOutcome::Skipped("rules file not generated by remem")
+    );
+    assert_eq!(std::fs::read_to_string(&path)?, "my own rule\n");
+    Ok(())
+}
+
+#[test]
+fn worker_refresh_never_creates_a_rules_file() -> anyhow::Result<()> {
+    let (_data_dir, cwd) = seeded_project("cursor-rules-refresh-missing")?;
+
+    assert_eq!(
+        refresh_cursor_rules(&cwd)?,
+        CursorRulesOutcome::Skipped("no rules file")
+    );
+    assert!(!cursor_rules_path(&cwd).exists());
+    Ok(())
+}
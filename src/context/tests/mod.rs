@@ -7,6 +7,7 @@ mod codex_hook_stdout;
 mod context_audit_persistence;
 mod current_truth_activation;
 mod cursor_hook;
+mod cursor_rules;
 mod diagnostics;
 mod engine_convergence;
 mod gate_pipeline;
@@ -63,6 +64,9 @@ pub(super) fn sample_workstream(id: i64, title: &str, next_action: Option<&str>)
         created_at_epoch: 0,
         updated_at_epoch: id,
         completed_at_epoch: None,
+        parent_id: None,
+        dependencies: Vec::new(),
+        milestones: Vec::new(),
     }
 }
 
@@ -389,7 +393,23 @@ pub(super) fn create_workstream_schema(conn: &Connection) {
             owner_key TEXT,
             target_project TEXT,
             identity_key TEXT,
-            merged_into_workstream_id INTEGER
+            merged_into_workstream_id INTEGER,
+            parent_workstream_id INTEGER
+        );
+        CREATE TABLE IF NOT EXISTS workstream_dependencies (
+            workstream_id INTEGER NOT NULL,
+            depends_on_workstream_id INTEGER NOT NULL,
+            kind TEXT NOT NULL DEFAULT 'hard',
+            created_at_epoch INTEGER NOT NULL,
+            PRIMARY KEY (workstream_id, depends_on_workstream_id)
+        );
+        CREATE TABLE IF NOT EXISTS workstream_milestones (
+            id INTEGER PRIMARY KEY,
+            workstream_id INTEGER NOT NULL,
+            title TEXT NOT NULL,
+            position INTEGER NOT NULL,
+            created_at_epoch INTEGER NOT NULL,
+            completed_at_epoch INTEGER
         );",
     )
     .unwrap();
@@ -18,6 +18,9 @@ fn render_workstreams_includes_next_action_when_present() {
         created_at_epoch: 0,
         updated_at_epoch: 0,
         completed_at_epoch: None,
+        parent_id: None,
+        dependencies: Vec::new(),
+        milestones: Vec::new(),
     }];
 
     render_workstreams(&mut output, &workstreams);
@@ -67,3 +70,48 @@ fn render_workstreams_stops_at_char_limit() {
     assert!(!output.contains("#2 [active] Second"));
     assert!(output.chars().count() <= 48);
 }
+
+#[test]
+fn render_workstreams_leads_with_critical_path_and_nests_children() {
+    use crate::workstream::{WorkStreamDependency, WorkStreamDependencyKind, WorkStreamMilestone};
+
+    let mut output = String::new();
+    let unrelated = sample_workstream(1, "Unrelated cleanup", None);
+    let schema = sample_workstream(2, "Schema migration", None);
+    let mut launch = sample_workstream(3, "Launch", Some("announce"));
+    launch.dependencies = vec![WorkStreamDependency {
+        depends_on_id: 2,
+        kind: WorkStreamDependencyKind::Hard,
+        satisfied: false,
+    }];
+    launch.milestones = vec![
+        WorkStreamMilestone {
+            id: 10,
+            title: "Docs".to_string(),
+            position: 1,
+            completed_at_epoch: Some(5),
+        },
+        WorkStreamMilestone {
+            id: 11,
+            title: "Release notes".to_string(),
+            position: 2,
+            completed_at_epoch: None,
+        },
+    ];
+    let mut child = sample_workstream(4, "Backfill", None);
+    child.parent_id = Some(2);
+
+    render_workstreams(&mut output, &[unrelated, schema, launch, child]);
+
+    assert!(output.contains("Critical path: #2 Schema migration => #3 Launch\n"));
+    let lines: Vec<&str> = output.lines().filter(|line| line.contains("- #")).collect();
+    assert_eq!(
+        lines,
+        [
+            "- #2 [active] Schema migration",
+            "  - #4 [active] Backfill",
+            "- #3 [active] Launch (1/2 milestones, next: Release notes) -> announce | blocked by #2",
+            "- #1 [active] Unrelated cleanup",
+        ]
+    );
+}
@@ -18,8 +18,11 @@
 //!   canonical per-call event/upsert identity.
 //! - B-016 MCP ownership is generic: `beforeMCPExecution` and
 //!   `afterMCPExecution` are both unregistered/unsupported.
-//! - All unobserved paths (Task success, Write/Edit/Delete, failed Shell,
-//!   multi-root, Windows/UNC, stop `status:"error"`) stay fail-closed.
+//! - All unobserved paths (Task success, Write/Edit/Delete, multi-root,
+//!   Windows/UNC, stop `status:"error"`) stay fail-closed.
+//! - Total delivered-failure policy (GH-824 contract v2): a structurally valid
+//!   `postToolUseFailure` for any tool other than the observed failed `Read`
+//!   (e.g. failed Shell) is an explicit exit-0 zero-write no-op.
 
 pub mod identity;
 pub mod input;
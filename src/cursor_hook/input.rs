@@ -68,9 +68,33 @@ impl CursorToolOutcome {
 const FAIL_CLOSED_SUCCESS_TOOL_NAMES: [&str; 4] = ["Task", "Write", "Edit", "Delete"];
 
 /// The only observed `postToolUseFailure` shape is a failed `Read`
-/// (PR #914); no other failure event is accepted by analogy.
+/// (PR #914); no other failure event is captured by analogy.
 const ACCEPTED_FAILURE_TOOL_NAMES: [&str; 1] = ["Read"];
 
+/// A structurally valid `postToolUseFailure` for a tool whose failure shape
+/// was never observed. Part of the total delivered-failure policy: the
+/// observe command turns this into an explicit exit-0 zero-write no-op
+/// instead of a hook error, so registering the failure hook never makes
+/// Cursor report remem failures for tools remem does not capture.
+#[derive(Debug)]
+pub struct UnsupportedDeliveredFailure {
+    pub tool_name: String,
+    pub correlation_id: String,
+}
+
+impl std::fmt::Display for UnsupportedDeliveredFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "cursor postToolUseFailure for tool '{}' has no approved capture shape; \
+             recorded as an explicit zero-write no-op [correlation_id={}]",
+            self.tool_name, self.correlation_id
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedDeliveredFailure {}
+
 /// Reads at most `CURSOR_HOOK_STDIN_MAX_BYTES + 1` bytes and rejects the
 /// one-byte-over sentinel before any UTF-8 conversion, `String` allocation,
 /// serde parse, or payload preview (B-009). The error records only the
@@ -116,6 +140,8 @@ pub fn parse_session_start(bytes: &[u8]) -> Result<CursorSessionStart> {
 /// Parses and validates a Cursor observe payload. Only exact `postToolUse`
 /// and `postToolUseFailure` are accepted (B-016 generic ownership keeps
 /// `beforeMCPExecution`/`afterMCPExecution` unregistered and unsupported).
+/// A fully valid failure for an unapproved tool returns
+/// [`UnsupportedDeliveredFailure`] so callers can downcast it.
 pub fn parse_observe_event(bytes: &[u8]) -> Result<CursorToolEvent> {
     let object = parse_outer_object(bytes)?;
     let event_name = required_non_empty_string(&object, "hook_event_name")?;
@@ -135,9 +161,16 @@ pub fn parse_observe_event(bytes: &[u8]) -> Result<CursorToolEvent> {
     let workspace_root = validate_workspace_root(&object)?;
     let transcript_path = validate_transcript_path(&object)?;
     let tool_name = required_non_empty_string(&object, "tool_name")?;
-    validate_tool_name_support(&tool_name, &outcome)?;
+    validate_success_tool_name(&tool_name, &outcome)?;
     let tool_use_id = required_non_empty_string(&object, "tool_use_id")?;
     let tool_input = validate_tool_input(&object)?;
+    if outcome.is_failure() && !ACCEPTED_FAILURE_TOOL_NAMES.contains(&tool_name.as_str()) {
+        return Err(UnsupportedDeliveredFailure {
+            tool_name,
+            correlation_id: correlation_id(),
+        }
+        .into());
+    }
     Ok(CursorToolEvent {
         session_id,
         workspace_root,
@@ -239,16 +272,8 @@ fn parse_failure_outcome(object: &serde_json::Map<String, Value>) -> Result<Curs
     })
 }
 
-fn validate_tool_name_support(tool_name: &str, outcome: &CursorToolOutcome) -> Result<()> {
+fn validate_success_tool_name(tool_name: &str, outcome: &CursorToolOutcome) -> Result<()> {
     if outcome.is_failure() {
-        if !ACCEPTED_FAILURE_TOOL_NAMES.contains(&tool_name) {
-            return Err(anyhow!(
-                "cursor postToolUseFailure for tool '{tool_name}' is unobserved and \
-                 stays fail-closed (only the PR #914 failed Read shape is accepted) \
-                 [correlation_id={}]",
-                correlation_id()
-            ));
-        }
         return Ok(());
     }
     if FAIL_CLOSED_SUCCESS_TOOL_NAMES.contains(&tool_name) {
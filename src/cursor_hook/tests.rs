@@ -5,7 +5,7 @@ use serde_json::json;
 use super::identity::normalize_workspace_root;
 use super::input::{
     parse_observe_event, parse_session_start, read_bounded_hook_input, require_stop_event,
-    CursorToolOutcome,
+    CursorToolOutcome, UnsupportedDeliveredFailure,
 };
 use super::{CURSOR_HOOK_STDIN_MAX_BYTES, CURSOR_TOOL_FIELD_MAX_BYTES};
 
@@ -387,11 +387,22 @@ fn observe_failure_accepts_only_observed_read_shape() {
         let mut fixture = post_tool_failure_fixture();
         fixture["tool_name"] = json!(tool);
         let error = parse_observe_event(&bytes(&fixture)).unwrap_err();
-        assert!(
-            error.to_string().contains("fail-closed"),
-            "failure for {tool} must stay fail-closed"
-        );
+        let skipped = error
+            .downcast_ref::<UnsupportedDeliveredFailure>()
+            .unwrap_or_else(|| panic!("failure for {tool} must be a zero-write no-op"));
+        assert_eq!(skipped.tool_name, tool);
+        assert_no_sentinel(&error);
     }
+
+    // Structural validation still runs first: an unapproved tool name does
+    // not turn a malformed failure into a no-op.
+    let mut fixture = post_tool_failure_fixture();
+    fixture["tool_name"] = json!("Shell");
+    fixture["tool_use_id"] = json!("");
+    let error = parse_observe_event(&bytes(&fixture)).unwrap_err();
+    assert!(error
+        .downcast_ref::<UnsupportedDeliveredFailure>()
+        .is_none());
 }
 
 #[test]
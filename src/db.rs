@@ -8,6 +8,8 @@ pub(crate) mod extraction_replay;
 mod extraction_task_kind;
 pub mod failure_lifecycle;
 pub mod job;
+pub(crate) mod key_provider;
+pub mod key_rotation;
 pub mod models;
 pub mod observation;
 pub mod pending;
@@ -35,6 +37,8 @@ pub use extraction_replay::*;
 pub use extraction_task_kind::*;
 pub use failure_lifecycle::*;
 pub use job::*;
+pub(crate) use key_provider::{configured_key_command, run_key_command};
+pub use key_rotation::{rotate_cipher_key, KeyRotationReport, KeySource};
 pub use models::*;
 pub use observation::*;
 pub use pending::*;
@@ -39,6 +39,23 @@ pub(crate) fn load_cipher_key() -> Result<Option<CipherKey>> {
             return parse_cipher_key(&key).context("parse REMEM_CIPHER_KEY");
         }
     }
+    match super::key_provider::configured_key_command() {
+        Ok(Some(command)) => {
+            return super::key_provider::load_key_from_command(&command).map(Some);
+        }
+        Ok(None) => {}
+        // Every database open lands here; a config.toml that fails to parse
+        // must not take the store down when the key file still opens it.
+        Err(error) => {
+            static WARNED: std::sync::Once = std::sync::Once::new();
+            WARNED.call_once(|| {
+                crate::log::warn(
+                    "db",
+                    &format!("{error:#}; falling back to the SQLCipher key file"),
+                );
+            });
+        }
+    }
 
     let key_path = super::data_dir::try_data_dir()?.join(".key");
     if key_path.exists() {
@@ -71,7 +88,12 @@ pub(crate) fn configure_cipher(conn: &Connection, key: Option<&CipherKey>) -> Re
     if let Some(key) = key {
         apply_cipher_key(conn, key)?;
         if !can_read_schema(conn) {
-            anyhow::bail!("SQLCipher key was applied but the database schema is unreadable");
+            anyhow::bail!(
+                "SQLCipher key was applied but the database schema is unreadable{}",
+                super::key_rotation::interrupted_rotation_hint()
+                    .map(|hint| format!("; {hint}"))
+                    .unwrap_or_default()
+            );
         }
         return Ok(true);
     }
@@ -133,12 +155,14 @@ pub fn generate_cipher_key() -> Result<String> {
     generate_cipher_key_with(getrandom::fill)
 }
 
-fn generate_cipher_key_with<F>(fill_random: F) -> Result<String>
+pub(crate) fn random_raw_key_hex() -> Result<String> {
+    raw_key_hex_with(getrandom::fill)
+}
+
+fn raw_key_hex_with<F>(fill_random: F) -> Result<String>
 where
     F: FnOnce(&mut [u8]) -> std::result::Result<(), getrandom::Error>,
 {
-    use std::io::Write;
-
     let mut key_bytes = [0u8; 32];
     fill_random(&mut key_bytes).map_err(|e| {
         anyhow::anyhow!(
@@ -146,11 +170,19 @@ where
             e
         )
     })?;
-    let key: String = key_bytes
+    Ok(key_bytes
         .iter()
         .map(|byte| format!("{:02x}", byte))
-        .collect();
+        .collect())
+}
 
+fn generate_cipher_key_with<F>(fill_random: F) -> Result<String>
+where
+    F: FnOnce(&mut [u8]) -> std::result::Result<(), getrandom::Error>,
+{
+    use std::io::Write;
+
+    let key = raw_key_hex_with(fill_random)?;
     let data_dir = super::data_dir::try_data_dir()?;
     std::fs::create_dir_all(&data_dir)?;
     #[cfg(unix)]
@@ -278,7 +310,7 @@ pub fn encrypt_database(key: &CipherKey) -> Result<()> {
     conn.execute("DETACH DATABASE encrypted", [])?;
     drop(conn);
 
-    remove_plaintext_sidecars_before_swap(&db_file)?;
+    remove_sqlite_sidecars_before_swap(&db_file)?;
     std::fs::rename(&db_file, &backup_path).with_context(|| {
         format!(
             "move plaintext database {} to temporary migration backup {}",
@@ -384,6 +416,19 @@ fn generated_key_should_be_kept_after_encrypt_failure(db_path: &Path) -> Result<
     }
 }
 
+/// True when `path` starts with the plaintext SQLite header; short files are
+/// not plaintext databases.
+pub(crate) fn sqlite_file_is_plaintext(path: &Path) -> Result<bool> {
+    let mut file =
+        std::fs::File::open(path).with_context(|| format!("inspect {}", path.display()))?;
+    let mut header = [0_u8; 16];
+    match std::io::Read::read_exact(&mut file, &mut header) {
+        Ok(()) => Ok(&header == b"SQLite format 3\0"),
+        Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => Ok(false),
+        Err(error) => Err(error).with_context(|| format!("read {}", path.display())),
+    }
+}
+
 fn finish_generated_key_rollback(errors: Vec<String>) -> Result<()> {
     if errors.is_empty() {
         Ok(())
@@ -420,7 +465,7 @@ fn checkpoint_plaintext_wal_before_export(conn: &Connection) -> Result<()> {
     Ok(())
 }
 
-fn remove_plaintext_sidecars_before_swap(db_file: &Path) -> Result<()> {
+pub(super) fn remove_sqlite_sidecars_before_swap(db_file: &Path) -> Result<()> {
     let mut removable_sidecars = Vec::new();
     for sidecar in sqlite_sidecar_paths(db_file)? {
         match std::fs::symlink_metadata(&sidecar) {
@@ -429,21 +474,20 @@ fn remove_plaintext_sidecars_before_swap(db_file: &Path) -> Result<()> {
             }
             Ok(_) => {
                 anyhow::bail!(
-                    "refusing to remove non-file SQLite sidecar {}; move it aside before retrying encryption",
+                    "refusing to remove non-file SQLite sidecar {}; move it aside and retry",
                     sidecar.display()
                 );
             }
             Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
             Err(error) => {
-                return Err(error).with_context(|| {
-                    format!("inspect plaintext SQLite sidecar {}", sidecar.display())
-                })
+                return Err(error)
+                    .with_context(|| format!("inspect SQLite sidecar {}", sidecar.display()))
             }
         }
     }
     for sidecar in removable_sidecars {
         std::fs::remove_file(&sidecar)
-            .with_context(|| format!("remove plaintext SQLite sidecar {}", sidecar.display()))?;
+            .with_context(|| format!("remove SQLite sidecar {}", sidecar.display()))?;
     }
     Ok(())
 }
@@ -460,7 +504,7 @@ fn sqlite_sidecar_paths(db_file: &Path) -> Result<[PathBuf; 3]> {
     ])
 }
 
-fn attach_key_sql(key: &CipherKey) -> Result<String> {
+pub(super) fn attach_key_sql(key: &CipherKey) -> Result<String> {
     match key {
         CipherKey::Raw(hex) => {
             validate_raw_key_hex(hex)?;
@@ -707,7 +751,7 @@ mod tests {
             std::fs::write(test_dir.path.join(format!("remem.db-{suffix}")), b"plain")?;
         }
 
-        remove_plaintext_sidecars_before_swap(&test_dir.db_path())?;
+        remove_sqlite_sidecars_before_swap(&test_dir.db_path())?;
 
         for suffix in ["wal", "shm", "journal"] {
             assert!(!test_dir.path.join(format!("remem.db-{suffix}")).exists());
@@ -724,7 +768,7 @@ mod tests {
         let shm_path = test_dir.path.join("remem.db-shm");
         std::fs::create_dir(&shm_path)?;
 
-        let error = remove_plaintext_sidecars_before_swap(&test_dir.db_path())
+        let error = remove_sqlite_sidecars_before_swap(&test_dir.db_path())
             .expect_err("non-file sidecar must fail before DB swap");
 
         assert!(
@@ -14,7 +14,7 @@ pub use cleanup::{
 pub use enqueue::{enqueue_job, maybe_enqueue_dream_job, DreamEnqueueDecision};
 pub(crate) use enqueue::{enqueue_job_in_transaction, maybe_enqueue_dream_job_in_transaction};
 pub use state::{
-    mark_job_done, mark_job_exhausted, mark_job_failed, mark_job_failed_or_retry,
+    defer_job, mark_job_done, mark_job_exhausted, mark_job_failed, mark_job_failed_or_retry,
     release_expired_job_leases, requeue_stuck_jobs, ExpiredJobLeaseBatch, ExpiredJobLeaseOutcome,
     JobIdentityKind, JobTransitionOutcome,
 };
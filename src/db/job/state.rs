@@ -147,6 +147,44 @@ pub fn mark_job_exhausted(conn: &Connection, job_id: i64, lease_owner: &str) ->
     Ok(())
 }
 
+/// Return a claimed job to the queue until `retry_at_epoch` without consuming
+/// an attempt. Admission uses this for work that is refused rather than failed,
+/// such as an exhausted AI spend budget.
+pub fn defer_job(
+    conn: &Connection,
+    job_id: i64,
+    lease_owner: &str,
+    reason: &str,
+    retry_at_epoch: i64,
+) -> Result<()> {
+    let now = chrono::Utc::now().timestamp();
+    let tx = immediate_transaction(conn, "defer job")?;
+    let updated = tx.execute(
+        "UPDATE jobs
+         SET state = 'pending',
+             next_retry_epoch = ?1,
+             last_error = ?2,
+             lease_owner = NULL,
+             lease_expires_epoch = NULL,
+             updated_at_epoch = ?3
+         WHERE id = ?4
+           AND state = 'processing'
+           AND lease_owner = ?5
+           AND lease_expires_epoch IS NOT NULL
+           AND lease_expires_epoch >= ?3",
+        params![
+            retry_at_epoch.max(now + 1),
+            crate::db::truncate_str(reason, COALESCED_ERROR_LIMIT),
+            now,
+            job_id,
+            lease_owner
+        ],
+    )?;
+    ensure_lease_transition(&tx, updated, job_id, lease_owner)?;
+    tx.commit().context("commit defer job transaction")?;
+    Ok(())
+}
+
 pub fn release_expired_job_leases(conn: &Connection) -> Result<ExpiredJobLeaseBatch> {
     let now = chrono::Utc::now().timestamp();
     let candidates = {
@@ -1,7 +1,7 @@
 use rusqlite::{params, types::Value, Connection};
 
 use super::{
-    claim_next_job, enqueue_job, mark_job_done, mark_job_exhausted, mark_job_failed,
+    claim_next_job, defer_job, enqueue_job, mark_job_done, mark_job_exhausted, mark_job_failed,
     mark_job_failed_or_retry, maybe_enqueue_dream_job, release_expired_job_leases,
     DreamEnqueueDecision, ExpiredJobLeaseOutcome, JobIdentityKind, JobTransitionOutcome, JobType,
 };
@@ -513,6 +513,51 @@ fn mark_job_failed_or_retry_requeues_before_max_attempts() {
     assert_eq!(row.4.as_deref(), Some("boom"));
 }
 
+#[test]
+fn defer_job_requeues_without_consuming_an_attempt() {
+    let mut conn = setup_conn();
+    let job_id = enqueue_job(&conn, "codex-cli", JobType::Dream, "alpha", None, "{}", 100)
+        .expect("job enqueue should succeed");
+    let claimed = claim_next_job(&mut conn, "worker-a", 60)
+        .expect("claim should succeed")
+        .expect("job should be claimed");
+    let retry_at = chrono::Utc::now().timestamp() + 3_600;
+
+    assert!(defer_job(&conn, claimed.id, "worker-b", "over budget", retry_at).is_err());
+    defer_job(&conn, claimed.id, "worker-a", "over budget", retry_at)
+        .expect("current owner should defer the job");
+
+    let row = conn
+        .query_row(
+            "SELECT state, attempt_count, lease_owner, next_retry_epoch, last_error
+             FROM jobs WHERE id = ?1",
+            params![job_id],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                    row.get::<_, i64>(3)?,
+                    row.get::<_, Option<String>>(4)?,
+                ))
+            },
+        )
+        .expect("job row should load");
+    assert_eq!(
+        row,
+        (
+            "pending".to_string(),
+            0,
+            None,
+            retry_at,
+            Some("over budget".to_string())
+        )
+    );
+    assert!(claim_next_job(&mut conn, "worker-a", 60)
+        .expect("claim should succeed")
+        .is_none());
+}
+
 #[test]
 fn mark_job_failed_or_retry_fails_permanent_error_without_retry() {
     let mut conn = setup_conn();
@@ -0,0 +1,119 @@
+//! External SQLCipher key providers: `encryption.key_command` runs a program
+//! (pass, age, a secret-manager CLI) and reads the key from its stdout.
+
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+
+use anyhow::{bail, Context, Result};
+
+use super::crypto::{parse_cipher_key, CipherKey};
+
+/// Hooks open the database several times per invocation; run the provider
+/// once per process and command.
+static COMMAND_KEY_CACHE: Mutex<Option<(String, CipherKey)>> = Mutex::new(None);
+
+pub(crate) fn configured_key_command() -> Result<Option<String>> {
+    Ok(crate::runtime_config::encryption_config()
+        .context("read [encryption] from remem config")?
+        .key_command)
+}
+
+pub(crate) fn load_key_from_command(command: &str) -> Result<CipherKey> {
+    let mut cache = COMMAND_KEY_CACHE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some((cached_command, key)) = cache.as_ref() {
+        if cached_command == command {
+            return Ok(key.clone());
+        }
+    }
+    let key = run_key_command(command)?;
+    *cache = Some((command.to_string(), key.clone()));
+    Ok(key)
+}
+
+/// Run `command` through the platform shell without the cache. Stdin is
+/// closed, so the provider must not prompt on the terminal.
+pub(crate) fn run_key_command(command: &str) -> Result<CipherKey> {
+    let output = shell_command(command)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .with_context(|| format!("run encryption.key_command `{command}`"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let detail = stderr.lines().next().unwrap_or_default().trim();
+        bail!(
+            "encryption.key_command `{command}` failed ({}){}",
+            output.status,
+            if detail.is_empty() {
+                String::new()
+            } else {
+                format!(": {}", crate::db::truncate_str(detail, 200))
+            }
+        );
+    }
+    let stdout = String::from_utf8(output.stdout)
+        .with_context(|| format!("encryption.key_command `{command}` printed non-UTF-8 output"))?;
+    parse_cipher_key(&stdout)
+        .with_context(|| format!("parse key printed by encryption.key_command `{command}`"))?
+        .with_context(|| format!("encryption.key_command `{command}` printed an empty key"))
+}
+
+#[cfg(unix)]
+fn shell_command(command: &str) -> Command {
+    let mut shell = Command::new("sh");
+    shell.arg("-c").arg(command);
+    shell
+}
+
+#[cfg(not(unix))]
+fn shell_command(command: &str) -> Command {
+    let mut shell = Command::new("cmd");
+    shell.arg("/C").arg(command);
+    shell
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::db::test_support::ScopedTestDataDir;
+
+    #[test]
+    fn key_command_supplies_the_cipher_key_ahead_of_the_key_file() -> Result<()> {
+        let test_dir = ScopedTestDataDir::new("key-command-load");
+        std::env::remove_var("REMEM_CIPHER_KEY");
+        std::fs::create_dir_all(&test_dir.path)?;
+        std::fs::write(test_dir.path.join(".key"), format!("v2:{}", "1".repeat(64)))?;
+        let command_hex = "c".repeat(64);
+        std::fs::write(
+            test_dir.path.join("config.toml"),
+            format!("[encryption]\nkey_command = \"printf 'v2:{command_hex}\\\\n'\"\n"),
+        )?;
+
+        assert_eq!(
+            crate::db::load_cipher_key()?,
+            Some(CipherKey::Raw(command_hex))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn failing_or_empty_key_command_fails_closed() -> Result<()> {
+        let _test_dir = ScopedTestDataDir::new("key-command-fail");
+
+        let error = run_key_command("echo locked >&2; exit 3")
+            .expect_err("non-zero exit must fail")
+            .to_string();
+        assert!(error.contains("encryption.key_command"), "{error}");
+        assert!(error.contains("locked"), "{error}");
+
+        let error = format!(
+            "{:#}",
+            run_key_command("printf '\\n'").expect_err("empty output must fail")
+        );
+        assert!(error.contains("empty key"), "{error}");
+        Ok(())
+    }
+}
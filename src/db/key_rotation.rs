@@ -0,0 +1,810 @@
+//! `remem encrypt --rotate`: move the database, spill queues and backups to
+//! a new SQLCipher key. A journal in the data directory records the phase so
+//! an interrupted rotation resumes instead of stranding data under a key
+//! nobody loads anymore. The journal names key sources, never key material.
+
+use std::fs::{File, OpenOptions};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, ensure, Context, Result};
+use fs2::FileExt;
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::crypto::{
+    apply_cipher_key, attach_key_sql, can_read_schema, parse_cipher_key, random_raw_key_hex,
+    remove_sqlite_sidecars_before_swap, sqlite_file_is_plaintext, write_raw_key_file, CipherKey,
+};
+use super::key_provider::{configured_key_command, run_key_command};
+
+const JOURNAL_FILE: &str = ".key-rotation.json";
+const KEY_FILE: &str = ".key";
+const NEXT_KEY_FILE: &str = ".key.next";
+const PREVIOUS_KEY_FILE: &str = ".key.prev";
+const JOURNAL_VERSION: u32 = 1;
+/// Held exclusively by the background worker for its whole lifetime.
+const WORKER_LOCK_FILE: &str = "worker.lock";
+/// Held shared by every running `remem api` server.
+const API_LOCK_FILE: &str = "api.lock";
+
+/// Where a rotation reads one of its two keys from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "source", rename_all = "snake_case")]
+pub enum KeySource {
+    /// `~/.remem/.key` (staged as `.key.next` until the rotation commits).
+    File,
+    /// `encryption.key_command`.
+    Command { command: String },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum RotationPhase {
+    /// New key staged; the database may still be under the old key.
+    Prepared,
+    /// Database swapped to the new key; the key source still names the old one.
+    DatabaseRekeyed,
+    /// Key source names the new key; spills and backups may lag behind.
+    KeyCommitted,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RotationJournal {
+    version: u32,
+    phase: RotationPhase,
+    old_key: KeySource,
+    new_key: KeySource,
+    old_fingerprint: String,
+    new_fingerprint: String,
+    started_at_epoch: i64,
+}
+
+#[derive(Debug)]
+pub struct KeyRotationReport {
+    pub resumed: bool,
+    pub new_key: KeySource,
+    pub database_rekeyed: bool,
+    pub spill_files: usize,
+    pub spill_lines: usize,
+    pub spill_unreadable_lines: usize,
+    pub backups_rekeyed: usize,
+}
+
+#[cfg(test)]
+static FAIL_AFTER_PHASE: std::sync::Mutex<Option<RotationPhase>> = std::sync::Mutex::new(None);
+
+/// Rotate to a freshly generated key file, or to the key printed by
+/// `new_key_command`. Resumes an interrupted rotation when a journal exists.
+pub fn rotate_cipher_key(new_key_command: Option<&str>) -> Result<KeyRotationReport> {
+    ensure!(
+        std::env::var("REMEM_CIPHER_KEY").map_or(true, |key| key.is_empty()),
+        "REMEM_CIPHER_KEY is set; remem cannot replace a key it reads from the environment. \
+         Unset it and store the key in ~/.remem/.key or encryption.key_command first"
+    );
+    let data_dir = super::data_dir::try_data_dir()?;
+    let db_path = super::core::try_db_path()?;
+    ensure!(
+        db_path.exists(),
+        "database not found: {}; run `remem encrypt` first",
+        db_path.display()
+    );
+    let _process_locks = lock_out_long_running_processes(&data_dir)?;
+    let journal_path = data_dir.join(JOURNAL_FILE);
+    if journal_path.exists() {
+        return resume_rotation(&data_dir, &db_path, &journal_path, new_key_command);
+    }
+
+    let old_source = match configured_key_command()? {
+        Some(command) => KeySource::Command { command },
+        None => KeySource::File,
+    };
+    let old_key = match &old_source {
+        KeySource::File => read_key_file(&data_dir.join(KEY_FILE))?.with_context(|| {
+            format!(
+                "no SQLCipher key file at {}; run `remem encrypt` first",
+                data_dir.join(KEY_FILE).display()
+            )
+        })?,
+        KeySource::Command { command } => run_key_command(command)?,
+    };
+    let next_key_path = data_dir.join(NEXT_KEY_FILE);
+    let (new_source, new_key) = match new_key_command {
+        Some(command) => (
+            KeySource::Command {
+                command: command.trim().to_string(),
+            },
+            run_key_command(command.trim())?,
+        ),
+        None => {
+            ensure!(
+                old_source == KeySource::File,
+                "encryption.key_command is set; store a new key with your provider and pass \
+                 --new-key-command to rotate to it"
+            );
+            // A staged key without a journal never protected anything.
+            remove_file_if_exists(&next_key_path)?;
+            let hex = random_raw_key_hex()?;
+            write_raw_key_file(&next_key_path, &hex)?;
+            (KeySource::File, CipherKey::Raw(hex))
+        }
+    };
+    let journal = RotationJournal {
+        version: JOURNAL_VERSION,
+        phase: RotationPhase::Prepared,
+        old_fingerprint: key_fingerprint(&old_key),
+        new_fingerprint: key_fingerprint(&new_key),
+        old_key: old_source,
+        new_key: new_source,
+        started_at_epoch: chrono::Utc::now().timestamp(),
+    };
+    let preflight = || -> Result<()> {
+        ensure!(
+            journal.old_fingerprint != journal.new_fingerprint,
+            "the new key is identical to the current key"
+        );
+        ensure!(
+            open_with_key(&db_path, &old_key)?.is_some(),
+            "the current SQLCipher key does not open {}; fix the key before rotating",
+            db_path.display()
+        );
+        Ok(())
+    };
+    if let Err(error) = preflight() {
+        if journal.new_key == KeySource::File {
+            remove_file_if_exists(&next_key_path)?;
+        }
+        return Err(error);
+    }
+    write_journal(&journal_path, &journal)?;
+    run_rotation(
+        &data_dir,
+        &db_path,
+        &journal_path,
+        journal,
+        &old_key,
+        &new_key,
+        false,
+    )
+}
+
+fn resume_rotation(
+    data_dir: &Path,
+    db_path: &Path,
+    journal_path: &Path,
+    new_key_command: Option<&str>,
+) -> Result<KeyRotationReport> {
+    let text = std::fs::read_to_string(journal_path)
+        .with_context(|| format!("read key rotation journal {}", journal_path.display()))?;
+    let journal: RotationJournal = serde_json::from_str(&text)
+        .with_context(|| format!("parse key rotation journal {}", journal_path.display()))?;
+    ensure!(
+        journal.version == JOURNAL_VERSION,
+        "unsupported key rotation journal version {} in {}",
+        journal.version,
+        journal_path.display()
+    );
+    if let Some(command) = new_key_command {
+        ensure!(
+            journal.new_key
+                == KeySource::Command {
+                    command: command.trim().to_string()
+                },
+            "an interrupted rotation to a different key is pending in {}; rerun \
+             `remem encrypt --rotate` without --new-key-command to finish it first",
+            journal_path.display()
+        );
+    }
+    let old_key = journal_key(
+        data_dir,
+        &journal.old_key,
+        &journal.old_fingerprint,
+        &[KEY_FILE, PREVIOUS_KEY_FILE],
+    )
+    .context("load the pre-rotation key")?;
+    let new_key = journal_key(
+        data_dir,
+        &journal.new_key,
+        &journal.new_fingerprint,
+        &[NEXT_KEY_FILE, KEY_FILE],
+    )
+    .context("load the rotation's new key")?;
+    crate::log::info(
+        "encrypt",
+        &format!("resuming key rotation from phase {:?}", journal.phase),
+    );
+    run_rotation(
+        data_dir,
+        db_path,
+        journal_path,
+        journal,
+        &old_key,
+        &new_key,
+        true,
+    )
+}
+
+fn run_rotation(
+    data_dir: &Path,
+    db_path: &Path,
+    journal_path: &Path,
+    mut journal: RotationJournal,
+    old_key: &CipherKey,
+    new_key: &CipherKey,
+    resumed: bool,
+) -> Result<KeyRotationReport> {
+    let mut report = KeyRotationReport {
+        resumed,
+        new_key: journal.new_key.clone(),
+        database_rekeyed: false,
+        spill_files: 0,
+        spill_lines: 0,
+        spill_unreadable_lines: 0,
+        backups_rekeyed: 0,
+    };
+
+    if journal.phase == RotationPhase::Prepared {
+        report.database_rekeyed = rekey_database_file(db_path, old_key, new_key)
+            .with_context(|| format!("re-key database {}", db_path.display()))?;
+        advance(journal_path, &mut journal, RotationPhase::DatabaseRekeyed)?;
+    }
+    if journal.phase == RotationPhase::DatabaseRekeyed {
+        commit_new_key(data_dir, &journal)?;
+        advance(journal_path, &mut journal, RotationPhase::KeyCommitted)?;
+    }
+
+    let spills = super::spill_crypto::rekey_spill_files(data_dir, old_key, new_key)?;
+    report.spill_files = spills.files;
+    report.spill_lines = spills.lines;
+    report.spill_unreadable_lines = spills.unreadable_lines;
+
+    let mut stuck_backups = Vec::new();
+    for backup in backup_files(data_dir)? {
+        if sqlite_file_is_plaintext(&backup)? {
+            continue;
+        }
+        match rekey_database_file(&backup, old_key, new_key) {
+            Ok(true) => report.backups_rekeyed += 1,
+            Ok(false) => {}
+            Err(error) => stuck_backups.push(format!("{}: {error:#}", backup.display())),
+        }
+    }
+    ensure!(
+        stuck_backups.is_empty(),
+        "database and key rotated, but these backups could not be re-keyed; move them aside \
+         (or fix them) and rerun `remem encrypt --rotate`: {}",
+        stuck_backups.join("; ")
+    );
+
+    remove_file_if_exists(&data_dir.join(PREVIOUS_KEY_FILE))?;
+    remove_file_if_exists(journal_path)?;
+    crate::log::info(
+        "encrypt",
+        &format!(
+            "SQLCipher key rotated; {} spill line(s) and {} backup(s) re-keyed",
+            report.spill_lines, report.backups_rekeyed
+        ),
+    );
+    Ok(report)
+}
+
+/// The worker and `remem api` keep the database open and write to it; a
+/// rotation that swaps the file under them strands their writes on the old
+/// inode. Refuse while either runs, and keep their locks for the rotation so
+/// neither can start mid-way.
+fn lock_out_long_running_processes(data_dir: &Path) -> Result<Vec<File>> {
+    let mut held = Vec::new();
+    for (name, process) in [
+        (WORKER_LOCK_FILE, "the remem worker"),
+        (API_LOCK_FILE, "`remem api`"),
+    ] {
+        let path = data_dir.join(name);
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)
+            .with_context(|| format!("open process lock {}", path.display()))?;
+        match file.try_lock_exclusive() {
+            Ok(()) => held.push(file),
+            Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => bail!(
+                "{process} is running ({} is locked); stop it and retry `remem encrypt --rotate`",
+                path.display()
+            ),
+            Err(error) => {
+                return Err(error).with_context(|| format!("lock {}", path.display()));
+            }
+        }
+    }
+    Ok(held)
+}
+
+/// Taken by `remem api` for as long as it serves, so a key rotation can tell
+/// the server is running. Shared, so several servers on different ports do
+/// not exclude each other.
+pub(crate) fn hold_api_lock() -> Result<File> {
+    let path = super::data_dir::try_data_dir()?.join(API_LOCK_FILE);
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(&path)
+        .with_context(|| format!("open API lock {}", path.display()))?;
+    FileExt::lock_shared(&file).with_context(|| format!("lock API {}", path.display()))?;
+    Ok(file)
+}
+
+fn advance(journal_path: &Path, journal: &mut RotationJournal, phase: RotationPhase) -> Result<()> {
+    journal.phase = phase;
+    write_journal(journal_path, journal)?;
+    #[cfg(test)]
+    if *FAIL_AFTER_PHASE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        == Some(phase)
+    {
+        bail!("injected key rotation failure after {phase:?}");
+    }
+    Ok(())
+}
+
+/// Point the configured key source at the new key. Idempotent, so a resume
+/// after a crash mid-commit converges.
+fn commit_new_key(data_dir: &Path, journal: &RotationJournal) -> Result<()> {
+    let key_path = data_dir.join(KEY_FILE);
+    let previous_path = data_dir.join(PREVIOUS_KEY_FILE);
+    let retire_old_key_file = || -> Result<()> {
+        let Some(current) = read_key_file(&key_path)? else {
+            return Ok(());
+        };
+        if key_fingerprint(&current) == journal.old_fingerprint {
+            std::fs::rename(&key_path, &previous_path).with_context(|| {
+                format!(
+                    "move retired SQLCipher key {} to {}",
+                    key_path.display(),
+                    previous_path.display()
+                )
+            })?;
+        }
+        Ok(())
+    };
+    match &journal.new_key {
+        KeySource::File => {
+            let next_path = data_dir.join(NEXT_KEY_FILE);
+            if next_path.exists() {
+                retire_old_key_file()?;
+                std::fs::rename(&next_path, &key_path).with_context(|| {
+                    format!(
+                        "install new SQLCipher key {} as {}",
+                        next_path.display(),
+                        key_path.display()
+                    )
+                })?;
+            }
+        }
+        KeySource::Command { command } => {
+            crate::runtime_config::set_key_command(command)?;
+            if journal.old_key == KeySource::File {
+                retire_old_key_file()?;
+            }
+        }
+    }
+    let loaded = super::crypto::load_cipher_key()?
+        .context("no SQLCipher key is configured after committing the rotation")?;
+    ensure!(
+        key_fingerprint(&loaded) == journal.new_fingerprint,
+        "the configured SQLCipher key source does not return the new key after commit"
+    );
+    Ok(())
+}
+
+fn journal_key(
+    data_dir: &Path,
+    source: &KeySource,
+    fingerprint: &str,
+    file_candidates: &[&str],
+) -> Result<CipherKey> {
+    match source {
+        KeySource::File => {
+            for name in file_candidates {
+                if let Some(key) = read_key_file(&data_dir.join(name))? {
+                    if key_fingerprint(&key) == fingerprint {
+                        return Ok(key);
+                    }
+                }
+            }
+            bail!(
+                "no key file in {} matches the key recorded when the rotation started",
+                data_dir.display()
+            )
+        }
+        KeySource::Command { command } => {
+            let key = run_key_command(command)?;
+            ensure!(
+                key_fingerprint(&key) == fingerprint,
+                "`{command}` no longer returns the key recorded when the rotation started"
+            );
+            Ok(key)
+        }
+    }
+}
+
+/// Swap `path` to `new_key` via `sqlcipher_export` into a sibling file and an
+/// atomic rename, so a crash leaves either the old or the new database in
+/// place. A second connection holds `BEGIN IMMEDIATE` from before the export
+/// until after the rename, so no write can land in the old file unexported.
+/// Returns `false` when the file already opens with `new_key`.
+fn rekey_database_file(path: &Path, old_key: &CipherKey, new_key: &CipherKey) -> Result<bool> {
+    let Some(conn) = open_with_key(path, old_key)? else {
+        if open_with_key(path, new_key)?.is_some() {
+            return Ok(false);
+        }
+        bail!(
+            "neither the old nor the new SQLCipher key opens {}",
+            path.display()
+        );
+    };
+    checkpoint_wal_before_rekey(&conn)?;
+
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| anyhow::anyhow!("invalid database file path {}", path.display()))?;
+    let rotated_path = path.with_file_name(format!("{file_name}.rotate"));
+    remove_file_if_exists(&rotated_path)?;
+    let rotated_str = rotated_path.to_str().ok_or_else(|| {
+        anyhow::anyhow!(
+            "re-keyed database path is not valid UTF-8: {}",
+            rotated_path.display()
+        )
+    })?;
+    ensure!(
+        !rotated_str.contains('\0'),
+        "re-keyed database path contains a NUL byte: {}",
+        rotated_path.display()
+    );
+    let write_lock = open_with_key(path, old_key)?
+        .with_context(|| format!("reopen {} to lock out writers", path.display()))?;
+    persist_wal_on_close(&write_lock)?;
+    write_lock
+        .execute_batch("BEGIN IMMEDIATE")
+        .with_context(|| format!("lock {} against writes during re-key", path.display()))?;
+    let user_version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    let schema_objects = schema_object_count(&conn)?;
+    conn.execute(
+        &format!(
+            "ATTACH DATABASE '{}' AS rotated KEY {}",
+            rotated_str.replace('\'', "''"),
+            attach_key_sql(new_key)?
+        ),
+        [],
+    )?;
+    conn.query_row("SELECT sqlcipher_export('rotated')", [], |_| Ok(()))?;
+    conn.execute_batch(&format!("PRAGMA rotated.user_version = {user_version};"))?;
+    conn.execute("DETACH DATABASE rotated", [])?;
+    drop(conn);
+
+    let verify = || -> Result<()> {
+        let rotated = open_with_key(&rotated_path, new_key)?
+            .context("re-keyed copy does not open with the new key")?;
+        let check: String = rotated.query_row("PRAGMA quick_check", [], |row| row.get(0))?;
+        ensure!(check == "ok", "re-keyed copy failed quick_check: {check}");
+        ensure!(
+            schema_object_count(&rotated)? == schema_objects,
+            "re-keyed copy is missing schema objects"
+        );
+        Ok(())
+    };
+    if let Err(error) = verify() {
+        remove_file_if_exists(&rotated_path)?;
+        return Err(error);
+    }
+
+    remove_sqlite_sidecars_before_swap(path)?;
+    std::fs::rename(&rotated_path, path).with_context(|| {
+        format!(
+            "install re-keyed database {} as {}",
+            rotated_path.display(),
+            path.display()
+        )
+    })?;
+    write_lock.execute_batch("ROLLBACK")?;
+    Ok(true)
+}
+
+fn open_with_key(path: &Path, key: &CipherKey) -> Result<Option<Connection>> {
+    ensure!(path.is_file(), "database not found: {}", path.display());
+    let conn = Connection::open(path).with_context(|| format!("open {}", path.display()))?;
+    conn.execute_batch("PRAGMA busy_timeout=5000;")?;
+    apply_cipher_key(&conn, key)?;
+    Ok(can_read_schema(&conn).then_some(conn))
+}
+
+/// Stop SQLite from unlinking `-wal`/`-shm` by name when `conn` closes: once
+/// the re-keyed file is renamed into place, those names belong to it.
+fn persist_wal_on_close(conn: &Connection) -> Result<()> {
+    let mut persist: std::ffi::c_int = 1;
+    // SAFETY: `conn.handle()` is a live connection, the schema name is
+    // NUL-terminated, and SQLite only accesses `persist` during the call.
+    let rc = unsafe {
+        rusqlite::ffi::sqlite3_file_control(
+            conn.handle(),
+            c"main".as_ptr(),
+            rusqlite::ffi::SQLITE_FCNTL_PERSIST_WAL,
+            (&mut persist as *mut std::ffi::c_int).cast(),
+        )
+    };
+    ensure!(
+        rc == rusqlite::ffi::SQLITE_OK,
+        "keep SQLite WAL files on close failed with code {rc}"
+    );
+    Ok(())
+}
+
+fn schema_object_count(conn: &Connection) -> Result<i64> {
+    Ok(conn.query_row("SELECT COUNT(*) FROM sqlite_master", [], |row| row.get(0))?)
+}
+
+fn checkpoint_wal_before_rekey(conn: &Connection) -> Result<()> {
+    let (busy, log_pages, checkpointed_pages) =
+        conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, i64>(2)?,
+            ))
+        })?;
+    ensure!(
+        busy == 0 && log_pages == checkpointed_pages,
+        "SQLite WAL checkpoint incomplete before re-key \
+         (busy={busy}, log_pages={log_pages}, checkpointed_pages={checkpointed_pages}); \
+         stop `remem api`, the worker and other remem processes and retry"
+    );
+    Ok(())
+}
+
+fn backup_files(data_dir: &Path) -> Result<Vec<PathBuf>> {
+    let dir = data_dir.join("backups");
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error).with_context(|| format!("read {}", dir.display())),
+    };
+    let mut backups = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_file()
+            && path.extension().and_then(|ext| ext.to_str()) == Some("sqlite")
+        {
+            backups.push(path);
+        }
+    }
+    backups.sort();
+    Ok(backups)
+}
+
+fn read_key_file(path: &Path) -> Result<Option<CipherKey>> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => parse_cipher_key(&text)
+            .with_context(|| format!("parse SQLCipher key file {}", path.display())),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(error) => {
+            Err(error).with_context(|| format!("read SQLCipher key file {}", path.display()))
+        }
+    }
+}
+
+fn write_journal(path: &Path, journal: &RotationJournal) -> Result<()> {
+    crate::atomic_file::write_atomic(path, serde_json::to_vec_pretty(journal)?)
+        .with_context(|| format!("write key rotation journal {}", path.display()))
+}
+
+fn remove_file_if_exists(path: &Path) -> Result<()> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(error) => Err(error).with_context(|| format!("remove {}", path.display())),
+    }
+}
+
+/// Short, non-reversible identifier used to match keys across a resume.
+fn key_fingerprint(key: &CipherKey) -> String {
+    let digest = Sha256::digest(key.stored_value().as_bytes());
+    digest[..8]
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Surfaced by open errors and `remem doctor` so a half-finished rotation is
+/// easy to diagnose.
+pub(crate) fn interrupted_rotation_hint() -> Option<&'static str> {
+    let data_dir = super::data_dir::try_data_dir().ok()?;
+    data_dir
+        .join(JOURNAL_FILE)
+        .exists()
+        .then_some("a key rotation was interrupted; run `remem encrypt --rotate` to finish it")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::ScopedTestDataDir;
+
+    const BACKUP_NAME: &str = "remem-backup-20261017-120000.sqlite";
+
+    fn seed_encrypted_store(test_dir: &ScopedTestDataDir, key: &CipherKey) -> Result<()> {
+        std::env::remove_var("REMEM_CIPHER_KEY");
+        std::fs::create_dir_all(test_dir.path.join("backups"))?;
+        std::fs::write(test_dir.path.join(KEY_FILE), key.stored_value())?;
+        let conn = Connection::open(test_dir.db_path())?;
+        super::super::configure_cipher(&conn, Some(key))?;
+        conn.execute_batch(
+            "PRAGMA journal_mode=WAL;
+             CREATE TABLE t (id INTEGER PRIMARY KEY, v TEXT);
+             INSERT INTO t (v) VALUES ('kept');
+             PRAGMA user_version = 42;",
+        )?;
+        let backup = Connection::open(test_dir.path.join("backups").join(BACKUP_NAME))?;
+        super::super::configure_cipher(&backup, Some(key))?;
+        backup.execute_batch("CREATE TABLE b (v TEXT); INSERT INTO b VALUES ('backup row');")?;
+        let line = super::super::spill_crypto::encode_json_line(
+            &serde_json::json!({ "message": "spilled" }),
+        )?;
+        std::fs::write(
+            test_dir.path.join("capture-spill.jsonl"),
+            format!("{line}\n"),
+        )?;
+        Ok(())
+    }
+
+    fn assert_store_uses(test_dir: &ScopedTestDataDir, key: &CipherKey) -> Result<()> {
+        let conn = open_with_key(&test_dir.db_path(), key)?.context("db opens with key")?;
+        let value: String = conn.query_row("SELECT v FROM t", [], |row| row.get(0))?;
+        assert_eq!(value, "kept");
+        let user_version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+        assert_eq!(user_version, 42);
+        let backup = open_with_key(&test_dir.path.join("backups").join(BACKUP_NAME), key)?
+            .context("backup opens with key")?;
+        let value: String = backup.query_row("SELECT v FROM b", [], |row| row.get(0))?;
+        assert_eq!(value, "backup row");
+        let spill = std::fs::read_to_string(test_dir.path.join("capture-spill.jsonl"))?;
+        let record: serde_json::Value = super::super::spill_crypto::decode_json_line(spill.trim())?;
+        assert_eq!(record["message"], "spilled");
+        for leftover in [
+            JOURNAL_FILE,
+            NEXT_KEY_FILE,
+            PREVIOUS_KEY_FILE,
+            "remem.db.rotate",
+        ] {
+            assert!(
+                !test_dir.path.join(leftover).exists(),
+                "{leftover} left behind"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn rotation_rekeys_database_spills_and_backups_to_a_new_key_file() -> Result<()> {
+        let test_dir = ScopedTestDataDir::new("key-rotation-file");
+        let old_key = CipherKey::Raw("4".repeat(64));
+        seed_encrypted_store(&test_dir, &old_key)?;
+
+        let report = rotate_cipher_key(None)?;
+
+        assert!(!report.resumed && report.database_rekeyed);
+        assert_eq!(report.new_key, KeySource::File);
+        assert_eq!((report.spill_lines, report.backups_rekeyed), (1, 1));
+        let new_key = super::super::load_cipher_key()?.context("new key loads")?;
+        assert!(matches!(new_key, CipherKey::Raw(_)));
+        assert_ne!(new_key, old_key);
+        assert!(open_with_key(&test_dir.db_path(), &old_key)?.is_none());
+        assert_store_uses(&test_dir, &new_key)
+    }
+
+    #[test]
+    fn interrupted_rotation_fails_open_with_a_hint_and_resumes() -> Result<()> {
+        let test_dir = ScopedTestDataDir::new("key-rotation-resume");
+        let old_key = CipherKey::Raw("6".repeat(64));
+        seed_encrypted_store(&test_dir, &old_key)?;
+
+        *FAIL_AFTER_PHASE.lock().expect("failpoint lock") = Some(RotationPhase::DatabaseRekeyed);
+        let interrupted = rotate_cipher_key(None);
+        *FAIL_AFTER_PHASE.lock().expect("failpoint lock") = None;
+        interrupted.expect_err("failpoint must interrupt the rotation");
+
+        assert!(test_dir.path.join(JOURNAL_FILE).exists());
+        assert_eq!(super::super::load_cipher_key()?, Some(old_key.clone()));
+        let error = match crate::db::open_db_read_only() {
+            Ok(_) => panic!("database is already on the staged key"),
+            Err(error) => format!("{error:#}"),
+        };
+        assert!(error.contains("remem encrypt --rotate"), "{error}");
+
+        let report = rotate_cipher_key(None)?;
+
+        assert!(report.resumed && !report.database_rekeyed);
+        let new_key = super::super::load_cipher_key()?.context("new key loads")?;
+        assert_ne!(new_key, old_key);
+        assert_store_uses(&test_dir, &new_key)?;
+        crate::db::open_db_read_only()?;
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rotation_to_a_key_command_updates_config_and_retires_the_key_file() -> Result<()> {
+        let test_dir = ScopedTestDataDir::new("key-rotation-command");
+        let old_key = CipherKey::Raw("7".repeat(64));
+        seed_encrypted_store(&test_dir, &old_key)?;
+        let new_hex = "8".repeat(64);
+        let command = format!("printf 'v2:{new_hex}'");
+
+        let report = rotate_cipher_key(Some(&command))?;
+
+        assert_eq!(
+            report.new_key,
+            KeySource::Command {
+                command: command.clone()
+            }
+        );
+        assert!(!test_dir.path.join(KEY_FILE).exists());
+        assert_eq!(
+            crate::runtime_config::encryption_config()?.key_command,
+            Some(command)
+        );
+        let new_key = CipherKey::Raw(new_hex);
+        assert_eq!(super::super::load_cipher_key()?, Some(new_key.clone()));
+        assert_store_uses(&test_dir, &new_key)?;
+
+        let error = rotate_cipher_key(None)
+            .expect_err("a command-held key is never replaced by a generated file")
+            .to_string();
+        assert!(error.contains("--new-key-command"), "{error}");
+        Ok(())
+    }
+
+    #[test]
+    fn rotation_refuses_while_the_worker_or_api_is_running() -> Result<()> {
+        let test_dir = ScopedTestDataDir::new("key-rotation-running");
+        let old_key = CipherKey::Raw("9".repeat(64));
+        seed_encrypted_store(&test_dir, &old_key)?;
+
+        let worker = File::create(test_dir.path.join(WORKER_LOCK_FILE))?;
+        worker.lock_exclusive()?;
+        let error = format!(
+            "{:#}",
+            rotate_cipher_key(None).expect_err("worker is running")
+        );
+        assert!(error.contains("worker is running"), "{error}");
+        drop(worker);
+        let api = hold_api_lock()?;
+        let error = format!("{:#}", rotate_cipher_key(None).expect_err("api is running"));
+        assert!(error.contains("`remem api` is running"), "{error}");
+        drop(api);
+
+        assert!(!test_dir.path.join(JOURNAL_FILE).exists());
+        assert!(!test_dir.path.join(NEXT_KEY_FILE).exists());
+        assert_store_uses(&test_dir, &old_key)?;
+        rotate_cipher_key(None)?;
+        Ok(())
+    }
+
+    #[test]
+    fn unparsable_config_falls_back_to_the_key_file() -> Result<()> {
+        let test_dir = ScopedTestDataDir::new("key-rotation-bad-config");
+        let key = CipherKey::Raw("a".repeat(64));
+        seed_encrypted_store(&test_dir, &key)?;
+        std::fs::write(
+            test_dir.path.join("config.toml"),
+            "[encryption\nkey_command = ",
+        )?;
+
+        assert_eq!(super::super::load_cipher_key()?, Some(key));
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use anyhow::{Context, Result};
 use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
@@ -8,6 +10,10 @@ use super::CipherKey;
 const SPILL_PROTECTION: &str = "remem-spill-v1";
 const SPILL_AAD: &[u8] = b"remem-spill-v1";
 const NONCE_LEN: usize = 12;
+/// Spill queues whose lines go through [`encode_json_line`]. Key rotation
+/// re-seals every file in each family: active, replay claims, failed and
+/// dead-letter.
+const SPILL_QUEUE_STEMS: [&str; 2] = ["capture-spill", "summary-hook-spill"];
 
 #[derive(Debug, Serialize, Deserialize)]
 struct SpillEnvelope {
@@ -69,15 +75,19 @@ fn protect_bytes(plaintext: &[u8], key: &CipherKey) -> Result<SpillEnvelope> {
 }
 
 fn open_envelope(envelope: &SpillEnvelope) -> Result<Vec<u8>> {
+    let key =
+        super::load_cipher_key()?.context("encrypted spill payload requires SQLCipher key")?;
+    open_envelope_with(envelope, &key)
+}
+
+fn open_envelope_with(envelope: &SpillEnvelope, key: &CipherKey) -> Result<Vec<u8>> {
     if envelope.version != 1 || envelope.protected != SPILL_PROTECTION {
         anyhow::bail!("unsupported spill protection envelope");
     }
-    let key =
-        super::load_cipher_key()?.context("encrypted spill payload requires SQLCipher key")?;
     let nonce = fixed_hex::<NONCE_LEN>(&envelope.nonce_hex).context("decode spill nonce")?;
     let mut ciphertext = hex_decode(&envelope.ciphertext_hex).context("decode spill ciphertext")?;
     let key = LessSafeKey::new(
-        UnboundKey::new(&AES_256_GCM, &spill_key_bytes(&key)?)
+        UnboundKey::new(&AES_256_GCM, &spill_key_bytes(key)?)
             .map_err(|_| anyhow::anyhow!("initialize spill decryption key"))?,
     );
     let plaintext = key
@@ -90,6 +100,108 @@ fn open_envelope(envelope: &SpillEnvelope) -> Result<Vec<u8>> {
     Ok(plaintext.to_vec())
 }
 
+#[derive(Debug, Default)]
+pub(crate) struct SpillRekeyReport {
+    pub(crate) files: usize,
+    pub(crate) lines: usize,
+    pub(crate) unreadable_lines: usize,
+}
+
+/// Re-seal protected spill lines from `old_key` to `new_key` under each
+/// queue's lock. Lines already sealed with `new_key` and plaintext lines are
+/// kept as-is, so a resumed rotation converges. Lines neither key opens are
+/// kept and counted.
+pub(crate) fn rekey_spill_files(
+    data_dir: &Path,
+    old_key: &CipherKey,
+    new_key: &CipherKey,
+) -> Result<SpillRekeyReport> {
+    let mut report = SpillRekeyReport::default();
+    let entries = match std::fs::read_dir(data_dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(report),
+        Err(error) => return Err(error).with_context(|| format!("read {}", data_dir.display())),
+    };
+    let mut files = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let Some(name) = entry.file_name().to_str().map(str::to_string) else {
+            continue;
+        };
+        let Some(stem) = SPILL_QUEUE_STEMS
+            .iter()
+            .find(|stem| name.starts_with(&format!("{stem}.")))
+        else {
+            continue;
+        };
+        if entry.file_type()?.is_file() && name.ends_with(".jsonl") {
+            let queue = if name.ends_with(".dead.jsonl") {
+                format!("{stem}.dead.jsonl")
+            } else {
+                format!("{stem}.jsonl")
+            };
+            files.push((data_dir.join(queue), entry.path()));
+        }
+    }
+    files.sort();
+    for (queue, path) in files {
+        let queue = crate::spill_queue::SpillQueue::new(queue)?;
+        if queue.with_lock(|| rekey_spill_file(&path, old_key, new_key, &mut report))? {
+            report.files += 1;
+        }
+    }
+    Ok(report)
+}
+
+fn rekey_spill_file(
+    path: &Path,
+    old_key: &CipherKey,
+    new_key: &CipherKey,
+    report: &mut SpillRekeyReport,
+) -> Result<bool> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(error) => return Err(error).with_context(|| format!("read {}", path.display())),
+    };
+    let mut rewritten = String::with_capacity(text.len());
+    let mut changed = false;
+    for line in text.split_inclusive('\n') {
+        let (body, newline) = match line.strip_suffix('\n') {
+            Some(body) => (body, "\n"),
+            None => (line, ""),
+        };
+        let Ok(envelope) = serde_json::from_str::<SpillEnvelope>(body) else {
+            rewritten.push_str(line);
+            continue;
+        };
+        if envelope.protected != SPILL_PROTECTION || open_envelope_with(&envelope, new_key).is_ok()
+        {
+            rewritten.push_str(line);
+            continue;
+        }
+        match open_envelope_with(&envelope, old_key) {
+            Ok(plaintext) => {
+                rewritten.push_str(&serde_json::to_string(&protect_bytes(
+                    &plaintext, new_key,
+                )?)?);
+                rewritten.push_str(newline);
+                report.lines += 1;
+                changed = true;
+            }
+            Err(_) => {
+                rewritten.push_str(line);
+                report.unreadable_lines += 1;
+            }
+        }
+    }
+    if changed {
+        crate::atomic_file::write_atomic(path, rewritten)
+            .with_context(|| format!("rewrite re-keyed spill file {}", path.display()))?;
+    }
+    Ok(changed)
+}
+
 fn spill_key_bytes(key: &CipherKey) -> Result<[u8; 32]> {
     match key {
         CipherKey::Raw(hex) => fixed_hex::<32>(hex),
@@ -175,6 +287,66 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn rekey_spill_files_reseals_every_queue_file_under_the_new_key() -> Result<()> {
+        let test_dir = ScopedTestDataDir::new("spill-crypto-rekey");
+        std::fs::create_dir_all(&test_dir.path)?;
+        let old_key = CipherKey::Raw("1".repeat(64));
+        let new_key = CipherKey::Raw("2".repeat(64));
+        let stranger = CipherKey::Raw("3".repeat(64));
+        let seal = |message: &str, key: &CipherKey| -> Result<String> {
+            let payload = serde_json::to_vec(&serde_json::json!({ "message": message }))?;
+            Ok(serde_json::to_string(&protect_bytes(&payload, key)?)?)
+        };
+        let active = test_dir.path.join("capture-spill.jsonl");
+        let plaintext = r#"{"message":"legacy plaintext"}"#;
+        std::fs::write(
+            &active,
+            format!(
+                "{}\n{}\n{plaintext}\n{}\n",
+                seal("old line", &old_key)?,
+                seal("already new", &new_key)?,
+                seal("foreign", &stranger)?
+            ),
+        )?;
+        let dead = test_dir.path.join("summary-hook-spill.dead.jsonl");
+        std::fs::write(&dead, seal("dead letter", &old_key)?)?;
+        let unrelated = test_dir.path.join("other.jsonl");
+        std::fs::write(&unrelated, seal("not a spill queue", &old_key)?)?;
+
+        let report = rekey_spill_files(&test_dir.path, &old_key, &new_key)?;
+        assert_eq!(
+            (report.files, report.lines, report.unreadable_lines),
+            (2, 2, 1)
+        );
+
+        let opened = |line: &str, key: &CipherKey| -> Option<serde_json::Value> {
+            let envelope: SpillEnvelope = serde_json::from_str(line).ok()?;
+            serde_json::from_slice(&open_envelope_with(&envelope, key).ok()?).ok()
+        };
+        let rewritten = std::fs::read_to_string(&active)?;
+        let lines: Vec<&str> = rewritten.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(opened(lines[0], &new_key).unwrap()["message"], "old line");
+        assert_eq!(
+            opened(lines[1], &new_key).unwrap()["message"],
+            "already new"
+        );
+        assert_eq!(lines[2], plaintext);
+        assert!(opened(lines[3], &stranger).is_some());
+        let dead_line = std::fs::read_to_string(&dead)?;
+        assert!(!dead_line.ends_with('\n'));
+        assert_eq!(
+            opened(&dead_line, &new_key).unwrap()["message"],
+            "dead letter"
+        );
+        assert!(opened(&std::fs::read_to_string(&unrelated)?, &old_key).is_some());
+
+        let again = rekey_spill_files(&test_dir.path, &old_key, &new_key)?;
+        assert_eq!((again.files, again.lines), (0, 0));
+        Ok(())
+    }
+
     #[test]
     fn no_key_without_plaintext_override_refuses_plaintext_spill() {
         let _test_dir = ScopedTestDataDir::new("spill-crypto-no-plaintext");
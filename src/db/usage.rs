@@ -8,6 +8,7 @@ pub(crate) fn record_ai_usage(
     conn: &Connection,
     project: Option<&str>,
     session_id: Option<&str>,
+    ai_profile: Option<&str>,
     operation: &str,
     executor: &str,
     model: Option<&str>,
@@ -25,8 +26,8 @@ pub(crate) fn record_ai_usage(
          (created_at, created_at_epoch, project, session_id, operation, executor, model, \
           input_tokens, output_tokens, reasoning_tokens, cache_creation_tokens, \
           cache_read_tokens, raw_input_tokens, raw_output_tokens, total_tokens, \
-          estimated_cost_usd, usage_source, pricing_source) \
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)",
+          estimated_cost_usd, usage_source, pricing_source, ai_profile) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19)",
         params![
             created_at,
             created_at_epoch,
@@ -45,12 +46,32 @@ pub(crate) fn record_ai_usage(
             usage.total_tokens(),
             estimated_cost_usd,
             usage_source,
-            pricing_source
+            pricing_source,
+            ai_profile
         ],
     )?;
     Ok(())
 }
 
+/// Estimated spend since `since_epoch`, optionally narrowed to one project
+/// and/or one memory AI profile. Feeds spend budget admission.
+pub(crate) fn query_ai_spend_since(
+    conn: &Connection,
+    project: Option<&str>,
+    ai_profile: Option<&str>,
+    since_epoch: i64,
+) -> Result<f64> {
+    let spent = conn.query_row(
+        "SELECT COALESCE(SUM(estimated_cost_usd), 0.0) FROM ai_usage_events \
+         WHERE created_at_epoch >= ?1 \
+           AND (?2 IS NULL OR project = ?2) \
+           AND (?3 IS NULL OR ai_profile = ?3)",
+        params![since_epoch, project, ai_profile],
+        |row| row.get(0),
+    )?;
+    Ok(spent)
+}
+
 #[cfg(test)]
 mod tests {
     use rusqlite::Connection;
@@ -58,7 +79,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn record_ai_usage_persists_session_id() -> Result<()> {
+    fn record_ai_usage_persists_session_id_and_profile() -> Result<()> {
         let conn = Connection::open_in_memory()?;
         crate::migrate::run_migrations(&conn)?;
         let usage = TokenUsage::estimated(100, 25);
@@ -67,6 +88,7 @@ mod tests {
             &conn,
             Some("/repo"),
             Some("sess-status-spend"),
+            Some("codex-default"),
             "summarize",
             "codex-cli",
             Some("codex-default"),
@@ -76,12 +98,19 @@ mod tests {
             0.001,
         )?;
 
-        let stored: (Option<String>, i64) = conn.query_row(
-            "SELECT session_id, total_tokens FROM ai_usage_events",
+        let stored: (Option<String>, Option<String>, i64) = conn.query_row(
+            "SELECT session_id, ai_profile, total_tokens FROM ai_usage_events",
             [],
-            |row| Ok((row.get(0)?, row.get(1)?)),
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
         )?;
-        assert_eq!(stored, (Some("sess-status-spend".to_string()), 125));
+        assert_eq!(
+            stored,
+            (
+                Some("sess-status-spend".to_string()),
+                Some("codex-default".to_string()),
+                125
+            )
+        );
         Ok(())
     }
 }
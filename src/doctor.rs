@@ -1,3 +1,4 @@
+mod ai_budgets;
 mod capture_capability;
 mod capture_liveness;
 mod cleanup;
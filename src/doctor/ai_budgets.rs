@@ -0,0 +1,80 @@
+use rusqlite::Connection;
+
+use super::types::{Check, Status};
+use crate::ai::budget::{budget_statuses, BudgetStatus};
+use crate::runtime_config::AiBudgetConfig;
+
+const CHECK_NAME: &str = "AI spend budgets";
+
+pub(super) fn check_ai_budgets(conn: Option<&Connection>) -> Check {
+    let config = match crate::runtime_config::ai_budget_config() {
+        Ok(config) => config,
+        Err(err) => {
+            return Check::new(
+                CHECK_NAME,
+                Status::Warn,
+                format!("invalid [budgets] config: {err}"),
+            )
+        }
+    };
+    budget_check(conn, &config, chrono::Utc::now())
+}
+
+pub(super) fn budget_check(
+    conn: Option<&Connection>,
+    config: &AiBudgetConfig,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Check {
+    if config.is_empty() {
+        return Check::new(CHECK_NAME, Status::Ok, "no budgets configured");
+    }
+    let Some(conn) = conn else {
+        return Check::new(CHECK_NAME, Status::Warn, "cannot open database");
+    };
+    let statuses = match budget_statuses(conn, config, now) {
+        Ok(statuses) => statuses,
+        Err(err) => {
+            return Check::new(
+                CHECK_NAME,
+                Status::Warn,
+                format!("cannot load AI spend: {err}"),
+            )
+        }
+    };
+    let pressed = statuses
+        .iter()
+        .filter(|status| status.used_ratio() >= config.warn_ratio)
+        .map(describe)
+        .collect::<Vec<_>>();
+    if pressed.is_empty() {
+        return Check::new(
+            CHECK_NAME,
+            Status::Ok,
+            format!(
+                "{} budget(s) below {:.0}% of their limit",
+                statuses.len(),
+                config.warn_ratio * 100.0
+            ),
+        );
+    }
+    Check::new(
+        CHECK_NAME,
+        Status::Warn,
+        format!("{}; see `remem usage`", pressed.join("; ")),
+    )
+}
+
+fn describe(status: &BudgetStatus) -> String {
+    let state = if status.is_exhausted() {
+        "exhausted, background AI work deferred"
+    } else {
+        "near limit"
+    };
+    format!(
+        "{} at {:.0}% (${:.2} of ${:.2}, {state})",
+        status.label(),
+        status.used_ratio() * 100.0,
+        status.spent_usd,
+        status.limit_usd
+    )
+}
@@ -15,6 +15,9 @@ fn active_capture_hosts() -> Vec<&'static str> {
     if home.join(".codex").exists() {
         hosts.push("codex");
     }
+    if home.join(".gemini").exists() {
+        hosts.push("gemini");
+    }
     hosts
 }
 
@@ -41,6 +44,11 @@ fn capture_capability(host: &'static str) -> Check {
             Status::Ok,
             "capture=drain-only; SessionStart context and Stop transcript drain; PostToolUse observe is intentionally unsupported",
         ),
+        "gemini" => Check::new(
+            "Capture capability (gemini)",
+            Status::Ok,
+            "capture=full; SessionStart context, AfterTool observe plus AfterAgent/PreCompress transcript drain",
+        ),
         _ => Check::new(
             "Capture capability",
             Status::Warn,
@@ -55,15 +63,18 @@ mod tests {
 
     #[test]
     fn labels_supported_host_capture_capabilities() {
-        let checks = check_capture_capabilities_for(&["claude", "codex"]);
+        let checks = check_capture_capabilities_for(&["claude", "codex", "gemini"]);
 
-        assert_eq!(checks.len(), 2);
+        assert_eq!(checks.len(), 3);
         assert_eq!(checks[0].name, "Capture capability (claude)");
         assert!(matches!(checks[0].status, Status::Ok));
         assert!(checks[0].detail.contains("capture=full"));
         assert_eq!(checks[1].name, "Capture capability (codex)");
         assert!(matches!(checks[1].status, Status::Ok));
         assert!(checks[1].detail.contains("capture=drain-only"));
+        assert_eq!(checks[2].name, "Capture capability (gemini)");
+        assert!(matches!(checks[2].status, Status::Ok));
+        assert!(checks[2].detail.contains("AfterTool observe"));
     }
 
     #[test]
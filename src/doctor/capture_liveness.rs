@@ -20,7 +20,7 @@ pub(super) fn check_capture_liveness(conn: Option<&Connection>, setup_checks: &[
         .filter(|finding| matches!(finding.status, Status::Fail))
         .map(|finding| finding.detail.clone())
         .collect();
-    let warnings: Vec<String> = setup_findings
+    let mut warnings: Vec<String> = setup_findings
         .iter()
         .filter(|finding| matches!(finding.status, Status::Warn))
         .map(|finding| finding.detail.clone())
@@ -204,6 +204,16 @@ pub(super) fn check_capture_liveness(conn: Option<&Connection>, setup_checks: &[
     if !failures.is_empty() {
         return Check::new("Capture liveness", Status::Fail, failures.join("; "));
     }
+    if cursor_hooks_registered(setup_checks) {
+        match latest_host_capture_epoch(conn, crate::cursor_hook::CURSOR_HOST) {
+            Ok(Some(_)) => {}
+            Ok(None) => warnings.push(
+                "Cursor hooks are registered but no cursor capture event has arrived yet; run one Cursor agent session (restart Cursor after install) and re-run doctor"
+                    .to_string(),
+            ),
+            Err(err) => warnings.push(format!("cannot load cursor capture heartbeat: {err}")),
+        }
+    }
     if stats.latest_capture_activity_epoch.is_none() {
         return Check::new(
             "Capture liveness",
@@ -276,6 +286,9 @@ fn capture_setup_findings(checks: &[Check]) -> Vec<SetupFinding> {
             if check.name == "Install paths" {
                 return install_path_setup_finding(check);
             }
+            if check.name == "Cursor install" {
+                return cursor_hook_setup_finding(check);
+            }
             None
         })
         .collect()
@@ -299,6 +312,43 @@ fn hook_warning_blocks_capture(detail: &str) -> bool {
     detail.contains(" registered (run `remem install --target")
 }
 
+/// Cursor install problems only block capture when the managed hook entries
+/// themselves are missing; MCP or capability findings stay in their own check.
+fn cursor_hook_setup_finding(check: &Check) -> Option<SetupFinding> {
+    let missing = check
+        .detail
+        .contains("managed hook entries are missing from hooks.json");
+    (matches!(check.status, Status::Fail) && missing).then(|| SetupFinding {
+        status: Status::Fail,
+        detail: format!("Cursor hooks incomplete: {}", check.detail),
+    })
+}
+
+/// True when the Cursor install check reports every receipt hook present.
+fn cursor_hooks_registered(checks: &[Check]) -> bool {
+    checks
+        .iter()
+        .filter(|check| check.name == "Cursor install")
+        .flat_map(|check| check.detail.lines())
+        .filter_map(|line| line.strip_prefix("hooks_registered="))
+        .any(|counts| {
+            counts
+                .split_once('/')
+                .is_some_and(|(registered, expected)| registered == expected && expected != "0")
+        })
+}
+
+fn latest_host_capture_epoch(conn: &Connection, host: &str) -> Result<Option<i64>> {
+    Ok(conn.query_row(
+        "SELECT MAX(ce.inserted_at_epoch)
+         FROM captured_events ce
+         JOIN hosts h ON h.id = ce.host_id
+         WHERE h.name = ?1",
+        [host],
+        |row| row.get::<_, Option<i64>>(0),
+    )?)
+}
+
 fn install_path_setup_finding(check: &Check) -> Option<SetupFinding> {
     match check.status {
         Status::Fail => Some(SetupFinding {
@@ -770,4 +820,53 @@ mod tests {
         assert!(check.detail.contains("no capture heartbeat yet"));
         Ok(())
     }
+
+    #[test]
+    fn capture_liveness_warns_until_registered_cursor_hooks_deliver() -> anyhow::Result<()> {
+        let conn = setup_liveness_conn()?;
+        record_liveness_capture(&conn)?;
+        let setup = vec![Check::new(
+            "Cursor install",
+            Status::Ok,
+            "detected=true configured=true configured_mode=full\nhooks_registered=3/3",
+        )];
+
+        let check = check_capture_liveness(Some(&conn), &setup);
+        assert!(matches!(check.status, Status::Warn), "{}", check.detail);
+        assert!(check
+            .detail
+            .contains("no cursor capture event has arrived yet"));
+
+        crate::db::record_captured_event(
+            &conn,
+            &crate::db::CaptureEventInput {
+                host: crate::cursor_hook::CURSOR_HOST,
+                session_id: "sess-cursor-doctor",
+                project: "/tmp/remem-doctor",
+                cwd: Some("/tmp/remem-doctor"),
+                event_type: "tool_result",
+                role: None,
+                tool_name: Some("Read"),
+                content: "captured event",
+                task_kind: None,
+            },
+        )?;
+        let check = check_capture_liveness(Some(&conn), &setup);
+        assert!(matches!(check.status, Status::Ok), "{}", check.detail);
+        Ok(())
+    }
+
+    #[test]
+    fn capture_liveness_fails_on_missing_cursor_hook_entries() {
+        let setup = vec![Check::new(
+            "Cursor install",
+            Status::Fail,
+            "hooks_registered=2/3; managed hook entries are missing from hooks.json, so Cursor capture is off (run `remem install --target cursor --repair`)",
+        )];
+
+        let check = check_capture_liveness(None, &setup);
+
+        assert!(matches!(check.status, Status::Fail));
+        assert!(check.detail.contains("Cursor hooks incomplete"));
+    }
 }
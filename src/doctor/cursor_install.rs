@@ -5,19 +5,24 @@
 //! `configured_mode`, `malformed`, `partial_state`, `drift`, `collision`,
 //! per-capability `effective`, `hook_failure_policy`, and the fixed
 //! `session-init: not supported on cursor` capability line.
+//!
+//! Hook registration is verified against the receipt: every receipt-recorded
+//! hook component must be present verbatim in `hooks.json`
+//! (`hooks_registered=N/M`). Whether events then actually arrive is the
+//! capture-liveness check's job.
 
 use serde_json::Value;
 use std::path::Path;
 
 use super::types::{Check, Status};
 use crate::install::cursor_config::plan::{
-    classify_remem_mcp, managed_cursor_mcp_entry, parse_receipt_from_config, CursorInstallReceipt,
-    McpOwnership,
+    classify_remem_mcp, managed_cursor_mcp_entry, managed_hook_entry, parse_receipt_from_config,
+    CursorInstallReceipt, McpOwnership,
 };
 use crate::install::cursor_config::schema::{validate_hooks_document, validate_mcp_document};
 use crate::install::cursor_config::{
     cursor_detected, cursor_hooks_path, cursor_mcp_path, CURSOR_HOOK_FAILURE_POLICY_LINE,
-    CURSOR_SESSION_INIT_LINE,
+    CURSOR_INSTALL_CONTRACT_VERSION, CURSOR_SESSION_CONTEXT_LINE, CURSOR_SESSION_INIT_LINE,
 };
 
 /// Per-capability `effective` baseline from the adopted GH-822 / PR #914
@@ -57,6 +62,10 @@ struct CursorDoctorState {
     partial_state: Option<&'static str>,
     drift: bool,
     collision: bool,
+    /// `(registered, expected)` receipt hook components found in hooks.json.
+    hooks_registered: Option<(usize, usize)>,
+    /// Receipt predates the contract that registers hook entries.
+    stale_contract: Option<i64>,
 }
 
 pub(super) fn check_cursor_install() -> Check {
@@ -73,6 +82,8 @@ fn classify(hooks_path: &Path, mcp_path: &Path) -> CursorDoctorState {
         partial_state: None,
         drift: false,
         collision: false,
+        hooks_registered: None,
+        stale_contract: None,
     };
     if !state.detected {
         return state;
@@ -124,6 +135,14 @@ fn classify(hooks_path: &Path, mcp_path: &Path) -> CursorDoctorState {
         .and_then(|servers| servers.get("remem"));
     let mcp_present = remem_entry.is_some();
 
+    if let Some(receipt) = &receipt {
+        if receipt.contract_version < CURSOR_INSTALL_CONTRACT_VERSION {
+            state.stale_contract = Some(receipt.contract_version);
+        } else {
+            state.hooks_registered = Some(registered_hook_components(hooks_doc.as_ref(), receipt));
+        }
+    }
+
     match &receipt {
         None => {
             if mcp_present {
@@ -190,10 +209,45 @@ fn classify(hooks_path: &Path, mcp_path: &Path) -> CursorDoctorState {
     state
 }
 
+/// Counts receipt hook components whose exact managed entry is present on
+/// the recorded event.
+fn registered_hook_components(
+    hooks_doc: Option<&Value>,
+    receipt: &CursorInstallReceipt,
+) -> (usize, usize) {
+    let components: Vec<_> = receipt
+        .components
+        .iter()
+        .filter(|component| component.kind == "hook")
+        .collect();
+    let registered = components
+        .iter()
+        .filter(|component| {
+            hooks_doc
+                .and_then(|doc| doc.get("hooks"))
+                .and_then(|hooks| hooks.get(&component.key))
+                .and_then(Value::as_array)
+                .is_some_and(|entries| entries.contains(&managed_hook_entry(component)))
+        })
+        .count();
+    (registered, components.len())
+}
+
+fn hooks_missing(state: &CursorDoctorState) -> bool {
+    state
+        .hooks_registered
+        .is_some_and(|(registered, expected)| registered < expected)
+}
+
 fn render(state: CursorDoctorState) -> Check {
-    let status = if state.malformed.is_some() || state.collision || state.partial_state.is_some() {
+    let status = if state.malformed.is_some()
+        || state.collision
+        || state.partial_state.is_some()
+        || hooks_missing(&state)
+    {
         Status::Fail
-    } else if state.drift || (state.detected && !state.configured) {
+    } else if state.drift || state.stale_contract.is_some() || (state.detected && !state.configured)
+    {
         Status::Warn
     } else {
         Status::Ok
@@ -225,10 +279,23 @@ fn render(state: CursorDoctorState) -> Check {
             "\ndrift: managed entry differs from the current builder shape; rerun `remem install --target cursor`",
         );
     }
+    if let Some((registered, expected)) = state.hooks_registered {
+        detail.push_str(&format!("\nhooks_registered={registered}/{expected}"));
+        if registered < expected {
+            detail.push_str(
+                "; managed hook entries are missing from hooks.json, so Cursor capture is off (run `remem install --target cursor --repair`)",
+            );
+        }
+    }
+    if let Some(version) = state.stale_contract {
+        detail.push_str(&format!(
+            "\nhooks: not registered (install contract v{version}); rerun `remem install --target cursor` to enable automatic capture"
+        ));
+    }
     detail.push_str(&format!("\n{CURSOR_HOOK_FAILURE_POLICY_LINE}"));
     detail.push_str(&format!("\n{EFFECTIVE_CAPABILITY_LINES}"));
     detail.push_str(&format!(
-        "\nsession_init: unsupported\n{CURSOR_SESSION_INIT_LINE}"
+        "\nsession_init: unsupported\n{CURSOR_SESSION_INIT_LINE}\n{CURSOR_SESSION_CONTEXT_LINE}"
     ));
 
     Check::new("Cursor install", status, detail)
@@ -4,7 +4,7 @@ use serde_json::json;
 use super::super::types::Status;
 use super::check_cursor_install;
 use crate::install::cursor_config::plan::build_receipt;
-use crate::install::cursor_config::plan::managed_cursor_mcp_entry;
+use crate::install::cursor_config::plan::{managed_cursor_mcp_entry, managed_hook_entry};
 
 struct DoctorTestEnv {
     _guard: crate::runtime_config::TestEnvGuard,
@@ -42,7 +42,22 @@ impl DoctorTestEnv {
     }
 
     fn write_receipt(&self, bin: &str, hooks_only: bool) -> Result<()> {
-        let receipt = build_receipt(bin, hooks_only);
+        self.write_receipt_with_contract(bin, hooks_only, None)
+    }
+
+    fn write_receipt_with_contract(
+        &self,
+        bin: &str,
+        hooks_only: bool,
+        contract_version: Option<i64>,
+    ) -> Result<()> {
+        let mut receipt = build_receipt(bin, hooks_only);
+        if let Some(version) = contract_version {
+            receipt.contract_version = version;
+            receipt
+                .components
+                .retain(|component| component.kind != "hook");
+        }
         let receipt_json = serde_json::to_string(&receipt)?;
         std::fs::write(
             self.home.join("config.toml"),
@@ -54,6 +69,26 @@ impl DoctorTestEnv {
         Ok(())
     }
 
+    /// Writes hooks.json with the managed entries of `bin`'s receipt,
+    /// skipping the listed events.
+    fn write_managed_hooks(&self, bin: &str, skip_events: &[&str]) -> Result<()> {
+        let mut hooks = serde_json::Map::new();
+        for component in build_receipt(bin, true).components {
+            if skip_events.contains(&component.key.as_str()) {
+                continue;
+            }
+            hooks.insert(
+                component.key.clone(),
+                json!([managed_hook_entry(&component)]),
+            );
+        }
+        std::fs::write(
+            self.home.join(".cursor").join("hooks.json"),
+            serde_json::to_string_pretty(&json!({ "version": 1, "hooks": hooks }))?,
+        )?;
+        Ok(())
+    }
+
     fn write_mcp(&self, doc: &serde_json::Value) -> Result<()> {
         std::fs::write(
             self.home.join(".cursor").join("mcp.json"),
@@ -99,6 +134,10 @@ fn assert_mandatory_capability_lines(detail: &str) {
         "{detail}"
     );
     assert!(detail.contains("sessionStart: blocked"), "{detail}");
+    assert!(
+        detail.contains("session_context: rules_file .cursor/rules/remem.mdc"),
+        "{detail}"
+    );
     assert!(detail.contains("stop: unknown"), "{detail}");
     assert!(detail.contains("preCompact: unknown"), "{detail}");
 }
@@ -130,6 +169,7 @@ fn detected_but_unconfigured_warns_not_fails() -> Result<()> {
 fn full_install_with_matching_receipt_is_configured() -> Result<()> {
     let env = DoctorTestEnv::new("full", true)?;
     env.write_mcp(&json!({ "mcpServers": { "remem": managed_cursor_mcp_entry(BIN) } }))?;
+    env.write_managed_hooks(BIN, &[])?;
     env.write_receipt(BIN, false)?;
     let check = check_cursor_install();
     assert_eq!(check.status, Status::Ok, "{}", check.detail);
@@ -140,6 +180,11 @@ fn full_install_with_matching_receipt_is_configured() -> Result<()> {
         "{}",
         check.detail
     );
+    assert!(
+        check.detail.contains("hooks_registered=3/3"),
+        "{}",
+        check.detail
+    );
     assert_mandatory_capability_lines(&check.detail);
     Ok(())
 }
@@ -147,6 +192,7 @@ fn full_install_with_matching_receipt_is_configured() -> Result<()> {
 #[test]
 fn intentional_hooks_only_is_not_partial_state() -> Result<()> {
     let env = DoctorTestEnv::new("hooks-only", true)?;
+    env.write_managed_hooks(BIN, &[])?;
     env.write_receipt(BIN, true)?;
     let check = check_cursor_install();
     assert_eq!(check.status, Status::Ok, "{}", check.detail);
@@ -201,3 +247,45 @@ fn foreign_entry_on_managed_key_reports_collision() -> Result<()> {
     assert_mandatory_capability_lines(&check.detail);
     Ok(())
 }
+
+#[test]
+fn missing_managed_hook_entry_fails_with_repair_hint() -> Result<()> {
+    let env = DoctorTestEnv::new("hooks-missing", true)?;
+    env.write_mcp(&json!({ "mcpServers": { "remem": managed_cursor_mcp_entry(BIN) } }))?;
+    env.write_managed_hooks(BIN, &["stop"])?;
+    env.write_receipt(BIN, false)?;
+    let check = check_cursor_install();
+    assert_eq!(check.status, Status::Fail, "{}", check.detail);
+    assert!(
+        check.detail.contains("hooks_registered=2/3"),
+        "{}",
+        check.detail
+    );
+    assert!(
+        check
+            .detail
+            .contains("remem install --target cursor --repair"),
+        "{}",
+        check.detail
+    );
+    assert_mandatory_capability_lines(&check.detail);
+    Ok(())
+}
+
+#[test]
+fn contract_v1_receipt_warns_that_hooks_are_not_registered() -> Result<()> {
+    let env = DoctorTestEnv::new("contract-v1", true)?;
+    env.write_mcp(&json!({ "mcpServers": { "remem": managed_cursor_mcp_entry(BIN) } }))?;
+    env.write_receipt_with_contract(BIN, false, Some(1))?;
+    let check = check_cursor_install();
+    assert_eq!(check.status, Status::Warn, "{}", check.detail);
+    assert!(
+        check
+            .detail
+            .contains("hooks: not registered (install contract v1)"),
+        "{}",
+        check.detail
+    );
+    assert_mandatory_capability_lines(&check.detail);
+    Ok(())
+}
@@ -390,7 +390,7 @@ fn probe_hooks_accepts_sibling_remem_hook_for_slim_commands() -> anyhow::Result<
       "hooks": [{{ "command": "{hook_s} session-init --host claude-code", "timeout": 15 }}]
     }}],
     "PreToolUse": [{{
-      "matcher": "Bash",
+      "matcher": "*",
       "hooks": [{{ "command": "{remem_s} rules eval --host claude-code", "timeout": 5 }}]
     }}],
     "PostToolUse": [{{
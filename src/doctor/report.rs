@@ -6,6 +6,7 @@ use std::{
 use anyhow::Result;
 use rusqlite::Connection;
 
+use super::ai_budgets::check_ai_budgets;
 use super::capture_capability::check_capture_capabilities;
 use super::capture_liveness::check_capture_liveness;
 use super::cleanup::check_cleanup_status;
@@ -28,7 +29,9 @@ use super::procedure_exports::check_procedure_exports;
 use super::reranker::check_reranker;
 use super::retrieval_enrichment::check_retrieval_enrichment;
 use super::review_queue::check_review_queue;
-use super::rule_enforcement::{check_compiled_rules, check_rule_enforcement_capabilities};
+use super::rule_enforcement::{
+    check_authored_rules, check_compiled_rules, check_rule_enforcement_capabilities,
+};
 use super::runtime_config_check::check_runtime_config;
 use super::schema::{check_key_format, check_schema_migration};
 use super::types::{Check, CheckJson, DoctorOutcome, ReportJson, Status, REPORT_SCHEMA_VERSION};
@@ -104,6 +107,7 @@ fn run_checks(mut on_check: impl FnMut(&Check) -> Result<()>) -> Result<Vec<Chec
     push_check(&mut checks, &mut on_check, || {
         check_compiled_rules(shared_db.conn())
     })?;
+    push_check(&mut checks, &mut on_check, check_authored_rules)?;
     push_checks(
         &mut checks,
         &mut on_check,
@@ -172,6 +176,9 @@ fn run_checks(mut on_check: impl FnMut(&Check) -> Result<()>) -> Result<Vec<Chec
     push_check(&mut checks, &mut on_check, || {
         check_cleanup_status(shared_db.conn())
     })?;
+    push_check(&mut checks, &mut on_check, || {
+        check_ai_budgets(shared_db.conn())
+    })?;
     push_check(&mut checks, &mut on_check, check_native_memory_sync)?;
     push_check(&mut checks, &mut on_check, check_codex_native_memories)?;
     push_check(&mut checks, &mut on_check, check_log_health)?;
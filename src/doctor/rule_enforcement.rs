@@ -39,6 +39,31 @@ pub(super) fn check_compiled_rules(conn: Option<&Connection>) -> Check {
     check_compiled_rules_for(conn, &data_dir, &project, config.enabled)
 }
 
+pub(super) fn check_authored_rules() -> Check {
+    let data_dir = match crate::db::absolute_data_dir() {
+        Ok(data_dir) => data_dir,
+        Err(error) => {
+            return Check::new(
+                "Authored rules",
+                Status::Fail,
+                format!("rule data directory is unavailable: {error}"),
+            )
+        }
+    };
+    let cwd = match std::env::current_dir() {
+        Ok(cwd) => cwd,
+        Err(error) => {
+            return Check::new(
+                "Authored rules",
+                Status::Fail,
+                format!("current project directory is unavailable: {error}"),
+            )
+        }
+    };
+    let project = crate::db::project_from_cwd(&cwd.to_string_lossy());
+    check_authored_rules_for(&data_dir, &project)
+}
+
 pub(super) fn check_rule_enforcement_capabilities() -> Vec<Check> {
     vec![
         Check::new(
@@ -145,6 +170,42 @@ fn check_compiled_rules_for(
     )
 }
 
+/// Unlike compiled artifacts, the authored file lives in the repository, so
+/// its validation errors are shown verbatim for the author to fix.
+fn check_authored_rules_for(data_dir: &Path, project: &str) -> Check {
+    let path = crate::rules::authored_rules_path(project);
+    let rules = match crate::rules::load_authored_rules(&path) {
+        Ok(Some(rules)) => rules,
+        Ok(None) => return Check::new("Authored rules", Status::Ok, "file=absent"),
+        Err(error) => {
+            return Check::new(
+                "Authored rules",
+                Status::Warn,
+                format!(
+                    "file={} valid=false error={error:#}; only compiled rules are enforced until it is fixed",
+                    path.display()
+                ),
+            )
+        }
+    };
+    let block_count = rules
+        .iter()
+        .filter(|rule| rule.action == crate::rules::RuleAction::Block)
+        .count();
+    let shadowed = crate::rules::load_project_rules_with_shadowed(data_dir, project)
+        .shadowed
+        .len();
+    Check::new(
+        "Authored rules",
+        Status::Ok,
+        format!(
+            "file={} valid=true rule_count={} block_count={block_count} shadowed_compiled={shadowed}",
+            path.display(),
+            rules.len()
+        ),
+    )
+}
+
 struct CompileDiagnosticSnapshot {
     latest_status: Option<String>,
     latest_epoch: Option<i64>,
@@ -188,6 +249,7 @@ fn artifact_error_label(kind: crate::rules::ArtifactLoadErrorKind) -> &'static s
         crate::rules::ArtifactLoadErrorKind::Read => "read",
         crate::rules::ArtifactLoadErrorKind::Parse => "parse",
         crate::rules::ArtifactLoadErrorKind::Validate => "validate",
+        crate::rules::ArtifactLoadErrorKind::AuthoredRules => "authored_rules",
     }
 }
 
@@ -213,7 +275,7 @@ mod tests {
     use crate::db::test_support::ScopedTestDataDir;
     use crate::rules::{
         write_artifact_atomic, CompiledRule, CompiledRulesArtifact, EvaluationDiagnosticCode,
-        RuleAction, RuleOverrideState, RulePredicate,
+        RuleAction, RuleOrigin, RuleOverrideState, RulePredicate,
     };
 
     #[test]
@@ -236,6 +298,7 @@ mod tests {
                     pattern: "TOP_SECRET_PATTERN".to_string(),
                     message: "TOP_SECRET_MESSAGE".to_string(),
                 },
+                origin: RuleOrigin::Compiled,
             }],
         );
         write_artifact_atomic(
@@ -316,6 +379,60 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn authored_rules_report_validation_errors_and_shadowed_rules() -> anyhow::Result<()> {
+        let dir = crate::rules::test_support::test_dir("doctor-authored-rules");
+        let project = dir.join("project");
+        let data_dir = dir.join("data");
+        let project_key = project.to_string_lossy().into_owned();
+
+        let absent = check_authored_rules_for(&data_dir, &project_key);
+        assert!(matches!(absent.status, Status::Ok));
+        assert_eq!(absent.detail, "file=absent");
+
+        std::fs::create_dir_all(project.join(".remem"))?;
+        std::fs::write(
+            project.join(".remem/rules.toml"),
+            "[[rule]]\nid = \"no-force\"\naction = \"block\"\nkind = \"git_push_force_forbidden\"\nmessage = \"Never force-push\"\n",
+        )?;
+        write_artifact_atomic(
+            crate::rules::artifact_path_for_project(&data_dir, &project_key),
+            &CompiledRulesArtifact::new(
+                10,
+                vec![CompiledRule {
+                    rule_id: "pref-4-1".to_string(),
+                    source_memory_id: 4,
+                    reinforcement_count: 3,
+                    action: RuleAction::Warn,
+                    override_state: RuleOverrideState {
+                        disabled: false,
+                        action_override: None,
+                    },
+                    predicate: RulePredicate::GitPushForceForbidden {
+                        message: "compiled".to_string(),
+                    },
+                    origin: RuleOrigin::Compiled,
+                }],
+            ),
+        )?;
+        let valid = check_authored_rules_for(&data_dir, &project_key);
+        assert!(matches!(valid.status, Status::Ok), "{}", valid.detail);
+        assert!(valid
+            .detail
+            .contains("valid=true rule_count=1 block_count=1 shadowed_compiled=1"));
+
+        std::fs::write(
+            project.join(".remem/rules.toml"),
+            "[[rule]]\nid = \"x\"\nkind = \"content_regex\"\npattern = \"(\"\nmessage = \"m\"\n",
+        )?;
+        let invalid = check_authored_rules_for(&data_dir, &project_key);
+        assert!(matches!(invalid.status, Status::Warn));
+        assert!(invalid.detail.contains("valid=false"), "{}", invalid.detail);
+        assert!(invalid.detail.contains("invalid content_regex pattern"));
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
     #[test]
     fn host_capabilities_are_explicit_and_honest() {
         let checks = check_rule_enforcement_capabilities();
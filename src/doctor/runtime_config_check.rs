@@ -31,6 +31,16 @@ pub(super) fn check_runtime_config() -> Check {
                     format!("{} invalid: {}", path.display(), error),
                 );
             }
+            if let Err(error) = crate::runtime_config::redaction_config() {
+                return Check::new(
+                    "Runtime config",
+                    Status::Fail,
+                    format!(
+                        "{} invalid: {error:#}; capture skips these redaction entries",
+                        path.display()
+                    ),
+                );
+            }
             Check::new(
                 "Runtime config",
                 Status::Ok,
@@ -80,4 +90,32 @@ mod tests {
             .contains("context.total_char_limit must be >= 0"));
         Ok(())
     }
+
+    #[test]
+    fn invalid_redaction_pattern_fails_runtime_config_check() -> anyhow::Result<()> {
+        let _guard = crate::runtime_config::TEST_ENV_LOCK.lock()?;
+        let path = std::env::temp_dir().join(format!(
+            "remem-doctor-redaction-{}-{}.toml",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        std::fs::write(&path, "[redaction.patterns]\nbroken = \"(unclosed\"\n")?;
+        let previous = std::env::var_os("REMEM_CONFIG");
+        unsafe { std::env::set_var("REMEM_CONFIG", &path) };
+
+        let check = check_runtime_config();
+
+        match previous {
+            Some(value) => unsafe { std::env::set_var("REMEM_CONFIG", value) },
+            None => unsafe { std::env::remove_var("REMEM_CONFIG") },
+        }
+        std::fs::remove_file(path)?;
+        assert_eq!(check.status, Status::Fail);
+        assert!(
+            check.detail.contains("redaction.patterns.broken"),
+            "{}",
+            check.detail
+        );
+        Ok(())
+    }
 }
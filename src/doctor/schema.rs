@@ -68,11 +68,19 @@ pub(super) fn check_schema_migration(conn: Option<&Connection>, open_error: Opti
 }
 
 pub(super) fn check_key_format() -> Check {
+    if let Some(hint) = db::key_rotation::interrupted_rotation_hint() {
+        return Check::new("Key format", Status::Warn, hint);
+    }
     if let Ok(env_key) = std::env::var("REMEM_CIPHER_KEY") {
         if !env_key.trim().is_empty() {
             return check_key_format_value("REMEM_CIPHER_KEY", &env_key, false);
         }
     }
+    match db::configured_key_command() {
+        Ok(Some(command)) => return check_key_command_format(&command),
+        Ok(None) => {}
+        Err(error) => return Check::new("Key format", Status::Fail, format!("{error:#}")),
+    }
 
     let key_path = match db::try_data_dir() {
         Ok(path) => path.join(".key"),
@@ -96,6 +104,24 @@ pub(super) fn check_key_format() -> Check {
     check_key_format_value(&source, &key_text, true)
 }
 
+fn check_key_command_format(command: &str) -> Check {
+    match db::run_key_command(command) {
+        Ok(db::CipherKey::Raw(_)) => Check::new(
+            "Key format",
+            Status::Ok,
+            format!("raw-key format (v2) from encryption.key_command `{command}`"),
+        ),
+        Ok(db::CipherKey::Passphrase(_)) => Check::new(
+            "Key format",
+            Status::Warn,
+            format!(
+                "legacy passphrase key format from encryption.key_command `{command}`; rotate to a v2:<64hex> key with `remem encrypt --rotate --new-key-command`"
+            ),
+        ),
+        Err(error) => Check::new("Key format", Status::Fail, format!("{error:#}")),
+    }
+}
+
 fn check_key_format_value(source: &str, key_text: &str, is_file: bool) -> Check {
     match db::parse_cipher_key(key_text) {
         Ok(Some(db::CipherKey::Raw(_))) => Check::new(
@@ -12,6 +12,7 @@ use super::database::{
 use super::report::{run_doctor_with_writer, DoctorOptions};
 use super::schema::{check_key_format, check_schema_migration};
 
+mod ai_budgets;
 mod health_action_tests;
 mod log_health;
 mod memory_usage_feedback;
@@ -155,6 +156,31 @@ fn check_key_format_reports_effective_env_key() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg(unix)]
+#[test]
+fn check_key_format_reports_key_command_source() -> anyhow::Result<()> {
+    let test_dir = ScopedTestDataDir::new("doctor-key-format-command");
+    let _env = ScopedCipherKeyEnv::remove();
+    std::fs::create_dir_all(&test_dir.path)?;
+    std::fs::write(test_dir.path.join(".key"), "3".repeat(64))?;
+    std::fs::write(
+        test_dir.path.join("config.toml"),
+        format!(
+            "[encryption]\nkey_command = \"printf v2:{}\"\n",
+            "5".repeat(64)
+        ),
+    )?;
+
+    let check = check_key_format();
+    assert_eq!(check.icon(), "ok");
+    assert!(
+        check.detail.contains("encryption.key_command"),
+        "{}",
+        check.detail
+    );
+    Ok(())
+}
+
 #[test]
 fn check_pending_queue_reports_shared_counts() -> anyhow::Result<()> {
     let _test_dir = ScopedTestDataDir::new("doctor-pending");
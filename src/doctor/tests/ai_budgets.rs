@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+
+use rusqlite::Connection;
+
+use crate::ai::TokenUsage;
+use crate::runtime_config::{AiBudgetConfig, SpendLimits};
+
+use super::super::ai_budgets::budget_check;
+
+fn config_with_profile_budget(monthly_usd: f64) -> AiBudgetConfig {
+    AiBudgetConfig {
+        warn_ratio: 0.8,
+        projects: BTreeMap::new(),
+        profiles: BTreeMap::from([(
+            "codex-default".to_string(),
+            SpendLimits {
+                daily_usd: None,
+                monthly_usd: Some(monthly_usd),
+            },
+        )]),
+    }
+}
+
+fn conn_with_spend(cost: f64) -> anyhow::Result<Connection> {
+    let conn = Connection::open_in_memory()?;
+    crate::migrate::run_migrations(&conn)?;
+    crate::db::record_ai_usage(
+        &conn,
+        Some("/repo"),
+        None,
+        Some("codex-default"),
+        "dream",
+        "codex-cli",
+        Some("gpt-5.2"),
+        &TokenUsage::estimated(100, 20),
+        "text_estimate",
+        "remem_static",
+        cost,
+    )?;
+    Ok(conn)
+}
+
+#[test]
+fn budget_check_is_ok_without_configured_budgets() {
+    let check = budget_check(None, &AiBudgetConfig::default(), chrono::Utc::now());
+    assert_eq!(check.icon(), "ok");
+    assert_eq!(check.detail, "no budgets configured");
+}
+
+#[test]
+fn budget_check_warns_near_and_over_limit() -> anyhow::Result<()> {
+    let conn = conn_with_spend(8.5)?;
+    let now = chrono::Utc::now();
+
+    let roomy = budget_check(Some(&conn), &config_with_profile_budget(100.0), now);
+    assert_eq!(roomy.icon(), "ok", "{}", roomy.detail);
+
+    let near = budget_check(Some(&conn), &config_with_profile_budget(10.0), now);
+    assert_eq!(near.icon(), "WARN");
+    assert!(
+        near.detail
+            .contains("monthly profile budget for codex-default at 85%"),
+        "{}",
+        near.detail
+    );
+    assert!(near.detail.contains("near limit"), "{}", near.detail);
+
+    let over = budget_check(Some(&conn), &config_with_profile_budget(5.0), now);
+    assert_eq!(over.icon(), "WARN");
+    assert!(
+        over.detail.contains("background AI work deferred"),
+        "{}",
+        over.detail
+    );
+    Ok(())
+}
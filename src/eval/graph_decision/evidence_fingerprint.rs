@@ -76,6 +76,15 @@ const IMPLEMENTATION_INPUTS: &[&str] = &[
     "src/migrate/schema_drift/invariants/v084.rs",
     "src/migrate/schema_drift/invariants/v084/shape.rs",
     "src/migrate/schema_drift/invariants/v085.rs",
+    "src/migrate/schema_drift/invariants/v086.rs",
+    "src/migrate/schema_drift/invariants/v087.rs",
+    "src/migrate/schema_drift/invariants/v088.rs",
+    "src/migrate/schema_drift/invariants/v089.rs",
+    "src/migrate/schema_drift/invariants/v090.rs",
+    "src/migrate/schema_drift/invariants/v091.rs",
+    "src/migrate/schema_drift/invariants/v092.rs",
+    "src/migrate/schema_drift/invariants/v093.rs",
+    "src/migrate/schema_drift/invariants/v094.rs",
     "src/migrate/state.rs",
     "src/migrate/transition.rs",
     "src/migrate/types.rs",
@@ -93,6 +102,9 @@ const IMPLEMENTATION_INPUTS: &[&str] = &[
     "src/retrieval/entity/search/lookup.rs",
     "src/retrieval/entity/search/runner.rs",
     "src/retrieval/entity/search/sql.rs",
+    "src/retrieval/glossary.rs",
+    "src/retrieval/glossary/file.rs",
+    "src/retrieval/glossary/store.rs",
     "src/retrieval/graph.rs",
     "src/retrieval/graph/query.rs",
     "src/retrieval/graph/traverse.rs",
@@ -244,6 +256,15 @@ const MIGRATION_SQL_INPUTS: &[&str] = &[
     "src/migrations/v083_retrieval_enrichment_budget.sql",
     "src/migrations/v084_session_observatory.sql",
     "src/migrations/v085_legacy_pending_bridge_state.sql",
+    "src/migrations/v086_sync_change_log.sql",
+    "src/migrations/v087_team_pack_sync.sql",
+    "src/migrations/v088_api_event_stream.sql",
+    "src/migrations/v089_api_tokens.sql",
+    "src/migrations/v090_workstream_plans.sql",
+    "src/migrations/v091_ai_usage_profile.sql",
+    "src/migrations/v092_memory_anchors.sql",
+    "src/migrations/v093_redaction_audit.sql",
+    "src/migrations/v094_project_glossary.sql",
 ];
 
 #[derive(Debug, Clone, Serialize)]
@@ -8,8 +8,14 @@ use serde::Serialize;
 use super::golden::{self, CategoryEvaluation, GoldenDataset, MetricAverages};
 use crate::retrieval::search::SearchWeights;
 
+mod usage_fit;
 mod usage_shadow;
 
+pub use usage_fit::{
+    fit_project_weights, WeightFitDecision, WeightFitOptions, WeightFitReport,
+    DEFAULT_MIN_FEEDBACK_QUERIES,
+};
+
 pub const DEFAULT_DATASET_PATH: &str = "eval/golden.json";
 pub const DEFAULT_REPORT_PATH: &str = "eval/weight-grid/report.json";
 const EPSILON: f64 = 0.000_001;
@@ -0,0 +1,268 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use anyhow::{bail, Context, Result};
+use rusqlite::Connection;
+use serde::Serialize;
+
+use super::{
+    build_candidate_deltas, compare_candidates, default_candidate_grid, evaluate_candidate,
+    weight_distance, WeightGridCandidate, WeightGridScoring, EPSILON,
+};
+use crate::eval::golden::{GoldenDataset, GoldenQuery};
+use crate::retrieval::search::SearchWeights;
+use crate::runtime_config::SearchWeightMode;
+
+pub const DEFAULT_MIN_FEEDBACK_QUERIES: usize = 20;
+const MAX_FEEDBACK_EVENTS: usize = 2_000;
+const MAX_PROMPT_CHARS: usize = 512;
+
+#[derive(Debug, Clone)]
+pub struct WeightFitOptions {
+    pub project: String,
+    pub k: usize,
+    /// Profile name to write; defaults to `fit-<project dir name>`.
+    pub profile: Option<String>,
+    pub min_queries: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WeightFitDecision {
+    InsufficientFeedback,
+    KeepShippedDefaults,
+    WroteShadowProfile,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WeightFitReport {
+    pub project: String,
+    pub k: usize,
+    pub citation_events: usize,
+    /// Citations without an archived user prompt in their session.
+    pub skipped_events: usize,
+    pub feedback_queries: usize,
+    pub min_queries: usize,
+    pub default_score: f64,
+    pub best: Option<WeightGridCandidate>,
+    pub decision: WeightFitDecision,
+    pub profile: Option<String>,
+    pub mode: Option<&'static str>,
+    pub config_path: Option<String>,
+}
+
+/// Learn channel weights for one project from its recorded citation
+/// feedback: every matched citation becomes a query (the preceding user
+/// prompt) whose relevant results are the memories the assistant cited. The
+/// default grid is scored against the live store, and a winner that beats the
+/// shipped defaults is written as a `shadow` profile so its ranking deltas are
+/// logged before anyone activates it.
+pub fn fit_project_weights(
+    conn: &Connection,
+    options: WeightFitOptions,
+) -> Result<WeightFitReport> {
+    let project = options.project.trim().to_string();
+    if project.is_empty() {
+        bail!("--fit requires --project");
+    }
+    let k = options.k.max(1);
+    let feedback =
+        crate::memory::usage::load_usage_feedback_queries(conn, &project, MAX_FEEDBACK_EVENTS)
+            .context("load usage feedback")?;
+    let citation_events = feedback.len();
+    let queries = feedback
+        .into_iter()
+        .filter_map(|event| {
+            let prompt = event.prompt?;
+            Some(GoldenQuery {
+                id: format!("citation-{}", event.citation_event_id),
+                query: prompt.chars().take(MAX_PROMPT_CHARS).collect(),
+                category: "usage_feedback".to_string(),
+                slice: None,
+                hop_path: None,
+                project: Some(project.clone()),
+                branch: None,
+                memory_type: None,
+                relevant_ids: event.memory_ids,
+                evidence_refs: vec![],
+                expect_abstain: false,
+                false_premise: false,
+                notes: None,
+            })
+        })
+        .collect::<Vec<_>>();
+    let mut report = WeightFitReport {
+        project,
+        k,
+        citation_events,
+        skipped_events: citation_events - queries.len(),
+        feedback_queries: queries.len(),
+        min_queries: options.min_queries,
+        default_score: 0.0,
+        best: None,
+        decision: WeightFitDecision::InsufficientFeedback,
+        profile: None,
+        mode: None,
+        config_path: None,
+    };
+    if queries.is_empty() || queries.len() < options.min_queries {
+        return Ok(report);
+    }
+
+    let dataset = GoldenDataset {
+        version: None,
+        description: None,
+        corpus: vec![],
+        queries,
+    };
+    let default_weights = SearchWeights::default();
+    let scoring = WeightGridScoring::default();
+    let mut evaluated = default_candidate_grid()
+        .into_iter()
+        .map(|weights| evaluate_candidate(conn, &dataset, k, weights, &scoring))
+        .collect::<Result<Vec<_>>>()?;
+    let default_overall = evaluated
+        .iter()
+        .find(|candidate| candidate.weights == default_weights)
+        .map(|candidate| (candidate.score, candidate.overall.clone()))
+        .context("default search weights were not included in the grid")?;
+    report.default_score = default_overall.0;
+    for candidate in &mut evaluated {
+        candidate.distance_from_defaults = weight_distance(candidate.weights, default_weights);
+        candidate.deltas_vs_default =
+            build_candidate_deltas(&default_overall.1, &candidate.overall);
+    }
+    evaluated.sort_by(compare_candidates);
+    for (index, candidate) in evaluated.iter_mut().enumerate() {
+        candidate.rank = index + 1;
+    }
+    let best = evaluated
+        .into_iter()
+        .next()
+        .context("weight fit produced no evaluated candidates")?;
+    let improves = best.weights != default_weights && best.score > report.default_score + EPSILON;
+    report.best = Some(best.clone());
+    if !improves {
+        report.decision = WeightFitDecision::KeepShippedDefaults;
+        return Ok(report);
+    }
+
+    let profile = options
+        .profile
+        .clone()
+        .unwrap_or_else(|| default_profile_name(&report.project));
+    let mode = SearchWeightMode::Shadow;
+    let path = crate::runtime_config::write_search_weight_profile(
+        &profile,
+        &best.weights,
+        &report.project,
+        mode,
+    )?;
+    report.decision = WeightFitDecision::WroteShadowProfile;
+    report.profile = Some(profile);
+    report.mode = Some(mode.as_str());
+    report.config_path = Some(path.display().to_string());
+    Ok(report)
+}
+
+fn default_profile_name(project: &str) -> String {
+    let name = std::path::Path::new(project)
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' {
+                ch.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>();
+    let name = name.trim_matches('-');
+    if name.is_empty() {
+        "fit".to_string()
+    } else {
+        format!("fit-{name}")
+    }
+}
+
+impl Display for WeightFitReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(
+            f,
+            "remem weight fit — {} feedback queries from {} citations ({} without prompt), k={}",
+            self.feedback_queries, self.citation_events, self.skipped_events, self.k
+        )?;
+        writeln!(f, "project: {}", self.project)?;
+        let Some(best) = &self.best else {
+            return writeln!(
+                f,
+                "decision: insufficient feedback (need at least {} queries)",
+                self.min_queries.max(1)
+            );
+        };
+        writeln!(
+            f,
+            "default score={:.4}, best score={:.4}",
+            self.default_score, best.score
+        )?;
+        writeln!(
+            f,
+            "best: fts={:.2} vector={:.2} entity={:.2} temporal={:.2} fact={:.2} like={:.2} usage={:.2}",
+            best.weights.fts,
+            best.weights.vector,
+            best.weights.entity,
+            best.weights.temporal,
+            best.weights.fact,
+            best.weights.like_fallback,
+            best.weights.usage
+        )?;
+        match (&self.profile, &self.config_path) {
+            (Some(profile), Some(path)) => {
+                writeln!(
+                    f,
+                    "decision: wrote profile {profile} in shadow mode to {path}"
+                )?;
+                writeln!(
+                    f,
+                    "set mode = \"active\" under [search.weights.projects.\"{}\"] to use it",
+                    self.project
+                )
+            }
+            _ => writeln!(f, "decision: keep shipped defaults"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_profile_name_uses_sanitized_project_dir() {
+        assert_eq!(
+            default_profile_name("/work/Payments API"),
+            "fit-payments-api"
+        );
+        assert_eq!(default_profile_name("/"), "fit");
+    }
+
+    #[test]
+    fn fit_without_feedback_reports_insufficient_and_writes_nothing() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        crate::migrate::run_migrations(&conn)?;
+        let report = fit_project_weights(
+            &conn,
+            WeightFitOptions {
+                project: "/repo".to_string(),
+                k: 5,
+                profile: None,
+                min_queries: 1,
+            },
+        )?;
+        assert_eq!(report.decision, WeightFitDecision::InsufficientFeedback);
+        assert_eq!(report.feedback_queries, 0);
+        assert!(report.best.is_none() && report.config_path.is_none());
+        Ok(())
+    }
+}
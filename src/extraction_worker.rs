@@ -40,6 +40,18 @@ pub(crate) async fn run_next(
             db::EXTRACTION_TASK_MAX_ATTEMPTS
         ),
     );
+    if let Some(budget) = crate::ai::budget::admission_block(
+        &conn,
+        &task.project,
+        &task.host,
+        task.ai_profile.as_deref(),
+    )? {
+        let reason = budget.deferral_reason();
+        let wait_secs = budget.resets_at_epoch - chrono::Utc::now().timestamp();
+        db::wait_extraction_task(&conn, task.id, lease_owner, &reason, wait_secs)?;
+        crate::log::warn("worker", &format!("extraction id={} {}", task.id, reason));
+        return Ok(true);
+    }
 
     let timed = tokio::time::timeout(
         Duration::from_secs(timeout_secs),
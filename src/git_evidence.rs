@@ -80,13 +80,13 @@ pub(crate) fn from_codex_transcript(
     byte_limit: u64,
     fallback_cwd: &str,
 ) -> Result<Vec<GitCommitEvidence>> {
-    let content =
-        crate::memory::raw_transcript::read_transcript_content(transcript_path, Some(byte_limit))
-            .with_context(|| {
-            format!(
-                "read Codex transcript commit evidence path={transcript_path} bytes={byte_limit}"
-            )
-        })?;
+    let content = crate::memory::raw_transcript::read_transcript_content(
+        transcript_path,
+        crate::memory::raw_transcript::TranscriptBoundary::bytes(byte_limit),
+    )
+    .with_context(|| {
+        format!("read Codex transcript commit evidence path={transcript_path} bytes={byte_limit}")
+    })?;
     let mut calls = BTreeMap::<String, CommitCall>::new();
     let mut evidence = BTreeMap::<String, GitCommitEvidence>::new();
     for (line_index, line) in content.lines().enumerate() {
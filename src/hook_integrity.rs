@@ -117,7 +117,7 @@ const CLAUDE_EXPECTED: &[ExpectedHookSpec] = &[
         subcommand: "rules",
         nested_subcommand: Some("eval"),
         host: "claude-code",
-        matcher: Some("Bash"),
+        matcher: Some("*"),
         timeout_seconds: Some(5),
     },
 ];
@@ -742,7 +742,7 @@ mod tests {
                     }]
                 }],
                 "PreToolUse": [{
-                    "matcher": "Bash",
+                    "matcher": "*",
                     "hooks": [{
                         "command": format!("{remem_s} rules eval --host claude-code"),
                         "timeout": 5
@@ -371,7 +371,8 @@ pub(crate) fn load_by_path(
 
 pub(crate) fn index_events(path: &str, byte_limit: u64) -> Result<EventIndex> {
     let mut index = EventIndex::default();
-    crate::memory::raw_transcript::stream_transcript_lines(path, Some(byte_limit), |line, _| {
+    let boundary = crate::memory::raw_transcript::TranscriptBoundary::bytes(byte_limit);
+    crate::memory::raw_transcript::stream_transcript_lines(path, boundary, |line, _| {
         let Ok(value) = serde_json::from_str::<serde_json::Value>(line) else {
             index.missing_event_time_count += 1;
             return;
@@ -507,41 +508,64 @@ fn probe_context(file: &Path, byte_limit: Option<u64>) -> Result<TranscriptConte
             }
             break;
         }
+        if consumed == 0 && crate::memory::raw_transcript::is_session_document_start(&line) {
+            return probe_document_context(file);
+        }
         consumed = consumed.saturating_add(u64::try_from(read).unwrap_or(u64::MAX));
         let Ok(value) = serde_json::from_str::<serde_json::Value>(&line) else {
             continue;
         };
-        let payload = value.get("payload");
-        context.session_id = context.session_id.or_else(|| {
-            value
-                .get("sessionId")
-                .and_then(serde_json::Value::as_str)
-                .or_else(|| value.get("session_id").and_then(serde_json::Value::as_str))
-                .or_else(|| {
-                    (value.get("type").and_then(serde_json::Value::as_str) == Some("session_meta"))
-                        .then_some(())
-                        .and_then(|_| payload?.get("id")?.as_str())
-                })
-                .map(str::to_string)
-        });
-        context.cwd = context.cwd.or_else(|| {
-            value
-                .get("cwd")
-                .and_then(serde_json::Value::as_str)
-                .or_else(|| payload?.get("cwd")?.as_str())
-                .map(str::to_string)
-        });
-        context.branch = context.branch.or_else(|| {
-            value
-                .get("gitBranch")
-                .and_then(serde_json::Value::as_str)
-                .or_else(|| payload?.get("git")?.get("branch")?.as_str())
-                .map(str::to_string)
-        });
+        absorb_context(&mut context, &value);
     }
     Ok(context)
 }
 
+/// Session documents (Gemini CLI) only parse whole; probe their replayed
+/// message records instead of raw lines. They are rewritten in place, so a
+/// captured byte length cannot bound them.
+fn probe_document_context(file: &Path) -> Result<TranscriptContext> {
+    let mut context = TranscriptContext::default();
+    let path = file.to_string_lossy();
+    let boundary = crate::memory::raw_transcript::TranscriptBoundary::default();
+    crate::memory::raw_transcript::stream_transcript_lines(&path, boundary, |line, _| {
+        if let Ok(value) = serde_json::from_str::<serde_json::Value>(line) {
+            absorb_context(&mut context, &value);
+        }
+    })
+    .with_context(|| format!("read transcript probe {}", file.display()))?;
+    Ok(context)
+}
+
+fn absorb_context(context: &mut TranscriptContext, value: &serde_json::Value) {
+    let payload = value.get("payload");
+    context.session_id = context.session_id.take().or_else(|| {
+        value
+            .get("sessionId")
+            .and_then(serde_json::Value::as_str)
+            .or_else(|| value.get("session_id").and_then(serde_json::Value::as_str))
+            .or_else(|| {
+                (value.get("type").and_then(serde_json::Value::as_str) == Some("session_meta"))
+                    .then_some(())
+                    .and_then(|_| payload?.get("id")?.as_str())
+            })
+            .map(str::to_string)
+    });
+    context.cwd = context.cwd.take().or_else(|| {
+        value
+            .get("cwd")
+            .and_then(serde_json::Value::as_str)
+            .or_else(|| payload?.get("cwd")?.as_str())
+            .map(str::to_string)
+    });
+    context.branch = context.branch.take().or_else(|| {
+        value
+            .get("gitBranch")
+            .and_then(serde_json::Value::as_str)
+            .or_else(|| payload?.get("git")?.get("branch")?.as_str())
+            .map(str::to_string)
+    });
+}
+
 fn fallback_project_slug(scan_root: &Path, file: &Path, source_root: &str) -> String {
     let parent = file.parent().unwrap_or(scan_root);
     let relative = parent.strip_prefix(scan_root).unwrap_or(parent);
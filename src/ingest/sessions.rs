@@ -1,8 +1,10 @@
 //! `remem ingest-sessions` — batch, incremental, idempotent ingestion of
-//! Claude Code / Codex session transcripts into `raw_messages` (issue #722).
+//! Claude Code / Codex / Gemini CLI session transcripts into `raw_messages`
+//! (issue #722).
 //!
-//! Discovery walks each scan root for `*.jsonl` files (skipping `subagents/`
-//! directories), a per-file cursor in `ingest_cursors` skips files whose
+//! Discovery walks each scan root for `*.jsonl` files plus Gemini's
+//! `chats/session-*.json` documents (skipping `subagents/` directories), a
+//! per-file cursor in `ingest_cursors` skips files whose
 //! mtime and size are unchanged, and each hit is drained through the existing
 //! `drain_transcript` path so the `raw_messages` UNIQUE constraint dedupes
 //! against the Stop-hook ingestion running concurrently.
@@ -61,8 +63,8 @@ fn shellexpand_home(path: &str) -> String {
     path.to_string()
 }
 
-/// Default local scan roots: `~/.claude/projects` and `~/.codex/sessions`.
-/// Both are labeled `local` to match the hook-path `source_root` default.
+/// Default local scan roots: `~/.claude/projects`, `~/.codex/sessions` and
+/// `~/.gemini/tmp`. All are labeled `local` to match the hook-path `source_root` default.
 pub fn default_scan_roots() -> Vec<ScanRoot> {
     let Some(home) = dirs::home_dir() else {
         crate::log::warn("ingest-sessions", "home directory unavailable");
@@ -79,6 +81,11 @@ pub fn default_scan_roots() -> Vec<ScanRoot> {
             path: home.join(".codex").join("sessions"),
             required: false,
         },
+        ScanRoot {
+            label: SOURCE_ROOT_LOCAL.to_string(),
+            path: home.join(".gemini").join("tmp"),
+            required: false,
+        },
     ]
 }
 
@@ -306,7 +313,8 @@ pub(crate) fn discover_transcript_files(root: &ScanRoot) -> (Vec<PathBuf>, Vec<S
     (files, failures)
 }
 
-/// Recursively collect `*.jsonl` files, excluding `subagents/` directories.
+/// Recursively collect `*.jsonl` files and Gemini `chats/session-*.json`
+/// documents, excluding `subagents/` directories.
 fn collect_jsonl_files(dir: &Path, out: &mut Vec<PathBuf>, failures: &mut Vec<String>) {
     let entries = match std::fs::read_dir(dir) {
         Ok(entries) => entries,
@@ -340,12 +348,26 @@ fn collect_jsonl_files(dir: &Path, out: &mut Vec<PathBuf>, failures: &mut Vec<St
                 continue;
             }
             collect_jsonl_files(&path, out, failures);
-        } else if file_type.is_file() && path.extension().is_some_and(|ext| ext == "jsonl") {
+        } else if file_type.is_file()
+            && (path.extension().is_some_and(|ext| ext == "jsonl") || is_gemini_session(&path))
+        {
             out.push(path);
         }
     }
 }
 
+fn is_gemini_session(path: &Path) -> bool {
+    let in_chats = path
+        .parent()
+        .and_then(Path::file_name)
+        .is_some_and(|dir| dir == "chats");
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+    in_chats && name.starts_with("session-") && name.ends_with(".json")
+}
+
 enum PreparedFileResult {
     Commit,
     Rollback { identity_conflict: bool },
@@ -427,7 +449,9 @@ fn ingest_prepared_file(
         plan.branch.as_deref(),
         plan.cwd.as_deref(),
         &drain_options,
-        Some(u64::try_from(size_bytes).unwrap_or(u64::MAX)),
+        crate::memory::raw_transcript::TranscriptBoundary::bytes(
+            u64::try_from(size_bytes).unwrap_or(u64::MAX),
+        ),
     ) {
         Ok(report) => {
             summary.ingested_messages += report.inserted;
@@ -336,6 +336,31 @@ fn codex_session_meta_id_overrides_rollout_filename() {
     assert_eq!(session_id, "019eba00-sanitized");
 }
 
+#[test]
+fn gemini_session_documents_are_discovered_and_ingested_per_message() {
+    let conn = setup_conn();
+    let root = TempRoot::new("gemini-session");
+    let fixture = include_str!("../../../tests/fixtures/gemini-session-minimal.json");
+    root.write(
+        "5b1e0d9c/chats/session-2026-06-12T08-00-3f6c2a1e.json",
+        fixture,
+    );
+    root.write("5b1e0d9c/logs.json", "[]");
+
+    let first = run(&conn, &[root.scan_root("local")]);
+    assert_eq!(first.scanned, 1, "only chats/session-*.json is discovered");
+    assert_eq!(first.failed_files, 0);
+    assert_eq!(first.ingested_messages, 2);
+
+    let session_id: String = conn
+        .query_row("SELECT DISTINCT session_id FROM raw_messages", [], |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert_eq!(session_id, "3f6c2a1e-sanitized");
+    assert_eq!(run(&conn, &[root.scan_root("local")]).skipped, 1);
+}
+
 #[test]
 fn transcript_timestamps_drive_raw_message_window_time() {
     let conn = setup_conn();
@@ -1,6 +1,7 @@
 mod config;
 pub(crate) mod cursor_config;
 pub(crate) mod duplicates;
+mod gemini_config;
 mod host;
 mod hosts;
 mod json_io;
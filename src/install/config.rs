@@ -49,15 +49,16 @@ impl HookStrategy {
 }
 
 fn hook_command(bin: &str, strategy: HookStrategy, subcommand: &str) -> String {
+    host_hook_command(bin, strategy.runtime_host(), subcommand)
+}
+
+/// `<quoted exe> <subcommand> --host <runtime host>`, routing hot-path
+/// subcommands to a sibling `remem-hook` binary when one is installed.
+pub(in crate::install) fn host_hook_command(bin: &str, host: &str, subcommand: &str) -> String {
     let first = subcommand.split_whitespace().next().unwrap_or(subcommand);
     let exe = crate::hook_cli::hook_invocation_binary(Path::new(bin), first);
     let exe = exe.to_str().unwrap_or(bin);
-    format!(
-        "{} {} --host {}",
-        shell_quote(exe),
-        subcommand,
-        strategy.runtime_host()
-    )
+    format!("{} {} --host {}", shell_quote(exe), subcommand, host)
 }
 
 /// Shared POSIX shell quoting, reused verbatim by the Cursor managed hook
@@ -101,7 +102,7 @@ pub(in crate::install) fn build_hooks(bin: &str, strategy: HookStrategy) -> Valu
         hooks.insert(
             "PreToolUse".to_string(),
             json!([{
-                "matcher": "Bash",
+                "matcher": "*",
                 "hooks": [{ "type": "command", "command": hook_command(bin, strategy, "rules eval"), "timeout": 5 }]
             }]),
         );
@@ -7,22 +7,22 @@
 //! `crate::install::config` (B-006/B-007 require exact structural
 //! ownership).
 //!
-//! Contract v1 capability gates (checked against the GH-823 runtime that is
+//! Contract v2 capability gates (checked against the runtime that is
 //! actually merged, not against candidate evidence):
-//! - The observe bundle (`postToolUse` + `postToolUseFailure`) is absent:
-//!   the merged GH-823 runtime accepts only the observed failed-`Read`
-//!   failure shape and rejects other delivered failure tool names with a
-//!   non-zero exit, which is not the required total
-//!   capture-or-explicit-zero-write (exit 0) policy.
+//! - The observe bundle (`postToolUse` + `postToolUseFailure`) installs:
+//!   the observe runtime applies the total delivered-failure policy (the
+//!   observed failed-`Read` shape is captured, every other structurally
+//!   valid delivered failure is an explicit exit-0 zero-write no-op).
+//! - `stop` summarize installs: `remem summarize --host cursor` reads the
+//!   Cursor transcript through the GH-825 reader.
 //! - `afterMCPExecution` is absent: GH-823 froze B-016 generic ownership.
-//! - `stop` summarize is absent: `remem summarize --host cursor` stays
-//!   fail-closed until GH-825's transcript reader is merged.
 //! - `sessionStart` injection is blocked on Cursor 3.12.17 and `preCompact`
 //!   has no approved action; neither installs.
 //!
-//! The managed hook component set for contract v1 is therefore empty; the
-//! install surface manages exactly one MCP component and still validates,
-//! preserves, and (via receipt) can later upgrade or remove hook entries.
+//! Contract v1 installed no hook entries. A v1 receipt is still accepted so
+//! `install` and `install --repair` can upgrade it in place; hook entries
+//! are owned exactly by the receipt, and foreign entries on the same events
+//! are preserved.
 
 pub(crate) mod plan;
 pub(crate) mod schema;
@@ -44,6 +44,11 @@ pub(crate) const CURSOR_HOOK_FAILURE_POLICY_LINE: &str = "hook_failure_policy: h
 /// doctor state.
 pub(crate) const CURSOR_SESSION_INIT_LINE: &str = "session-init: not supported on cursor";
 
+/// Cursor's session-start context path while sessionStart injection stays
+/// blocked: an always-applied project rule rendered from the shared bundle.
+pub(crate) const CURSOR_SESSION_CONTEXT_LINE: &str =
+    "session_context: rules_file .cursor/rules/remem.mdc (create with `remem sync-memory --host cursor`; refreshed after each summary)";
+
 /// User-level Cursor paths, re-exported for the doctor classifier.
 pub(crate) fn cursor_hooks_path() -> PathBuf {
     crate::install::paths::cursor_hooks_path()
@@ -67,8 +72,11 @@ pub(crate) fn cursor_renderer_supported() -> bool {
 }
 
 /// GH-824 install contract version. Any schema drift in the frozen Cursor
-/// hooks/MCP shapes requires a new version; the v1 parser never guesses.
-pub(crate) const CURSOR_INSTALL_CONTRACT_VERSION: i64 = 1;
+/// hooks/MCP shapes requires a new version; the parser never guesses.
+pub(crate) const CURSOR_INSTALL_CONTRACT_VERSION: i64 = 2;
+
+/// Contract versions whose receipts can still be read (and upgraded).
+pub(crate) const CURSOR_SUPPORTED_CONTRACT_VERSIONS: [i64; 2] = [1, 2];
 
 /// Install receipt schema version (`hosts.cursor.install_receipt`).
 pub(crate) const CURSOR_RECEIPT_SCHEMA_VERSION: i64 = 1;
@@ -89,11 +97,12 @@ pub(crate) struct CursorCapabilityGates {
     pub summarize_reader_proven: bool,
 }
 
-/// Gates as satisfied by the currently merged runtime: all closed.
+/// Gates as satisfied by the currently merged runtime: observe and stop
+/// summarize are open, MCP-specific ownership stays closed.
 pub(crate) const CURRENT_CAPABILITY_GATES: CursorCapabilityGates = CursorCapabilityGates {
-    observe_total_failure_policy: false,
+    observe_total_failure_policy: true,
     mcp_specific_per_call_id: false,
-    summarize_reader_proven: false,
+    summarize_reader_proven: true,
 };
 
 /// Canonical JSON digest used by receipt-bound ownership matching: SHA-256
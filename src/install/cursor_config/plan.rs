@@ -2,8 +2,8 @@
 //!
 //! `CursorConfigPlan::build` reads and fully validates both Cursor files and
 //! the canonical runtime config/receipt before any write is planned. All
-//! operations and modes (`install`, `uninstall`, `--hooks-only`, `--dry-run`,
-//! doctor) share this parser and its error codes.
+//! operations and modes (`install`, `uninstall`, `--hooks-only`, `--repair`,
+//! `--dry-run`, doctor) share this parser and its error codes.
 
 use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
@@ -15,13 +15,19 @@ use super::schema::{validate_hooks_document, validate_mcp_document};
 use super::{
     canonical_json_digest, CursorCapabilityGates, CURRENT_CAPABILITY_GATES,
     CURSOR_INSTALL_CONTRACT_VERSION, CURSOR_RECEIPT_SCHEMA_VERSION,
+    CURSOR_SUPPORTED_CONTRACT_VERSIONS,
 };
 use crate::install::paths::{cursor_hooks_path, cursor_mcp_path};
 
 /// Operation being planned.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum CursorOperation {
-    Install { hooks_only: bool },
+    Install {
+        hooks_only: bool,
+    },
+    /// Re-register the managed hook entries of an existing install without
+    /// touching `mcp.json` (`remem install --repair`).
+    Repair,
     Uninstall,
 }
 
@@ -128,10 +134,9 @@ pub(crate) fn managed_cursor_mcp_entry(bin: &str) -> Value {
     })
 }
 
-/// Managed hook builder for contract v1 (B-005). Every component is behind a
-/// runtime capability gate; with the currently merged GH-823 runtime all
-/// gates are closed, so the component list is empty. The shapes are kept
-/// here (not deleted) because they are the frozen contract for the gates.
+/// Managed hook builder (B-005). Every component is behind a runtime
+/// capability gate; contract v2 opens the observe bundle and `stop`
+/// summarize, while `afterMCPExecution` stays gated.
 pub(crate) fn managed_hook_components(
     bin: &str,
     gates: CursorCapabilityGates,
@@ -143,17 +148,17 @@ pub(crate) fn managed_hook_components(
     };
     let mut push = |component: &str, event: &str, subcommand: &str, tail: &str| {
         let quoted = quoted_for(subcommand);
-        let rendered = format!("{quoted}{tail}");
-        let entry = json!({ "command": rendered, "timeout": 120 });
-        components.push(ReceiptComponent {
+        let mut component = ReceiptComponent {
             component: component.to_string(),
             kind: "hook".to_string(),
             key: event.to_string(),
             command_tail: Some(tail.to_string()),
-            rendered_command: Some(rendered),
+            rendered_command: Some(format!("{quoted}{tail}")),
             timeout: Some(120),
-            digest: canonical_json_digest(&entry),
-        });
+            digest: String::new(),
+        };
+        component.digest = canonical_json_digest(&managed_hook_entry(&component));
+        components.push(component);
     };
     // The observe bundle is atomic: success capture never installs without
     // the total delivered-failure policy (B-005).
@@ -190,6 +195,15 @@ pub(crate) fn managed_hook_components(
     components
 }
 
+/// The exact `hooks.json` entry a hook component owns: rendered command
+/// plus timeout, nothing else.
+pub(crate) fn managed_hook_entry(component: &ReceiptComponent) -> Value {
+    json!({
+        "command": component.rendered_command.as_deref().unwrap_or_default(),
+        "timeout": component.timeout.unwrap_or_default(),
+    })
+}
+
 /// Builds the receipt that a successful apply commits (B-015).
 pub(crate) fn build_receipt(bin: &str, hooks_only: bool) -> CursorInstallReceipt {
     let mut components = managed_hook_components(bin, CURRENT_CAPABILITY_GATES);
@@ -271,7 +285,7 @@ pub(crate) fn parse_receipt_from_config(doc: &DocumentMut) -> Result<Option<Curs
     let receipt: CursorInstallReceipt = serde_json::from_str(raw)
         .context("cursor install receipt is not valid receipt JSON (code=receipt_invalid)")?;
     if receipt.schema_version != CURSOR_RECEIPT_SCHEMA_VERSION
-        || receipt.contract_version != CURSOR_INSTALL_CONTRACT_VERSION
+        || !CURSOR_SUPPORTED_CONTRACT_VERSIONS.contains(&receipt.contract_version)
     {
         bail!(
             "cursor install receipt has unsupported schema/contract version (code=receipt_unsupported_version)"
@@ -283,7 +297,7 @@ pub(crate) fn parse_receipt_from_config(doc: &DocumentMut) -> Result<Option<Curs
     Ok(Some(receipt))
 }
 
-fn parse_json_snapshot(snapshot: &FileSnapshot) -> Result<Option<Value>> {
+pub(in crate::install) fn parse_json_snapshot(snapshot: &FileSnapshot) -> Result<Option<Value>> {
     let Some(bytes) = snapshot.bytes.as_ref() else {
         return Ok(None);
     };
@@ -302,7 +316,7 @@ fn parse_json_snapshot(snapshot: &FileSnapshot) -> Result<Option<Value>> {
     Ok(Some(doc))
 }
 
-fn render_json_bytes(doc: &Value) -> Result<Vec<u8>> {
+pub(in crate::install) fn render_json_bytes(doc: &Value) -> Result<Vec<u8>> {
     let mut text = serde_json::to_string_pretty(doc)?;
     text.push('\n');
     Ok(text.into_bytes())
@@ -436,12 +450,31 @@ impl CursorConfigPlan {
                 hooks_only,
                 bin,
                 hooks_snapshot,
+                hooks_doc,
                 mcp_snapshot,
                 mcp_doc,
                 ownership,
                 config_snapshot,
                 &mut config_doc,
+                receipt.as_ref(),
             ),
+            CursorOperation::Repair => {
+                let Some(receipt) = receipt else {
+                    bail!(
+                        "cursor has no install receipt; run `remem install --target cursor` before `--repair` (code=not_installed)"
+                    );
+                };
+                Self::build_repair(
+                    operation,
+                    bin,
+                    hooks_snapshot,
+                    hooks_doc,
+                    mcp_snapshot,
+                    config_snapshot,
+                    &mut config_doc,
+                    &receipt,
+                )
+            }
             CursorOperation::Uninstall => Self::build_uninstall(
                 operation,
                 hooks_snapshot,
@@ -462,20 +495,16 @@ impl CursorConfigPlan {
         hooks_only: bool,
         bin: &str,
         hooks_snapshot: FileSnapshot,
+        hooks_doc: Option<Value>,
         mcp_snapshot: FileSnapshot,
         mcp_doc: Option<Value>,
         ownership: McpOwnership,
         config_snapshot: FileSnapshot,
         config_doc: &mut DocumentMut,
+        previous: Option<&CursorInstallReceipt>,
     ) -> Result<Self> {
-        // Contract v1 has no installable hook components (all capability
-        // gates closed); hooks.json is validated and preserved verbatim.
-        let hooks = FilePlan {
-            snapshot: hooks_snapshot,
-            action: FileAction::NoOp,
-            reason: "validated; contract v1 installs no hook entries (capability gates closed)",
-            new_bytes: None,
-        };
+        let receipt = build_receipt(bin, hooks_only);
+        let hooks = plan_managed_hooks(hooks_snapshot, hooks_doc, previous, &receipt)?;
 
         let mcp = if hooks_only {
             FilePlan {
@@ -520,28 +549,61 @@ impl CursorConfigPlan {
         };
 
         plan_cursor_host_section(config_doc)?;
-        let receipt = build_receipt(bin, hooks_only);
-        let receipt_json =
-            serde_json::to_string(&receipt).context("serialize cursor install receipt")?;
-        set_cursor_receipt(config_doc, Some(&receipt_json))?;
-        let config_bytes = config_doc.to_string().into_bytes();
-        let runtime_config = FilePlan {
-            action: if config_snapshot.bytes.as_deref() == Some(config_bytes.as_slice()) {
-                FileAction::NoOp
-            } else if config_snapshot.bytes.is_none() {
-                FileAction::Add
-            } else {
-                FileAction::Replace
-            },
-            reason: "materialize hosts.cursor defaults and record the install receipt",
-            new_bytes: if config_snapshot.bytes.as_deref() == Some(config_bytes.as_slice()) {
-                None
-            } else {
-                Some(config_bytes)
-            },
-            snapshot: config_snapshot,
-        };
+        let runtime_config = plan_receipt_write(
+            config_snapshot,
+            config_doc,
+            &receipt,
+            "materialize hosts.cursor defaults and record the install receipt",
+        )?;
+
+        Ok(Self {
+            operation,
+            hooks,
+            mcp,
+            runtime_config,
+        })
+    }
 
+    /// Repair keeps the recorded mode, binary path, and MCP component, and
+    /// only re-registers the managed hook entries for the current binary.
+    #[allow(clippy::too_many_arguments)]
+    fn build_repair(
+        operation: CursorOperation,
+        bin: &str,
+        hooks_snapshot: FileSnapshot,
+        hooks_doc: Option<Value>,
+        mcp_snapshot: FileSnapshot,
+        config_snapshot: FileSnapshot,
+        config_doc: &mut DocumentMut,
+        previous: &CursorInstallReceipt,
+    ) -> Result<Self> {
+        let mut components: Vec<ReceiptComponent> = previous
+            .components
+            .iter()
+            .filter(|component| component.kind != "hook")
+            .cloned()
+            .collect();
+        components.extend(managed_hook_components(bin, CURRENT_CAPABILITY_GATES));
+        let receipt = CursorInstallReceipt {
+            schema_version: CURSOR_RECEIPT_SCHEMA_VERSION,
+            contract_version: CURSOR_INSTALL_CONTRACT_VERSION,
+            mode: previous.mode.clone(),
+            binary_path: previous.binary_path.clone(),
+            components,
+        };
+        let hooks = plan_managed_hooks(hooks_snapshot, hooks_doc, Some(previous), &receipt)?;
+        let mcp = FilePlan {
+            snapshot: mcp_snapshot,
+            action: FileAction::NoOp,
+            reason: "read-only diagnostic; no writes (repair)",
+            new_bytes: None,
+        };
+        let runtime_config = plan_receipt_write(
+            config_snapshot,
+            config_doc,
+            &receipt,
+            "record the repaired hook components in the install receipt",
+        )?;
         Ok(Self {
             operation,
             hooks,
@@ -650,6 +712,7 @@ impl CursorConfigPlan {
         let header = match self.operation {
             CursorOperation::Install { hooks_only: true } => "  plan: install (hooks-only)",
             CursorOperation::Install { hooks_only: false } => "  plan: install",
+            CursorOperation::Repair => "  plan: repair hooks",
             CursorOperation::Uninstall => "  plan: uninstall",
         };
         std::iter::once(header.to_string())
@@ -673,6 +736,114 @@ impl CursorConfigPlan {
     }
 }
 
+fn plan_receipt_write(
+    config_snapshot: FileSnapshot,
+    config_doc: &mut DocumentMut,
+    receipt: &CursorInstallReceipt,
+    reason: &'static str,
+) -> Result<FilePlan> {
+    let receipt_json =
+        serde_json::to_string(receipt).context("serialize cursor install receipt")?;
+    set_cursor_receipt(config_doc, Some(&receipt_json))?;
+    let config_bytes = config_doc.to_string().into_bytes();
+    let unchanged = config_snapshot.bytes.as_deref() == Some(config_bytes.as_slice());
+    Ok(FilePlan {
+        action: if unchanged {
+            FileAction::NoOp
+        } else if config_snapshot.bytes.is_none() {
+            FileAction::Add
+        } else {
+            FileAction::Replace
+        },
+        reason,
+        new_bytes: (!unchanged).then_some(config_bytes),
+        snapshot: config_snapshot,
+    })
+}
+
+/// Converges `hooks.json` on the hook components of `receipt`. Entries owned
+/// by `previous` that the new receipt no longer records are removed, exact
+/// current entries are kept in place (so a repeated install is a no-op),
+/// missing ones are appended, and every foreign entry is preserved.
+fn plan_managed_hooks(
+    hooks_snapshot: FileSnapshot,
+    hooks_doc: Option<Value>,
+    previous: Option<&CursorInstallReceipt>,
+    receipt: &CursorInstallReceipt,
+) -> Result<FilePlan> {
+    let current = hooks_doc.unwrap_or_else(|| json!({ "version": 1, "hooks": {} }));
+    let mut doc = current.clone();
+    let wanted: Vec<&ReceiptComponent> = receipt
+        .components
+        .iter()
+        .filter(|component| component.kind == "hook")
+        .collect();
+    if let Some(previous) = previous {
+        let stale = CursorInstallReceipt {
+            components: previous
+                .components
+                .iter()
+                .filter(|old| {
+                    old.kind == "hook"
+                        && !wanted.iter().any(|new| {
+                            new.key == old.key && managed_hook_entry(new) == managed_hook_entry(old)
+                        })
+                })
+                .cloned()
+                .collect(),
+            ..previous.clone()
+        };
+        remove_receipt_hook_entries(&mut doc, &stale);
+    }
+    let hooks = doc
+        .get_mut("hooks")
+        .and_then(Value::as_object_mut)
+        .expect("validated hooks container must be an object");
+    for component in &wanted {
+        let entry = managed_hook_entry(component);
+        let entries = hooks
+            .entry(component.key.clone())
+            .or_insert_with(|| json!([]))
+            .as_array_mut()
+            .expect("validated hook event must be an array");
+        if !entries.contains(&entry) {
+            entries.push(entry);
+        }
+    }
+    validate_hooks_document(&doc).map_err(|error| {
+        anyhow::anyhow!(
+            "{}: planned cursor hooks failed schema validation: {error}",
+            hooks_snapshot.path.display()
+        )
+    })?;
+
+    let (action, reason, new_bytes) = if doc == current {
+        (
+            FileAction::NoOp,
+            "managed hook entries already registered",
+            None,
+        )
+    } else if hooks_snapshot.bytes.is_none() {
+        (
+            FileAction::Add,
+            "create hooks.json with the managed observe/stop entries",
+            Some(render_json_bytes(&doc)?),
+        )
+    } else {
+        (
+            FileAction::Replace,
+            "register managed observe/stop entries; foreign entries preserved",
+            Some(render_json_bytes(&doc)?),
+        )
+    };
+    Ok(FilePlan {
+        snapshot: hooks_snapshot,
+        action,
+        reason,
+        new_bytes,
+    })
+}
+
 fn set_cursor_receipt(doc: &mut DocumentMut, receipt_json: Option<&str>) -> Result<()> {
     let memory_ai = doc
         .entry("memory_ai")
@@ -701,8 +872,9 @@ fn set_cursor_receipt(doc: &mut DocumentMut, receipt_json: Option<&str>) -> Resu
 }
 
 /// Removes hook entries that exactly match a receipt-recorded hook component
-/// (event, rendered command, timeout, and whole-entry digest). Returns true
-/// when at least one entry was removed.
+/// (event, rendered command, timeout, and whole-entry digest). An event array
+/// emptied by the removal is dropped. Returns true when at least one entry
+/// was removed.
 fn remove_receipt_hook_entries(doc: &mut Value, receipt: &CursorInstallReceipt) -> bool {
     let mut removed = false;
     let Some(hooks) = doc.get_mut("hooks").and_then(Value::as_object_mut) else {
@@ -712,6 +884,7 @@ fn remove_receipt_hook_entries(doc: &mut Value, receipt: &CursorInstallReceipt)
         let Some(entries) = hooks.get_mut(&component.key).and_then(Value::as_array_mut) else {
             continue;
         };
+        let before = entries.len();
         entries.retain(|entry| {
             let matches_receipt = entry
                 .get("command")
@@ -724,6 +897,9 @@ fn remove_receipt_hook_entries(doc: &mut Value, receipt: &CursorInstallReceipt)
             }
             !matches_receipt
         });
+        if entries.is_empty() && before > 0 {
+            hooks.remove(&component.key);
+        }
     }
     removed
 }
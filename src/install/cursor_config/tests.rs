@@ -3,7 +3,7 @@ use serde_json::{json, Value};
 
 use super::plan::{
     build_receipt, classify_remem_mcp, managed_cursor_mcp_entry, managed_hook_components,
-    CursorConfigPlan, CursorOperation, FileAction, McpOwnership,
+    managed_hook_entry, CursorConfigPlan, CursorOperation, FileAction, McpOwnership,
 };
 use super::schema::{validate_hooks_document, validate_mcp_document};
 use super::writer::apply_plan;
@@ -276,15 +276,32 @@ fn managed_mcp_entry_is_exact_stdio_shape() {
 }
 
 #[test]
-fn current_capability_gates_install_no_hook_components() {
-    // The merged GH-823 runtime has no total delivered-failure policy, MCP
-    // ownership is generic, and GH-825's reader is unproven: the contract v1
-    // hook component set must be empty (B-005).
-    assert!(managed_hook_components(BIN, CURRENT_CAPABILITY_GATES).is_empty());
+fn current_capability_gates_install_observe_bundle_and_stop() {
+    // Contract v2: the observe runtime has the total delivered-failure
+    // policy and GH-825's reader backs stop summarize; MCP ownership stays
+    // generic, so afterMCPExecution is still absent (B-005).
+    let components = managed_hook_components(BIN, CURRENT_CAPABILITY_GATES);
+    let ids: Vec<&str> = components.iter().map(|c| c.component.as_str()).collect();
+    assert_eq!(
+        ids,
+        [
+            "observe_generic_success_v1",
+            "observe_generic_failure_v1",
+            "summarize_stop_v1"
+        ]
+    );
     let receipt = build_receipt(BIN, false);
     assert_eq!(receipt.mode, "full");
-    assert_eq!(receipt.components.len(), 1);
+    assert_eq!(receipt.contract_version, 2);
+    assert_eq!(receipt.components.len(), 4);
     assert_eq!(receipt.components[0].component, "mcp_server_v1");
+    assert_eq!(
+        managed_hook_entry(&receipt.components[1]),
+        json!({ "command": format!("{BIN} observe --host cursor"), "timeout": 120 })
+    );
+    assert!(receipt.components[1..]
+        .iter()
+        .all(|c| c.digest == canonical_json_digest(&managed_hook_entry(c))));
 }
 
 #[test]
@@ -401,7 +418,7 @@ fn install_apply_uninstall_roundtrip_preserves_foreign_data() -> Result<()> {
 
     // Install.
     let plan = CursorConfigPlan::build(CursorOperation::Install { hooks_only: false }, BIN)?;
-    assert_eq!(plan.hooks.action, FileAction::NoOp);
+    assert_eq!(plan.hooks.action, FileAction::Replace);
     assert_eq!(plan.mcp.action, FileAction::Add);
     apply_plan(&plan)?;
 
@@ -411,7 +428,19 @@ fn install_apply_uninstall_roundtrip_preserves_foreign_data() -> Result<()> {
         mcp["mcpServers"]["remem-helper"]["env"]["SECRET_TOKEN"],
         "sentinel-secret"
     );
-    assert_eq!(parse_file(&env.hooks_path()), foreign_hooks_before);
+    // Managed entries are appended after the foreign ones on each event.
+    let hooks = parse_file(&env.hooks_path());
+    let observe = json!({ "command": format!("{BIN} observe --host cursor"), "timeout": 120 });
+    let stop = json!({ "command": format!("{BIN} summarize --host cursor"), "timeout": 120 });
+    assert_eq!(
+        hooks["hooks"]["postToolUse"],
+        json!([foreign_hooks_before["hooks"]["postToolUse"][0], observe])
+    );
+    assert_eq!(hooks["hooks"]["postToolUseFailure"], json!([observe]));
+    assert_eq!(
+        hooks["hooks"]["stop"],
+        json!([foreign_hooks_before["hooks"]["stop"][0], stop])
+    );
     let config_text = std::fs::read_to_string(env.config_path())?;
     assert!(
         config_text.contains("[memory_ai.hosts.cursor]"),
@@ -429,6 +458,7 @@ fn install_apply_uninstall_roundtrip_preserves_foreign_data() -> Result<()> {
 
     // Idempotency: a second install converges to a no-op plan.
     let second = CursorConfigPlan::build(CursorOperation::Install { hooks_only: false }, BIN)?;
+    assert_eq!(second.hooks.action, FileAction::NoOp);
     assert_eq!(second.mcp.action, FileAction::NoOp);
     assert_eq!(second.runtime_config.action, FileAction::NoOp);
     apply_plan(&second)?;
@@ -436,7 +466,7 @@ fn install_apply_uninstall_roundtrip_preserves_foreign_data() -> Result<()> {
     // Uninstall removes exactly the managed entry and clears the receipt.
     let uninstall = CursorConfigPlan::build(CursorOperation::Uninstall, BIN)?;
     assert_eq!(uninstall.mcp.action, FileAction::Remove);
-    assert_eq!(uninstall.hooks.action, FileAction::NoOp);
+    assert_eq!(uninstall.hooks.action, FileAction::Remove);
     apply_plan(&uninstall)?;
     let mcp_after = parse_file(&env.mcp_path());
     assert!(mcp_after["mcpServers"].get("remem").is_none());
@@ -551,6 +581,116 @@ fn receipt_bound_old_binary_path_upgrades_exactly_once() -> Result<()> {
     Ok(())
 }
 
+fn write_receipt_config(
+    env: &CursorTestEnv,
+    receipt: &super::plan::CursorInstallReceipt,
+) -> Result<()> {
+    std::fs::write(
+        env.config_path(),
+        format!(
+            "[memory_ai.hosts.cursor]\nmemory_profile = \"codex\"\ncontext_gate = \"strict\"\ncontext_color = true\ncapture_adapter = \"cursor\"\ninstall_receipt = {}\n",
+            toml_edit::Value::from(serde_json::to_string(receipt)?)
+        ),
+    )?;
+    Ok(())
+}
+
+#[test]
+fn contract_v1_receipt_upgrades_to_v2_hook_registration() -> Result<()> {
+    let env = CursorTestEnv::new("contract-upgrade")?;
+    std::fs::write(
+        env.mcp_path(),
+        serde_json::to_string_pretty(&json!({
+            "mcpServers": { "remem": managed_cursor_mcp_entry(BIN) }
+        }))?,
+    )?;
+    let mut v1 = build_receipt(BIN, false);
+    v1.contract_version = 1;
+    v1.components.retain(|component| component.kind == "mcp");
+    write_receipt_config(&env, &v1)?;
+
+    let plan = CursorConfigPlan::build(CursorOperation::Install { hooks_only: false }, BIN)?;
+    assert_eq!(plan.hooks.action, FileAction::Add);
+    assert_eq!(plan.mcp.action, FileAction::NoOp);
+    assert_eq!(plan.runtime_config.action, FileAction::Replace);
+    apply_plan(&plan)?;
+    let config = std::fs::read_to_string(env.config_path())?.parse::<toml_edit::DocumentMut>()?;
+    let receipt = super::plan::parse_receipt_from_config(&config)?.expect("receipt recorded");
+    assert_eq!(receipt.contract_version, 2);
+    assert_eq!(receipt.components.len(), 4);
+    Ok(())
+}
+
+#[test]
+fn receipt_owned_hook_entries_move_with_the_binary_path() -> Result<()> {
+    let env = CursorTestEnv::new("hook-upgrade")?;
+    let old_bin = "/old/location/remem";
+    let old = build_receipt(old_bin, true);
+    let old_entries: Vec<Value> = old.components.iter().map(managed_hook_entry).collect();
+    std::fs::write(
+        env.hooks_path(),
+        serde_json::to_string_pretty(&json!({
+            "version": 1,
+            "hooks": {
+                "postToolUse": [old_entries[0], { "command": "/tools/other" }],
+                "postToolUseFailure": [old_entries[1]],
+                "stop": [old_entries[2]]
+            }
+        }))?,
+    )?;
+    write_receipt_config(&env, &old)?;
+
+    let plan = CursorConfigPlan::build(CursorOperation::Install { hooks_only: true }, BIN)?;
+    assert_eq!(plan.hooks.action, FileAction::Replace);
+    apply_plan(&plan)?;
+    let hooks = parse_file(&env.hooks_path());
+    let text = hooks.to_string();
+    assert!(!text.contains(old_bin), "{text}");
+    assert_eq!(
+        hooks["hooks"]["postToolUse"],
+        json!([
+            { "command": "/tools/other" },
+            { "command": format!("{BIN} observe --host cursor"), "timeout": 120 }
+        ])
+    );
+    Ok(())
+}
+
+#[test]
+fn repair_reregisters_missing_hooks_without_touching_mcp() -> Result<()> {
+    let env = CursorTestEnv::new("repair")?;
+    let error = CursorConfigPlan::build(CursorOperation::Repair, BIN)
+        .expect_err("repair needs an existing install");
+    assert!(format!("{error:#}").contains("not_installed"), "{error:#}");
+
+    let install = CursorConfigPlan::build(CursorOperation::Install { hooks_only: false }, BIN)?;
+    apply_plan(&install)?;
+    let mcp_before = std::fs::read(env.mcp_path())?;
+    // The user (or another tool) deletes the stop entry.
+    let mut hooks = parse_file(&env.hooks_path());
+    hooks["hooks"]
+        .as_object_mut()
+        .expect("hooks")
+        .remove("stop");
+    std::fs::write(env.hooks_path(), serde_json::to_string_pretty(&hooks)?)?;
+
+    let repair = CursorConfigPlan::build(CursorOperation::Repair, BIN)?;
+    assert_eq!(repair.hooks.action, FileAction::Replace);
+    assert_eq!(repair.mcp.action, FileAction::NoOp);
+    assert!(repair.dry_run_lines()[0].contains("repair"));
+    apply_plan(&repair)?;
+    assert_eq!(
+        parse_file(&env.hooks_path())["hooks"]["stop"],
+        json!([{ "command": format!("{BIN} summarize --host cursor"), "timeout": 120 }])
+    );
+    assert_eq!(std::fs::read(env.mcp_path())?, mcp_before);
+
+    let again = CursorConfigPlan::build(CursorOperation::Repair, BIN)?;
+    assert_eq!(again.hooks.action, FileAction::NoOp);
+    assert_eq!(again.runtime_config.action, FileAction::NoOp);
+    Ok(())
+}
+
 #[test]
 fn capture_adapter_identity_boundary_rejects_other_hosts() -> Result<()> {
     let env = CursorTestEnv::new("adapter")?;
@@ -614,9 +754,13 @@ fn hooks_only_mode_validates_but_never_writes_mcp() -> Result<()> {
     );
     let config_text = std::fs::read_to_string(env.config_path())?;
     assert!(config_text.contains("hooks_only"), "{config_text}");
+    let hooks = parse_file(&env.hooks_path());
+    assert_eq!(hooks["version"], 1);
+    assert_eq!(hooks["hooks"].as_object().map(|hooks| hooks.len()), Some(3));
 
     // Intentional hooks-only converges and stays receipt-consistent.
     let plan = CursorConfigPlan::build(CursorOperation::Install { hooks_only: true }, BIN)?;
+    assert_eq!(plan.hooks.action, FileAction::NoOp);
     assert_eq!(plan.runtime_config.action, FileAction::NoOp);
     Ok(())
 }
@@ -635,8 +779,13 @@ fn dry_run_lines_show_both_absolute_paths_and_actions() -> Result<()> {
         "{lines}"
     );
     assert!(lines.contains("[add]"), "{lines}");
-    assert!(lines.contains("[no-op]"), "{lines}");
+    let hooks_only = CursorConfigPlan::build(CursorOperation::Install { hooks_only: true }, BIN)?;
+    assert!(
+        hooks_only.dry_run_lines().join("\n").contains("[no-op]"),
+        "hooks-only leaves mcp.json as a no-op"
+    );
     // Dry-run rendering itself has zero side effects.
+    assert!(!env.hooks_path().exists());
     assert!(!env.mcp_path().exists());
     assert!(!env.config_path().exists());
     Ok(())
@@ -709,12 +858,16 @@ fn later_target_failure_rolls_back_committed_targets() -> Result<()> {
     super::writer::clear_failpoints_for_test();
     let rendered = format!("{error:#}");
     assert!(
-        rendered.contains("injected cursor staged rename failure"),
+        rendered.contains("injected staged rename failure"),
         "{rendered}"
     );
     assert!(rendered.contains("compensating rollback"), "{rendered}");
 
-    // The already-committed MCP write was restored to the snapshot.
+    // The already-committed hooks/MCP writes were restored to the snapshot.
+    assert!(
+        !env.hooks_path().exists(),
+        "created hooks.json is rolled back"
+    );
     assert_eq!(std::fs::read_to_string(env.mcp_path())?, mcp_before);
     assert!(!env.config_path().exists(), "config must not be committed");
     Ok(())
@@ -49,10 +49,7 @@ fn rename_failpoint(path: &Path) -> Result<()> {
             .unwrap_or_else(|poisoned| poisoned.into_inner());
         if let Some(position) = paths.iter().position(|candidate| candidate == path) {
             paths.remove(position);
-            bail!(
-                "injected cursor staged rename failure for {}",
-                path.display()
-            );
+            bail!("injected staged rename failure for {}", path.display());
         }
     }
     let _ = path;
@@ -75,7 +72,9 @@ fn write_secure(path: &Path, bytes: &[u8]) -> Result<()> {
     #[cfg(not(unix))]
     {
         let _ = (path, bytes);
-        bail!("cursor secure staged writer is only approved for Unix platforms (code=platform_unsupported)");
+        bail!(
+            "secure staged writer is only approved for Unix platforms (code=platform_unsupported)"
+        );
     }
     #[cfg(unix)]
     {
@@ -86,7 +85,7 @@ fn write_secure(path: &Path, bytes: &[u8]) -> Result<()> {
         std::fs::create_dir_all(parent)
             .with_context(|| format!("create directory {}", parent.display()))?;
         let temp_path = parent.join(format!(
-            ".remem-cursor-{}-{}.tmp",
+            ".remem-staged-{}-{}.tmp",
             std::process::id(),
             chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
         ));
@@ -147,8 +146,16 @@ struct CommittedTarget<'a> {
 /// rollback; rollback failures surface `partial_state` with per-path status
 /// and doctor guidance (B-010/B-011).
 pub(crate) fn apply_plan(plan: &CursorConfigPlan) -> Result<()> {
-    let targets: Vec<&FilePlan> = [&plan.hooks, &plan.mcp, &plan.runtime_config]
-        .into_iter()
+    apply_file_plans("cursor", &[&plan.hooks, &plan.mcp, &plan.runtime_config])
+}
+
+/// Staged apply shared by every host whose install is planned as whole-file
+/// [`FilePlan`]s (Cursor, Gemini CLI). Targets are applied in the given
+/// order; `host` only labels errors.
+pub(in crate::install) fn apply_file_plans(host: &str, targets: &[&FilePlan]) -> Result<()> {
+    let targets: Vec<&FilePlan> = targets
+        .iter()
+        .copied()
         .filter(|target| target.action != FileAction::NoOp && target.new_bytes.is_some())
         .collect();
 
@@ -158,7 +165,7 @@ pub(crate) fn apply_plan(plan: &CursorConfigPlan) -> Result<()> {
         match apply_result {
             Ok(()) => committed.push(CommittedTarget { plan: target }),
             Err(error) => {
-                return Err(rollback_committed(&committed, error));
+                return Err(rollback_committed(host, &committed, error));
             }
         }
     }
@@ -183,7 +190,11 @@ fn apply_single(target: &FilePlan) -> Result<()> {
     Ok(())
 }
 
-fn rollback_committed(committed: &[CommittedTarget<'_>], original: anyhow::Error) -> anyhow::Error {
+fn rollback_committed(
+    host: &str,
+    committed: &[CommittedTarget<'_>],
+    original: anyhow::Error,
+) -> anyhow::Error {
     let mut failures: Vec<String> = Vec::new();
     for target in committed.iter().rev() {
         if let Err(error) = restore_snapshot(target.plan) {
@@ -194,12 +205,12 @@ fn rollback_committed(committed: &[CommittedTarget<'_>], original: anyhow::Error
         }
     }
     if failures.is_empty() {
-        original.context(
-            "cursor staged apply failed; all previously committed targets were restored via compensating rollback",
-        )
+        original.context(format!(
+            "{host} staged apply failed; all previously committed targets were restored via compensating rollback"
+        ))
     } else {
         original.context(format!(
-            "partial_state: cursor compensating rollback failed for [{}]; run `remem doctor` and repair the listed paths before retrying install/uninstall",
+            "partial_state: {host} compensating rollback failed for [{}]; run `remem doctor` and repair the listed paths before retrying install/uninstall",
             failures.join("; ")
         ))
     }
@@ -0,0 +1,305 @@
+//! Gemini CLI user-level install surface (`~/.gemini/settings.json`).
+//!
+//! Gemini keeps MCP servers, hooks and every other user setting in one
+//! hand-edited JSON file, so installs follow the Cursor discipline rather
+//! than the Claude/Codex read-modify-write helpers: the whole document is
+//! validated before a write is planned, remem owns entries structurally (the
+//! `mcpServers.remem` key and hook entries whose hooks are named `remem-*`),
+//! and planned bytes go through the staged writer with final comparison,
+//! read-back and rollback. A document that only differs in formatting is
+//! left untouched.
+
+#[cfg(test)]
+mod tests;
+
+use anyhow::{bail, Context, Result};
+use serde_json::{json, Map, Value};
+use std::path::Path;
+
+use crate::install::config::host_hook_command;
+use crate::install::cursor_config::plan::{
+    parse_json_snapshot, render_json_bytes, FileAction, FilePlan, FileSnapshot,
+};
+use crate::install::cursor_config::writer::apply_file_plans;
+use crate::install::paths::gemini_settings_path;
+use crate::runtime_config::GEMINI_HOST;
+
+/// Hook `name` prefix marking an entry as remem-managed.
+pub(crate) const GEMINI_HOOK_NAME_PREFIX: &str = "remem-";
+
+const MCP_KEY: &str = "remem";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(in crate::install) enum GeminiOperation {
+    InstallMcp,
+    InstallHooks,
+    UninstallMcp,
+    UninstallHooks,
+}
+
+struct ManagedHook {
+    event: &'static str,
+    matcher: Option<&'static str>,
+    name: &'static str,
+    subcommand: &'static str,
+    /// Gemini hook timeouts are milliseconds.
+    timeout_ms: i64,
+}
+
+/// SessionStart context, AfterTool capture for the tools the shared
+/// summarizer understands, and AfterAgent/PreCompress transcript drain.
+const MANAGED_HOOKS: &[ManagedHook] = &[
+    ManagedHook {
+        event: "SessionStart",
+        matcher: None,
+        name: "remem-context",
+        subcommand: "context",
+        timeout_ms: 15_000,
+    },
+    ManagedHook {
+        event: "AfterTool",
+        matcher: Some("write_file|replace|run_shell_command|search_file_content|glob"),
+        name: "remem-observe",
+        subcommand: "observe",
+        timeout_ms: 120_000,
+    },
+    ManagedHook {
+        event: "PreCompress",
+        matcher: None,
+        name: "remem-summarize-precompress",
+        subcommand: "summarize",
+        timeout_ms: 120_000,
+    },
+    ManagedHook {
+        event: "AfterAgent",
+        matcher: None,
+        name: "remem-summarize",
+        subcommand: "summarize",
+        timeout_ms: 120_000,
+    },
+];
+
+pub(in crate::install) fn managed_mcp_entry(bin: &str) -> Value {
+    json!({ "command": bin, "args": ["mcp"] })
+}
+
+pub(in crate::install) fn managed_hooks(bin: &str) -> Map<String, Value> {
+    let mut hooks = Map::new();
+    for hook in MANAGED_HOOKS {
+        let mut entry = json!({
+            "hooks": [{
+                "name": hook.name,
+                "type": "command",
+                "command": host_hook_command(bin, GEMINI_HOST, hook.subcommand),
+                "timeout": hook.timeout_ms,
+            }]
+        });
+        if let Some(matcher) = hook.matcher {
+            entry["matcher"] = json!(matcher);
+        }
+        hooks.insert(hook.event.to_string(), Value::Array(vec![entry]));
+    }
+    hooks
+}
+
+/// Plans one operation against the current settings file without writing.
+pub(in crate::install) fn plan(operation: GeminiOperation, bin: &str) -> Result<FilePlan> {
+    plan_for_path(&gemini_settings_path(), operation, bin)
+}
+
+pub(in crate::install) fn plan_for_path(
+    path: &Path,
+    operation: GeminiOperation,
+    bin: &str,
+) -> Result<FilePlan> {
+    let snapshot = FileSnapshot::capture(path.to_path_buf())?;
+    let current = parse_json_snapshot(&snapshot)?.unwrap_or_else(|| json!({}));
+    validate_settings(&current).with_context(|| format!("{} failed validation", path.display()))?;
+
+    let mut planned = current.clone();
+    let settings = planned
+        .as_object_mut()
+        .expect("validated settings root is an object");
+    match operation {
+        GeminiOperation::InstallMcp => install_mcp(settings, bin)?,
+        GeminiOperation::UninstallMcp => uninstall_mcp(settings),
+        GeminiOperation::InstallHooks => {
+            strip_managed_hooks(settings);
+            let hooks = settings
+                .entry("hooks")
+                .or_insert_with(|| json!({}))
+                .as_object_mut()
+                .expect("validated hooks container is an object");
+            for (event, entries) in managed_hooks(bin) {
+                let existing = hooks
+                    .entry(event)
+                    .or_insert_with(|| json!([]))
+                    .as_array_mut()
+                    .expect("validated hook event is an array");
+                existing.extend(entries.as_array().into_iter().flatten().cloned());
+            }
+        }
+        GeminiOperation::UninstallHooks => strip_managed_hooks(settings),
+    }
+    validate_settings(&planned).context("planned Gemini settings failed validation")?;
+
+    let (action, reason, new_bytes) = if planned == current {
+        (FileAction::NoOp, "already in the requested state", None)
+    } else if snapshot.bytes.is_none() {
+        (
+            FileAction::Add,
+            "create settings with remem entries",
+            Some(render_json_bytes(&planned)?),
+        )
+    } else {
+        (
+            FileAction::Replace,
+            "update remem entries; other settings preserved",
+            Some(render_json_bytes(&planned)?),
+        )
+    };
+    Ok(FilePlan {
+        snapshot,
+        action,
+        reason,
+        new_bytes,
+    })
+}
+
+/// Re-plans against a fresh snapshot and applies through the staged writer,
+/// which re-compares the on-disk bytes before committing.
+pub(in crate::install) fn apply(operation: GeminiOperation, bin: &str) -> Result<FilePlan> {
+    let plan = plan(operation, bin)?;
+    apply_file_plans("gemini", &[&plan])?;
+    Ok(plan)
+}
+
+/// Shape checks for the parts of the document remem reads or edits. Unknown
+/// top-level keys are preserved as-is; only the containers remem merges into
+/// must have the shape Gemini itself requires.
+pub(in crate::install) fn validate_settings(doc: &Value) -> Result<()> {
+    let Some(root) = doc.as_object() else {
+        bail!("settings root is not an object (code=settings_root_not_object)");
+    };
+    if let Some(servers) = root.get("mcpServers") {
+        let Some(servers) = servers.as_object() else {
+            bail!("mcpServers is not an object (code=mcp_container_invalid)");
+        };
+        if let Some((name, _)) = servers.iter().find(|(_, server)| !server.is_object()) {
+            bail!("mcpServers.{name} is not an object (code=mcp_entry_invalid)");
+        }
+    }
+    if let Some(hooks) = root.get("hooks") {
+        let Some(hooks) = hooks.as_object() else {
+            bail!("hooks is not an object (code=hooks_container_invalid)");
+        };
+        for (event, entries) in hooks {
+            let Some(entries) = entries.as_array() else {
+                bail!("hooks.{event} is not an array (code=hooks_event_invalid)");
+            };
+            for entry in entries {
+                let valid = entry.as_object().is_some_and(|entry| {
+                    entry.get("hooks").is_none_or(|hooks| {
+                        hooks
+                            .as_array()
+                            .is_some_and(|hooks| hooks.iter().all(Value::is_object))
+                    })
+                });
+                if !valid {
+                    bail!("hooks.{event} has a malformed entry (code=hooks_entry_invalid)");
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn install_mcp(settings: &mut Map<String, Value>, bin: &str) -> Result<()> {
+    let servers = settings
+        .entry("mcpServers")
+        .or_insert_with(|| json!({}))
+        .as_object_mut()
+        .expect("validated mcpServers is an object");
+    if let Some(existing) = servers.get(MCP_KEY) {
+        if !is_remem_mcp_entry(existing) {
+            bail!(
+                "mcpServers.{MCP_KEY} is owned by another command; rename it before installing (code=ownership_collision)"
+            );
+        }
+    }
+    servers.insert(MCP_KEY.to_string(), managed_mcp_entry(bin));
+    Ok(())
+}
+
+/// Foreign `mcpServers.remem` entries are left in place on uninstall.
+fn uninstall_mcp(settings: &mut Map<String, Value>) {
+    let Some(servers) = settings
+        .get_mut("mcpServers")
+        .and_then(Value::as_object_mut)
+    else {
+        return;
+    };
+    if servers.get(MCP_KEY).is_some_and(is_remem_mcp_entry) {
+        servers.remove(MCP_KEY);
+    }
+    if servers.is_empty() {
+        settings.remove("mcpServers");
+    }
+}
+
+fn is_remem_mcp_entry(entry: &Value) -> bool {
+    let command_is_remem = entry
+        .get("command")
+        .and_then(Value::as_str)
+        .and_then(|command| Path::new(command).file_stem()?.to_str())
+        .is_some_and(|stem| stem == "remem");
+    command_is_remem && entry.get("args") == Some(&json!(["mcp"]))
+}
+
+pub(in crate::install) fn is_managed_hook_entry(entry: &Value) -> bool {
+    entry
+        .get("hooks")
+        .and_then(Value::as_array)
+        .is_some_and(|hooks| {
+            hooks.iter().any(|hook| {
+                hook.get("name")
+                    .and_then(Value::as_str)
+                    .is_some_and(|name| name.starts_with(GEMINI_HOOK_NAME_PREFIX))
+            })
+        })
+}
+
+fn strip_managed_hooks(settings: &mut Map<String, Value>) {
+    let Some(hooks) = settings.get_mut("hooks").and_then(Value::as_object_mut) else {
+        return;
+    };
+    hooks.retain(|_, entries| {
+        let Some(entries) = entries.as_array_mut() else {
+            return true;
+        };
+        let before = entries.len();
+        entries.retain(|entry| !is_managed_hook_entry(entry));
+        !(entries.is_empty() && before > 0)
+    });
+    if hooks.is_empty() {
+        settings.remove("hooks");
+    }
+}
+
+/// Dry-run line for one planned operation: action plus non-sensitive reason,
+/// or the validation error a real run would fail with.
+pub(in crate::install) fn dry_run_line(
+    label: &str,
+    operation: GeminiOperation,
+    bin: &str,
+) -> String {
+    match plan(operation, bin) {
+        Ok(plan) => format!(
+            "  {label} -> {} [{}] ({})",
+            plan.snapshot.path.display(),
+            plan.action.label(),
+            plan.reason
+        ),
+        Err(error) => format!("  {label} would-fail: {error:#}"),
+    }
+}
@@ -0,0 +1,175 @@
+use anyhow::Result;
+use serde_json::{json, Value};
+
+use super::*;
+
+const BIN: &str = "/opt/remem/bin/remem";
+
+struct TempSettings {
+    dir: std::path::PathBuf,
+}
+
+impl TempSettings {
+    fn new(label: &str) -> Result<Self> {
+        let dir = std::env::temp_dir().join(format!(
+            "remem-gemini-install-{label}-{}-{}",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    fn path(&self) -> std::path::PathBuf {
+        self.dir.join("settings.json")
+    }
+
+    fn write(&self, doc: &Value) -> Result<()> {
+        std::fs::write(self.path(), serde_json::to_vec_pretty(doc)?)?;
+        Ok(())
+    }
+
+    fn read(&self) -> Result<Value> {
+        Ok(serde_json::from_slice(&std::fs::read(self.path())?)?)
+    }
+
+    fn apply(&self, operation: GeminiOperation) -> Result<FilePlan> {
+        let plan = plan_for_path(&self.path(), operation, BIN)?;
+        apply_file_plans("gemini", &[&plan])?;
+        Ok(plan)
+    }
+}
+
+impl Drop for TempSettings {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+#[test]
+fn install_preserves_foreign_settings_and_is_idempotent() -> Result<()> {
+    let settings = TempSettings::new("roundtrip")?;
+    let original = json!({
+        "theme": "GitHub",
+        "mcpServers": { "other": { "command": "/tools/other" } },
+        "hooks": {
+            "AfterTool": [
+                { "matcher": "write_file", "hooks": [{ "name": "lint", "type": "command", "command": "lint" }] }
+            ]
+        }
+    });
+    settings.write(&original)?;
+
+    let mcp = settings.apply(GeminiOperation::InstallMcp)?;
+    let hooks = settings.apply(GeminiOperation::InstallHooks)?;
+    assert_eq!(mcp.action, FileAction::Replace);
+    assert_eq!(hooks.action, FileAction::Replace);
+
+    let installed = settings.read()?;
+    assert_eq!(installed["theme"], "GitHub");
+    assert_eq!(
+        installed["mcpServers"]["other"],
+        original["mcpServers"]["other"]
+    );
+    assert_eq!(installed["mcpServers"]["remem"], managed_mcp_entry(BIN));
+    let after_tool = installed["hooks"]["AfterTool"].as_array().expect("array");
+    assert_eq!(after_tool.len(), 2);
+    assert_eq!(after_tool[0]["hooks"][0]["name"], "lint");
+    let command = after_tool[1]["hooks"][0]["command"]
+        .as_str()
+        .expect("command");
+    assert!(command.ends_with("observe --host gemini-cli"), "{command}");
+    for event in ["SessionStart", "AfterAgent", "PreCompress"] {
+        assert!(installed["hooks"][event].is_array(), "{event} missing");
+    }
+
+    for operation in [GeminiOperation::InstallMcp, GeminiOperation::InstallHooks] {
+        let plan = plan_for_path(&settings.path(), operation, BIN)?;
+        assert_eq!(
+            plan.action,
+            FileAction::NoOp,
+            "{operation:?} not idempotent"
+        );
+    }
+
+    settings.apply(GeminiOperation::UninstallHooks)?;
+    settings.apply(GeminiOperation::UninstallMcp)?;
+    assert_eq!(settings.read()?, original);
+    Ok(())
+}
+
+#[test]
+fn missing_settings_are_created_and_uninstall_does_not_create() -> Result<()> {
+    let settings = TempSettings::new("missing")?;
+
+    let uninstall = plan_for_path(&settings.path(), GeminiOperation::UninstallMcp, BIN)?;
+    assert_eq!(uninstall.action, FileAction::NoOp);
+
+    let install = settings.apply(GeminiOperation::InstallMcp)?;
+    assert_eq!(install.action, FileAction::Add);
+    assert_eq!(
+        settings.read()?,
+        json!({ "mcpServers": { "remem": managed_mcp_entry(BIN) } })
+    );
+    Ok(())
+}
+
+#[test]
+fn malformed_or_foreign_entries_fail_closed_without_writing() -> Result<()> {
+    let settings = TempSettings::new("fail-closed")?;
+    for (doc, operation, expected) in [
+        (
+            json!([]),
+            GeminiOperation::InstallMcp,
+            "settings_root_not_object",
+        ),
+        (
+            json!({ "mcpServers": [] }),
+            GeminiOperation::InstallMcp,
+            "mcp_container_invalid",
+        ),
+        (
+            json!({ "hooks": { "AfterTool": {} } }),
+            GeminiOperation::InstallHooks,
+            "hooks_event_invalid",
+        ),
+        (
+            json!({ "hooks": { "AfterTool": [{ "hooks": "x" }] } }),
+            GeminiOperation::UninstallHooks,
+            "hooks_entry_invalid",
+        ),
+        (
+            json!({ "mcpServers": { "remem": { "command": "/tools/not-remem" } } }),
+            GeminiOperation::InstallMcp,
+            "ownership_collision",
+        ),
+    ] {
+        settings.write(&doc)?;
+        let before = std::fs::read(settings.path())?;
+        let error = plan_for_path(&settings.path(), operation, BIN)
+            .expect_err("planning should fail closed");
+        assert!(format!("{error:#}").contains(expected), "{error:#}");
+        assert_eq!(std::fs::read(settings.path())?, before);
+    }
+
+    std::fs::write(settings.path(), "{ not json")?;
+    assert!(plan_for_path(&settings.path(), GeminiOperation::InstallHooks, BIN).is_err());
+
+    let foreign = json!({ "mcpServers": { "remem": { "command": "/tools/not-remem" } } });
+    settings.write(&foreign)?;
+    settings.apply(GeminiOperation::UninstallMcp)?;
+    assert_eq!(settings.read()?, foreign);
+    Ok(())
+}
+
+#[test]
+fn apply_refuses_when_settings_change_after_planning() -> Result<()> {
+    let settings = TempSettings::new("drift")?;
+    settings.write(&json!({ "theme": "GitHub" }))?;
+    let plan = plan_for_path(&settings.path(), GeminiOperation::InstallMcp, BIN)?;
+    settings.write(&json!({ "theme": "Dracula" }))?;
+
+    assert!(apply_file_plans("gemini", &[&plan]).is_err());
+    assert_eq!(settings.read()?, json!({ "theme": "Dracula" }));
+    Ok(())
+}
@@ -13,6 +13,8 @@ pub enum InstallTarget {
     Codex,
     /// Install only to Cursor (~/.cursor/hooks.json + ~/.cursor/mcp.json).
     Cursor,
+    /// Install only to Gemini CLI (~/.gemini/settings.json).
+    Gemini,
     /// Install to every known host, creating config files if missing.
     All,
 }
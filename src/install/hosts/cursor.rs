@@ -8,7 +8,9 @@
 
 use anyhow::{bail, Result};
 
-use crate::install::cursor_config::plan::{CursorConfigPlan, CursorOperation};
+use crate::install::cursor_config::plan::{
+    parse_receipt_from_config, CursorConfigPlan, CursorOperation,
+};
 use crate::install::cursor_config::writer::apply_plan;
 pub(in crate::install) use crate::install::cursor_config::{
     cursor_detected, cursor_renderer_supported, CURSOR_HOOK_FAILURE_POLICY_LINE,
@@ -49,3 +51,17 @@ pub(in crate::install) fn apply(operation: CursorOperation, bin: &str) -> Result
     apply_plan(&plan)?;
     Ok(plan)
 }
+
+/// Whether the runtime config records a readable Cursor install receipt.
+/// `--repair` under `--target auto|all` only touches Cursor when this holds,
+/// so a detected-but-never-installed Cursor does not block Claude repair.
+pub(in crate::install) fn cursor_receipt_recorded() -> bool {
+    let Ok(path) = crate::runtime_config::config_path() else {
+        return false;
+    };
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|text| text.parse::<toml_edit::DocumentMut>().ok())
+        .and_then(|doc| parse_receipt_from_config(&doc).ok().flatten())
+        .is_some()
+}
@@ -0,0 +1,49 @@
+use anyhow::Result;
+use std::path::PathBuf;
+
+use crate::install::gemini_config::{self, GeminiOperation};
+use crate::install::host::{HookSupport, InstallHost};
+use crate::install::paths::{gemini_dir, gemini_settings_path};
+
+/// Gemini CLI keeps MCP servers and hooks in one `settings.json`; every
+/// trait method plans and applies through `install::gemini_config`.
+pub(in crate::install) struct GeminiHost;
+
+impl InstallHost for GeminiHost {
+    fn name(&self) -> &'static str {
+        "gemini"
+    }
+
+    fn config_path(&self) -> PathBuf {
+        gemini_settings_path()
+    }
+
+    fn is_available(&self) -> bool {
+        gemini_dir().exists()
+    }
+
+    fn install_mcp(&self, bin: &str) -> Result<()> {
+        gemini_config::apply(GeminiOperation::InstallMcp, bin).map(drop)
+    }
+
+    fn uninstall_mcp(&self, bin: &str) -> Result<()> {
+        gemini_config::apply(GeminiOperation::UninstallMcp, bin).map(drop)
+    }
+
+    fn install_hooks(&self, bin: &str) -> Result<HookSupport> {
+        gemini_config::apply(GeminiOperation::InstallHooks, bin)?;
+        Ok(HookSupport::Installed)
+    }
+
+    fn uninstall_hooks(&self, bin: &str) -> Result<()> {
+        gemini_config::apply(GeminiOperation::UninstallHooks, bin).map(drop)
+    }
+
+    fn dry_run_plan(&self, bin: &str) -> Vec<String> {
+        vec![
+            gemini_config::dry_run_line("MCP   ", GeminiOperation::InstallMcp, bin),
+            gemini_config::dry_run_line("hooks ", GeminiOperation::InstallHooks, bin),
+            format!("  binary -> {}", bin),
+        ]
+    }
+}
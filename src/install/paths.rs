@@ -63,6 +63,19 @@ pub(in crate::install) fn cursor_mcp_path() -> PathBuf {
     cursor_dir().join("mcp.json")
 }
 
+pub(in crate::install) fn gemini_dir() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".gemini")
+}
+
+/// User-level Gemini CLI settings (`~/.gemini/settings.json`), which holds
+/// both `mcpServers` and `hooks`. Project `.gemini/settings.json` files are
+/// never touched.
+pub(in crate::install) fn gemini_settings_path() -> PathBuf {
+    gemini_dir().join("settings.json")
+}
+
 /// Official Codex CLI user-level rollout-summary memory location, verified on
 /// codex-cli 0.145.0 (docs/research/gh852-host-native-memory-poc.md).
 pub(crate) fn codex_memories_dir() -> PathBuf {
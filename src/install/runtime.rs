@@ -33,10 +33,10 @@ pub fn install(target: InstallTarget, dry_run: bool, hooks_only: bool, repair: b
              no host was modified (code=platform_unsupported)"
         );
     }
-    if repair && matches!(target, InstallTarget::Cursor) {
-        bail!("`--repair` 首版只支持 Claude hooks；cursor 不支持 hook repair");
-    }
-    let with_cursor = !repair && cursor_selected(target);
+    let with_cursor = cursor_selected(target)
+        && (!repair
+            || matches!(target, InstallTarget::Cursor)
+            || cursor::cursor_receipt_recorded());
     if matches!(target, InstallTarget::Auto)
         && cursor::cursor_detected()
         && !cursor::cursor_renderer_supported()
@@ -51,7 +51,7 @@ pub fn install(target: InstallTarget, dry_run: bool, hooks_only: bool, repair: b
     }
 
     if repair {
-        return repair_install(target, dry_run, &bin, hosts);
+        return repair_install(target, dry_run, &bin, hosts, with_cursor);
     }
 
     if dry_run {
@@ -158,9 +158,6 @@ pub fn install(target: InstallTarget, dry_run: bool, hooks_only: bool, repair: b
         );
         eprintln!("  {}", cursor::CURSOR_HOOK_FAILURE_POLICY_LINE);
         eprintln!("  {}", cursor::CURSOR_SESSION_INIT_LINE);
-        eprintln!(
-            "  note   contract v1 installs no Cursor hook entries yet (observe/summarize capability gates are closed); automatic Cursor memory is NOT enabled"
-        );
     }
 
     let data_dir = remem_data_dir()?;
@@ -184,7 +181,7 @@ pub fn install(target: InstallTarget, dry_run: bool, hooks_only: bool, repair: b
     eprintln!();
     eprintln!("Next steps:");
     eprintln!(
-        "  1. Restart the affected host(s) (Claude Code / Codex) so MCP reconnects to this binary"
+        "  1. Restart the affected host(s) (Claude Code / Codex / Gemini CLI) so MCP reconnects to this binary"
     );
     eprintln!("  2. remem will automatically capture your sessions (hosts with hook support)");
     eprintln!("  3. Run 'remem doctor' to check hook/MCP paths and stale MCP processes");
@@ -197,15 +194,21 @@ fn repair_install(
     dry_run: bool,
     bin: &str,
     hosts: Vec<Box<dyn crate::install::host::InstallHost>>,
+    with_cursor: bool,
 ) -> Result<()> {
     let repairable_hosts = hosts.iter().filter(|host| host.name() == "claude").count();
-    if repairable_hosts == 0 {
+    if repairable_hosts == 0 && !with_cursor {
         if matches!(target, InstallTarget::Auto) {
             bail!(
-                "没有检测到 Claude 配置；`--repair` 首版只支持 Claude hooks。请使用 `remem install --target claude --repair` 强制修复 Claude。"
+                "没有检测到 Claude/Cursor 配置；`--repair` 只支持 Claude 与 Cursor hooks。请使用 `remem install --target claude --repair` 强制修复 Claude。"
             );
         }
-        bail!("`--repair` 首版只支持 Claude hooks；target={target:?} 没有可修复 host");
+        bail!("`--repair` 只支持 Claude 与 Cursor hooks；target={target:?} 没有可修复 host");
+    }
+    // Like install, a malformed/collision/uninstalled Cursor state fails
+    // before any Claude hook is rewritten.
+    if with_cursor && !dry_run {
+        cursor::preflight(CursorOperation::Repair, bin)?;
     }
 
     if dry_run {
@@ -224,6 +227,9 @@ fn repair_install(
                 eprintln!("  repair skipped: unsupported in this release");
             }
         }
+        if with_cursor {
+            print_cursor_dry_run(CursorOperation::Repair, bin);
+        }
         print_install_path_warnings(bin);
         return Ok(());
     }
@@ -254,6 +260,18 @@ fn repair_install(
         }
         repaired += 1;
     }
+    if with_cursor {
+        eprintln!("→ cursor");
+        let plan = cursor::apply(CursorOperation::Repair, bin)?;
+        eprintln!(
+            "  hooks  -> {} [{}]",
+            plan.hooks.snapshot.path.display(),
+            plan.hooks.action.label()
+        );
+        eprintln!("  MCP    read-only diagnostic; no writes");
+        eprintln!("  {}", cursor::CURSOR_HOOK_FAILURE_POLICY_LINE);
+        repaired += 1;
+    }
     ensure!(repaired > 0, "no repairable hooks were repaired");
     eprintln!("  binary -> {}", bin);
     print_install_path_warnings(bin);
@@ -282,6 +300,22 @@ pub(in crate::install) fn ensure_runtime_store_ready() -> Result<RuntimeStoreRea
         });
     }
 
+    if crate::db::configured_key_command()?.is_some() {
+        ensure!(
+            !db_path.exists() || !crate::db::sqlite_file_is_plaintext(&db_path)?,
+            "encryption.key_command is set but {} is still plaintext; run `remem encrypt` to encrypt it with that key, then rerun `remem install`",
+            db_path.display()
+        );
+        let schema_version = migrate_runtime_store(&key_path, &db_path)?;
+        return Ok(RuntimeStoreReady {
+            key_path,
+            db_path,
+            schema_version,
+            created_key: false,
+            encrypted_existing_db: false,
+        });
+    }
+
     if env_cipher_key_is_set()? {
         bail!(
             "REMEM_CIPHER_KEY is set but {} is missing; unset REMEM_CIPHER_KEY and run `remem install` again to create a persistent key file, or write the same key to {} before running `remem status`",
@@ -459,6 +493,7 @@ fn runtime_host_name(install_host: &str) -> &'static str {
         // GH-824: the canonical cursor identity must never degrade to
         // `hosts.unknown` (B-002).
         "cursor" => crate::runtime_config::CURSOR_HOST,
+        "gemini" => crate::runtime_config::GEMINI_HOST,
         _ => "unknown",
     }
 }
@@ -508,7 +543,7 @@ pub fn uninstall(target: InstallTarget, dry_run: bool) -> Result<()> {
         InstallTarget::All | InstallTarget::Auto => {
             cursor::cursor_detected() && cursor::cursor_renderer_supported()
         }
-        InstallTarget::Claude | InstallTarget::Codex => false,
+        InstallTarget::Claude | InstallTarget::Codex | InstallTarget::Gemini => false,
     };
 
     if dry_run {
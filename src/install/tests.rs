@@ -510,7 +510,7 @@ fn build_hooks_contains_expected_claude_commands() {
         hooks["PreToolUse"][0]["hooks"][0]["command"],
         "/tmp/remem rules eval --host claude-code"
     );
-    assert_eq!(hooks["PreToolUse"][0]["matcher"], "Bash");
+    assert_eq!(hooks["PreToolUse"][0]["matcher"], "*");
     assert_eq!(hooks["PreToolUse"][0]["hooks"][0]["timeout"], 5);
     assert_eq!(
         hooks["Stop"][0]["hooks"][0]["command"],
@@ -51,6 +51,7 @@ mod session_rollup;
 mod spill_queue;
 pub mod summarize;
 pub mod timeline;
+mod toml_json;
 pub mod truth;
 pub mod user_context;
 pub mod worker;
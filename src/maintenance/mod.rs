@@ -4,6 +4,11 @@ use serde::Serialize;
 
 use crate::{db, memory, workstream};
 
+mod redact;
+
+pub(crate) use redact::{apply_stored_secret_redaction, scan_stored_secrets};
+pub use redact::{RedactionColumnSummary, RedactionFinding, RedactionReport};
+
 const CLEANUP_POLICY_VERSION: i64 = 1;
 const FAILURE_ERROR_LIMIT_BYTES: usize = 1_000;
 
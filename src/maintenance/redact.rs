@@ -0,0 +1,566 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use ring::hmac;
+use rusqlite::{params, types::Value, Connection, ErrorCode, TransactionBehavior};
+use serde::Serialize;
+
+use crate::adapter::redaction::SecretDetectors;
+
+const SCAN_BATCH_SIZE: i64 = 500;
+const MAX_REPORTED_FINDINGS: usize = 50;
+/// Data-directory file holding the HMAC key for `redaction_audit.original_hash`.
+/// It lives outside the database so a copy of the database alone cannot be
+/// used to confirm guesses of a short redacted value.
+const AUDIT_KEY_FILE: &str = "redaction_audit.key";
+const AUDIT_KEY_BYTES: usize = 32;
+
+/// Stored text a secret can hide in. FTS tables follow through the `*_au`
+/// triggers; memory embeddings and search context are invalidated by
+/// `memories_au`.
+struct RedactionTarget {
+    table: &'static str,
+    /// SQL expression for the owning project, matched by `--project` and
+    /// recorded in the audit row.
+    project: &'static str,
+    columns: &'static [&'static str],
+    format: StoredFormat,
+    fts_table: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StoredFormat {
+    Text,
+    /// `event_blobs.content_bytes`; only `plain` blobs hold readable text.
+    PlainBlob,
+    /// A JSON document scrubbed value by value so it stays parseable.
+    Json,
+}
+
+const REDACTION_TARGETS: &[RedactionTarget] = &[
+    RedactionTarget {
+        table: "raw_messages",
+        project: "project",
+        columns: &["content"],
+        format: StoredFormat::Text,
+        fts_table: Some("raw_messages_fts"),
+    },
+    RedactionTarget {
+        table: "observations",
+        project: "project",
+        columns: &["title", "subtitle", "narrative", "facts", "concepts"],
+        format: StoredFormat::Text,
+        fts_table: Some("observations_fts"),
+    },
+    RedactionTarget {
+        table: "memories",
+        project: "project",
+        columns: &["title", "content"],
+        format: StoredFormat::Text,
+        fts_table: Some("memories_fts"),
+    },
+    RedactionTarget {
+        table: "memory_candidates",
+        project: "COALESCE(target_project,
+            (SELECT project_key FROM projects WHERE projects.id = memory_candidates.project_id))",
+        columns: &["text"],
+        format: StoredFormat::Text,
+        fts_table: None,
+    },
+    RedactionTarget {
+        table: "captured_events",
+        project:
+            "(SELECT project_key FROM projects WHERE projects.id = captured_events.project_id)",
+        columns: &["content_text"],
+        format: StoredFormat::Text,
+        fts_table: None,
+    },
+    RedactionTarget {
+        table: "event_blobs",
+        project: "(SELECT projects.project_key FROM captured_events
+            JOIN projects ON projects.id = captured_events.project_id
+            WHERE captured_events.content_blob_id = event_blobs.id
+            ORDER BY captured_events.id LIMIT 1)",
+        columns: &["content_bytes"],
+        format: StoredFormat::PlainBlob,
+        fts_table: None,
+    },
+    RedactionTarget {
+        table: "session_summaries",
+        project: "project",
+        columns: &[
+            "request",
+            "completed",
+            "decisions",
+            "learned",
+            "next_steps",
+            "preferences",
+            "summary_text",
+        ],
+        format: StoredFormat::Text,
+        fts_table: None,
+    },
+    RedactionTarget {
+        table: "session_turns",
+        project: "project",
+        columns: &["understanding", "actions_summary", "result_summary"],
+        format: StoredFormat::Text,
+        fts_table: None,
+    },
+    RedactionTarget {
+        table: "session_turn_actions",
+        project: "(SELECT project FROM session_turns
+            WHERE session_turns.id = session_turn_actions.session_turn_id)",
+        columns: &["summary"],
+        format: StoredFormat::Text,
+        fts_table: None,
+    },
+    // Memory titles ride along in `memory_saved` payloads; the rewrite above
+    // appends a clean event, this scrubs the ones already in the feed.
+    RedactionTarget {
+        table: "api_stream_events",
+        project: "project",
+        columns: &["payload_json"],
+        format: StoredFormat::Json,
+        fts_table: None,
+    },
+];
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct RedactionColumnSummary {
+    pub table: String,
+    pub column: String,
+    pub rows: usize,
+    pub matches: usize,
+    /// Detector name -> match count.
+    pub detectors: BTreeMap<String, usize>,
+}
+
+/// One column value holding secrets. The secret text is never reported.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct RedactionFinding {
+    pub table: String,
+    pub column: String,
+    pub row_id: i64,
+    pub project: Option<String>,
+    pub detectors: Vec<String>,
+    pub matches: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct RedactionReport {
+    pub applied: bool,
+    /// `redaction_audit.run_id` of an applied run.
+    pub run_id: Option<String>,
+    pub project: Option<String>,
+    pub rows: usize,
+    pub matches: usize,
+    pub columns: Vec<RedactionColumnSummary>,
+    /// First findings in table order, capped for terminal output.
+    pub findings: Vec<RedactionFinding>,
+    pub findings_truncated: bool,
+    /// Memories whose embeddings were dropped for re-embedding.
+    pub memory_embeddings_invalidated: usize,
+}
+
+struct PendingRewrite {
+    finding: RedactionFinding,
+    format: StoredFormat,
+    original: String,
+    scrubbed: String,
+}
+
+/// Report stored secrets without changing anything.
+pub(crate) fn scan_stored_secrets(
+    conn: &Connection,
+    detectors: &SecretDetectors,
+    project: Option<&str>,
+) -> Result<RedactionReport> {
+    let rewrites = collect_rewrites(conn, detectors, project)?;
+    Ok(summarize(project, &rewrites))
+}
+
+/// Replace every stored secret with `[REDACTED]` in one transaction and record
+/// a `redaction_audit` row per rewritten value, keyed-hashed with the
+/// data directory's audit key. FTS indexes are optimized afterwards so the
+/// old postings are merged away.
+pub(crate) fn apply_stored_secret_redaction(
+    conn: &mut Connection,
+    detectors: &SecretDetectors,
+    project: Option<&str>,
+    data_dir: &Path,
+    now_epoch: i64,
+) -> Result<RedactionReport> {
+    conn.pragma_update(None, "secure_delete", true)
+        .context("enable secure_delete for redaction")?;
+    let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+    let rewrites = collect_rewrites(&tx, detectors, project)?;
+    let mut report = summarize(project, &rewrites);
+    report.applied = true;
+    if rewrites.is_empty() {
+        tx.commit()?;
+        return Ok(report);
+    }
+
+    let audit_key = load_or_create_audit_key(data_dir)?;
+    let run_id = new_run_id(now_epoch)?;
+    let memory_ids = rewrites
+        .iter()
+        .filter(|rewrite| rewrite.finding.table == "memories")
+        .map(|rewrite| rewrite.finding.row_id)
+        .collect::<HashSet<_>>();
+    report.memory_embeddings_invalidated = count_embedded_memories(&tx, &memory_ids)?;
+    for rewrite in &rewrites {
+        rewrite_value(&tx, rewrite)?;
+        tx.execute(
+            "INSERT INTO redaction_audit
+             (run_id, table_name, row_id, column_name, project, detectors, match_count,
+              original_hash, redacted_at_epoch)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                run_id,
+                rewrite.finding.table,
+                rewrite.finding.row_id,
+                rewrite.finding.column,
+                rewrite.finding.project,
+                rewrite.finding.detectors.join(","),
+                rewrite.finding.matches as i64,
+                audit_hash(&audit_key, &rewrite.original),
+                now_epoch,
+            ],
+        )?;
+    }
+    tx.commit()?;
+
+    let touched = rewrites
+        .iter()
+        .map(|rewrite| rewrite.finding.table.as_str())
+        .collect::<HashSet<_>>();
+    for target in REDACTION_TARGETS {
+        let Some(fts_table) = target.fts_table else {
+            continue;
+        };
+        if touched.contains(target.table) {
+            conn.execute(
+                &format!("INSERT INTO {fts_table}({fts_table}) VALUES ('optimize')"),
+                [],
+            )
+            .with_context(|| format!("optimize {fts_table} after redaction"))?;
+        }
+    }
+    report.run_id = Some(run_id);
+    Ok(report)
+}
+
+fn collect_rewrites(
+    conn: &Connection,
+    detectors: &SecretDetectors,
+    project: Option<&str>,
+) -> Result<Vec<PendingRewrite>> {
+    let mut rewrites = Vec::new();
+    for target in REDACTION_TARGETS {
+        let columns = target.columns.join(", ");
+        let plain_only = if target.format == StoredFormat::PlainBlob {
+            "AND content_encoding = 'plain'"
+        } else {
+            ""
+        };
+        let sql = format!(
+            "SELECT id, {project}, {columns} FROM {table}
+             WHERE id > ?1 AND (?2 IS NULL OR {project} = ?2) {plain_only}
+             ORDER BY id
+             LIMIT ?3",
+            project = target.project,
+            table = target.table
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let mut after_id = 0_i64;
+        loop {
+            let rows = stmt
+                .query_map(params![after_id, project, SCAN_BATCH_SIZE], |row| {
+                    let mut values = Vec::with_capacity(target.columns.len());
+                    for index in 0..target.columns.len() {
+                        values.push(stored_text(row.get::<_, Value>(index + 2)?));
+                    }
+                    Ok((
+                        row.get::<_, i64>(0)?,
+                        row.get::<_, Option<String>>(1)?,
+                        values,
+                    ))
+                })?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            let Some((last_id, _, _)) = rows.last() else {
+                break;
+            };
+            after_id = *last_id;
+            for (row_id, row_project, values) in rows {
+                for (column, value) in target.columns.iter().zip(values) {
+                    let Some(value) = value else {
+                        continue;
+                    };
+                    let Some((scrubbed, mut names, match_count)) =
+                        scrub_stored(detectors, target.format, &value)
+                    else {
+                        continue;
+                    };
+                    names.sort();
+                    names.dedup();
+                    rewrites.push(PendingRewrite {
+                        finding: RedactionFinding {
+                            table: target.table.to_string(),
+                            column: (*column).to_string(),
+                            row_id,
+                            project: row_project.clone(),
+                            detectors: names,
+                            matches: match_count,
+                        },
+                        format: target.format,
+                        scrubbed,
+                        original: value,
+                    });
+                }
+            }
+        }
+    }
+    Ok(rewrites)
+}
+
+fn stored_text(value: Value) -> Option<String> {
+    match value {
+        Value::Text(text) => Some(text),
+        Value::Blob(bytes) => String::from_utf8(bytes).ok(),
+        _ => None,
+    }
+}
+
+/// Scrubbed value, matched detector names and match count, or `None` when
+/// the value is clean.
+fn scrub_stored(
+    detectors: &SecretDetectors,
+    format: StoredFormat,
+    value: &str,
+) -> Option<(String, Vec<String>, usize)> {
+    if format == StoredFormat::Json {
+        if let Ok(mut document) = serde_json::from_str::<serde_json::Value>(value) {
+            let mut names = Vec::new();
+            scrub_json_strings(detectors, &mut document, &mut names);
+            if names.is_empty() {
+                return None;
+            }
+            let count = names.len();
+            return Some((document.to_string(), names, count));
+        }
+    }
+    let (scrubbed, matches) = detectors.scrub(value);
+    if matches.is_empty() {
+        return None;
+    }
+    let names = matches
+        .iter()
+        .map(|found| found.detector.clone())
+        .collect::<Vec<_>>();
+    Some((scrubbed.into_owned(), names, matches.len()))
+}
+
+fn scrub_json_strings(
+    detectors: &SecretDetectors,
+    value: &mut serde_json::Value,
+    names: &mut Vec<String>,
+) {
+    match value {
+        serde_json::Value::String(text) => {
+            let (scrubbed, matches) = detectors.scrub(text);
+            if !matches.is_empty() {
+                names.extend(matches.into_iter().map(|found| found.detector));
+                *text = scrubbed.into_owned();
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                scrub_json_strings(detectors, item, names);
+            }
+        }
+        serde_json::Value::Object(fields) => {
+            for field in fields.values_mut() {
+                scrub_json_strings(detectors, field, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn summarize(project: Option<&str>, rewrites: &[PendingRewrite]) -> RedactionReport {
+    let mut columns: Vec<RedactionColumnSummary> = Vec::new();
+    let mut rows = HashSet::new();
+    let mut matches = 0;
+    for rewrite in rewrites {
+        let finding = &rewrite.finding;
+        rows.insert((finding.table.as_str(), finding.row_id));
+        matches += finding.matches;
+        let position = columns
+            .iter()
+            .position(|summary| summary.table == finding.table && summary.column == finding.column)
+            .unwrap_or_else(|| {
+                columns.push(RedactionColumnSummary {
+                    table: finding.table.clone(),
+                    column: finding.column.clone(),
+                    ..RedactionColumnSummary::default()
+                });
+                columns.len() - 1
+            });
+        let summary = &mut columns[position];
+        summary.rows += 1;
+        summary.matches += finding.matches;
+        for detector in &finding.detectors {
+            *summary.detectors.entry(detector.clone()).or_default() += 1;
+        }
+    }
+    RedactionReport {
+        project: project.map(str::to_string),
+        rows: rows.len(),
+        matches,
+        columns,
+        findings: rewrites
+            .iter()
+            .take(MAX_REPORTED_FINDINGS)
+            .map(|rewrite| rewrite.finding.clone())
+            .collect(),
+        findings_truncated: rewrites.len() > MAX_REPORTED_FINDINGS,
+        ..RedactionReport::default()
+    }
+}
+
+fn rewrite_value(conn: &Connection, rewrite: &PendingRewrite) -> Result<()> {
+    let finding = &rewrite.finding;
+    if finding.table == "raw_messages" {
+        // Keep the identity hash in step with the scrubbed content so a
+        // transcript re-drain matches this row instead of re-archiving it.
+        let hash = crate::db::content_identity_hash(rewrite.scrubbed.trim().as_bytes());
+        match conn.execute(
+            "UPDATE raw_messages SET content = ?1, content_hash = ?2 WHERE id = ?3",
+            params![rewrite.scrubbed, hash, finding.row_id],
+        ) {
+            Ok(_) => return Ok(()),
+            Err(rusqlite::Error::SqliteFailure(error, _))
+                if error.code == ErrorCode::ConstraintViolation => {}
+            Err(error) => return Err(error.into()),
+        }
+    }
+    if rewrite.format == StoredFormat::PlainBlob {
+        let bytes = rewrite.scrubbed.as_bytes();
+        let hash = crate::db::content_identity_hash(bytes);
+        match conn.execute(
+            "UPDATE event_blobs
+             SET content_bytes = ?1, content_hash = ?2, original_bytes = ?3, stored_bytes = ?3
+             WHERE id = ?4",
+            params![bytes, hash, bytes.len() as i64, finding.row_id],
+        ) {
+            Ok(_) => return Ok(()),
+            Err(rusqlite::Error::SqliteFailure(error, _))
+                if error.code == ErrorCode::ConstraintViolation => {}
+            Err(error) => return Err(error.into()),
+        }
+        conn.execute(
+            "UPDATE event_blobs SET content_bytes = ?1, original_bytes = ?2, stored_bytes = ?2
+             WHERE id = ?3",
+            params![bytes, bytes.len() as i64, finding.row_id],
+        )
+        .with_context(|| format!("redact event_blobs row {}", finding.row_id))?;
+        return Ok(());
+    }
+    conn.execute(
+        &format!(
+            "UPDATE {table} SET {column} = ?1 WHERE id = ?2",
+            table = finding.table,
+            column = finding.column
+        ),
+        params![rewrite.scrubbed, finding.row_id],
+    )
+    .with_context(|| {
+        format!(
+            "redact {}.{} row {}",
+            finding.table, finding.column, finding.row_id
+        )
+    })?;
+    Ok(())
+}
+
+fn count_embedded_memories(conn: &Connection, memory_ids: &HashSet<i64>) -> Result<usize> {
+    let mut stmt =
+        conn.prepare_cached("SELECT EXISTS(SELECT 1 FROM memory_embeddings WHERE memory_id = ?1)")?;
+    let mut count = 0;
+    for id in memory_ids {
+        if stmt.query_row([id], |row| row.get::<_, bool>(0))? {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+fn new_run_id(now_epoch: i64) -> Result<String> {
+    let mut nonce = [0_u8; 8];
+    getrandom::fill(&mut nonce)
+        .map_err(|error| anyhow::anyhow!("generate redaction run id: {error}"))?;
+    Ok(format!(
+        "redact:{now_epoch}:{:016x}",
+        u64::from_ne_bytes(nonce)
+    ))
+}
+
+fn audit_hash(key: &hmac::Key, value: &str) -> String {
+    hex_encode(hmac::sign(key, value.as_bytes()).as_ref())
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// The data directory's audit key, generated with owner-only permissions on
+/// first use. A concurrent first run that loses the create race reads the
+/// winner's key.
+fn load_or_create_audit_key(data_dir: &Path) -> Result<hmac::Key> {
+    use std::io::Write;
+
+    std::fs::create_dir_all(data_dir).with_context(|| format!("create {}", data_dir.display()))?;
+    let path = data_dir.join(AUDIT_KEY_FILE);
+    let mut options = std::fs::OpenOptions::new();
+    options.create_new(true).write(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    match options.open(&path) {
+        Ok(mut file) => {
+            let mut key = [0_u8; AUDIT_KEY_BYTES];
+            getrandom::fill(&mut key)
+                .map_err(|error| anyhow::anyhow!("generate redaction audit key: {error}"))?;
+            file.write_all(hex_encode(&key).as_bytes())
+                .and_then(|()| file.sync_all())
+                .with_context(|| format!("write {}", path.display()))?;
+            Ok(hmac::Key::new(hmac::HMAC_SHA256, &key))
+        }
+        Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => {
+            let encoded = std::fs::read_to_string(&path)
+                .with_context(|| format!("read {}", path.display()))?;
+            let key = decode_audit_key(encoded.trim())
+                .with_context(|| format!("{} is not a 64-hex key", path.display()))?;
+            Ok(hmac::Key::new(hmac::HMAC_SHA256, &key))
+        }
+        Err(error) => Err(error).with_context(|| format!("create {}", path.display())),
+    }
+}
+
+fn decode_audit_key(encoded: &str) -> Option<Vec<u8>> {
+    if encoded.len() != AUDIT_KEY_BYTES * 2 {
+        return None;
+    }
+    (0..encoded.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(encoded.get(index..index + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,288 @@
+use anyhow::Result;
+use rusqlite::Connection;
+
+use sha2::Digest;
+
+use super::*;
+use crate::db::test_support::ScopedTestDataDir;
+
+const PROJECT: &str = "/tmp/remem-redact";
+
+fn github_token() -> String {
+    format!("ghp_{}", "a1B2c3D4e5F6g7H8i9J0".repeat(2))
+}
+
+fn setup() -> Result<(Connection, i64)> {
+    let conn = Connection::open_in_memory()?;
+    crate::migrate::run_migrations(&conn)?;
+    let token = github_token();
+    conn.execute(
+        "INSERT INTO raw_messages
+         (id, session_id, project, role, content, content_hash, source, created_at_epoch)
+         VALUES (1, 's1', ?1, 'user', ?2, 'legacy-hash', 'hook', 1)",
+        params![PROJECT, format!("use {token} for the push")],
+    )?;
+    conn.execute(
+        "INSERT INTO observations (id, memory_session_id, project, type, title, narrative)
+         VALUES (1, 's1', ?1, 'discovery', 'Push setup', ?2)",
+        params![PROJECT, format!("Configured remote with {token}")],
+    )?;
+    conn.execute(
+        "INSERT INTO observations (id, memory_session_id, project, type, title, narrative)
+         VALUES (2, 's1', '/tmp/other', 'discovery', 'Other', ?1)",
+        params![format!("Other project {token}")],
+    )?;
+    let memory_id = crate::memory::insert_memory(
+        &conn,
+        None,
+        PROJECT,
+        None,
+        "Deploy credentials",
+        &format!("CI pushes with {token}"),
+        "discovery",
+        None,
+    )?;
+    conn.execute(
+        "INSERT INTO memory_embeddings
+         (memory_id, embedding, dimensions, model, content_hash, updated_at_epoch)
+         VALUES (?1, x'00', 1, 'test', 'h', 1)",
+        [memory_id],
+    )?;
+    Ok((conn, memory_id))
+}
+
+fn fts_hits(conn: &Connection, table: &str, needle: &str) -> Result<i64> {
+    Ok(conn.query_row(
+        &format!("SELECT COUNT(*) FROM {table} WHERE {table} MATCH ?1"),
+        [format!("\"{needle}\"")],
+        |row| row.get(0),
+    )?)
+}
+
+#[test]
+fn scan_reports_findings_per_column_without_mutating() -> Result<()> {
+    let (conn, memory_id) = setup()?;
+    let detectors = SecretDetectors::builtin();
+    let report = scan_stored_secrets(&conn, &detectors, Some(PROJECT))?;
+    assert!(!report.applied);
+    assert_eq!(report.rows, 3);
+    assert_eq!(
+        report
+            .findings
+            .iter()
+            .map(|finding| (
+                finding.table.as_str(),
+                finding.column.as_str(),
+                finding.row_id
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            ("raw_messages", "content", 1),
+            ("observations", "narrative", 1),
+            ("memories", "content", memory_id),
+        ]
+    );
+    assert_eq!(report.columns[0].detectors["github_token"], 1);
+    assert_eq!(scan_stored_secrets(&conn, &detectors, None)?.rows, 4);
+    let content: String =
+        conn.query_row("SELECT content FROM raw_messages WHERE id = 1", [], |row| {
+            row.get(0)
+        })?;
+    assert!(content.contains(&github_token()));
+    Ok(())
+}
+
+#[test]
+fn apply_scrubs_rows_indexes_and_embeddings_with_an_audit_trail() -> Result<()> {
+    let (mut conn, memory_id) = setup()?;
+    let data_dir = ScopedTestDataDir::new("redact-apply");
+    let detectors = SecretDetectors::builtin();
+    let token = github_token();
+    assert_eq!(fts_hits(&conn, "memories_fts", &token)?, 1);
+
+    let report =
+        apply_stored_secret_redaction(&mut conn, &detectors, Some(PROJECT), &data_dir.path, 1_000)?;
+    assert!(report.applied);
+    assert_eq!(report.matches, 3);
+    assert_eq!(report.memory_embeddings_invalidated, 1);
+    let run_id = report.run_id.expect("run id");
+
+    let (content, hash): (String, String) = conn.query_row(
+        "SELECT content, content_hash FROM raw_messages WHERE id = 1",
+        [],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )?;
+    assert_eq!(content, "use [REDACTED] for the push");
+    assert_eq!(hash, crate::db::content_identity_hash(content.as_bytes()));
+    let memory_text: String = conn.query_row(
+        "SELECT content FROM memories WHERE id = ?1",
+        [memory_id],
+        |row| row.get(0),
+    )?;
+    assert_eq!(memory_text, "CI pushes with [REDACTED]");
+    // Observation 2 belongs to another project and keeps its token.
+    for (table, hits) in [
+        ("raw_messages_fts", 0),
+        ("observations_fts", 1),
+        ("memories_fts", 0),
+    ] {
+        assert_eq!(fts_hits(&conn, table, &token)?, hits, "{table}");
+    }
+    let embeddings: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM memory_embeddings WHERE memory_id = ?1",
+        [memory_id],
+        |row| row.get(0),
+    )?;
+    assert_eq!(embeddings, 0);
+
+    let audit: Vec<(String, String, String)> = {
+        let mut stmt = conn.prepare(
+            "SELECT table_name, detectors, original_hash FROM redaction_audit
+             WHERE run_id = ?1 ORDER BY id",
+        )?;
+        let rows = stmt.query_map([&run_id], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?;
+        rows.collect::<rusqlite::Result<_>>()?
+    };
+    assert_eq!(audit.len(), 3);
+    assert!(audit
+        .iter()
+        .all(|(_, detectors, hash)| detectors == "github_token" && hash.len() == 64));
+    assert!(audit.iter().all(|(_, _, hash)| !hash.contains(&token)));
+    let raw_original = format!("use {token} for the push");
+    let unkeyed = format!("{:x}", sha2::Sha256::digest(raw_original.as_bytes()));
+    assert!(
+        audit.iter().all(|(_, _, hash)| *hash != unkeyed),
+        "audit hashes must not be a plain digest of the secret"
+    );
+    let key = load_or_create_audit_key(&data_dir.path)?;
+    assert_eq!(audit[0].0, "raw_messages");
+    assert_eq!(audit[0].2, audit_hash(&key, &raw_original));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(data_dir.path.join(AUDIT_KEY_FILE))?
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    let other: String = conn.query_row(
+        "SELECT narrative FROM observations WHERE id = 2",
+        [],
+        |row| row.get(0),
+    )?;
+    assert!(other.contains(&token), "other projects stay untouched");
+    let again =
+        apply_stored_secret_redaction(&mut conn, &detectors, Some(PROJECT), &data_dir.path, 2_000)?;
+    assert_eq!((again.matches, again.run_id), (0, None));
+    Ok(())
+}
+
+#[test]
+fn apply_reaches_derived_tables_and_the_event_stream() -> Result<()> {
+    let (mut conn, _) = setup()?;
+    let detectors = SecretDetectors::builtin();
+    let token = github_token();
+    let titled = crate::memory::insert_memory(
+        &conn,
+        None,
+        PROJECT,
+        None,
+        &format!("Token {token}"),
+        "Rotated after the leak",
+        "discovery",
+        None,
+    )?;
+    conn.execute_batch(&format!(
+        "INSERT INTO hosts (id, name, created_at_epoch) VALUES (90, 'redact-test', 1);
+         INSERT INTO workspaces (id, root_path, created_at_epoch, updated_at_epoch)
+         VALUES (1, '{PROJECT}', 1, 1);
+         INSERT INTO projects
+         (id, workspace_id, project_path, project_key, created_at_epoch, updated_at_epoch)
+         VALUES (1, 1, '{PROJECT}', '{PROJECT}', 1, 1);
+         INSERT INTO sessions
+         (id, host_id, workspace_id, project_id, session_id, last_seen_at_epoch, status)
+         VALUES (1, 90, 1, 1, 's1', 1, 'active');
+         INSERT INTO event_blobs
+         (id, content_hash, content_encoding, content_bytes, original_bytes, stored_bytes,
+          created_at_epoch)
+         VALUES (1, 'blob-hash', 'plain', CAST('git push with {token}' AS BLOB), 1, 1, 1);
+         INSERT INTO captured_events
+         (id, host_id, workspace_id, project_id, session_row_id, session_id, event_id,
+          event_type, content_text, content_blob_id, content_hash, retention_class,
+          created_at_epoch, inserted_at_epoch)
+         VALUES (1, 90, 1, 1, 1, 's1', 'e1', 'tool_use', 'git push with {token}', 1,
+                 'event-hash', 'raw_compact', 1, 1);
+         INSERT INTO memory_candidates
+         (id, project_id, scope, memory_type, topic_key, text, evidence_event_ids,
+          confidence, risk_class, review_status, created_at_epoch, updated_at_epoch)
+         VALUES (1, 1, 'project', 'discovery', 'push', 'Push uses {token}', '[1]',
+                 0.9, 'low', 'pending', 1, 1);
+         INSERT INTO session_summaries (id, memory_session_id, project, learned)
+         VALUES (1, 's1', '{PROJECT}', 'The push token is {token}');
+         INSERT INTO session_turns
+         (id, source_root, project, session_id, turn_index, user_message_id, result_status,
+          result_summary, started_at_epoch, capture_health, source_digest,
+          projection_version, created_at_epoch, updated_at_epoch)
+         VALUES (1, 'hook', '{PROJECT}', 's1', 1, 1, 'done', 'Pushed with {token}', 1,
+                 'full', 'digest', 1, 1, 1);"
+    ))?;
+    let stream_hits = |conn: &Connection| -> Result<i64> {
+        Ok(conn.query_row(
+            "SELECT COUNT(*) FROM api_stream_events WHERE instr(payload_json, ?1) > 0",
+            [&token],
+            |row| row.get(0),
+        )?)
+    };
+    assert_eq!(stream_hits(&conn)?, 1);
+
+    let data_dir = ScopedTestDataDir::new("redact-derived");
+    let report =
+        apply_stored_secret_redaction(&mut conn, &detectors, Some(PROJECT), &data_dir.path, 1_000)?;
+    let tables = report
+        .columns
+        .iter()
+        .map(|summary| summary.table.as_str())
+        .collect::<HashSet<_>>();
+    for table in [
+        "memory_candidates",
+        "captured_events",
+        "event_blobs",
+        "session_summaries",
+        "session_turns",
+        "api_stream_events",
+    ] {
+        assert!(tables.contains(table), "{table} not redacted");
+    }
+    assert_eq!(stream_hits(&conn)?, 0);
+    let payloads = {
+        let mut stmt = conn.prepare(
+            "SELECT payload_json FROM api_stream_events
+             WHERE resource_kind = 'memory' AND resource_id = ?1",
+        )?;
+        let rows = stmt.query_map([titled], |row| row.get::<_, String>(0))?;
+        rows.collect::<rusqlite::Result<Vec<_>>>()?
+    };
+    assert!(!payloads.is_empty());
+    for payload in payloads {
+        let payload: serde_json::Value = serde_json::from_str(&payload)?;
+        assert_eq!(payload["title"], "Token [REDACTED]");
+    }
+    let (blob, blob_hash): (Vec<u8>, String) = conn.query_row(
+        "SELECT content_bytes, content_hash FROM event_blobs WHERE id = 1",
+        [],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )?;
+    assert_eq!(blob, b"git push with [REDACTED]");
+    assert_eq!(blob_hash, crate::db::content_identity_hash(&blob));
+    let leftovers: i64 = conn.query_row(
+        "SELECT (SELECT COUNT(*) FROM memory_candidates WHERE instr(text, ?1) > 0)
+              + (SELECT COUNT(*) FROM captured_events WHERE instr(content_text, ?1) > 0)
+              + (SELECT COUNT(*) FROM session_summaries WHERE instr(learned, ?1) > 0)
+              + (SELECT COUNT(*) FROM session_turns WHERE instr(result_summary, ?1) > 0)",
+        [&token],
+        |row| row.get(0),
+    )?;
+    assert_eq!(leftovers, 0);
+    Ok(())
+}
@@ -1,10 +1,14 @@
 mod commit_tools;
 mod context_tools;
 mod errors;
+mod http;
+mod prompts;
 mod raw_tools;
+mod resources;
 mod runtime;
 mod search_routing;
 mod search_tools;
+mod subscriptions;
 #[cfg(test)]
 mod tests;
 mod tool_contracts;
@@ -19,6 +23,7 @@ use errors::{McpToolError, McpToolResult};
 #[derive(Clone)]
 pub(super) struct MemoryServer {
     tool_router: ToolRouter<Self>,
+    subscriptions: subscriptions::ResourceSubscriptions,
 }
 
 impl MemoryServer {
@@ -30,7 +35,10 @@ impl MemoryServer {
             + Self::tool_router_workstream()
             + Self::tool_router_raw();
         tool_contracts::apply(&mut tool_router)?;
-        Ok(Self { tool_router })
+        Ok(Self {
+            tool_router,
+            subscriptions: subscriptions::ResourceSubscriptions::default(),
+        })
     }
 
     fn with_conn<F, T>(&self, tool: &'static str, f: F) -> McpToolResult<T>
@@ -0,0 +1,161 @@
+//! Streamable-HTTP transport for `remem mcp --listen`.
+//!
+//! One long-lived server on loopback can be shared by every host on the
+//! workstation instead of each host spawning its own stdio process. Requests
+//! must carry the REST API bearer token (`Authorization: Bearer
+//! $(cat ~/.remem/.api-token)`); each MCP session gets its own
+//! `MemoryServer`, so resource subscriptions stay per client.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context, Result};
+use axum::{middleware, Router};
+use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
+use rmcp::transport::streamable_http_server::{StreamableHttpServerConfig, StreamableHttpService};
+
+use super::MemoryServer;
+
+pub(super) const MCP_HTTP_PATH: &str = "/mcp";
+
+pub(super) async fn run_mcp_http_server(addr: SocketAddr) -> Result<()> {
+    if !addr.ip().is_loopback() {
+        return Err(anyhow!(
+            "remem mcp --listen only binds loopback addresses (got {addr}); \
+             use 127.0.0.1:PORT or [::1]:PORT"
+        ));
+    }
+    let token_path = crate::api::ensure_api_token()?;
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("bind MCP listener on {addr}"))?;
+    let local_addr = listener.local_addr()?;
+
+    crate::log::info(
+        "mcp",
+        &format!("streamable HTTP listening on http://{local_addr}{MCP_HTTP_PATH}"),
+    );
+    println!(
+        "remem MCP v{} on http://{}{}",
+        env!("CARGO_PKG_VERSION"),
+        local_addr,
+        MCP_HTTP_PATH
+    );
+    println!(
+        "API token: Authorization: Bearer $(cat {})",
+        token_path.display()
+    );
+
+    serve_mcp_http(listener, async {
+        let _ = tokio::signal::ctrl_c().await;
+    })
+    .await
+}
+
+/// Serve the MCP endpoint on an already-bound listener until `shutdown`
+/// resolves, then terminate every open session.
+pub(super) async fn serve_mcp_http(
+    listener: tokio::net::TcpListener,
+    shutdown: impl std::future::Future<Output = ()> + Send + 'static,
+) -> Result<()> {
+    let config = StreamableHttpServerConfig::default();
+    let sessions = config.cancellation_token.clone();
+    let app = build_mcp_http_router(config);
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async move {
+            shutdown.await;
+            sessions.cancel();
+        })
+        .await?;
+    crate::log::info("mcp", "streamable HTTP server stopped");
+    Ok(())
+}
+
+fn build_mcp_http_router(config: StreamableHttpServerConfig) -> Router {
+    let service = StreamableHttpService::new(
+        || MemoryServer::new().map_err(std::io::Error::other),
+        Arc::new(LocalSessionManager::default()),
+        config,
+    );
+    Router::new()
+        .nest_service(MCP_HTTP_PATH, service)
+        .layer(middleware::from_fn(crate::api::require_api_token))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::db::test_support::ScopedTestDataDir;
+
+    fn initialize_body() -> String {
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-03-26",
+                "capabilities": {},
+                "clientInfo": { "name": "remem-http-test", "version": "1" }
+            }
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn http_transport_requires_bearer_token_and_initializes_session() -> Result<()> {
+        let _dir = ScopedTestDataDir::new("mcp-http-transport");
+        crate::api::ensure_api_token()?;
+        let token = crate::api::load_api_token()?;
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}{MCP_HTTP_PATH}", listener.local_addr()?);
+        let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+        let server = tokio::spawn(serve_mcp_http(listener, async {
+            let _ = stopped.await;
+        }));
+
+        let client = reqwest::Client::new();
+        let unauthorized = client
+            .post(&url)
+            .header("accept", "application/json, text/event-stream")
+            .header("content-type", "application/json")
+            .body(initialize_body())
+            .send()
+            .await?;
+        assert_eq!(unauthorized.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+        let response = client
+            .post(&url)
+            .bearer_auth(&token)
+            .header("accept", "application/json, text/event-stream")
+            .header("content-type", "application/json")
+            .body(initialize_body())
+            .send()
+            .await?;
+        assert!(response.status().is_success(), "{}", response.status());
+        assert!(response.headers().contains_key("mcp-session-id"));
+        let body = response.text().await?;
+        let message = body
+            .lines()
+            .filter_map(|line| line.strip_prefix("data:"))
+            .map(str::trim)
+            .find(|data| !data.is_empty())
+            .ok_or_else(|| anyhow!("initialize response had no SSE data: {body}"))?;
+        let message: Value = serde_json::from_str(message)?;
+        assert_eq!(message["id"], 1);
+        assert!(message["result"]["capabilities"]["tools"].is_object());
+
+        let _ = stop.send(());
+        server.await??;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn http_transport_rejects_non_loopback_bind() {
+        let err = run_mcp_http_server("0.0.0.0:0".parse().expect("addr"))
+            .await
+            .expect_err("wildcard bind must be rejected");
+        assert!(err.to_string().contains("loopback"), "{err}");
+    }
+}
@@ -0,0 +1,222 @@
+//! MCP prompts built from the compiled ContextBundle. Each prompt returns a
+//! single user message that carries the bundle rendered as Markdown, so a
+//! host can start a task with remem context without issuing tool calls.
+
+use std::fmt::Write as _;
+
+use rmcp::model::{
+    GetPromptResult, JsonObject, Prompt, PromptArgument, PromptMessage, PromptMessageRole,
+};
+use rusqlite::Connection;
+
+use super::resources::{compile_project_bundle, SurfaceError};
+use crate::context_bundle::{ContextBundle, ContextItem};
+
+pub(super) const RESUME_WORKSTREAM: &str = "resume_workstream";
+pub(super) const EXPLAIN_DECISION: &str = "explain_decision";
+
+pub(super) fn prompt_definitions() -> Vec<Prompt> {
+    vec![
+        Prompt::new(
+            RESUME_WORKSTREAM,
+            Some(
+                "Resume a workstream with its progress, next action and blockers plus the \
+                 compiled project context. Without workstream_id the most recently updated \
+                 active workstream is used.",
+            ),
+            Some(vec![
+                argument("project", "Project key the workstream belongs to.", true),
+                argument(
+                    "workstream_id",
+                    "Workstream id from the workstreams tool or resource.",
+                    false,
+                ),
+            ]),
+        ),
+        Prompt::new(
+            EXPLAIN_DECISION,
+            Some(
+                "Explain a past decision from curated project memory. The compiled context is \
+                 attached and the answer must cite memory ids.",
+            ),
+            Some(vec![
+                argument("project", "Project key to explain the decision for.", true),
+                argument(
+                    "topic",
+                    "Decision topic, e.g. 'why SQLCipher over plain SQLite'.",
+                    true,
+                ),
+            ]),
+        ),
+    ]
+}
+
+fn argument(name: &str, description: &str, required: bool) -> PromptArgument {
+    PromptArgument {
+        name: name.to_string(),
+        title: None,
+        description: Some(description.to_string()),
+        required: Some(required),
+    }
+}
+
+pub(super) fn get_prompt(
+    conn: &Connection,
+    name: &str,
+    arguments: Option<&JsonObject>,
+) -> Result<GetPromptResult, SurfaceError> {
+    let project = required_argument(arguments, "project")?;
+    match name {
+        RESUME_WORKSTREAM => resume_workstream(conn, &project, arguments),
+        EXPLAIN_DECISION => {
+            let topic = required_argument(arguments, "topic")?;
+            explain_decision(conn, &project, &topic)
+        }
+        other => Err(SurfaceError::InvalidParams(format!(
+            "unknown prompt '{other}'; expected {RESUME_WORKSTREAM} or {EXPLAIN_DECISION}"
+        ))),
+    }
+}
+
+fn resume_workstream(
+    conn: &Connection,
+    project: &str,
+    arguments: Option<&JsonObject>,
+) -> Result<GetPromptResult, SurfaceError> {
+    let workstreams = crate::workstream::query_workstreams(conn, project, None)?;
+    let workstream = match optional_i64_argument(arguments, "workstream_id")? {
+        Some(id) => workstreams
+            .into_iter()
+            .find(|workstream| workstream.id == id)
+            .ok_or_else(|| {
+                SurfaceError::NotFound(format!("workstream {id} not found in project {project}"))
+            })?,
+        None => workstreams
+            .into_iter()
+            .find(|workstream| workstream.status == crate::workstream::WorkStreamStatus::Active)
+            .ok_or_else(|| {
+                SurfaceError::NotFound(format!("no active workstream in project {project}"))
+            })?,
+    };
+
+    let bundle = compile_project_bundle(
+        conn,
+        project,
+        &format!("Resume workstream: {}", workstream.title),
+    )?;
+    let mut text = String::new();
+    let _ = writeln!(
+        text,
+        "Resume the workstream below. Confirm the next action against the current code \
+         before acting, and update the workstream when progress changes.\n"
+    );
+    let _ = writeln!(
+        text,
+        "# Workstream #{}: {}",
+        workstream.id, workstream.title
+    );
+    let _ = writeln!(text, "- Status: {}", workstream.status.as_str());
+    for (label, value) in [
+        ("Description", &workstream.description),
+        ("Progress", &workstream.progress),
+        ("Next action", &workstream.next_action),
+        ("Blockers", &workstream.blockers),
+    ] {
+        if let Some(value) = value.as_deref().filter(|value| !value.trim().is_empty()) {
+            let _ = writeln!(text, "- {label}: {value}");
+        }
+    }
+    text.push('\n');
+    render_bundle_markdown(&mut text, &bundle);
+    Ok(prompt_result(
+        format!("Resume workstream #{} in {project}", workstream.id),
+        text,
+    ))
+}
+
+fn explain_decision(
+    conn: &Connection,
+    project: &str,
+    topic: &str,
+) -> Result<GetPromptResult, SurfaceError> {
+    let bundle = compile_project_bundle(conn, project, &format!("Explain decision: {topic}"))?;
+    let mut text = String::new();
+    let _ = writeln!(
+        text,
+        "Explain the decision about \"{topic}\" using only the project memory below. \
+         Cite the memory ids you rely on, and say plainly when the memory does not \
+         cover the decision instead of guessing.\n"
+    );
+    render_bundle_markdown(&mut text, &bundle);
+    Ok(prompt_result(
+        format!("Explain decision \"{topic}\" in {project}"),
+        text,
+    ))
+}
+
+fn prompt_result(description: String, text: String) -> GetPromptResult {
+    GetPromptResult {
+        description: Some(description),
+        messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+    }
+}
+
+pub(super) fn render_bundle_markdown(out: &mut String, bundle: &ContextBundle) {
+    let sections: [(&str, &[ContextItem]); 6] = [
+        ("Preferences", &bundle.preferences),
+        ("Failure lessons", &bundle.failure_lessons),
+        ("Current truth", &bundle.current_truth),
+        ("Workstreams", &bundle.workstreams),
+        ("Memory index", &bundle.memory_index),
+        ("Recent sessions", &bundle.recent_sessions),
+    ];
+    let _ = writeln!(out, "# remem context");
+    let mut rendered = 0;
+    for (heading, items) in sections {
+        if items.is_empty() {
+            continue;
+        }
+        let _ = writeln!(out, "\n## {heading}");
+        for item in items {
+            let _ = writeln!(out, "- [{}] {}: {}", item.stable_key, item.title, item.text);
+            rendered += 1;
+        }
+    }
+    if rendered == 0 {
+        let _ = writeln!(
+            out,
+            "\nNo memory was selected for this project (degraded_mode={:?}).",
+            bundle.degraded_mode
+        );
+    }
+}
+
+fn required_argument(arguments: Option<&JsonObject>, name: &str) -> Result<String, SurfaceError> {
+    arguments
+        .and_then(|arguments| arguments.get(name))
+        .and_then(serde_json::Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .ok_or_else(|| SurfaceError::InvalidParams(format!("prompt argument '{name}' is required")))
+}
+
+/// Prompt arguments are strings on the wire, but accept JSON numbers too.
+fn optional_i64_argument(
+    arguments: Option<&JsonObject>,
+    name: &str,
+) -> Result<Option<i64>, SurfaceError> {
+    let Some(value) = arguments.and_then(|arguments| arguments.get(name)) else {
+        return Ok(None);
+    };
+    let parsed = match value {
+        serde_json::Value::Null => return Ok(None),
+        serde_json::Value::Number(number) => number.as_i64(),
+        serde_json::Value::String(text) if text.trim().is_empty() => return Ok(None),
+        serde_json::Value::String(text) => text.trim().parse::<i64>().ok(),
+        _ => None,
+    };
+    parsed.map(Some).ok_or_else(|| {
+        SurfaceError::InvalidParams(format!("prompt argument '{name}' must be an integer"))
+    })
+}
@@ -0,0 +1,405 @@
+//! MCP resources: curated memories, workstreams, timeline reports and the
+//! compiled ContextBundle exposed as `remem://project/<project>/...` URIs so
+//! hosts that support resources can attach memory without a tool call.
+//!
+//! Project keys are usually absolute paths, so the project segment is
+//! percent-encoded. Reads are passive: unlike `get_observations`, reading a
+//! memory resource does not bump its access metadata.
+
+use anyhow::Result;
+use rmcp::model::{
+    AnnotateAble, ErrorData, RawResource, RawResourceTemplate, ReadResourceResult, Resource,
+    ResourceContents, ResourceTemplate,
+};
+use rusqlite::{params, Connection, OptionalExtension};
+
+const URI_PREFIX: &str = "remem://project/";
+const MIME_JSON: &str = "application/json";
+const MIME_MARKDOWN: &str = "text/markdown";
+const LISTED_PROJECT_LIMIT: i64 = 20;
+const LISTED_MEMORIES_PER_PROJECT: i64 = 10;
+const CONTEXT_TASK: &str = "Attach current project memory";
+const CONTEXT_TOKEN_BUDGET: u32 = 4_000;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum ResourceRef {
+    Memory { project: String, id: i64 },
+    Workstreams { project: String },
+    Timeline { project: String },
+    Context { project: String },
+}
+
+impl ResourceRef {
+    pub(super) fn parse(uri: &str) -> Result<Self, SurfaceError> {
+        let invalid =
+            || SurfaceError::InvalidParams(format!("unsupported remem resource URI '{uri}'"));
+        let rest = uri.strip_prefix(URI_PREFIX).ok_or_else(invalid)?;
+        let (encoded_project, path) = rest.split_once('/').ok_or_else(invalid)?;
+        let project = percent_decode(encoded_project).ok_or_else(invalid)?;
+        if project.trim().is_empty() {
+            return Err(invalid());
+        }
+        match path.split('/').collect::<Vec<_>>().as_slice() {
+            ["workstreams"] => Ok(Self::Workstreams { project }),
+            ["timeline"] => Ok(Self::Timeline { project }),
+            ["context"] => Ok(Self::Context { project }),
+            ["memory", id] => {
+                let id = id.parse::<i64>().map_err(|_| invalid())?;
+                Ok(Self::Memory { project, id })
+            }
+            _ => Err(invalid()),
+        }
+    }
+
+    pub(super) fn uri(&self) -> String {
+        let project = percent_encode(self.project());
+        match self {
+            Self::Memory { id, .. } => format!("{URI_PREFIX}{project}/memory/{id}"),
+            Self::Workstreams { .. } => format!("{URI_PREFIX}{project}/workstreams"),
+            Self::Timeline { .. } => format!("{URI_PREFIX}{project}/timeline"),
+            Self::Context { .. } => format!("{URI_PREFIX}{project}/context"),
+        }
+    }
+
+    pub(super) fn project(&self) -> &str {
+        match self {
+            Self::Memory { project, .. }
+            | Self::Workstreams { project }
+            | Self::Timeline { project }
+            | Self::Context { project } => project,
+        }
+    }
+
+    fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Timeline { .. } => MIME_MARKDOWN,
+            _ => MIME_JSON,
+        }
+    }
+}
+
+/// Failure surfaced on the resource/prompt protocol methods. Tool calls keep
+/// their structured `McpToolError` envelope; these methods answer with plain
+/// JSON-RPC errors instead.
+#[derive(Debug)]
+pub(super) enum SurfaceError {
+    InvalidParams(String),
+    NotFound(String),
+    Internal(anyhow::Error),
+}
+
+impl std::fmt::Display for SurfaceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidParams(message) | Self::NotFound(message) => f.write_str(message),
+            Self::Internal(error) => write!(f, "{error:#}"),
+        }
+    }
+}
+
+impl std::error::Error for SurfaceError {}
+
+impl From<anyhow::Error> for SurfaceError {
+    fn from(error: anyhow::Error) -> Self {
+        Self::Internal(error)
+    }
+}
+
+impl From<SurfaceError> for ErrorData {
+    fn from(error: SurfaceError) -> Self {
+        match error {
+            SurfaceError::InvalidParams(message) => ErrorData::invalid_params(message, None),
+            SurfaceError::NotFound(message) => ErrorData::resource_not_found(message, None),
+            SurfaceError::Internal(error) => ErrorData::internal_error(format!("{error:#}"), None),
+        }
+    }
+}
+
+pub(super) fn resource_templates() -> Vec<ResourceTemplate> {
+    [
+        (
+            "remem://project/{project}/memory/{id}",
+            "memory",
+            "One curated memory as JSON. {project} is the percent-encoded project key.",
+            MIME_JSON,
+        ),
+        (
+            "remem://project/{project}/workstreams",
+            "workstreams",
+            "All workstreams for the project as a JSON array.",
+            MIME_JSON,
+        ),
+        (
+            "remem://project/{project}/timeline",
+            "timeline",
+            "Compact Markdown timeline report with activity history and Token ROI.",
+            MIME_MARKDOWN,
+        ),
+        (
+            "remem://project/{project}/context",
+            "context",
+            "Compiled Context Bundle v1 for the project, as served at SessionStart.",
+            MIME_JSON,
+        ),
+    ]
+    .into_iter()
+    .map(|(uri_template, name, description, mime_type)| {
+        RawResourceTemplate {
+            uri_template: uri_template.to_string(),
+            name: name.to_string(),
+            title: None,
+            description: Some(description.to_string()),
+            mime_type: Some(mime_type.to_string()),
+            icons: None,
+        }
+        .no_annotation()
+    })
+    .collect()
+}
+
+pub(super) fn list_resources(conn: &Connection) -> Result<Vec<Resource>> {
+    let mut resources = Vec::new();
+    for project in recent_projects(conn, LISTED_PROJECT_LIMIT)? {
+        for (reference, name) in [
+            (
+                ResourceRef::Context {
+                    project: project.clone(),
+                },
+                "context",
+            ),
+            (
+                ResourceRef::Workstreams {
+                    project: project.clone(),
+                },
+                "workstreams",
+            ),
+            (
+                ResourceRef::Timeline {
+                    project: project.clone(),
+                },
+                "timeline",
+            ),
+        ] {
+            resources.push(resource(&reference, format!("{name}: {project}"), None));
+        }
+        for memory in
+            crate::memory::get_recent_memories(conn, &project, LISTED_MEMORIES_PER_PROJECT)?
+        {
+            let reference = ResourceRef::Memory {
+                project: project.clone(),
+                id: memory.id,
+            };
+            resources.push(resource(
+                &reference,
+                memory.title.clone(),
+                Some(format!("{} memory #{}", memory.memory_type, memory.id)),
+            ));
+        }
+    }
+    Ok(resources)
+}
+
+fn resource(reference: &ResourceRef, name: String, description: Option<String>) -> Resource {
+    let mut raw = RawResource::new(reference.uri(), name);
+    raw.description = description;
+    raw.mime_type = Some(reference.mime_type().to_string());
+    raw.no_annotation()
+}
+
+pub(super) fn read_resource(
+    conn: &Connection,
+    reference: &ResourceRef,
+) -> Result<ReadResourceResult, SurfaceError> {
+    let text = match reference {
+        ResourceRef::Memory { project, id } => {
+            let memory = crate::memory::get_memories_by_ids_with_suppressed_policy(
+                conn,
+                &[*id],
+                Some(project),
+                false,
+            )?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                SurfaceError::NotFound(format!("memory {id} not found in project {project}"))
+            })?;
+            to_json(&memory)?
+        }
+        ResourceRef::Workstreams { project } => {
+            to_json(&crate::workstream::query_workstreams(conn, project, None)?)?
+        }
+        ResourceRef::Timeline { project } => {
+            crate::timeline::generate_timeline_report(conn, project, false)?
+        }
+        ResourceRef::Context { project } => {
+            to_json(&compile_project_bundle(conn, project, CONTEXT_TASK)?)?
+        }
+    };
+    Ok(ReadResourceResult {
+        contents: vec![ResourceContents::TextResourceContents {
+            uri: reference.uri(),
+            mime_type: Some(reference.mime_type().to_string()),
+            text,
+            meta: None,
+        }],
+    })
+}
+
+/// Compile the SessionStart ContextBundle for `project` with the same
+/// defaults as the `context_bundle` tool; the project key doubles as cwd.
+pub(super) fn compile_project_bundle(
+    conn: &Connection,
+    project: &str,
+    task: &str,
+) -> Result<crate::context_bundle::ContextBundle> {
+    let request = crate::context_bundle::ContextRequest {
+        schema_version: crate::context_bundle::CONTEXT_BUNDLE_SCHEMA_VERSION,
+        task: task.to_string(),
+        project: crate::context_bundle::ProjectRef {
+            key: project.to_string(),
+        },
+        branch: None,
+        worktree: Some(project.to_string()),
+        role: crate::context_bundle::AgentRole::Coder,
+        as_of_epoch: 0,
+        token_budget: CONTEXT_TOKEN_BUDGET,
+        risk: crate::context_bundle::RiskClass::Medium,
+        include_superseded: false,
+    };
+    crate::context_bundle::compile_session_start_bundle(conn, &request, project, None, true)
+}
+
+/// Cheap change detector for subscriptions; it never renders the resource.
+pub(super) fn resource_fingerprint(conn: &Connection, reference: &ResourceRef) -> Result<String> {
+    match reference {
+        ResourceRef::Memory { id, .. } => Ok(conn
+            .query_row(
+                "SELECT updated_at_epoch, status FROM memories WHERE id = ?1",
+                [id],
+                |row| {
+                    Ok(format!(
+                        "{}:{}",
+                        row.get::<_, i64>(0)?,
+                        row.get::<_, String>(1)?
+                    ))
+                },
+            )
+            .optional()?
+            .unwrap_or_else(|| "missing".to_string())),
+        ResourceRef::Workstreams { project } => workstream_fingerprint(conn, project),
+        ResourceRef::Timeline { project } => Ok(format!(
+            "{}|{}",
+            memory_fingerprint(conn, project)?,
+            observation_fingerprint(conn, project)?
+        )),
+        ResourceRef::Context { project } => Ok(format!(
+            "{}|{}",
+            memory_fingerprint(conn, project)?,
+            workstream_fingerprint(conn, project)?
+        )),
+    }
+}
+
+/// Fingerprint of the listed resource set; a change triggers
+/// `notifications/resources/list_changed`.
+pub(super) fn list_fingerprint(conn: &Connection) -> Result<String> {
+    Ok(conn.query_row(
+        "SELECT COUNT(*), COALESCE(MAX(id), 0), COALESCE(MAX(updated_at_epoch), 0)
+         FROM memories",
+        [],
+        |row| {
+            Ok(format!(
+                "{}:{}:{}",
+                row.get::<_, i64>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, i64>(2)?
+            ))
+        },
+    )?)
+}
+
+fn memory_fingerprint(conn: &Connection, project: &str) -> Result<String> {
+    Ok(conn.query_row(
+        "SELECT COUNT(*), COALESCE(MAX(updated_at_epoch), 0) FROM memories WHERE project = ?1",
+        params![project],
+        |row| {
+            Ok(format!(
+                "m{}:{}",
+                row.get::<_, i64>(0)?,
+                row.get::<_, i64>(1)?
+            ))
+        },
+    )?)
+}
+
+fn observation_fingerprint(conn: &Connection, project: &str) -> Result<String> {
+    Ok(conn.query_row(
+        "SELECT COUNT(*), COALESCE(MAX(id), 0) FROM observations WHERE project = ?1",
+        params![project],
+        |row| {
+            Ok(format!(
+                "o{}:{}",
+                row.get::<_, i64>(0)?,
+                row.get::<_, i64>(1)?
+            ))
+        },
+    )?)
+}
+
+fn workstream_fingerprint(conn: &Connection, project: &str) -> Result<String> {
+    let workstreams = crate::workstream::query_workstreams(conn, project, None)?;
+    let latest = workstreams
+        .iter()
+        .map(|workstream| workstream.updated_at_epoch)
+        .max()
+        .unwrap_or(0);
+    Ok(format!("w{}:{}", workstreams.len(), latest))
+}
+
+fn recent_projects(conn: &Connection, limit: i64) -> Result<Vec<String>> {
+    let sql = format!(
+        "SELECT project FROM memories
+         WHERE {} AND {}
+         GROUP BY project
+         ORDER BY MAX(updated_at_epoch) DESC, project ASC
+         LIMIT ?1",
+        crate::memory::memory_current_filter_sql("status", "expires_at_epoch", false),
+        crate::memory::suppression::memory_policy_filter_sql("memories"),
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map([limit], |row| row.get::<_, String>(0))?;
+    crate::db::query::collect_rows(rows)
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<String> {
+    Ok(serde_json::to_string_pretty(value)?)
+}
+
+fn percent_encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    out
+}
+
+fn percent_decode(value: &str) -> Option<String> {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+    while idx < bytes.len() {
+        if bytes[idx] == b'%' {
+            let hex = value.get(idx + 1..idx + 3)?;
+            out.push(u8::from_str_radix(hex, 16).ok()?);
+            idx += 3;
+        } else {
+            out.push(bytes[idx]);
+            idx += 1;
+        }
+    }
+    String::from_utf8(out).ok()
+}
@@ -1,8 +1,15 @@
 use anyhow::{Context, Result};
-use rmcp::model::{ServerCapabilities, ServerInfo};
-use rmcp::{tool_handler, ServerHandler, ServiceExt};
+use rmcp::model::{
+    ErrorData, GetPromptRequestParams, GetPromptResult, ListPromptsResult,
+    ListResourceTemplatesResult, ListResourcesResult, PaginatedRequestParams,
+    ReadResourceRequestParams, ReadResourceResult, ServerCapabilities, ServerInfo,
+    SubscribeRequestParams, UnsubscribeRequestParams,
+};
+use rmcp::service::RequestContext;
+use rmcp::{tool_handler, RoleServer, ServerHandler, ServiceExt};
 
-use super::MemoryServer;
+use super::resources::{self, ResourceRef, SurfaceError};
+use super::{prompts, MemoryServer};
 use crate::db;
 
 const SERVER_INSTRUCTIONS: &str = r#"Persistent memory for Claude Code and Codex sessions.
@@ -20,20 +27,121 @@ Persistence:
 
 Workstreams:
 - `workstreams(project, status?)` lists all statuses by default; pass a status to filter tasks.
-- `update_workstream(id, status?, next_action?, blockers?)` updates status, next action, or blockers."#;
+- `create_workstream(project, title, parent_id?, dependencies?, milestones?)` adds a workstream with its plan.
+- `update_workstream(id, status?, next_action?, blockers?, ...)` updates status, next action, blockers, parent, dependencies, or milestones.
+
+Resources and prompts:
+- `remem://project/<project>/{memory/<id>,workstreams,timeline,context}` (project percent-encoded) attach memory without tool calls; subscribe for change notifications.
+- Prompts `resume_workstream(project, workstream_id?)` and `explain_decision(project, topic)` embed the compiled Context Bundle."#;
 
 #[tool_handler]
 impl ServerHandler for MemoryServer {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
             instructions: Some(SERVER_INSTRUCTIONS.into()),
-            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_prompts()
+                .enable_resources()
+                .enable_resources_subscribe()
+                .enable_resources_list_changed()
+                .build(),
             ..Default::default()
         }
     }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, ErrorData> {
+        let conn = open_surface_db()?;
+        let listed = resources::list_resources(&conn).map_err(SurfaceError::from)?;
+        Ok(ListResourcesResult::with_all_items(listed))
+    }
+
+    async fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, ErrorData> {
+        Ok(ListResourceTemplatesResult::with_all_items(
+            resources::resource_templates(),
+        ))
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, ErrorData> {
+        let reference = ResourceRef::parse(&request.uri)?;
+        crate::log::info("mcp", &format!("read_resource uri={}", request.uri));
+        let conn = open_surface_db()?;
+        Ok(resources::read_resource(&conn, &reference)?)
+    }
+
+    async fn subscribe(
+        &self,
+        request: SubscribeRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<(), ErrorData> {
+        let reference = ResourceRef::parse(&request.uri)?;
+        let conn = open_surface_db()?;
+        let fingerprint =
+            resources::resource_fingerprint(&conn, &reference).map_err(SurfaceError::from)?;
+        let list_fingerprint = resources::list_fingerprint(&conn).map_err(SurfaceError::from)?;
+        crate::log::info("mcp", &format!("subscribe uri={}", request.uri));
+        if self
+            .subscriptions
+            .subscribe(reference, fingerprint, list_fingerprint)
+        {
+            self.subscriptions.spawn_poller(context.peer);
+        }
+        Ok(())
+    }
+
+    async fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), ErrorData> {
+        let reference = ResourceRef::parse(&request.uri)?;
+        self.subscriptions.unsubscribe(&reference);
+        Ok(())
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListPromptsResult, ErrorData> {
+        Ok(ListPromptsResult::with_all_items(
+            prompts::prompt_definitions(),
+        ))
+    }
+
+    async fn get_prompt(
+        &self,
+        request: GetPromptRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<GetPromptResult, ErrorData> {
+        crate::log::info("mcp", &format!("get_prompt name={}", request.name));
+        let conn = open_surface_db()?;
+        Ok(prompts::get_prompt(
+            &conn,
+            &request.name,
+            request.arguments.as_ref(),
+        )?)
+    }
 }
 
-pub async fn run_mcp_server() -> Result<()> {
+fn open_surface_db() -> Result<rusqlite::Connection, SurfaceError> {
+    crate::db::open_db().map_err(|error| SurfaceError::Internal(error.context("DB open failed")))
+}
+
+/// Serve MCP over stdio, or over streamable HTTP when `listen` is set.
+pub async fn run_mcp_server(listen: Option<std::net::SocketAddr>) -> Result<()> {
     let db_path = crate::db::try_db_path()?;
     let db_exists = db_path.exists();
     crate::log::info(
@@ -53,6 +161,10 @@ pub async fn run_mcp_server() -> Result<()> {
             mem_count, obs_count
         ),
     );
+    if let Some(addr) = listen {
+        drop(server);
+        return super::http::run_mcp_http_server(addr).await;
+    }
     let service = server.serve(rmcp::transport::stdio()).await?;
     service.waiting().await?;
     crate::log::info("mcp", "server stopped");
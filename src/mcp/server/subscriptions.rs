@@ -0,0 +1,157 @@
+//! Resource subscriptions for the MCP server.
+//!
+//! remem writes come from hooks, the worker and other processes, so there is
+//! no in-process event to hang notifications on. The first `resources/subscribe`
+//! starts one poller per session that re-reads cheap fingerprints and sends
+//! `notifications/resources/updated` for changed subscribed URIs and
+//! `notifications/resources/list_changed` when the listed set moves. The
+//! poller keeps one database connection and exits once the last URI is
+//! unsubscribed. Clients that never subscribe never receive unsolicited
+//! notifications.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use rmcp::model::ResourceUpdatedNotificationParam;
+use rmcp::service::Peer;
+use rmcp::RoleServer;
+
+use super::resources::{self, ResourceRef};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Default)]
+pub(super) struct ResourceSubscriptions {
+    inner: Arc<Mutex<SubscriptionState>>,
+}
+
+#[derive(Default)]
+struct SubscriptionState {
+    /// Subscribed URI -> last fingerprint observed for it.
+    fingerprints: BTreeMap<ResourceRef, String>,
+    list_fingerprint: Option<String>,
+    poller_running: bool,
+}
+
+impl ResourceSubscriptions {
+    /// Record a subscription and report whether the caller must start the
+    /// poller for this session.
+    pub(super) fn subscribe(
+        &self,
+        reference: ResourceRef,
+        fingerprint: String,
+        list_fingerprint: String,
+    ) -> bool {
+        let mut state = self.lock();
+        state.fingerprints.insert(reference, fingerprint);
+        state.list_fingerprint.get_or_insert(list_fingerprint);
+        let start = !state.poller_running;
+        state.poller_running = true;
+        start
+    }
+
+    pub(super) fn unsubscribe(&self, reference: &ResourceRef) {
+        self.lock().fingerprints.remove(reference);
+    }
+
+    /// Mark the poller stopped when nothing is subscribed any more. Checked
+    /// under the same lock as `subscribe`, so a racing subscription either
+    /// keeps this poller alive or starts a fresh one.
+    pub(super) fn stop_if_idle(&self) -> bool {
+        let mut state = self.lock();
+        if !state.fingerprints.is_empty() {
+            return false;
+        }
+        state.poller_running = false;
+        state.list_fingerprint = None;
+        true
+    }
+
+    #[cfg(test)]
+    pub(super) fn subscribed(&self) -> Vec<ResourceRef> {
+        self.lock().fingerprints.keys().cloned().collect()
+    }
+
+    pub(super) fn spawn_poller(&self, peer: Peer<RoleServer>) {
+        let subscriptions = self.clone();
+        tokio::spawn(async move {
+            let mut conn = None;
+            loop {
+                tokio::time::sleep(POLL_INTERVAL).await;
+                if subscriptions.stop_if_idle() {
+                    return;
+                }
+                if peer.is_transport_closed() {
+                    break;
+                }
+                let polled = match conn.as_ref() {
+                    Some(conn) => subscriptions.poll_changes(conn),
+                    None => crate::db::open_db()
+                        .and_then(|opened| subscriptions.poll_changes(conn.insert(opened))),
+                };
+                let (updated, list_changed) = match polled {
+                    Ok(changes) => changes,
+                    Err(error) => {
+                        crate::log::warn("mcp", &format!("resource poll failed: {error:#}"));
+                        // Reopen on the next tick in case the database moved.
+                        conn = None;
+                        continue;
+                    }
+                };
+                for uri in updated {
+                    if let Err(error) = peer
+                        .notify_resource_updated(ResourceUpdatedNotificationParam { uri })
+                        .await
+                    {
+                        crate::log::warn("mcp", &format!("resource notify failed: {error}"));
+                    }
+                }
+                if list_changed {
+                    if let Err(error) = peer.notify_resource_list_changed().await {
+                        crate::log::warn("mcp", &format!("resource notify failed: {error}"));
+                    }
+                }
+            }
+            subscriptions.lock().poller_running = false;
+        });
+    }
+
+    /// Re-fingerprint every subscribed resource, returning the URIs whose
+    /// fingerprint changed and whether the listed resource set changed.
+    pub(super) fn poll_changes(
+        &self,
+        conn: &rusqlite::Connection,
+    ) -> anyhow::Result<(Vec<String>, bool)> {
+        let references: Vec<ResourceRef> = self.lock().fingerprints.keys().cloned().collect();
+        let mut current = Vec::with_capacity(references.len());
+        for reference in references {
+            let fingerprint = resources::resource_fingerprint(conn, &reference)?;
+            current.push((reference, fingerprint));
+        }
+        let list_fingerprint = resources::list_fingerprint(conn)?;
+
+        let mut state = self.lock();
+        let mut updated = Vec::new();
+        for (reference, fingerprint) in current {
+            // A concurrent unsubscribe wins; never resurrect the entry.
+            if let Some(previous) = state.fingerprints.get_mut(&reference) {
+                if *previous != fingerprint {
+                    *previous = fingerprint;
+                    updated.push(reference.uri());
+                }
+            }
+        }
+        let list_changed = state
+            .list_fingerprint
+            .replace(list_fingerprint.clone())
+            .is_some_and(|previous| previous != list_fingerprint);
+        Ok((updated, list_changed))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, SubscriptionState> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
@@ -14,6 +14,7 @@ use crate::memory::service::{resolve_local_note_path, sanitize_segment};
 mod compressed_sources;
 mod context_bundle;
 mod current_state;
+mod resources;
 mod search;
 mod tool_metadata;
 
@@ -236,6 +237,7 @@ fn save_memory_local_copy_failures_are_invalid_request() {
         claim_enabled: None,
         claim_source: None,
         acknowledge_pattern: None,
+        anchors: None,
     }));
 
     let err = match outside {
@@ -278,6 +280,7 @@ fn save_memory_local_copy_failures_are_invalid_request() {
         claim_enabled: None,
         claim_source: None,
         acknowledge_pattern: None,
+        anchors: None,
     }));
 
     let err = match write_failure {
@@ -314,6 +317,7 @@ fn save_memory_response_reports_durable_feedback_shape() {
             claim_enabled: None,
             claim_source: None,
             acknowledge_pattern: None,
+            anchors: None,
         }))
         .expect("save_memory should succeed");
     let json: Value = serde_json::from_str(&response).expect("response should be json");
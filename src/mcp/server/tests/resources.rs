@@ -0,0 +1,309 @@
+use rmcp::model::{ErrorCode, ErrorData, ResourceContents};
+use rmcp::ServiceExt;
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+use super::super::prompts;
+use super::super::resources::{self, ResourceRef};
+use super::super::subscriptions::ResourceSubscriptions;
+use super::super::MemoryServer;
+use crate::db::test_support::ScopedTestDataDir;
+
+fn seed_memory(conn: &rusqlite::Connection, project: &str, title: &str) -> anyhow::Result<i64> {
+    crate::memory::insert_memory(
+        conn,
+        None,
+        project,
+        None,
+        title,
+        "Resources expose curated memory without tool calls.",
+        "decision",
+        None,
+    )
+}
+
+fn seed_workstream(conn: &rusqlite::Connection, project: &str) -> anyhow::Result<i64> {
+    conn.execute(
+        "INSERT INTO workstreams
+         (project, title, status, next_action, created_at_epoch, updated_at_epoch)
+         VALUES (?1, 'Ship MCP resources', 'active', 'wire subscriptions', 1, 1)",
+        [project],
+    )?;
+    Ok(conn.last_insert_rowid())
+}
+
+fn resource_text(result: &rmcp::model::ReadResourceResult) -> &str {
+    match result.contents.first() {
+        Some(ResourceContents::TextResourceContents { text, .. }) => text,
+        other => panic!("expected one text resource, got {other:?}"),
+    }
+}
+
+#[test]
+fn resource_uris_round_trip_percent_encoded_project_keys() {
+    let reference = ResourceRef::Memory {
+        project: "/Users/me/my repo".to_string(),
+        id: 42,
+    };
+    let uri = reference.uri();
+    assert_eq!(uri, "remem://project/%2FUsers%2Fme%2Fmy%20repo/memory/42");
+    assert_eq!(ResourceRef::parse(&uri).expect("uri parses"), reference);
+
+    for reference in [
+        ResourceRef::Workstreams {
+            project: "/repo".to_string(),
+        },
+        ResourceRef::Timeline {
+            project: "/repo".to_string(),
+        },
+        ResourceRef::Context {
+            project: "/repo".to_string(),
+        },
+    ] {
+        assert_eq!(
+            ResourceRef::parse(&reference.uri()).expect("uri parses"),
+            reference
+        );
+    }
+
+    for bad in [
+        "remem://project/%2Frepo/memory/not-a-number",
+        "remem://project/%2Frepo/unknown",
+        "remem://project//workstreams",
+        "file:///etc/passwd",
+        "remem://project/%ZZ/workstreams",
+    ] {
+        let err = ErrorData::from(ResourceRef::parse(bad).expect_err("must reject"));
+        assert_eq!(err.code, ErrorCode::INVALID_PARAMS, "{bad}");
+    }
+}
+
+#[test]
+fn list_and_read_resources_cover_memory_workstreams_and_context() -> anyhow::Result<()> {
+    let _dir = ScopedTestDataDir::new("mcp-resources-read");
+    let conn = crate::db::open_db()?;
+    let memory_id = seed_memory(&conn, "/repo", "Expose resources")?;
+    seed_workstream(&conn, "/repo")?;
+
+    let listed = resources::list_resources(&conn)?;
+    let uris: Vec<&str> = listed
+        .iter()
+        .map(|resource| resource.uri.as_str())
+        .collect();
+    let memory_uri = format!("remem://project/%2Frepo/memory/{memory_id}");
+    assert!(uris.contains(&memory_uri.as_str()), "{uris:?}");
+    assert!(
+        uris.contains(&"remem://project/%2Frepo/workstreams"),
+        "{uris:?}"
+    );
+    assert!(
+        uris.contains(&"remem://project/%2Frepo/context"),
+        "{uris:?}"
+    );
+
+    let memory = resources::read_resource(&conn, &ResourceRef::parse(&memory_uri)?)?;
+    let memory: Value = serde_json::from_str(resource_text(&memory))?;
+    assert_eq!(memory["id"], memory_id);
+    assert_eq!(memory["title"], "Expose resources");
+
+    let workstreams = resources::read_resource(
+        &conn,
+        &ResourceRef::Workstreams {
+            project: "/repo".to_string(),
+        },
+    )?;
+    let workstreams: Value = serde_json::from_str(resource_text(&workstreams))?;
+    assert_eq!(workstreams[0]["title"], "Ship MCP resources");
+
+    let context = resources::read_resource(
+        &conn,
+        &ResourceRef::Context {
+            project: "/repo".to_string(),
+        },
+    )?;
+    let bundle: Value = serde_json::from_str(resource_text(&context))?;
+    assert_eq!(bundle["schema_version"], 1);
+
+    let missing = resources::read_resource(
+        &conn,
+        &ResourceRef::Memory {
+            project: "/other".to_string(),
+            id: memory_id,
+        },
+    )
+    .expect_err("memory from another project must not resolve");
+    assert_eq!(ErrorData::from(missing).code, ErrorCode::RESOURCE_NOT_FOUND);
+    Ok(())
+}
+
+#[test]
+fn subscription_poll_reports_changed_resources_once() -> anyhow::Result<()> {
+    let _dir = ScopedTestDataDir::new("mcp-resources-subscribe");
+    let conn = crate::db::open_db()?;
+    let memory_id = seed_memory(&conn, "/repo", "Subscribed memory")?;
+    let workstreams = ResourceRef::Workstreams {
+        project: "/repo".to_string(),
+    };
+    let memory = ResourceRef::Memory {
+        project: "/repo".to_string(),
+        id: memory_id,
+    };
+
+    let subscriptions = ResourceSubscriptions::default();
+    let list = resources::list_fingerprint(&conn)?;
+    assert!(subscriptions.subscribe(
+        workstreams.clone(),
+        resources::resource_fingerprint(&conn, &workstreams)?,
+        list.clone(),
+    ));
+    assert!(
+        !subscriptions.subscribe(
+            memory.clone(),
+            resources::resource_fingerprint(&conn, &memory)?,
+            list,
+        ),
+        "second subscription must reuse the running poller"
+    );
+    assert_eq!(subscriptions.poll_changes(&conn)?, (Vec::new(), false));
+
+    seed_workstream(&conn, "/repo")?;
+    seed_memory(&conn, "/repo", "New memory")?;
+    let (updated, list_changed) = subscriptions.poll_changes(&conn)?;
+    assert_eq!(updated, vec![workstreams.uri()]);
+    assert!(list_changed);
+    assert_eq!(subscriptions.poll_changes(&conn)?, (Vec::new(), false));
+
+    subscriptions.unsubscribe(&workstreams);
+    assert_eq!(subscriptions.subscribed(), vec![memory.clone()]);
+    assert!(
+        !subscriptions.stop_if_idle(),
+        "poller keeps running while a URI is subscribed"
+    );
+
+    subscriptions.unsubscribe(&memory);
+    assert!(subscriptions.stop_if_idle());
+    assert!(
+        subscriptions.subscribe(
+            memory.clone(),
+            resources::resource_fingerprint(&conn, &memory)?,
+            resources::list_fingerprint(&conn)?,
+        ),
+        "a subscription after the poller stopped must start a new one"
+    );
+    Ok(())
+}
+
+#[test]
+fn prompts_embed_workstream_and_compiled_context() -> anyhow::Result<()> {
+    let _dir = ScopedTestDataDir::new("mcp-prompts");
+    let conn = crate::db::open_db()?;
+    let workstream_id = seed_workstream(&conn, "/repo")?;
+
+    let names: Vec<String> = prompts::prompt_definitions()
+        .into_iter()
+        .map(|prompt| prompt.name)
+        .collect();
+    assert_eq!(names, ["resume_workstream", "explain_decision"]);
+
+    let arguments = json!({ "project": "/repo", "workstream_id": workstream_id.to_string() });
+    let result = prompts::get_prompt(&conn, "resume_workstream", arguments.as_object())?;
+    let text = serde_json::to_value(&result.messages[0])?["content"]["text"]
+        .as_str()
+        .map(str::to_string)
+        .expect("prompt message should be text");
+    assert!(text.contains("Ship MCP resources"), "{text}");
+    assert!(text.contains("Next action: wire subscriptions"), "{text}");
+    assert!(text.contains("# remem context"), "{text}");
+
+    let missing_topic = prompts::get_prompt(
+        &conn,
+        "explain_decision",
+        json!({ "project": "/repo" }).as_object(),
+    )
+    .expect_err("topic is required");
+    assert_eq!(
+        ErrorData::from(missing_topic).code,
+        ErrorCode::INVALID_PARAMS
+    );
+
+    let unknown = prompts::get_prompt(
+        &conn,
+        "resume_workstream",
+        json!({ "project": "/repo", "workstream_id": "999999" }).as_object(),
+    )
+    .expect_err("unknown workstream");
+    assert_eq!(ErrorData::from(unknown).code, ErrorCode::RESOURCE_NOT_FOUND);
+    Ok(())
+}
+
+#[tokio::test]
+async fn served_initialize_advertises_and_serves_resources_and_prompts() -> anyhow::Result<()> {
+    let _dir = ScopedTestDataDir::new("mcp-resources-wire");
+    let conn = crate::db::open_db()?;
+    let memory_id = seed_memory(&conn, "/repo", "Wire resource")?;
+    drop(conn);
+
+    let (server_transport, client_transport) = tokio::io::duplex(256 * 1024);
+    let server = MemoryServer::new()?;
+    let server_task = tokio::spawn(async move {
+        server.serve(server_transport).await?.waiting().await?;
+        anyhow::Ok(())
+    });
+    let (client_reader, mut client_writer) = tokio::io::split(client_transport);
+    let mut messages = BufReader::new(client_reader).lines();
+    let request = |id: i64, method: &str, params: Value| {
+        json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }).to_string()
+    };
+
+    let requests = [
+        request(
+            1,
+            "initialize",
+            json!({
+                "protocolVersion": "2025-03-26",
+                "capabilities": {},
+                "clientInfo": { "name": "remem-resources-test", "version": "1" }
+            }),
+        ),
+        json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }).to_string(),
+        request(
+            2,
+            "resources/read",
+            json!({ "uri": format!("remem://project/%2Frepo/memory/{memory_id}") }),
+        ),
+        request(3, "prompts/list", json!({})),
+    ];
+    let mut responses = Vec::new();
+    for line in requests {
+        client_writer.write_all(line.as_bytes()).await?;
+        client_writer.write_all(b"\n").await?;
+        client_writer.flush().await?;
+        if line.contains("\"id\"") {
+            let response = messages
+                .next_line()
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("MCP transport closed before its response"))?;
+            responses.push(serde_json::from_str::<Value>(&response)?);
+        }
+    }
+    drop(client_writer);
+    drop(messages);
+    let _ = server_task.await;
+
+    let capabilities = &responses[0]["result"]["capabilities"];
+    assert_eq!(capabilities["resources"]["subscribe"], true);
+    assert_eq!(capabilities["resources"]["listChanged"], true);
+    assert!(capabilities["prompts"].is_object(), "{capabilities}");
+    assert!(capabilities["tools"].is_object(), "{capabilities}");
+
+    let text = responses[1]["result"]["contents"][0]["text"]
+        .as_str()
+        .expect("resource text");
+    let memory: Value = serde_json::from_str(text)?;
+    assert_eq!(memory["title"], "Wire resource");
+    assert_eq!(
+        responses[2]["result"]["prompts"][0]["name"],
+        "resume_workstream"
+    );
+    Ok(())
+}
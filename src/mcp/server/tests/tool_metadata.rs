@@ -2,8 +2,8 @@ use super::MemoryServer;
 use super::{assert_mcp_error, McpErrorCode};
 use crate::db::test_support::ScopedTestDataDir;
 use crate::mcp::types::{
-    GetObservationsParams, SearchParams, TimelineParams, TimelineReportParams,
-    UpdateWorkStreamParams, WorkStreamsParams,
+    CreateWorkStreamParams, GetObservationsParams, SearchParams, TimelineParams,
+    TimelineReportParams, UpdateWorkStreamParams, WorkStreamsParams,
 };
 use rmcp::handler::server::wrapper::Parameters;
 use rmcp::model::Tool;
@@ -136,6 +136,15 @@ const EXPECTED_TOOL_METADATA: &[ExpectedToolMetadata] = &[
         open_world: false,
         required_output_fields: &["workstreams"],
     },
+    ExpectedToolMetadata {
+        name: "create_workstream",
+        title: "Create Workstream",
+        read_only: false,
+        destructive: false,
+        idempotent: false,
+        open_world: false,
+        required_output_fields: &["id", "title", "status", "dependencies", "milestones"],
+    },
     ExpectedToolMetadata {
         name: "update_workstream",
         title: "Update Workstream",
@@ -584,6 +593,12 @@ fn update_workstream_rejects_unknown_status_without_mutating() -> anyhow::Result
             status: Some("running".to_string()),
             next_action: None,
             blockers: None,
+            parent_id: None,
+            clear_parent: None,
+            add_dependencies: None,
+            remove_dependencies: None,
+            add_milestones: None,
+            complete_milestones: None,
         }))
         .expect_err("unknown status should be rejected");
     let json = assert_mcp_error(
@@ -624,6 +639,12 @@ fn update_workstream_rejects_empty_update_without_touching_timestamp() -> anyhow
             status: None,
             next_action: None,
             blockers: None,
+            parent_id: None,
+            clear_parent: None,
+            add_dependencies: None,
+            remove_dependencies: None,
+            add_milestones: None,
+            complete_milestones: None,
         }))
         .expect_err("an update field should be required");
     let json = assert_mcp_error(
@@ -645,6 +666,45 @@ fn update_workstream_rejects_empty_update_without_touching_timestamp() -> anyhow
     Ok(())
 }
 
+#[test]
+fn create_workstream_rejects_cross_project_parent_without_inserting() -> anyhow::Result<()> {
+    let _dir = ScopedTestDataDir::new("mcp-create-workstream-cross-project");
+    let server = MemoryServer::new()?;
+    let conn = crate::db::open_db()?;
+    conn.execute(
+        "INSERT INTO workstreams
+         (project, title, status, created_at_epoch, updated_at_epoch)
+         VALUES ('other/proj', 'Foreign parent', 'active', 1, 1)",
+        [],
+    )?;
+    let parent_id = conn.last_insert_rowid();
+
+    let err = server
+        .create_workstream(Parameters(CreateWorkStreamParams {
+            project: "test/proj".to_string(),
+            title: "Child".to_string(),
+            description: None,
+            next_action: None,
+            parent_id: Some(parent_id),
+            dependencies: None,
+            milestones: None,
+        }))
+        .expect_err("a parent from another project should be rejected");
+    let json = assert_mcp_error(
+        err,
+        McpErrorCode::InvalidRequest,
+        "create_workstream",
+        false,
+    );
+    assert!(json["error"]["message"]
+        .as_str()
+        .is_some_and(|message| message.contains("different project")));
+
+    let count: i64 = conn.query_row("SELECT COUNT(*) FROM workstreams", [], |row| row.get(0))?;
+    assert_eq!(count, 1);
+    Ok(())
+}
+
 #[test]
 fn server_instructions_match_default_all_status_workstream_listing() -> anyhow::Result<()> {
     let server = MemoryServer::new()?;
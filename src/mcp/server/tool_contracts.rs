@@ -69,7 +69,7 @@ const fn json_array(
     }
 }
 
-const CONTRACTS: [ToolContract; 15] = [
+const CONTRACTS: [ToolContract; 16] = [
     json_object(
         "current_state",
         "Current State",
@@ -183,6 +183,15 @@ const CONTRACTS: [ToolContract; 15] = [
         OutputSchema::Workstreams,
         "workstreams",
     ),
+    json_object(
+        "create_workstream",
+        "Create Workstream",
+        false,
+        false,
+        false,
+        false,
+        OutputSchema::CreateWorkstream,
+    ),
     json_object(
         "update_workstream",
         "Update Workstream",
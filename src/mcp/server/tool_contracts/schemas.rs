@@ -36,6 +36,7 @@ pub(super) enum OutputSchema {
     SaveMemory,
     GovernMemory,
     Workstreams,
+    CreateWorkstream,
     UpdateWorkstream,
     SearchRaw,
     ListRawSessions,
@@ -54,6 +55,7 @@ pub(super) fn build_schema(kind: OutputSchema) -> anyhow::Result<Arc<JsonObject>
         OutputSchema::SaveMemory => schema_for_output::<SaveMemoryOutput>(),
         OutputSchema::GovernMemory => schema_for_output::<GovernMemoryOutput>(),
         OutputSchema::Workstreams => schema_for_output::<WorkstreamsOutput>(),
+        OutputSchema::CreateWorkstream => schema_for_output::<WorkstreamOutput>(),
         OutputSchema::UpdateWorkstream => schema_for_output::<UpdateWorkstreamOutput>(),
         OutputSchema::SearchRaw => schema_for_output::<SearchRawOutput>(),
         OutputSchema::ListRawSessions => schema_for_output::<RawSessionsOutput>(),
@@ -80,6 +82,7 @@ pub(super) fn validate_output(kind: OutputSchema, value: &Value) -> anyhow::Resu
         OutputSchema::SaveMemory => validate::<SaveMemoryOutput>(kind, value),
         OutputSchema::GovernMemory => validate::<GovernMemoryOutput>(kind, value),
         OutputSchema::Workstreams => validate::<WorkstreamsOutput>(kind, value),
+        OutputSchema::CreateWorkstream => validate::<WorkstreamOutput>(kind, value),
         OutputSchema::UpdateWorkstream => validate::<UpdateWorkstreamOutput>(kind, value),
         OutputSchema::SearchRaw => validate::<SearchRawOutput>(kind, value),
         OutputSchema::ListRawSessions => validate::<RawSessionsOutput>(kind, value),
@@ -460,6 +463,34 @@ struct WorkstreamOutput {
     created_at_epoch: i64,
     updated_at_epoch: i64,
     completed_at_epoch: Option<i64>,
+    parent_id: Option<i64>,
+    dependencies: Vec<WorkstreamDependencyOutput>,
+    milestones: Vec<WorkstreamMilestoneOutput>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct WorkstreamDependencyOutput {
+    depends_on_id: i64,
+    kind: WorkstreamDependencyKind,
+    satisfied: bool,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[schemars(rename_all = "lowercase")]
+enum WorkstreamDependencyKind {
+    Hard,
+    Soft,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct WorkstreamMilestoneOutput {
+    id: i64,
+    title: String,
+    position: i64,
+    completed_at_epoch: Option<i64>,
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -140,6 +140,17 @@ async fn every_schema_bearing_served_route_validates_a_real_non_empty_success()
             json!({ "project": "/repo" }),
             "workstreams",
         ),
+        WireCall::object(
+            "create_workstream",
+            "create_workstream",
+            json!({
+                "project": "/repo",
+                "title": "Wire contract follow-up",
+                "parent_id": fixture.workstream_id,
+                "dependencies": [{ "workstream_id": fixture.workstream_id, "kind": "soft" }],
+                "milestones": ["Draft", "Ship"]
+            }),
+        ),
         WireCall::object(
             "update_workstream",
             "update_workstream",
@@ -162,7 +173,7 @@ async fn every_schema_bearing_served_route_validates_a_real_non_empty_success()
             .map(|call| call.tool)
             .collect::<BTreeSet<_>>()
             .len(),
-        14
+        15
     );
 
     let mut results = BTreeMap::new();
@@ -219,6 +230,14 @@ async fn every_schema_bearing_served_route_validates_a_real_non_empty_success()
     );
     assert!(results["govern_memory"]["reason"].is_null());
     assert!(results["workstreams"]["workstreams"][0]["description"].is_null());
+    assert_eq!(
+        results["create_workstream"]["parent_id"],
+        fixture.workstream_id
+    );
+    assert_eq!(
+        results["create_workstream"]["milestones"][1]["title"],
+        "Ship"
+    );
     assert_eq!(results["update_workstream"]["updated"], true);
     assert_eq!(results["search_raw"]["results"][0]["source"], SOURCE_HOOK);
     assert_eq!(
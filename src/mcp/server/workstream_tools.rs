@@ -2,9 +2,14 @@ use rmcp::handler::server::wrapper::Parameters;
 use rmcp::{tool, tool_router};
 use serde_json::json;
 
-use super::super::types::{UpdateWorkStreamParams, WorkStreamsParams};
+use super::super::types::{
+    CreateWorkStreamParams, UpdateWorkStreamParams, WorkStreamDependencyParam, WorkStreamsParams,
+};
 use super::errors::{self, McpToolError, McpToolResult};
 use super::MemoryServer;
+use crate::workstream::{
+    NewWorkStream, WorkStreamDependencyKind, WorkStreamPlanError, WorkStreamPlanUpdate,
+};
 
 const WORKSTREAM_STATUSES: [&str; 4] = ["active", "paused", "completed", "abandoned"];
 
@@ -18,10 +23,40 @@ fn validate_workstream_status(tool: &'static str, status: &str) -> McpToolResult
     ))
 }
 
+fn parse_dependencies(
+    tool: &'static str,
+    dependencies: Option<&[WorkStreamDependencyParam]>,
+) -> McpToolResult<Vec<(i64, WorkStreamDependencyKind)>> {
+    dependencies
+        .unwrap_or_default()
+        .iter()
+        .map(|dependency| {
+            let kind = match dependency.kind.as_deref() {
+                None => WorkStreamDependencyKind::Hard,
+                Some(kind) => WorkStreamDependencyKind::parse(kind).ok_or_else(|| {
+                    McpToolError::invalid_request(
+                        tool,
+                        format!("unknown dependency kind '{kind}'; expected hard or soft"),
+                    )
+                })?,
+            };
+            Ok((dependency.workstream_id, kind))
+        })
+        .collect()
+}
+
+fn plan_error(tool: &'static str, err: anyhow::Error) -> McpToolError {
+    if err.is::<WorkStreamPlanError>() {
+        return McpToolError::invalid_request(tool, err.to_string());
+    }
+    crate::log::warn("mcp", &format!("{tool} failed: {err}"));
+    McpToolError::db_query(tool, err)
+}
+
 #[tool_router(router = tool_router_workstream, vis = "pub(super)")]
 impl MemoryServer {
     #[tool(
-        description = "Read-only. List existing high-level workstreams for a required project, optionally filtered by status=active, paused, completed, or abandoned. Returns a JSON array with each workstream's status, progress, next action, blockers, and timestamps. Use update_workstream to mutate an existing row; this tool does not create, update, or delete workstreams. A missing project or database failure returns a tool error."
+        description = "Read-only. List existing high-level workstreams for a required project, optionally filtered by status=active, paused, completed, or abandoned. Returns a JSON array with each workstream's status, progress, next action, blockers, parent_id, dependencies (kind and whether the target is completed), milestones, and timestamps. Use update_workstream to mutate an existing row and create_workstream to add one; this tool does not create, update, or delete workstreams. A missing project or database failure returns a tool error."
     )]
     pub(super) fn workstreams(
         &self,
@@ -58,17 +93,85 @@ impl MemoryServer {
     }
 
     #[tool(
-        description = "Mutates one existing workstream by id. At least one of status, next_action, or blockers is required; omitted fields remain unchanged. status accepts only active, paused, completed, or abandoned. Returns a JSON object with id and updated, where updated=false means no row matched. Use workstreams to list/read rows first. This tool does not create or delete workstreams; an empty update, an unknown status, or a database failure returns a tool error."
+        description = "Creates one active workstream in a required project with a required title. Optional parent_id nests it under another workstream; dependencies lists workstreams that must land first (kind hard blocks resuming or completing this workstream until the target completes and is the default, soft only records a preferred order); milestones is an ordered checklist. Parents and dependencies must be unmerged workstreams in the same project and may not form a cycle. Returns the created workstream as a JSON object, including milestone ids for later update_workstream complete_milestones calls. Never merges into an existing workstream with the same title; a blank title, an unknown or cross-project link, or a database failure returns a tool error."
+    )]
+    pub(super) fn create_workstream(
+        &self,
+        Parameters(params): Parameters<CreateWorkStreamParams>,
+    ) -> McpToolResult<String> {
+        const TOOL: &str = "create_workstream";
+        let dependencies = parse_dependencies(TOOL, params.dependencies.as_deref())?;
+        crate::log::info(
+            "mcp",
+            &format!(
+                "create_workstream called project={:?} parent={:?} dependencies={}",
+                params.project,
+                params.parent_id,
+                dependencies.len()
+            ),
+        );
+        self.with_conn(TOOL, |conn| {
+            let milestones = params.milestones.clone().unwrap_or_default();
+            let id = crate::workstream::create_workstream(
+                conn,
+                &NewWorkStream {
+                    project: &params.project,
+                    title: &params.title,
+                    description: params.description.as_deref(),
+                    next_action: params.next_action.as_deref(),
+                    parent_id: params.parent_id,
+                    dependencies: &dependencies,
+                    milestones: &milestones,
+                },
+            )
+            .map_err(|e| plan_error(TOOL, e))?;
+            let created = crate::workstream::load_workstream(conn, id)
+                .map_err(|e| McpToolError::db_query(TOOL, e))?
+                .ok_or_else(|| {
+                    McpToolError::db_query(TOOL, format!("created workstream {id} vanished"))
+                })?;
+            crate::log::info("mcp", &format!("create_workstream done id={id}"));
+            errors::to_json_pretty(TOOL, &created)
+        })
+    }
+
+    #[tool(
+        description = "Mutates one existing workstream by id. At least one of status, next_action, blockers, parent_id, clear_parent, add_dependencies, remove_dependencies, add_milestones, or complete_milestones is required; omitted fields remain unchanged. status accepts only active, paused, completed, or abandoned; dependency kind accepts only hard or soft. Parents and dependencies must be workstreams in the same project and may not form a cycle. A hard dependency blocks its workstream until the target completes: status=active or status=completed is rejected while one is open; soft dependencies only record order. All changes apply atomically. complete_milestones takes milestone ids from the workstreams listing and stamps their completion time once. Returns a JSON object with id and updated, where updated=false means no row matched. Use workstreams to list/read rows first. This tool does not create or delete workstreams (use create_workstream); an empty update, an unknown status or kind, a cross-project or cyclic link, a status blocked by an open hard dependency, or a database failure returns a tool error."
     )]
     pub(super) fn update_workstream(
         &self,
         Parameters(params): Parameters<UpdateWorkStreamParams>,
     ) -> McpToolResult<String> {
         const TOOL: &str = "update_workstream";
-        if params.status.is_none() && params.next_action.is_none() && params.blockers.is_none() {
+        if params.parent_id.is_some() && params.clear_parent == Some(true) {
+            return Err(McpToolError::invalid_request(
+                TOOL,
+                "parent_id and clear_parent cannot be combined",
+            ));
+        }
+        let add_dependencies = parse_dependencies(TOOL, params.add_dependencies.as_deref())?;
+        let remove_dependencies = params.remove_dependencies.clone().unwrap_or_default();
+        let add_milestones = params.add_milestones.clone().unwrap_or_default();
+        let complete_milestones = params.complete_milestones.clone().unwrap_or_default();
+        let plan = WorkStreamPlanUpdate {
+            parent_id: match (params.parent_id, params.clear_parent) {
+                (Some(parent_id), _) => Some(Some(parent_id)),
+                (None, Some(true)) => Some(None),
+                (None, _) => None,
+            },
+            add_dependencies: &add_dependencies,
+            remove_dependencies: &remove_dependencies,
+            add_milestones: &add_milestones,
+            complete_milestones: &complete_milestones,
+        };
+        let has_field_update =
+            params.status.is_some() || params.next_action.is_some() || params.blockers.is_some();
+        if !has_field_update && plan.is_empty() {
             return Err(McpToolError::invalid_request(
                 TOOL,
-                "at least one of status, next_action, or blockers is required",
+                "at least one of status, next_action, blockers, parent_id, clear_parent, \
+                 add_dependencies, remove_dependencies, add_milestones, or complete_milestones \
+                 is required",
             ));
         }
         if let Some(status) = params.status.as_deref() {
@@ -82,17 +185,15 @@ impl MemoryServer {
             ),
         );
         self.with_conn(TOOL, |conn| {
-            let updated = crate::workstream::update_workstream_manual(
+            let updated = crate::workstream::update_workstream_plan_and_fields(
                 conn,
                 params.id,
+                &plan,
                 params.status.as_deref(),
                 params.next_action.as_deref(),
                 params.blockers.as_deref(),
             )
-            .map_err(|e| {
-                crate::log::warn("mcp", &format!("update_workstream failed: {}", e));
-                McpToolError::db_query(TOOL, e)
-            })?;
+            .map_err(|e| plan_error(TOOL, e))?;
             crate::log::info(
                 "mcp",
                 &format!(
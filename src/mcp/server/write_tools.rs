@@ -112,6 +112,17 @@ impl MemoryServer {
                     .filter(|source| !source.trim().is_empty())
                     .or_else(|| Some("manual_save".to_string())),
                 acknowledge_pattern: params.acknowledge_pattern.clone(),
+                anchors: params.anchors.as_ref().map(|anchors| {
+                    anchors
+                        .iter()
+                        .map(|anchor| crate::memory::anchors::MemoryAnchorInput {
+                            file: anchor.file.clone(),
+                            symbol: anchor.symbol.clone(),
+                            start_line: anchor.start_line,
+                            end_line: anchor.end_line,
+                        })
+                        .collect()
+                }),
             };
             let saved = service::save_memory_with_reference_time(
                 conn,
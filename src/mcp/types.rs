@@ -241,6 +241,24 @@ pub(super) struct SaveMemoryParams {
         description = "Explicitly acknowledge an instruction-pattern match in direct save text after human review. Must match the detected pattern id, e.g. override_previous_instructions."
     )]
     pub acknowledge_pattern: Option<String>,
+    #[schemars(
+        description = "Optional code anchors captured at the current git HEAD. Staleness then fires only when the anchored symbol or lines change, not on any edit to the file. Passing an empty list clears existing anchors."
+    )]
+    pub anchors: Option<Vec<MemoryAnchorParam>>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub(super) struct MemoryAnchorParam {
+    #[schemars(description = "File path, relative to the repository root or absolute")]
+    pub file: String,
+    #[schemars(
+        description = "Symbol path such as 'auth::Session::refresh' or 'pkg.Client.send'. Its line range is resolved at save time."
+    )]
+    pub symbol: Option<String>,
+    #[schemars(description = "1-based first anchored line")]
+    pub start_line: Option<i64>,
+    #[schemars(description = "1-based last anchored line (inclusive). Defaults to start_line.")]
+    pub end_line: Option<i64>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -295,6 +313,57 @@ pub(super) struct UpdateWorkStreamParams {
     pub next_action: Option<String>,
     #[schemars(description = "Current blockers")]
     pub blockers: Option<String>,
+    #[schemars(
+        description = "Make this workstream a child of another workstream in the same project"
+    )]
+    pub parent_id: Option<i64>,
+    #[schemars(description = "Detach the workstream from its parent (default false)")]
+    pub clear_parent: Option<bool>,
+    #[schemars(description = "Dependencies to add or retype")]
+    pub add_dependencies: Option<Vec<WorkStreamDependencyParam>>,
+    #[schemars(description = "Workstream IDs whose dependency edge should be removed")]
+    pub remove_dependencies: Option<Vec<i64>>,
+    #[schemars(description = "Milestone titles to append to the checklist")]
+    pub add_milestones: Option<Vec<String>>,
+    #[schemars(description = "Milestone IDs to mark completed")]
+    pub complete_milestones: Option<Vec<i64>>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub(super) struct CreateWorkStreamParams {
+    #[schemars(description = "Project the workstream belongs to (required)")]
+    pub project: String,
+    #[schemars(description = "Workstream title (required)")]
+    pub title: String,
+    #[schemars(description = "Longer description")]
+    pub description: Option<String>,
+    #[schemars(description = "Next action to take")]
+    pub next_action: Option<String>,
+    #[schemars(description = "Parent workstream ID in the same project")]
+    pub parent_id: Option<i64>,
+    #[schemars(description = "Workstreams this one depends on")]
+    pub dependencies: Option<Vec<WorkStreamDependencyParam>>,
+    #[schemars(description = "Ordered milestone checklist titles")]
+    pub milestones: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub(super) struct WorkStreamDependencyParam {
+    #[schemars(description = "Workstream ID that must land first")]
+    pub workstream_id: i64,
+    #[schemars(
+        description = "hard (blocks status active/completed until the target completes, default) or soft (preferred order only)"
+    )]
+    #[schemars(schema_with = "workstream_dependency_kind_schema")]
+    pub kind: Option<String>,
+}
+
+fn workstream_dependency_kind_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+        "type": "string",
+        "enum": ["hard", "soft"],
+        "nullable": true
+    })
 }
 
 fn workstream_status_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
@@ -1,3 +1,4 @@
+pub mod anchors;
 pub mod claims;
 pub(crate) mod conflict_common;
 pub mod current_state;
@@ -0,0 +1,493 @@
+//! Symbol- and line-range anchors for memories (schema v92).
+//!
+//! `Memory.files` only says which files a memory is about; an anchor pins the
+//! exact region (a symbol path, a line range, or both) at the repository HEAD
+//! of save time. Re-resolution diffs that commit against the current HEAD and
+//! caches a drift verdict, so staleness labels fire only when the anchored
+//! code itself changed and `remem why` can show the drifted hunks.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use rusqlite::{params, Connection};
+use serde::Serialize;
+
+mod resolve;
+#[cfg(test)]
+mod tests;
+
+use resolve::{locate_symbol, parse_unified_zero_diff, resolve_line_range, resolve_symbol};
+pub(crate) use resolve::{Drift, LineRange};
+
+const MAX_ANCHORS_PER_MEMORY: usize = 20;
+
+/// Caller-supplied anchor. `symbol` is a path such as `module::Type::method`
+/// or `pkg.Class.method`; a line range is 1-based and inclusive.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryAnchorInput {
+    pub file: String,
+    pub symbol: Option<String>,
+    pub start_line: Option<i64>,
+    pub end_line: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MemoryAnchor {
+    pub id: i64,
+    pub memory_id: i64,
+    pub file_path: String,
+    pub symbol: Option<String>,
+    pub start_line: Option<i64>,
+    pub end_line: Option<i64>,
+    pub repo_path: Option<String>,
+    pub base_commit_sha: Option<String>,
+    pub resolved_commit_sha: Option<String>,
+    pub resolved_commit_epoch: Option<i64>,
+    pub resolved_start_line: Option<i64>,
+    pub resolved_end_line: Option<i64>,
+    pub drift_status: String,
+    pub drift_diff: Option<String>,
+    pub checked_at_epoch: Option<i64>,
+}
+
+impl MemoryAnchor {
+    /// `changed` and `missing` mean the anchored code no longer matches.
+    pub fn has_drifted(&self) -> bool {
+        matches!(self.drift_status.as_str(), "changed" | "missing")
+    }
+
+    /// `unchanged` and `moved` are verified as of `resolved_commit_epoch`.
+    pub fn is_verified(&self) -> bool {
+        matches!(self.drift_status.as_str(), "unchanged" | "moved")
+    }
+
+    /// Compact `file::symbol L10-20` label for human output.
+    pub fn label(&self) -> String {
+        let mut label = self.file_path.clone();
+        if let Some(symbol) = &self.symbol {
+            label.push_str("::");
+            label.push_str(symbol);
+        }
+        if let (Some(start), Some(end)) = (self.start_line, self.end_line) {
+            label.push_str(&format!(" L{start}-{end}"));
+        }
+        label
+    }
+}
+
+/// Anchor ready to persist: paths normalized against the repository root and
+/// the save-time HEAD recorded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CapturedAnchor {
+    pub file_path: String,
+    pub symbol: Option<String>,
+    pub start_line: Option<i64>,
+    pub end_line: Option<i64>,
+    pub repo_path: Option<String>,
+    pub base_commit_sha: Option<String>,
+}
+
+pub(crate) fn validate_anchor_inputs(inputs: &[MemoryAnchorInput]) -> Result<(), String> {
+    if inputs.len() > MAX_ANCHORS_PER_MEMORY {
+        return Err(format!(
+            "save_memory accepts at most {MAX_ANCHORS_PER_MEMORY} anchors"
+        ));
+    }
+    for anchor in inputs {
+        if anchor.file.trim().is_empty() {
+            return Err("save_memory anchors require a file".to_string());
+        }
+        let symbol = anchor
+            .symbol
+            .as_deref()
+            .map(str::trim)
+            .filter(|symbol| !symbol.is_empty());
+        match (anchor.start_line, anchor.end_line) {
+            (None, None) if symbol.is_none() => {
+                return Err(format!(
+                    "save_memory anchor for {} needs a symbol or a line range",
+                    anchor.file.trim()
+                ))
+            }
+            (None, None) => {}
+            (Some(start), Some(end)) if start >= 1 && end >= start => {}
+            (Some(start), None) if start >= 1 => {}
+            _ => {
+                return Err(format!(
+                    "save_memory anchor for {} has an invalid line range; use 1-based start_line <= end_line",
+                    anchor.file.trim()
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Resolve repository root, HEAD, and symbol line ranges for `inputs`. Work
+/// outside a git checkout is still recorded, as `untracked` anchors.
+pub(crate) fn capture_anchors(project: &str, inputs: &[MemoryAnchorInput]) -> Vec<CapturedAnchor> {
+    let repo = Path::new(project)
+        .is_dir()
+        .then(|| crate::git_util::resolve_toplevel(Path::new(project)))
+        .flatten();
+    let head = repo.as_deref().and_then(head_commit);
+    inputs
+        .iter()
+        .map(|input| {
+            let file_path = relative_file_path(repo.as_deref(), input.file.trim());
+            let symbol = input
+                .symbol
+                .as_deref()
+                .map(str::trim)
+                .filter(|symbol| !symbol.is_empty())
+                .map(str::to_string);
+            let (mut start_line, mut end_line) = match (input.start_line, input.end_line) {
+                (Some(start), end) => (Some(start), Some(end.unwrap_or(start))),
+                _ => (None, None),
+            };
+            if start_line.is_none() {
+                if let (Some(repo), Some(head), Some(symbol)) =
+                    (repo.as_deref(), head.as_ref(), symbol.as_deref())
+                {
+                    if let Some(range) = file_at(repo, &head.sha, &file_path)
+                        .and_then(|source| locate_symbol(&source, symbol))
+                    {
+                        start_line = Some(range.start);
+                        end_line = Some(range.end);
+                    }
+                }
+            }
+            CapturedAnchor {
+                file_path,
+                symbol,
+                start_line,
+                end_line,
+                repo_path: head
+                    .as_ref()
+                    .and(repo.as_ref())
+                    .map(|repo| repo.display().to_string()),
+                base_commit_sha: head.as_ref().map(|head| head.sha.clone()),
+            }
+        })
+        .collect()
+}
+
+/// Replace every anchor of `memory_id`. An empty slice clears them.
+pub(crate) fn replace_memory_anchors(
+    conn: &Connection,
+    memory_id: i64,
+    anchors: &[CapturedAnchor],
+) -> Result<()> {
+    let now = chrono::Utc::now().timestamp();
+    conn.execute(
+        "DELETE FROM memory_anchors WHERE memory_id = ?1",
+        [memory_id],
+    )?;
+    let mut insert = conn.prepare_cached(
+        "INSERT INTO memory_anchors
+         (memory_id, file_path, symbol, start_line, end_line, repo_path,
+          base_commit_sha, drift_status, created_at_epoch)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+    )?;
+    for anchor in anchors {
+        let status = if anchor.base_commit_sha.is_some() {
+            "pending"
+        } else {
+            "untracked"
+        };
+        insert
+            .execute(params![
+                memory_id,
+                anchor.file_path,
+                anchor.symbol,
+                anchor.start_line,
+                anchor.end_line,
+                anchor.repo_path,
+                anchor.base_commit_sha,
+                status,
+                now
+            ])
+            .with_context(|| format!("insert anchor for memory {memory_id}"))?;
+    }
+    Ok(())
+}
+
+pub fn memory_anchors(conn: &Connection, memory_id: i64) -> Result<Vec<MemoryAnchor>> {
+    let mut stmt = conn.prepare_cached(
+        "SELECT id, memory_id, file_path, symbol, start_line, end_line, repo_path,
+                base_commit_sha, resolved_commit_sha, resolved_commit_epoch,
+                resolved_start_line, resolved_end_line, drift_status, drift_diff,
+                checked_at_epoch
+         FROM memory_anchors
+         WHERE memory_id = ?1
+         ORDER BY id",
+    )?;
+    let rows = stmt.query_map([memory_id], map_anchor)?;
+    rows.collect::<rusqlite::Result<Vec<_>>>()
+        .map_err(Into::into)
+}
+
+fn map_anchor(row: &rusqlite::Row<'_>) -> rusqlite::Result<MemoryAnchor> {
+    Ok(MemoryAnchor {
+        id: row.get(0)?,
+        memory_id: row.get(1)?,
+        file_path: row.get(2)?,
+        symbol: row.get(3)?,
+        start_line: row.get(4)?,
+        end_line: row.get(5)?,
+        repo_path: row.get(6)?,
+        base_commit_sha: row.get(7)?,
+        resolved_commit_sha: row.get(8)?,
+        resolved_commit_epoch: row.get(9)?,
+        resolved_start_line: row.get(10)?,
+        resolved_end_line: row.get(11)?,
+        drift_status: row.get(12)?,
+        drift_diff: row.get(13)?,
+        checked_at_epoch: row.get(14)?,
+    })
+}
+
+/// Re-resolve one memory's anchors against their repository's current HEAD.
+pub(crate) fn refresh_memory_anchors(conn: &Connection, memory_id: i64) -> Result<usize> {
+    let mut refreshed = 0;
+    for anchor in memory_anchors(conn, memory_id)? {
+        let Some(repo) = anchor.repo_path.as_deref().map(PathBuf::from) else {
+            continue;
+        };
+        let Some(head) = head_commit(&repo) else {
+            continue;
+        };
+        if anchor.resolved_commit_sha.as_deref() == Some(head.sha.as_str()) {
+            continue;
+        }
+        store_resolution(conn, &anchor, &repo, &head)?;
+        refreshed += 1;
+    }
+    Ok(refreshed)
+}
+
+/// Worker batch: re-resolve up to `limit` anchors whose cached verdict
+/// predates their repository's HEAD. A per-anchor git failure is logged and
+/// stored as `unresolvable` at that HEAD, so a rewritten history is not
+/// retried ahead of healthy anchors until HEAD moves again.
+pub(crate) fn refresh_outdated_anchors(conn: &Connection, limit: usize) -> Result<usize> {
+    let repos = {
+        let mut stmt = conn.prepare(
+            "SELECT DISTINCT repo_path FROM memory_anchors
+             WHERE repo_path IS NOT NULL AND base_commit_sha IS NOT NULL",
+        )?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+        rows.collect::<rusqlite::Result<Vec<_>>>()?
+    };
+    let mut refreshed = 0;
+    for repo in repos {
+        if refreshed >= limit {
+            break;
+        }
+        let repo = PathBuf::from(repo);
+        let Some(head) = head_commit(&repo) else {
+            continue;
+        };
+        let anchors = {
+            let mut stmt = conn.prepare(
+                "SELECT id, memory_id, file_path, symbol, start_line, end_line, repo_path,
+                        base_commit_sha, resolved_commit_sha, resolved_commit_epoch,
+                        resolved_start_line, resolved_end_line, drift_status, drift_diff,
+                        checked_at_epoch
+                 FROM memory_anchors
+                 WHERE repo_path = ?1
+                   AND base_commit_sha IS NOT NULL
+                   AND (resolved_commit_sha IS NULL OR resolved_commit_sha <> ?2)
+                 ORDER BY COALESCE(checked_at_epoch, 0), id
+                 LIMIT ?3",
+            )?;
+            let rows = stmt.query_map(
+                params![
+                    repo.display().to_string(),
+                    head.sha,
+                    (limit - refreshed) as i64
+                ],
+                map_anchor,
+            )?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()?
+        };
+        for anchor in anchors {
+            match store_resolution(conn, &anchor, &repo, &head) {
+                Ok(()) => refreshed += 1,
+                Err(error) => {
+                    crate::log::warn(
+                        "anchors",
+                        &format!("anchor id={} re-resolution failed: {error:#}", anchor.id),
+                    );
+                    store_unresolvable(conn, &anchor, &head, &format!("{error:#}"))?;
+                }
+            }
+        }
+    }
+    Ok(refreshed)
+}
+
+fn store_resolution(
+    conn: &Connection,
+    anchor: &MemoryAnchor,
+    repo: &Path,
+    head: &HeadCommit,
+) -> Result<()> {
+    let drift = resolve_anchor(anchor, repo, &head.sha)?;
+    let (range, diff) = match &drift {
+        Drift::Unchanged(range) | Drift::Moved(range) => (Some(*range), None),
+        Drift::Changed { range, diff } => (*range, Some(diff.clone())),
+        Drift::Missing(reason) => (None, Some(reason.clone())),
+    };
+    conn.execute(
+        "UPDATE memory_anchors
+         SET resolved_commit_sha = ?1,
+             resolved_commit_epoch = ?2,
+             resolved_start_line = ?3,
+             resolved_end_line = ?4,
+             drift_status = ?5,
+             drift_diff = ?6,
+             checked_at_epoch = ?7
+         WHERE id = ?8",
+        params![
+            head.sha,
+            head.epoch,
+            range.map(|range| range.start),
+            range.map(|range| range.end),
+            drift.status(),
+            diff,
+            chrono::Utc::now().timestamp(),
+            anchor.id
+        ],
+    )?;
+    Ok(())
+}
+
+fn store_unresolvable(
+    conn: &Connection,
+    anchor: &MemoryAnchor,
+    head: &HeadCommit,
+    reason: &str,
+) -> Result<()> {
+    conn.execute(
+        "UPDATE memory_anchors
+         SET resolved_commit_sha = ?1,
+             resolved_commit_epoch = ?2,
+             resolved_start_line = NULL,
+             resolved_end_line = NULL,
+             drift_status = 'unresolvable',
+             drift_diff = ?3,
+             checked_at_epoch = ?4
+         WHERE id = ?5",
+        params![
+            head.sha,
+            head.epoch,
+            reason,
+            chrono::Utc::now().timestamp(),
+            anchor.id
+        ],
+    )?;
+    Ok(())
+}
+
+fn resolve_anchor(anchor: &MemoryAnchor, repo: &Path, head_sha: &str) -> Result<Drift> {
+    let base_sha = anchor
+        .base_commit_sha
+        .as_deref()
+        .ok_or_else(|| anyhow!("anchor {} has no base commit", anchor.id))?;
+    let base_range = match (anchor.start_line, anchor.end_line) {
+        (Some(start), Some(end)) => Some(LineRange { start, end }),
+        _ => None,
+    };
+    if base_sha == head_sha {
+        if let Some(range) = base_range {
+            return Ok(Drift::Unchanged(range));
+        }
+    }
+    let Some(head_source) = file_at(repo, head_sha, &anchor.file_path) else {
+        return Ok(Drift::Missing(format!(
+            "{} no longer exists at {}",
+            anchor.file_path,
+            crate::git_util::short_sha_for(head_sha)
+        )));
+    };
+    let diff = git_stdout(
+        repo,
+        &[
+            "diff",
+            "-U0",
+            "--no-color",
+            "--no-ext-diff",
+            base_sha,
+            head_sha,
+            "--",
+            &anchor.file_path,
+        ],
+    )
+    .with_context(|| format!("diff {} for anchor {}", anchor.file_path, anchor.id))?;
+    let hunks = parse_unified_zero_diff(&diff);
+    match (anchor.symbol.as_deref(), base_range) {
+        (Some(symbol), _) => {
+            let base_source = file_at(repo, base_sha, &anchor.file_path).ok_or_else(|| {
+                anyhow!(
+                    "saved commit {} is no longer readable",
+                    crate::git_util::short_sha_for(base_sha)
+                )
+            })?;
+            Ok(resolve_symbol(
+                symbol,
+                base_range,
+                &base_source,
+                &head_source,
+                &hunks,
+            ))
+        }
+        (None, Some(range)) => Ok(resolve_line_range(range, &hunks)),
+        (None, None) => Err(anyhow!("anchor {} has neither symbol nor range", anchor.id)),
+    }
+}
+
+struct HeadCommit {
+    sha: String,
+    epoch: i64,
+}
+
+fn head_commit(repo: &Path) -> Option<HeadCommit> {
+    let stdout = git_stdout(repo, &["log", "-1", "--format=%H %ct", "HEAD"]).ok()?;
+    let (sha, epoch) = stdout.trim().split_once(' ')?;
+    Some(HeadCommit {
+        sha: sha.to_string(),
+        epoch: epoch.parse().ok()?,
+    })
+}
+
+fn file_at(repo: &Path, sha: &str, file_path: &str) -> Option<String> {
+    git_stdout(repo, &["show", &format!("{sha}:{file_path}")]).ok()
+}
+
+fn git_stdout(repo: &Path, args: &[&str]) -> Result<String> {
+    let output = crate::git_util::git_output_soft(repo, args)
+        .ok_or_else(|| anyhow!("git {} did not run", args.join(" ")))?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "git {} failed: {}",
+            args.first().copied().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn relative_file_path(repo: Option<&Path>, file: &str) -> String {
+    let file = file.strip_prefix("./").unwrap_or(file);
+    if let Some(repo) = repo {
+        let path = Path::new(file);
+        let canonical = path.canonicalize().ok();
+        for candidate in [Some(path), canonical.as_deref()].into_iter().flatten() {
+            if let Ok(relative) = candidate.strip_prefix(repo) {
+                return relative.to_string_lossy().into_owned();
+            }
+        }
+    }
+    file.to_string()
+}
@@ -0,0 +1,324 @@
+//! Pure anchor re-resolution: `git diff -U0` hunk parsing, line-range
+//! shifting, and a language-agnostic symbol locator. No git or SQLite here so
+//! every drift rule is unit-testable on plain strings.
+
+const MAX_DRIFT_DIFF_CHARS: usize = 4_000;
+
+const DEFINITION_KEYWORDS: &[&str] = &[
+    "fn",
+    "def",
+    "class",
+    "struct",
+    "enum",
+    "trait",
+    "impl",
+    "interface",
+    "type",
+    "func",
+    "function",
+    "mod",
+    "module",
+    "const",
+    "static",
+    "let",
+    "var",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct LineRange {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl LineRange {
+    fn overlaps(self, other: LineRange) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+/// One `@@ -old_start,old_len +new_start,new_len @@` hunk with its body.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Hunk {
+    pub old_start: i64,
+    pub old_len: i64,
+    pub new_start: i64,
+    pub new_len: i64,
+    pub text: String,
+}
+
+impl Hunk {
+    /// True when the hunk removes, rewrites, or inserts lines inside `range`.
+    fn touches(&self, range: LineRange) -> bool {
+        if self.old_len == 0 {
+            // Pure insertion after `old_start`: only drift when it lands
+            // between two anchored lines.
+            return self.old_start >= range.start && self.old_start < range.end;
+        }
+        LineRange {
+            start: self.old_start,
+            end: self.old_start + self.old_len - 1,
+        }
+        .overlaps(range)
+    }
+
+    fn ends_before(&self, line: i64) -> bool {
+        if self.old_len == 0 {
+            self.old_start < line
+        } else {
+            self.old_start + self.old_len - 1 < line
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Drift {
+    Unchanged(LineRange),
+    Moved(LineRange),
+    Changed {
+        range: Option<LineRange>,
+        diff: String,
+    },
+    Missing(String),
+}
+
+impl Drift {
+    pub(crate) fn status(&self) -> &'static str {
+        match self {
+            Self::Unchanged(_) => "unchanged",
+            Self::Moved(_) => "moved",
+            Self::Changed { .. } => "changed",
+            Self::Missing(_) => "missing",
+        }
+    }
+}
+
+pub(crate) fn parse_unified_zero_diff(diff: &str) -> Vec<Hunk> {
+    let mut hunks: Vec<Hunk> = Vec::new();
+    for line in diff.lines() {
+        if let Some(header) = line.strip_prefix("@@ ") {
+            if let Some(hunk) = parse_hunk_header(header) {
+                let mut hunk = hunk;
+                hunk.text = line.to_string();
+                hunks.push(hunk);
+                continue;
+            }
+        }
+        if let Some(hunk) = hunks.last_mut() {
+            if line.starts_with('+') || line.starts_with('-') || line.starts_with('\\') {
+                hunk.text.push('\n');
+                hunk.text.push_str(line);
+            }
+        }
+    }
+    hunks
+}
+
+fn parse_hunk_header(header: &str) -> Option<Hunk> {
+    let mut parts = header.split_whitespace();
+    let (old_start, old_len) = parse_hunk_span(parts.next()?.strip_prefix('-')?)?;
+    let (new_start, new_len) = parse_hunk_span(parts.next()?.strip_prefix('+')?)?;
+    Some(Hunk {
+        old_start,
+        old_len,
+        new_start,
+        new_len,
+        text: String::new(),
+    })
+}
+
+fn parse_hunk_span(span: &str) -> Option<(i64, i64)> {
+    match span.split_once(',') {
+        Some((start, len)) => Some((start.parse().ok()?, len.parse().ok()?)),
+        None => Some((span.parse().ok()?, 1)),
+    }
+}
+
+/// Re-resolve a pure line-range anchor through a base..head diff.
+pub(crate) fn resolve_line_range(range: LineRange, hunks: &[Hunk]) -> Drift {
+    let touching = hunks
+        .iter()
+        .filter(|hunk| hunk.touches(range))
+        .collect::<Vec<_>>();
+    if !touching.is_empty() {
+        return Drift::Changed {
+            range: None,
+            diff: join_hunks(&touching),
+        };
+    }
+    let offset: i64 = hunks
+        .iter()
+        .filter(|hunk| hunk.ends_before(range.start))
+        .map(|hunk| hunk.new_len - hunk.old_len)
+        .sum();
+    let shifted = LineRange {
+        start: range.start + offset,
+        end: range.end + offset,
+    };
+    if offset == 0 {
+        Drift::Unchanged(shifted)
+    } else {
+        Drift::Moved(shifted)
+    }
+}
+
+/// Re-resolve a symbol anchor: locate it again in `head` and compare its body
+/// with the body at `base`. Relocation alone is `Moved`, not stale.
+pub(crate) fn resolve_symbol(
+    symbol: &str,
+    base_range: Option<LineRange>,
+    base: &str,
+    head: &str,
+    hunks: &[Hunk],
+) -> Drift {
+    let Some(base_range) = base_range.or_else(|| locate_symbol(base, symbol)) else {
+        return Drift::Missing(format!("symbol `{symbol}` not found at the saved commit"));
+    };
+    let Some(head_range) = locate_symbol(head, symbol) else {
+        return Drift::Missing(format!("symbol `{symbol}` no longer exists"));
+    };
+    if slice_lines(base, base_range) == slice_lines(head, head_range) {
+        return if head_range == base_range {
+            Drift::Unchanged(head_range)
+        } else {
+            Drift::Moved(head_range)
+        };
+    }
+    let touching = hunks
+        .iter()
+        .filter(|hunk| hunk.touches(base_range))
+        .collect::<Vec<_>>();
+    Drift::Changed {
+        range: Some(head_range),
+        diff: if touching.is_empty() {
+            format!("symbol `{symbol}` body changed")
+        } else {
+            join_hunks(&touching)
+        },
+    }
+}
+
+/// Find the definition line of the last path segment of `symbol`
+/// (`module::Type::method`, `pkg.Class.method`, or a bare name) and extend it
+/// to the end of its brace block or indented block. When the path names a
+/// parent, a definition inside the parent's block wins.
+pub(crate) fn locate_symbol(source: &str, symbol: &str) -> Option<LineRange> {
+    let segments = symbol
+        .split(['.', ':', '/', '#'])
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+    let name = *segments.last()?;
+    let lines = source.lines().collect::<Vec<_>>();
+    let candidates = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| defines(line, name))
+        .map(|(index, _)| block_range(&lines, index))
+        .collect::<Vec<_>>();
+    if segments.len() > 1 {
+        let parent = segments[segments.len() - 2];
+        let parents = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| defines(line, parent))
+            .map(|(index, _)| block_range(&lines, index))
+            .collect::<Vec<_>>();
+        if let Some(nested) = candidates.iter().find(|candidate| {
+            parents
+                .iter()
+                .any(|parent| parent.start < candidate.start && candidate.end <= parent.end)
+        }) {
+            return Some(*nested);
+        }
+    }
+    candidates.first().copied()
+}
+
+fn defines(line: &str, name: &str) -> bool {
+    let tokens = line
+        .split(|ch: char| !(ch.is_alphanumeric() || ch == '_' || ch == '$'))
+        .filter(|token| !token.is_empty())
+        .collect::<Vec<_>>();
+    tokens
+        .windows(2)
+        .any(|pair| DEFINITION_KEYWORDS.contains(&pair[0]) && pair[1] == name)
+        || tokens
+            .windows(4)
+            .any(|quad| quad[0] == "impl" && quad[2] == "for" && quad[3] == name)
+}
+
+fn block_range(lines: &[&str], start: usize) -> LineRange {
+    let first = lines[start];
+    let end = if first.trim_end().ends_with(':') {
+        indented_block_end(lines, start)
+    } else {
+        brace_block_end(lines, start).unwrap_or(start)
+    };
+    LineRange {
+        start: start as i64 + 1,
+        end: end as i64 + 1,
+    }
+}
+
+fn brace_block_end(lines: &[&str], start: usize) -> Option<usize> {
+    let mut depth = 0_i64;
+    let mut opened = false;
+    for (index, line) in lines.iter().enumerate().skip(start) {
+        for ch in line.chars() {
+            match ch {
+                '{' => {
+                    depth += 1;
+                    opened = true;
+                }
+                '}' => depth -= 1,
+                ';' if !opened && depth == 0 => return Some(index),
+                _ => {}
+            }
+        }
+        if opened && depth <= 0 {
+            return Some(index);
+        }
+    }
+    None
+}
+
+fn indented_block_end(lines: &[&str], start: usize) -> usize {
+    let indent = leading_spaces(lines[start]);
+    let mut end = start;
+    for (index, line) in lines.iter().enumerate().skip(start + 1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if leading_spaces(line) <= indent {
+            break;
+        }
+        end = index;
+    }
+    end
+}
+
+fn leading_spaces(line: &str) -> usize {
+    line.chars().take_while(|ch| ch.is_whitespace()).count()
+}
+
+fn slice_lines(source: &str, range: LineRange) -> Vec<&str> {
+    source
+        .lines()
+        .skip((range.start - 1).max(0) as usize)
+        .take((range.end - range.start + 1).max(0) as usize)
+        .map(str::trim_end)
+        .collect()
+}
+
+fn join_hunks(hunks: &[&Hunk]) -> String {
+    let mut out = hunks
+        .iter()
+        .map(|hunk| hunk.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+    if out.chars().count() > MAX_DRIFT_DIFF_CHARS {
+        out = out.chars().take(MAX_DRIFT_DIFF_CHARS).collect();
+        out.push_str("\n... (diff truncated)");
+    }
+    out
+}
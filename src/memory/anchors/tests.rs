@@ -0,0 +1,301 @@
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::Result;
+use rusqlite::Connection;
+
+use super::resolve::{locate_symbol, parse_unified_zero_diff, resolve_line_range, resolve_symbol};
+use super::*;
+use crate::db::test_support::ScopedTestDataDir;
+
+const BASE_SOURCE: &str = "use std::fmt;\n\nstruct Cache {\n    size: usize,\n}\n\nimpl Cache {\n    fn evict(&mut self) {\n        self.size = 0;\n    }\n\n    fn len(&self) -> usize {\n        self.size\n    }\n}\n";
+
+fn run_git(repo: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git").args(args).current_dir(repo).output()?;
+    anyhow::ensure!(
+        output.status.success(),
+        "git {} failed: {}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn init_repo(path: &Path) -> Result<()> {
+    std::fs::create_dir_all(path)?;
+    run_git(path, &["init", "-b", "main"])?;
+    run_git(
+        path,
+        &["config", "user.email", "remem-test@example.invalid"],
+    )?;
+    run_git(path, &["config", "user.name", "Remem Test"])?;
+    Ok(())
+}
+
+fn commit(repo: &Path, contents: &str, message: &str) -> Result<()> {
+    std::fs::write(repo.join("cache.rs"), contents)?;
+    run_git(repo, &["add", "cache.rs"])?;
+    run_git(repo, &["commit", "-m", message])?;
+    Ok(())
+}
+
+fn setup_memory(conn: &Connection, project: &str) -> Result<i64> {
+    crate::migrate::run_migrations(conn)?;
+    crate::memory::insert_memory(
+        conn,
+        None,
+        project,
+        None,
+        "Cache eviction",
+        "evict resets size to zero",
+        "discovery",
+        Some("[\"cache.rs\"]"),
+    )
+}
+
+#[test]
+fn parse_unified_zero_diff_reads_hunk_spans() {
+    let hunks = parse_unified_zero_diff(
+        "diff --git a/x b/x\n--- a/x\n+++ b/x\n@@ -3 +3,2 @@ fn a\n-old\n+new\n+more\n@@ -10,0 +12 @@\n+added\n",
+    );
+    assert_eq!(hunks.len(), 2);
+    assert_eq!(
+        (
+            hunks[0].old_start,
+            hunks[0].old_len,
+            hunks[0].new_start,
+            hunks[0].new_len
+        ),
+        (3, 1, 3, 2)
+    );
+    assert!(hunks[0].text.ends_with("+more"), "{}", hunks[0].text);
+    assert_eq!((hunks[1].old_start, hunks[1].old_len), (10, 0));
+}
+
+#[test]
+fn line_range_shifts_for_edits_above_and_drifts_for_overlap() {
+    let range = LineRange { start: 8, end: 10 };
+    let above = parse_unified_zero_diff("@@ -1,0 +2,2 @@\n+a\n+b\n");
+    assert_eq!(
+        resolve_line_range(range, &above),
+        Drift::Moved(LineRange { start: 10, end: 12 })
+    );
+    let below = parse_unified_zero_diff("@@ -12 +12 @@\n-x\n+y\n");
+    assert_eq!(resolve_line_range(range, &below), Drift::Unchanged(range));
+    let inside = parse_unified_zero_diff("@@ -9 +9 @@\n-x\n+y\n");
+    assert_eq!(resolve_line_range(range, &inside).status(), "changed");
+    let appended_after_last_line = parse_unified_zero_diff("@@ -10,0 +11 @@\n+z\n");
+    assert_eq!(
+        resolve_line_range(range, &appended_after_last_line),
+        Drift::Unchanged(range)
+    );
+}
+
+#[test]
+fn locate_symbol_prefers_definitions_nested_in_the_named_parent() {
+    assert_eq!(
+        locate_symbol(BASE_SOURCE, "Cache::evict"),
+        Some(LineRange { start: 8, end: 10 })
+    );
+    assert_eq!(
+        locate_symbol(BASE_SOURCE, "Cache"),
+        Some(LineRange { start: 3, end: 5 })
+    );
+    let python = "class Store:\n    def get(self):\n        return 1\n\n    def put(self):\n        pass\n\ndef get():\n    pass\n";
+    assert_eq!(
+        locate_symbol(python, "Store.get"),
+        Some(LineRange { start: 2, end: 3 })
+    );
+    assert_eq!(locate_symbol(python, "missing"), None);
+}
+
+#[test]
+fn resolve_symbol_treats_relocation_as_moved_not_changed() {
+    let head = format!("// header\n// more\n{BASE_SOURCE}");
+    let moved = resolve_symbol("Cache::evict", None, BASE_SOURCE, &head, &[]);
+    assert_eq!(moved, Drift::Moved(LineRange { start: 10, end: 12 }));
+
+    let edited = BASE_SOURCE.replace("self.size = 0;", "self.size = 1;");
+    let hunks =
+        parse_unified_zero_diff("@@ -9 +9 @@\n-        self.size = 0;\n+        self.size = 1;\n");
+    match resolve_symbol("Cache::evict", None, BASE_SOURCE, &edited, &hunks) {
+        Drift::Changed { range, diff } => {
+            assert_eq!(range, Some(LineRange { start: 8, end: 10 }));
+            assert!(diff.contains("self.size = 1"), "{diff}");
+        }
+        other => panic!("expected changed drift, got {other:?}"),
+    }
+
+    let removed = BASE_SOURCE.replace("fn evict", "fn clear");
+    assert_eq!(
+        resolve_symbol("Cache::evict", None, BASE_SOURCE, &removed, &[]).status(),
+        "missing"
+    );
+}
+
+#[test]
+fn validate_anchor_inputs_rejects_empty_and_inverted_anchors() {
+    let ok = MemoryAnchorInput {
+        file: "src/a.rs".to_string(),
+        symbol: Some("run".to_string()),
+        ..Default::default()
+    };
+    assert!(validate_anchor_inputs(std::slice::from_ref(&ok)).is_ok());
+    for (input, expected) in [
+        (
+            MemoryAnchorInput {
+                file: " ".to_string(),
+                ..ok.clone()
+            },
+            "require a file",
+        ),
+        (
+            MemoryAnchorInput {
+                symbol: None,
+                ..ok.clone()
+            },
+            "needs a symbol or a line range",
+        ),
+        (
+            MemoryAnchorInput {
+                start_line: Some(5),
+                end_line: Some(3),
+                ..ok.clone()
+            },
+            "invalid line range",
+        ),
+    ] {
+        let err = validate_anchor_inputs(&[input]).expect_err(expected);
+        assert!(err.contains(expected), "{err}");
+    }
+}
+
+#[test]
+fn anchors_outside_git_are_stored_untracked() -> Result<()> {
+    let test_dir = ScopedTestDataDir::new("anchors-untracked");
+    let plain = test_dir.path.join("plain");
+    std::fs::create_dir_all(&plain)?;
+    let conn = Connection::open_in_memory()?;
+    let memory_id = setup_memory(&conn, &plain.to_string_lossy())?;
+    let captured = capture_anchors(
+        &plain.to_string_lossy(),
+        &[MemoryAnchorInput {
+            file: "cache.rs".to_string(),
+            start_line: Some(2),
+            ..Default::default()
+        }],
+    );
+    replace_memory_anchors(&conn, memory_id, &captured)?;
+    let anchors = memory_anchors(&conn, memory_id)?;
+    assert_eq!(anchors.len(), 1);
+    assert_eq!(anchors[0].drift_status, "untracked");
+    assert_eq!(
+        (anchors[0].start_line, anchors[0].end_line),
+        (Some(2), Some(2))
+    );
+    assert_eq!(refresh_memory_anchors(&conn, memory_id)?, 0);
+    Ok(())
+}
+
+#[test]
+fn refresh_tracks_symbol_moves_and_body_changes_across_commits() -> Result<()> {
+    let test_dir = ScopedTestDataDir::new("anchors-git");
+    let repo = test_dir.path.join("repo");
+    init_repo(&repo)?;
+    commit(&repo, BASE_SOURCE, "base")?;
+    let project = repo.to_string_lossy().to_string();
+    let conn = Connection::open_in_memory()?;
+    let memory_id = setup_memory(&conn, &project)?;
+
+    let captured = capture_anchors(
+        &project,
+        &[
+            MemoryAnchorInput {
+                file: repo.join("cache.rs").to_string_lossy().to_string(),
+                symbol: Some("Cache::evict".to_string()),
+                ..Default::default()
+            },
+            MemoryAnchorInput {
+                file: "./cache.rs".to_string(),
+                start_line: Some(12),
+                end_line: Some(14),
+                ..Default::default()
+            },
+        ],
+    );
+    assert_eq!(captured[0].file_path, "cache.rs");
+    assert_eq!(
+        (captured[0].start_line, captured[0].end_line),
+        (Some(8), Some(10))
+    );
+    replace_memory_anchors(&conn, memory_id, &captured)?;
+    assert_eq!(refresh_memory_anchors(&conn, memory_id)?, 2);
+    let statuses = |conn: &Connection| -> Result<Vec<String>> {
+        Ok(memory_anchors(conn, memory_id)?
+            .into_iter()
+            .map(|anchor| anchor.drift_status)
+            .collect())
+    };
+    assert_eq!(statuses(&conn)?, vec!["unchanged", "unchanged"]);
+
+    commit(&repo, &format!("// license\n{BASE_SOURCE}"), "header")?;
+    assert_eq!(refresh_outdated_anchors(&conn, 10)?, 2);
+    let anchors = memory_anchors(&conn, memory_id)?;
+    assert_eq!(statuses(&conn)?, vec!["moved", "moved"]);
+    assert_eq!(anchors[0].resolved_start_line, Some(9));
+    assert_eq!(anchors[1].resolved_start_line, Some(13));
+    assert!(anchors.iter().all(MemoryAnchor::is_verified));
+
+    let edited = format!("// license\n{BASE_SOURCE}").replace("self.size = 0;", "self.size = 7;");
+    commit(&repo, &edited, "edit evict")?;
+    assert_eq!(refresh_outdated_anchors(&conn, 10)?, 2);
+    let anchors = memory_anchors(&conn, memory_id)?;
+    assert_eq!(statuses(&conn)?, vec!["changed", "moved"]);
+    assert!(anchors[0].has_drifted());
+    assert!(anchors[0]
+        .drift_diff
+        .as_deref()
+        .is_some_and(|diff| diff.contains("self.size = 7")));
+    assert_eq!(refresh_outdated_anchors(&conn, 10)?, 0);
+    Ok(())
+}
+
+#[test]
+fn unresolvable_anchor_is_recorded_and_does_not_starve_the_batch() -> Result<()> {
+    let test_dir = ScopedTestDataDir::new("anchors-unresolvable");
+    let repo = test_dir.path.join("repo");
+    init_repo(&repo)?;
+    commit(&repo, BASE_SOURCE, "base")?;
+    let project = repo.to_string_lossy().to_string();
+    let conn = Connection::open_in_memory()?;
+    let memory_id = setup_memory(&conn, &project)?;
+    let range = |start| MemoryAnchorInput {
+        file: "cache.rs".to_string(),
+        start_line: Some(start),
+        end_line: Some(start),
+        ..Default::default()
+    };
+    let captured = capture_anchors(&project, &[range(8), range(12)]);
+    replace_memory_anchors(&conn, memory_id, &captured)?;
+    let broken_id = memory_anchors(&conn, memory_id)?[0].id;
+    conn.execute(
+        "UPDATE memory_anchors SET base_commit_sha = ?1 WHERE id = ?2",
+        params!["0".repeat(40), broken_id],
+    )?;
+    commit(&repo, &format!("// license\n{BASE_SOURCE}"), "header")?;
+
+    assert_eq!(refresh_outdated_anchors(&conn, 1)?, 0);
+    let anchors = memory_anchors(&conn, memory_id)?;
+    assert_eq!(anchors[0].drift_status, "unresolvable");
+    assert!(anchors[0].checked_at_epoch.is_some());
+    assert!(anchors[0].drift_diff.is_some());
+    assert!(!anchors[0].has_drifted() && !anchors[0].is_verified());
+    assert_eq!(anchors[1].drift_status, "pending");
+
+    assert_eq!(refresh_outdated_anchors(&conn, 1)?, 1);
+    let anchors = memory_anchors(&conn, memory_id)?;
+    assert_eq!(anchors[0].drift_status, "unresolvable");
+    assert_eq!(anchors[1].drift_status, "moved");
+    assert_eq!(refresh_outdated_anchors(&conn, 1)?, 0);
+    Ok(())
+}
@@ -164,6 +164,20 @@ fn predicate_identities(content: &str) -> Vec<PredicateIdentity> {
                     value: conflict_key.trim().to_ascii_lowercase(),
                 }
             }
+            crate::rules::PreferencePredicate::FilePathGlob { glob, .. } => PredicateIdentity {
+                kind: "file_path_glob",
+                value: glob.trim().to_string(),
+            },
+            crate::rules::PreferencePredicate::ToolNameDeny { tool, .. } => PredicateIdentity {
+                kind: "tool_name_deny",
+                value: tool.trim().to_ascii_lowercase(),
+            },
+            crate::rules::PreferencePredicate::ContentRegex { conflict_key, .. } => {
+                PredicateIdentity {
+                    kind: "content_regex",
+                    value: conflict_key.trim().to_string(),
+                }
+            }
         })
         .collect()
 }
@@ -148,7 +148,8 @@ pub fn insert_raw_message_from_root_at(
     source_root: &str,
     created_at_epoch: Option<i64>,
 ) -> Result<Option<RawInsertOutcome>> {
-    let trimmed = content.trim();
+    let scrubbed = crate::adapter::redaction::scrub_secrets(content.trim());
+    let trimmed = scrubbed.as_ref();
     if trimmed.is_empty() {
         return Ok(None);
     }
@@ -319,7 +320,7 @@ pub fn drain_transcript_with_options(
         branch,
         cwd,
         options,
-        None,
+        crate::memory::raw_transcript::TranscriptBoundary::default(),
     )
 }
 
@@ -332,14 +333,14 @@ pub(crate) fn drain_transcript_with_capture_limit(
     branch: Option<&str>,
     cwd: Option<&str>,
     options: &TranscriptDrainOptions<'_>,
-    byte_limit: Option<u64>,
+    boundary: crate::memory::raw_transcript::TranscriptBoundary,
 ) -> Result<RawIngestReport> {
     let mut report = RawIngestReport::default();
     let mut record_ordinal = 0_i64;
     let stream_result = with_raw_archive_drain_savepoint(conn, || {
         crate::memory::raw_transcript::stream_transcript_lines(
             transcript_path,
-            byte_limit,
+            boundary,
             |line, is_final| {
                 let ordinal = record_ordinal;
                 record_ordinal += 1;
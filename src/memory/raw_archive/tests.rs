@@ -1,5 +1,6 @@
 use super::*;
 use crate::memory::raw_query::{parse_time_lower_bound, parse_time_upper_bound};
+use crate::memory::raw_transcript::TranscriptBoundary;
 
 fn setup_conn() -> Connection {
     let conn = Connection::open_in_memory().unwrap();
@@ -303,7 +304,7 @@ fn drain_transcript_honors_captured_byte_limit() -> Result<()> {
         None,
         None,
         &options,
-        Some((first.len() + 1) as u64),
+        TranscriptBoundary::bytes((first.len() + 1) as u64),
     )?;
 
     assert_eq!(report.inserted, 1);
@@ -334,7 +335,7 @@ fn drain_transcript_rejects_content_truncated_before_captured_boundary() -> Resu
         None,
         None,
         &options,
-        Some(content.len() as u64 + 10),
+        TranscriptBoundary::bytes(content.len() as u64 + 10),
     )?;
 
     assert!(report.read_error.is_some());
@@ -37,7 +37,8 @@ pub(crate) fn insert_transcript_occurrence(
     transcript_identity_id: i64,
     transcript_record_ordinal: i64,
 ) -> Result<Option<RawInsertOutcome>> {
-    let trimmed = content.trim();
+    let scrubbed = crate::adapter::redaction::scrub_secrets(content.trim());
+    let trimmed = scrubbed.as_ref();
     if trimmed.is_empty() {
         return Ok(None);
     }
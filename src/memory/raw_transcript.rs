@@ -74,41 +74,137 @@ fn transcript_is_meta(value: &Value) -> bool {
         .unwrap_or(false)
 }
 
+/// The snapshot of a transcript captured by a hook. JSONL transcripts only
+/// grow by appending, so their byte length bounds the snapshot. Session
+/// documents are rewritten in place, so any byte prefix of a grown document
+/// is invalid JSON; they are parsed whole and cut at the message count.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct TranscriptBoundary {
+    pub byte_len: Option<u64>,
+    pub message_count: Option<u64>,
+}
+
+impl TranscriptBoundary {
+    pub(crate) fn bytes(byte_len: u64) -> Self {
+        Self {
+            byte_len: Some(byte_len),
+            message_count: None,
+        }
+    }
+}
+
 pub(crate) fn read_transcript_content(
     transcript_path: &str,
-    byte_limit: Option<u64>,
+    boundary: TranscriptBoundary,
 ) -> std::io::Result<String> {
-    let Some(byte_limit) = byte_limit else {
-        return std::fs::read_to_string(transcript_path);
+    let mut lines = Vec::new();
+    stream_transcript_lines(transcript_path, boundary, |line, _| {
+        lines.push(line.to_string());
+    })?;
+    Ok(lines.join("\n"))
+}
+
+/// Gemini CLI records each chat as one pretty-printed JSON document
+/// (`{"sessionId": ..., "messages": [...]}`) that it rewrites in place. A
+/// lone `{` can never be a JSONL record, so it marks that shape.
+pub(crate) fn is_session_document_start(first_line: &str) -> bool {
+    first_line.trim() == "{"
+}
+
+/// Number of messages in a session document, or `None` for JSONL
+/// transcripts. Hooks record it next to the byte length at capture.
+pub(crate) fn session_document_message_count(
+    transcript_path: &str,
+) -> std::io::Result<Option<u64>> {
+    let mut reader = BufReader::new(std::fs::File::open(transcript_path)?);
+    let mut first = Vec::new();
+    reader.read_until(b'\n', &mut first)?;
+    if !is_session_document_start(&String::from_utf8_lossy(&first)) {
+        return Ok(None);
+    }
+    let mut document = first;
+    reader.read_to_end(&mut document)?;
+    let records = session_document_records(&document, None)?;
+    Ok(Some(records.len() as u64))
+}
+
+/// Replays a session document as one compact record per message, with the
+/// document's `sessionId` copied in, so line-oriented consumers see JSONL.
+/// A captured `message_count` keeps only the messages present at capture.
+fn session_document_records(
+    document: &[u8],
+    message_count: Option<u64>,
+) -> std::io::Result<Vec<String>> {
+    let invalid = |reason: &str| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("session document {reason}"),
+        )
     };
-    let file = std::fs::File::open(transcript_path)?;
-    let mut content = String::new();
-    file.take(byte_limit).read_to_string(&mut content)?;
-    if content.len() as u64 != byte_limit {
-        return Err(std::io::Error::new(
+    let document: Value =
+        serde_json::from_slice(document).map_err(|_| invalid("is not valid JSON"))?;
+    let session_id = document
+        .get("sessionId")
+        .and_then(Value::as_str)
+        .ok_or_else(|| invalid("has no sessionId"))?;
+    let messages = document
+        .get("messages")
+        .and_then(Value::as_array)
+        .ok_or_else(|| invalid("has no messages array"))?;
+    let captured = match message_count {
+        Some(count) => {
+            let count = usize::try_from(count).unwrap_or(usize::MAX);
+            if count > messages.len() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    format!(
+                        "session document shrank below captured boundary: expected {count} messages, read {}",
+                        messages.len()
+                    ),
+                ));
+            }
+            &messages[..count]
+        }
+        None => &messages[..],
+    };
+    captured
+        .iter()
+        .map(|message| {
+            let mut record = message.clone();
+            if let Some(object) = record.as_object_mut() {
+                object
+                    .entry("sessionId")
+                    .or_insert_with(|| Value::from(session_id));
+            }
+            serde_json::to_string(&record).map_err(std::io::Error::from)
+        })
+        .collect()
+}
+
+fn ensure_captured_size(expected: Option<u64>, read: u64) -> std::io::Result<()> {
+    match expected {
+        Some(expected) if expected != read => Err(std::io::Error::new(
             std::io::ErrorKind::UnexpectedEof,
             format!(
-                "transcript truncated before captured boundary: expected {byte_limit} bytes, read {}",
-                content.len()
+                "transcript truncated before captured boundary: expected {expected} bytes, read {read}"
             ),
-        ));
+        )),
+        _ => Ok(()),
     }
-    Ok(content)
 }
 
 /// Visit a transcript one line at a time while retaining at most the current
 /// and next JSONL records. A captured byte boundary is treated as an immutable
 /// snapshot: shorter files fail instead of silently draining a later shape.
+/// Session documents are read whole, cut at the captured message count, and
+/// visited one message record at a time; their byte length is never applied.
 pub(crate) fn stream_transcript_lines(
     transcript_path: &str,
-    byte_limit: Option<u64>,
+    boundary: TranscriptBoundary,
     mut visit: impl FnMut(&str, bool),
 ) -> std::io::Result<()> {
     let file = std::fs::File::open(transcript_path)?;
-    match byte_limit {
-        Some(limit) => stream_reader(file.take(limit), Some(limit), &mut visit),
-        None => stream_reader(file, None, &mut visit),
-    }
+    stream_reader(file, boundary, &mut visit)
 }
 
 pub(crate) fn stream_captured_transcript(
@@ -116,17 +212,35 @@ pub(crate) fn stream_captured_transcript(
     byte_limit: u64,
     mut visit: impl FnMut(&str, bool),
 ) -> std::io::Result<()> {
-    stream_reader(file.take(byte_limit), Some(byte_limit), &mut visit)
+    stream_reader(file, TranscriptBoundary::bytes(byte_limit), &mut visit)
 }
 
 fn stream_reader(
     reader: impl Read,
-    expected_bytes: Option<u64>,
+    boundary: TranscriptBoundary,
     visit: &mut impl FnMut(&str, bool),
 ) -> std::io::Result<()> {
     let mut reader = BufReader::new(reader);
-    let mut pending = None;
-    let mut total_bytes = 0_u64;
+    // The first line is read unbounded: it decides the transcript shape, and
+    // a session document must never be cut at a byte length.
+    let mut first = Vec::new();
+    reader.read_until(b'\n', &mut first)?;
+    if is_session_document_start(&String::from_utf8_lossy(&first)) {
+        let mut document = first;
+        reader.read_to_end(&mut document)?;
+        let records = session_document_records(&document, boundary.message_count)?;
+        let last = records.len().saturating_sub(1);
+        for (index, record) in records.iter().enumerate() {
+            visit(record, index == last);
+        }
+        return Ok(());
+    }
+    let byte_limit = boundary.byte_len.unwrap_or(u64::MAX);
+    first.truncate(usize::try_from(byte_limit).unwrap_or(usize::MAX));
+    let first_len = first.len() as u64;
+    let mut reader = reader.take(byte_limit - first_len);
+    let mut pending = (first_len > 0).then_some(first);
+    let mut total_bytes = first_len;
 
     loop {
         let mut next = Vec::new();
@@ -142,16 +256,7 @@ fn stream_reader(
         }
     }
 
-    if let Some(expected) = expected_bytes {
-        if total_bytes != expected {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::UnexpectedEof,
-                format!(
-                    "transcript truncated before captured boundary: expected {expected} bytes, read {total_bytes}"
-                ),
-            ));
-        }
-    }
+    ensure_captured_size(boundary.byte_len, total_bytes)?;
     if let Some(line) = pending {
         visit_line(&line, true, visit)?;
     }
@@ -181,7 +286,13 @@ pub(crate) fn parse_transcript_message(value: &Value) -> Option<ParsedTranscript
     match value.get("type").and_then(Value::as_str)? {
         "user" => Some(ParsedTranscriptMessage {
             role: ROLE_USER,
-            text: extract_content_text(&value["message"]["content"]),
+            text: extract_content_text(message_content(value)),
+            created_at_epoch,
+        }),
+        // Gemini CLI session documents carry `content` on the record itself.
+        "gemini" => Some(ParsedTranscriptMessage {
+            role: ROLE_ASSISTANT,
+            text: extract_content_text(&value["content"]),
             created_at_epoch,
         }),
         "assistant" => Some(ParsedTranscriptMessage {
@@ -194,6 +305,13 @@ pub(crate) fn parse_transcript_message(value: &Value) -> Option<ParsedTranscript
     }
 }
 
+fn message_content(value: &Value) -> &Value {
+    match value.get("message") {
+        Some(message) => &message["content"],
+        None => &value["content"],
+    }
+}
+
 fn parse_codex_response_item(value: &Value) -> Option<ParsedTranscriptMessage> {
     let payload = value.get("payload")?;
     if payload.get("type").and_then(Value::as_str) != Some("message") {
@@ -219,7 +337,8 @@ fn extract_content_text(content: &Value) -> String {
         let parts: Vec<String> = array
             .iter()
             .filter_map(|entry| match entry.get("type").and_then(Value::as_str) {
-                Some("text" | "input_text" | "output_text") => entry
+                // Gemini `Part`s are untyped `{"text": ...}` objects.
+                Some("text" | "input_text" | "output_text") | None => entry
                     .get("text")
                     .and_then(Value::as_str)
                     .map(str::to_string),
@@ -369,4 +488,113 @@ mod tests {
         std::fs::remove_file(path)?;
         Ok(())
     }
+
+    #[test]
+    fn gemini_session_document_replays_as_message_records() -> std::io::Result<()> {
+        let path = std::env::temp_dir().join(format!(
+            "remem-gemini-session-{}-{}.json",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let document = serde_json::json!({
+            "sessionId": "g-1",
+            "projectHash": "abc",
+            "messages": [
+                {"id": "m1", "timestamp": "2026-01-01T00:00:00Z", "type": "user", "content": "fix the parser"},
+                {"id": "m2", "timestamp": "2026-01-01T00:00:05Z", "type": "gemini", "content": [{"text": "Patched parse.rs"}]},
+                {"id": "m3", "timestamp": "2026-01-01T00:00:06Z", "type": "info", "content": "tool output"}
+            ]
+        });
+        std::fs::write(&path, serde_json::to_string_pretty(&document)?)?;
+        let size = std::fs::metadata(&path)?.len();
+        let mut records = Vec::new();
+
+        stream_transcript_lines(
+            path.to_str().unwrap(),
+            TranscriptBoundary::bytes(size),
+            |line, is_final| {
+                records.push((classify_transcript_line(line, None), is_final));
+            },
+        )?;
+        let content =
+            read_transcript_content(path.to_str().unwrap(), TranscriptBoundary::default())?;
+
+        assert_eq!(records.len(), 3);
+        let TranscriptRecordClass::Conversation(user) = &records[0].0 else {
+            panic!("user record should be conversation: {records:?}");
+        };
+        assert_eq!(
+            (user.role, user.text.as_str()),
+            (ROLE_USER, "fix the parser")
+        );
+        let TranscriptRecordClass::Conversation(reply) = &records[1].0 else {
+            panic!("gemini record should be conversation: {records:?}");
+        };
+        assert_eq!(reply.role, ROLE_ASSISTANT);
+        assert_eq!(reply.created_at_epoch, Some(1_767_225_605));
+        assert_eq!(records[2], (TranscriptRecordClass::UnsupportedRecord, true));
+        assert_eq!(content.lines().count(), 3);
+        assert!(content
+            .lines()
+            .all(|line| line.contains(r#""sessionId":"g-1""#)));
+        let beyond_capture = TranscriptBoundary {
+            byte_len: Some(size),
+            message_count: Some(4),
+        };
+        assert!(
+            stream_transcript_lines(path.to_str().unwrap(), beyond_capture, |_, _| {}).is_err()
+        );
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn gemini_session_document_grown_after_capture_replays_captured_messages() -> std::io::Result<()>
+    {
+        let path = std::env::temp_dir().join(format!(
+            "remem-gemini-grown-{}-{}.json",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let mut document = serde_json::json!({
+            "sessionId": "g-2",
+            "messages": [
+                {"id": "m1", "timestamp": "2026-01-01T00:00:00Z", "type": "user", "content": "fix the parser"},
+                {"id": "m2", "timestamp": "2026-01-01T00:00:05Z", "type": "gemini", "content": [{"text": "Patched parse.rs"}]}
+            ]
+        });
+        std::fs::write(&path, serde_json::to_string_pretty(&document)?)?;
+        let transcript = path.to_str().unwrap();
+        let captured = TranscriptBoundary {
+            byte_len: Some(std::fs::metadata(&path)?.len()),
+            message_count: session_document_message_count(transcript)?,
+        };
+        document["messages"]
+            .as_array_mut()
+            .unwrap()
+            .push(serde_json::json!({
+                "id": "m3",
+                "timestamp": "2026-01-01T00:01:00Z",
+                "type": "user",
+                "content": "now the lexer"
+            }));
+        std::fs::write(&path, serde_json::to_string_pretty(&document)?)?;
+        let mut texts = Vec::new();
+
+        stream_transcript_lines(transcript, captured, |line, _| {
+            if let TranscriptRecordClass::Conversation(message) =
+                classify_transcript_line(line, None)
+            {
+                texts.push(message.text);
+            }
+        })?;
+        let content = read_transcript_content(transcript, captured)?;
+
+        assert_eq!(captured.message_count, Some(2));
+        assert_eq!(texts, vec!["fix the parser", "Patched parse.rs"]);
+        assert_eq!(content.lines().count(), 2);
+        assert!(!content.contains("now the lexer"));
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
 }
@@ -84,12 +84,39 @@ fn save_memory_inner(
     req: &SaveMemoryRequest,
     reference_time_epoch: Option<i64>,
 ) -> Result<SaveMemoryResult> {
+    let scrubbed_req;
+    let req = match scrub_request_secrets(req) {
+        Some(scrubbed) => {
+            scrubbed_req = scrubbed;
+            &scrubbed_req
+        }
+        None => req,
+    };
     let validated = validate_save_memory_request(req)?;
     let project = req.project.as_deref().unwrap_or("manual");
     let title = req.title.as_deref().unwrap_or("Memory");
     let memory_type = validated.memory_type.as_str();
-    let files_json = req
-        .files
+    let anchors = req
+        .anchors
+        .as_deref()
+        .map(|inputs| crate::memory::anchors::capture_anchors(project, inputs));
+    // Anchored files count as the memory's files when none were given, so
+    // file-level staleness still covers anchors that cannot be re-resolved.
+    let files = req.files.clone().or_else(|| {
+        anchors
+            .as_ref()
+            .filter(|anchors| !anchors.is_empty())
+            .map(|anchors| {
+                let mut files = Vec::new();
+                for anchor in anchors {
+                    if !files.contains(&anchor.file_path) {
+                        files.push(anchor.file_path.clone());
+                    }
+                }
+                files
+            })
+    });
+    let files_json = files
         .as_ref()
         .and_then(|files| serde_json::to_string(files).ok());
 
@@ -151,6 +178,7 @@ fn save_memory_inner(
                 Some(id),
             )?;
             mark_direct_save_poisoning_metadata(conn, id, acknowledgement)?;
+            store_anchors(conn, id, anchors.as_deref())?;
             Ok((id, logged_plan.op))
         })
     } else {
@@ -181,6 +209,7 @@ fn save_memory_inner(
                     Some(id),
                 )?;
                 mark_direct_save_poisoning_metadata(conn, id, acknowledgement)?;
+                store_anchors(conn, id, anchors.as_deref())?;
                 return Ok((id, MemoryLifecycleOp::Noop));
             }
             let previous_preference = if memory_type == "preference" {
@@ -226,6 +255,7 @@ fn save_memory_inner(
                 )?;
             }
             mark_direct_save_poisoning_metadata(conn, result.0, acknowledgement)?;
+            store_anchors(conn, result.0, anchors.as_deref())?;
             Ok(result)
         })
     };
@@ -278,6 +308,26 @@ fn save_memory_inner(
     })
 }
 
+/// Copy of `req` with detected secrets replaced in the title and text, or
+/// `None` when both are clean. Keeps secrets out of the store, the FTS index
+/// and the local markdown copy.
+fn scrub_request_secrets(req: &SaveMemoryRequest) -> Option<SaveMemoryRequest> {
+    let detectors = crate::adapter::redaction::capture_detectors();
+    let (text, text_matches) = detectors.scrub(&req.text);
+    let title = req.title.as_deref().map(|title| detectors.scrub(title));
+    let title_dirty = title
+        .as_ref()
+        .is_some_and(|(_, matches)| !matches.is_empty());
+    if text_matches.is_empty() && !title_dirty {
+        return None;
+    }
+    Some(SaveMemoryRequest {
+        text: text.into_owned(),
+        title: title.map(|(title, _)| title.into_owned()),
+        ..req.clone()
+    })
+}
+
 fn direct_save_pattern_acknowledgement(
     title: &str,
     text: &str,
@@ -381,9 +431,25 @@ fn validate_save_memory_request(req: &SaveMemoryRequest) -> Result<ValidatedSave
         None => "project".to_string(),
     };
 
+    if let Some(anchors) = req.anchors.as_deref() {
+        crate::memory::anchors::validate_anchor_inputs(anchors)
+            .map_err(SaveMemoryValidationError::new)?;
+    }
+
     Ok(ValidatedSaveMemoryRequest { memory_type, scope })
 }
 
+fn store_anchors(
+    conn: &Connection,
+    memory_id: i64,
+    anchors: Option<&[crate::memory::anchors::CapturedAnchor]>,
+) -> Result<()> {
+    match anchors {
+        Some(anchors) => crate::memory::anchors::replace_memory_anchors(conn, memory_id, anchors),
+        None => Ok(()),
+    }
+}
+
 struct ClaimSaveResult {
     status: String,
     id: Option<i64>,
@@ -53,22 +53,45 @@ pub(crate) fn search_memories_with_explain_details_with_routing(
             req.branch.as_deref(),
             req.include_suppressed,
             true,
-            search_execution_policy(true, routing),
+            search_execution_policy(
+                true,
+                crate::retrieval::search::SearchWeights::default(),
+                routing,
+            ),
         )?
     } else {
-        crate::retrieval::search::search_with_branch_execution_policy_with_suppressed_policy(
-            conn,
-            query,
+        let project_weights =
+            crate::retrieval::search::SearchWeights::for_project(req.project.as_deref());
+        let run = |weights| {
+            crate::retrieval::search::search_with_branch_execution_policy_with_suppressed_policy(
+                conn,
+                query,
+                req.project.as_deref(),
+                req.memory_type.as_deref(),
+                limit + 1,
+                req.offset.max(0),
+                req.include_stale,
+                req.branch.as_deref(),
+                req.include_suppressed,
+                false,
+                search_execution_policy(false, weights, routing),
+            )
+        };
+        let (memories, explain_details) = run(project_weights.weights)?;
+        // Routed plans pin their own channel weights, and listing without a
+        // query ignores them, so only plain text search runs the shadow.
+        let shadow = project_weights
+            .shadow
+            .as_ref()
+            .filter(|_| routing.is_none() && query.is_some_and(|query| !query.is_empty()));
+        crate::retrieval::search::log_shadow_ranking(
+            "search",
             req.project.as_deref(),
-            req.memory_type.as_deref(),
-            limit + 1,
-            req.offset.max(0),
-            req.include_stale,
-            req.branch.as_deref(),
-            req.include_suppressed,
-            false,
-            search_execution_policy(false, routing),
-        )?
+            shadow,
+            &memories,
+            |weights| run(weights).map(|(memories, _)| memories),
+        );
+        (memories, explain_details)
     };
     let has_more = memories.len() as i64 > limit;
     memories.truncate(limit as usize);
@@ -195,18 +218,14 @@ fn maybe_fallback_raw(
 
 fn search_execution_policy(
     explain: bool,
+    base: crate::retrieval::search::SearchWeights,
     routing: Option<&SearchRoutingPolicy>,
 ) -> crate::retrieval::search::SearchExecutionPolicy {
-    let base = if explain {
-        crate::retrieval::search::SearchWeights::default()
-    } else {
-        crate::retrieval::search::SearchWeights::production()
-    };
     let Some(routing) = routing else {
         return if explain {
             crate::retrieval::search::SearchExecutionPolicy::explain_default()
         } else {
-            crate::retrieval::search::SearchExecutionPolicy::production()
+            crate::retrieval::search::SearchExecutionPolicy::with_weights(base)
         };
     };
     let mut weights = base;
@@ -153,6 +153,9 @@ pub struct SaveMemoryRequest {
     pub claim_enabled: Option<bool>,
     pub claim_source: Option<String>,
     pub acknowledge_pattern: Option<String>,
+    /// Symbol/line-range anchors. `None` keeps a reinforced memory's existing
+    /// anchors; `Some` replaces them.
+    pub anchors: Option<Vec<crate::memory::anchors::MemoryAnchorInput>>,
 }
 
 #[derive(Debug, Clone)]
@@ -149,6 +149,12 @@ pub(crate) fn memory_staleness_labels_for_memories_lossy(
     Ok(labels)
 }
 
+/// Project-relative paths listed in a memory's `files` column, normalized the
+/// same way source-anchor staleness compares them.
+pub(crate) fn memory_file_paths(raw: Option<&str>, project: &str) -> Result<HashSet<String>> {
+    parse_file_list(raw, project)
+}
+
 pub fn memory_staleness(memory: &Memory, now_epoch: i64) -> String {
     memory_staleness_label(memory, now_epoch).label
 }
@@ -173,6 +179,11 @@ fn source_anchor_for_memory(
     memory: &Memory,
     capabilities: &StalenessCapabilities,
 ) -> Result<&'static str> {
+    if capabilities.memory_anchors_exists {
+        if let Some(source_anchor) = code_anchor_for_memory(conn, memory.id)? {
+            return Ok(source_anchor);
+        }
+    }
     if !capabilities.git_trace_tables_exist {
         return Ok("untracked");
     }
@@ -235,6 +246,30 @@ fn source_anchor_for_memory(
     }
 }
 
+/// Symbol/line anchors are more precise than file-level commit tracing: any
+/// drifted anchor needs verification, and fully verified anchors mean edits
+/// elsewhere in the file do not. Pending or untracked anchors defer to the
+/// file-level check.
+fn code_anchor_for_memory(conn: &Connection, memory_id: i64) -> Result<Option<&'static str>> {
+    let anchors = super::anchors::memory_anchors(conn, memory_id)?;
+    if anchors.is_empty() {
+        return Ok(None);
+    }
+    if anchors
+        .iter()
+        .any(super::anchors::MemoryAnchor::has_drifted)
+    {
+        return Ok(Some("verify-before-trust"));
+    }
+    if anchors
+        .iter()
+        .all(super::anchors::MemoryAnchor::is_verified)
+    {
+        return Ok(Some("tracked"));
+    }
+    Ok(None)
+}
+
 fn source_project_for_memory(
     conn: &Connection,
     memory: &Memory,
@@ -5,6 +5,7 @@ use rusqlite::Connection;
 pub(super) struct StalenessCapabilities {
     pub(super) git_trace_tables_exist: bool,
     pub(super) git_commit_files_exists: bool,
+    pub(super) memory_anchors_exists: bool,
     pub(super) memories_exists: bool,
     pub(super) memories_source_project: bool,
     pub(super) memories_evidence_event_ids: bool,
@@ -30,6 +31,7 @@ impl StalenessCapabilities {
         Ok(Self {
             git_trace_tables_exist: git_trace_tables_exist(conn)?,
             git_commit_files_exists: table_exists(conn, "git_commit_files")?,
+            memory_anchors_exists: table_exists(conn, "memory_anchors")?,
             memories_exists,
             memories_source_project: memories_exists
                 && column_exists(conn, "memories", "source_project")?,
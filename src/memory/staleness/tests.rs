@@ -102,6 +102,56 @@ fn source_anchor_requires_verification_after_later_file_change() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn code_anchors_override_file_level_commit_tracing() -> Result<()> {
+    let conn = migrated_staleness_db()?;
+    let mut memory = tracked_staleness_memory(Some(r#"["src/lib.rs"]"#));
+    memory.id = crate::memory::insert_memory(
+        &conn,
+        Some("mem-session-1"),
+        "proj",
+        None,
+        "Anchored",
+        "body",
+        "decision",
+        memory.files.as_deref(),
+    )?;
+    link_staleness_commit(
+        &conn,
+        1,
+        "source-sha",
+        100,
+        &["src/lib.rs"],
+        "mem-session-1",
+    )?;
+    insert_staleness_commit(&conn, 2, "later-sha", 200, &["src/lib.rs"])?;
+    conn.execute(
+        "INSERT INTO memory_anchors
+         (memory_id, file_path, symbol, start_line, end_line, repo_path, base_commit_sha,
+          drift_status, created_at_epoch)
+         VALUES (?1, 'src/lib.rs', 'run', 3, 9, '/repo', 'source-sha', 'moved', 0)",
+        [memory.id],
+    )?;
+
+    let label = memory_staleness_label_with_conn(&conn, &memory, 1_700_000_000)?;
+    assert_eq!(label.source_anchor, "tracked");
+
+    conn.execute(
+        "UPDATE memory_anchors SET drift_status = 'pending' WHERE memory_id = ?1",
+        [memory.id],
+    )?;
+    let label = memory_staleness_label_with_conn(&conn, &memory, 1_700_000_000)?;
+    assert_eq!(label.source_anchor, "verify-before-trust");
+
+    conn.execute(
+        "UPDATE memory_anchors SET drift_status = 'changed' WHERE memory_id = ?1",
+        [memory.id],
+    )?;
+    let label = memory_staleness_label_with_conn(&conn, &memory, 1_700_000_000)?;
+    assert_eq!(label.source_anchor, "verify-before-trust");
+    Ok(())
+}
+
 #[test]
 fn batch_source_anchor_labels_preserve_per_memory_results() -> Result<()> {
     let conn = migrated_staleness_db()?;
@@ -10,6 +10,7 @@ use crate::memory::{
     },
     preference::consolidation::PreferenceConsolidationKind,
 };
+use crate::retrieval::entity::ProjectVocabulary;
 
 pub fn insert_memory(
     conn: &Connection,
@@ -104,6 +105,42 @@ pub fn insert_memory_full_with_reference_time(
     scope: &str,
     created_at_override: Option<i64>,
     reference_time_override: Option<i64>,
+) -> Result<i64> {
+    // A vocabulary cache miss scans git history and manifests; do it before
+    // the write savepoint so the database lock is not held across it.
+    let vocabulary = ProjectVocabulary::for_project(conn, project);
+    insert_memory_with_vocabulary(
+        conn,
+        &vocabulary,
+        session_id,
+        project,
+        topic_key,
+        title,
+        content,
+        memory_type,
+        files,
+        branch,
+        scope,
+        created_at_override,
+        reference_time_override,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn insert_memory_with_vocabulary(
+    conn: &Connection,
+    vocabulary: &ProjectVocabulary,
+    session_id: Option<&str>,
+    project: &str,
+    topic_key: Option<&str>,
+    title: &str,
+    content: &str,
+    memory_type: &str,
+    files: Option<&str>,
+    branch: Option<&str>,
+    scope: &str,
+    created_at_override: Option<i64>,
+    reference_time_override: Option<i64>,
 ) -> Result<i64> {
     let now = chrono::Utc::now().timestamp();
     let created_at = created_at_override.unwrap_or(now);
@@ -220,7 +257,7 @@ pub fn insert_memory_full_with_reference_time(
                 now,
                 reference_time,
             )?;
-            refresh_memory_entities(conn, id, title, content)?;
+            refresh_memory_entities(conn, vocabulary, id, project, title, content)?;
             refresh_memory_embedding(conn, id, title, content, memory_type, topic_key)?;
             Ok(id)
         });
@@ -262,7 +299,7 @@ pub fn insert_memory_full_with_reference_time(
         )?;
         let id = conn.last_insert_rowid();
         attach_state_key(conn, id, memory_type, &ownership, state_key.as_ref(), now)?;
-        refresh_memory_entities(conn, id, title, content)?;
+        refresh_memory_entities(conn, vocabulary, id, project, title, content)?;
         refresh_memory_embedding(conn, id, title, content, memory_type, topic_key)?;
         Ok(id)
     })
@@ -285,9 +322,11 @@ pub fn insert_memory_full_with_operation_log(
     operation_input: &MemoryOperationInput,
     operation_plan: &MemoryOperationPlan,
 ) -> Result<(i64, MemoryLifecycleOp)> {
+    let vocabulary = ProjectVocabulary::for_project(conn, project);
     with_operation_savepoint(conn, || {
-        let id = insert_memory_full_with_reference_time(
+        let id = insert_memory_with_vocabulary(
             conn,
+            &vocabulary,
             session_id,
             project,
             topic_key,
@@ -514,8 +553,17 @@ fn with_memory_savepoint<T>(conn: &Connection, f: impl FnOnce() -> Result<T>) ->
     }
 }
 
-fn refresh_memory_entities(conn: &Connection, id: i64, title: &str, content: &str) -> Result<()> {
-    let entities = crate::retrieval::entity::extract_entities(title, content);
+fn refresh_memory_entities(
+    conn: &Connection,
+    vocabulary: &ProjectVocabulary,
+    id: i64,
+    project: &str,
+    title: &str,
+    content: &str,
+) -> Result<()> {
+    let entities = crate::retrieval::entity::extract_project_entities_with(
+        conn, vocabulary, project, title, content,
+    );
     crate::retrieval::entity::refresh_memory_entities(conn, id, &entities)
         .with_context(|| format!("entity refresh failed for memory id={id}"))
 }
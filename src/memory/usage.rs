@@ -1,7 +1,7 @@
 use std::collections::{BTreeSet, HashMap};
 
 use anyhow::{bail, Context, Result};
-use rusqlite::params;
+use rusqlite::{params, OptionalExtension};
 use serde::{Deserialize, Serialize};
 
 const CITATION_PREFIX: &str = "Memory citations:";
@@ -295,6 +295,90 @@ fn injected_memory_items(
     Ok(injected)
 }
 
+/// One matched citation turned into a ranking example: the user prompt that
+/// preceded it in the session and the injected memories the assistant cited.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UsageFeedbackQuery {
+    pub citation_event_id: i64,
+    pub session_id: String,
+    pub prompt: Option<String>,
+    pub memory_ids: Vec<i64>,
+}
+
+/// Matched citation events for `project`, newest first, each paired with the
+/// latest user prompt archived in the same session at or before the citation.
+/// `prompt` is `None` when the raw archive holds no such message.
+pub(crate) fn load_usage_feedback_queries(
+    conn: &rusqlite::Connection,
+    project: &str,
+    limit: usize,
+) -> Result<Vec<UsageFeedbackQuery>> {
+    let mut stmt = conn.prepare(
+        "SELECT c.id, c.session_id, c.created_at_epoch, u.memory_id
+         FROM memory_citation_events c
+         JOIN memory_usage_events u ON u.citation_event_id = c.id
+         WHERE c.project = ?1 AND c.status = 'matched'
+           AND c.id IN (
+               SELECT id FROM memory_citation_events
+               WHERE project = ?1 AND status = 'matched'
+               ORDER BY created_at_epoch DESC, id DESC
+               LIMIT ?2
+           )
+         ORDER BY c.created_at_epoch DESC, c.id DESC, u.memory_id",
+    )?;
+    let rows = stmt.query_map(params![project, limit as i64], |row| {
+        Ok((
+            row.get::<_, i64>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, i64>(2)?,
+            row.get::<_, i64>(3)?,
+        ))
+    })?;
+    let mut events: Vec<(UsageFeedbackQuery, i64)> = Vec::new();
+    for row in rows {
+        let (citation_event_id, session_id, created_at_epoch, memory_id) = row?;
+        match events.last_mut() {
+            Some((event, _)) if event.citation_event_id == citation_event_id => {
+                event.memory_ids.push(memory_id);
+            }
+            _ => events.push((
+                UsageFeedbackQuery {
+                    citation_event_id,
+                    session_id,
+                    prompt: None,
+                    memory_ids: vec![memory_id],
+                },
+                created_at_epoch,
+            )),
+        }
+    }
+
+    let mut prompt_stmt = conn.prepare(
+        "SELECT content FROM raw_messages
+         WHERE project = ?1 AND session_id = ?2 AND role = ?3 AND created_at_epoch <= ?4
+         ORDER BY created_at_epoch DESC, id DESC
+         LIMIT 1",
+    )?;
+    let mut feedback = Vec::with_capacity(events.len());
+    for (mut event, created_at_epoch) in events {
+        event.prompt = prompt_stmt
+            .query_row(
+                params![
+                    project,
+                    event.session_id,
+                    crate::memory::raw_archive::ROLE_USER,
+                    created_at_epoch
+                ],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()?
+            .map(|prompt| prompt.trim().to_string())
+            .filter(|prompt| !prompt.is_empty());
+        feedback.push(event);
+    }
+    Ok(feedback)
+}
+
 pub(crate) fn query_memory_usage_feedback_stats(
     conn: &rusqlite::Connection,
 ) -> Result<MemoryUsageFeedbackStats> {
@@ -140,3 +140,49 @@ fn usage_feedback_stats_expose_no_citation_and_unmatched_categories() -> Result<
     assert_eq!(stats.usage_events, 1);
     Ok(())
 }
+
+#[test]
+fn usage_feedback_pairs_matched_citations_with_preceding_user_prompt() -> Result<()> {
+    let conn = rusqlite::Connection::open_in_memory()?;
+    crate::migrate::run_migrations(&conn)?;
+    let cited = insert_usage_memory(&conn, "cited")?;
+    insert_injected_item(&conn, cited)?;
+    crate::memory::raw_archive::insert_raw_message(
+        &conn,
+        "sess-1",
+        "/repo",
+        crate::memory::raw_archive::ROLE_USER,
+        "  how do we retry ptx settlement?  ",
+        crate::memory::raw_archive::SOURCE_HOOK,
+        None,
+        None,
+    )?;
+    record_stop_memory_citations(
+        &conn,
+        "codex-cli",
+        "/repo",
+        "sess-1",
+        "hash-a",
+        &format!("Used memory.\nMemory citations: memory:#{cited}"),
+    )?;
+    record_stop_memory_citations(
+        &conn,
+        "codex-cli",
+        "/repo",
+        "sess-1",
+        "hash-none",
+        "Memory citations: none",
+    )?;
+
+    let feedback = load_usage_feedback_queries(&conn, "/repo", 10)?;
+
+    assert_eq!(feedback.len(), 1);
+    assert_eq!(feedback[0].session_id, "sess-1");
+    assert_eq!(
+        feedback[0].prompt.as_deref(),
+        Some("how do we retry ptx settlement?")
+    );
+    assert_eq!(feedback[0].memory_ids, vec![cited]);
+    assert!(load_usage_feedback_queries(&conn, "/other", 10)?.is_empty());
+    Ok(())
+}
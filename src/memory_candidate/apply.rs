@@ -13,6 +13,7 @@ use crate::memory::poisoning::SourceTrustClass;
 use crate::memory::preference::consolidation::{
     load_active_preference_content, PreferenceConsolidationKind,
 };
+use crate::retrieval::entity::ProjectVocabulary;
 
 mod dream_supersede;
 
@@ -121,6 +122,7 @@ pub(super) fn promote_candidate_to_memory_with_route_and_policy(
     let title = candidate_title(candidate);
     let memory_project = route.memory_project(source_project);
     let memory_scope = route.memory_scope();
+    let vocabulary = ProjectVocabulary::for_project(conn, &memory_project);
 
     with_operation_savepoint(conn, || {
         let now = chrono::Utc::now().timestamp();
@@ -303,6 +305,7 @@ pub(super) fn promote_candidate_to_memory_with_route_and_policy(
             state_key.as_ref(),
             reference_time_epoch,
             source_trust,
+            &vocabulary,
         )?;
         plan.target_memory_id = Some(memory_id);
         let superseded = soft_supersede_routed(conn, &superseded_ids, Some(memory_id))?;
@@ -578,6 +581,7 @@ fn insert_routed_memory(
     state_key: Option<&crate::memory::state_key::StateKeyDecision>,
     reference_time_epoch: i64,
     source_trust: SourceTrustClass,
+    vocabulary: &ProjectVocabulary,
 ) -> Result<i64> {
     let now = chrono::Utc::now().timestamp();
     let (expires_at_epoch, valid_from_epoch) = crate::memory::lifecycle::ttl_metadata(
@@ -646,7 +650,14 @@ fn insert_routed_memory(
     if candidate.memory_type == "lesson" {
         insert_lesson_metadata(conn, memory_id, candidate, evidence_json, now)?;
     }
-    refresh_memory_entities(conn, memory_id, title, &candidate.text)?;
+    refresh_memory_entities(
+        conn,
+        vocabulary,
+        memory_id,
+        memory_project,
+        title,
+        &candidate.text,
+    )?;
     crate::retrieval::vector::upsert_memory_embedding_for_row(conn, memory_id)?;
     Ok(memory_id)
 }
@@ -731,8 +742,17 @@ fn insert_lesson_metadata(
     Ok(())
 }
 
-fn refresh_memory_entities(conn: &Connection, id: i64, title: &str, content: &str) -> Result<()> {
-    let entities = crate::retrieval::entity::extract_entities(title, content);
+fn refresh_memory_entities(
+    conn: &Connection,
+    vocabulary: &ProjectVocabulary,
+    id: i64,
+    project: &str,
+    title: &str,
+    content: &str,
+) -> Result<()> {
+    let entities = crate::retrieval::entity::extract_project_entities_with(
+        conn, vocabulary, project, title, content,
+    );
     crate::retrieval::entity::refresh_memory_entities(conn, id, &entities)
         .with_context(|| format!("entity refresh failed for memory id={id}"))
 }
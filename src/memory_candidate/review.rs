@@ -682,7 +682,8 @@ impl CandidateRow {
     }
 }
 
-fn decode_pack_review_text(text: &str) -> Option<(String, String)> {
+/// Splits review text written for a pack candidate back into title and content.
+pub(crate) fn decode_pack_review_text(text: &str) -> Option<(String, String)> {
     let mut lines = text.lines();
     let title_line = lines.next()?;
     let content_marker = lines.next()?;
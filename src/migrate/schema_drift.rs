@@ -90,7 +90,9 @@ pub(super) use invariants::{
     V072_SCHEMA_INVARIANTS, V073_SCHEMA_INVARIANTS, V074_SCHEMA_INVARIANTS, V075_SCHEMA_INVARIANTS,
     V076_SCHEMA_INVARIANTS, V077_SCHEMA_INVARIANTS, V078_SCHEMA_INVARIANTS, V079_SCHEMA_INVARIANTS,
     V080_SCHEMA_INVARIANTS, V081_SCHEMA_INVARIANTS, V082_SCHEMA_INVARIANTS, V083_SCHEMA_INVARIANTS,
-    V084_SCHEMA_INVARIANTS, V085_SCHEMA_INVARIANTS,
+    V084_SCHEMA_INVARIANTS, V085_SCHEMA_INVARIANTS, V086_SCHEMA_INVARIANTS, V087_SCHEMA_INVARIANTS,
+    V088_SCHEMA_INVARIANTS, V089_SCHEMA_INVARIANTS, V090_SCHEMA_INVARIANTS, V091_SCHEMA_INVARIANTS,
+    V092_SCHEMA_INVARIANTS, V093_SCHEMA_INVARIANTS, V094_SCHEMA_INVARIANTS,
 };
 
 pub(crate) fn validate_schema_invariants(conn: &Connection) -> Result<Vec<String>> {
@@ -182,6 +184,15 @@ fn missing_schema_invariants(conn: &Connection, applied: &[i64]) -> Result<Vec<S
         .chain(V083_SCHEMA_INVARIANTS)
         .chain(V084_SCHEMA_INVARIANTS)
         .chain(V085_SCHEMA_INVARIANTS)
+        .chain(V086_SCHEMA_INVARIANTS)
+        .chain(V087_SCHEMA_INVARIANTS)
+        .chain(V088_SCHEMA_INVARIANTS)
+        .chain(V089_SCHEMA_INVARIANTS)
+        .chain(V090_SCHEMA_INVARIANTS)
+        .chain(V091_SCHEMA_INVARIANTS)
+        .chain(V092_SCHEMA_INVARIANTS)
+        .chain(V093_SCHEMA_INVARIANTS)
+        .chain(V094_SCHEMA_INVARIANTS)
     {
         if !applied.contains(&invariant.version) || schema_object_exists(conn, invariant.object)? {
             continue;
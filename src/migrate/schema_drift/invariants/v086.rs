@@ -0,0 +1,6 @@
+use super::SchemaInvariant;
+
+pub(in crate::migrate) const V086_SCHEMA_INVARIANTS: &[SchemaInvariant] = &[
+    SchemaInvariant::table(86, "sync_change_log", "sync_export_cursors"),
+    SchemaInvariant::table(86, "sync_change_log", "sync_peer_cursors"),
+];
@@ -0,0 +1,6 @@
+use super::SchemaInvariant;
+
+pub(in crate::migrate) const V087_SCHEMA_INVARIANTS: &[SchemaInvariant] = &[
+    SchemaInvariant::table(87, "team_pack_sync", "pack_sync_heads"),
+    SchemaInvariant::table(87, "team_pack_sync", "pack_sync_entries"),
+];
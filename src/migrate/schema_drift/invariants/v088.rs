@@ -0,0 +1,17 @@
+use super::SchemaInvariant;
+
+pub(in crate::migrate) const V088_SCHEMA_INVARIANTS: &[SchemaInvariant] = &[
+    SchemaInvariant::table(88, "api_event_stream", "api_stream_events"),
+    SchemaInvariant::index(88, "api_event_stream", "idx_api_stream_events_project"),
+    SchemaInvariant::index(88, "api_event_stream", "idx_api_stream_events_created"),
+    SchemaInvariant::trigger(88, "api_event_stream", "api_stream_events_prune"),
+    SchemaInvariant::trigger(88, "api_event_stream", "api_stream_memory_insert"),
+    SchemaInvariant::trigger(88, "api_event_stream", "api_stream_memory_update"),
+    SchemaInvariant::trigger(88, "api_event_stream", "api_stream_candidate_insert"),
+    SchemaInvariant::trigger(88, "api_event_stream", "api_stream_candidate_review"),
+    SchemaInvariant::trigger(88, "api_event_stream", "api_stream_workstream_insert"),
+    SchemaInvariant::trigger(88, "api_event_stream", "api_stream_workstream_status"),
+    SchemaInvariant::trigger(88, "api_event_stream", "api_stream_job_finished"),
+    SchemaInvariant::trigger(88, "api_event_stream", "api_stream_injection_insert"),
+    SchemaInvariant::trigger(88, "api_event_stream", "api_stream_injection_update"),
+];
@@ -0,0 +1,4 @@
+use super::SchemaInvariant;
+
+pub(in crate::migrate) const V089_SCHEMA_INVARIANTS: &[SchemaInvariant] =
+    &[SchemaInvariant::table(89, "api_tokens", "api_tokens")];
@@ -0,0 +1,19 @@
+use super::SchemaInvariant;
+
+pub(in crate::migrate) const V090_SCHEMA_INVARIANTS: &[SchemaInvariant] = &[
+    SchemaInvariant::column(
+        90,
+        "workstream_plans",
+        "workstreams",
+        "parent_workstream_id",
+    ),
+    SchemaInvariant::index(90, "workstream_plans", "idx_workstreams_parent"),
+    SchemaInvariant::table(90, "workstream_plans", "workstream_dependencies"),
+    SchemaInvariant::index(90, "workstream_plans", "idx_workstream_dependencies_target"),
+    SchemaInvariant::table(90, "workstream_plans", "workstream_milestones"),
+    SchemaInvariant::index(
+        90,
+        "workstream_plans",
+        "idx_workstream_milestones_workstream",
+    ),
+];
@@ -0,0 +1,6 @@
+use super::SchemaInvariant;
+
+pub(in crate::migrate) const V091_SCHEMA_INVARIANTS: &[SchemaInvariant] = &[
+    SchemaInvariant::column(91, "ai_usage_profile", "ai_usage_events", "ai_profile"),
+    SchemaInvariant::index(91, "ai_usage_profile", "idx_ai_usage_profile_created"),
+];
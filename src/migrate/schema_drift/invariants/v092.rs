@@ -0,0 +1,7 @@
+use super::SchemaInvariant;
+
+pub(in crate::migrate) const V092_SCHEMA_INVARIANTS: &[SchemaInvariant] = &[
+    SchemaInvariant::table(92, "memory_anchors", "memory_anchors"),
+    SchemaInvariant::index(92, "memory_anchors", "idx_memory_anchors_memory"),
+    SchemaInvariant::index(92, "memory_anchors", "idx_memory_anchors_repo_resolved"),
+];
@@ -0,0 +1,7 @@
+use super::SchemaInvariant;
+
+pub(in crate::migrate) const V093_SCHEMA_INVARIANTS: &[SchemaInvariant] = &[
+    SchemaInvariant::table(93, "redaction_audit", "redaction_audit"),
+    SchemaInvariant::index(93, "redaction_audit", "idx_redaction_audit_run"),
+    SchemaInvariant::index(93, "redaction_audit", "idx_redaction_audit_row"),
+];
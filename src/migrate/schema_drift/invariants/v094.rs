@@ -0,0 +1,10 @@
+use super::SchemaInvariant;
+
+pub(in crate::migrate) const V094_SCHEMA_INVARIANTS: &[SchemaInvariant] = &[
+    SchemaInvariant::table(94, "project_glossary", "project_glossary"),
+    SchemaInvariant::index(
+        94,
+        "project_glossary",
+        "idx_project_glossary_project_status",
+    ),
+];
@@ -430,6 +430,51 @@ pub(crate) const MIGRATIONS: &[Migration] = &[
         name: "legacy_pending_bridge_state",
         sql: include_str!("../migrations/v085_legacy_pending_bridge_state.sql"),
     },
+    Migration {
+        version: 86,
+        name: "sync_change_log",
+        sql: include_str!("../migrations/v086_sync_change_log.sql"),
+    },
+    Migration {
+        version: 87,
+        name: "team_pack_sync",
+        sql: include_str!("../migrations/v087_team_pack_sync.sql"),
+    },
+    Migration {
+        version: 88,
+        name: "api_event_stream",
+        sql: include_str!("../migrations/v088_api_event_stream.sql"),
+    },
+    Migration {
+        version: 89,
+        name: "api_tokens",
+        sql: include_str!("../migrations/v089_api_tokens.sql"),
+    },
+    Migration {
+        version: 90,
+        name: "workstream_plans",
+        sql: include_str!("../migrations/v090_workstream_plans.sql"),
+    },
+    Migration {
+        version: 91,
+        name: "ai_usage_profile",
+        sql: include_str!("../migrations/v091_ai_usage_profile.sql"),
+    },
+    Migration {
+        version: 92,
+        name: "memory_anchors",
+        sql: include_str!("../migrations/v092_memory_anchors.sql"),
+    },
+    Migration {
+        version: 93,
+        name: "redaction_audit",
+        sql: include_str!("../migrations/v093_redaction_audit.sql"),
+    },
+    Migration {
+        version: 94,
+        name: "project_glossary",
+        sql: include_str!("../migrations/v094_project_glossary.sql"),
+    },
 ];
 
 pub(crate) const OLD_BASELINE_VERSION: i64 = 13;
@@ -0,0 +1,25 @@
+-- v086_sync_change_log: cursors for `remem sync` change-log segments.
+--
+-- Segments themselves live in the user-chosen sync directory; the database
+-- only records how far this device has exported its own changes and how far
+-- it has replayed each peer's log, keyed by the local sync directory path.
+
+CREATE TABLE sync_export_cursors (
+    project TEXT NOT NULL,
+    sync_dir TEXT NOT NULL,
+    memory_updated_epoch INTEGER NOT NULL DEFAULT 0,
+    governance_event_id INTEGER NOT NULL DEFAULT 0,
+    last_seq INTEGER NOT NULL DEFAULT 0,
+    updated_at_epoch INTEGER NOT NULL,
+    PRIMARY KEY (project, sync_dir)
+);
+
+CREATE TABLE sync_peer_cursors (
+    project TEXT NOT NULL,
+    sync_dir TEXT NOT NULL,
+    device_id TEXT NOT NULL
+        CHECK(length(device_id) = 32 AND device_id NOT GLOB '*[^0-9a-f]*'),
+    last_seq INTEGER NOT NULL DEFAULT 0,
+    updated_at_epoch INTEGER NOT NULL,
+    PRIMARY KEY (project, sync_dir, device_id)
+);
@@ -0,0 +1,25 @@
+-- v087_team_pack_sync: what this device last saw of a shared team pack.
+--
+-- `remem pack pull` and `remem pack push` record the pack digest they left
+-- the directory at and every pack identity with its content hash, so the next
+-- pull can tell upstream supersessions and deletions apart from rows that
+-- were simply never imported, and push can refuse to overwrite unpulled work.
+
+CREATE TABLE pack_sync_heads (
+    project TEXT NOT NULL,
+    pack_dir TEXT NOT NULL,
+    content_digest TEXT NOT NULL,
+    author TEXT,
+    updated_at_epoch INTEGER NOT NULL,
+    PRIMARY KEY (project, pack_dir)
+);
+
+CREATE TABLE pack_sync_entries (
+    project TEXT NOT NULL,
+    pack_dir TEXT NOT NULL,
+    identity TEXT NOT NULL,
+    memory_type TEXT NOT NULL,
+    content_hash TEXT NOT NULL,
+    seen_at_epoch INTEGER NOT NULL,
+    PRIMARY KEY (project, pack_dir, identity)
+);
@@ -0,0 +1,224 @@
+-- v088_api_event_stream: append-only change feed behind `/api/v1/stream`.
+--
+-- Hooks, the worker, the CLI and the API all write from separate processes,
+-- so the feed is filled by triggers on the source tables instead of an
+-- in-process bus. Payloads carry structural fields only; titles are redacted
+-- by the API before they leave the process. AUTOINCREMENT keeps ids
+-- monotonic across pruning so stream cursors never point at a reused row.
+
+CREATE TABLE api_stream_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    event_type TEXT NOT NULL CHECK (event_type IN (
+        'memory_saved',
+        'candidate_created',
+        'candidate_reviewed',
+        'workstream_transition',
+        'job_completed',
+        'injection_audit'
+    )),
+    project TEXT,
+    resource_kind TEXT NOT NULL,
+    resource_id INTEGER NOT NULL,
+    payload_json TEXT NOT NULL,
+    created_at_epoch INTEGER NOT NULL
+);
+
+CREATE INDEX idx_api_stream_events_project
+    ON api_stream_events(project, id);
+CREATE INDEX idx_api_stream_events_created
+    ON api_stream_events(created_at_epoch);
+
+-- Keep a week of history; clients resuming from an older cursor get a
+-- `stream_reset` event and refetch through the list endpoints.
+CREATE TRIGGER api_stream_events_prune
+AFTER INSERT ON api_stream_events
+WHEN NEW.id % 128 = 0
+BEGIN
+    DELETE FROM api_stream_events
+    WHERE created_at_epoch < NEW.created_at_epoch - 7 * 86400;
+END;
+
+CREATE TRIGGER api_stream_memory_insert
+AFTER INSERT ON memories
+BEGIN
+    INSERT INTO api_stream_events
+        (event_type, project, resource_kind, resource_id, payload_json, created_at_epoch)
+    VALUES (
+        'memory_saved', NEW.project, 'memory', NEW.id,
+        json_object(
+            'change', 'created',
+            'memory_type', NEW.memory_type,
+            'status', NEW.status,
+            'title', NEW.title
+        ),
+        CAST(strftime('%s', 'now') AS INTEGER)
+    );
+END;
+
+CREATE TRIGGER api_stream_memory_update
+AFTER UPDATE OF title, content, status ON memories
+WHEN OLD.title IS NOT NEW.title
+  OR OLD.content IS NOT NEW.content
+  OR OLD.status IS NOT NEW.status
+BEGIN
+    INSERT INTO api_stream_events
+        (event_type, project, resource_kind, resource_id, payload_json, created_at_epoch)
+    VALUES (
+        'memory_saved', NEW.project, 'memory', NEW.id,
+        json_object(
+            'change', CASE
+                WHEN OLD.title IS NOT NEW.title OR OLD.content IS NOT NEW.content
+                    THEN 'updated'
+                ELSE 'status'
+            END,
+            'memory_type', NEW.memory_type,
+            'previous_status', OLD.status,
+            'status', NEW.status,
+            'title', NEW.title
+        ),
+        CAST(strftime('%s', 'now') AS INTEGER)
+    );
+END;
+
+CREATE TRIGGER api_stream_candidate_insert
+AFTER INSERT ON memory_candidates
+BEGIN
+    INSERT INTO api_stream_events
+        (event_type, project, resource_kind, resource_id, payload_json, created_at_epoch)
+    VALUES (
+        'candidate_created',
+        COALESCE(
+            NEW.target_project,
+            (SELECT project_key FROM projects WHERE id = NEW.project_id)
+        ),
+        'candidate', NEW.id,
+        json_object(
+            'memory_type', NEW.memory_type,
+            'review_status', NEW.review_status,
+            'source_kind', NEW.source_kind,
+            'block_reason', NEW.auto_promote_block_reason
+        ),
+        CAST(strftime('%s', 'now') AS INTEGER)
+    );
+END;
+
+CREATE TRIGGER api_stream_candidate_review
+AFTER UPDATE OF review_status ON memory_candidates
+WHEN OLD.review_status IS NOT NEW.review_status
+BEGIN
+    INSERT INTO api_stream_events
+        (event_type, project, resource_kind, resource_id, payload_json, created_at_epoch)
+    VALUES (
+        'candidate_reviewed',
+        COALESCE(
+            NEW.target_project,
+            (SELECT project_key FROM projects WHERE id = NEW.project_id)
+        ),
+        'candidate', NEW.id,
+        json_object(
+            'memory_type', NEW.memory_type,
+            'previous_status', OLD.review_status,
+            'review_status', NEW.review_status,
+            'review_actor', NEW.review_actor
+        ),
+        CAST(strftime('%s', 'now') AS INTEGER)
+    );
+END;
+
+CREATE TRIGGER api_stream_workstream_insert
+AFTER INSERT ON workstreams
+BEGIN
+    INSERT INTO api_stream_events
+        (event_type, project, resource_kind, resource_id, payload_json, created_at_epoch)
+    VALUES (
+        'workstream_transition', NEW.project, 'workstream', NEW.id,
+        json_object(
+            'previous_status', NULL,
+            'status', NEW.status,
+            'title', NEW.title
+        ),
+        CAST(strftime('%s', 'now') AS INTEGER)
+    );
+END;
+
+CREATE TRIGGER api_stream_workstream_status
+AFTER UPDATE OF status ON workstreams
+WHEN OLD.status IS NOT NEW.status
+BEGIN
+    INSERT INTO api_stream_events
+        (event_type, project, resource_kind, resource_id, payload_json, created_at_epoch)
+    VALUES (
+        'workstream_transition', NEW.project, 'workstream', NEW.id,
+        json_object(
+            'previous_status', OLD.status,
+            'status', NEW.status,
+            'title', NEW.title,
+            'merged_into_workstream_id', NEW.merged_into_workstream_id
+        ),
+        CAST(strftime('%s', 'now') AS INTEGER)
+    );
+END;
+
+CREATE TRIGGER api_stream_job_finished
+AFTER UPDATE OF state ON jobs
+WHEN OLD.state IS NOT NEW.state AND NEW.state IN ('done', 'failed')
+BEGIN
+    INSERT INTO api_stream_events
+        (event_type, project, resource_kind, resource_id, payload_json, created_at_epoch)
+    VALUES (
+        'job_completed', NEW.project, 'job', NEW.id,
+        json_object(
+            'job_type', NEW.job_type,
+            'state', NEW.state,
+            'attempt_count', NEW.attempt_count
+        ),
+        CAST(strftime('%s', 'now') AS INTEGER)
+    );
+END;
+
+-- `context_injections` is the per-emission gate every context hook writes;
+-- an insert or a bumped emit/suppress counter is one injection decision.
+CREATE TRIGGER api_stream_injection_insert
+AFTER INSERT ON context_injections
+BEGIN
+    INSERT INTO api_stream_events
+        (event_type, project, resource_kind, resource_id, payload_json, created_at_epoch)
+    VALUES (
+        'injection_audit', NEW.project, 'context_injection', NEW.id,
+        json_object(
+            'decision', 'emitted',
+            'host', NEW.host,
+            'hook_source', NEW.hook_source,
+            'output_mode', NEW.output_mode,
+            'output_chars', NEW.output_chars,
+            'emit_count', NEW.emit_count,
+            'suppress_count', NEW.suppress_count
+        ),
+        CAST(strftime('%s', 'now') AS INTEGER)
+    );
+END;
+
+CREATE TRIGGER api_stream_injection_update
+AFTER UPDATE OF emit_count, suppress_count ON context_injections
+WHEN OLD.emit_count IS NOT NEW.emit_count
+  OR OLD.suppress_count IS NOT NEW.suppress_count
+BEGIN
+    INSERT INTO api_stream_events
+        (event_type, project, resource_kind, resource_id, payload_json, created_at_epoch)
+    VALUES (
+        'injection_audit', NEW.project, 'context_injection', NEW.id,
+        json_object(
+            'decision', CASE
+                WHEN OLD.emit_count IS NOT NEW.emit_count THEN 'emitted'
+                ELSE 'suppressed'
+            END,
+            'host', NEW.host,
+            'hook_source', NEW.hook_source,
+            'output_mode', NEW.output_mode,
+            'output_chars', NEW.output_chars,
+            'emit_count', NEW.emit_count,
+            'suppress_count', NEW.suppress_count
+        ),
+        CAST(strftime('%s', 'now') AS INTEGER)
+    );
+END;
@@ -0,0 +1,18 @@
+-- v089_api_tokens: named, scoped bearer tokens for the local API.
+--
+-- The `.api-token` file stays the unrestricted admin credential. Named tokens
+-- are stored as SHA-256 digests only; the plaintext is shown once at
+-- creation. `projects_json` is a JSON array allow-list, NULL for every
+-- project. Revocation keeps the row so audit actors stay resolvable.
+
+CREATE TABLE api_tokens (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    token_sha256 TEXT NOT NULL UNIQUE,
+    scope TEXT NOT NULL CHECK (scope IN ('read', 'review', 'write', 'admin')),
+    projects_json TEXT CHECK (projects_json IS NULL OR json_valid(projects_json)),
+    created_at_epoch INTEGER NOT NULL,
+    expires_at_epoch INTEGER,
+    revoked_at_epoch INTEGER,
+    last_used_at_epoch INTEGER
+);
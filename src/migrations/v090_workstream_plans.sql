@@ -0,0 +1,35 @@
+-- v090_workstream_plans: parent/child workstreams, typed dependencies between
+-- workstreams, and milestone checklists.
+--
+-- `kind = 'hard'` means the dependent workstream cannot be resumed or
+-- completed before the target completes (enforced when its status is set);
+-- `soft` records a preferred order only. Milestones keep
+-- their own completion timestamp so progress is not inferred from free text.
+
+ALTER TABLE workstreams ADD COLUMN parent_workstream_id INTEGER REFERENCES workstreams(id);
+
+CREATE INDEX idx_workstreams_parent ON workstreams(parent_workstream_id);
+
+CREATE TABLE workstream_dependencies (
+    workstream_id INTEGER NOT NULL REFERENCES workstreams(id) ON DELETE CASCADE,
+    depends_on_workstream_id INTEGER NOT NULL REFERENCES workstreams(id) ON DELETE CASCADE,
+    kind TEXT NOT NULL DEFAULT 'hard' CHECK (kind IN ('hard', 'soft')),
+    created_at_epoch INTEGER NOT NULL,
+    PRIMARY KEY (workstream_id, depends_on_workstream_id),
+    CHECK (workstream_id <> depends_on_workstream_id)
+);
+
+CREATE INDEX idx_workstream_dependencies_target
+    ON workstream_dependencies(depends_on_workstream_id);
+
+CREATE TABLE workstream_milestones (
+    id INTEGER PRIMARY KEY,
+    workstream_id INTEGER NOT NULL REFERENCES workstreams(id) ON DELETE CASCADE,
+    title TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    created_at_epoch INTEGER NOT NULL,
+    completed_at_epoch INTEGER
+);
+
+CREATE INDEX idx_workstream_milestones_workstream
+    ON workstream_milestones(workstream_id, position);
@@ -0,0 +1,5 @@
+-- v091_ai_usage_profile: record which memory AI profile served each call so
+-- spend budgets can be enforced per profile as well as per project.
+ALTER TABLE ai_usage_events ADD COLUMN ai_profile TEXT;
+CREATE INDEX IF NOT EXISTS idx_ai_usage_profile_created
+    ON ai_usage_events(ai_profile, created_at_epoch DESC);
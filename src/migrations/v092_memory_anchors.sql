@@ -0,0 +1,36 @@
+-- v092_memory_anchors: optional symbol / line-range anchors captured when a
+-- memory is saved. Each anchor pins a file region at the repository HEAD of
+-- save time (`base_commit_sha`); the worker re-resolves it against later
+-- HEADs and caches the verdict so staleness only fires when the anchored
+-- code itself changed.
+--
+-- `drift_status`: `pending` (not yet resolved), `unchanged`, `moved` (same
+-- code at new lines), `changed`, `missing` (file or symbol gone),
+-- `unresolvable` (git could not diff it against HEAD; retried once HEAD
+-- moves), or `untracked` (no git checkout at save time).
+CREATE TABLE IF NOT EXISTS memory_anchors (
+    id INTEGER PRIMARY KEY,
+    memory_id INTEGER NOT NULL REFERENCES memories(id) ON DELETE CASCADE,
+    file_path TEXT NOT NULL,
+    symbol TEXT,
+    start_line INTEGER,
+    end_line INTEGER,
+    repo_path TEXT,
+    base_commit_sha TEXT,
+    resolved_commit_sha TEXT,
+    resolved_commit_epoch INTEGER,
+    resolved_start_line INTEGER,
+    resolved_end_line INTEGER,
+    drift_status TEXT NOT NULL DEFAULT 'pending'
+        CHECK (drift_status IN ('pending', 'unchanged', 'moved', 'changed', 'missing', 'unresolvable', 'untracked')),
+    drift_diff TEXT,
+    created_at_epoch INTEGER NOT NULL,
+    checked_at_epoch INTEGER,
+    CHECK (symbol IS NOT NULL OR (start_line IS NOT NULL AND end_line IS NOT NULL)),
+    CHECK (start_line IS NULL OR (start_line >= 1 AND end_line >= start_line))
+);
+
+CREATE INDEX IF NOT EXISTS idx_memory_anchors_memory
+    ON memory_anchors(memory_id);
+CREATE INDEX IF NOT EXISTS idx_memory_anchors_repo_resolved
+    ON memory_anchors(repo_path, resolved_commit_sha);
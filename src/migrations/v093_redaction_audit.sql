@@ -0,0 +1,22 @@
+-- v093_redaction_audit: one row per column value rewritten by
+-- `remem redact --apply`. The secret itself is never stored; `original_hash`
+-- (HMAC-SHA256 of the pre-redaction value under a key kept in the data
+-- directory, not the database) lets an operator confirm which copy was
+-- scrubbed, and `detectors` lists the detector names that matched.
+CREATE TABLE IF NOT EXISTS redaction_audit (
+    id INTEGER PRIMARY KEY,
+    run_id TEXT NOT NULL,
+    table_name TEXT NOT NULL,
+    row_id INTEGER NOT NULL,
+    column_name TEXT NOT NULL,
+    project TEXT,
+    detectors TEXT NOT NULL,
+    match_count INTEGER NOT NULL CHECK (match_count > 0),
+    original_hash TEXT NOT NULL,
+    redacted_at_epoch INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_redaction_audit_run
+    ON redaction_audit(run_id);
+CREATE INDEX IF NOT EXISTS idx_redaction_audit_row
+    ON redaction_audit(table_name, row_id);
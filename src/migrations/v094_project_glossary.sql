@@ -0,0 +1,21 @@
+-- v094_project_glossary: per-project term -> expansion pairs consumed by query
+-- expansion, entity extraction and FTS search. One row per pair; a term with
+-- several expansions has several rows. `suggested` rows are mined from
+-- co-occurring terms in memories and stay inert until accepted; `rejected`
+-- rows keep a dismissed suggestion from being proposed again.
+CREATE TABLE IF NOT EXISTS project_glossary (
+    id INTEGER PRIMARY KEY,
+    project TEXT NOT NULL,
+    term TEXT NOT NULL COLLATE NOCASE,
+    expansion TEXT NOT NULL COLLATE NOCASE,
+    status TEXT NOT NULL DEFAULT 'active'
+        CHECK (status IN ('active', 'suggested', 'rejected')),
+    source TEXT NOT NULL CHECK (source IN ('manual', 'import', 'mined')),
+    support_count INTEGER NOT NULL DEFAULT 0 CHECK (support_count >= 0),
+    created_at_epoch INTEGER NOT NULL,
+    updated_at_epoch INTEGER NOT NULL,
+    UNIQUE(project, term, expansion)
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_glossary_project_status
+    ON project_glossary(project, status);
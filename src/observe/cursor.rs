@@ -9,7 +9,7 @@
 use anyhow::Result;
 use rusqlite::OptionalExtension;
 
-use crate::cursor_hook::input::{CursorToolEvent, CursorToolOutcome};
+use crate::cursor_hook::input::{CursorToolEvent, CursorToolOutcome, UnsupportedDeliveredFailure};
 use crate::cursor_hook::{CURSOR_HOST, CURSOR_TOOL_FAILURE_EVENT_TYPE};
 use crate::db;
 
@@ -24,7 +24,18 @@ pub async fn observe_cursor() -> Result<()> {
 }
 
 pub async fn observe_cursor_bytes(bytes: &[u8]) -> Result<()> {
-    let event = crate::cursor_hook::input::parse_observe_event(bytes)?;
+    let event = match crate::cursor_hook::input::parse_observe_event(bytes) {
+        Ok(event) => event,
+        Err(error) => {
+            // Total delivered-failure policy: an unapproved failure shape is
+            // acknowledged with exit 0 and nothing is opened or written.
+            if let Some(skipped) = error.downcast_ref::<UnsupportedDeliveredFailure>() {
+                crate::log::info("observe", &skipped.to_string());
+                return Ok(());
+            }
+            return Err(error);
+        }
+    };
     record_cursor_tool_event(&event)
 }
 
@@ -651,6 +651,27 @@ mod cursor_observe {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn cursor_unapproved_delivered_failure_is_exit_zero_no_op() -> anyhow::Result<()> {
+        let _dir = ScopedTestDataDir::new("cursor-observe-failure-no-op");
+        drop(db::open_db()?);
+
+        for tool in ["Shell", "Write", "SomethingNew"] {
+            let mut payload: serde_json::Value =
+                serde_json::from_slice(&failure_payload(&format!("tu-{tool}")))?;
+            payload["tool_name"] = json!(tool);
+            crate::observe::observe_cursor_bytes(&payload.to_string().into_bytes()).await?;
+        }
+
+        let conn = db::open_db()?;
+        assert!(captured_events(&conn).is_empty(), "no-op must not capture");
+        assert!(
+            !crate::db::data_dir().join("capture-spill.jsonl").exists(),
+            "no-op must not spill"
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn cursor_db_open_failure_spills_sanitized_event_and_replays_failure_type(
     ) -> anyhow::Result<()> {
@@ -4,9 +4,12 @@ mod link;
 mod search;
 #[cfg(test)]
 mod tests;
+mod vocabulary;
 
-pub use extract::extract_entities;
+pub use extract::{extract_entities, extract_project_entities, extract_project_entities_with};
+pub(crate) use extract::{extract_with_vocabulary, is_stop_word};
 pub use graph::{expand_via_entity_graph, expand_via_entity_graph_filtered};
 pub use link::{link_entities, refresh_memory_entities};
 pub(crate) use search::search_exact_entity_names_filtered;
 pub use search::{search_by_entity, search_by_entity_filtered};
+pub use vocabulary::ProjectVocabulary;
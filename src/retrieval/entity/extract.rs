@@ -1,9 +1,84 @@
+use regex::Regex;
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use super::vocabulary::ProjectVocabulary;
+use crate::retrieval::glossary::ProjectGlossary;
+
+const MAX_ENTITIES: usize = 12;
+
 /// Extract simple entities from text (project names, tools, concepts).
-/// No LLM needed — rule-based extraction from title + content.
+/// No LLM needed — rule-based extraction from title + content. Issue refs
+/// and code symbols come first, then capitalized words and known tools.
 pub fn extract_entities(title: &str, content: &str) -> Vec<String> {
+    extract_with_vocabulary(None, title, content)
+}
+
+/// [`extract_entities`] plus identifiers learned for `project` (crate and
+/// package names, module paths, service names); see
+/// [`ProjectVocabulary`]. Project terms rank right after code symbols,
+/// followed by the glossary term of any glossary form the text mentions.
+pub fn extract_project_entities(
+    conn: &rusqlite::Connection,
+    project: &str,
+    title: &str,
+    content: &str,
+) -> Vec<String> {
+    let vocabulary = ProjectVocabulary::for_project(conn, project);
+    extract_project_entities_with(conn, &vocabulary, project, title, content)
+}
+
+/// [`extract_project_entities`] with a vocabulary the caller built up front,
+/// so the git and manifest scan can stay outside a write transaction.
+pub fn extract_project_entities_with(
+    conn: &rusqlite::Connection,
+    vocabulary: &ProjectVocabulary,
+    project: &str,
+    title: &str,
+    content: &str,
+) -> Vec<String> {
+    let glossary = ProjectGlossary::for_project(conn, project);
+    let glossary_terms = glossary.canonical_terms(&format!("{title} {content}"));
+    extract_with_project_terms(Some(vocabulary), &glossary_terms, title, content)
+}
+
+pub(crate) fn extract_with_vocabulary(
+    vocabulary: Option<&ProjectVocabulary>,
+    title: &str,
+    content: &str,
+) -> Vec<String> {
+    extract_with_project_terms(vocabulary, &[], title, content)
+}
+
+fn extract_with_project_terms(
+    vocabulary: Option<&ProjectVocabulary>,
+    glossary_terms: &[String],
+    title: &str,
+    content: &str,
+) -> Vec<String> {
     let mut entities = Vec::new();
-    let mut seen = std::collections::HashSet::new();
+    let mut seen = HashSet::new();
     let combined = format!("{} {}", title, content);
+    let mut push = |entity: String, entities: &mut Vec<String>| {
+        if seen.insert(entity.to_lowercase()) {
+            entities.push(entity);
+        }
+    };
+
+    for entity in issue_refs(&combined) {
+        push(entity, &mut entities);
+    }
+    for entity in code_symbols(&combined) {
+        push(entity, &mut entities);
+    }
+    if let Some(vocabulary) = vocabulary {
+        for entity in vocabulary.matches(&combined) {
+            push(entity, &mut entities);
+        }
+    }
+    for entity in glossary_terms {
+        push(entity.clone(), &mut entities);
+    }
 
     for word in combined.split_whitespace() {
         let clean = word.trim_matches(|c: char| !c.is_alphanumeric() && c != '-' && c != '_');
@@ -17,11 +92,9 @@ pub fn extract_entities(title: &str, content: &str) -> Vec<String> {
             .unwrap_or(false)
             && clean.chars().any(|c| c.is_lowercase())
             && clean.len() >= 3
+            && !is_stop_word(&clean.to_lowercase())
         {
-            let lower = clean.to_lowercase();
-            if !is_stop_word(&lower) && seen.insert(lower.clone()) {
-                entities.push(clean.to_string());
-            }
+            push(clean.to_string(), &mut entities);
         }
         if clean.len() >= 2
             && clean.len() <= 8
@@ -29,23 +102,70 @@ pub fn extract_entities(title: &str, content: &str) -> Vec<String> {
                 .chars()
                 .all(|c| c.is_uppercase() || c.is_ascii_digit())
         {
-            let lower = clean.to_lowercase();
-            if seen.insert(lower) {
-                entities.push(clean.to_string());
-            }
+            push(clean.to_string(), &mut entities);
         }
     }
 
     for term in technical_terms() {
-        if contains_technical_term(&combined, term) && seen.insert(term.to_lowercase()) {
-            entities.push(term.to_string());
+        if contains_technical_term(&combined, term) {
+            push(term.to_string(), &mut entities);
         }
     }
 
-    entities.truncate(10);
+    entities.truncate(MAX_ENTITIES);
     entities
 }
 
+/// Tracker keys (`GH-823`, `PROJ-1204`) and cross-repo refs (`owner/repo#12`).
+fn issue_refs(text: &str) -> Vec<String> {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    let pattern = PATTERN.get_or_init(|| {
+        Regex::new(r"\b(?:[A-Za-z0-9_.-]+/[A-Za-z0-9_.-]+#[0-9]+|[A-Z][A-Z0-9]{1,9}-[0-9]{1,7})\b")
+            .expect("issue ref pattern compiles")
+    });
+    pattern
+        .find_iter(text)
+        .map(|found| found.as_str().to_string())
+        .collect()
+}
+
+/// Module paths (`retrieval::entity`), source paths (`src/db/core.rs`),
+/// backticked identifiers, `call()` sites and snake_case symbols.
+fn code_symbols(text: &str) -> Vec<String> {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    let pattern = PATTERN.get_or_init(|| {
+        Regex::new(concat!(
+            r"`(?P<tick>[A-Za-z_][A-Za-z0-9_:./-]{1,80}?)(?:\(\))?`",
+            r"|(?P<module>\b[A-Za-z_][A-Za-z0-9_]*(?:::[A-Za-z_][A-Za-z0-9_]*)+)",
+            r"|(?P<file>(?:[A-Za-z0-9_.-]+/)+[A-Za-z0-9_-]+\.[A-Za-z0-9]{1,6}\b)",
+            r"|(?P<call>\b[A-Za-z_][A-Za-z0-9_]{2,}\(\))",
+            r"|(?P<snake>\b[a-z][a-z0-9]*(?:_[a-z0-9]+)+\b)",
+        ))
+        .expect("code symbol pattern compiles")
+    });
+    let mut symbols = Vec::new();
+    for captures in pattern.captures_iter(text) {
+        let Some(found) = ["tick", "module", "file", "call", "snake"]
+            .iter()
+            .find_map(|name| captures.name(name))
+        else {
+            continue;
+        };
+        if text[..found.start()].ends_with([':', '/']) {
+            // Tail of a URL (`https://host/path.html`), not a source path.
+            continue;
+        }
+        let symbol = found
+            .as_str()
+            .trim_end_matches("()")
+            .trim_start_matches("crate::");
+        if symbol.chars().count() >= 3 {
+            symbols.push(symbol.to_string());
+        }
+    }
+    symbols
+}
+
 fn contains_technical_term(text: &str, term: &str) -> bool {
     text.split(|character: char| !character.is_ascii_alphanumeric())
         .any(|token| token.eq_ignore_ascii_case(term))
@@ -77,7 +197,7 @@ fn technical_terms() -> &'static [&'static str] {
     ]
 }
 
-fn is_stop_word(word: &str) -> bool {
+pub(crate) fn is_stop_word(word: &str) -> bool {
     matches!(
         word,
         "the"
@@ -1,6 +1,8 @@
 use anyhow::Result;
 use rusqlite::Connection;
 
+use crate::retrieval::glossary::ProjectGlossary;
+
 use super::super::extract::extract_entities;
 use super::lookup::{query_memory_ids, search_by_query_words};
 
@@ -22,7 +24,17 @@ pub fn search_by_entity_filtered(
     limit: i64,
     include_inactive: bool,
 ) -> Result<Vec<i64>> {
-    let query_entities = extract_entities(query, "");
+    let mut query_entities = extract_entities(query, "");
+    if let Some(project) = project {
+        for term in ProjectGlossary::for_project(conn, project).canonical_terms(query) {
+            if !query_entities
+                .iter()
+                .any(|entity| entity.eq_ignore_ascii_case(&term))
+            {
+                query_entities.push(term);
+            }
+        }
+    }
     if query_entities.is_empty() {
         return search_by_query_words(
             conn,
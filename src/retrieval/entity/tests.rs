@@ -1,3 +1,4 @@
 mod extract;
 mod search;
 mod support;
+mod vocabulary;
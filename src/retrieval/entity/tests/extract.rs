@@ -1,4 +1,4 @@
-use crate::retrieval::entity::extract_entities;
+use crate::retrieval::entity::{extract_entities, extract_with_vocabulary, ProjectVocabulary};
 
 #[test]
 fn extract_tool_names() {
@@ -71,3 +71,66 @@ fn technical_terms_match_at_cjk_script_boundaries() {
         .iter()
         .any(|entity| entity.eq_ignore_ascii_case("sqlite")));
 }
+
+#[test]
+fn extracts_issue_refs_and_code_symbols_first() {
+    let entities = extract_entities(
+        "Fix GH-823 regression",
+        "See owner/repo#42: `retrieval::entity::extract` calls extract_entities() \
+         from src/cli/actions/query/backfill.rs via crate::db::open_db.",
+    );
+
+    assert_eq!(entities[0], "GH-823");
+    assert_eq!(entities[1], "owner/repo#42");
+    for expected in [
+        "retrieval::entity::extract",
+        "extract_entities",
+        "src/cli/actions/query/backfill.rs",
+        "db::open_db",
+    ] {
+        assert!(
+            entities.iter().any(|entity| entity == expected),
+            "missing {expected}: {entities:?}"
+        );
+    }
+}
+
+#[test]
+fn url_tails_are_not_source_paths() {
+    let entities = extract_entities("", "docs at https://example.com/guide/setup.html");
+
+    assert!(
+        !entities.iter().any(|entity| entity.ends_with("setup.html")),
+        "{entities:?}"
+    );
+}
+
+#[test]
+fn project_vocabulary_terms_rank_after_code_symbols() {
+    let vocabulary = ProjectVocabulary::from_terms(["billing-service", "remem-hook", "tokio"]);
+
+    let entities = extract_with_vocabulary(
+        Some(&vocabulary),
+        "Billing-Service timeouts",
+        "restart remem-hook before the worker; see PAY-17",
+    );
+
+    assert_eq!(
+        &entities[..3],
+        &["PAY-17", "billing-service", "remem-hook"],
+        "{entities:?}"
+    );
+    assert!(!entities.iter().any(|entity| entity == "tokio"));
+}
+
+#[test]
+fn plain_words_never_match_without_vocabulary() {
+    let entities = extract_entities("", "restart the billing-service worker");
+
+    assert!(
+        !entities
+            .iter()
+            .any(|entity| entity.eq_ignore_ascii_case("billing-service")),
+        "{entities:?}"
+    );
+}
@@ -0,0 +1,146 @@
+use anyhow::Result;
+use rusqlite::{params, Connection};
+
+use crate::retrieval::entity::vocabulary::{cargo_manifest_terms, package_json_terms};
+use crate::retrieval::entity::ProjectVocabulary;
+
+fn setup_capture_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE memories (
+            id INTEGER PRIMARY KEY,
+            project TEXT NOT NULL,
+            files TEXT,
+            updated_at_epoch INTEGER NOT NULL DEFAULT 0
+        );
+        CREATE TABLE observations (
+            id INTEGER PRIMARY KEY,
+            project TEXT,
+            files_read TEXT,
+            files_modified TEXT,
+            created_at_epoch INTEGER
+        );",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn cargo_manifest_contributes_package_members_and_dependencies() {
+    let terms = cargo_manifest_terms(
+        r#"
+[package]
+name = "remem-ai"
+
+[lib]
+name = "remem"
+
+[workspace]
+members = ["crates/remem-hook", "crates/*"]
+
+[dependencies]
+tokio = "1"
+toml_edit = { version = "0.22" }
+
+[dev-dependencies]
+tempfile = "3"
+"#,
+    );
+
+    for expected in [
+        "remem-ai",
+        "remem",
+        "remem-hook",
+        "tokio",
+        "toml_edit",
+        "tempfile",
+    ] {
+        assert!(
+            terms.iter().any(|term| term == expected),
+            "missing {expected}: {terms:?}"
+        );
+    }
+    assert!(!terms.iter().any(|term| term.contains('*')), "{terms:?}");
+}
+
+#[test]
+fn package_json_strips_npm_scopes() {
+    let terms = package_json_terms(
+        r#"{"name": "@acme/billing-service", "dependencies": {"@acme/ledger-client": "1"}, "devDependencies": {"vitest": "1"}}"#,
+    );
+
+    assert_eq!(terms, vec!["billing-service", "ledger-client", "vitest"]);
+}
+
+#[test]
+fn captured_paths_only_teach_identifier_shaped_components() -> Result<()> {
+    let conn = Connection::open_in_memory()?;
+    setup_capture_schema(&conn)?;
+    conn.execute(
+        "INSERT INTO memories (project, files) VALUES (?1, ?2)",
+        params![
+            "proj",
+            r#"["src/retrieval/search_multihop/discover.rs", "src/search/mod.rs"]"#
+        ],
+    )?;
+    conn.execute(
+        "INSERT INTO observations (project, files_modified, created_at_epoch) VALUES (?1, ?2, 1)",
+        params!["proj", r#"["services/billing-service/src/ToolAdapter.ts"]"#],
+    )?;
+    conn.execute(
+        "INSERT INTO observations (project, files_read, created_at_epoch) VALUES (?1, ?2, 1)",
+        params!["other", r#"["src/other_project_only.rs"]"#],
+    )?;
+
+    let vocabulary = ProjectVocabulary::build(&conn, "proj");
+
+    let text = "search_multihop and billing-service call ToolAdapter while search and \
+                retrieval and discover stay plain; other_project_only is foreign";
+    let learned = crate::retrieval::entity::extract_with_vocabulary(Some(&vocabulary), "", text);
+    for expected in ["search_multihop", "billing-service", "ToolAdapter"] {
+        assert!(
+            learned.iter().any(|term| term == expected),
+            "missing {expected}: {learned:?}"
+        );
+    }
+    assert_eq!(
+        vocabulary.len(),
+        3,
+        "only identifier-shaped components are learned"
+    );
+    Ok(())
+}
+
+#[test]
+fn manifest_at_project_root_is_learned() -> Result<()> {
+    let root = std::env::temp_dir().join(format!(
+        "remem-entity-vocabulary-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)?
+            .as_nanos()
+    ));
+    std::fs::create_dir_all(&root)?;
+    std::fs::write(
+        root.join("Cargo.toml"),
+        "[package]\nname = \"ledgerd\"\n\n[dependencies]\nsqlx = \"0.8\"\n",
+    )?;
+    let conn = Connection::open_in_memory()?;
+    setup_capture_schema(&conn)?;
+
+    let vocabulary = ProjectVocabulary::build(&conn, &root.to_string_lossy());
+    let entities = crate::retrieval::entity::extract_with_vocabulary(
+        Some(&vocabulary),
+        "ledgerd uses sqlx",
+        "",
+    );
+
+    assert!(
+        entities.iter().any(|entity| entity == "ledgerd"),
+        "{entities:?}"
+    );
+    assert!(
+        entities.iter().any(|entity| entity == "sqlx"),
+        "{entities:?}"
+    );
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
@@ -0,0 +1,311 @@
+//! Per-project identifier vocabulary for entity extraction.
+//!
+//! Terms are learned from three sources: file paths captured for the project
+//! (memory `files`, observation `files_read`/`files_modified`), the package
+//! manifests at the project root (`Cargo.toml`, `package.json`), and recent
+//! git history. Manifest names (package, workspace members, dependencies) are
+//! explicit identifiers and always qualify; path components only qualify when
+//! they look like identifiers rather than plain words, so a `search/` module
+//! does not turn every mention of "search" into an entity.
+
+use rusqlite::{params, Connection};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use super::extract::is_stop_word;
+
+const VOCABULARY_TTL: Duration = Duration::from_secs(600);
+const CAPTURED_PATH_ROW_LIMIT: i64 = 500;
+const GIT_LOG_COMMIT_LIMIT: &str = "200";
+const MAX_TERMS: usize = 4096;
+const MIN_TERM_CHARS: usize = 3;
+
+/// Path components that name layout rather than anything project-specific.
+const GENERIC_PATH_COMPONENTS: &[&str] = &[
+    "src",
+    "lib",
+    "bin",
+    "mod",
+    "main",
+    "index",
+    "test",
+    "tests",
+    "spec",
+    "docs",
+    "doc",
+    "target",
+    "build",
+    "dist",
+    "node_modules",
+    "vendor",
+    "examples",
+    "benches",
+    "scripts",
+    "assets",
+    "readme",
+    "changelog",
+    "license",
+    "cargo",
+    "package",
+];
+
+#[derive(Debug, Default, Clone)]
+pub struct ProjectVocabulary {
+    /// Lowercased term -> display form (first spelling learned).
+    terms: HashMap<String, String>,
+}
+
+impl ProjectVocabulary {
+    /// Cached per process for [`VOCABULARY_TTL`] so hot write paths do not
+    /// re-read manifests and git history on every memory save.
+    pub fn for_project(conn: &Connection, project: &str) -> Arc<Self> {
+        static CACHE: OnceLock<Mutex<HashMap<String, (Instant, Arc<ProjectVocabulary>)>>> =
+            OnceLock::new();
+        let cache = CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+        if let Ok(guard) = cache.lock() {
+            if let Some((loaded_at, vocabulary)) = guard.get(project) {
+                if loaded_at.elapsed() < VOCABULARY_TTL {
+                    return Arc::clone(vocabulary);
+                }
+            }
+        }
+        let vocabulary = Arc::new(Self::build(conn, project));
+        if let Ok(mut guard) = cache.lock() {
+            guard.insert(
+                project.to_string(),
+                (Instant::now(), Arc::clone(&vocabulary)),
+            );
+        }
+        vocabulary
+    }
+
+    /// Uncached build from every source. Source failures degrade to fewer
+    /// terms; extraction never fails because a manifest or git is missing.
+    pub fn build(conn: &Connection, project: &str) -> Self {
+        let mut vocabulary = Self::default();
+        for path in captured_paths(conn, project) {
+            vocabulary.learn_path(&path);
+        }
+        let root = Path::new(project);
+        if root.is_dir() {
+            for term in manifest_terms(root) {
+                vocabulary.insert(&term);
+            }
+            for path in git_history_paths(root) {
+                vocabulary.learn_path(&path);
+            }
+        }
+        vocabulary
+    }
+
+    #[cfg(test)]
+    pub(super) fn from_terms<I, S>(terms: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut vocabulary = Self::default();
+        for term in terms {
+            vocabulary.insert(term.as_ref());
+        }
+        vocabulary
+    }
+
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Vocabulary terms appearing as whole tokens in `text`, in text order.
+    pub(super) fn matches(&self, text: &str) -> Vec<String> {
+        if self.terms.is_empty() {
+            return Vec::new();
+        }
+        text.split(|character: char| {
+            !(character.is_alphanumeric() || character == '_' || character == '-')
+        })
+        .map(|token| token.trim_matches('-'))
+        .filter(|token| token.chars().count() >= MIN_TERM_CHARS)
+        .filter_map(|token| self.terms.get(&token.to_lowercase()).cloned())
+        .collect()
+    }
+
+    fn learn_path(&mut self, path: &str) {
+        let path = path.trim().trim_start_matches("./");
+        let mut components = path.split(['/', '\\']).filter(|part| !part.is_empty());
+        let Some(last) = components.next_back() else {
+            return;
+        };
+        let stem = last.split('.').next().unwrap_or(last);
+        for component in components.chain(std::iter::once(stem)) {
+            if looks_like_identifier(component) {
+                self.insert(component);
+            }
+        }
+    }
+
+    fn insert(&mut self, term: &str) {
+        let term = term.trim();
+        if term.chars().count() < MIN_TERM_CHARS || self.terms.len() >= MAX_TERMS {
+            return;
+        }
+        let lower = term.to_lowercase();
+        if is_stop_word(&lower) || GENERIC_PATH_COMPONENTS.contains(&lower.as_str()) {
+            return;
+        }
+        self.terms.entry(lower).or_insert_with(|| term.to_string());
+    }
+}
+
+/// Separators, digits or inner capitals mark a path component as a code
+/// identifier (`search_multihop`, `remem-hook`, `v082`, `ToolAdapter`).
+fn looks_like_identifier(component: &str) -> bool {
+    if component.starts_with('.') || !component.chars().any(char::is_alphabetic) {
+        return false;
+    }
+    component.contains(['_', '-'])
+        || component
+            .chars()
+            .any(|character| character.is_ascii_digit())
+        || component.chars().skip(1).any(char::is_uppercase)
+}
+
+fn captured_paths(conn: &Connection, project: &str) -> Vec<String> {
+    let mut raw_lists = Vec::new();
+    for sql in [
+        "SELECT files FROM memories
+         WHERE project = ?1 AND files IS NOT NULL
+         ORDER BY updated_at_epoch DESC LIMIT ?2",
+        "SELECT files_modified FROM observations
+         WHERE project = ?1 AND files_modified IS NOT NULL
+         ORDER BY created_at_epoch DESC LIMIT ?2",
+        "SELECT files_read FROM observations
+         WHERE project = ?1 AND files_read IS NOT NULL
+         ORDER BY created_at_epoch DESC LIMIT ?2",
+    ] {
+        let rows = conn.prepare(sql).and_then(|mut stmt| {
+            stmt.query_map(params![project, CAPTURED_PATH_ROW_LIMIT], |row| {
+                row.get::<_, String>(0)
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()
+        });
+        match rows {
+            Ok(rows) => raw_lists.extend(rows),
+            Err(error) => crate::log::debug(
+                "entity",
+                &format!("vocabulary path source skipped for {project}: {error}"),
+            ),
+        }
+    }
+    raw_lists
+        .iter()
+        .flat_map(|raw| parse_path_list(raw))
+        .collect()
+}
+
+fn parse_path_list(raw: &str) -> Vec<String> {
+    let raw = raw.trim();
+    if raw.starts_with('[') {
+        return serde_json::from_str::<Vec<String>>(raw).unwrap_or_default();
+    }
+    raw.split([',', '\n'])
+        .map(|value| value.trim().trim_matches('"'))
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn git_history_paths(root: &Path) -> Vec<String> {
+    let Some(output) = crate::git_util::git_output_soft(
+        root,
+        &[
+            "log",
+            "--format=",
+            "--name-only",
+            "-n",
+            GIT_LOG_COMMIT_LIMIT,
+        ],
+    ) else {
+        return Vec::new();
+    };
+    if !output.status.success() {
+        return Vec::new();
+    }
+    crate::git_util::parse_changed_files_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+fn manifest_terms(root: &Path) -> Vec<String> {
+    let mut terms = Vec::new();
+    if let Ok(raw) = std::fs::read_to_string(root.join("Cargo.toml")) {
+        terms.extend(cargo_manifest_terms(&raw));
+    }
+    if let Ok(raw) = std::fs::read_to_string(root.join("package.json")) {
+        terms.extend(package_json_terms(&raw));
+    }
+    terms
+}
+
+pub(super) fn cargo_manifest_terms(raw: &str) -> Vec<String> {
+    let Ok(doc) = raw.parse::<toml_edit::DocumentMut>() else {
+        return Vec::new();
+    };
+    let mut terms = Vec::new();
+    if let Some(name) = doc
+        .get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(|name| name.as_str())
+    {
+        terms.push(name.to_string());
+    }
+    if let Some(name) = doc
+        .get("lib")
+        .and_then(|lib| lib.get("name"))
+        .and_then(|name| name.as_str())
+    {
+        terms.push(name.to_string());
+    }
+    if let Some(members) = doc
+        .get("workspace")
+        .and_then(|workspace| workspace.get("members"))
+        .and_then(|members| members.as_array())
+    {
+        terms.extend(
+            members
+                .iter()
+                .filter_map(|member| member.as_str())
+                .filter_map(|member| member.rsplit('/').next())
+                .filter(|member| !member.contains('*'))
+                .map(str::to_string),
+        );
+    }
+    for table in ["dependencies", "dev-dependencies", "build-dependencies"] {
+        if let Some(deps) = doc.get(table).and_then(|deps| deps.as_table_like()) {
+            terms.extend(deps.iter().map(|(name, _)| name.to_string()));
+        }
+    }
+    terms
+}
+
+pub(super) fn package_json_terms(raw: &str) -> Vec<String> {
+    let Ok(doc) = serde_json::from_str::<serde_json::Value>(raw) else {
+        return Vec::new();
+    };
+    let mut terms = Vec::new();
+    if let Some(name) = doc.get("name").and_then(|name| name.as_str()) {
+        terms.push(name.rsplit('/').next().unwrap_or(name).to_string());
+    }
+    for table in ["dependencies", "devDependencies"] {
+        if let Some(deps) = doc.get(table).and_then(|deps| deps.as_object()) {
+            terms.extend(
+                deps.keys()
+                    .map(|name| name.rsplit('/').next().unwrap_or(name).to_string()),
+            );
+        }
+    }
+    terms
+}
@@ -0,0 +1,219 @@
+//! Per-project glossary of codenames, abbreviations and synonyms.
+//!
+//! Entries come from two places: the `project_glossary` table (managed with
+//! `remem glossary add/import/accept`) and the repository file
+//! `.remem/glossary.toml`. Each entry groups a term with its expansions;
+//! any form found in a query pulls in the others for query expansion and FTS,
+//! and any form found in memory text yields the term as an entity so both
+//! spellings link to the same entity row.
+
+mod file;
+mod store;
+mod suggest;
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use rusqlite::Connection;
+
+use super::query_expand::{expand_query_with_terms, is_cjk};
+
+pub use file::{glossary_file_path, load_glossary_file, GLOSSARY_FILE};
+pub use store::{
+    add_glossary_entry, list_glossary_entries, remove_glossary_entry, set_glossary_entry_status,
+    GlossaryEntry, GlossarySource, GlossaryStatus,
+};
+pub use suggest::{suggest_glossary_entries, GlossarySuggestReport, GlossarySuggestion};
+
+/// How long a loaded glossary serves searches and extraction before the
+/// table and file are read again. Store writes in this process drop the
+/// cache immediately; edits to the file show up within the TTL.
+const GLOSSARY_TTL: Duration = Duration::from_secs(60);
+
+type GlossaryCache = Mutex<HashMap<String, (Instant, Arc<ProjectGlossary>)>>;
+
+fn glossary_cache() -> &'static GlossaryCache {
+    static CACHE: OnceLock<GlossaryCache> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Drop every cached glossary after a `project_glossary` write.
+fn invalidate_cached_glossaries() {
+    if let Ok(mut guard) = glossary_cache().lock() {
+        guard.clear();
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ProjectGlossary {
+    /// Each group is the term followed by its expansions, all distinct.
+    groups: Vec<Vec<String>>,
+}
+
+impl ProjectGlossary {
+    /// [`Self::load`] cached per process for [`GLOSSARY_TTL`], so searches
+    /// and memory writes do not re-read the table and file every time.
+    pub fn for_project(conn: &Connection, project: &str) -> Arc<Self> {
+        if let Ok(guard) = glossary_cache().lock() {
+            if let Some((loaded_at, glossary)) = guard.get(project) {
+                if loaded_at.elapsed() < GLOSSARY_TTL {
+                    return Arc::clone(glossary);
+                }
+            }
+        }
+        let glossary = Arc::new(Self::load(conn, project));
+        if let Ok(mut guard) = glossary_cache().lock() {
+            guard.insert(project.to_string(), (Instant::now(), Arc::clone(&glossary)));
+        }
+        glossary
+    }
+
+    /// Active DB entries merged with the repository file. Either source
+    /// failing degrades to the other; search never fails on a bad glossary.
+    pub fn load(conn: &Connection, project: &str) -> Self {
+        let mut pairs = match store::active_pairs(conn, project) {
+            Ok(pairs) => pairs,
+            Err(error) => {
+                crate::log::debug(
+                    "glossary",
+                    &format!("glossary table skipped for {project}: {error}"),
+                );
+                Vec::new()
+            }
+        };
+        match load_glossary_file(glossary_file_path(project)) {
+            Ok(Some(file_pairs)) => pairs.extend(file_pairs),
+            Ok(None) => {}
+            Err(error) => crate::log::warn(
+                "glossary",
+                &format!("glossary file skipped for {project}: {error:#}"),
+            ),
+        }
+        Self::from_pairs(pairs)
+    }
+
+    pub fn from_pairs<I, T, E>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (T, E)>,
+        T: AsRef<str>,
+        E: AsRef<str>,
+    {
+        let mut glossary = Self::default();
+        let mut group_by_term: HashMap<String, usize> = HashMap::new();
+        for (term, expansion) in pairs {
+            let (term, expansion) = (term.as_ref().trim(), expansion.as_ref().trim());
+            if term.is_empty() || expansion.is_empty() {
+                continue;
+            }
+            let index = *group_by_term.entry(term.to_lowercase()).or_insert_with(|| {
+                glossary.groups.push(vec![term.to_string()]);
+                glossary.groups.len() - 1
+            });
+            let group = &mut glossary.groups[index];
+            if !group
+                .iter()
+                .any(|form| form.eq_ignore_ascii_case(expansion))
+            {
+                group.push(expansion.to_string());
+            }
+        }
+        glossary
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Whether `term -> expansion` is already covered, in either direction.
+    pub fn contains_pair(&self, term: &str, expansion: &str) -> bool {
+        self.groups.iter().any(|group| {
+            group.iter().any(|form| form.eq_ignore_ascii_case(term))
+                && group
+                    .iter()
+                    .any(|form| form.eq_ignore_ascii_case(expansion))
+        })
+    }
+
+    /// The other forms of every entry that `query` mentions.
+    pub fn expand(&self, query: &str) -> Vec<String> {
+        let text = GlossaryText::new(query);
+        let mut expansions = Vec::new();
+        for group in &self.groups {
+            if !group.iter().any(|form| text.mentions(form)) {
+                continue;
+            }
+            for form in group {
+                if !text.mentions(form)
+                    && !expansions
+                        .iter()
+                        .any(|seen: &String| seen.eq_ignore_ascii_case(form))
+                {
+                    expansions.push(form.clone());
+                }
+            }
+        }
+        expansions
+    }
+
+    /// The term of every entry that `text` mentions in any form.
+    pub fn canonical_terms(&self, text: &str) -> Vec<String> {
+        let text = GlossaryText::new(text);
+        self.groups
+            .iter()
+            .filter(|group| group.iter().any(|form| text.mentions(form)))
+            .map(|group| group[0].clone())
+            .collect()
+    }
+}
+
+/// [`crate::retrieval::query_expand::expand_query`] with the project's
+/// glossary expansions appended.
+pub fn expand_query_for_project(
+    conn: &Connection,
+    project: Option<&str>,
+    query: &str,
+) -> Vec<String> {
+    let extra_terms = project
+        .map(|project| ProjectGlossary::for_project(conn, project).expand(query))
+        .unwrap_or_default();
+    expand_query_with_terms(query, &extra_terms)
+}
+
+/// Lowercased text split into word tokens. A form matches when its tokens
+/// occur contiguously, so `ptx` does not match inside `ptxd`; forms with CJK
+/// characters, which have no word boundaries, match as substrings.
+struct GlossaryText {
+    lower: String,
+    tokens: Vec<String>,
+}
+
+impl GlossaryText {
+    fn new(text: &str) -> Self {
+        let lower = text.to_lowercase();
+        let tokens = word_tokens(&lower);
+        Self { lower, tokens }
+    }
+
+    fn mentions(&self, form: &str) -> bool {
+        let form = form.to_lowercase();
+        if form.chars().any(is_cjk) {
+            return self.lower.contains(&form);
+        }
+        let needle = word_tokens(&form);
+        !needle.is_empty()
+            && self
+                .tokens
+                .windows(needle.len())
+                .any(|window| window == needle.as_slice())
+    }
+}
+
+fn word_tokens(text: &str) -> Vec<String> {
+    text.split(|character: char| !character.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+        .collect()
+}
@@ -0,0 +1,79 @@
+//! Glossary checked into the repository at `.remem/glossary.toml`.
+//!
+//! A single `[terms]` table maps each term to one expansion or a list of
+//! them:
+//!
+//! ```toml
+//! [terms]
+//! ptx = "payment transaction"
+//! k8s = ["kubernetes", "kube"]
+//! ```
+//!
+//! `remem glossary import` accepts the same format.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+use toml_edit::{DocumentMut, Item, Value};
+
+use super::store::validate_pair;
+
+pub const GLOSSARY_FILE: &str = ".remem/glossary.toml";
+
+pub fn glossary_file_path(project: &str) -> PathBuf {
+    Path::new(project).join(GLOSSARY_FILE)
+}
+
+/// Load a glossary file; `Ok(None)` when it does not exist.
+pub fn load_glossary_file(path: impl AsRef<Path>) -> Result<Option<Vec<(String, String)>>> {
+    let path = path.as_ref();
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err).with_context(|| format!("read {}", path.display())),
+    };
+    parse_glossary_file(&text)
+        .with_context(|| format!("invalid glossary {}", path.display()))
+        .map(Some)
+}
+
+/// `(term, expansion)` pairs in file order.
+pub(crate) fn parse_glossary_file(text: &str) -> Result<Vec<(String, String)>> {
+    let doc = text.parse::<DocumentMut>().context("parse TOML")?;
+    if let Some((key, _)) = doc.iter().find(|(key, _)| *key != "terms") {
+        bail!("unknown top-level key '{key}'; declare entries under [terms]");
+    }
+    let Some(item) = doc.get("terms") else {
+        return Ok(Vec::new());
+    };
+    let table = item
+        .as_table_like()
+        .ok_or_else(|| anyhow!("'terms' must be a table"))?;
+
+    let mut pairs = Vec::new();
+    for (term, value) in table.iter() {
+        let expansions = match value {
+            Item::Value(Value::String(expansion)) => vec![expansion.value().clone()],
+            Item::Value(Value::Array(array)) => array
+                .iter()
+                .map(|value| {
+                    value
+                        .as_str()
+                        .map(str::to_string)
+                        .ok_or_else(|| anyhow!("term '{term}': expansions must be strings"))
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("term '{term}': expected a string or an array of strings"),
+        };
+        if expansions.is_empty() {
+            bail!("term '{term}': at least one expansion is required");
+        }
+        for expansion in expansions {
+            let (term, expansion) =
+                validate_pair(term, &expansion).with_context(|| format!("term '{term}'"))?;
+            pairs.push((term.to_string(), expansion.to_string()));
+        }
+    }
+    Ok(pairs)
+}
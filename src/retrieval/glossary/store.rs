@@ -0,0 +1,223 @@
+use anyhow::{bail, Result};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+
+const MAX_GLOSSARY_TEXT_CHARS: usize = 120;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GlossaryStatus {
+    Active,
+    Suggested,
+    Rejected,
+}
+
+impl GlossaryStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Suggested => "suggested",
+            Self::Rejected => "rejected",
+        }
+    }
+
+    fn parse(raw: &str) -> rusqlite::Result<Self> {
+        match raw {
+            "active" => Ok(Self::Active),
+            "suggested" => Ok(Self::Suggested),
+            "rejected" => Ok(Self::Rejected),
+            other => Err(rusqlite::Error::FromSqlConversionFailure(
+                0,
+                rusqlite::types::Type::Text,
+                format!("unknown glossary status '{other}'").into(),
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GlossarySource {
+    Manual,
+    Import,
+    Mined,
+}
+
+impl GlossarySource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Manual => "manual",
+            Self::Import => "import",
+            Self::Mined => "mined",
+        }
+    }
+}
+
+/// One stored `term -> expansion` pair.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GlossaryEntry {
+    pub id: i64,
+    pub project: String,
+    pub term: String,
+    pub expansion: String,
+    pub status: GlossaryStatus,
+    pub source: String,
+    pub support_count: i64,
+    pub updated_at_epoch: i64,
+}
+
+/// Insert or re-activate `term -> expansion` for `project`. Returns the row id.
+pub fn add_glossary_entry(
+    conn: &Connection,
+    project: &str,
+    term: &str,
+    expansion: &str,
+    source: GlossarySource,
+) -> Result<i64> {
+    let (term, expansion) = validate_pair(term, expansion)?;
+    let now = chrono::Utc::now().timestamp();
+    conn.execute(
+        "INSERT INTO project_glossary
+         (project, term, expansion, status, source, created_at_epoch, updated_at_epoch)
+         VALUES (?1, ?2, ?3, 'active', ?4, ?5, ?5)
+         ON CONFLICT(project, term, expansion) DO UPDATE SET
+             status = 'active',
+             source = excluded.source,
+             updated_at_epoch = excluded.updated_at_epoch",
+        params![project, term, expansion, source.as_str(), now],
+    )?;
+    super::invalidate_cached_glossaries();
+    Ok(conn.query_row(
+        "SELECT id FROM project_glossary WHERE project = ?1 AND term = ?2 AND expansion = ?3",
+        params![project, term, expansion],
+        |row| row.get(0),
+    )?)
+}
+
+/// Record a mined suggestion. Existing rows keep their status so an active
+/// or rejected pair is never demoted back to a suggestion; only the support
+/// count of a pending suggestion is refreshed. Returns whether a new
+/// suggestion row was created.
+pub(super) fn upsert_suggestion(
+    conn: &Connection,
+    project: &str,
+    term: &str,
+    expansion: &str,
+    support_count: usize,
+) -> Result<bool> {
+    let (term, expansion) = validate_pair(term, expansion)?;
+    let now = chrono::Utc::now().timestamp();
+    let inserted = conn.execute(
+        "INSERT OR IGNORE INTO project_glossary
+         (project, term, expansion, status, source, support_count,
+          created_at_epoch, updated_at_epoch)
+         VALUES (?1, ?2, ?3, 'suggested', 'mined', ?4, ?5, ?5)",
+        params![project, term, expansion, support_count as i64, now],
+    )?;
+    if inserted == 0 {
+        conn.execute(
+            "UPDATE project_glossary SET support_count = ?4, updated_at_epoch = ?5
+             WHERE project = ?1 AND term = ?2 AND expansion = ?3 AND status = 'suggested'",
+            params![project, term, expansion, support_count as i64, now],
+        )?;
+    }
+    Ok(inserted > 0)
+}
+
+pub fn list_glossary_entries(
+    conn: &Connection,
+    project: &str,
+    status: Option<GlossaryStatus>,
+) -> Result<Vec<GlossaryEntry>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, project, term, expansion, status, source, support_count, updated_at_epoch
+         FROM project_glossary
+         WHERE project = ?1 AND (?2 IS NULL OR status = ?2)
+         ORDER BY term COLLATE NOCASE, expansion COLLATE NOCASE, id",
+    )?;
+    let rows = stmt.query_map(
+        params![project, status.map(GlossaryStatus::as_str)],
+        |row| {
+            Ok(GlossaryEntry {
+                id: row.get(0)?,
+                project: row.get(1)?,
+                term: row.get(2)?,
+                expansion: row.get(3)?,
+                status: GlossaryStatus::parse(&row.get::<_, String>(4)?)?,
+                source: row.get(5)?,
+                support_count: row.get(6)?,
+                updated_at_epoch: row.get(7)?,
+            })
+        },
+    )?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+/// Move one row to `status` (accepting or rejecting a suggestion). Returns the
+/// updated row, or `None` when `id` does not exist.
+pub fn set_glossary_entry_status(
+    conn: &Connection,
+    id: i64,
+    status: GlossaryStatus,
+) -> Result<Option<GlossaryEntry>> {
+    let updated = conn.execute(
+        "UPDATE project_glossary SET status = ?2, updated_at_epoch = ?3 WHERE id = ?1",
+        params![id, status.as_str(), chrono::Utc::now().timestamp()],
+    )?;
+    if updated == 0 {
+        return Ok(None);
+    }
+    super::invalidate_cached_glossaries();
+    let project: Option<String> = conn
+        .query_row(
+            "SELECT project FROM project_glossary WHERE id = ?1",
+            [id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let Some(project) = project else {
+        return Ok(None);
+    };
+    Ok(list_glossary_entries(conn, &project, Some(status))?
+        .into_iter()
+        .find(|entry| entry.id == id))
+}
+
+pub fn remove_glossary_entry(conn: &Connection, id: i64) -> Result<bool> {
+    let removed = conn.execute("DELETE FROM project_glossary WHERE id = ?1", [id])? > 0;
+    if removed {
+        super::invalidate_cached_glossaries();
+    }
+    Ok(removed)
+}
+
+/// Active `(term, expansion)` pairs for `project`.
+pub(super) fn active_pairs(conn: &Connection, project: &str) -> Result<Vec<(String, String)>> {
+    let mut stmt = conn.prepare(
+        "SELECT term, expansion FROM project_glossary
+         WHERE project = ?1 AND status = 'active'
+         ORDER BY id",
+    )?;
+    let rows = stmt.query_map([project], |row| Ok((row.get(0)?, row.get(1)?)))?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+pub(super) fn validate_pair<'a>(term: &'a str, expansion: &'a str) -> Result<(&'a str, &'a str)> {
+    let term = term.trim();
+    let expansion = expansion.trim();
+    for (label, value) in [("term", term), ("expansion", expansion)] {
+        if value.is_empty() {
+            bail!("glossary {label} must not be empty");
+        }
+        if value.chars().count() > MAX_GLOSSARY_TEXT_CHARS {
+            bail!("glossary {label} exceeds {MAX_GLOSSARY_TEXT_CHARS} characters");
+        }
+        if value.contains(['\n', '\r']) {
+            bail!("glossary {label} must be a single line");
+        }
+    }
+    if term.eq_ignore_ascii_case(expansion) {
+        bail!("glossary expansion must differ from the term");
+    }
+    Ok((term, expansion))
+}
@@ -0,0 +1,188 @@
+//! Mine glossary suggestions from the project's memories.
+//!
+//! A suggestion pairs a short token (`ptx`) with a 2-4 word phrase in the
+//! same memory whose words spell it out in order (`payment transaction`):
+//! every phrase word contributes its initial, and the remaining letters of
+//! the abbreviation may come from inside those words. Pairs need to co-occur
+//! in at least [`MIN_SUPPORT`] memories and are stored as `suggested` rows,
+//! which stay inert until `remem glossary accept`.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use rusqlite::{params, Connection};
+use serde::Serialize;
+
+use super::{store, word_tokens, ProjectGlossary};
+use crate::retrieval::entity::is_stop_word;
+
+const MIN_SUPPORT: usize = 2;
+const MEMORY_SCAN_LIMIT: i64 = 2_000;
+const MAX_SUGGESTIONS: usize = 50;
+const MIN_ABBREVIATION_CHARS: usize = 2;
+const MAX_ABBREVIATION_CHARS: usize = 6;
+const MIN_PHRASE_WORDS: usize = 2;
+const MAX_PHRASE_WORDS: usize = 4;
+const MIN_PHRASE_WORD_CHARS: usize = 3;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GlossarySuggestion {
+    pub term: String,
+    pub expansion: String,
+    pub support_count: usize,
+    /// False when the pair was already pending and only its support changed.
+    pub new: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GlossarySuggestReport {
+    pub project: String,
+    pub scanned_memories: usize,
+    pub suggestions: Vec<GlossarySuggestion>,
+}
+
+/// Scan active memories for abbreviation/phrase pairs and record them as
+/// `suggested` glossary rows. Pairs already in the glossary (including
+/// rejected ones and the repository file) are skipped.
+pub fn suggest_glossary_entries(conn: &Connection, project: &str) -> Result<GlossarySuggestReport> {
+    let mut stmt = conn.prepare(
+        "SELECT title, content FROM memories
+         WHERE project = ?1 AND status = 'active'
+         ORDER BY updated_at_epoch DESC
+         LIMIT ?2",
+    )?;
+    let texts = stmt
+        .query_map(params![project, MEMORY_SCAN_LIMIT], |row| {
+            Ok(format!(
+                "{} {}",
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut support: HashMap<(String, String), usize> = HashMap::new();
+    for text in &texts {
+        for pair in memory_pairs(text) {
+            *support.entry(pair).or_default() += 1;
+        }
+    }
+
+    let glossary = ProjectGlossary::for_project(conn, project);
+    let known = store::list_glossary_entries(conn, project, None)?
+        .into_iter()
+        .map(|entry| (entry.term.to_lowercase(), entry.expansion.to_lowercase()))
+        .collect::<HashSet<_>>();
+    let mut candidates = support
+        .into_iter()
+        .filter(|(_, count)| *count >= MIN_SUPPORT)
+        .filter(|((term, expansion), _)| {
+            !glossary.contains_pair(term, expansion)
+                && !known.contains(&(term.clone(), expansion.clone()))
+        })
+        .collect::<Vec<_>>();
+    candidates.sort_by(|(left, left_count), (right, right_count)| {
+        right_count.cmp(left_count).then_with(|| left.cmp(right))
+    });
+    candidates.truncate(MAX_SUGGESTIONS);
+
+    let mut suggestions = Vec::new();
+    for ((term, expansion), support_count) in candidates {
+        let new = store::upsert_suggestion(conn, project, &term, &expansion, support_count)?;
+        suggestions.push(GlossarySuggestion {
+            term,
+            expansion,
+            support_count,
+            new,
+        });
+    }
+    Ok(GlossarySuggestReport {
+        project: project.to_string(),
+        scanned_memories: texts.len(),
+        suggestions,
+    })
+}
+
+/// Distinct `(abbreviation, phrase)` pairs found in one memory.
+pub(super) fn memory_pairs(text: &str) -> HashSet<(String, String)> {
+    let tokens = word_tokens(&text.to_lowercase());
+    let abbreviations = tokens
+        .iter()
+        .filter(|token| is_abbreviation_candidate(token))
+        .collect::<HashSet<_>>();
+    let mut pairs = HashSet::new();
+    if abbreviations.is_empty() {
+        return pairs;
+    }
+    for start in 0..tokens.len() {
+        for len in MIN_PHRASE_WORDS..=MAX_PHRASE_WORDS {
+            let Some(words) = tokens.get(start..start + len) else {
+                break;
+            };
+            if !words.iter().all(|word| is_phrase_word(word)) {
+                break;
+            }
+            for abbreviation in &abbreviations {
+                if !words.contains(abbreviation) && abbreviates(abbreviation, words) {
+                    pairs.insert(((*abbreviation).clone(), words.join(" ")));
+                }
+            }
+        }
+    }
+    pairs
+}
+
+fn is_abbreviation_candidate(token: &str) -> bool {
+    let count = token.chars().count();
+    (MIN_ABBREVIATION_CHARS..=MAX_ABBREVIATION_CHARS).contains(&count)
+        && token
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic())
+        && token.chars().all(|c| c.is_ascii_alphanumeric())
+        && !is_stop_word(token)
+}
+
+fn is_phrase_word(word: &str) -> bool {
+    word.chars().count() >= MIN_PHRASE_WORD_CHARS
+        && word.chars().all(|c| c.is_ascii_alphabetic())
+        && !is_stop_word(word)
+}
+
+/// Whether `abbreviation` spells `words` out: each word starts a new run of
+/// letters with its initial and may add more letters from its remainder, in
+/// order, and every letter of the abbreviation is used.
+pub(super) fn abbreviates(abbreviation: &str, words: &[String]) -> bool {
+    let letters = abbreviation.chars().collect::<Vec<_>>();
+    let words = words
+        .iter()
+        .map(|word| word.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    spells(&letters, &words)
+}
+
+fn spells(letters: &[char], words: &[Vec<char>]) -> bool {
+    let Some((word, rest)) = words.split_first() else {
+        return letters.is_empty();
+    };
+    if letters.first() != word.first() {
+        return false;
+    }
+    // Try every number of extra letters taken from this word, earliest
+    // positions first.
+    let mut used = 1;
+    let mut position = 1;
+    loop {
+        if spells(&letters[used..], rest) {
+            return true;
+        }
+        let Some(next) = letters.get(used) else {
+            return false;
+        };
+        let Some(offset) = word[position..].iter().position(|c| c == next) else {
+            return false;
+        };
+        position += offset + 1;
+        used += 1;
+    }
+}
@@ -0,0 +1,245 @@
+use anyhow::Result;
+use rusqlite::Connection;
+
+use super::file::parse_glossary_file;
+use super::suggest::{abbreviates, memory_pairs};
+use super::*;
+
+fn open_db() -> Result<Connection> {
+    let conn = Connection::open_in_memory()?;
+    crate::migrate::run_migrations(&conn)?;
+    Ok(conn)
+}
+
+fn words(phrase: &str) -> Vec<String> {
+    phrase.split_whitespace().map(str::to_string).collect()
+}
+
+#[test]
+fn expand_returns_other_forms_of_mentioned_entries() {
+    let glossary = ProjectGlossary::from_pairs([
+        ("ptx", "payment transaction"),
+        ("ptx", "pay tx"),
+        ("k8s", "kubernetes"),
+    ]);
+    assert_eq!(
+        glossary.expand("Why did PTX retries fail?"),
+        vec!["payment transaction", "pay tx"]
+    );
+    assert_eq!(
+        glossary.expand("payment transaction timeout"),
+        vec!["ptx", "pay tx"]
+    );
+    assert!(glossary.expand("ptxd transaction").is_empty());
+    assert!(glossary.expand("payment").is_empty());
+}
+
+#[test]
+fn canonical_terms_map_any_form_to_the_term_and_match_cjk_substrings() {
+    let glossary = ProjectGlossary::from_pairs([("ptx", "payment transaction"), ("支付", "ptx")]);
+    assert_eq!(
+        glossary.canonical_terms("retry the Payment Transaction"),
+        vec!["ptx"]
+    );
+    assert_eq!(glossary.canonical_terms("PTX retries"), vec!["ptx", "支付"]);
+    assert_eq!(glossary.canonical_terms("修复支付重试"), vec!["支付"]);
+    assert!(glossary.canonical_terms("unrelated").is_empty());
+}
+
+#[test]
+fn glossary_file_accepts_strings_and_arrays_and_rejects_unknown_keys() -> Result<()> {
+    let pairs = parse_glossary_file(
+        "[terms]\nptx = \"payment transaction\"\nk8s = [\"kubernetes\", \"kube\"]\n",
+    )?;
+    assert_eq!(
+        pairs,
+        vec![
+            ("ptx".to_string(), "payment transaction".to_string()),
+            ("k8s".to_string(), "kubernetes".to_string()),
+            ("k8s".to_string(), "kube".to_string()),
+        ]
+    );
+    assert!(parse_glossary_file("[aliases]\nptx = \"x\"\n").is_err());
+    assert!(parse_glossary_file("[terms]\nptx = 3\n").is_err());
+    assert!(parse_glossary_file("[terms]\nptx = []\n").is_err());
+    assert!(parse_glossary_file("[terms]\nptx = \"PTX\"\n").is_err());
+    Ok(())
+}
+
+#[test]
+fn store_round_trips_status_changes_and_feeds_the_loader() -> Result<()> {
+    let conn = open_db()?;
+    let project = "/work/payments";
+    let id = add_glossary_entry(
+        &conn,
+        project,
+        "ptx",
+        "payment transaction",
+        GlossarySource::Manual,
+    )?;
+    assert_eq!(
+        add_glossary_entry(
+            &conn,
+            project,
+            "PTX",
+            "Payment Transaction",
+            GlossarySource::Import
+        )?,
+        id,
+        "pairs are unique case-insensitively"
+    );
+    assert!(add_glossary_entry(&conn, project, "ptx", " ", GlossarySource::Manual).is_err());
+
+    let glossary = ProjectGlossary::for_project(&conn, project);
+    assert_eq!(glossary.expand("ptx"), vec!["payment transaction"]);
+    assert!(ProjectGlossary::for_project(&conn, "/work/other").is_empty());
+
+    let rejected =
+        set_glossary_entry_status(&conn, id, GlossaryStatus::Rejected)?.expect("entry exists");
+    assert_eq!(rejected.status, GlossaryStatus::Rejected);
+    assert!(ProjectGlossary::for_project(&conn, project).is_empty());
+    assert!(set_glossary_entry_status(&conn, id + 100, GlossaryStatus::Active)?.is_none());
+
+    assert!(remove_glossary_entry(&conn, id)?);
+    assert!(list_glossary_entries(&conn, project, None)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn abbreviation_spells_phrase_with_initials_then_inner_letters() {
+    assert!(abbreviates("rrf", &words("reciprocal rank fusion")));
+    assert!(abbreviates("pmt", &words("payment metrics")));
+    assert!(abbreviates("pytr", &words("payment transaction")));
+    assert!(!abbreviates("rf", &words("reciprocal rank fusion")));
+    assert!(!abbreviates("prt", &words("rank payment")));
+    assert!(!abbreviates("pq", &words("payment transaction")));
+}
+
+#[test]
+fn memory_pairs_skip_stop_words_and_self_references() {
+    let pairs = memory_pairs("The RRF step (reciprocal rank fusion) merges the channels.");
+    assert!(pairs.contains(&("rrf".to_string(), "reciprocal rank fusion".to_string())));
+    assert!(memory_pairs("rank fusion and the rrf").is_empty());
+    assert!(memory_pairs("fusion for fun").is_empty());
+}
+
+#[test]
+fn suggest_records_supported_pairs_once_and_respects_rejections() -> Result<()> {
+    let conn = open_db()?;
+    let project = "/work/search";
+    for content in [
+        "RRF (reciprocal rank fusion) merges FTS and vector hits.",
+        "Tuned reciprocal rank fusion constants; rrf k stays at 60.",
+        "Only mentions rrf here.",
+    ] {
+        crate::memory::insert_memory(
+            &conn, None, project, None, "Ranking", content, "decision", None,
+        )?;
+    }
+
+    let report = suggest_glossary_entries(&conn, project)?;
+    assert_eq!(report.scanned_memories, 3);
+    let suggestion = report
+        .suggestions
+        .iter()
+        .find(|suggestion| suggestion.term == "rrf")
+        .expect("rrf suggestion");
+    assert_eq!(suggestion.expansion, "reciprocal rank fusion");
+    assert_eq!(suggestion.support_count, 2);
+    assert!(suggestion.new);
+    assert!(
+        ProjectGlossary::for_project(&conn, project).is_empty(),
+        "suggestions stay inert until accepted"
+    );
+
+    let pending = list_glossary_entries(&conn, project, Some(GlossaryStatus::Suggested))?;
+    let entry = pending
+        .iter()
+        .find(|entry| entry.term == "rrf")
+        .expect("stored suggestion");
+    assert_eq!(entry.source, "mined");
+    set_glossary_entry_status(&conn, entry.id, GlossaryStatus::Rejected)?;
+
+    let again = suggest_glossary_entries(&conn, project)?;
+    assert!(again
+        .suggestions
+        .iter()
+        .all(|suggestion| suggestion.term != "rrf"));
+    Ok(())
+}
+
+#[test]
+fn project_entity_extraction_and_query_expansion_use_the_glossary() -> Result<()> {
+    let conn = open_db()?;
+    let project = "/work/payments-glossary";
+    add_glossary_entry(
+        &conn,
+        project,
+        "ptx",
+        "payment transaction",
+        GlossarySource::Manual,
+    )?;
+
+    let entities = crate::retrieval::entity::extract_project_entities(
+        &conn,
+        project,
+        "Retry policy",
+        "Every payment transaction retries twice.",
+    );
+    assert!(entities.contains(&"ptx".to_string()), "{entities:?}");
+
+    let expanded = expand_query_for_project(&conn, Some(project), "ptx retries");
+    assert!(
+        expanded.contains(&"payment transaction".to_string()),
+        "{expanded:?}"
+    );
+    assert_eq!(
+        expand_query_for_project(&conn, None, "ptx retries"),
+        crate::retrieval::query_expand::expand_query("ptx retries")
+    );
+    Ok(())
+}
+
+#[test]
+fn multi_word_expansions_match_fts_only_as_phrases() -> Result<()> {
+    let conn = open_db()?;
+    let project = "/work/payments-phrase";
+    add_glossary_entry(
+        &conn,
+        project,
+        "ptx",
+        "payment transaction",
+        GlossarySource::Manual,
+    )?;
+    let phrase = crate::memory::insert_memory(
+        &conn,
+        None,
+        project,
+        None,
+        "Retries",
+        "Every payment transaction retries twice.",
+        "decision",
+        None,
+    )?;
+    crate::memory::insert_memory(
+        &conn,
+        None,
+        project,
+        None,
+        "Billing page",
+        "The payment page lists one transaction per row.",
+        "decision",
+        None,
+    )?;
+
+    let expanded = expand_query_for_project(&conn, Some(project), "ptx");
+    let terms = expanded.iter().map(String::as_str).collect::<Vec<_>>();
+    let query = crate::retrieval::search::common::sanitize_fts_terms(&terms);
+    let mut stmt =
+        conn.prepare("SELECT rowid FROM memories_fts WHERE memories_fts MATCH ?1 ORDER BY rowid")?;
+    let ids = stmt
+        .query_map([query.as_str()], |row| row.get::<_, i64>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    assert_eq!(ids, vec![phrase], "{query}");
+    Ok(())
+}
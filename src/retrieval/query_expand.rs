@@ -5,4 +5,5 @@ mod translations;
 #[cfg(test)]
 mod tests;
 
-pub use expand::{core_tokens, expand_query};
+pub use expand::{core_tokens, expand_query, expand_query_with_terms};
+pub(crate) use tokenize::is_cjk;
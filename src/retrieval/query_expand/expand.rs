@@ -16,34 +16,58 @@ pub fn core_tokens(raw: &str) -> Vec<String> {
 }
 
 pub fn expand_query(raw: &str) -> Vec<String> {
+    expand_query_with_terms(raw, &[])
+}
+
+/// [`expand_query`] plus project glossary expansions (see
+/// `retrieval::glossary`). Single-word terms are tokenized like the query;
+/// a multi-word term stays one entry so FTS matches it as a phrase rather
+/// than as any of its words. Expansions come after the query's own tokens.
+pub fn expand_query_with_terms(raw: &str, extra_terms: &[String]) -> Vec<String> {
     let mut expanded = Vec::new();
     let mut seen = HashSet::new();
 
-    let mixed_tokens = tokenize_mixed(raw);
-    for token in &mixed_tokens {
-        let chars: Vec<char> = token.chars().collect();
-        let all_cjk = !chars.is_empty() && chars.iter().all(|c| is_cjk(*c));
+    for token in tokenize_mixed(raw) {
+        add_query_token(&token, &mut expanded, &mut seen);
+    }
+    for term in extra_terms {
+        let tokens = tokenize_mixed(term);
+        if tokens.len() > 1 {
+            let phrase = term.split_whitespace().collect::<Vec<_>>().join(" ");
+            if seen.insert(phrase.to_lowercase()) {
+                expanded.push(phrase);
+            }
+            continue;
+        }
+        for token in tokens {
+            add_query_token(&token, &mut expanded, &mut seen);
+        }
+    }
 
-        if all_cjk && chars.len() > 1 {
-            let segments = segment_cjk(token);
-            let any_multi = segments.iter().any(|segment| segment.chars().count() > 1);
+    expanded
+}
 
-            if any_multi {
-                for segment in &segments {
-                    add_with_translations(segment, &mut expanded, &mut seen);
-                }
-                if seen.insert(token.to_lowercase()) {
-                    expanded.push(token.to_string());
-                }
-            } else {
-                add_with_translations(token, &mut expanded, &mut seen);
+fn add_query_token(token: &str, expanded: &mut Vec<String>, seen: &mut HashSet<String>) {
+    let chars: Vec<char> = token.chars().collect();
+    let all_cjk = !chars.is_empty() && chars.iter().all(|c| is_cjk(*c));
+
+    if all_cjk && chars.len() > 1 {
+        let segments = segment_cjk(token);
+        let any_multi = segments.iter().any(|segment| segment.chars().count() > 1);
+
+        if any_multi {
+            for segment in &segments {
+                add_with_translations(segment, expanded, seen);
+            }
+            if seen.insert(token.to_lowercase()) {
+                expanded.push(token.to_string());
             }
         } else {
-            add_with_translations(token, &mut expanded, &mut seen);
+            add_with_translations(token, expanded, seen);
         }
+    } else {
+        add_with_translations(token, expanded, seen);
     }
-
-    expanded
 }
 
 fn push_core_token(token: &str, tokens: &mut Vec<String>, seen: &mut HashSet<String>) {
@@ -224,3 +224,12 @@ fn compact_mixed_identifiers_do_not_cross_boundaries_or_leading_cjk() {
     assert!(!tokenize_mixed("A-区").contains(&"A区".to_string()));
     assert!(!tokenize_mixed("在EU").contains(&"在EU".to_string()));
 }
+
+#[test]
+fn glossary_terms_append_after_query_tokens_as_phrases() {
+    let expanded = super::expand_query_with_terms(
+        "ptx retries",
+        &["payment transaction".to_string(), "PTX".to_string()],
+    );
+    assert_eq!(expanded, vec!["ptx", "retries", "payment transaction"]);
+}
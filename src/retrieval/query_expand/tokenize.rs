@@ -63,7 +63,7 @@ const CJK_COMPACT_IDENTIFIER_STOP_SEGMENTS: &[&str] = &[
 
 const TERMINAL_CJK_QUERY_SEGMENTS: &[&str] = &["了", "吗", "呢"];
 
-pub(super) fn is_cjk(c: char) -> bool {
+pub(crate) fn is_cjk(c: char) -> bool {
     matches!(
         c,
         '\u{4E00}'..='\u{9FFF}' |
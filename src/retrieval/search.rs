@@ -4,8 +4,9 @@ mod observation;
 
 pub(crate) use memory::usage_rank::usage_hits_for_retrieved_candidates;
 pub(crate) use memory::{
-    apply_score_demotions, search_with_branch_execution_policy_with_suppressed_policy,
-    search_with_branch_weights, SearchExecutionPolicy, SearchWeights,
+    apply_score_demotions, log_shadow_ranking,
+    search_with_branch_execution_policy_with_suppressed_policy, search_with_branch_weights,
+    SearchExecutionPolicy, SearchWeights,
 };
 pub use memory::{
     search, search_with_branch, search_with_branch_explain, search_with_branch_explain_details,
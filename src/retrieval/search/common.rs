@@ -5,17 +5,25 @@ use anyhow::{bail, ensure, Result};
 use crate::memory::Memory;
 
 pub(crate) fn sanitize_fts_query(raw: &str) -> String {
-    let tokens: Vec<String> = raw
-        .split_whitespace()
-        .map(|token| {
-            let escaped = token.replace('"', "\"\"");
+    sanitize_fts_terms(&raw.split_whitespace().collect::<Vec<_>>())
+}
+
+/// OR-joined FTS query with each term quoted whole, so a multi-word term
+/// (a glossary expansion) must match as a phrase.
+pub(crate) fn sanitize_fts_terms(terms: &[&str]) -> String {
+    let quoted: Vec<String> = terms
+        .iter()
+        .map(|term| term.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|term| !term.is_empty())
+        .map(|term| {
+            let escaped = term.replace('"', "\"\"");
             format!("\"{escaped}\"")
         })
         .collect();
-    if tokens.len() <= 1 {
-        tokens.join("")
+    if quoted.len() <= 1 {
+        quoted.join("")
     } else {
-        tokens.join(" OR ")
+        quoted.join(" OR ")
     }
 }
 
@@ -10,6 +10,7 @@ mod suppression_filter;
 mod tests;
 mod text;
 pub(crate) mod usage_rank;
+mod weight_shadow;
 mod weights;
 
 pub use explain::{
@@ -27,4 +28,5 @@ pub(crate) use runner::{
     SearchExecutionPolicy, SearchRerankPolicy,
 };
 pub(crate) use source_anchor::apply_score_demotions;
+pub(crate) use weight_shadow::log_shadow_ranking;
 pub(crate) use weights::SearchWeights;
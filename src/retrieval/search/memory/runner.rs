@@ -5,6 +5,7 @@ use crate::memory::Memory;
 
 use super::listing::search_without_query;
 use super::text::{search_with_query, search_with_query_explain};
+use super::weight_shadow::log_shadow_ranking;
 use super::SearchExplain;
 use super::SearchExplainDetails;
 use super::SearchWeights;
@@ -17,14 +18,6 @@ pub(crate) struct SearchExecutionPolicy {
 }
 
 impl SearchExecutionPolicy {
-    pub(crate) fn production() -> Self {
-        Self {
-            weights: SearchWeights::production(),
-            rerank: SearchRerankPolicy::Ambient,
-            disable_zero_weight_channels: false,
-        }
-    }
-
     pub(crate) fn explain_default() -> Self {
         Self {
             weights: SearchWeights::default(),
@@ -130,19 +123,30 @@ pub fn search_with_branch_with_suppressed_policy(
     branch: Option<&str>,
     include_suppressed: bool,
 ) -> Result<Vec<Memory>> {
-    let (memories, _) = search_with_branch_execution_policy_with_suppressed_policy(
-        conn,
-        query,
-        project,
-        memory_type,
-        limit,
-        offset,
-        include_stale,
-        branch,
-        include_suppressed,
-        false,
-        SearchExecutionPolicy::production(),
-    )?;
+    let project_weights = SearchWeights::for_project(project);
+    let run = |weights: SearchWeights| {
+        search_with_branch_execution_policy_with_suppressed_policy(
+            conn,
+            query,
+            project,
+            memory_type,
+            limit,
+            offset,
+            include_stale,
+            branch,
+            include_suppressed,
+            false,
+            SearchExecutionPolicy::with_weights(weights),
+        )
+        .map(|(memories, _)| memories)
+    };
+    let memories = run(project_weights.weights)?;
+    // Listing without a query ignores channel weights; nothing to compare.
+    let shadow = project_weights
+        .shadow
+        .as_ref()
+        .filter(|_| query.is_some_and(|query| !query.is_empty()));
+    log_shadow_ranking("search", project, shadow, &memories, run);
     Ok(memories)
 }
 
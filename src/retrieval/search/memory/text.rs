@@ -8,7 +8,7 @@ use crate::memory::{self, Memory};
 use crate::perf::{push_elapsed, time_result, time_value, PhaseTiming};
 
 use super::super::common::{
-    calibrated_vector_hits, paginate_memories, sanitize_fts_query, weighted_ranked_fuse,
+    calibrated_vector_hits, paginate_memories, sanitize_fts_terms, weighted_ranked_fuse,
     WeightedRankedHit,
 };
 use super::{
@@ -386,7 +386,7 @@ fn build_query_search_plan(
     let page_target = (limit.max(1) + offset.max(0) + 1).max(2);
     let fetch = page_target * 3;
     let expanded = time_value(&mut timings, "query_expand", || {
-        crate::retrieval::query_expand::expand_query(query_text)
+        crate::retrieval::glossary::expand_query_for_project(conn, project, query_text)
     });
     let expanded_refs: Vec<&str> = expanded.iter().map(|token| token.as_str()).collect();
     let long_tokens: Vec<&str> = expanded_refs
@@ -424,7 +424,7 @@ fn build_query_search_plan(
             "fts channel weight is zero",
         ));
     } else if !long_tokens.is_empty() {
-        let safe_query = sanitize_fts_query(&long_tokens.join(" "));
+        let safe_query = sanitize_fts_terms(&long_tokens);
         fts_query = Some(safe_query.clone());
         let fts = time_result(&mut timings, "fts", || {
             memory::search_memories_fts_hits_filtered(
@@ -0,0 +1,161 @@
+use anyhow::Result;
+
+use crate::memory::Memory;
+
+use super::weights::{SearchWeights, ShadowSearchWeights};
+
+/// How a shadow profile's ranking differs from the live one over the same
+/// result window.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ShadowRankingDelta {
+    pub(crate) live_top: Option<i64>,
+    pub(crate) shadow_top: Option<i64>,
+    pub(crate) overlap: usize,
+    pub(crate) window: usize,
+    /// Ids only the shadow ranking returned.
+    pub(crate) entered: Vec<i64>,
+    /// Ids only the live ranking returned.
+    pub(crate) left: Vec<i64>,
+    /// Largest position change among ids both rankings returned.
+    pub(crate) max_rank_shift: usize,
+}
+
+impl ShadowRankingDelta {
+    pub(crate) fn between(live: &[i64], shadow: &[i64]) -> Self {
+        let mut delta = Self {
+            live_top: live.first().copied(),
+            shadow_top: shadow.first().copied(),
+            window: live.len().max(shadow.len()),
+            ..Self::default()
+        };
+        for (live_rank, id) in live.iter().enumerate() {
+            match shadow.iter().position(|shadow_id| shadow_id == id) {
+                Some(shadow_rank) => {
+                    delta.overlap += 1;
+                    delta.max_rank_shift =
+                        delta.max_rank_shift.max(live_rank.abs_diff(shadow_rank));
+                }
+                None => delta.left.push(*id),
+            }
+        }
+        delta.entered = shadow
+            .iter()
+            .filter(|id| !live.contains(id))
+            .copied()
+            .collect();
+        delta
+    }
+
+    pub(crate) fn is_unchanged(&self) -> bool {
+        self.live_top == self.shadow_top
+            && self.entered.is_empty()
+            && self.left.is_empty()
+            && self.max_rank_shift == 0
+    }
+}
+
+/// A shadow run repeats the whole retrieval on the request path, so only
+/// about one request in this many pays for it.
+const SHADOW_SAMPLE_EVERY: u64 = 10;
+
+/// Re-run a sampled share of retrievals with the shadow profile and log how
+/// its ranking differs from `live`. The live results are never touched, and
+/// a failing shadow run only logs.
+pub(crate) fn log_shadow_ranking(
+    surface: &str,
+    project: Option<&str>,
+    shadow: Option<&ShadowSearchWeights>,
+    live: &[Memory],
+    run: impl FnOnce(SearchWeights) -> Result<Vec<Memory>>,
+) {
+    let shadow = shadow.filter(|_| shadow_sampled());
+    compare_shadow_ranking(surface, project, shadow, live, run);
+}
+
+fn shadow_sampled() -> bool {
+    let mut draw = [0_u8; 8];
+    getrandom::fill(&mut draw).is_ok() && sampled_draw(u64::from_ne_bytes(draw))
+}
+
+fn sampled_draw(draw: u64) -> bool {
+    draw.is_multiple_of(SHADOW_SAMPLE_EVERY)
+}
+
+fn compare_shadow_ranking(
+    surface: &str,
+    project: Option<&str>,
+    shadow: Option<&ShadowSearchWeights>,
+    live: &[Memory],
+    run: impl FnOnce(SearchWeights) -> Result<Vec<Memory>>,
+) {
+    let Some(shadow) = shadow else {
+        return;
+    };
+    let project = project.unwrap_or("-");
+    let shadow_results = match run(shadow.weights) {
+        Ok(results) => results,
+        Err(error) => {
+            crate::log::warn(
+                "search-weights",
+                &format!(
+                    "shadow profile {} failed for {project} ({surface}): {error:#}",
+                    shadow.profile
+                ),
+            );
+            return;
+        }
+    };
+    let live_ids = live.iter().map(|memory| memory.id).collect::<Vec<_>>();
+    let shadow_ids = shadow_results
+        .iter()
+        .map(|memory| memory.id)
+        .collect::<Vec<_>>();
+    let delta = ShadowRankingDelta::between(&live_ids, &shadow_ids);
+    if delta.is_unchanged() {
+        return;
+    }
+    crate::log::info(
+        "search-weights",
+        &format!(
+            "shadow profile {} for {project} ({surface}): top {:?} -> {:?}, overlap {}/{}, entered {:?}, left {:?}, max shift {}",
+            shadow.profile,
+            delta.live_top,
+            delta.shadow_top,
+            delta.overlap,
+            delta.window,
+            delta.entered,
+            delta.left,
+            delta.max_rank_shift
+        ),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare_shadow_ranking, sampled_draw, ShadowRankingDelta, SHADOW_SAMPLE_EVERY};
+
+    #[test]
+    fn ranking_delta_reports_top_change_churn_and_shift() {
+        let delta = ShadowRankingDelta::between(&[1, 2, 3, 4], &[3, 1, 2, 9]);
+        assert_eq!(delta.live_top, Some(1));
+        assert_eq!(delta.shadow_top, Some(3));
+        assert_eq!(delta.overlap, 3);
+        assert_eq!(delta.window, 4);
+        assert_eq!(delta.entered, vec![9]);
+        assert_eq!(delta.left, vec![4]);
+        assert_eq!(delta.max_rank_shift, 2);
+        assert!(!delta.is_unchanged());
+
+        assert!(ShadowRankingDelta::between(&[5, 6], &[5, 6]).is_unchanged());
+        assert!(ShadowRankingDelta::between(&[], &[]).is_unchanged());
+    }
+
+    #[test]
+    fn unsampled_requests_skip_the_shadow_run() {
+        compare_shadow_ranking("search", Some("/p"), None, &[], |_| {
+            panic!("shadow retrieval must not run without a sampled profile")
+        });
+        let sampled = (0..100).filter(|draw| sampled_draw(*draw)).count() as u64;
+        assert_eq!(sampled, 100 / SHADOW_SAMPLE_EVERY);
+    }
+}
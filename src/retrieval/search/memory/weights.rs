@@ -1,3 +1,5 @@
+use crate::runtime_config::SearchWeightMode;
+
 const RRF_K: f64 = 60.0;
 const MAX_VECTOR_DISTANCE: f32 = 0.51;
 const FTS_WEIGHT: f64 = 2.5;
@@ -21,6 +23,20 @@ const MIN_EVIDENCE_CONFIDENCE: f64 = 0.62;
 /// `REMEM_USAGE_WEIGHT=0` restores the pre-rollout ranking byte-for-byte.
 pub(crate) const USAGE_WEIGHT_ENV: &str = "REMEM_USAGE_WEIGHT";
 
+/// Weights selected for one project's retrieval, plus the profile running
+/// in shadow mode next to them, if any.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ProjectSearchWeights {
+    pub(crate) weights: SearchWeights,
+    pub(crate) shadow: Option<ShadowSearchWeights>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ShadowSearchWeights {
+    pub(crate) profile: String,
+    pub(crate) weights: SearchWeights,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct SearchWeights {
     pub fts: f64,
@@ -80,7 +96,52 @@ impl SearchWeights {
     /// calibrated default; parse failures log at error level instead of
     /// silently degrading.
     pub(crate) fn production() -> Self {
-        let mut weights = Self::default();
+        Self::default().with_usage_override()
+    }
+
+    /// Production weights for `project`: its `[search.weights]` profile when
+    /// assigned in `active` mode, the calibrated defaults otherwise. A
+    /// `shadow` assignment leaves retrieval on the defaults and hands the
+    /// profile back so the caller can log ranking deltas against it. The
+    /// `REMEM_USAGE_WEIGHT` override applies on top of either, and a broken
+    /// config logs and falls back to the defaults.
+    pub(crate) fn for_project(project: Option<&str>) -> ProjectSearchWeights {
+        let production = ProjectSearchWeights {
+            weights: Self::production(),
+            shadow: None,
+        };
+        let Some(project) = project.map(str::trim).filter(|project| !project.is_empty()) else {
+            return production;
+        };
+        let assignment = match crate::runtime_config::project_search_weight_profile(project) {
+            Ok(Some(assignment)) => assignment,
+            Ok(None) => return production,
+            Err(error) => {
+                crate::log::error(
+                    "retrieval",
+                    &format!("ignoring search weight profiles: {error:#}"),
+                );
+                return production;
+            }
+        };
+        let profile_weights = assignment.weights.with_usage_override();
+        match assignment.mode {
+            SearchWeightMode::Active => ProjectSearchWeights {
+                weights: profile_weights,
+                shadow: None,
+            },
+            SearchWeightMode::Shadow => ProjectSearchWeights {
+                shadow: Some(ShadowSearchWeights {
+                    profile: assignment.profile,
+                    weights: profile_weights,
+                }),
+                ..production
+            },
+        }
+    }
+
+    fn with_usage_override(self) -> Self {
+        let mut weights = self;
         let Ok(raw) = std::env::var(USAGE_WEIGHT_ENV) else {
             return weights;
         };
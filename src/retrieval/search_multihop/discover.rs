@@ -1,17 +1,28 @@
 use std::collections::HashSet;
 
 use crate::memory::Memory;
+use crate::retrieval::entity::ProjectVocabulary;
 
-pub(crate) fn discover_entities(query: &str, first_hop: &[Memory]) -> Vec<String> {
+/// Entities named by first-hop memories but not by the query itself. With a
+/// project vocabulary, learned project identifiers count on both sides.
+pub(crate) fn discover_entities(
+    query: &str,
+    first_hop: &[Memory],
+    vocabulary: Option<&ProjectVocabulary>,
+) -> Vec<String> {
     let mut discovered_entities = Vec::new();
     let mut seen_entities: HashSet<String> = HashSet::new();
 
-    for entity in crate::retrieval::entity::extract_entities(query, "") {
+    for entity in crate::retrieval::entity::extract_with_vocabulary(vocabulary, query, "") {
         seen_entities.insert(entity.to_lowercase());
     }
 
     for memory in first_hop {
-        for entity in crate::retrieval::entity::extract_entities(&memory.title, &memory.text) {
+        for entity in crate::retrieval::entity::extract_with_vocabulary(
+            vocabulary,
+            &memory.title,
+            &memory.text,
+        ) {
             let lower = entity.to_lowercase();
             if !seen_entities.contains(&lower) {
                 seen_entities.insert(lower);
@@ -70,7 +70,9 @@ pub fn search_multi_hop(
         });
     }
 
-    let discovered_entities = discover_entities(query, &first_hop);
+    let vocabulary = project
+        .map(|project| crate::retrieval::entity::ProjectVocabulary::for_project(conn, project));
+    let discovered_entities = discover_entities(query, &first_hop, vocabulary.as_deref());
     if discovered_entities.is_empty() {
         return Ok(MultiHopResult {
             memories: paginate_memories(first_hop, limit, offset),
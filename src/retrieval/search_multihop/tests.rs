@@ -30,7 +30,7 @@ fn discover_entities_skips_query_entities_and_deduplicates() {
         make_memory(2, "Tom", "Sarah Tom"),
     ];
 
-    let entities = discover_entities("Melanie", &first_hop);
+    let entities = discover_entities("Melanie", &first_hop, None);
     assert_eq!(entities, vec!["Tom", "Sarah"]);
 }
 
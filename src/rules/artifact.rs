@@ -1,7 +1,10 @@
 use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
 
-pub const ARTIFACT_VERSION: u32 = 2;
+pub const ARTIFACT_VERSION: u32 = 3;
+/// v2 predicates are a strict subset of v3 and share `regex_lite` semantics,
+/// so loaded v2 artifacts migrate in place.
+pub const V2_ARTIFACT_VERSION: u32 = 2;
 pub const LEGACY_ARTIFACT_VERSION: u32 = 1;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -21,6 +24,19 @@ pub struct CompiledRule {
     pub action: RuleAction,
     pub override_state: RuleOverrideState,
     pub predicate: RulePredicate,
+    /// Never serialized: artifacts on disk only hold compiled rules, and
+    /// hand-authored rules are merged in when the project rules load.
+    #[serde(skip)]
+    pub origin: RuleOrigin,
+}
+
+/// Where a rule came from. Compiled rules cite a preference memory;
+/// authored rules come from the project's `.remem/rules.toml`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RuleOrigin {
+    #[default]
+    Compiled,
+    Authored,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -40,9 +56,41 @@ pub struct RuleOverrideState {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
 pub enum RulePredicate {
-    CommandRegex { pattern: String, message: String },
-    CommitTrailerForbidden { trailer: String, message: String },
-    GitPushForceForbidden { message: String },
+    CommandRegex {
+        pattern: String,
+        message: String,
+    },
+    CommitTrailerForbidden {
+        trailer: String,
+        message: String,
+    },
+    GitPushForceForbidden {
+        message: String,
+    },
+    /// File-writing tool (Edit/Write/MultiEdit/NotebookEdit) targets a path
+    /// matching `glob`; see `rules::glob` for the syntax.
+    FilePathGlob {
+        glob: String,
+        message: String,
+    },
+    /// Tool name matches one of `tools` (`*` / `?` wildcards allowed).
+    ToolNameDeny {
+        tools: Vec<String>,
+        message: String,
+    },
+    /// Tool name matches none of `tools`.
+    ToolNameAllow {
+        tools: Vec<String>,
+        message: String,
+    },
+    /// Text written by a file-writing tool matches `pattern`, optionally only
+    /// for target paths matching `path_glob`.
+    ContentRegex {
+        pattern: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        path_glob: Option<String>,
+        message: String,
+    },
 }
 
 impl CompiledRulesArtifact {
@@ -55,11 +103,15 @@ impl CompiledRulesArtifact {
     }
 
     pub fn validate(&self) -> Result<()> {
-        if !matches!(self.version, LEGACY_ARTIFACT_VERSION | ARTIFACT_VERSION) {
+        if !matches!(
+            self.version,
+            LEGACY_ARTIFACT_VERSION | V2_ARTIFACT_VERSION | ARTIFACT_VERSION
+        ) {
             bail!(
-                "unsupported compiled rule artifact version {}; expected {} or {}",
+                "unsupported compiled rule artifact version {}; expected {}, {} or {}",
                 self.version,
                 LEGACY_ARTIFACT_VERSION,
+                V2_ARTIFACT_VERSION,
                 ARTIFACT_VERSION
             );
         }
@@ -71,6 +123,15 @@ impl CompiledRulesArtifact {
         }
         Ok(())
     }
+
+    /// Upgrade a validated v2 artifact to the current version. v1 keeps its
+    /// Unicode `regex` semantics and is never rewritten.
+    pub fn migrate(mut self) -> Self {
+        if self.version == V2_ARTIFACT_VERSION {
+            self.version = ARTIFACT_VERSION;
+        }
+        self
+    }
 }
 
 impl CompiledRule {
@@ -105,11 +166,64 @@ impl RulePredicate {
         match self {
             RulePredicate::CommandRegex { message, .. }
             | RulePredicate::CommitTrailerForbidden { message, .. }
-            | RulePredicate::GitPushForceForbidden { message } => message,
+            | RulePredicate::GitPushForceForbidden { message }
+            | RulePredicate::FilePathGlob { message, .. }
+            | RulePredicate::ToolNameDeny { message, .. }
+            | RulePredicate::ToolNameAllow { message, .. }
+            | RulePredicate::ContentRegex { message, .. } => message,
+        }
+    }
+
+    /// True when the predicate only inspects Bash commands.
+    pub fn is_command_predicate(&self) -> bool {
+        matches!(
+            self,
+            RulePredicate::CommandRegex { .. }
+                | RulePredicate::CommitTrailerForbidden { .. }
+                | RulePredicate::GitPushForceForbidden { .. }
+        )
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            RulePredicate::CommandRegex { .. } => "command_regex",
+            RulePredicate::CommitTrailerForbidden { .. } => "commit_trailer_forbidden",
+            RulePredicate::GitPushForceForbidden { .. } => "git_push_force_forbidden",
+            RulePredicate::FilePathGlob { .. } => "file_path_glob",
+            RulePredicate::ToolNameDeny { .. } => "tool_name_deny",
+            RulePredicate::ToolNameAllow { .. } => "tool_name_allow",
+            RulePredicate::ContentRegex { .. } => "content_regex",
+        }
+    }
+
+    /// Predicate with its message cleared, so a hand-authored rule and a
+    /// compiled rule guarding the same thing compare equal.
+    pub(crate) fn target(&self) -> RulePredicate {
+        let mut target = self.clone();
+        match &mut target {
+            RulePredicate::CommandRegex { message, .. }
+            | RulePredicate::CommitTrailerForbidden { message, .. }
+            | RulePredicate::GitPushForceForbidden { message }
+            | RulePredicate::FilePathGlob { message, .. }
+            | RulePredicate::ToolNameDeny { message, .. }
+            | RulePredicate::ToolNameAllow { message, .. }
+            | RulePredicate::ContentRegex { message, .. } => message.clear(),
         }
+        target
     }
 
-    fn validate(&self, rule_id: &str, artifact_version: u32) -> Result<()> {
+    pub(super) fn validate(&self, rule_id: &str, artifact_version: u32) -> Result<()> {
+        if !self.is_command_predicate() {
+            if artifact_version < ARTIFACT_VERSION {
+                bail!(
+                    "compiled rule {rule_id} uses {} in a v{artifact_version} artifact",
+                    self.kind()
+                );
+            }
+            if self.message().trim().is_empty() {
+                bail!("compiled rule {rule_id} has empty {} message", self.kind());
+            }
+        }
         match self {
             RulePredicate::CommandRegex { pattern, message } => {
                 if pattern.trim().is_empty() {
@@ -147,6 +261,35 @@ impl RulePredicate {
                     bail!("compiled rule {rule_id} has empty forbidden force-push message");
                 }
             }
+            RulePredicate::FilePathGlob { glob, .. } => {
+                if let Err(error) = crate::rules::glob::validate_glob(glob) {
+                    bail!("compiled rule {rule_id} has invalid file_path_glob: {error}");
+                }
+            }
+            RulePredicate::ToolNameDeny { tools, .. }
+            | RulePredicate::ToolNameAllow { tools, .. } => {
+                if tools.is_empty() || tools.iter().any(|tool| tool.trim().is_empty()) {
+                    bail!(
+                        "compiled rule {rule_id} needs a non-empty {} tool list",
+                        self.kind()
+                    );
+                }
+            }
+            RulePredicate::ContentRegex {
+                pattern, path_glob, ..
+            } => {
+                if pattern.trim().is_empty() {
+                    bail!("compiled rule {rule_id} has empty content_regex pattern");
+                }
+                if let Err(error) = regex_lite::Regex::new(pattern) {
+                    bail!("compiled rule {rule_id} has invalid content_regex pattern: {error}");
+                }
+                if let Some(Err(error)) =
+                    path_glob.as_deref().map(crate::rules::glob::validate_glob)
+                {
+                    bail!("compiled rule {rule_id} has invalid content_regex path_glob: {error}");
+                }
+            }
         }
         Ok(())
     }
@@ -256,4 +399,71 @@ mod tests {
         assert!(error.to_string().contains("in a v1 artifact"));
         Ok(())
     }
+
+    #[test]
+    fn tool_predicates_require_v3_and_migrate_from_v2() -> Result<()> {
+        let mut artifact =
+            CompiledRulesArtifact::new(1234, vec![package_manager_rule(RuleAction::Warn)]);
+        artifact.rules[0].predicate = RulePredicate::ContentRegex {
+            pattern: r"console\.log".to_string(),
+            path_glob: Some("src/".to_string()),
+            message: "No console logging".to_string(),
+        };
+        artifact.validate()?;
+        let encoded = serde_json::to_string(&artifact)?;
+        assert_eq!(
+            serde_json::from_str::<CompiledRulesArtifact>(&encoded)?,
+            artifact
+        );
+
+        artifact.version = V2_ARTIFACT_VERSION;
+        let error = artifact
+            .validate()
+            .expect_err("v2 artifact must reject v3-only predicates");
+        assert!(error.to_string().contains("content_regex in a v2 artifact"));
+
+        let v2 = CompiledRulesArtifact {
+            version: V2_ARTIFACT_VERSION,
+            compiled_at_epoch: 1234,
+            rules: vec![package_manager_rule(RuleAction::Warn)],
+        };
+        v2.validate()?;
+        assert_eq!(v2.clone().migrate().version, ARTIFACT_VERSION);
+        assert_eq!(
+            v2.migrate().rules[0],
+            package_manager_rule(RuleAction::Warn)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn tool_predicates_validate_globs_and_tool_lists() {
+        for predicate in [
+            RulePredicate::FilePathGlob {
+                glob: "src/[ab]/".to_string(),
+                message: "bad glob".to_string(),
+            },
+            RulePredicate::ToolNameDeny {
+                tools: Vec::new(),
+                message: "empty list".to_string(),
+            },
+            RulePredicate::ToolNameAllow {
+                tools: vec![" ".to_string()],
+                message: "blank tool".to_string(),
+            },
+            RulePredicate::FilePathGlob {
+                glob: ".env".to_string(),
+                message: " ".to_string(),
+            },
+        ] {
+            let mut rule = package_manager_rule(RuleAction::Warn);
+            rule.predicate = predicate.clone();
+            assert!(
+                CompiledRulesArtifact::new(1, vec![rule])
+                    .validate()
+                    .is_err(),
+                "must reject {predicate:?}"
+            );
+        }
+    }
 }
@@ -0,0 +1,245 @@
+//! Hand-authored rules checked into the repository at `.remem/rules.toml`.
+//!
+//! Each `[[rule]]` table carries an `id`, an optional `action` (`warn` by
+//! default) and the same fields as a compiled `RulePredicate` (`kind`,
+//! `message`, and the kind's own keys). Authored rules are validated with the
+//! current artifact schema and surface as `repo-<id>` next to compiled rules.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde_json::Value;
+use toml_edit::DocumentMut;
+
+use super::artifact::{
+    CompiledRule, CompiledRulesArtifact, RuleAction, RuleOrigin, RuleOverrideState, RulePredicate,
+    ARTIFACT_VERSION,
+};
+
+pub const AUTHORED_RULES_FILE: &str = ".remem/rules.toml";
+pub(crate) const AUTHORED_RULE_ID_PREFIX: &str = "repo-";
+const MAX_AUTHORED_ID_CHARS: usize = 64;
+
+/// A compiled rule that an authored rule with the same predicate replaces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShadowedRule {
+    pub rule_id: String,
+    pub authored_rule_id: String,
+}
+
+pub fn authored_rules_path(project: &str) -> PathBuf {
+    Path::new(project).join(AUTHORED_RULES_FILE)
+}
+
+/// Load `.remem/rules.toml`; `Ok(None)` when the project has no such file.
+pub fn load_authored_rules(path: impl AsRef<Path>) -> Result<Option<Vec<CompiledRule>>> {
+    let path = path.as_ref();
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err).with_context(|| format!("read {}", path.display())),
+    };
+    parse_authored_rules(&text)
+        .with_context(|| format!("invalid authored rules {}", path.display()))
+        .map(Some)
+}
+
+pub(crate) fn parse_authored_rules(text: &str) -> Result<Vec<CompiledRule>> {
+    let doc = text.parse::<DocumentMut>().context("parse TOML")?;
+    if let Some((key, _)) = doc.iter().find(|(key, _)| *key != "rule") {
+        bail!("unknown top-level key '{key}'; declare rules as [[rule]] tables");
+    }
+    let Some(item) = doc.get("rule") else {
+        return Ok(Vec::new());
+    };
+    let tables = item
+        .as_array_of_tables()
+        .ok_or_else(|| anyhow!("'rule' must be an array of [[rule]] tables"))?;
+
+    let mut rules: Vec<CompiledRule> = Vec::new();
+    for (index, table) in tables.iter().enumerate() {
+        let position = index + 1;
+        let mut fields = crate::toml_json::table_to_json(table)
+            .map_err(|err| anyhow!("rule #{position}: {err}"))?;
+        let id = match fields.remove("id") {
+            Some(Value::String(id)) => id,
+            Some(_) => bail!("rule #{position}: 'id' must be a string"),
+            None => bail!("rule #{position}: missing 'id'"),
+        };
+        validate_id(&id).with_context(|| format!("rule #{position}"))?;
+        let rule_id = format!("{AUTHORED_RULE_ID_PREFIX}{id}");
+        if rules.iter().any(|rule| rule.rule_id == rule_id) {
+            bail!("rule #{position}: duplicate id '{id}'");
+        }
+        let action = match fields.remove("action") {
+            None => RuleAction::Warn,
+            Some(value) => serde_json::from_value::<RuleAction>(value)
+                .map_err(|_| anyhow!("rule '{id}': action must be \"warn\" or \"block\""))?,
+        };
+        let predicate = serde_json::from_value::<RulePredicate>(Value::Object(fields))
+            .map_err(|err| anyhow!("rule '{id}': {err}"))?;
+        predicate.validate(&rule_id, ARTIFACT_VERSION)?;
+        rules.push(CompiledRule {
+            rule_id,
+            source_memory_id: 0,
+            reinforcement_count: 0,
+            action,
+            override_state: RuleOverrideState {
+                disabled: false,
+                action_override: None,
+            },
+            predicate,
+            origin: RuleOrigin::Authored,
+        });
+    }
+    Ok(rules)
+}
+
+/// Put authored rules ahead of compiled ones. A compiled rule whose predicate
+/// targets the same thing as an authored rule is dropped: the checked-in
+/// rule is the team's explicit decision and wins regardless of action.
+pub fn merge_authored_rules(
+    compiled: CompiledRulesArtifact,
+    authored: Vec<CompiledRule>,
+) -> (CompiledRulesArtifact, Vec<ShadowedRule>) {
+    let mut shadowed = Vec::new();
+    let mut rules = authored;
+    let authored_count = rules.len();
+    for rule in compiled.rules {
+        let target = rule.predicate.target();
+        match rules[..authored_count]
+            .iter()
+            .find(|authored| authored.predicate.target() == target)
+        {
+            Some(authored) => shadowed.push(ShadowedRule {
+                rule_id: rule.rule_id,
+                authored_rule_id: authored.rule_id.clone(),
+            }),
+            None => rules.push(rule),
+        }
+    }
+    (CompiledRulesArtifact { rules, ..compiled }, shadowed)
+}
+
+fn validate_id(id: &str) -> Result<()> {
+    if id.is_empty() || id.chars().count() > MAX_AUTHORED_ID_CHARS {
+        bail!("'id' must be 1-{MAX_AUTHORED_ID_CHARS} characters");
+    }
+    if !id
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+    {
+        bail!("'id' {id:?} may only contain ASCII letters, digits, '-' and '_'");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::test_support::package_manager_rule;
+
+    const RULES: &str = r#"
+[[rule]]
+id = "protect-migrations"
+action = "block"
+kind = "file_path_glob"
+glob = "src/migrations/"
+message = "Generated migrations are read-only"
+
+[[rule]]
+id = "npm"
+kind = "command_regex"
+pattern = '(^|[ \t\r\n])npm[ \t\r\n]+(install|i|add)([ \t\r\n;&|)<>]|$)'
+message = "This repo uses pnpm"
+"#;
+
+    #[test]
+    fn parses_rules_with_prefixed_ids_and_default_action() -> Result<()> {
+        let rules = parse_authored_rules(RULES)?;
+
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].rule_id, "repo-protect-migrations");
+        assert_eq!(rules[0].action, RuleAction::Block);
+        assert_eq!(rules[0].origin, RuleOrigin::Authored);
+        assert_eq!(
+            rules[0].predicate,
+            RulePredicate::FilePathGlob {
+                glob: "src/migrations/".to_string(),
+                message: "Generated migrations are read-only".to_string(),
+            }
+        );
+        assert_eq!(rules[1].action, RuleAction::Warn);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_rules_with_their_position() {
+        for (text, expected) in [
+            ("rules = []", "unknown top-level key 'rules'"),
+            ("[[rule]]\nkind = \"tool_name_deny\"", "rule #1: missing 'id'"),
+            (
+                "[[rule]]\nid = \"a b\"\nkind = \"tool_name_deny\"",
+                "may only contain",
+            ),
+            (
+                "[[rule]]\nid = \"x\"\nkind = \"shell_magic\"\nmessage = \"m\"",
+                "unknown variant `shell_magic`",
+            ),
+            (
+                "[[rule]]\nid = \"x\"\naction = \"deny\"\nkind = \"tool_name_deny\"\ntools = [\"WebFetch\"]\nmessage = \"m\"",
+                "action must be",
+            ),
+            (
+                "[[rule]]\nid = \"x\"\nkind = \"file_path_glob\"\nglob = \"src/[ab]\"\nmessage = \"m\"",
+                "invalid file_path_glob",
+            ),
+            (
+                "[[rule]]\nid = \"x\"\nkind = \"tool_name_deny\"\ntools = [\"A\"]\nmessage = \"m\"\n[[rule]]\nid = \"x\"\nkind = \"tool_name_deny\"\ntools = [\"B\"]\nmessage = \"m\"",
+                "rule #2: duplicate id 'x'",
+            ),
+        ] {
+            let error = format!("{:#}", parse_authored_rules(text).unwrap_err());
+            assert!(error.contains(expected), "{text:?} -> {error}");
+        }
+    }
+
+    #[test]
+    fn authored_rule_shadows_compiled_rule_with_same_target() -> Result<()> {
+        let compiled = CompiledRulesArtifact::new(
+            77,
+            vec![
+                package_manager_rule(RuleAction::Warn),
+                CompiledRule {
+                    rule_id: "pref-9-1".to_string(),
+                    predicate: RulePredicate::GitPushForceForbidden {
+                        message: "Command violates a compiled forbidden-command preference"
+                            .to_string(),
+                    },
+                    ..package_manager_rule(RuleAction::Warn)
+                },
+            ],
+        );
+
+        let (merged, shadowed) = merge_authored_rules(compiled, parse_authored_rules(RULES)?);
+
+        assert_eq!(merged.compiled_at_epoch, 77);
+        assert_eq!(
+            merged
+                .rules
+                .iter()
+                .map(|rule| rule.rule_id.as_str())
+                .collect::<Vec<_>>(),
+            ["repo-protect-migrations", "repo-npm", "pref-9-1"]
+        );
+        assert_eq!(
+            shadowed,
+            [ShadowedRule {
+                rule_id: "pref-123-1".to_string(),
+                authored_rule_id: "repo-npm".to_string(),
+            }]
+        );
+        Ok(())
+    }
+}
@@ -7,7 +7,9 @@ use anyhow::{bail, Context, Result};
 use rusqlite::{params, Connection, OptionalExtension};
 use std::io::ErrorKind;
 
-use crate::rules::artifact::{CompiledRule, CompiledRulesArtifact, RuleAction, RulePredicate};
+use crate::rules::artifact::{
+    CompiledRule, CompiledRulesArtifact, RuleAction, RuleOrigin, RulePredicate,
+};
 use crate::rules::store::{
     artifact_path_for_project, load_artifact_fail_open, write_artifact_atomic, ArtifactLoad,
 };
@@ -23,6 +25,9 @@ pub use classify::{
 const PACKAGE_MANAGER_MESSAGE: &str = "Command violates a compiled package-manager preference";
 const FORBIDDEN_COMMAND_MESSAGE: &str = "Command violates a compiled forbidden-command preference";
 const COMMIT_TRAILER_MESSAGE: &str = "Commit message violates a compiled trailer preference";
+const PROTECTED_PATH_MESSAGE: &str = "File write violates a compiled protected-path preference";
+const FORBIDDEN_TOOL_MESSAGE: &str = "Tool call violates a compiled forbidden-tool preference";
+const FORBIDDEN_CONTENT_MESSAGE: &str = "Written content violates a compiled content preference";
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CompileOutcome {
@@ -284,6 +289,21 @@ fn compile_project_rules_with_conflicts(
                         message: FORBIDDEN_COMMAND_MESSAGE.to_string(),
                     }
                 }
+                PreferencePredicate::FilePathGlob { glob, .. } => RulePredicate::FilePathGlob {
+                    glob,
+                    message: PROTECTED_PATH_MESSAGE.to_string(),
+                },
+                PreferencePredicate::ToolNameDeny { tool, .. } => RulePredicate::ToolNameDeny {
+                    tools: vec![tool],
+                    message: FORBIDDEN_TOOL_MESSAGE.to_string(),
+                },
+                PreferencePredicate::ContentRegex {
+                    pattern, path_glob, ..
+                } => RulePredicate::ContentRegex {
+                    pattern,
+                    path_glob,
+                    message: FORBIDDEN_CONTENT_MESSAGE.to_string(),
+                },
             };
             let override_state =
                 overrides
@@ -300,6 +320,7 @@ fn compile_project_rules_with_conflicts(
                 action: RuleAction::Warn,
                 override_state,
                 predicate,
+                origin: RuleOrigin::Compiled,
             });
         }
     }
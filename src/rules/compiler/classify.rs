@@ -1,4 +1,4 @@
-//! Deterministic, conservative classification of preference text into v1
+//! Deterministic, conservative classification of preference text into rule
 //! predicates. No LLM, no network. The recognised grammar is intentionally
 //! closed so ambiguous natural-language exceptions never become block rules.
 
@@ -17,6 +17,19 @@ pub enum PreferencePredicate {
     GitPushForceForbidden {
         conflict_key: String,
     },
+    FilePathGlob {
+        glob: String,
+        conflict_key: String,
+    },
+    ToolNameDeny {
+        tool: String,
+        conflict_key: String,
+    },
+    ContentRegex {
+        pattern: String,
+        path_glob: Option<String>,
+        conflict_key: String,
+    },
 }
 
 impl PreferencePredicate {
@@ -24,7 +37,10 @@ impl PreferencePredicate {
         match self {
             PreferencePredicate::CommandRegex { conflict_key, .. }
             | PreferencePredicate::CommitTrailerForbidden { conflict_key, .. }
-            | PreferencePredicate::GitPushForceForbidden { conflict_key } => conflict_key.clone(),
+            | PreferencePredicate::GitPushForceForbidden { conflict_key }
+            | PreferencePredicate::FilePathGlob { conflict_key, .. }
+            | PreferencePredicate::ToolNameDeny { conflict_key, .. }
+            | PreferencePredicate::ContentRegex { conflict_key, .. } => conflict_key.clone(),
         }
     }
 }
@@ -98,6 +114,59 @@ const TRAILER_ACTIONS: &[&str] = &[
 
 const KNOWN_TRAILERS: &[&str] = &["AI-generated-by", "Co-authored-by", "Generated-by"];
 
+const PROTECTED_PATH_ACTIONS: &[&str] = &[
+    "do not edit",
+    "don't edit",
+    "dont edit",
+    "never edit",
+    "do not modify",
+    "don't modify",
+    "never modify",
+    "do not touch",
+    "don't touch",
+    "never touch",
+    "do not write to",
+    "don't write to",
+    "never write to",
+];
+
+/// Qualifiers that name a directory ("files under src/migrations").
+const DIRECTORY_QUALIFIERS: &[&str] = &[
+    "generated files under ",
+    "generated files in ",
+    "files under ",
+    "files in ",
+    "anything under ",
+    "anything in ",
+];
+
+const LOCAL_DIRECTIVE_SUFFIXES: &[&str] = &[" in this project", " in this repo", ""];
+
+const TOOL_ACTIONS: &[&str] = &["do not use", "don't use", "dont use", "never use"];
+
+const KNOWN_TOOLS: &[&str] = &[
+    "Agent",
+    "Bash",
+    "Edit",
+    "MultiEdit",
+    "NotebookEdit",
+    "Task",
+    "WebFetch",
+    "WebSearch",
+    "Write",
+];
+
+const CONTENT_ACTIONS: &[&str] = &[
+    "do not add",
+    "don't add",
+    "never add",
+    "do not write",
+    "don't write",
+    "never write",
+];
+
+const MAX_CONTENT_LITERAL_CHARS: usize = 200;
+
 pub fn classify_preference_predicate(text: &str) -> Option<PreferenceClassification> {
     classify_preference_predicates(text).into_iter().next()
 }
@@ -116,9 +185,147 @@ pub fn classify_preference_predicates(text: &str) -> Vec<PreferenceClassificatio
         .collect::<Vec<_>>();
     classifications.extend(classify_forbidden_command(&lower));
     classifications.extend(classify_commit_trailers(&lower));
+    // Paths and literals are case-sensitive, so these read the original text.
+    classifications.extend(classify_protected_path(text));
+    classifications.extend(classify_forbidden_tool(text));
+    classifications.extend(classify_forbidden_content(text));
     classifications
 }
 
+/// "Never edit generated files under src/migrations", "Don't write to .env".
+fn classify_protected_path(text: &str) -> Option<PreferenceClassification> {
+    let statement = path_statement(text)?;
+    let rest = PROTECTED_PATH_ACTIONS
+        .iter()
+        .find_map(|action| strip_prefix_ignore_case(statement, &format!("{action} ")))?;
+    let (directory, rest) = DIRECTORY_QUALIFIERS
+        .iter()
+        .find_map(|qualifier| strip_prefix_ignore_case(rest, qualifier).map(|rest| (true, rest)))
+        .or_else(|| strip_prefix_ignore_case(rest, "the ").map(|rest| (false, rest)))
+        .unwrap_or((false, rest));
+    let (token, suffix) = rest.find(' ').map_or((rest, ""), |at| rest.split_at(at));
+    if !LOCAL_DIRECTIVE_SUFFIXES
+        .iter()
+        .any(|allowed| allowed.eq_ignore_ascii_case(suffix))
+    {
+        return None;
+    }
+    let glob = path_glob_from_token(token, directory)?;
+    Some(PreferenceClassification {
+        predicate: PreferencePredicate::FilePathGlob {
+            conflict_key: format!("protected-path:{glob}"),
+            glob,
+        },
+        summary: "Protected file path".to_string(),
+    })
+}
+
+/// "Never use the WebFetch tool".
+fn classify_forbidden_tool(text: &str) -> Option<PreferenceClassification> {
+    let statement = path_statement(text)?;
+    let rest = TOOL_ACTIONS
+        .iter()
+        .find_map(|action| strip_prefix_ignore_case(statement, &format!("{action} ")))?;
+    let rest = strip_prefix_ignore_case(rest, "the ").unwrap_or(rest);
+    let tool = KNOWN_TOOLS.iter().find(|tool| {
+        strip_prefix_ignore_case(rest, &format!("{tool} tool")).is_some_and(|suffix| {
+            LOCAL_DIRECTIVE_SUFFIXES
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(suffix))
+        })
+    })?;
+    Some(PreferenceClassification {
+        predicate: PreferencePredicate::ToolNameDeny {
+            tool: (*tool).to_string(),
+            conflict_key: format!("tool:{}", tool.to_ascii_lowercase()),
+        },
+        summary: "Forbidden tool".to_string(),
+    })
+}
+
+/// "Never add `console.log`" or "Do not write `unwrap()` in src/".
+fn classify_forbidden_content(text: &str) -> Option<PreferenceClassification> {
+    let statement = path_statement(text)?;
+    let rest = CONTENT_ACTIONS
+        .iter()
+        .find_map(|action| strip_prefix_ignore_case(statement, &format!("{action} `")))?;
+    let (literal, rest) = rest.split_once('`')?;
+    if literal.trim().is_empty() || literal.chars().count() > MAX_CONTENT_LITERAL_CHARS {
+        return None;
+    }
+    let path_glob = match strip_prefix_ignore_case(rest, " in ")
+        .or_else(|| strip_prefix_ignore_case(rest, " to "))
+    {
+        Some(token) => Some(path_glob_from_token(token, false)?),
+        None if LOCAL_DIRECTIVE_SUFFIXES
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(rest)) =>
+        {
+            None
+        }
+        None => return None,
+    };
+    Some(PreferenceClassification {
+        predicate: PreferencePredicate::ContentRegex {
+            pattern: regex_lite::escape(literal),
+            conflict_key: format!("content:{literal}@{}", path_glob.as_deref().unwrap_or("*")),
+            path_glob,
+        },
+        summary: "Forbidden written content".to_string(),
+    })
+}
+
+/// Single-statement check that, unlike `normalized_single_statement`, keeps
+/// case and allows `.` inside path tokens and backtick literals.
+fn path_statement(text: &str) -> Option<&str> {
+    let statement = text.trim().trim_end_matches(['.', '!', '?']).trim_end();
+    if statement
+        .chars()
+        .any(|ch| [';', ':', '!', '?', '\n', '\r'].contains(&ch))
+    {
+        return None;
+    }
+    Some(statement)
+}
+
+/// Turn a path-like token into a glob. Plain words are rejected so
+/// "never touch production" never becomes a path rule; a last segment
+/// without an extension is treated as a directory.
+fn path_glob_from_token(token: &str, directory: bool) -> Option<String> {
+    let token = token.trim_matches(|ch| matches!(ch, '`' | '"' | '\''));
+    if token.is_empty()
+        || !token
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || "._-/*?".contains(ch))
+        || !token.contains(['/', '.', '*'])
+        || token.trim_matches(['.', '/']).is_empty()
+    {
+        return None;
+    }
+    let has_wildcard = token.contains(['*', '?']);
+    let last_segment = token
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or(token);
+    let glob = if token.ends_with('/') || has_wildcard {
+        token.to_string()
+    } else if directory || !last_segment.contains('.') {
+        format!("{}/", token.trim_end_matches('/'))
+    } else {
+        token.to_string()
+    };
+    crate::rules::glob::validate_glob(&glob).ok()?;
+    Some(glob)
+}
+
+fn strip_prefix_ignore_case<'a>(value: &'a str, prefix: &str) -> Option<&'a str> {
+    value
+        .get(..prefix.len())
+        .filter(|head| head.eq_ignore_ascii_case(prefix))
+        .map(|_| &value[prefix.len()..])
+}
+
 fn classify_forbidden_command(lower: &str) -> Option<PreferenceClassification> {
     for (command, key) in FORBIDDEN_COMMANDS {
         let is_exact_directive = FORBIDDEN_COMMAND_ACTIONS.iter().any(|action| {
@@ -391,7 +598,10 @@ mod tests {
         .filter_map(|classification| match classification.predicate {
             PreferencePredicate::CommitTrailerForbidden { trailer, .. } => Some(trailer),
             PreferencePredicate::CommandRegex { .. }
-            | PreferencePredicate::GitPushForceForbidden { .. } => None,
+            | PreferencePredicate::GitPushForceForbidden { .. }
+            | PreferencePredicate::FilePathGlob { .. }
+            | PreferencePredicate::ToolNameDeny { .. }
+            | PreferencePredicate::ContentRegex { .. } => None,
         })
         .collect::<Vec<_>>();
         assert_eq!(trailers, ["Co-authored-by"]);
@@ -406,7 +616,10 @@ mod tests {
         .filter_map(|classification| match classification.predicate {
             PreferencePredicate::CommitTrailerForbidden { trailer, .. } => Some(trailer),
             PreferencePredicate::CommandRegex { .. }
-            | PreferencePredicate::GitPushForceForbidden { .. } => None,
+            | PreferencePredicate::GitPushForceForbidden { .. }
+            | PreferencePredicate::FilePathGlob { .. }
+            | PreferencePredicate::ToolNameDeny { .. }
+            | PreferencePredicate::ContentRegex { .. } => None,
         })
         .collect::<Vec<_>>();
         assert_eq!(trailers, ["AI-generated-by", "Co-authored-by"]);
@@ -443,4 +656,66 @@ mod tests {
         )
         .is_none());
     }
+
+    #[test]
+    fn protected_path_directives_compile_to_globs() {
+        for (text, expected) in [
+            (
+                "Never edit generated files under src/migrations",
+                "src/migrations/",
+            ),
+            ("Don't write to .env", ".env"),
+            (
+                "Do not modify src/generated/ in this project.",
+                "src/generated/",
+            ),
+            ("Never touch the Cargo.lock", "Cargo.lock"),
+            ("Never edit `schema/*.json`", "schema/*.json"),
+        ] {
+            let classification = classify_preference_predicate(text)
+                .unwrap_or_else(|| panic!("expected path classification for {text}"));
+            assert_eq!(
+                classification.predicate,
+                PreferencePredicate::FilePathGlob {
+                    glob: expected.to_string(),
+                    conflict_key: format!("protected-path:{expected}"),
+                },
+                "{text}"
+            );
+        }
+        for text in [
+            "Never touch production",
+            "Never edit .env unless asked",
+            "Never edit ../outside",
+            "Never edit src/migrations; regenerate them instead",
+        ] {
+            assert!(
+                classify_preference_predicate(text).is_none(),
+                "must fail closed: {text}"
+            );
+        }
+    }
+
+    #[test]
+    fn forbidden_tool_and_content_directives_are_closed() {
+        assert_eq!(
+            classify_preference_predicate("Never use the WebFetch tool")
+                .map(|classification| classification.predicate),
+            Some(PreferencePredicate::ToolNameDeny {
+                tool: "WebFetch".to_string(),
+                conflict_key: "tool:webfetch".to_string(),
+            })
+        );
+        assert!(classify_preference_predicate("Never use the browser tool").is_none());
+
+        let classification = classify_preference_predicate("Never add `console.log` in web/")
+            .expect("backticked literal should classify");
+        assert!(matches!(
+            classification.predicate,
+            PreferencePredicate::ContentRegex { ref pattern, ref path_glob, .. }
+                if pattern == r"console\.log" && path_glob.as_deref() == Some("web/")
+        ));
+        assert!(classify_preference_predicate("Never add console.log").is_none());
+        assert!(classify_preference_predicate("Never add `x` because reasons").is_none());
+    }
 }
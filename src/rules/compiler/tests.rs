@@ -155,8 +155,7 @@ fn eligible_preference_compiles_with_warn_default() -> Result<()> {
                 "Command violates a compiled package-manager preference"
             )
         }
-        RulePredicate::CommitTrailerForbidden { .. }
-        | RulePredicate::GitPushForceForbidden { .. } => unreachable!(),
+        _ => unreachable!(),
     }
     let serialized = serde_json::to_string(&artifact)?;
     assert!(!serialized.contains("Use bun, not npm"));
@@ -5,15 +5,41 @@ use serde::{Deserialize, Serialize};
 mod bash_ast;
 
 use crate::rules::artifact::{
-    CompiledRule, CompiledRulesArtifact, RuleAction, RulePredicate, LEGACY_ARTIFACT_VERSION,
+    CompiledRule, CompiledRulesArtifact, RuleAction, RuleOrigin, RulePredicate,
+    LEGACY_ARTIFACT_VERSION,
+};
+use crate::rules::glob::{glob_matches, wildcard_matches};
+use crate::rules::store::{
+    load_artifact_fail_open, load_project_rules_with_shadowed, ArtifactLoad, ArtifactLoadErrorKind,
 };
-use crate::rules::store::{load_artifact_fail_open, ArtifactLoad, ArtifactLoadErrorKind};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EvaluationInput {
     pub command: String,
 }
 
+/// One PreToolUse call. `command` is set for Bash; `file_path` (project
+/// relative when inside the project) and `content` are set only for
+/// file-writing tools, so read-only tools never trip path or content rules.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolUseInput {
+    pub tool_name: String,
+    pub command: Option<String>,
+    pub file_path: Option<String>,
+    pub content: Option<String>,
+}
+
+impl From<&EvaluationInput> for ToolUseInput {
+    fn from(input: &EvaluationInput) -> Self {
+        Self {
+            tool_name: "Bash".to_string(),
+            command: Some(input.command.clone()),
+            file_path: None,
+            content: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EvaluationOutcome {
     pub verdict: EvaluationVerdict,
@@ -34,6 +60,7 @@ pub struct RuleMatch {
     pub source_memory_id: i64,
     pub action: RuleAction,
     pub message: String,
+    pub origin: RuleOrigin,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -46,6 +73,9 @@ pub struct EvaluationDiagnostic {
 pub(crate) struct CodedEvaluationOutcome {
     pub outcome: EvaluationOutcome,
     pub diagnostic_codes: Vec<EvaluationDiagnosticCode>,
+    /// A rules source that failed to load while the other was still
+    /// evaluated; reported without failing the verdict open.
+    pub skipped_source: Option<(EvaluationDiagnosticCode, String)>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -55,6 +85,7 @@ pub(crate) enum EvaluationDiagnosticCode {
     ArtifactRead,
     ArtifactParse,
     ArtifactValidate,
+    AuthoredRules,
     RuleEvaluation,
     HookInputRead,
     Config,
@@ -69,6 +100,7 @@ impl EvaluationDiagnosticCode {
             Self::ArtifactRead => "artifact_read",
             Self::ArtifactParse => "artifact_parse",
             Self::ArtifactValidate => "artifact_validate",
+            Self::AuthoredRules => "authored_rules",
             Self::RuleEvaluation => "rule_evaluation",
             Self::HookInputRead => "hook_input_read",
             Self::Config => "config",
@@ -86,13 +118,20 @@ pub enum EvaluationDiagnosticStatus {
 pub fn evaluate_artifact(
     artifact: &CompiledRulesArtifact,
     input: &EvaluationInput,
+) -> EvaluationOutcome {
+    evaluate_tool_use(artifact, &ToolUseInput::from(input))
+}
+
+pub fn evaluate_tool_use(
+    artifact: &CompiledRulesArtifact,
+    input: &ToolUseInput,
 ) -> EvaluationOutcome {
     evaluate_artifact_with_codes(artifact, input).outcome
 }
 
 fn evaluate_artifact_with_codes(
     artifact: &CompiledRulesArtifact,
-    input: &EvaluationInput,
+    input: &ToolUseInput,
 ) -> CodedEvaluationOutcome {
     let mut matches = Vec::new();
     let mut diagnostics = Vec::new();
@@ -108,6 +147,7 @@ fn evaluate_artifact_with_codes(
                 source_memory_id: rule.source_memory_id,
                 action: rule.effective_action(),
                 message: rule.predicate.message().to_string(),
+                origin: rule.origin,
             }),
             Ok(false) => {}
             Err(message) => {
@@ -128,6 +168,7 @@ fn evaluate_artifact_with_codes(
                 diagnostics,
             },
             diagnostic_codes,
+            skipped_source: None,
         };
     }
 
@@ -138,6 +179,7 @@ fn evaluate_artifact_with_codes(
             diagnostics,
         },
         diagnostic_codes,
+        skipped_source: None,
     }
 }
 
@@ -145,14 +187,34 @@ pub fn evaluate_artifact_file(
     path: impl AsRef<Path>,
     input: &EvaluationInput,
 ) -> EvaluationOutcome {
-    evaluate_artifact_file_with_codes(path, input).outcome
+    evaluate_artifact_file_with_codes(path, &ToolUseInput::from(input)).outcome
 }
 
-pub(crate) fn evaluate_artifact_file_with_codes(
+fn evaluate_artifact_file_with_codes(
     path: impl AsRef<Path>,
-    input: &EvaluationInput,
+    input: &ToolUseInput,
+) -> CodedEvaluationOutcome {
+    evaluate_loaded_with_codes(load_artifact_fail_open(path), input)
+}
+
+/// Evaluate the compiled artifact merged with the project's authored rules.
+/// A broken source is reported in `skipped_source` while the other source's
+/// rules still decide the verdict.
+pub(crate) fn evaluate_project_rules_with_codes(
+    data_dir: &Path,
+    project: &str,
+    input: &ToolUseInput,
 ) -> CodedEvaluationOutcome {
-    match load_artifact_fail_open(path) {
+    let project_rules = load_project_rules_with_shadowed(data_dir, project);
+    let mut coded = evaluate_loaded_with_codes(project_rules.load, input);
+    coded.skipped_source = project_rules
+        .skipped
+        .map(|(kind, message)| (diagnostic_code_for_artifact_error(kind), message));
+    coded
+}
+
+fn evaluate_loaded_with_codes(load: ArtifactLoad, input: &ToolUseInput) -> CodedEvaluationOutcome {
+    match load {
         ArtifactLoad::Loaded(artifact) => evaluate_artifact_with_codes(&artifact, input),
         ArtifactLoad::FailOpen { kind, message } => CodedEvaluationOutcome {
             outcome: EvaluationOutcome {
@@ -164,6 +226,7 @@ pub(crate) fn evaluate_artifact_file_with_codes(
                 }],
             },
             diagnostic_codes: vec![diagnostic_code_for_artifact_error(kind)],
+            skipped_source: None,
         },
     }
 }
@@ -174,32 +237,86 @@ fn diagnostic_code_for_artifact_error(kind: ArtifactLoadErrorKind) -> Evaluation
         ArtifactLoadErrorKind::Read => EvaluationDiagnosticCode::ArtifactRead,
         ArtifactLoadErrorKind::Parse => EvaluationDiagnosticCode::ArtifactParse,
         ArtifactLoadErrorKind::Validate => EvaluationDiagnosticCode::ArtifactValidate,
+        ArtifactLoadErrorKind::AuthoredRules => EvaluationDiagnosticCode::AuthoredRules,
     }
 }
 
 fn rule_matches(
     artifact_version: u32,
     rule: &CompiledRule,
-    input: &EvaluationInput,
+    input: &ToolUseInput,
+) -> Result<bool, String> {
+    if rule.predicate.is_command_predicate() {
+        return match input.command.as_deref() {
+            Some(command) => command_rule_matches(artifact_version, rule, command),
+            None => Ok(false),
+        };
+    }
+    match &rule.predicate {
+        RulePredicate::FilePathGlob { glob, .. } => Ok(input
+            .file_path
+            .as_deref()
+            .is_some_and(|path| glob_matches(glob, path))),
+        RulePredicate::ToolNameDeny { tools, .. } => Ok(tool_listed(tools, &input.tool_name)),
+        RulePredicate::ToolNameAllow { tools, .. } => Ok(!tool_listed(tools, &input.tool_name)),
+        RulePredicate::ContentRegex {
+            pattern, path_glob, ..
+        } => {
+            let Some(content) = input.content.as_deref() else {
+                return Ok(false);
+            };
+            if let Some(glob) = path_glob {
+                if !input
+                    .file_path
+                    .as_deref()
+                    .is_some_and(|path| glob_matches(glob, path))
+                {
+                    return Ok(false);
+                }
+            }
+            regex_lite::Regex::new(pattern)
+                .map(|regex| regex.is_match(content))
+                .map_err(|err| format!("rule {} has invalid regex: {err}", rule.rule_id))
+        }
+        RulePredicate::CommandRegex { .. }
+        | RulePredicate::CommitTrailerForbidden { .. }
+        | RulePredicate::GitPushForceForbidden { .. } => Ok(false),
+    }
+}
+
+fn tool_listed(tools: &[String], tool_name: &str) -> bool {
+    tools
+        .iter()
+        .any(|tool| wildcard_matches(tool.trim(), tool_name))
+}
+
+fn command_rule_matches(
+    artifact_version: u32,
+    rule: &CompiledRule,
+    command: &str,
 ) -> Result<bool, String> {
     match &rule.predicate {
         RulePredicate::CommandRegex { pattern, .. } => {
             if artifact_version == LEGACY_ARTIFACT_VERSION {
                 regex::Regex::new(pattern)
-                    .map(|regex| regex.is_match(&input.command))
+                    .map(|regex| regex.is_match(command))
                     .map_err(|err| format!("rule {} has invalid regex: {err}", rule.rule_id))
             } else {
                 regex_lite::Regex::new(pattern)
-                    .map(|regex| regex.is_match(&input.command))
+                    .map(|regex| regex.is_match(command))
                     .map_err(|err| format!("rule {} has invalid regex: {err}", rule.rule_id))
             }
         }
         RulePredicate::CommitTrailerForbidden { trailer, .. } => {
-            command_adds_forbidden_commit_trailer(&input.command, trailer)
+            command_adds_forbidden_commit_trailer(command, trailer)
                 .map_err(|err| format!("rule {} could not parse command: {err}", rule.rule_id))
         }
-        RulePredicate::GitPushForceForbidden { .. } => command_forces_git_push(&input.command)
+        RulePredicate::GitPushForceForbidden { .. } => command_forces_git_push(command)
             .map_err(|err| format!("rule {} could not parse command: {err}", rule.rule_id)),
+        RulePredicate::FilePathGlob { .. }
+        | RulePredicate::ToolNameDeny { .. }
+        | RulePredicate::ToolNameAllow { .. }
+        | RulePredicate::ContentRegex { .. } => Ok(false),
     }
 }
 
@@ -6,6 +6,7 @@ mod git_execution;
 mod git_execution_alternative_state;
 mod git_execution_positional_regressions;
 mod git_execution_wrapper_options;
+mod tool_predicates;
 
 fn forbidden_trailer_rule() -> CompiledRule {
     CompiledRule {
@@ -21,6 +22,7 @@ fn forbidden_trailer_rule() -> CompiledRule {
             trailer: "AI-generated-by".to_string(),
             message: "Do not add AI-generated commit trailers".to_string(),
         },
+        origin: RuleOrigin::Compiled,
     }
 }
 
@@ -37,6 +39,7 @@ fn forbidden_force_push_rule() -> CompiledRule {
         predicate: RulePredicate::GitPushForceForbidden {
             message: "Do not force push".to_string(),
         },
+        origin: RuleOrigin::Compiled,
     }
 }
 
@@ -0,0 +1,149 @@
+//! Minimal gitignore-style path globs for file-path rule predicates.
+//!
+//! `*` and `?` never cross `/`, `**` spans any number of segments, a trailing
+//! `/` means "everything under this directory", a leading `/` anchors at the
+//! project root, and a pattern without an inner `/` matches at any depth.
+//! Character classes are intentionally unsupported so patterns stay auditable.
+
+pub(crate) fn validate_glob(glob: &str) -> Result<(), String> {
+    let trimmed = glob.trim();
+    if trimmed.is_empty() {
+        return Err("empty path glob".to_string());
+    }
+    if trimmed.chars().any(|ch| ch.is_control()) {
+        return Err(format!("path glob {trimmed:?} contains control characters"));
+    }
+    if trimmed.contains(['[', ']', '{', '}']) {
+        return Err(format!(
+            "path glob {trimmed:?} uses unsupported character classes or alternation"
+        ));
+    }
+    if compile(trimmed).iter().any(|segment| {
+        (segment.contains("**") && *segment != "**") || *segment == "." || *segment == ".."
+    }) {
+        return Err(format!(
+            "path glob {trimmed:?} must use ** as a whole segment without . or .."
+        ));
+    }
+    Ok(())
+}
+
+/// Match a project-relative (or absolute, outside the project) path.
+pub(crate) fn glob_matches(glob: &str, path: &str) -> bool {
+    let pattern = compile(glob.trim());
+    let normalized = path.replace('\\', "/");
+    let segments = normalized
+        .trim_start_matches("./")
+        .split('/')
+        .filter(|segment| !segment.is_empty() && *segment != ".")
+        .collect::<Vec<_>>();
+    !segments.is_empty() && match_segments(&pattern, &segments)
+}
+
+/// Match one name (e.g. a tool name) against `*` / `?` wildcards.
+pub(crate) fn wildcard_matches(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let value = value.chars().collect::<Vec<_>>();
+    let (mut p, mut v) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while v < value.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, v));
+                p += 1;
+            }
+            Some('?') => {
+                p += 1;
+                v += 1;
+            }
+            Some(ch) if *ch == value[v] => {
+                p += 1;
+                v += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    v = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|ch| *ch == '*')
+}
+
+fn compile(glob: &str) -> Vec<&str> {
+    let glob = glob.trim_start_matches("./");
+    let anchored = glob.starts_with('/');
+    let directory = glob.ends_with('/');
+    let body = glob.trim_matches('/');
+    let mut segments = Vec::new();
+    if !anchored && !body.contains('/') {
+        segments.push("**");
+    }
+    segments.extend(body.split('/').filter(|segment| !segment.is_empty()));
+    if directory {
+        segments.push("**");
+    }
+    segments
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| match_segments(rest, &path[skip..])),
+        Some((segment, rest)) => path.split_first().is_some_and(|(head, tail)| {
+            wildcard_matches(segment, head) && match_segments(rest, tail)
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directory_and_basename_globs_follow_gitignore_shape() {
+        assert!(glob_matches(
+            "src/migrations/",
+            "src/migrations/0001_init.sql"
+        ));
+        assert!(glob_matches("src/migrations/**", "src/migrations/a/b.rs"));
+        assert!(!glob_matches("src/migrations/", "src/migrations.rs"));
+        assert!(glob_matches(".env", ".env"));
+        assert!(glob_matches(".env", "services/api/.env"));
+        assert!(!glob_matches(".env", ".env.example"));
+        assert!(glob_matches("*.lock", "Cargo.lock"));
+        assert!(!glob_matches("/Cargo.lock", "crates/a/Cargo.lock"));
+        assert!(glob_matches("src/*.rs", "src/main.rs"));
+        assert!(!glob_matches("src/*.rs", "src/bin/main.rs"));
+        assert!(glob_matches("src/**/*.rs", "src/main.rs"));
+        assert!(glob_matches(".env", "/home/me/other/.env"));
+    }
+
+    #[test]
+    fn wildcard_names_cover_prefix_and_single_character() {
+        assert!(wildcard_matches(
+            "mcp__github__*",
+            "mcp__github__create_issue"
+        ));
+        assert!(wildcard_matches("Web?etch", "WebFetch"));
+        assert!(!wildcard_matches("Write", "WriteFile"));
+    }
+
+    #[test]
+    fn validation_rejects_unsupported_syntax() {
+        assert!(validate_glob("src/**").is_ok());
+        for glob in [
+            "",
+            "  ",
+            "src/[ab].rs",
+            "src/{a,b}",
+            "src/a**b",
+            "../secrets",
+        ] {
+            assert!(validate_glob(glob).is_err(), "must reject {glob:?}");
+        }
+    }
+}
@@ -6,10 +6,14 @@ use serde_json::{json, Value};
 use sha2::{Digest, Sha256};
 
 use super::{
-    artifact_path_for_project, evaluate_artifact_file_with_codes, EvaluationDiagnosticCode,
-    EvaluationInput, EvaluationVerdict, RuleMatch,
+    evaluate_project_rules_with_codes, EvaluationDiagnosticCode, EvaluationVerdict, RuleMatch,
+    RuleOrigin, ToolUseInput,
 };
 
+/// Claude Code tools whose input writes a file; only these feed the
+/// file-path and content predicates.
+const FILE_WRITE_TOOLS: &[&str] = &["Edit", "MultiEdit", "Write", "NotebookEdit"];
+
 #[derive(Debug, Deserialize)]
 struct PreToolUsePayload {
     session_id: Option<String>,
@@ -31,6 +35,8 @@ pub(crate) struct DetailedRuleHookEvaluation {
     pub evaluation: RuleHookEvaluation,
     pub project: Option<String>,
     pub diagnostic_codes: Vec<EvaluationDiagnosticCode>,
+    /// Broken rules source skipped while the verdict still applied.
+    pub skipped_source: Option<(EvaluationDiagnosticCode, String)>,
 }
 
 pub fn session_id_hint(raw: &str) -> Option<String> {
@@ -82,6 +88,7 @@ pub(crate) fn evaluate_pre_tool_use_with_diagnostics(
             },
             project: None,
             diagnostic_codes: Vec::new(),
+            skipped_source: None,
         });
     }
 
@@ -93,26 +100,16 @@ pub(crate) fn evaluate_pre_tool_use_with_diagnostics(
             payload.hook_event_name
         );
     }
-    if payload.tool_name != "Bash" {
-        bail!(
-            "rules eval expected tool_name=Bash, got '{}'",
-            payload.tool_name
-        );
+    if payload.tool_name.trim().is_empty() {
+        bail!("rules eval expected a non-empty tool_name");
     }
-    let command = payload
-        .tool_input
-        .get("command")
-        .and_then(Value::as_str)
-        .filter(|command| !command.trim().is_empty())
-        .context("Claude PreToolUse Bash input is missing tool_input.command")?;
     let project = crate::db::project_from_cwd(&payload.cwd);
-    let coded_outcome = evaluate_artifact_file_with_codes(
-        artifact_path_for_project(data_dir, &project),
-        &EvaluationInput {
-            command: command.to_string(),
-        },
-    );
+    let input = tool_use_input(&payload, &project)?;
+    let coded_outcome = evaluate_project_rules_with_codes(data_dir, &project, &input);
     let diagnostic_codes = coded_outcome.diagnostic_codes;
+    let skipped_source = coded_outcome
+        .skipped_source
+        .map(|(code, message)| (code, sanitize_diagnostic(&message)));
     let outcome = coded_outcome.outcome;
     let diagnostics = outcome
         .diagnostics
@@ -128,6 +125,7 @@ pub(crate) fn evaluate_pre_tool_use_with_diagnostics(
             },
             project: Some(project),
             diagnostic_codes,
+            skipped_source,
         });
     }
 
@@ -139,14 +137,181 @@ pub(crate) fn evaluate_pre_tool_use_with_diagnostics(
         },
         project: Some(project),
         diagnostic_codes,
+        skipped_source,
     })
 }
 
+/// File a PreToolUse call is about to read or write, for file-scoped memory
+/// injection. Paths are relative to the project root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PreToolUseFileTouch {
+    pub session_id: String,
+    pub cwd: String,
+    pub project: String,
+    pub file_path: String,
+}
+
+/// `None` unless `raw` is a PreToolUse call of one of `tools` with a session
+/// and a file target inside the project.
+pub(crate) fn pre_tool_use_file_touch(raw: &str, tools: &[&str]) -> Option<PreToolUseFileTouch> {
+    let payload: PreToolUsePayload = serde_json::from_str(raw).ok()?;
+    if payload.hook_event_name != "PreToolUse" || !tools.contains(&payload.tool_name.as_str()) {
+        return None;
+    }
+    let session_id = payload
+        .session_id
+        .filter(|session_id| !session_id.trim().is_empty())?;
+    let file_path = ["file_path", "notebook_path"]
+        .iter()
+        .find_map(|key| payload.tool_input.get(*key).and_then(Value::as_str))
+        .filter(|path| !path.trim().is_empty())?;
+    let project = crate::db::project_from_cwd(&payload.cwd);
+    let file_path = project_relative_path(&project, &payload.cwd, file_path);
+    if Path::new(&file_path).is_absolute() {
+        return None;
+    }
+    Some(PreToolUseFileTouch {
+        session_id,
+        cwd: payload.cwd,
+        project,
+        file_path,
+    })
+}
+
+/// Fold context from `lookup` into a rule verdict. A verdict carrying a
+/// `permissionDecision` (deny/ask) is returned as-is without calling
+/// `lookup`, so nothing is looked up or audited for context the host would
+/// never see.
+pub(crate) fn with_additional_context(
+    output: Option<Value>,
+    lookup: impl FnOnce() -> Option<String>,
+) -> Option<Value> {
+    let decides_permission = output
+        .as_ref()
+        .and_then(|output| output.get("hookSpecificOutput"))
+        .is_some_and(|specific| specific.get("permissionDecision").is_some());
+    if decides_permission {
+        return output;
+    }
+    match lookup() {
+        Some(context) => merge_additional_context(output, &context),
+        None => output,
+    }
+}
+
+/// Fold extra context into a rule verdict. A deny verdict is returned as-is:
+/// the tool will not run, so context about its file is moot.
+pub(crate) fn merge_additional_context(output: Option<Value>, context: &str) -> Option<Value> {
+    let Some(mut output) = output else {
+        return Some(json!({
+            "hookSpecificOutput": {
+                "hookEventName": "PreToolUse",
+                "additionalContext": context,
+            }
+        }));
+    };
+    let Some(specific) = output
+        .get_mut("hookSpecificOutput")
+        .and_then(Value::as_object_mut)
+    else {
+        return Some(output);
+    };
+    if specific.contains_key("permissionDecision") {
+        return Some(output);
+    }
+    let merged = match specific.get("additionalContext").and_then(Value::as_str) {
+        Some(existing) if !existing.is_empty() => format!("{existing}\n\n{context}"),
+        _ => context.to_string(),
+    };
+    specific.insert("additionalContext".to_string(), Value::String(merged));
+    Some(output)
+}
+
+fn tool_use_input(payload: &PreToolUsePayload, project: &str) -> Result<ToolUseInput> {
+    let mut input = ToolUseInput {
+        tool_name: payload.tool_name.clone(),
+        ..ToolUseInput::default()
+    };
+    if payload.tool_name == "Bash" {
+        let command = payload
+            .tool_input
+            .get("command")
+            .and_then(Value::as_str)
+            .filter(|command| !command.trim().is_empty())
+            .context("Claude PreToolUse Bash input is missing tool_input.command")?;
+        input.command = Some(command.to_string());
+    } else if FILE_WRITE_TOOLS.contains(&payload.tool_name.as_str()) {
+        let tool_input = &payload.tool_input;
+        let file_path = ["file_path", "notebook_path"]
+            .iter()
+            .find_map(|key| tool_input.get(*key).and_then(Value::as_str))
+            .filter(|path| !path.trim().is_empty())
+            .with_context(|| {
+                format!(
+                    "Claude PreToolUse {} input is missing tool_input.file_path",
+                    payload.tool_name
+                )
+            })?;
+        input.file_path = Some(project_relative_path(project, &payload.cwd, file_path));
+        input.content = written_text(tool_input);
+    }
+    Ok(input)
+}
+
+/// Text a file-writing tool is about to write: Write `content`, Edit
+/// `new_string`, every MultiEdit `new_string`, or NotebookEdit `new_source`.
+fn written_text(tool_input: &Value) -> Option<String> {
+    let mut parts = ["content", "new_string", "new_source"]
+        .iter()
+        .filter_map(|key| tool_input.get(*key).and_then(Value::as_str))
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    if let Some(edits) = tool_input.get("edits").and_then(Value::as_array) {
+        parts.extend(
+            edits
+                .iter()
+                .filter_map(|edit| edit.get("new_string").and_then(Value::as_str))
+                .map(str::to_string),
+        );
+    }
+    (!parts.is_empty()).then(|| parts.join("\n"))
+}
+
+/// Express `file_path` relative to the canonical project root so globs like
+/// `src/migrations/` work whether the host sent an absolute or cwd-relative
+/// path. Paths outside the project stay absolute.
+fn project_relative_path(project: &str, cwd: &str, file_path: &str) -> String {
+    let raw = Path::new(file_path);
+    let absolute = if raw.is_absolute() {
+        raw.to_path_buf()
+    } else {
+        Path::new(cwd).join(raw)
+    };
+    let root = Path::new(project);
+    if let Ok(relative) = absolute.strip_prefix(root) {
+        return relative.to_string_lossy().replace('\\', "/");
+    }
+    // The target may not exist yet (Write), so canonicalize its nearest
+    // existing ancestor to resolve symlinked cwd prefixes.
+    let mut existing = absolute.as_path();
+    while let Some(parent) = existing.parent() {
+        if let Ok(canonical) = existing.canonicalize() {
+            let rest = absolute.strip_prefix(existing).unwrap_or(Path::new(""));
+            if let Ok(relative) = canonical.join(rest).strip_prefix(root) {
+                return relative.to_string_lossy().replace('\\', "/");
+            }
+            break;
+        }
+        existing = parent;
+    }
+    absolute.to_string_lossy().replace('\\', "/")
+}
+
 fn render_hook_output(verdict: EvaluationVerdict, matches: &[RuleMatch]) -> Option<Value> {
     match verdict {
         EvaluationVerdict::Allow => None,
         EvaluationVerdict::Warn => {
-            let message = static_match_message("warning", matches);
+            let message = match_message("warning", matches);
             Some(json!({
                 "systemMessage": message,
                 "hookSpecificOutput": {
@@ -156,7 +321,7 @@ fn render_hook_output(verdict: EvaluationVerdict, matches: &[RuleMatch]) -> Opti
             }))
         }
         EvaluationVerdict::Block => {
-            let message = static_match_message("blocked", matches);
+            let message = match_message("blocked", matches);
             Some(json!({
                 "systemMessage": message,
                 "hookSpecificOutput": {
@@ -169,21 +334,47 @@ fn render_hook_output(verdict: EvaluationVerdict, matches: &[RuleMatch]) -> Opti
     }
 }
 
-fn static_match_message(disposition: &str, matches: &[RuleMatch]) -> String {
+/// Compiled rules cite only their source memories so preference text never
+/// leaks into host output; authored rules carry the message the team wrote.
+fn match_message(disposition: &str, matches: &[RuleMatch]) -> String {
     let mut source_ids = matches
         .iter()
+        .filter(|matched| matched.origin == RuleOrigin::Compiled)
         .map(|matched| matched.source_memory_id)
         .collect::<Vec<_>>();
     source_ids.sort_unstable();
     source_ids.dedup();
-    let sources = source_ids
+    let authored = matches
         .iter()
-        .map(ToString::to_string)
-        .collect::<Vec<_>>()
-        .join(", ");
-    format!(
-        "remem compiled preference rule {disposition} for source memory(s) {sources}; inspect with `remem rules list`."
-    )
+        .filter(|matched| matched.origin == RuleOrigin::Authored)
+        .map(|matched| {
+            format!(
+                "{} ({})",
+                sanitize_diagnostic(&matched.message),
+                matched.rule_id
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut parts = Vec::new();
+    if !source_ids.is_empty() {
+        let sources = source_ids
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        parts.push(format!(
+            "remem compiled preference rule {disposition} for source memory(s) {sources}; inspect with `remem rules list`."
+        ));
+    }
+    if !authored.is_empty() {
+        parts.push(format!(
+            "remem repository rule {disposition}: {}; see {}.",
+            authored.join("; "),
+            super::AUTHORED_RULES_FILE
+        ));
+    }
+    parts.join(" ")
 }
 
 fn sanitize_diagnostic(message: &str) -> String {
@@ -3,7 +3,10 @@ use serde_json::json;
 
 use super::*;
 use crate::rules::test_support::{package_manager_rule, test_dir};
-use crate::rules::{write_artifact_atomic, CompiledRulesArtifact, RuleAction, RuleOverrideState};
+use crate::rules::{
+    artifact_path_for_project, write_artifact_atomic, CompiledRulesArtifact, RuleAction,
+    RuleOverrideState,
+};
 
 fn hook_input(project: &Path, session_id: &str, command: &str) -> String {
     json!({
@@ -75,6 +78,172 @@ fn claude_block_denies_before_command_execution() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn file_write_under_protected_glob_is_denied_and_reads_are_allowed() -> Result<()> {
+    let data_dir = test_dir("hook-path-data");
+    let project = test_dir("hook-path-project");
+    std::fs::create_dir_all(project.join("src/migrations"))?;
+    let project_key = crate::db::project_from_cwd(&project.to_string_lossy());
+    let mut rule = package_manager_rule(RuleAction::Block);
+    rule.predicate = crate::rules::RulePredicate::FilePathGlob {
+        glob: "src/migrations/".to_string(),
+        message: "Generated migrations are read-only".to_string(),
+    };
+    write_artifact_atomic(
+        artifact_path_for_project(&data_dir, &project_key),
+        &CompiledRulesArtifact::new(123, vec![rule]),
+    )?;
+    let tool_call = |tool_name: &str, tool_input: serde_json::Value| {
+        json!({
+            "session_id": "session-path",
+            "cwd": project,
+            "hook_event_name": "PreToolUse",
+            "tool_name": tool_name,
+            "tool_input": tool_input
+        })
+        .to_string()
+    };
+
+    for raw in [
+        tool_call(
+            "Write",
+            json!({"file_path": project.join("src/migrations/0002.sql"), "content": "x"}),
+        ),
+        tool_call(
+            "Edit",
+            json!({"file_path": "src/migrations/0001.sql", "old_string": "a", "new_string": "b"}),
+        ),
+    ] {
+        let evaluated = evaluate_pre_tool_use(&raw, Some("claude-code"), &data_dir, true)?;
+        let output = evaluated.output.context("block output")?;
+        assert_eq!(output["hookSpecificOutput"]["permissionDecision"], "deny");
+    }
+
+    let read = evaluate_pre_tool_use(
+        &tool_call(
+            "Read",
+            json!({"file_path": project.join("src/migrations/0001.sql")}),
+        ),
+        Some("claude-code"),
+        &data_dir,
+        true,
+    )?;
+    assert!(read.output.is_none());
+    assert!(read.diagnostics.is_empty());
+    Ok(())
+}
+
+#[test]
+fn authored_rule_shadows_compiled_rule_and_cites_its_message() -> Result<()> {
+    let data_dir = test_dir("hook-authored-data");
+    let project = test_dir("hook-authored-project");
+    std::fs::create_dir_all(project.join(".remem"))?;
+    std::fs::write(
+        project.join(".remem/rules.toml"),
+        r#"
+[[rule]]
+id = "npm"
+action = "block"
+kind = "command_regex"
+pattern = '(^|[ \t\r\n])npm[ \t\r\n]+(install|i|add)([ \t\r\n;&|)<>]|$)'
+message = "This repo uses pnpm"
+"#,
+    )?;
+    write_rule(&data_dir, &project, RuleAction::Warn)?;
+
+    let evaluated = evaluate_pre_tool_use(
+        &hook_input(&project, "session-authored", "npm install"),
+        Some("claude-code"),
+        &data_dir,
+        true,
+    )?;
+    let output = evaluated.output.context("block output")?;
+    let message = output["systemMessage"].as_str().unwrap_or_default();
+
+    assert_eq!(output["hookSpecificOutput"]["permissionDecision"], "deny");
+    assert!(
+        message.contains("This repo uses pnpm (repo-npm)") && message.contains(".remem/rules.toml"),
+        "{message}"
+    );
+    assert!(!message.contains("source memory"), "{message}");
+
+    std::fs::remove_file(artifact_path_for_project(
+        &data_dir,
+        &crate::db::project_from_cwd(&project.to_string_lossy()),
+    ))?;
+    let authored_only = evaluate_pre_tool_use(
+        &hook_input(&project, "session-authored", "npm install"),
+        Some("claude-code"),
+        &data_dir,
+        true,
+    )?;
+    assert!(authored_only.diagnostics.is_empty());
+    assert!(authored_only.output.is_some());
+    Ok(())
+}
+
+#[test]
+fn one_broken_rules_source_keeps_the_other_enforced() -> Result<()> {
+    let data_dir = test_dir("hook-broken-source-data");
+    let project = test_dir("hook-broken-source-project");
+    std::fs::create_dir_all(project.join(".remem"))?;
+    std::fs::write(project.join(".remem/rules.toml"), "[[rule]]\n")?;
+    write_rule(&data_dir, &project, RuleAction::Block)?;
+
+    let evaluated = evaluate_pre_tool_use_with_diagnostics(
+        &hook_input(&project, "session-broken-authored", "npm install"),
+        Some("claude-code"),
+        &data_dir,
+        true,
+    )?;
+    let output = evaluated
+        .evaluation
+        .output
+        .context("compiled block output")?;
+    assert_eq!(output["hookSpecificOutput"]["permissionDecision"], "deny");
+    assert!(evaluated.evaluation.diagnostics.is_empty());
+    assert!(matches!(
+        &evaluated.skipped_source,
+        Some((crate::rules::EvaluationDiagnosticCode::AuthoredRules, message))
+            if message.contains("missing 'id'")
+    ));
+
+    std::fs::write(
+        project.join(".remem/rules.toml"),
+        r#"
+[[rule]]
+id = "npm"
+action = "block"
+kind = "command_regex"
+pattern = '(^|[ \t\r\n])npm[ \t\r\n]+(install|i|add)([ \t\r\n;&|)<>]|$)'
+message = "This repo uses pnpm"
+"#,
+    )?;
+    std::fs::write(
+        artifact_path_for_project(
+            &data_dir,
+            &crate::db::project_from_cwd(&project.to_string_lossy()),
+        ),
+        "{not-json",
+    )?;
+    let evaluated = evaluate_pre_tool_use_with_diagnostics(
+        &hook_input(&project, "session-broken-artifact", "npm install"),
+        Some("claude-code"),
+        &data_dir,
+        true,
+    )?;
+    let output = evaluated
+        .evaluation
+        .output
+        .context("authored block output")?;
+    assert_eq!(output["hookSpecificOutput"]["permissionDecision"], "deny");
+    assert!(matches!(
+        &evaluated.skipped_source,
+        Some((crate::rules::EvaluationDiagnosticCode::ArtifactParse, _))
+    ));
+    Ok(())
+}
+
 #[test]
 fn missing_artifact_fails_open_with_diagnostic() -> Result<()> {
     let data_dir = test_dir("hook-missing-data");
@@ -163,12 +332,12 @@ fn invalid_hook_input_can_record_a_project_scoped_closed_code() -> Result<()> {
         "session_id": "session-invalid-input",
         "cwd": project,
         "hook_event_name": "PreToolUse",
-        "tool_name": "Read",
+        "tool_name": "Bash",
         "tool_input": {}
     })
     .to_string();
     let error = evaluate_pre_tool_use(&raw, Some("claude-code"), &data_dir, true)
-        .expect_err("non-Bash hook input should fail open through the CLI");
+        .expect_err("Bash hook input without a command should fail open through the CLI");
     let project = project_hint(&raw).context("project hint")?;
 
     log_evaluation_error_once_with_diagnostic(
@@ -316,3 +485,74 @@ fn evaluation_error_without_session_is_not_globally_suppressed() {
         .join(".evaluation-error-sessions")
         .exists());
 }
+
+#[test]
+fn file_touch_targets_project_relative_reads_and_merges_with_warnings() {
+    let raw = json!({
+        "session_id": "s-1",
+        "cwd": "/tmp/remem-touch",
+        "hook_event_name": "PreToolUse",
+        "tool_name": "Read",
+        "tool_input": {"file_path": "/tmp/remem-touch/src/lib.rs"}
+    })
+    .to_string();
+    let touch = pre_tool_use_file_touch(&raw, &["Read"]).expect("file touch");
+    assert_eq!(touch.file_path, "src/lib.rs");
+    assert_eq!(pre_tool_use_file_touch(&raw, &["Edit"]), None);
+    let outside = raw.replace("/tmp/remem-touch/src/lib.rs", "/etc/hosts");
+    assert_eq!(pre_tool_use_file_touch(&outside, &["Read"]), None);
+
+    let fresh = merge_additional_context(None, "files").expect("output");
+    assert_eq!(
+        fresh["hookSpecificOutput"]["additionalContext"],
+        json!("files")
+    );
+    let warn = json!({"hookSpecificOutput": {
+        "hookEventName": "PreToolUse",
+        "additionalContext": "rule warning"
+    }});
+    let merged = merge_additional_context(Some(warn), "files").expect("output");
+    assert_eq!(
+        merged["hookSpecificOutput"]["additionalContext"],
+        json!("rule warning\n\nfiles")
+    );
+    let deny = json!({"hookSpecificOutput": {
+        "hookEventName": "PreToolUse",
+        "permissionDecision": "deny"
+    }});
+    assert_eq!(
+        merge_additional_context(Some(deny.clone()), "files"),
+        Some(deny)
+    );
+}
+
+#[test]
+fn deny_verdict_skips_file_touch_lookup() {
+    let deny = json!({"hookSpecificOutput": {
+        "hookEventName": "PreToolUse",
+        "permissionDecision": "deny",
+        "permissionDecisionReason": "blocked by rule"
+    }});
+    let mut looked_up = false;
+
+    let output = with_additional_context(Some(deny.clone()), || {
+        looked_up = true;
+        Some("files".to_string())
+    });
+
+    assert!(
+        !looked_up,
+        "deny verdict must not record file-touch audit rows"
+    );
+    assert_eq!(output, Some(deny));
+    let warn = json!({"hookSpecificOutput": {
+        "hookEventName": "PreToolUse",
+        "additionalContext": "rule warning"
+    }});
+    let merged = with_additional_context(Some(warn), || Some("files".to_string())).expect("output");
+    assert_eq!(
+        merged["hookSpecificOutput"]["additionalContext"],
+        json!("rule warning\n\nfiles")
+    );
+    assert_eq!(with_additional_context(None, || None), None);
+}
@@ -3,19 +3,26 @@ use rusqlite::{params, Connection};
 use std::path::Path;
 
 use super::{
-    artifact_path_for_project, load_artifact_fail_open, ArtifactLoad, CompiledRule, RuleAction,
+    artifact_path_for_project, load_artifact_fail_open, load_project_rules_with_shadowed,
+    ArtifactLoad, CompiledRule, RuleAction, ShadowedRule, AUTHORED_RULES_FILE,
+    AUTHORED_RULE_ID_PREFIX,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ProjectRules {
     pub project: String,
     pub compiled_at_epoch: i64,
+    /// Effective rules: authored rules first, then compiled rules that no
+    /// authored rule shadows.
     pub rules: Vec<CompiledRule>,
+    pub shadowed: Vec<ShadowedRule>,
+    /// Load error of a broken source whose rules are missing from `rules`.
+    pub skipped_source: Option<String>,
 }
 
 pub fn list_project_rules(data_dir: &Path, project: &str) -> Result<ProjectRules> {
-    let path = artifact_path_for_project(data_dir, project);
-    let artifact = match load_artifact_fail_open(&path) {
+    let project_rules = load_project_rules_with_shadowed(data_dir, project);
+    let artifact = match project_rules.load {
         ArtifactLoad::Loaded(artifact) => artifact,
         ArtifactLoad::FailOpen { message, .. } => bail!(message),
     };
@@ -23,6 +30,8 @@ pub fn list_project_rules(data_dir: &Path, project: &str) -> Result<ProjectRules
         project: project.to_string(),
         compiled_at_epoch: artifact.compiled_at_epoch,
         rules: artifact.rules,
+        shadowed: project_rules.shadowed,
+        skipped_source: project_rules.skipped.map(|(_, message)| message),
     })
 }
 
@@ -76,8 +85,16 @@ fn update_rule_override(
     rule_id: &str,
     update: RuleOverrideUpdate,
 ) -> Result<()> {
-    let project_rules = list_project_rules(data_dir, project)?;
-    let artifact_rule = project_rules
+    if rule_id.starts_with(AUTHORED_RULE_ID_PREFIX) {
+        bail!("rule '{rule_id}' is hand-authored in {AUTHORED_RULES_FILE}; edit that file to change it");
+    }
+    // Overrides target compiled rules, including ones an authored rule
+    // currently shadows, so read the compiled artifact on its own.
+    let artifact = match load_artifact_fail_open(artifact_path_for_project(data_dir, project)) {
+        ArtifactLoad::Loaded(artifact) => artifact,
+        ArtifactLoad::FailOpen { message, .. } => bail!(message),
+    };
+    let artifact_rule = artifact
         .rules
         .iter()
         .find(|rule| rule.rule_id == rule_id)
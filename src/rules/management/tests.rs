@@ -365,3 +365,22 @@ fn enqueue_failure_rolls_back_override() -> Result<()> {
     assert_eq!(state, (0, 0));
     Ok(())
 }
+
+#[test]
+fn authored_rules_cannot_be_overridden_from_the_cli() -> Result<()> {
+    let scoped = ScopedTestDataDir::new("rules-cli-authored");
+    let conn = db::open_db()?;
+
+    let error = set_rule_action(
+        &conn,
+        &scoped.path,
+        PROJECT,
+        "repo-no-force",
+        RuleAction::Warn,
+        true,
+    )
+    .expect_err("authored rules are changed by editing the repository file");
+
+    assert!(error.to_string().contains(".remem/rules.toml"), "{error:#}");
+    Ok(())
+}
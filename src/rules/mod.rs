@@ -1,15 +1,19 @@
 mod artifact;
+mod authored;
 mod compiler;
 mod diagnostics;
 mod evaluator;
+mod glob;
 mod hook;
 mod management;
 mod store;
 
 pub use artifact::{
-    CompiledRule, CompiledRulesArtifact, RuleAction, RuleOverrideState, RulePredicate,
+    CompiledRule, CompiledRulesArtifact, RuleAction, RuleOrigin, RuleOverrideState, RulePredicate,
     ARTIFACT_VERSION,
 };
+pub(crate) use authored::AUTHORED_RULE_ID_PREFIX;
+pub use authored::{authored_rules_path, load_authored_rules, ShadowedRule, AUTHORED_RULES_FILE};
 pub use compiler::{
     classify_preference_predicate, classify_preference_predicates, compile_project_rules,
     run_compile_rules_job, run_compile_rules_sweep, CompileOutcome, CompileSweepOutcome,
@@ -19,20 +23,22 @@ pub(crate) use diagnostics::{
     evaluation_marker_dir, load_evaluation_error, upsert_evaluation_error_record,
 };
 pub use evaluator::{
-    evaluate_artifact, evaluate_artifact_file, EvaluationDiagnostic, EvaluationInput,
-    EvaluationOutcome, EvaluationVerdict, RuleMatch,
+    evaluate_artifact, evaluate_artifact_file, evaluate_tool_use, EvaluationDiagnostic,
+    EvaluationInput, EvaluationOutcome, EvaluationVerdict, RuleMatch, ToolUseInput,
 };
-pub(crate) use evaluator::{evaluate_artifact_file_with_codes, EvaluationDiagnosticCode};
+pub(crate) use evaluator::{evaluate_project_rules_with_codes, EvaluationDiagnosticCode};
 pub use hook::{
     evaluate_pre_tool_use, log_evaluation_error_once, session_id_hint, RuleHookEvaluation,
 };
 pub(crate) use hook::{
-    evaluate_pre_tool_use_with_diagnostics, log_evaluation_error_once_with_diagnostic, project_hint,
+    evaluate_pre_tool_use_with_diagnostics, log_evaluation_error_once_with_diagnostic,
+    pre_tool_use_file_touch, project_hint, with_additional_context,
 };
 pub use management::{list_project_rules, set_rule_action, set_rule_disabled, ProjectRules};
+pub(crate) use store::load_project_rules_with_shadowed;
 pub use store::{
-    artifact_path_for_project, load_artifact_fail_open, write_artifact_atomic, ArtifactLoad,
-    ArtifactLoadErrorKind,
+    artifact_path_for_project, load_artifact_fail_open, load_project_rules_fail_open,
+    write_artifact_atomic, ArtifactLoad, ArtifactLoadErrorKind,
 };
 
 #[cfg(test)]
@@ -40,7 +46,8 @@ pub(crate) mod test_support {
     use std::path::PathBuf;
 
     use super::{
-        CompiledRule, CompiledRulesArtifact, RuleAction, RuleOverrideState, RulePredicate,
+        CompiledRule, CompiledRulesArtifact, RuleAction, RuleOrigin, RuleOverrideState,
+        RulePredicate,
     };
 
     pub(crate) fn package_manager_rule(action: RuleAction) -> CompiledRule {
@@ -58,6 +65,7 @@ pub(crate) mod test_support {
                     .to_string(),
                 message: "Command violates a compiled package-manager preference".to_string(),
             },
+            origin: RuleOrigin::Compiled,
         }
     }
 
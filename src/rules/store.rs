@@ -5,6 +5,9 @@ use anyhow::Result;
 use sha2::{Digest, Sha256};
 
 use crate::rules::artifact::CompiledRulesArtifact;
+use crate::rules::authored::{
+    authored_rules_path, load_authored_rules, merge_authored_rules, ShadowedRule,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ArtifactLoad {
@@ -21,6 +24,7 @@ pub enum ArtifactLoadErrorKind {
     Read,
     Parse,
     Validate,
+    AuthoredRules,
 }
 
 pub fn artifact_path_for_project(data_dir: impl AsRef<Path>, project: &str) -> PathBuf {
@@ -75,7 +79,7 @@ pub fn load_artifact_fail_open(path: impl AsRef<Path>) -> ArtifactLoad {
     };
 
     match artifact.validate() {
-        Ok(()) => ArtifactLoad::Loaded(artifact),
+        Ok(()) => ArtifactLoad::Loaded(artifact.migrate()),
         Err(err) => ArtifactLoad::FailOpen {
             kind: ArtifactLoadErrorKind::Validate,
             message: format!(
@@ -86,6 +90,76 @@ pub fn load_artifact_fail_open(path: impl AsRef<Path>) -> ArtifactLoad {
     }
 }
 
+/// Project rules plus the source that failed to load alongside them, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ProjectRulesLoad {
+    pub load: ArtifactLoad,
+    pub shadowed: Vec<ShadowedRule>,
+    /// A broken compiled artifact or authored file whose rules were skipped
+    /// while the other source's rules stay in `load`.
+    pub skipped: Option<(ArtifactLoadErrorKind, String)>,
+}
+
+/// Load the compiled artifact merged with the project's `.remem/rules.toml`.
+/// Authored rules still apply when nothing has been compiled yet. When one
+/// source is invalid, the other is still enforced; only when neither yields
+/// rules does the load fail open.
+pub fn load_project_rules_fail_open(data_dir: impl AsRef<Path>, project: &str) -> ArtifactLoad {
+    load_project_rules_with_shadowed(data_dir.as_ref(), project).load
+}
+
+pub(crate) fn load_project_rules_with_shadowed(data_dir: &Path, project: &str) -> ProjectRulesLoad {
+    let compiled = load_artifact_fail_open(artifact_path_for_project(data_dir, project));
+    let authored = match load_authored_rules(authored_rules_path(project)) {
+        Ok(Some(authored)) => authored,
+        Ok(None) => {
+            return ProjectRulesLoad {
+                load: compiled,
+                shadowed: Vec::new(),
+                skipped: None,
+            }
+        }
+        Err(err) => {
+            let message = format!("{err:#}");
+            return match compiled {
+                ArtifactLoad::FailOpen {
+                    kind: ArtifactLoadErrorKind::Missing,
+                    ..
+                } => ProjectRulesLoad {
+                    load: ArtifactLoad::FailOpen {
+                        kind: ArtifactLoadErrorKind::AuthoredRules,
+                        message,
+                    },
+                    shadowed: Vec::new(),
+                    skipped: None,
+                },
+                compiled => ProjectRulesLoad {
+                    load: compiled,
+                    shadowed: Vec::new(),
+                    skipped: Some((ArtifactLoadErrorKind::AuthoredRules, message)),
+                },
+            };
+        }
+    };
+    let (compiled, skipped) = match compiled {
+        ArtifactLoad::Loaded(artifact) => (artifact, None),
+        ArtifactLoad::FailOpen {
+            kind: ArtifactLoadErrorKind::Missing,
+            ..
+        } => (CompiledRulesArtifact::new(0, Vec::new()), None),
+        ArtifactLoad::FailOpen { kind, message } => (
+            CompiledRulesArtifact::new(0, Vec::new()),
+            Some((kind, message)),
+        ),
+    };
+    let (merged, shadowed) = merge_authored_rules(compiled, authored);
+    ProjectRulesLoad {
+        load: ArtifactLoad::Loaded(merged),
+        shadowed,
+        skipped,
+    }
+}
+
 fn project_hash(project: &str) -> String {
     let digest = Sha256::digest(project.as_bytes());
     digest.iter().map(|byte| format!("{byte:02x}")).collect()
@@ -214,4 +288,91 @@ mod tests {
         fs::remove_dir_all(dir)?;
         Ok(())
     }
+
+    #[test]
+    fn load_v2_artifact_migrates_to_current_version() -> Result<()> {
+        let dir = test_dir("v2-migration");
+        let path = dir.join("artifact.json");
+        let mut artifact = package_manager_artifact();
+        artifact.version = 2;
+        fs::create_dir_all(&dir)?;
+        fs::write(&path, serde_json::to_vec(&artifact)?)?;
+
+        let ArtifactLoad::Loaded(loaded) = load_artifact_fail_open(&path) else {
+            panic!("v2 artifact should load");
+        };
+
+        assert_eq!(loaded.version, ARTIFACT_VERSION);
+        assert_eq!(loaded.rules, artifact.rules);
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn project_rules_merge_authored_file_with_or_without_compiled_artifact() -> Result<()> {
+        let dir = test_dir("authored-merge");
+        let project = dir.join("project");
+        let data_dir = dir.join("data");
+        fs::create_dir_all(project.join(".remem"))?;
+        fs::write(
+            project.join(".remem/rules.toml"),
+            "[[rule]]\nid = \"no-fetch\"\nkind = \"tool_name_deny\"\ntools = [\"WebFetch\"]\nmessage = \"Offline repo\"\n",
+        )?;
+        let project = project.to_string_lossy().into_owned();
+
+        let ArtifactLoad::Loaded(authored_only) = load_project_rules_fail_open(&data_dir, &project)
+        else {
+            panic!("authored rules should load without a compiled artifact");
+        };
+        assert_eq!(authored_only.rules.len(), 1);
+        assert_eq!(authored_only.rules[0].rule_id, "repo-no-fetch");
+
+        write_artifact_atomic(
+            artifact_path_for_project(&data_dir, &project),
+            &package_manager_artifact(),
+        )?;
+        let ArtifactLoad::Loaded(merged) = load_project_rules_fail_open(&data_dir, &project) else {
+            panic!("merged rules should load");
+        };
+        assert_eq!(merged.compiled_at_epoch, 123);
+        assert_eq!(
+            merged
+                .rules
+                .iter()
+                .map(|rule| rule.rule_id.as_str())
+                .collect::<Vec<_>>(),
+            ["repo-no-fetch", "pref-123-1"]
+        );
+
+        fs::write(Path::new(&project).join(".remem/rules.toml"), "[[rule]]\n")?;
+        let loaded = load_project_rules_with_shadowed(&data_dir, &project);
+        assert_eq!(
+            loaded.load,
+            ArtifactLoad::Loaded(package_manager_artifact()),
+            "a broken authored file must not drop compiled rules"
+        );
+        assert!(
+            matches!(
+                &loaded.skipped,
+                Some((ArtifactLoadErrorKind::AuthoredRules, message))
+                    if message.contains("rules.toml") && message.contains("missing 'id'")
+            ),
+            "{loaded:?}"
+        );
+
+        fs::remove_file(artifact_path_for_project(&data_dir, &project))?;
+        let loaded = load_project_rules_fail_open(&data_dir, &project);
+        assert!(
+            matches!(
+                &loaded,
+                ArtifactLoad::FailOpen {
+                    kind: ArtifactLoadErrorKind::AuthoredRules,
+                    message,
+                } if message.contains("missing 'id'")
+            ),
+            "{loaded:?}"
+        );
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
 }
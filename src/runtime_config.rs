@@ -3,26 +3,40 @@ use std::path::PathBuf;
 use anyhow::{bail, Context, Result};
 use toml_edit::{value, DocumentMut, Item, Table};
 
+mod budget;
 mod config_value;
 mod context;
+mod encryption;
 #[cfg(test)]
 mod migration_tests;
 mod model;
+mod pack;
 mod pricing;
 mod promotion;
+mod redaction;
 mod rules;
+mod search_weights;
 mod user_auto_promote;
+pub use budget::{ai_budget_config, AiBudgetConfig, SpendLimits};
 use config_value::cli_value;
 pub(crate) use context::context_budget_limits;
+pub(crate) use encryption::set_key_command;
+pub use encryption::{encryption_config, EncryptionConfig};
 pub use model::{
     model_status, model_statuses, rollback_model_config, set_model, ModelChange, ModelPreset,
     ModelStatus, MODEL_PRESETS,
 };
+pub use pack::{pack_trust_config, PackTrustConfig};
 pub(crate) use pricing::{
     family_pricing_overlay, global_pricing_override, validate_pricing_config, PricingRates,
 };
 pub use promotion::{summary_gate_mode, SummaryGateMode};
+pub use redaction::{redaction_config, redaction_config_with_problems, RedactionConfig};
 pub use rules::{rule_compilation_config, RuleCompilationConfig};
+pub use search_weights::{
+    project_search_weight_profile, write_search_weight_profile, ProjectSearchWeightProfile,
+    SearchWeightMode,
+};
 pub use user_auto_promote::{
     user_context_auto_promote_config, AutoPromotePolicy, UserContextAutoPromoteConfig,
 };
@@ -32,6 +46,7 @@ pub const CODEX_HOST: &str = "codex-cli";
 /// Canonical Cursor host name (GH-823). The install-side `hosts.cursor`
 /// defaults/normalization and receipt are owned by GH-824.
 pub const CURSOR_HOST: &str = "cursor";
+pub const GEMINI_HOST: &str = "gemini-cli";
 pub const DEFAULT_CODEX_MODEL: &str = "gpt-5.2";
 pub const MEMORY_AI_PROFILE_FIELD: &str = "remem_ai_profile";
 
@@ -50,6 +65,7 @@ pub enum MemoryAiExecutor {
     Http,
     ClaudeCli,
     CodexCli,
+    OpenAiCompatible,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -65,6 +81,7 @@ pub struct ResolvedMemoryAiProfile {
     pub model: Option<String>,
     pub cli_path: Option<String>,
     pub base_url: Option<String>,
+    pub api_key_env: Option<String>,
     pub reasoning_effort: Option<String>,
 }
 
@@ -184,6 +201,7 @@ pub fn normalize_host(raw: &str) -> String {
         "claude" | "claude-code" | "claudecode" => CLAUDE_HOST.to_string(),
         "codex" | "codex-cli" | "codexcli" => CODEX_HOST.to_string(),
         "cursor" => CURSOR_HOST.to_string(),
+        "gemini" | "gemini-cli" | "geminicli" => GEMINI_HOST.to_string(),
         "unknown" => "unknown".to_string(),
         _ => raw.trim().to_string(),
     }
@@ -269,9 +287,12 @@ fn ensure_config_defaults(doc: &mut DocumentMut, hosts: &[&str]) -> Result<()> {
 
     promotion::ensure_defaults(doc)?;
     rules::ensure_defaults(doc)?;
+    pack::ensure_defaults(doc)?;
     user_auto_promote::ensure_defaults(doc)?;
     context::ensure_defaults(doc)?;
     pricing::ensure_defaults(doc)?;
+    budget::ensure_defaults(doc)?;
+    redaction::ensure_defaults(doc)?;
 
     let memory_ai = top_table_mut(doc, "memory_ai")?;
     set_str_if_missing(memory_ai, "default_host", CODEX_HOST);
@@ -351,6 +372,12 @@ fn ensure_host_config(hosts: &mut Table, host: &str) -> Result<()> {
             set_bool_if_missing(table, "context_color", true);
             set_str_if_missing(table, "capture_adapter", CURSOR_HOST);
         }
+        GEMINI_HOST => {
+            set_str_if_missing(table, "memory_profile", "codex");
+            set_str_if_missing(table, "context_gate", "strict");
+            set_bool_if_missing(table, "context_color", true);
+            set_str_if_missing(table, "capture_adapter", GEMINI_HOST);
+        }
         "unknown" => {
             set_str_if_missing(table, "memory_profile", "codex");
             set_str_if_missing(table, "context_gate", "off");
@@ -456,6 +483,7 @@ fn profile_from_doc(doc: &DocumentMut, profile_name: &str) -> Result<ResolvedMem
         model,
         cli_path: optional_str(table, "path"),
         base_url: optional_str(table, "base_url"),
+        api_key_env: optional_str(table, "api_key_env"),
         reasoning_effort: optional_str(table, "reasoning_effort"),
     })
 }
@@ -465,6 +493,7 @@ fn parse_executor(raw: &str) -> Result<MemoryAiExecutor> {
         "http" | "anthropic" | "anthropic-http" => Ok(MemoryAiExecutor::Http),
         "claude" | "cli" | "claude-cli" => Ok(MemoryAiExecutor::ClaudeCli),
         "codex" | "codex-cli" => Ok(MemoryAiExecutor::CodexCli),
+        "openai" | "openai-compatible" | "openai-http" => Ok(MemoryAiExecutor::OpenAiCompatible),
         other => bail!("unknown memory_ai executor: {other}"),
     }
 }
@@ -586,6 +615,37 @@ mod tests {
         let _ = std::fs::remove_file(path);
     }
 
+    #[test]
+    fn openai_compatible_profile_resolves_endpoint_and_key_env() -> Result<()> {
+        let path = temp_config_path("runtime-openai-compatible");
+        with_config_path(&path, || -> Result<()> {
+            init_config()?;
+            set_config_value("memory_ai.profiles.local.executor", "openai-compatible")?;
+            set_config_value("memory_ai.profiles.local.model", "qwen2.5-coder:7b")?;
+            set_config_value(
+                "memory_ai.profiles.local.base_url",
+                "http://127.0.0.1:11434/v1",
+            )?;
+            set_config_value("memory_ai.profiles.local.api_key_env", "LOCAL_LLM_KEY")?;
+            let profile = resolve_memory_ai_profile(MemoryAiSelection {
+                host: None,
+                profile: Some("local"),
+            })?;
+
+            assert_eq!(profile.executor, MemoryAiExecutor::OpenAiCompatible);
+            assert_eq!(profile.executor.as_str(), "openai-compatible");
+            assert_eq!(profile.model.as_deref(), Some("qwen2.5-coder:7b"));
+            assert_eq!(
+                profile.base_url.as_deref(),
+                Some("http://127.0.0.1:11434/v1")
+            );
+            assert_eq!(profile.api_key_env.as_deref(), Some("LOCAL_LLM_KEY"));
+            Ok(())
+        })?;
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
     #[test]
     fn host_and_profile_are_mutually_exclusive() {
         let err = resolve_memory_ai_profile(MemoryAiSelection {
@@ -787,4 +847,20 @@ mod tests {
         std::fs::remove_file(path)?;
         Ok(())
     }
+    #[test]
+    fn gemini_host_aliases_resolve_to_gemini_defaults() -> Result<()> {
+        let path = temp_config_path("runtime-gemini-host");
+        with_config_path(&path, || -> Result<()> {
+            ensure_config_for_hosts(&[GEMINI_HOST])?;
+            let host = resolve_host_runtime_config(Some("gemini"))?;
+
+            assert_eq!(normalize_host("Gemini-CLI"), GEMINI_HOST);
+            assert_eq!(host.host, GEMINI_HOST);
+            assert_eq!(host.capture_adapter, GEMINI_HOST);
+            assert_eq!(host.context_gate.as_deref(), Some("strict"));
+            Ok(())
+        })?;
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
 }
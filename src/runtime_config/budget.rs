@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use toml_edit::{DocumentMut, Item, TableLike};
+
+const DEFAULT_WARN_RATIO: f64 = 0.8;
+const LIMIT_KEYS: &[&str] = &["daily_usd", "monthly_usd"];
+
+/// Spend ceilings for one project or memory AI profile. Either window may be
+/// unset; an unset window never blocks work.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SpendLimits {
+    pub daily_usd: Option<f64>,
+    pub monthly_usd: Option<f64>,
+}
+
+/// `[budgets]`: daily/monthly USD ceilings for background memory AI calls.
+/// Days and months are UTC calendar windows.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AiBudgetConfig {
+    /// Fraction of a budget at which `remem doctor` starts warning.
+    pub warn_ratio: f64,
+    /// Project path -> limits.
+    pub projects: BTreeMap<String, SpendLimits>,
+    /// Memory AI profile name -> limits.
+    pub profiles: BTreeMap<String, SpendLimits>,
+}
+
+impl Default for AiBudgetConfig {
+    fn default() -> Self {
+        Self {
+            warn_ratio: DEFAULT_WARN_RATIO,
+            projects: BTreeMap::new(),
+            profiles: BTreeMap::new(),
+        }
+    }
+}
+
+impl AiBudgetConfig {
+    pub fn is_empty(&self) -> bool {
+        self.projects.is_empty() && self.profiles.is_empty()
+    }
+}
+
+pub fn ai_budget_config() -> Result<AiBudgetConfig> {
+    let mut doc = super::read_config_doc_or_default()?;
+    ensure_defaults(&mut doc)?;
+    ai_budget_config_from_doc(&doc)
+}
+
+pub(super) fn ensure_defaults(doc: &mut DocumentMut) -> Result<()> {
+    let budgets = super::top_table_mut(doc, "budgets")?;
+    if budgets.get("warn_ratio").is_none() {
+        budgets["warn_ratio"] = toml_edit::value(DEFAULT_WARN_RATIO);
+    }
+    Ok(())
+}
+
+fn ai_budget_config_from_doc(doc: &DocumentMut) -> Result<AiBudgetConfig> {
+    let Some(item) = doc.get("budgets") else {
+        return Ok(AiBudgetConfig::default());
+    };
+    let Some(table) = item.as_table_like() else {
+        bail!("budgets must be a table");
+    };
+    let mut config = AiBudgetConfig::default();
+    for (key, _) in table.iter() {
+        if !matches!(key, "warn_ratio" | "projects" | "profiles") {
+            bail!("budgets has unknown key '{key}'");
+        }
+    }
+    if let Some(item) = table.get("warn_ratio") {
+        let ratio = number(item, "budgets.warn_ratio")?;
+        if !(ratio > 0.0 && ratio <= 1.0) {
+            bail!("budgets.warn_ratio must be in (0, 1], got {ratio}");
+        }
+        config.warn_ratio = ratio;
+    }
+    config.projects = scoped_limits(table, "projects")?;
+    config.profiles = scoped_limits(table, "profiles")?;
+    Ok(config)
+}
+
+fn scoped_limits(table: &dyn TableLike, key: &str) -> Result<BTreeMap<String, SpendLimits>> {
+    let mut out = BTreeMap::new();
+    let Some(item) = table.get(key) else {
+        return Ok(out);
+    };
+    let Some(scopes) = item.as_table_like() else {
+        bail!("budgets.{key} must be a table of name = {{ daily_usd, monthly_usd }}");
+    };
+    for (name, item) in scopes.iter() {
+        let path = format!("budgets.{key}.\"{name}\"");
+        let Some(limits) = item.as_table_like() else {
+            bail!("{path} must be a table");
+        };
+        if name.trim().is_empty() {
+            bail!("budgets.{key} has an empty name");
+        }
+        let mut parsed = SpendLimits::default();
+        for (limit_key, item) in limits.iter() {
+            if !LIMIT_KEYS.contains(&limit_key) {
+                bail!("{path} has unknown key '{limit_key}'");
+            }
+            let amount = number(item, &format!("{path}.{limit_key}"))?;
+            if amount < 0.0 {
+                bail!("{path}.{limit_key} must be >= 0, got {amount}");
+            }
+            match limit_key {
+                "daily_usd" => parsed.daily_usd = Some(amount),
+                _ => parsed.monthly_usd = Some(amount),
+            }
+        }
+        out.insert(name.trim().to_string(), parsed);
+    }
+    Ok(out)
+}
+
+fn number(item: &Item, path: &str) -> Result<f64> {
+    let value = item
+        .as_float()
+        .or_else(|| item.as_integer().map(|value| value as f64))
+        .ok_or_else(|| anyhow::anyhow!("{path} must be a number"))?;
+    if !value.is_finite() {
+        bail!("{path} must be a finite number, got {value}");
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config_has_no_budgets() -> Result<()> {
+        let text = super::super::default_config_text();
+        assert!(text.contains("[budgets]"), "{text}");
+        let config = ai_budget_config_from_doc(&text.parse::<DocumentMut>()?)?;
+        assert!(config.is_empty());
+        assert_eq!(config.warn_ratio, DEFAULT_WARN_RATIO);
+        Ok(())
+    }
+
+    #[test]
+    fn ai_budget_config_reads_project_and_profile_limits() -> Result<()> {
+        let doc = "[budgets]\nwarn_ratio = 0.5\n\n[budgets.projects.\"/work/app\"]\ndaily_usd = 2\nmonthly_usd = 30.5\n\n[budgets.profiles.codex-default]\nmonthly_usd = 10.0\n"
+            .parse::<DocumentMut>()?;
+        let config = ai_budget_config_from_doc(&doc)?;
+        assert_eq!(config.warn_ratio, 0.5);
+        assert_eq!(
+            config.projects.get("/work/app"),
+            Some(&SpendLimits {
+                daily_usd: Some(2.0),
+                monthly_usd: Some(30.5),
+            })
+        );
+        assert_eq!(
+            config.profiles.get("codex-default"),
+            Some(&SpendLimits {
+                daily_usd: None,
+                monthly_usd: Some(10.0),
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ai_budget_config_rejects_malformed_limits() {
+        for (text, expected) in [
+            (
+                "[budgets.projects.\"/a\"]\ndaily_usd = \"5\"\n",
+                "daily_usd must be a number",
+            ),
+            (
+                "[budgets.profiles.p]\nmonthly_usd = -1\n",
+                "monthly_usd must be >= 0",
+            ),
+            (
+                "[budgets.profiles.p]\nweekly_usd = 1\n",
+                "unknown key 'weekly_usd'",
+            ),
+            (
+                "[budgets]\nwarn_ratio = 1.5\n",
+                "warn_ratio must be in (0, 1]",
+            ),
+            ("[budgets]\ndaily = 1\n", "unknown key 'daily'"),
+        ] {
+            let doc = text.parse::<DocumentMut>().expect("test toml parses");
+            let err = ai_budget_config_from_doc(&doc).expect_err(text);
+            assert!(err.to_string().contains(expected), "{text}: {err}");
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use toml_edit::{value, DocumentMut};
+
+/// `[encryption]`: where the SQLCipher key comes from when
+/// `REMEM_CIPHER_KEY` is unset.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EncryptionConfig {
+    /// Shell command whose stdout is the key (`v2:<64 hex>` or a legacy
+    /// passphrase). When set, `~/.remem/.key` is ignored.
+    pub key_command: Option<String>,
+}
+
+pub fn encryption_config() -> Result<EncryptionConfig> {
+    let doc = super::read_config_doc_or_default()?;
+    encryption_config_from_doc(&doc)
+}
+
+/// Point `encryption.key_command` at a new provider; used when a key
+/// rotation commits a key that lives outside the data directory.
+pub(crate) fn set_key_command(command: &str) -> Result<PathBuf> {
+    let path = super::config_path()?;
+    let mut doc = super::read_config_doc_or_default()?;
+    super::top_table_mut(&mut doc, "encryption")?["key_command"] = value(command);
+    super::write_config_doc(&path, &doc)?;
+    Ok(path)
+}
+
+fn encryption_config_from_doc(doc: &DocumentMut) -> Result<EncryptionConfig> {
+    let Some(item) = doc.get("encryption") else {
+        return Ok(EncryptionConfig::default());
+    };
+    let Some(table) = item.as_table() else {
+        bail!("encryption must be a table");
+    };
+    if let Some(item) = table.get("key_command") {
+        if !item.is_str() {
+            bail!("encryption.key_command must be a string");
+        }
+    }
+    Ok(EncryptionConfig {
+        key_command: super::optional_str(table, "key_command"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_command_is_optional_and_trimmed() -> Result<()> {
+        let doc = DocumentMut::new();
+        assert_eq!(encryption_config_from_doc(&doc)?.key_command, None);
+
+        let doc =
+            "[encryption]\nkey_command = \"  pass show remem/db  \"\n".parse::<DocumentMut>()?;
+        assert_eq!(
+            encryption_config_from_doc(&doc)?.key_command.as_deref(),
+            Some("pass show remem/db")
+        );
+
+        let doc = "[encryption]\nkey_command = \"   \"\n".parse::<DocumentMut>()?;
+        assert_eq!(encryption_config_from_doc(&doc)?.key_command, None);
+        Ok(())
+    }
+
+    #[test]
+    fn non_string_key_command_is_rejected() -> Result<()> {
+        let doc = "[encryption]\nkey_command = 1\n".parse::<DocumentMut>()?;
+        let error = encryption_config_from_doc(&doc)
+            .expect_err("numeric key_command must fail")
+            .to_string();
+        assert!(error.contains("encryption.key_command"), "{error}");
+        Ok(())
+    }
+}
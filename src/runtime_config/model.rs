@@ -74,6 +74,7 @@ impl MemoryAiExecutor {
             Self::Http => "http",
             Self::ClaudeCli => "claude-cli",
             Self::CodexCli => "codex-cli",
+            Self::OpenAiCompatible => "openai-compatible",
         }
     }
 }
@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use toml_edit::{DocumentMut, Item};
+
+const DEFAULT_REQUIRE_SIGNATURE: bool = true;
+
+/// Who may sign a shared team pack and whether unsigned packs are refused.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PackTrustConfig {
+    pub require_signature: bool,
+    /// Author name written into `pack.sig` by `remem pack push`.
+    pub author: Option<String>,
+    /// Author name -> base64 Ed25519 public key.
+    pub trusted_authors: BTreeMap<String, String>,
+}
+
+pub fn pack_trust_config() -> Result<PackTrustConfig> {
+    let mut doc = super::read_config_doc_or_default()?;
+    ensure_defaults(&mut doc)?;
+    pack_trust_config_from_doc(&doc)
+}
+
+pub(super) fn ensure_defaults(doc: &mut DocumentMut) -> Result<()> {
+    let pack = super::top_table_mut(doc, "pack")?;
+    super::set_bool_if_missing(pack, "require_signature", DEFAULT_REQUIRE_SIGNATURE);
+    Ok(())
+}
+
+fn pack_trust_config_from_doc(doc: &DocumentMut) -> Result<PackTrustConfig> {
+    let Some(table) = doc.get("pack").and_then(Item::as_table) else {
+        return Ok(PackTrustConfig {
+            require_signature: DEFAULT_REQUIRE_SIGNATURE,
+            ..PackTrustConfig::default()
+        });
+    };
+    let require_signature = match table.get("require_signature") {
+        Some(item) => item
+            .as_bool()
+            .ok_or_else(|| anyhow::anyhow!("pack.require_signature must be a boolean"))?,
+        None => DEFAULT_REQUIRE_SIGNATURE,
+    };
+    let mut trusted_authors = BTreeMap::new();
+    if let Some(item) = table.get("trusted_authors") {
+        let Some(authors) = item.as_table_like() else {
+            bail!("pack.trusted_authors must be a table of author = \"public key\"");
+        };
+        for (name, key) in authors.iter() {
+            let Some(key) = key.as_str().map(str::trim).filter(|key| !key.is_empty()) else {
+                bail!("pack.trusted_authors.{name} must be a base64 public key string");
+            };
+            trusted_authors.insert(name.to_string(), key.to_string());
+        }
+    }
+    Ok(PackTrustConfig {
+        require_signature,
+        author: super::optional_str(table, "author"),
+        trusted_authors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config_requires_pack_signatures() -> Result<()> {
+        let text = super::super::default_config_text();
+        assert!(text.contains("[pack]"), "{text}");
+        let doc = text.parse::<DocumentMut>()?;
+        let config = pack_trust_config_from_doc(&doc)?;
+        assert!(config.require_signature);
+        assert!(config.trusted_authors.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn pack_trust_config_reads_author_and_trusted_keys() -> Result<()> {
+        let doc = "[pack]\nrequire_signature = false\nauthor = \"alice\"\n\n[pack.trusted_authors]\nalice = \"AAAA\"\nbob = \" BBBB \"\n"
+            .parse::<DocumentMut>()?;
+        let config = pack_trust_config_from_doc(&doc)?;
+        assert!(!config.require_signature);
+        assert_eq!(config.author.as_deref(), Some("alice"));
+        assert_eq!(
+            config.trusted_authors.get("bob").map(String::as_str),
+            Some("BBBB")
+        );
+
+        let invalid = "[pack.trusted_authors]\nalice = 3\n".parse::<DocumentMut>()?;
+        let error = pack_trust_config_from_doc(&invalid).expect_err("non-string key must fail");
+        assert!(
+            error.to_string().contains("pack.trusted_authors.alice"),
+            "{error}"
+        );
+        Ok(())
+    }
+}
@@ -21,6 +21,7 @@ const FAMILY_TABLES: &[&str] = &[
     "gpt5",
     "gpt4",
     "codex_mini",
+    "openai_compatible",
 ];
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -0,0 +1,133 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use toml_edit::{DocumentMut, Item};
+
+const DEFAULT_ENTROPY: bool = true;
+
+/// `[redaction]`: secret detectors applied at capture on top of the built-in
+/// provider token formats and private-key blocks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RedactionConfig {
+    /// Redact long mixed-case alphanumeric strings with high Shannon entropy.
+    pub entropy: bool,
+    /// Detector name -> regex. Every match is replaced with `[REDACTED]`.
+    pub patterns: BTreeMap<String, String>,
+}
+
+impl Default for RedactionConfig {
+    fn default() -> Self {
+        Self {
+            entropy: DEFAULT_ENTROPY,
+            patterns: BTreeMap::new(),
+        }
+    }
+}
+
+pub fn redaction_config() -> Result<RedactionConfig> {
+    let (config, problems) = redaction_config_with_problems()?;
+    if !problems.is_empty() {
+        bail!("{}", problems.join("; "));
+    }
+    Ok(config)
+}
+
+/// `[redaction]` with each invalid entry dropped and described in the
+/// returned list, so one bad pattern does not discard `entropy = false` or
+/// the other patterns. Fails only when `config.toml` itself is unreadable.
+pub fn redaction_config_with_problems() -> Result<(RedactionConfig, Vec<String>)> {
+    let mut doc = super::read_config_doc_or_default()?;
+    ensure_defaults(&mut doc)?;
+    Ok(redaction_config_from_doc(&doc))
+}
+
+pub(super) fn ensure_defaults(doc: &mut DocumentMut) -> Result<()> {
+    let redaction = super::top_table_mut(doc, "redaction")?;
+    super::set_bool_if_missing(redaction, "entropy", DEFAULT_ENTROPY);
+    Ok(())
+}
+
+fn redaction_config_from_doc(doc: &DocumentMut) -> (RedactionConfig, Vec<String>) {
+    let mut problems = Vec::new();
+    let Some(table) = doc.get("redaction").and_then(Item::as_table) else {
+        return (RedactionConfig::default(), problems);
+    };
+    let entropy = match table.get("entropy") {
+        Some(item) => item.as_bool().unwrap_or_else(|| {
+            problems.push("redaction.entropy must be a boolean".to_string());
+            DEFAULT_ENTROPY
+        }),
+        None => DEFAULT_ENTROPY,
+    };
+    let mut patterns = BTreeMap::new();
+    if let Some(item) = table.get("patterns") {
+        match item.as_table_like() {
+            Some(entries) => {
+                for (name, pattern) in entries.iter() {
+                    let Some(pattern) = pattern
+                        .as_str()
+                        .filter(|pattern| !pattern.trim().is_empty())
+                    else {
+                        problems.push(format!(
+                            "redaction.patterns.{name} must be a non-empty regex string"
+                        ));
+                        continue;
+                    };
+                    if let Err(error) = regex::Regex::new(pattern) {
+                        problems.push(format!(
+                            "redaction.patterns.{name} is not a valid regex: {error}"
+                        ));
+                        continue;
+                    }
+                    patterns.insert(name.to_string(), pattern.to_string());
+                }
+            }
+            None => {
+                problems.push("redaction.patterns must be a table of name = \"regex\"".to_string())
+            }
+        }
+    }
+    (RedactionConfig { entropy, patterns }, problems)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config_enables_entropy_without_custom_patterns() -> Result<()> {
+        let text = super::super::default_config_text();
+        assert!(text.contains("[redaction]"), "{text}");
+        let (config, problems) = redaction_config_from_doc(&text.parse::<DocumentMut>()?);
+        assert_eq!(config, RedactionConfig::default());
+        assert!(problems.is_empty(), "{problems:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn redaction_config_reads_named_patterns_and_rejects_bad_regex() -> Result<()> {
+        let doc =
+            "[redaction]\nentropy = false\n\n[redaction.patterns]\nacme = \"ACME-[0-9]{6}\"\n"
+                .parse::<DocumentMut>()?;
+        let (config, problems) = redaction_config_from_doc(&doc);
+        assert!(problems.is_empty(), "{problems:?}");
+        assert!(!config.entropy);
+        assert_eq!(config.patterns["acme"], "ACME-[0-9]{6}");
+
+        let doc = "[redaction]\nentropy = false\n\n[redaction.patterns]\nbroken = \"(unclosed\"\nacme = \"ACME-[0-9]{6}\"\n"
+            .parse::<DocumentMut>()?;
+        let (config, problems) = redaction_config_from_doc(&doc);
+        assert_eq!(problems.len(), 1);
+        assert!(
+            problems[0].contains("redaction.patterns.broken"),
+            "{problems:?}"
+        );
+        assert!(!config.entropy, "valid settings survive a bad pattern");
+        assert_eq!(
+            config.patterns.keys().collect::<Vec<_>>(),
+            vec!["acme"],
+            "valid patterns survive a bad one"
+        );
+        Ok(())
+    }
+}
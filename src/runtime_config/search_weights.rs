@@ -0,0 +1,304 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use toml_edit::{DocumentMut, Item, Table, TableLike};
+
+use crate::retrieval::search::SearchWeights;
+
+/// Channel weights a profile may override. Everything else (RRF k, vector
+/// distance cutoff, evidence confidence) stays on the calibrated defaults.
+const PROFILE_KEYS: &[(&str, fn(&mut SearchWeights) -> &mut f64)] = &[
+    ("fts", |weights| &mut weights.fts),
+    ("vector", |weights| &mut weights.vector),
+    ("entity", |weights| &mut weights.entity),
+    ("graph", |weights| &mut weights.graph),
+    ("temporal", |weights| &mut weights.temporal),
+    ("fact", |weights| &mut weights.fact),
+    ("like_fallback", |weights| &mut weights.like_fallback),
+    ("usage", |weights| &mut weights.usage),
+];
+
+/// How a project's assigned profile participates in retrieval.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SearchWeightMode {
+    /// The profile replaces the calibrated defaults for this project.
+    Active,
+    /// Retrieval keeps the defaults; the profile runs alongside and only its
+    /// ranking deltas are logged.
+    Shadow,
+}
+
+impl SearchWeightMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Shadow => "shadow",
+        }
+    }
+
+    fn parse(raw: &str) -> Option<Self> {
+        match raw.trim() {
+            "active" => Some(Self::Active),
+            "shadow" => Some(Self::Shadow),
+            _ => None,
+        }
+    }
+}
+
+/// The `[search.weights.projects."<project>"]` assignment, resolved against
+/// its `[search.weights.profiles.<name>]` table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectSearchWeightProfile {
+    pub profile: String,
+    pub mode: SearchWeightMode,
+    pub weights: SearchWeights,
+}
+
+pub fn project_search_weight_profile(project: &str) -> Result<Option<ProjectSearchWeightProfile>> {
+    let doc = super::read_config_doc_or_default()?;
+    project_profile_from_doc(&doc, project)
+}
+
+/// Store `weights` as profile `name` and assign it to `project` in `mode`,
+/// replacing any earlier profile of the same name.
+pub fn write_search_weight_profile(
+    name: &str,
+    weights: &SearchWeights,
+    project: &str,
+    mode: SearchWeightMode,
+) -> Result<PathBuf> {
+    let name = name.trim();
+    if name.is_empty() {
+        bail!("search weight profile name must not be empty");
+    }
+    if project.trim().is_empty() {
+        bail!("search weight profile project must not be empty");
+    }
+    weights.validate()?;
+    let path = super::config_path()?;
+    let mut doc = super::read_config_doc_or_default()?;
+    set_project_profile(&mut doc, name, weights, project.trim(), mode)?;
+    // Round-trip through the reader so a hand-edited sibling entry that is
+    // already invalid fails here rather than on the next search.
+    profiles_from_doc(&doc)?;
+    super::write_config_doc(&path, &doc)?;
+    Ok(path)
+}
+
+fn set_project_profile(
+    doc: &mut DocumentMut,
+    name: &str,
+    weights: &SearchWeights,
+    project: &str,
+    mode: SearchWeightMode,
+) -> Result<()> {
+    let search = super::top_table_mut(doc, "search")?;
+    search.set_implicit(true);
+    let search_weights = super::child_table_mut(search, "weights")?;
+    search_weights.set_implicit(true);
+
+    let profiles = super::child_table_mut(search_weights, "profiles")?;
+    profiles.set_implicit(true);
+    let mut profile = Table::new();
+    let mut weights = *weights;
+    for (key, field) in PROFILE_KEYS {
+        profile[*key] = toml_edit::value(*field(&mut weights));
+    }
+    profiles[name] = Item::Table(profile);
+
+    let projects = super::child_table_mut(search_weights, "projects")?;
+    projects.set_implicit(true);
+    let mut assignment = Table::new();
+    assignment["profile"] = toml_edit::value(name);
+    assignment["mode"] = toml_edit::value(mode.as_str());
+    projects[project] = Item::Table(assignment);
+    Ok(())
+}
+
+fn project_profile_from_doc(
+    doc: &DocumentMut,
+    project: &str,
+) -> Result<Option<ProjectSearchWeightProfile>> {
+    let (profiles, projects) = profiles_from_doc(doc)?;
+    let Some((profile, mode)) = projects.get(project) else {
+        return Ok(None);
+    };
+    Ok(Some(ProjectSearchWeightProfile {
+        profile: profile.clone(),
+        mode: *mode,
+        weights: profiles[profile],
+    }))
+}
+
+type ProfileTables = (
+    BTreeMap<String, SearchWeights>,
+    BTreeMap<String, (String, SearchWeightMode)>,
+);
+
+fn profiles_from_doc(doc: &DocumentMut) -> Result<ProfileTables> {
+    let mut profiles = BTreeMap::new();
+    let mut projects = BTreeMap::new();
+    let Some(table) = doc.get("search").and_then(|search| search.get("weights")) else {
+        return Ok((profiles, projects));
+    };
+    let Some(table) = table.as_table_like() else {
+        bail!("search.weights must be a table");
+    };
+    for (key, _) in table.iter() {
+        if !matches!(key, "profiles" | "projects") {
+            bail!("search.weights has unknown key '{key}'");
+        }
+    }
+    if let Some(item) = table.get("profiles") {
+        let Some(entries) = item.as_table_like() else {
+            bail!("search.weights.profiles must be a table of name = {{ fts, vector, ... }}");
+        };
+        for (name, item) in entries.iter() {
+            profiles.insert(name.to_string(), profile_weights(name, item)?);
+        }
+    }
+    if let Some(item) = table.get("projects") {
+        let Some(entries) = item.as_table_like() else {
+            bail!("search.weights.projects must be a table of project = {{ profile, mode }}");
+        };
+        for (project, item) in entries.iter() {
+            let path = format!("search.weights.projects.\"{project}\"");
+            let Some(assignment) = item.as_table_like() else {
+                bail!("{path} must be a table");
+            };
+            let (profile, mode) = project_assignment(&path, assignment)?;
+            if !profiles.contains_key(&profile) {
+                bail!("{path}.profile names unknown profile '{profile}'");
+            }
+            projects.insert(project.to_string(), (profile, mode));
+        }
+    }
+    Ok((profiles, projects))
+}
+
+fn profile_weights(name: &str, item: &Item) -> Result<SearchWeights> {
+    let path = format!("search.weights.profiles.{name}");
+    let Some(entries) = item.as_table_like() else {
+        bail!("{path} must be a table");
+    };
+    let mut weights = SearchWeights::default();
+    for (key, item) in entries.iter() {
+        let Some((_, field)) = PROFILE_KEYS.iter().find(|(known, _)| *known == key) else {
+            bail!("{path} has unknown key '{key}'");
+        };
+        *field(&mut weights) = number(item, &format!("{path}.{key}"))?;
+    }
+    Ok(weights)
+}
+
+fn project_assignment(path: &str, table: &dyn TableLike) -> Result<(String, SearchWeightMode)> {
+    for (key, _) in table.iter() {
+        if !matches!(key, "profile" | "mode") {
+            bail!("{path} has unknown key '{key}'");
+        }
+    }
+    let Some(profile) = table
+        .get("profile")
+        .and_then(Item::as_str)
+        .map(str::trim)
+        .filter(|profile| !profile.is_empty())
+    else {
+        bail!("{path}.profile must be a profile name");
+    };
+    let mode = match table.get("mode") {
+        Some(item) => item
+            .as_str()
+            .and_then(SearchWeightMode::parse)
+            .ok_or_else(|| anyhow::anyhow!("{path}.mode must be \"active\" or \"shadow\""))?,
+        None => SearchWeightMode::Active,
+    };
+    Ok((profile.to_string(), mode))
+}
+
+fn number(item: &Item, path: &str) -> Result<f64> {
+    let value = item
+        .as_float()
+        .or_else(|| item.as_integer().map(|value| value as f64))
+        .ok_or_else(|| anyhow::anyhow!("{path} must be a number"))?;
+    if !value.is_finite() {
+        bail!("{path} must be a finite number, got {value}");
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn project_profile_overrides_named_channels_only() -> Result<()> {
+        let doc = "[search.weights.profiles.docs-heavy]\nfts = 3\nvector = 2.25\n\n[search.weights.projects.\"/work/app\"]\nprofile = \"docs-heavy\"\nmode = \"shadow\"\n"
+            .parse::<DocumentMut>()?;
+        let resolved = project_profile_from_doc(&doc, "/work/app")?.expect("assigned project");
+        assert_eq!(resolved.profile, "docs-heavy");
+        assert_eq!(resolved.mode, SearchWeightMode::Shadow);
+        assert_eq!(
+            resolved.weights,
+            SearchWeights {
+                fts: 3.0,
+                vector: 2.25,
+                ..SearchWeights::default()
+            }
+        );
+        assert_eq!(project_profile_from_doc(&doc, "/work/other")?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn written_profile_round_trips_and_defaults_to_active() -> Result<()> {
+        let mut doc = DocumentMut::new();
+        let weights = SearchWeights {
+            entity: 1.5,
+            ..SearchWeights::default()
+        };
+        set_project_profile(
+            &mut doc,
+            "fit-app",
+            &weights,
+            "/work/app",
+            SearchWeightMode::Shadow,
+        )?;
+        let text = doc.to_string();
+        assert!(text.contains("[search.weights.profiles.fit-app]"), "{text}");
+        assert!(!text.contains("[search]\n"), "{text}");
+        let resolved = project_profile_from_doc(&text.parse()?, "/work/app")?.expect("assigned");
+        assert_eq!(resolved.weights, weights);
+        assert_eq!(resolved.mode, SearchWeightMode::Shadow);
+
+        let doc = "[search.weights.profiles.p]\nfts = 1\n\n[search.weights.projects.\"/a\"]\nprofile = \"p\"\n"
+            .parse::<DocumentMut>()?;
+        let resolved = project_profile_from_doc(&doc, "/a")?.expect("assigned");
+        assert_eq!(resolved.mode, SearchWeightMode::Active);
+        Ok(())
+    }
+
+    #[test]
+    fn search_weight_profiles_reject_malformed_entries() {
+        for (text, expected) in [
+            (
+                "[search.weights.profiles.p]\nrrf_k = 10\n",
+                "unknown key 'rrf_k'",
+            ),
+            ("[search.weights.profiles.p]\nfts = \"2\"\n", "fts must be a number"),
+            (
+                "[search.weights.projects.\"/a\"]\nprofile = \"missing\"\n",
+                "unknown profile 'missing'",
+            ),
+            (
+                "[search.weights.profiles.p]\n\n[search.weights.projects.\"/a\"]\nprofile = \"p\"\nmode = \"on\"\n",
+                "mode must be \"active\" or \"shadow\"",
+            ),
+            ("[search.weights]\ndefault = \"p\"\n", "unknown key 'default'"),
+        ] {
+            let doc = text.parse::<DocumentMut>().expect("test toml parses");
+            let err = project_profile_from_doc(&doc, "/a").expect_err(text);
+            assert!(err.to_string().contains(expected), "{text}: {err}");
+        }
+    }
+}
@@ -4,6 +4,7 @@ use anyhow::{bail, Context, Result};
 use rusqlite::Connection;
 
 use crate::memory::raw_archive::{RawIngestReport, TranscriptDrainOptions, SOURCE_ROOT_LOCAL};
+use crate::memory::raw_transcript::TranscriptBoundary;
 
 #[derive(Debug)]
 struct StopTranscriptProbeFailed;
@@ -18,7 +19,7 @@ impl std::error::Error for StopTranscriptProbeFailed {}
 
 pub(super) struct StopTranscript<'a> {
     pub path: &'a str,
-    pub byte_limit: Option<u64>,
+    pub boundary: TranscriptBoundary,
     pub project: &'a str,
     pub branch: Option<&'a str>,
     pub cwd: &'a str,
@@ -30,7 +31,7 @@ pub(super) fn drain_with_identity(
 ) -> Result<RawIngestReport> {
     let transcript = Path::new(input.path);
     let scan_root = transcript.parent().unwrap_or_else(|| Path::new("."));
-    let plan = match input.byte_limit {
+    let plan = match input.boundary.byte_len {
         Some(byte_limit) => crate::ingest::session_identity::probe_bounded(
             SOURCE_ROOT_LOCAL,
             scan_root,
@@ -73,11 +74,12 @@ pub(super) fn drain_with_identity(
         plan.branch.as_deref().or(input.branch),
         plan.cwd.as_deref().or(Some(input.cwd)),
         &options,
-        input.byte_limit,
+        input.boundary,
     )?;
 
     let captured_full_file = input
-        .byte_limit
+        .boundary
+        .byte_len
         .is_none_or(|limit| i64::try_from(limit).ok() == Some(plan.observed_size_bytes));
     if !report.has_failures() && !report.partial_tail && captured_full_file {
         let index = crate::ingest::session_identity::index_events(
@@ -126,7 +128,7 @@ mod tests {
             &conn,
             StopTranscript {
                 path: &path_text,
-                byte_limit: None,
+                boundary: TranscriptBoundary::default(),
                 project: "hook-project",
                 branch: Some("volatile"),
                 cwd: "/tmp/project",
@@ -178,7 +180,7 @@ mod tests {
             &conn,
             StopTranscript {
                 path: &path_text,
-                byte_limit: Some(byte_limit),
+                boundary: TranscriptBoundary::bytes(byte_limit),
                 project: "/tmp/fallback",
                 branch: None,
                 cwd: "/tmp/fallback",
@@ -240,7 +242,7 @@ mod tests {
             &conn,
             StopTranscript {
                 path: &path_text,
-                byte_limit: None,
+                boundary: TranscriptBoundary::default(),
                 project: "/tmp/remem",
                 branch: None,
                 cwd: "/tmp/remem",
@@ -27,6 +27,7 @@ pub(super) struct StopHookPayload {
     pub(super) cwd: Option<String>,
     pub(super) transcript_path: Option<String>,
     pub(super) transcript_byte_len: Option<u64>,
+    pub(super) transcript_message_count: Option<u64>,
     pub(super) last_assistant_message: Option<String>,
     /// Machine-readable Cursor capture fidelity marker (GH-825). Cursor Stop
     /// payloads never carry `transcript_path`, so the Claude/Codex transcript
@@ -34,6 +35,15 @@ pub(super) struct StopHookPayload {
     pub(super) cursor_capture: Option<super::cursor_snapshot::CursorCaptureMarker>,
 }
 
+impl StopHookPayload {
+    pub(super) fn transcript_boundary(&self) -> crate::memory::raw_transcript::TranscriptBoundary {
+        crate::memory::raw_transcript::TranscriptBoundary {
+            byte_len: self.transcript_byte_len,
+            message_count: self.transcript_message_count,
+        }
+    }
+}
+
 pub(super) fn drain_raw_archive_from_range(
     conn: &Connection,
     task: &db::ExtractionTask,
@@ -73,7 +83,7 @@ pub(super) fn drain_raw_archive_from_range(
             conn,
             super::raw_identity::StopTranscript {
                 path: transcript_path,
-                byte_limit: payload.transcript_byte_len,
+                boundary: payload.transcript_boundary(),
                 project: &task.project,
                 branch: branch.as_deref(),
                 cwd,
@@ -236,7 +246,7 @@ pub(super) fn run_post_archive_stop_memory_side_effects(
             let assistant_message = payload.transcript_path.as_deref().and_then(|path| {
                 crate::summarize::extract_last_assistant_message_with_limit(
                     path,
-                    payload.transcript_byte_len,
+                    payload.transcript_boundary(),
                 )
             });
             if let Some(message) = assistant_message {
@@ -765,6 +775,7 @@ mod stop_payload_selection_tests {
             cwd: None,
             transcript_path: Some(path.to_string()),
             transcript_byte_len,
+            transcript_message_count: None,
             last_assistant_message: None,
             cursor_capture: None,
         }
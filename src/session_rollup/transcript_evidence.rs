@@ -252,7 +252,10 @@ pub(super) fn load_prompt_transcript_evidence(
         };
         let content = crate::memory::raw_transcript::read_transcript_content(
             transcript_path,
-            Some(transcript_byte_len),
+            crate::memory::raw_transcript::TranscriptBoundary {
+                byte_len: Some(transcript_byte_len),
+                message_count: payload.transcript_message_count,
+            },
         )
         .with_context(|| {
             format!(
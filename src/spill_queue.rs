@@ -131,7 +131,7 @@ impl SpillQueue {
         })
     }
 
-    fn with_lock<T>(&self, operation: impl FnOnce() -> Result<T>) -> Result<T> {
+    pub(crate) fn with_lock<T>(&self, operation: impl FnOnce() -> Result<T>) -> Result<T> {
         let lock_path = self.active_path.with_extension("lock");
         if let Some(parent) = lock_path.parent() {
             std::fs::create_dir_all(parent)
@@ -1,3 +1,4 @@
+use crate::memory::raw_transcript::TranscriptBoundary;
 use anyhow::Result;
 use serde::Deserialize;
 use std::collections::hash_map::DefaultHasher;
@@ -276,15 +277,15 @@ pub(crate) fn hash_message(msg: &str) -> String {
 }
 
 pub(super) fn extract_last_assistant_message(transcript_path: &str) -> Option<String> {
-    extract_last_assistant_message_with_limit(transcript_path, None)
+    extract_last_assistant_message_with_limit(transcript_path, TranscriptBoundary::default())
 }
 
 pub(crate) fn extract_last_assistant_message_with_limit(
     transcript_path: &str,
-    byte_limit: Option<u64>,
+    boundary: TranscriptBoundary,
 ) -> Option<String> {
     let content =
-        crate::memory::raw_transcript::read_transcript_content(transcript_path, byte_limit).ok()?;
+        crate::memory::raw_transcript::read_transcript_content(transcript_path, boundary).ok()?;
 
     for line in content.lines().rev() {
         let val: serde_json::Value = match serde_json::from_str(line) {
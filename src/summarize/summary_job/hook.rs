@@ -351,6 +351,21 @@ fn summary_payload_with_cwd(input: &str, cwd: &str, profile: Option<&str>) -> Re
                 "transcript_byte_len".to_string(),
                 serde_json::Value::Number(metadata.len().into()),
             );
+            // Session documents are rewritten in place, so readers cut them
+            // at the captured message count rather than the byte length.
+            match crate::memory::raw_transcript::session_document_message_count(&transcript_path) {
+                Ok(Some(message_count)) => {
+                    obj.insert(
+                        "transcript_message_count".to_string(),
+                        serde_json::Value::Number(message_count.into()),
+                    );
+                }
+                Ok(None) => {}
+                Err(error) => crate::log::warn(
+                    "summarize",
+                    &format!("snapshot transcript message count path={transcript_path}: {error}"),
+                ),
+            }
         }
     }
     if let Some(profile) = clean_optional(profile) {
@@ -498,11 +513,13 @@ fn record_codex_transcript_message_events(
     else {
         return Ok(0);
     };
-    let content =
-        crate::memory::raw_transcript::read_transcript_content(transcript_path, Some(byte_limit))
-            .with_context(|| {
-            format!("read bounded Codex transcript message capture path={transcript_path}")
-        })?;
+    let content = crate::memory::raw_transcript::read_transcript_content(
+        transcript_path,
+        crate::memory::raw_transcript::TranscriptBoundary::bytes(byte_limit),
+    )
+    .with_context(|| {
+        format!("read bounded Codex transcript message capture path={transcript_path}")
+    })?;
     let mut inserted = 0_usize;
     for (line_index, line) in content.lines().enumerate() {
         use crate::memory::raw_transcript::TranscriptRecordClass;
@@ -160,6 +160,33 @@ fn summary_payload_snapshots_transcript_byte_length() -> anyhow::Result<()> {
         parsed["transcript_byte_len"].as_u64(),
         Some(std::fs::metadata(&transcript)?.len())
     );
+    assert!(parsed.get("transcript_message_count").is_none());
+    Ok(())
+}
+
+#[test]
+fn summary_payload_snapshots_session_document_message_count() -> anyhow::Result<()> {
+    let test_dir = ScopedTestDataDir::new("summary-transcript-message-count");
+    std::fs::create_dir_all(&test_dir.path)?;
+    let transcript = test_dir.path.join("session.json");
+    let document = serde_json::json!({
+        "sessionId": "g-count",
+        "messages": [
+            {"type": "user", "content": "first"},
+            {"type": "gemini", "content": [{"text": "second"}]}
+        ]
+    });
+    std::fs::write(&transcript, serde_json::to_string_pretty(&document)?)?;
+    let input = serde_json::json!({
+        "session_id": "sess-transcript-message-count",
+        "transcript_path": transcript
+    })
+    .to_string();
+
+    let payload = summary_payload_with_cwd(&input, "/tmp/project", None)?;
+    let parsed: serde_json::Value = serde_json::from_str(&payload)?;
+
+    assert_eq!(parsed["transcript_message_count"].as_u64(), Some(2));
     Ok(())
 }
 
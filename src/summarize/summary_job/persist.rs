@@ -151,6 +151,19 @@ pub(super) fn sync_native_memory(conn: &rusqlite::Connection, cwd: &str, project
     }
 }
 
+/// Keeps an existing remem-generated `.cursor/rules/remem.mdc` in step with
+/// the summary that just landed; projects without one are untouched.
+pub(super) fn refresh_cursor_rules(cwd: &str) {
+    match crate::context::refresh_cursor_rules(cwd) {
+        Ok(crate::context::CursorRulesOutcome::Skipped(_)) => {}
+        Ok(outcome) => crate::log::debug("summary-job", &format!("cursor rules: {outcome:?}")),
+        Err(err) => crate::log::warn(
+            "summary-job",
+            &format!("cursor rules refresh failed: {}", err),
+        ),
+    }
+}
+
 fn push_summary_tag(parts: &mut Vec<String>, tag: &str, value: Option<&str>) {
     if let Some(value) = value {
         parts.push(format!("<{tag}>{}</{tag}>", format::xml_escape_text(value)));
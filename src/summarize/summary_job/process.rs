@@ -11,7 +11,9 @@ use super::super::constants::{
 };
 use super::super::input::{hash_message, SummarizeInput};
 use super::super::parse::parse_summary;
-use super::persist::{build_existing_summary_context, finalize_summary, sync_native_memory};
+use super::persist::{
+    build_existing_summary_context, finalize_summary, refresh_cursor_rules, sync_native_memory,
+};
 use super::side_effects::run_stop_hook_side_effects;
 
 pub async fn process_summary_job_input(
@@ -146,6 +148,9 @@ pub async fn process_summary_job_input(
     time_value(&mut timings, "sync_native_memory", || {
         sync_native_memory(&conn, cwd, &project)
     });
+    time_value(&mut timings, "refresh_cursor_rules", || {
+        refresh_cursor_rules(cwd)
+    });
     push_elapsed(&mut timings, "job_total", total_start);
     log_summary_job_timing("summarized", &project, &timings);
     Ok(())
@@ -1,9 +1,12 @@
 mod detail;
+mod export;
 mod report;
 mod summary;
 #[cfg(test)]
 mod tests;
 mod types;
 
+pub(crate) use export::render_timeline_report;
+pub use export::TimelineReportFormat;
 pub use report::generate_timeline_report;
 pub(crate) use report::{generate_timeline_report_data, TimelineReportData};
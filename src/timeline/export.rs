@@ -0,0 +1,390 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::memory::format::xml_escape_text;
+
+use super::report::{render_markdown, TimelineReportData};
+
+/// Output formats shared by `remem timeline report --format` and
+/// `GET /api/v1/timeline/report`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimelineReportFormat {
+    #[default]
+    Markdown,
+    Json,
+    Csv,
+    Html,
+}
+
+impl TimelineReportFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "markdown" => Some(Self::Markdown),
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            "html" => Some(Self::Html),
+            _ => None,
+        }
+    }
+
+    pub const fn content_type(self) -> &'static str {
+        match self {
+            Self::Markdown => "text/markdown; charset=utf-8",
+            Self::Json => "application/json",
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Html => "text/html; charset=utf-8",
+        }
+    }
+}
+
+pub(crate) fn render_timeline_report(
+    report: &TimelineReportData,
+    format: TimelineReportFormat,
+) -> Result<String> {
+    match format {
+        TimelineReportFormat::Markdown => render_markdown(report),
+        TimelineReportFormat::Json => Ok(serde_json::to_string_pretty(report)?),
+        TimelineReportFormat::Csv => render_csv(report),
+        TimelineReportFormat::Html => render_html(report),
+    }
+}
+
+/// One `section,key,metric,value` row per figure, so every section of the
+/// report fits a single sheet that pivots cleanly.
+fn render_csv(report: &TimelineReportData) -> Result<String> {
+    let mut out = String::from("section,key,metric,value\n");
+    let overview = &report.overview;
+    for (metric, value) in [
+        ("first_date", overview.first_date.clone()),
+        ("last_date", overview.last_date.clone()),
+        ("days_span", overview.days_span.to_string()),
+        (
+            "total_observations",
+            overview.total_observations.to_string(),
+        ),
+        ("total_sessions", overview.total_sessions.to_string()),
+        ("total_memories", overview.total_memories.to_string()),
+    ] {
+        csv_row(&mut out, "overview", &report.project, metric, &value)?;
+    }
+    for type_count in &report.activity_by_type {
+        csv_row(
+            &mut out,
+            "activity_by_type",
+            &type_count.obs_type,
+            "count",
+            &type_count.count.to_string(),
+        )?;
+    }
+    let economics = &report.token_economics;
+    for (metric, value) in [
+        (
+            "total_ai_cost_usd",
+            format!("{:.4}", economics.total_ai_cost),
+        ),
+        (
+            "total_discovery_tokens",
+            economics.total_discovery_tokens.to_string(),
+        ),
+        (
+            "sessions_with_context",
+            economics.sessions_with_context.to_string(),
+        ),
+        (
+            "estimated_recall_savings_tokens",
+            economics.estimated_recall_savings_tokens.to_string(),
+        ),
+        (
+            "recall_tokens_per_usd",
+            economics
+                .recall_tokens_per_usd
+                .map(|ratio| format!("{ratio:.1}"))
+                .unwrap_or_default(),
+        ),
+    ] {
+        csv_row(&mut out, "token_roi", &report.project, metric, &value)?;
+    }
+    for month in report.monthly_breakdown.iter().flatten() {
+        for (metric, value) in [
+            ("observations", month.observations.to_string()),
+            ("sessions", month.sessions.to_string()),
+            ("ai_cost_usd", format!("{:.4}", month.ai_cost)),
+        ] {
+            csv_row(&mut out, "monthly", &month.month, metric, &value)?;
+        }
+    }
+    for observation in report.recent_timeline.iter().flatten() {
+        let key = observation.id.to_string();
+        for (metric, value) in [
+            ("date", format_date(observation.created_at_epoch)),
+            ("type", observation.obs_type.clone()),
+            (
+                "title",
+                observation
+                    .title
+                    .clone()
+                    .unwrap_or_else(|| "(untitled)".into()),
+            ),
+        ] {
+            csv_row(&mut out, "timeline", &key, metric, &value)?;
+        }
+    }
+    Ok(out)
+}
+
+fn csv_row(out: &mut String, section: &str, key: &str, metric: &str, value: &str) -> Result<()> {
+    writeln!(
+        out,
+        "{},{},{},{}",
+        csv_cell(section),
+        csv_cell(key),
+        csv_cell(metric),
+        csv_cell(value)
+    )?;
+    Ok(())
+}
+
+/// Quotes cells per RFC 4180 and prefixes text a spreadsheet would evaluate
+/// as a formula.
+fn csv_cell(value: &str) -> String {
+    let formula = value.starts_with(['=', '+', '@', '\t', '\r'])
+        || (value.starts_with('-') && value.parse::<f64>().is_err());
+    let value = if formula {
+        format!("'{value}")
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+const HTML_STYLE: &str = "body{font-family:system-ui,-apple-system,sans-serif;margin:2rem auto;max-width:960px;padding:0 1rem;color:#1f2933}\
+h1{font-size:1.6rem}h2{font-size:1.2rem;margin-top:2rem;border-bottom:1px solid #d9e2ec;padding-bottom:.25rem}\
+.cards{display:grid;grid-template-columns:repeat(auto-fit,minmax(160px,1fr));gap:.75rem}\
+.card{border:1px solid #d9e2ec;border-radius:6px;padding:.6rem .8rem}\
+.card b{display:block;font-size:1.3rem}.card span{color:#52606d;font-size:.85rem}\
+table{border-collapse:collapse;width:100%}td,th{border-bottom:1px solid #e4e7eb;padding:.3rem .5rem;text-align:left}\
+td.n,th.n{text-align:right}svg text{font-size:12px;fill:#1f2933}ul{padding-left:1.2rem}\
+footer{margin-top:2rem;color:#7b8794;font-size:.8rem}";
+
+/// A single file with inline CSS and SVG charts; it loads nothing remote.
+fn render_html(report: &TimelineReportData) -> Result<String> {
+    let project = xml_escape_text(&report.project);
+    let overview = &report.overview;
+    let economics = &report.token_economics;
+    let mut out = String::with_capacity(16 * 1024);
+    writeln!(
+        out,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Journey Into {project}</title>\n<style>{HTML_STYLE}</style>\n</head>\n<body>"
+    )?;
+    writeln!(out, "<h1>Journey Into {project}</h1>")?;
+    writeln!(
+        out,
+        "<p>{} &rarr; {} ({} days)</p>",
+        xml_escape_text(&overview.first_date),
+        xml_escape_text(&overview.last_date),
+        overview.days_span
+    )?;
+    html_cards(
+        &mut out,
+        &[
+            (overview.total_observations.to_string(), "observations"),
+            (overview.total_sessions.to_string(), "sessions"),
+            (overview.total_memories.to_string(), "active memories"),
+        ],
+    )?;
+
+    writeln!(out, "<h2>Activity by Type</h2>")?;
+    let activity: Vec<(String, f64, String)> = report
+        .activity_by_type
+        .iter()
+        .map(|row| {
+            (
+                row.obs_type.clone(),
+                row.count as f64,
+                row.count.to_string(),
+            )
+        })
+        .collect();
+    html_bar_chart(&mut out, "Observations by type", &activity)?;
+
+    writeln!(out, "<h2>Token ROI</h2>")?;
+    html_cards(
+        &mut out,
+        &[
+            (format!("${:.2}", economics.total_ai_cost), "AI cost"),
+            (
+                format!(
+                    "{:.1}M",
+                    economics.total_discovery_tokens as f64 / 1_000_000.0
+                ),
+                "discovery tokens",
+            ),
+            (
+                economics.sessions_with_context.to_string(),
+                "sessions with context",
+            ),
+            (
+                format!("~{}K", economics.estimated_recall_savings_tokens / 1000),
+                "estimated recall savings (tokens)",
+            ),
+            (
+                economics
+                    .recall_tokens_per_usd
+                    .map(|ratio| format!("{:.0}", ratio))
+                    .unwrap_or_else(|| "n/a".into()),
+                "recall tokens per $",
+            ),
+        ],
+    )?;
+
+    if let Some(monthly) = report.monthly_breakdown.as_deref() {
+        writeln!(out, "<h2>Monthly Breakdown</h2>")?;
+        let observations: Vec<(String, f64, String)> = monthly
+            .iter()
+            .map(|month| {
+                (
+                    month.month.clone(),
+                    month.observations as f64,
+                    month.observations.to_string(),
+                )
+            })
+            .collect();
+        html_bar_chart(&mut out, "Observations per month", &observations)?;
+        let cost: Vec<(String, f64, String)> = monthly
+            .iter()
+            .map(|month| {
+                (
+                    month.month.clone(),
+                    month.ai_cost,
+                    format!("${:.2}", month.ai_cost),
+                )
+            })
+            .collect();
+        html_bar_chart(&mut out, "AI cost per month", &cost)?;
+        writeln!(
+            out,
+            "<table>\n<tr><th>Month</th><th class=\"n\">Observations</th>\
+             <th class=\"n\">Sessions</th><th class=\"n\">AI cost</th></tr>"
+        )?;
+        for month in monthly {
+            writeln!(
+                out,
+                "<tr><td>{}</td><td class=\"n\">{}</td><td class=\"n\">{}</td>\
+                 <td class=\"n\">${:.2}</td></tr>",
+                xml_escape_text(&month.month),
+                month.observations,
+                month.sessions,
+                month.ai_cost
+            )?;
+        }
+        writeln!(out, "</table>")?;
+    }
+
+    if let Some(recent) = report.recent_timeline.as_deref() {
+        writeln!(out, "<h2>Timeline (recent first)</h2>")?;
+        let mut current_date = None;
+        for observation in recent {
+            let date = format_date(observation.created_at_epoch);
+            if current_date.as_ref() != Some(&date) {
+                if current_date.is_some() {
+                    writeln!(out, "</ul>")?;
+                }
+                writeln!(out, "<h3>{}</h3>\n<ul>", xml_escape_text(&date))?;
+                current_date = Some(date);
+            }
+            writeln!(
+                out,
+                "<li>#{} [{}] {}</li>",
+                observation.id,
+                xml_escape_text(&observation.obs_type),
+                xml_escape_text(observation.title.as_deref().unwrap_or("(untitled)"))
+            )?;
+        }
+        if current_date.is_some() {
+            writeln!(out, "</ul>")?;
+        }
+    }
+
+    writeln!(
+        out,
+        "<footer>Generated by remem {}</footer>\n</body>\n</html>",
+        xml_escape_text(crate::build_info::package_version())
+    )?;
+    Ok(out)
+}
+
+fn html_cards(out: &mut String, cards: &[(String, &str)]) -> Result<()> {
+    writeln!(out, "<div class=\"cards\">")?;
+    for (value, label) in cards {
+        writeln!(
+            out,
+            "<div class=\"card\"><b>{}</b><span>{}</span></div>",
+            xml_escape_text(value),
+            xml_escape_text(label)
+        )?;
+    }
+    writeln!(out, "</div>")?;
+    Ok(())
+}
+
+const CHART_LABEL_WIDTH: usize = 150;
+const CHART_BAR_WIDTH: usize = 480;
+const CHART_ROW_HEIGHT: usize = 22;
+
+/// Horizontal bar chart; bars scale to the largest value in `rows`.
+fn html_bar_chart(out: &mut String, title: &str, rows: &[(String, f64, String)]) -> Result<()> {
+    if rows.is_empty() {
+        writeln!(out, "<p>No data.</p>")?;
+        return Ok(());
+    }
+    let max = rows
+        .iter()
+        .map(|(_, value, _)| *value)
+        .fold(0.0_f64, f64::max);
+    let width = CHART_LABEL_WIDTH + CHART_BAR_WIDTH + 80;
+    let height = rows.len() * CHART_ROW_HEIGHT + 4;
+    writeln!(
+        out,
+        "<svg role=\"img\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\
+         <title>{}</title>",
+        xml_escape_text(title)
+    )?;
+    for (index, (label, value, display)) in rows.iter().enumerate() {
+        let y = index * CHART_ROW_HEIGHT;
+        let bar = if max > 0.0 {
+            (value / max * CHART_BAR_WIDTH as f64).round() as usize
+        } else {
+            0
+        };
+        writeln!(
+            out,
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"end\">{}</text>\
+             <rect x=\"{CHART_LABEL_WIDTH}\" y=\"{}\" width=\"{bar}\" height=\"{}\" fill=\"#3e7cb1\"/>\
+             <text x=\"{}\" y=\"{}\">{}</text>",
+            CHART_LABEL_WIDTH - 6,
+            y + 15,
+            xml_escape_text(label),
+            y + 4,
+            CHART_ROW_HEIGHT - 6,
+            CHART_LABEL_WIDTH + bar + 6,
+            y + 15,
+            xml_escape_text(display)
+        )?;
+    }
+    writeln!(out, "</svg>")?;
+    Ok(())
+}
+
+fn format_date(epoch: i64) -> String {
+    chrono::DateTime::from_timestamp(epoch, 0)
+        .map(|dt| dt.format("%Y-%m-%d").to_string())
+        .unwrap_or_default()
+}
@@ -10,15 +10,25 @@ use super::types::{MonthRow, Overview, RecentObservation, TokenEcon, TypeCount};
 
 #[derive(Debug, Serialize)]
 pub(crate) struct TimelineReportData {
-    project: String,
-    full: bool,
-    overview: Overview,
-    activity_by_type: Vec<TypeCount>,
-    token_economics: TokenEcon,
+    pub(super) project: String,
+    pub(super) full: bool,
+    pub(super) overview: Overview,
+    pub(super) activity_by_type: Vec<TypeCount>,
+    pub(super) token_economics: TokenEcon,
     #[serde(skip_serializing_if = "Option::is_none")]
-    recent_timeline: Option<Vec<RecentObservation>>,
+    pub(super) recent_timeline: Option<Vec<RecentObservation>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    monthly_breakdown: Option<Vec<MonthRow>>,
+    pub(super) monthly_breakdown: Option<Vec<MonthRow>>,
+}
+
+impl TimelineReportData {
+    /// Rewrites every observation title, e.g. to redact it before the report
+    /// leaves the process.
+    pub(crate) fn map_titles(&mut self, map: impl Fn(&str) -> String) {
+        for observation in self.recent_timeline.iter_mut().flatten() {
+            observation.title = observation.title.as_deref().map(&map);
+        }
+    }
 }
 
 fn render_recent_timeline(out: &mut String, recent: &[RecentObservation]) -> Result<()> {
@@ -89,7 +99,11 @@ pub(crate) fn generate_timeline_report_data(
 }
 
 pub fn generate_timeline_report(conn: &Connection, project: &str, full: bool) -> Result<String> {
-    let report = generate_timeline_report_data(conn, project, full)?;
+    render_markdown(&generate_timeline_report_data(conn, project, full)?)
+}
+
+pub(super) fn render_markdown(report: &TimelineReportData) -> Result<String> {
+    let project = &report.project;
     let mut out = String::with_capacity(4096);
     writeln!(out, "# Journey Into {}\n", project)?;
 
@@ -136,11 +150,10 @@ pub fn generate_timeline_report(conn: &Connection, project: &str, full: bool) ->
         "- Sessions with context injection: {}",
         report.token_economics.sessions_with_context
     )?;
-    let recall_savings = report.token_economics.sessions_with_context * 300;
     writeln!(
         out,
         "- Estimated passive recall savings: ~{}K tokens\n",
-        recall_savings
+        report.token_economics.estimated_recall_savings_tokens / 1000
     )?;
 
     if let Some(recent) = report.recent_timeline.as_deref() {
@@ -6,6 +6,9 @@ use crate::db::to_sql_refs;
 
 use super::types::{Overview, TokenEcon, TypeCount};
 
+/// Rough tokens a session avoids re-discovering when context was injected.
+const RECALL_SAVINGS_TOKENS_PER_SESSION: i64 = 300_000;
+
 pub(super) fn query_overview(conn: &Connection, project: &str) -> Result<Overview> {
     let mut p: Vec<Box<dyn rusqlite::types::ToSql>> = Vec::new();
     let (pf, _) = push_project_filter("project", project, 1, &mut p);
@@ -136,9 +139,15 @@ pub(super) fn query_token_economics(conn: &Connection, project: &str) -> Result<
             anyhow::anyhow!("timeline token-economics context-session query failed: {error}")
         })?;
 
+    let estimated_recall_savings_tokens = sessions_with_context * RECALL_SAVINGS_TOKENS_PER_SESSION;
+    let recall_tokens_per_usd =
+        (total_ai_cost > 0.0).then(|| estimated_recall_savings_tokens as f64 / total_ai_cost);
+
     Ok(TokenEcon {
         total_ai_cost,
         total_discovery_tokens,
         sessions_with_context,
+        estimated_recall_savings_tokens,
+        recall_tokens_per_usd,
     })
 }
@@ -1,6 +1,9 @@
 use rusqlite::{params, Connection};
 
-use super::{generate_timeline_report, generate_timeline_report_data};
+use super::{
+    generate_timeline_report, generate_timeline_report_data, render_timeline_report,
+    TimelineReportFormat,
+};
 
 fn setup_test_db(conn: &Connection) {
     conn.execute_batch(
@@ -186,3 +189,70 @@ fn full_report_includes_timeline_and_monthly() {
     assert!(report.contains("Total sessions: 1"));
     assert!(report.contains("Total memories: 1"));
 }
+
+#[test]
+fn csv_export_quotes_cells_and_defuses_formulas() -> anyhow::Result<()> {
+    let conn = Connection::open_in_memory()?;
+    setup_test_db(&conn);
+    let now = chrono::Utc::now().timestamp();
+    conn.execute(
+        "INSERT INTO observations (memory_session_id, project, type, title, created_at_epoch, discovery_tokens) \
+         VALUES ('s1', 'tools/remem', 'decision', '=HYPERLINK(\"x\"), then ship', ?1, 100)",
+        params![now],
+    )?;
+    conn.execute(
+        "INSERT INTO session_summaries (memory_session_id, project, request, created_at, created_at_epoch) \
+         VALUES ('s1', 'tools/remem', 'ship', '2026-03-19', ?1)",
+        params![now],
+    )?;
+
+    let report = generate_timeline_report_data(&conn, "tools/remem", true)?;
+    let csv = render_timeline_report(&report, TimelineReportFormat::Csv)?;
+
+    let mut lines = csv.lines();
+    assert_eq!(lines.next(), Some("section,key,metric,value"));
+    assert!(csv.contains("overview,tools/remem,total_observations,1\n"));
+    assert!(csv.contains("activity_by_type,decision,count,1\n"));
+    assert!(csv.contains("token_roi,tools/remem,estimated_recall_savings_tokens,300000\n"));
+    assert!(csv.contains("token_roi,tools/remem,recall_tokens_per_usd,\n"));
+    assert!(csv.contains(",title,\"'=HYPERLINK(\"\"x\"\"), then ship\"\n"));
+    assert!(csv.contains("\nmonthly,"));
+    Ok(())
+}
+
+#[test]
+fn html_export_is_self_contained_and_escapes_titles() -> anyhow::Result<()> {
+    let conn = Connection::open_in_memory()?;
+    setup_test_db(&conn);
+    let now = chrono::Utc::now().timestamp();
+    conn.execute(
+        "INSERT INTO observations (memory_session_id, project, type, title, created_at_epoch, discovery_tokens) \
+         VALUES ('s1', 'tools/remem', 'bugfix', '<script>alert(1)</script>', ?1, 100)",
+        params![now],
+    )?;
+
+    let report = generate_timeline_report_data(&conn, "tools/remem", true)?;
+    let html = render_timeline_report(&report, TimelineReportFormat::Html)?;
+
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<h1>Journey Into tools/remem</h1>"));
+    assert!(html.contains("<title>Observations by type</title>"));
+    assert!(html.contains("<h2>Token ROI</h2>"));
+    assert!(html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+    assert!(!html.contains("<script"));
+    assert!(!html.contains("http://") && !html.contains("https://"));
+    Ok(())
+}
+
+#[test]
+fn markdown_format_matches_the_mcp_report() -> anyhow::Result<()> {
+    let conn = Connection::open_in_memory()?;
+    setup_test_db(&conn);
+
+    let report = generate_timeline_report_data(&conn, "tools/remem", true)?;
+    assert_eq!(
+        render_timeline_report(&report, TimelineReportFormat::Markdown)?,
+        generate_timeline_report(&conn, "tools/remem", true)?
+    );
+    Ok(())
+}
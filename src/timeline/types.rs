@@ -29,6 +29,9 @@ pub(super) struct TokenEcon {
     pub total_ai_cost: f64,
     pub total_discovery_tokens: i64,
     pub sessions_with_context: i64,
+    pub estimated_recall_savings_tokens: i64,
+    /// Estimated recall savings per dollar of AI spend; `None` before any spend.
+    pub recall_tokens_per_usd: Option<f64>,
 }
 
 #[derive(Debug, Serialize)]
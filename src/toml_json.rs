@@ -0,0 +1,80 @@
+//! Convert `toml_edit` documents into `serde_json::Value` so TOML files can
+//! reuse the serde types that already define the equivalent JSON shape.
+
+use anyhow::{anyhow, bail, Result};
+use serde_json::{Map, Number, Value};
+use toml_edit::{Item, Table};
+
+pub(crate) fn table_to_json(table: &Table) -> Result<Map<String, Value>> {
+    let mut object = Map::new();
+    for (key, item) in table.iter() {
+        if let Some(value) = item_to_json(item).map_err(|err| anyhow!("'{key}': {err}"))? {
+            object.insert(key.to_string(), value);
+        }
+    }
+    Ok(object)
+}
+
+fn item_to_json(item: &Item) -> Result<Option<Value>> {
+    Ok(match item {
+        Item::None => None,
+        Item::Value(value) => Some(value_to_json(value)?),
+        Item::Table(table) => Some(Value::Object(table_to_json(table)?)),
+        Item::ArrayOfTables(tables) => Some(Value::Array(
+            tables
+                .iter()
+                .map(|table| table_to_json(table).map(Value::Object))
+                .collect::<Result<_>>()?,
+        )),
+    })
+}
+
+pub(crate) fn value_to_json(value: &toml_edit::Value) -> Result<Value> {
+    Ok(match value {
+        toml_edit::Value::String(text) => Value::String(text.value().clone()),
+        toml_edit::Value::Integer(number) => Value::Number(Number::from(*number.value())),
+        toml_edit::Value::Float(number) => Number::from_f64(*number.value())
+            .map(Value::Number)
+            .ok_or_else(|| anyhow!("non-finite float"))?,
+        toml_edit::Value::Boolean(flag) => Value::Bool(*flag.value()),
+        toml_edit::Value::Array(array) => {
+            Value::Array(array.iter().map(value_to_json).collect::<Result<_>>()?)
+        }
+        toml_edit::Value::InlineTable(table) => Value::Object(
+            table
+                .iter()
+                .map(|(key, value)| Ok((key.to_string(), value_to_json(value)?)))
+                .collect::<Result<_>>()?,
+        ),
+        toml_edit::Value::Datetime(_) => bail!("datetimes are not supported"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_nested_tables_arrays_and_scalars() -> Result<()> {
+        let doc =
+            "name = \"x\"\nlist = [1, 2.5, true]\n[paths]\ncwd = \"/cwd\"\n[[items]]\nid = 1\n"
+                .parse::<toml_edit::DocumentMut>()?;
+
+        assert_eq!(
+            Value::Object(table_to_json(doc.as_table())?),
+            serde_json::json!({
+                "name": "x",
+                "list": [1, 2.5, true],
+                "paths": {"cwd": "/cwd"},
+                "items": [{"id": 1}],
+            })
+        );
+        assert!(table_to_json(
+            "when = 1979-05-27"
+                .parse::<toml_edit::DocumentMut>()?
+                .as_table()
+        )
+        .is_err());
+        Ok(())
+    }
+}
@@ -26,6 +26,8 @@ const EXTRACTION_TASK_TIMEOUT_SECS: u64 = JOB_TIMEOUT_SECS;
 const EMBEDDING_BACKFILL_IDLE_BATCH_SIZE: i64 = 128;
 const RULE_COMPILATION_SWEEP_INTERVAL_SECS: u64 = 60;
 const RETRIEVAL_ENRICHMENT_INTERVAL: Duration = Duration::from_secs(60);
+const ANCHOR_REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+const ANCHOR_REFRESH_BATCH_SIZE: usize = 64;
 const ONCE_WORKER_MAX_WORK_ITEMS: usize = 4;
 const ONCE_WORKER_MAX_ELAPSED: Duration = Duration::from_secs(180);
 
@@ -112,6 +114,19 @@ fn record_worker_heartbeat(
     )
 }
 
+/// Re-resolve memory code anchors against moved HEADs so staleness labels
+/// stay current without waiting for `remem why`. Failures only log.
+fn run_idle_anchor_refresh(conn: &rusqlite::Connection) {
+    match crate::memory::anchors::refresh_outdated_anchors(conn, ANCHOR_REFRESH_BATCH_SIZE) {
+        Ok(0) => {}
+        Ok(refreshed) => crate::log::info(
+            "worker",
+            &format!("re-resolved {refreshed} memory anchor(s)"),
+        ),
+        Err(error) => crate::log::warn("worker", &format!("anchor refresh failed: {error:#}")),
+    }
+}
+
 fn run_idle_embedding_backfill(conn: &rusqlite::Connection) -> Result<bool> {
     match crate::retrieval::vector::reindex_memory_embeddings_with_report(
         conn,
@@ -330,6 +345,8 @@ pub async fn run(once: bool, idle_sleep_ms: u64) -> Result<()> {
     let mut run_budget = WorkerRunBudget::new(once, Instant::now());
     let mut cleanup_probe_schedule =
         admission::IntervalAdmission::new(once, Instant::now(), cleanup::CLEANUP_PROBE_INTERVAL);
+    let mut anchor_refresh_schedule =
+        admission::IntervalAdmission::new(once, Instant::now(), ANCHOR_REFRESH_INTERVAL);
     let mut next_rule_compilation_sweep_at = Instant::now();
     loop {
         if Instant::now() >= next_rule_compilation_sweep_at {
@@ -493,6 +510,20 @@ pub async fn run(once: bool, idle_sleep_ms: u64) -> Result<()> {
                     job.max_attempts
                 ),
             );
+            if matches!(job.job_type, db::JobType::Compress | db::JobType::Dream) {
+                let profile = job::job_profile(&job.payload_json);
+                if let Some(budget) = crate::ai::budget::admission_block(
+                    &conn,
+                    &job.project,
+                    &job.host,
+                    profile.as_deref(),
+                )? {
+                    let reason = budget.deferral_reason();
+                    db::defer_job(&conn, job.id, &lease_owner, &reason, budget.resets_at_epoch)?;
+                    crate::log::warn("worker", &format!("job id={} {}", job.id, reason));
+                    continue;
+                }
+            }
 
             let timed = tokio::time::timeout(
                 Duration::from_secs(JOB_TIMEOUT_SECS),
@@ -555,6 +586,11 @@ pub async fn run(once: bool, idle_sleep_ms: u64) -> Result<()> {
             continue;
         }
 
+        if anchor_refresh_schedule.is_due(Instant::now()) {
+            run_idle_anchor_refresh(&conn);
+            anchor_refresh_schedule.record_attempt(Instant::now());
+        }
+
         if once {
             break;
         }
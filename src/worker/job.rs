@@ -49,7 +49,7 @@ pub(super) async fn process_job(job: &db::Job) -> Result<()> {
     }
 }
 
-fn job_profile(payload_json: &str) -> Option<String> {
+pub(super) fn job_profile(payload_json: &str) -> Option<String> {
     serde_json::from_str::<serde_json::Value>(payload_json)
         .ok()
         .and_then(|value| {
@@ -2,6 +2,7 @@ mod identity;
 mod lifecycle;
 mod matcher;
 mod merge;
+mod plan;
 mod query;
 #[cfg(test)]
 mod tests;
@@ -17,8 +18,13 @@ pub use lifecycle::{
 };
 pub use matcher::find_matching_workstream;
 pub use merge::merge_workstreams_manual;
-pub use query::{query_active_workstreams, query_workstreams};
+pub use plan::{
+    create_workstream, critical_path, update_workstream_plan, update_workstream_plan_and_fields,
+    NewWorkStream, WorkStreamPlanError, WorkStreamPlanUpdate,
+};
+pub use query::{load_workstream, query_active_workstreams, query_workstreams};
 pub use types::{
-    ParsedWorkStream, WorkStream, WorkStreamMergeResult, WorkStreamStatus, WorkStreamUpsertResult,
+    ParsedWorkStream, WorkStream, WorkStreamDependency, WorkStreamDependencyKind,
+    WorkStreamMergeResult, WorkStreamMilestone, WorkStreamStatus, WorkStreamUpsertResult,
 };
 pub use write::{update_workstream_manual, upsert_workstream, upsert_workstream_with_match};
@@ -137,6 +137,7 @@ pub fn merge_workstreams_manual(
             "DELETE FROM workstream_sessions WHERE workstream_id = ?1",
             params![duplicate_id],
         )?;
+        move_plan_rows(&tx, canonical_id, *duplicate_id)?;
         tx.execute(
             "UPDATE workstreams
                 SET merged_into_workstream_id = ?1,
@@ -162,6 +163,50 @@ pub fn merge_workstreams_manual(
     })
 }
 
+/// Re-homes children, milestones and dependency edges of a merged duplicate.
+/// Edges that would point the canonical row at itself are dropped.
+fn move_plan_rows(conn: &Connection, canonical_id: i64, duplicate_id: i64) -> Result<()> {
+    conn.execute(
+        "UPDATE workstreams SET parent_workstream_id = NULL
+         WHERE id = ?1 AND parent_workstream_id = ?2",
+        params![canonical_id, duplicate_id],
+    )?;
+    conn.execute(
+        "UPDATE workstreams SET parent_workstream_id = ?1 WHERE parent_workstream_id = ?2",
+        params![canonical_id, duplicate_id],
+    )?;
+    conn.execute(
+        "UPDATE workstream_milestones
+            SET workstream_id = ?1,
+                position = position + (SELECT COALESCE(MAX(position), 0)
+                                         FROM workstream_milestones WHERE workstream_id = ?1)
+          WHERE workstream_id = ?2",
+        params![canonical_id, duplicate_id],
+    )?;
+    conn.execute(
+        "INSERT OR IGNORE INTO workstream_dependencies
+         (workstream_id, depends_on_workstream_id, kind, created_at_epoch)
+         SELECT ?1, depends_on_workstream_id, kind, created_at_epoch
+           FROM workstream_dependencies
+          WHERE workstream_id = ?2 AND depends_on_workstream_id <> ?1",
+        params![canonical_id, duplicate_id],
+    )?;
+    conn.execute(
+        "INSERT OR IGNORE INTO workstream_dependencies
+         (workstream_id, depends_on_workstream_id, kind, created_at_epoch)
+         SELECT workstream_id, ?1, kind, created_at_epoch
+           FROM workstream_dependencies
+          WHERE depends_on_workstream_id = ?2 AND workstream_id <> ?1",
+        params![canonical_id, duplicate_id],
+    )?;
+    conn.execute(
+        "DELETE FROM workstream_dependencies
+          WHERE workstream_id = ?1 OR depends_on_workstream_id = ?1",
+        params![duplicate_id],
+    )?;
+    Ok(())
+}
+
 #[derive(Debug)]
 struct MergeRow {
     title: String,
@@ -0,0 +1,477 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use rusqlite::{params, Connection, OptionalExtension};
+
+use super::{
+    identity::{ensure_workstream_alias, workstream_identity_key},
+    write::update_workstream_manual,
+    WorkStream, WorkStreamDependency, WorkStreamDependencyKind, WorkStreamMilestone,
+    WorkStreamStatus,
+};
+
+/// Upper bound on parent-chain walks; a deeper chain is treated as corrupt.
+const MAX_PARENT_DEPTH: usize = 64;
+
+/// Rejected plan edits: unknown or cross-project targets, cycles, empty
+/// titles, and status changes blocked by open hard dependencies. Callers map
+/// this to a client error rather than a database one.
+#[derive(Debug)]
+pub struct WorkStreamPlanError {
+    message: String,
+}
+
+impl WorkStreamPlanError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for WorkStreamPlanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for WorkStreamPlanError {}
+
+#[derive(Debug, Clone, Default)]
+pub struct NewWorkStream<'a> {
+    pub project: &'a str,
+    pub title: &'a str,
+    pub description: Option<&'a str>,
+    pub next_action: Option<&'a str>,
+    pub parent_id: Option<i64>,
+    pub dependencies: &'a [(i64, WorkStreamDependencyKind)],
+    pub milestones: &'a [String],
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct WorkStreamPlanUpdate<'a> {
+    /// `Some(None)` detaches the workstream from its parent.
+    pub parent_id: Option<Option<i64>>,
+    pub add_dependencies: &'a [(i64, WorkStreamDependencyKind)],
+    pub remove_dependencies: &'a [i64],
+    pub add_milestones: &'a [String],
+    pub complete_milestones: &'a [i64],
+}
+
+impl WorkStreamPlanUpdate<'_> {
+    pub fn is_empty(&self) -> bool {
+        self.parent_id.is_none()
+            && self.add_dependencies.is_empty()
+            && self.remove_dependencies.is_empty()
+            && self.add_milestones.is_empty()
+            && self.complete_milestones.is_empty()
+    }
+}
+
+/// Creates an active workstream together with its parent link, dependencies
+/// and milestones in one transaction.
+pub fn create_workstream(conn: &Connection, new: &NewWorkStream<'_>) -> Result<i64> {
+    let project = new.project.trim();
+    let title = new.title.trim();
+    if project.is_empty() {
+        return Err(WorkStreamPlanError::new("workstream project is required").into());
+    }
+    if title.is_empty() {
+        return Err(WorkStreamPlanError::new("workstream title is required").into());
+    }
+
+    let tx = conn.unchecked_transaction()?;
+    let now = chrono::Utc::now().timestamp();
+    if let Some(parent_id) = new.parent_id {
+        require_same_project(&tx, project, parent_id, "parent")?;
+    }
+    tx.execute(
+        "INSERT INTO workstreams
+         (project, title, description, status, next_action, created_at_epoch, updated_at_epoch,
+          source_project, target_project, owner_scope, owner_key, context_class,
+          parent_workstream_id)
+         VALUES (?1, ?2, ?3, 'active', ?4, ?5, ?5, ?1, ?1, 'repo', ?1, 'startup_core', ?6)",
+        params![
+            project,
+            title,
+            new.description,
+            new.next_action,
+            now,
+            new.parent_id,
+        ],
+    )?;
+    let id = tx.last_insert_rowid();
+    tx.execute(
+        "UPDATE workstreams SET identity_key = ?1 WHERE id = ?2",
+        params![workstream_identity_key(project, "manual", now, id), id],
+    )?;
+    ensure_workstream_alias(&tx, id, title, "manual_create", None, None, now)?;
+    for (depends_on_id, kind) in new.dependencies {
+        add_dependency(&tx, project, id, *depends_on_id, *kind, now)?;
+    }
+    add_milestones(&tx, id, new.milestones, now)?;
+    tx.commit()?;
+    Ok(id)
+}
+
+/// Applies structural edits to an existing workstream. Returns `false` when
+/// no visible workstream has `id`.
+pub fn update_workstream_plan(
+    conn: &Connection,
+    id: i64,
+    update: &WorkStreamPlanUpdate<'_>,
+) -> Result<bool> {
+    let tx = conn.unchecked_transaction()?;
+    let updated = apply_plan(&tx, id, update)?;
+    tx.commit()?;
+    Ok(updated)
+}
+
+/// Applies plan edits and the manual status/next-action/blockers fields in
+/// one transaction, so a status change is checked against the dependencies
+/// the same call adds or removes and a rejected edit leaves nothing behind.
+/// Returns `false` when no visible workstream has `id`.
+pub fn update_workstream_plan_and_fields(
+    conn: &Connection,
+    id: i64,
+    update: &WorkStreamPlanUpdate<'_>,
+    status: Option<&str>,
+    next_action: Option<&str>,
+    blockers: Option<&str>,
+) -> Result<bool> {
+    let tx = conn.unchecked_transaction()?;
+    if !update.is_empty() && !apply_plan(&tx, id, update)? {
+        return Ok(false);
+    }
+    let has_field_update = status.is_some() || next_action.is_some() || blockers.is_some();
+    let updated = if has_field_update {
+        update_workstream_manual(&tx, id, status, next_action, blockers)?
+    } else {
+        !update.is_empty()
+    };
+    tx.commit()?;
+    Ok(updated)
+}
+
+/// Hard dependencies block their workstream until the target completes, so
+/// it can neither be resumed nor completed while one is still open.
+pub(super) fn require_hard_dependencies_met(
+    conn: &Connection,
+    id: i64,
+    status: WorkStreamStatus,
+) -> Result<()> {
+    if !matches!(
+        status,
+        WorkStreamStatus::Active | WorkStreamStatus::Completed
+    ) {
+        return Ok(());
+    }
+    let mut stmt = conn.prepare_cached(
+        "SELECT d.depends_on_workstream_id
+         FROM workstream_dependencies d
+         JOIN workstreams t ON t.id = d.depends_on_workstream_id
+         WHERE d.workstream_id = ?1 AND d.kind = 'hard' AND t.status <> 'completed'
+         ORDER BY d.depends_on_workstream_id",
+    )?;
+    let open = stmt
+        .query_map(params![id], |row| row.get::<_, i64>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    if open.is_empty() {
+        return Ok(());
+    }
+    let open = open
+        .iter()
+        .map(i64::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    Err(WorkStreamPlanError::new(format!(
+        "workstream {id} cannot become {} while hard dependencies are open: {open}",
+        status.as_str()
+    ))
+    .into())
+}
+
+fn apply_plan(conn: &Connection, id: i64, update: &WorkStreamPlanUpdate<'_>) -> Result<bool> {
+    let Some(project) = workstream_project(conn, id)? else {
+        return Ok(false);
+    };
+    let now = chrono::Utc::now().timestamp();
+
+    if let Some(parent_id) = update.parent_id {
+        if let Some(parent_id) = parent_id {
+            require_same_project(conn, &project, parent_id, "parent")?;
+            if parent_id == id || ancestors(conn, parent_id)?.contains(&id) {
+                return Err(WorkStreamPlanError::new(format!(
+                    "workstream {parent_id} cannot become the parent of {id}: it would create a cycle"
+                ))
+                .into());
+            }
+        }
+        conn.execute(
+            "UPDATE workstreams SET parent_workstream_id = ?1 WHERE id = ?2",
+            params![parent_id, id],
+        )?;
+    }
+    for depends_on_id in update.remove_dependencies {
+        conn.execute(
+            "DELETE FROM workstream_dependencies
+             WHERE workstream_id = ?1 AND depends_on_workstream_id = ?2",
+            params![id, depends_on_id],
+        )?;
+    }
+    for (depends_on_id, kind) in update.add_dependencies {
+        add_dependency(conn, &project, id, *depends_on_id, *kind, now)?;
+    }
+    add_milestones(conn, id, update.add_milestones, now)?;
+    for milestone_id in update.complete_milestones {
+        let exists = conn
+            .query_row(
+                "SELECT 1 FROM workstream_milestones WHERE id = ?1 AND workstream_id = ?2",
+                params![milestone_id, id],
+                |_| Ok(()),
+            )
+            .optional()?;
+        if exists.is_none() {
+            return Err(WorkStreamPlanError::new(format!(
+                "milestone {milestone_id} does not belong to workstream {id}"
+            ))
+            .into());
+        }
+        conn.execute(
+            "UPDATE workstream_milestones
+             SET completed_at_epoch = COALESCE(completed_at_epoch, ?1)
+             WHERE id = ?2",
+            params![now, milestone_id],
+        )?;
+    }
+    conn.execute(
+        "UPDATE workstreams SET updated_at_epoch = ?1 WHERE id = ?2",
+        params![now, id],
+    )?;
+    Ok(true)
+}
+
+/// Fills `parent_id`, `dependencies` and `milestones` for rows loaded by the
+/// flat workstream queries.
+pub(super) fn attach_plans(conn: &Connection, workstreams: &mut [WorkStream]) -> Result<()> {
+    if workstreams.is_empty() {
+        return Ok(());
+    }
+    let mut parent_stmt =
+        conn.prepare("SELECT parent_workstream_id FROM workstreams WHERE id = ?1")?;
+    let mut dependency_stmt = conn.prepare(
+        "SELECT d.depends_on_workstream_id, d.kind, t.status
+         FROM workstream_dependencies d
+         JOIN workstreams t ON t.id = d.depends_on_workstream_id
+         WHERE d.workstream_id = ?1
+         ORDER BY d.depends_on_workstream_id",
+    )?;
+    let mut milestone_stmt = conn.prepare(
+        "SELECT id, title, position, completed_at_epoch
+         FROM workstream_milestones
+         WHERE workstream_id = ?1
+         ORDER BY position, id",
+    )?;
+    for workstream in workstreams.iter_mut() {
+        workstream.parent_id = parent_stmt
+            .query_row(params![workstream.id], |row| row.get(0))
+            .optional()?
+            .flatten();
+        let dependencies = dependency_stmt.query_map(params![workstream.id], |row| {
+            let kind: String = row.get(1)?;
+            let status: String = row.get(2)?;
+            Ok(WorkStreamDependency {
+                depends_on_id: row.get(0)?,
+                kind: WorkStreamDependencyKind::parse(&kind)
+                    .unwrap_or(WorkStreamDependencyKind::Hard),
+                satisfied: status == "completed",
+            })
+        })?;
+        workstream.dependencies = crate::db::query::collect_rows(dependencies)?;
+        let milestones = milestone_stmt.query_map(params![workstream.id], |row| {
+            Ok(WorkStreamMilestone {
+                id: row.get(0)?,
+                title: row.get(1)?,
+                position: row.get(2)?,
+                completed_at_epoch: row.get(3)?,
+            })
+        })?;
+        workstream.milestones = crate::db::query::collect_rows(milestones)?;
+    }
+    Ok(())
+}
+
+/// Longest chain of unsatisfied hard dependencies among `workstreams`, in the
+/// order the work has to land. Empty when nothing in the set blocks anything
+/// else in the set.
+pub fn critical_path(workstreams: &[WorkStream]) -> Vec<i64> {
+    let by_id: HashMap<i64, &WorkStream> = workstreams.iter().map(|ws| (ws.id, ws)).collect();
+    let mut memo: HashMap<i64, Vec<i64>> = HashMap::new();
+    let mut best: Vec<i64> = Vec::new();
+    for workstream in workstreams {
+        let chain = longest_chain(workstream.id, &by_id, &mut memo, &mut HashSet::new());
+        if chain.len() > best.len() {
+            best = chain;
+        }
+    }
+    if best.len() < 2 {
+        return Vec::new();
+    }
+    best.reverse();
+    best
+}
+
+fn longest_chain(
+    id: i64,
+    by_id: &HashMap<i64, &WorkStream>,
+    memo: &mut HashMap<i64, Vec<i64>>,
+    visiting: &mut HashSet<i64>,
+) -> Vec<i64> {
+    if let Some(chain) = memo.get(&id) {
+        return chain.clone();
+    }
+    if !visiting.insert(id) {
+        return Vec::new();
+    }
+    let mut tail: Vec<i64> = Vec::new();
+    if let Some(workstream) = by_id.get(&id) {
+        for dependency in &workstream.dependencies {
+            if dependency.satisfied
+                || dependency.kind != WorkStreamDependencyKind::Hard
+                || !by_id.contains_key(&dependency.depends_on_id)
+            {
+                continue;
+            }
+            let chain = longest_chain(dependency.depends_on_id, by_id, memo, visiting);
+            if chain.len() > tail.len() {
+                tail = chain;
+            }
+        }
+    }
+    visiting.remove(&id);
+    let mut chain = vec![id];
+    chain.extend(tail);
+    memo.insert(id, chain.clone());
+    chain
+}
+
+fn add_dependency(
+    conn: &Connection,
+    project: &str,
+    id: i64,
+    depends_on_id: i64,
+    kind: WorkStreamDependencyKind,
+    now: i64,
+) -> Result<()> {
+    if depends_on_id == id {
+        return Err(
+            WorkStreamPlanError::new(format!("workstream {id} cannot depend on itself")).into(),
+        );
+    }
+    require_same_project(conn, project, depends_on_id, "dependency")?;
+    if depends_on_transitively(conn, depends_on_id, id)? {
+        return Err(WorkStreamPlanError::new(format!(
+            "workstream {id} cannot depend on {depends_on_id}: {depends_on_id} already depends on {id}"
+        ))
+        .into());
+    }
+    conn.execute(
+        "INSERT INTO workstream_dependencies
+         (workstream_id, depends_on_workstream_id, kind, created_at_epoch)
+         VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(workstream_id, depends_on_workstream_id) DO UPDATE SET kind = excluded.kind",
+        params![id, depends_on_id, kind.as_str(), now],
+    )?;
+    Ok(())
+}
+
+fn add_milestones(conn: &Connection, id: i64, titles: &[String], now: i64) -> Result<()> {
+    if titles.is_empty() {
+        return Ok(());
+    }
+    let mut position: i64 = conn.query_row(
+        "SELECT COALESCE(MAX(position), 0) FROM workstream_milestones WHERE workstream_id = ?1",
+        params![id],
+        |row| row.get(0),
+    )?;
+    for title in titles {
+        let title = title.trim();
+        if title.is_empty() {
+            return Err(WorkStreamPlanError::new("milestone title must not be empty").into());
+        }
+        position += 1;
+        conn.execute(
+            "INSERT INTO workstream_milestones
+             (workstream_id, title, position, created_at_epoch)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![id, title, position, now],
+        )?;
+    }
+    Ok(())
+}
+
+/// Project key used to keep parents and dependencies inside one project.
+fn workstream_project(conn: &Connection, id: i64) -> Result<Option<String>> {
+    conn.query_row(
+        "SELECT COALESCE(target_project, project)
+         FROM workstreams
+         WHERE id = ?1 AND merged_into_workstream_id IS NULL",
+        params![id],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(Into::into)
+}
+
+fn require_same_project(conn: &Connection, project: &str, id: i64, role: &str) -> Result<()> {
+    match workstream_project(conn, id)? {
+        Some(target) if target == project => Ok(()),
+        Some(_) => Err(WorkStreamPlanError::new(format!(
+            "{role} workstream {id} belongs to a different project"
+        ))
+        .into()),
+        None => Err(WorkStreamPlanError::new(format!("{role} workstream {id} not found")).into()),
+    }
+}
+
+fn ancestors(conn: &Connection, id: i64) -> Result<Vec<i64>> {
+    let mut out = Vec::new();
+    let mut current = id;
+    for _ in 0..MAX_PARENT_DEPTH {
+        let parent: Option<i64> = conn
+            .query_row(
+                "SELECT parent_workstream_id FROM workstreams WHERE id = ?1",
+                params![current],
+                |row| row.get(0),
+            )
+            .optional()?
+            .flatten();
+        match parent {
+            Some(parent) if !out.contains(&parent) => {
+                out.push(parent);
+                current = parent;
+            }
+            _ => return Ok(out),
+        }
+    }
+    Ok(out)
+}
+
+fn depends_on_transitively(conn: &Connection, from: i64, target: i64) -> Result<bool> {
+    let mut stmt = conn.prepare(
+        "SELECT depends_on_workstream_id FROM workstream_dependencies WHERE workstream_id = ?1",
+    )?;
+    let mut seen = HashSet::new();
+    let mut stack = vec![from];
+    while let Some(current) = stack.pop() {
+        if current == target {
+            return Ok(true);
+        }
+        if !seen.insert(current) {
+            continue;
+        }
+        let rows = stmt.query_map(params![current], |row| row.get::<_, i64>(0))?;
+        stack.extend(crate::db::query::collect_rows(rows)?);
+    }
+    Ok(false)
+}
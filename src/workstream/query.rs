@@ -1,7 +1,7 @@
 use anyhow::Result;
-use rusqlite::Connection;
+use rusqlite::{params, Connection, OptionalExtension};
 
-use super::{WorkStream, WorkStreamStatus};
+use super::{plan::attach_plans, WorkStream, WorkStreamStatus};
 
 const SELECT_FIELDS: &str =
     "SELECT id, project, title, description, status, progress, next_action, blockers,
@@ -51,7 +51,9 @@ pub fn query_active_workstreams(conn: &Connection, project: &str) -> Result<Vec<
     let mut stmt = conn.prepare(&sql)?;
     let refs = crate::db::to_sql_refs(&params_vec);
     let rows = stmt.query_map(refs.as_slice(), map_workstream_row)?;
-    crate::db::query::collect_rows(rows)
+    let mut workstreams = crate::db::query::collect_rows(rows)?;
+    attach_plans(conn, &mut workstreams)?;
+    Ok(workstreams)
 }
 
 pub fn query_workstreams(
@@ -80,7 +82,27 @@ pub fn query_workstreams(
     let mut stmt = conn.prepare(&sql)?;
     let refs = crate::db::to_sql_refs(&params_vec);
     let rows = stmt.query_map(refs.as_slice(), map_workstream_row)?;
-    crate::db::query::collect_rows(rows)
+    let mut workstreams = crate::db::query::collect_rows(rows)?;
+    attach_plans(conn, &mut workstreams)?;
+    Ok(workstreams)
+}
+
+/// One unmerged workstream with its parent, dependencies and milestones.
+pub fn load_workstream(conn: &Connection, id: i64) -> Result<Option<WorkStream>> {
+    let Some(workstream) = conn
+        .query_row(
+            &format!("{SELECT_FIELDS} WHERE id = ?1 AND merged_into_workstream_id IS NULL"),
+            params![id],
+            map_workstream_row,
+        )
+        .optional()?
+    else {
+        return Ok(None);
+    };
+    let mut rows = [workstream];
+    attach_plans(conn, &mut rows)?;
+    let [workstream] = rows;
+    Ok(Some(workstream))
 }
 
 pub(crate) fn map_workstream_row(row: &rusqlite::Row) -> rusqlite::Result<WorkStream> {
@@ -97,5 +119,8 @@ pub(crate) fn map_workstream_row(row: &rusqlite::Row) -> rusqlite::Result<WorkSt
         created_at_epoch: row.get(8)?,
         updated_at_epoch: row.get(9)?,
         completed_at_epoch: row.get(10)?,
+        parent_id: None,
+        dependencies: Vec::new(),
+        milestones: Vec::new(),
     })
 }
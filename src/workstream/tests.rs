@@ -1,4 +1,5 @@
 mod lifecycle;
+mod plan;
 mod query;
 mod support;
 mod write;
@@ -0,0 +1,359 @@
+use rusqlite::{params, Connection};
+
+use super::support::setup_workstream_schema;
+use crate::workstream::{
+    create_workstream, critical_path, load_workstream, merge_workstreams_manual,
+    query_active_workstreams, update_workstream_manual, update_workstream_plan,
+    update_workstream_plan_and_fields, NewWorkStream, WorkStreamDependencyKind,
+    WorkStreamPlanError, WorkStreamPlanUpdate, WorkStreamStatus,
+};
+
+fn create(conn: &Connection, project: &str, title: &str) -> i64 {
+    create_workstream(
+        conn,
+        &NewWorkStream {
+            project,
+            title,
+            ..NewWorkStream::default()
+        },
+    )
+    .unwrap()
+}
+
+#[test]
+fn create_workstream_records_parent_dependencies_and_milestones() {
+    let conn = Connection::open_in_memory().unwrap();
+    setup_workstream_schema(&conn);
+    let epic = create(&conn, "test/proj", "Storage rewrite");
+    let schema = create(&conn, "test/proj", "Schema migration");
+
+    let milestones = vec!["Draft".to_string(), "Review".to_string()];
+    let id = create_workstream(
+        &conn,
+        &NewWorkStream {
+            project: "test/proj",
+            title: "Backfill",
+            parent_id: Some(epic),
+            dependencies: &[(schema, WorkStreamDependencyKind::Hard)],
+            milestones: &milestones,
+            ..NewWorkStream::default()
+        },
+    )
+    .unwrap();
+
+    let backfill = load_workstream(&conn, id).unwrap().unwrap();
+    assert_eq!(backfill.parent_id, Some(epic));
+    assert_eq!(backfill.dependencies.len(), 1);
+    assert_eq!(backfill.dependencies[0].depends_on_id, schema);
+    assert!(!backfill.dependencies[0].satisfied);
+    let titles: Vec<&str> = backfill
+        .milestones
+        .iter()
+        .map(|m| m.title.as_str())
+        .collect();
+    assert_eq!(titles, ["Draft", "Review"]);
+
+    conn.execute(
+        "UPDATE workstreams SET status = 'completed' WHERE id = ?1",
+        params![schema],
+    )
+    .unwrap();
+    let listed = query_active_workstreams(&conn, "test/proj").unwrap();
+    let backfill = listed.iter().find(|ws| ws.id == id).unwrap();
+    assert!(backfill.dependencies[0].satisfied);
+}
+
+#[test]
+fn plan_updates_reject_cycles_and_cross_project_links() {
+    let conn = Connection::open_in_memory().unwrap();
+    setup_workstream_schema(&conn);
+    let first = create(&conn, "test/proj", "First");
+    let second = create(&conn, "test/proj", "Second");
+    let foreign = create(&conn, "other/proj", "Foreign");
+
+    let add = [(first, WorkStreamDependencyKind::Hard)];
+    assert!(update_workstream_plan(
+        &conn,
+        second,
+        &WorkStreamPlanUpdate {
+            add_dependencies: &add,
+            ..WorkStreamPlanUpdate::default()
+        },
+    )
+    .unwrap());
+
+    let back = [(second, WorkStreamDependencyKind::Soft)];
+    let err = update_workstream_plan(
+        &conn,
+        first,
+        &WorkStreamPlanUpdate {
+            add_dependencies: &back,
+            ..WorkStreamPlanUpdate::default()
+        },
+    )
+    .unwrap_err();
+    assert!(err.is::<WorkStreamPlanError>(), "{err}");
+
+    assert!(update_workstream_plan(
+        &conn,
+        second,
+        &WorkStreamPlanUpdate {
+            parent_id: Some(Some(first)),
+            ..WorkStreamPlanUpdate::default()
+        },
+    )
+    .unwrap());
+    let err = update_workstream_plan(
+        &conn,
+        first,
+        &WorkStreamPlanUpdate {
+            parent_id: Some(Some(second)),
+            ..WorkStreamPlanUpdate::default()
+        },
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("cycle"), "{err}");
+
+    let err = update_workstream_plan(
+        &conn,
+        first,
+        &WorkStreamPlanUpdate {
+            parent_id: Some(Some(foreign)),
+            ..WorkStreamPlanUpdate::default()
+        },
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("different project"), "{err}");
+
+    assert!(!update_workstream_plan(
+        &conn,
+        9_999,
+        &WorkStreamPlanUpdate {
+            parent_id: Some(None),
+            ..WorkStreamPlanUpdate::default()
+        },
+    )
+    .unwrap());
+}
+
+#[test]
+fn completing_a_milestone_stamps_it_once() {
+    let conn = Connection::open_in_memory().unwrap();
+    setup_workstream_schema(&conn);
+    let id = create(&conn, "test/proj", "Checklist");
+    let milestones = vec!["Only step".to_string()];
+    update_workstream_plan(
+        &conn,
+        id,
+        &WorkStreamPlanUpdate {
+            add_milestones: &milestones,
+            ..WorkStreamPlanUpdate::default()
+        },
+    )
+    .unwrap();
+    let milestone_id = load_workstream(&conn, id).unwrap().unwrap().milestones[0].id;
+    conn.execute(
+        "UPDATE workstream_milestones SET completed_at_epoch = 5 WHERE id = ?1",
+        params![milestone_id],
+    )
+    .unwrap();
+
+    update_workstream_plan(
+        &conn,
+        id,
+        &WorkStreamPlanUpdate {
+            complete_milestones: &[milestone_id],
+            ..WorkStreamPlanUpdate::default()
+        },
+    )
+    .unwrap();
+    let milestone = &load_workstream(&conn, id).unwrap().unwrap().milestones[0];
+    assert_eq!(milestone.completed_at_epoch, Some(5));
+
+    let err = update_workstream_plan(
+        &conn,
+        id,
+        &WorkStreamPlanUpdate {
+            complete_milestones: &[milestone_id + 100],
+            ..WorkStreamPlanUpdate::default()
+        },
+    )
+    .unwrap_err();
+    assert!(err.is::<WorkStreamPlanError>(), "{err}");
+}
+
+#[test]
+fn open_hard_dependency_blocks_resuming_and_completing() {
+    let conn = Connection::open_in_memory().unwrap();
+    setup_workstream_schema(&conn);
+    let schema = create(&conn, "test/proj", "Schema migration");
+    let docs = create(&conn, "test/proj", "Docs pass");
+    let backfill = create_workstream(
+        &conn,
+        &NewWorkStream {
+            project: "test/proj",
+            title: "Backfill",
+            dependencies: &[
+                (schema, WorkStreamDependencyKind::Hard),
+                (docs, WorkStreamDependencyKind::Soft),
+            ],
+            ..NewWorkStream::default()
+        },
+    )
+    .unwrap();
+    let status = |id| load_workstream(&conn, id).unwrap().unwrap().status;
+
+    for blocked in ["completed", "active"] {
+        let err = update_workstream_manual(&conn, backfill, Some(blocked), None, None).unwrap_err();
+        assert!(err.is::<WorkStreamPlanError>(), "{err}");
+        assert!(err.to_string().contains(&schema.to_string()), "{err}");
+    }
+    assert!(update_workstream_manual(&conn, backfill, Some("paused"), None, None).unwrap());
+    let err = update_workstream_plan_and_fields(
+        &conn,
+        backfill,
+        &WorkStreamPlanUpdate {
+            add_milestones: &["Verify".to_string()],
+            ..WorkStreamPlanUpdate::default()
+        },
+        Some("completed"),
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert!(err.is::<WorkStreamPlanError>(), "{err}");
+    let rejected = load_workstream(&conn, backfill).unwrap().unwrap();
+    assert_eq!(rejected.status, WorkStreamStatus::Paused);
+    assert!(
+        rejected.milestones.is_empty(),
+        "rejected update must roll back"
+    );
+
+    assert!(update_workstream_manual(&conn, schema, Some("completed"), None, None).unwrap());
+    assert!(update_workstream_manual(&conn, backfill, Some("completed"), None, None).unwrap());
+    assert_eq!(status(backfill), WorkStreamStatus::Completed);
+    assert_eq!(status(docs), WorkStreamStatus::Active);
+}
+
+#[test]
+fn removing_a_hard_dependency_unblocks_completion_in_the_same_update() {
+    let conn = Connection::open_in_memory().unwrap();
+    setup_workstream_schema(&conn);
+    let schema = create(&conn, "test/proj", "Schema migration");
+    let backfill = create_workstream(
+        &conn,
+        &NewWorkStream {
+            project: "test/proj",
+            title: "Backfill",
+            dependencies: &[(schema, WorkStreamDependencyKind::Hard)],
+            ..NewWorkStream::default()
+        },
+    )
+    .unwrap();
+
+    let updated = update_workstream_plan_and_fields(
+        &conn,
+        backfill,
+        &WorkStreamPlanUpdate {
+            remove_dependencies: &[schema],
+            ..WorkStreamPlanUpdate::default()
+        },
+        Some("completed"),
+        None,
+        None,
+    )
+    .unwrap();
+
+    assert!(updated);
+    let completed = load_workstream(&conn, backfill).unwrap().unwrap();
+    assert_eq!(completed.status, WorkStreamStatus::Completed);
+    assert!(completed.dependencies.is_empty());
+    assert!(!update_workstream_plan_and_fields(
+        &conn,
+        backfill + 100,
+        &WorkStreamPlanUpdate {
+            remove_dependencies: &[schema],
+            ..WorkStreamPlanUpdate::default()
+        },
+        Some("completed"),
+        None,
+        None,
+    )
+    .unwrap());
+}
+
+#[test]
+fn critical_path_follows_the_longest_open_hard_chain() {
+    let conn = Connection::open_in_memory().unwrap();
+    setup_workstream_schema(&conn);
+    let schema = create(&conn, "test/proj", "Schema");
+    let api = create(&conn, "test/proj", "API");
+    let launch = create(&conn, "test/proj", "Launch");
+    let docs = create(&conn, "test/proj", "Docs");
+    for (id, depends_on, kind) in [
+        (api, schema, WorkStreamDependencyKind::Hard),
+        (launch, api, WorkStreamDependencyKind::Hard),
+        (launch, docs, WorkStreamDependencyKind::Soft),
+    ] {
+        update_workstream_plan(
+            &conn,
+            id,
+            &WorkStreamPlanUpdate {
+                add_dependencies: &[(depends_on, kind)],
+                ..WorkStreamPlanUpdate::default()
+            },
+        )
+        .unwrap();
+    }
+
+    let active = query_active_workstreams(&conn, "test/proj").unwrap();
+    assert_eq!(critical_path(&active), vec![schema, api, launch]);
+
+    conn.execute(
+        "UPDATE workstreams SET status = 'completed' WHERE id IN (?1, ?2)",
+        params![schema, api],
+    )
+    .unwrap();
+    let active = query_active_workstreams(&conn, "test/proj").unwrap();
+    assert!(critical_path(&active).is_empty());
+}
+
+#[test]
+fn merge_moves_children_milestones_and_dependencies_to_canonical() {
+    let conn = Connection::open_in_memory().unwrap();
+    setup_workstream_schema(&conn);
+    let canonical = create(&conn, "test/proj", "Canonical");
+    let duplicate = create(&conn, "test/proj", "Duplicate");
+    let child = create(&conn, "test/proj", "Child");
+    let upstream = create(&conn, "test/proj", "Upstream");
+    let milestones = vec!["Carry over".to_string()];
+    update_workstream_plan(
+        &conn,
+        duplicate,
+        &WorkStreamPlanUpdate {
+            add_dependencies: &[(upstream, WorkStreamDependencyKind::Hard)],
+            add_milestones: &milestones,
+            ..WorkStreamPlanUpdate::default()
+        },
+    )
+    .unwrap();
+    update_workstream_plan(
+        &conn,
+        child,
+        &WorkStreamPlanUpdate {
+            parent_id: Some(Some(duplicate)),
+            add_dependencies: &[(duplicate, WorkStreamDependencyKind::Hard)],
+            ..WorkStreamPlanUpdate::default()
+        },
+    )
+    .unwrap();
+
+    merge_workstreams_manual(&conn, "test/proj", canonical, &[duplicate]).unwrap();
+
+    let canonical_row = load_workstream(&conn, canonical).unwrap().unwrap();
+    assert_eq!(canonical_row.dependencies[0].depends_on_id, upstream);
+    assert_eq!(canonical_row.milestones[0].title, "Carry over");
+    let child_row = load_workstream(&conn, child).unwrap().unwrap();
+    assert_eq!(child_row.parent_id, Some(canonical));
+    assert_eq!(child_row.dependencies[0].depends_on_id, canonical);
+}
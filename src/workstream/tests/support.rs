@@ -32,7 +32,8 @@ pub(super) fn setup_workstream_schema(conn: &Connection) {
             valid_from_epoch INTEGER,
             valid_to_epoch INTEGER,
             identity_key TEXT,
-            merged_into_workstream_id INTEGER
+            merged_into_workstream_id INTEGER,
+            parent_workstream_id INTEGER
         );
         CREATE TABLE workstream_sessions (
             id INTEGER PRIMARY KEY,
@@ -59,6 +60,21 @@ pub(super) fn setup_workstream_schema(conn: &Connection) {
             observed_title TEXT NOT NULL,
             first_seen_epoch INTEGER NOT NULL,
             last_seen_epoch INTEGER NOT NULL
+        );
+        CREATE TABLE workstream_dependencies (
+            workstream_id INTEGER NOT NULL,
+            depends_on_workstream_id INTEGER NOT NULL,
+            kind TEXT NOT NULL DEFAULT 'hard',
+            created_at_epoch INTEGER NOT NULL,
+            PRIMARY KEY (workstream_id, depends_on_workstream_id)
+        );
+        CREATE TABLE workstream_milestones (
+            id INTEGER PRIMARY KEY,
+            workstream_id INTEGER NOT NULL,
+            title TEXT NOT NULL,
+            position INTEGER NOT NULL,
+            created_at_epoch INTEGER NOT NULL,
+            completed_at_epoch INTEGER
         );",
     )
     .unwrap();
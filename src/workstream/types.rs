@@ -42,6 +42,55 @@ pub struct WorkStream {
     pub created_at_epoch: i64,
     pub updated_at_epoch: i64,
     pub completed_at_epoch: Option<i64>,
+    #[serde(default)]
+    pub parent_id: Option<i64>,
+    #[serde(default)]
+    pub dependencies: Vec<WorkStreamDependency>,
+    #[serde(default)]
+    pub milestones: Vec<WorkStreamMilestone>,
+}
+
+/// `Hard` dependencies block the dependent workstream until the target
+/// completes: it cannot be set `active` or `completed` while one is open.
+/// `Soft` ones only record a preferred order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkStreamDependencyKind {
+    Hard,
+    Soft,
+}
+
+impl WorkStreamDependencyKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Hard => "hard",
+            Self::Soft => "soft",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "hard" => Some(Self::Hard),
+            "soft" => Some(Self::Soft),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkStreamDependency {
+    pub depends_on_id: i64,
+    pub kind: WorkStreamDependencyKind,
+    /// True once the target workstream is completed.
+    pub satisfied: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkStreamMilestone {
+    pub id: i64,
+    pub title: String,
+    pub position: i64,
+    pub completed_at_epoch: Option<i64>,
 }
 
 #[derive(Debug, Clone)]
@@ -4,6 +4,7 @@ use rusqlite::{params, Connection};
 use super::{
     identity::{ensure_workstream_alias, workstream_identity_key, MATCH_REASON_INSERT},
     matcher::find_workstream_for_upsert,
+    plan::require_hard_dependencies_met,
     ParsedWorkStream, WorkStreamStatus, WorkStreamUpsertResult,
 };
 
@@ -124,6 +125,9 @@ pub fn upsert_workstream_with_match(
     })
 }
 
+/// Sets status, next action and blockers. Moving a workstream to `active` or
+/// `completed` fails with [`WorkStreamPlanError`](super::WorkStreamPlanError)
+/// while one of its hard dependencies is still open.
 pub fn update_workstream_manual(
     conn: &Connection,
     id: i64,
@@ -136,6 +140,9 @@ pub fn update_workstream_manual(
     let mut param_idx = 2u32;
 
     let status_val = status.map(WorkStreamStatus::from_db);
+    if let Some(status) = status_val {
+        require_hard_dependencies_met(conn, id, status)?;
+    }
     if status_val.is_some() {
         sets.push(format!("status = ?{}", param_idx));
         param_idx += 1;
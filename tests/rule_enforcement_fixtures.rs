@@ -3,11 +3,11 @@ use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::time::{Duration, Instant};
 
-use anyhow::{ensure, Context, Result};
+use anyhow::{bail, ensure, Context, Result};
 use remem::rules::{
     artifact_path_for_project, classify_preference_predicate, evaluate_pre_tool_use,
     write_artifact_atomic, CompiledRule, CompiledRulesArtifact, PreferencePredicate, RuleAction,
-    RuleOverrideState, RulePredicate,
+    RuleOrigin, RuleOverrideState, RulePredicate,
 };
 use serde::Deserialize;
 use serde_json::json;
@@ -411,6 +411,11 @@ fn compiled_rule(scenario: &Scenario) -> Result<CompiledRule> {
         PreferencePredicate::GitPushForceForbidden { .. } => RulePredicate::GitPushForceForbidden {
             message: "Command violates a compiled forbidden-command preference".to_string(),
         },
+        PreferencePredicate::FilePathGlob { .. }
+        | PreferencePredicate::ToolNameDeny { .. }
+        | PreferencePredicate::ContentRegex { .. } => {
+            bail!("{} classified as a non-command predicate", scenario.id)
+        }
     };
     Ok(CompiledRule {
         rule_id: format!("pref-{}-1", scenario.source_memory_id),
@@ -422,6 +427,7 @@ fn compiled_rule(scenario: &Scenario) -> Result<CompiledRule> {
             action_override: None,
         },
         predicate,
+        origin: RuleOrigin::Compiled,
     })
 }
 